@@ -0,0 +1,21 @@
+#!/usr/bin/env bash
+# Mino JVM layer root-level install script
+# Installs Gradle from the official distribution (not packaged by Fedora).
+# The JDK and Maven come from distro packages — see [root_install] in layer.toml.
+#
+# Must run as root. Idempotent - safe to run multiple times.
+set -euo pipefail
+
+GRADLE_VERSION=8.14.3
+GRADLE_ZIP="gradle-${GRADLE_VERSION}-bin.zip"
+GRADLE_URL="https://services.gradle.org/distributions/${GRADLE_ZIP}"
+
+if [ ! -x "/opt/gradle-${GRADLE_VERSION}/bin/gradle" ]; then
+    tmp=$(mktemp -d)
+    curl -fsSL -o "${tmp}/${GRADLE_ZIP}" "${GRADLE_URL}"
+    echo "$(curl -fsSL "${GRADLE_URL}.sha256")  ${tmp}/${GRADLE_ZIP}" | sha256sum -c -
+    unzip -q "${tmp}/${GRADLE_ZIP}" -d /opt
+    rm -rf "${tmp}"
+fi
+
+ln -sf "/opt/gradle-${GRADLE_VERSION}/bin/gradle" /usr/local/bin/gradle
@@ -4,6 +4,7 @@
 //! Always-on by default (security tool — audit should be opt-out, not opt-in).
 
 use crate::config::{schema::Config, ConfigManager};
+use crate::redact;
 use chrono::Utc;
 use std::path::PathBuf;
 use tokio::fs::OpenOptions;
@@ -34,6 +35,9 @@ impl AuditLog {
             return;
         }
 
+        let mut data = data.clone();
+        redact::redact_json(&mut data);
+
         let entry = serde_json::json!({
             "timestamp": Utc::now().to_rfc3339(),
             "event": event,
@@ -83,6 +87,23 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn redacts_sensitive_values() {
+        let dir = TempDir::new().unwrap();
+        let audit = test_audit_log(&dir, true);
+
+        audit
+            .log(
+                "session.created",
+                &serde_json::json!({"command": ["env", "GH_TOKEN=ghp_secret", "gh"]}),
+            )
+            .await;
+
+        let content = tokio::fs::read_to_string(&audit.path).await.unwrap();
+        assert!(!content.contains("ghp_secret"));
+        assert!(content.contains("GH_TOKEN=***"));
+    }
+
     #[tokio::test]
     async fn writes_json_line() {
         let dir = TempDir::new().unwrap();
@@ -8,6 +8,17 @@ use std::sync::atomic::{AtomicI32, Ordering};
 #[cfg(unix)]
 pub(crate) static CHILD_PID: AtomicI32 = AtomicI32::new(0);
 
+/// Signals relayed to the sandboxed child
+#[cfg(unix)]
+const FORWARDED_SIGNALS: &[libc::c_int] = &[
+    libc::SIGINT,
+    libc::SIGTERM,
+    libc::SIGHUP,
+    libc::SIGQUIT,
+    libc::SIGUSR1,
+    libc::SIGUSR2,
+];
+
 /// Set up signal forwarding to child process
 ///
 /// # Safety
@@ -22,8 +33,11 @@ pub(crate) unsafe fn setup_signal_forwarding(child_pid: i32) {
     action.sa_flags = libc::SA_RESTART;
     libc::sigemptyset(&mut action.sa_mask);
 
-    libc::sigaction(libc::SIGINT, &action, std::ptr::null_mut());
-    libc::sigaction(libc::SIGTERM, &action, std::ptr::null_mut());
+    // Everything `mino stop` and `mino kill` send, so reload/dump signals
+    // reach the agent instead of terminating the helper
+    for sig in FORWARDED_SIGNALS {
+        libc::sigaction(*sig, &action, std::ptr::null_mut());
+    }
 }
 
 /// C-compatible signal handler that forwards signals to the child
@@ -84,7 +98,10 @@ mod tests {
             // Guard is satisfied: kill() would be skipped. Test passes.
         } else {
             // Another test set CHILD_PID. Verify it is a positive valid PID.
-            assert!(pid > 0, "CHILD_PID must be > 0 when set by setup_signal_forwarding");
+            assert!(
+                pid > 0,
+                "CHILD_PID must be > 0 when set by setup_signal_forwarding"
+            );
         }
     }
 }
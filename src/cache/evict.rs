@@ -0,0 +1,199 @@
+//! Least-recently-used eviction of complete caches
+//!
+//! With `[cache] auto_evict = true`, `mino run` removes complete caches,
+//! least recently used first, once usage (including the expected size of
+//! caches the run is about to build) reaches `max_total_gb`, until usage is
+//! back under the `evict_target_percent` high-water mark. `mino cache gc
+//! --max-size` frees space the same way, in a chosen order.
+
+use crate::cache::lockfile::Ecosystem;
+use crate::cache::volume::CacheSizeStatus;
+use chrono::{DateTime, Utc};
+
+/// A complete cache that may be evicted
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EvictionCandidate {
+    pub volume_name: String,
+    pub ecosystem: Ecosystem,
+    /// Disk usage in bytes
+    pub size: u64,
+    /// When a session last mounted the cache
+    pub last_used: DateTime<Utc>,
+    /// When the cache was created
+    pub created_at: DateTime<Utc>,
+}
+
+/// Order in which caches are removed to free space
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RemovalOrder {
+    /// Least recently used first
+    LeastRecentlyUsed,
+    /// Largest first, freeing the space with the fewest removals
+    Largest,
+    /// Oldest first, by creation time
+    Oldest,
+}
+
+/// Caches to evict, least recently used first, so that `usage` drops to
+/// `target` bytes. Nothing is evicted while `usage` is under `limit`.
+pub fn plan_eviction(
+    candidates: Vec<EvictionCandidate>,
+    usage: u64,
+    limit: u64,
+    target: u64,
+) -> Vec<EvictionCandidate> {
+    if CacheSizeStatus::from_usage(usage, limit) != CacheSizeStatus::Exceeded {
+        return Vec::new();
+    }
+    plan_removal(candidates, RemovalOrder::LeastRecentlyUsed, usage, target)
+}
+
+/// Caches to remove, in `order`, so that `usage` drops to `target` bytes
+pub fn plan_removal(
+    mut candidates: Vec<EvictionCandidate>,
+    order: RemovalOrder,
+    usage: u64,
+    target: u64,
+) -> Vec<EvictionCandidate> {
+    candidates.sort_by(|a, b| {
+        match order {
+            RemovalOrder::LeastRecentlyUsed => a.last_used.cmp(&b.last_used),
+            RemovalOrder::Largest => b.size.cmp(&a.size),
+            RemovalOrder::Oldest => a.created_at.cmp(&b.created_at),
+        }
+        .then_with(|| a.volume_name.cmp(&b.volume_name))
+    });
+    let mut remaining = usage;
+    candidates
+        .into_iter()
+        .take_while(|candidate| {
+            if remaining <= target {
+                return false;
+            }
+            remaining = remaining.saturating_sub(candidate.size);
+            true
+        })
+        .collect()
+}
+
+/// High-water mark: `percent` of `limit` (capped at 100%)
+pub fn target_bytes(limit: u64, percent: u32) -> u64 {
+    limit / 100 * u64::from(percent.min(100))
+}
+
+/// Expected growth of the caches being built: each is assumed to end up
+/// the average size of the complete caches of its ecosystem
+pub fn projected_growth(building: &[(Ecosystem, u64)], complete: &[(Ecosystem, u64)]) -> u64 {
+    building
+        .iter()
+        .map(|(ecosystem, current)| {
+            let sizes: Vec<u64> = complete
+                .iter()
+                .filter(|(e, _)| e == ecosystem)
+                .map(|(_, size)| *size)
+                .collect();
+            if sizes.is_empty() {
+                return 0;
+            }
+            let average = sizes.iter().sum::<u64>() / sizes.len() as u64;
+            average.saturating_sub(*current)
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    const GB: u64 = 1024 * 1024 * 1024;
+
+    fn candidate(name: &str, size_gb: u64, days_ago: i64) -> EvictionCandidate {
+        EvictionCandidate {
+            volume_name: name.to_string(),
+            ecosystem: Ecosystem::Npm,
+            size: size_gb * GB,
+            last_used: Utc::now() - Duration::days(days_ago),
+            created_at: Utc::now() - Duration::days(100 - size_gb as i64),
+        }
+    }
+
+    #[test]
+    fn nothing_evicted_under_limit() {
+        let plan = plan_eviction(vec![candidate("a", 5, 30)], 40 * GB, 50 * GB, 40 * GB);
+        assert!(plan.is_empty());
+    }
+
+    #[test]
+    fn evicts_least_recently_used_until_under_target() {
+        let candidates = vec![
+            candidate("recent", 10, 1),
+            candidate("oldest", 5, 60),
+            candidate("older", 8, 20),
+            candidate("old", 10, 10),
+        ];
+        let plan = plan_eviction(candidates, 55 * GB, 50 * GB, 40 * GB);
+        let names: Vec<&str> = plan.iter().map(|c| c.volume_name.as_str()).collect();
+        // 55 - 5 - 8 = 42, still over 40; - 10 = 32
+        assert_eq!(names, ["oldest", "older", "old"]);
+    }
+
+    #[test]
+    fn evicts_everything_available_when_short() {
+        let plan = plan_eviction(vec![candidate("only", 1, 3)], 60 * GB, 50 * GB, 40 * GB);
+        assert_eq!(plan.len(), 1);
+    }
+
+    #[test]
+    fn removal_order_by_strategy() {
+        let candidates = vec![
+            candidate("small-recent", 1, 1),
+            candidate("large", 10, 5),
+            candidate("medium-stale", 4, 30),
+        ];
+        let names = |order| -> Vec<String> {
+            plan_removal(candidates.clone(), order, 15 * GB, 4 * GB)
+                .into_iter()
+                .map(|c| c.volume_name)
+                .collect()
+        };
+        assert_eq!(
+            names(RemovalOrder::LeastRecentlyUsed),
+            ["medium-stale", "large"]
+        );
+        assert_eq!(names(RemovalOrder::Largest), ["large", "medium-stale"]);
+        // Created 100 - size days ago: the largest is the newest
+        assert_eq!(
+            names(RemovalOrder::Oldest),
+            ["small-recent", "medium-stale", "large"]
+        );
+    }
+
+    #[test]
+    fn removal_stops_at_target() {
+        let plan = plan_removal(
+            vec![candidate("a", 5, 1)],
+            RemovalOrder::Largest,
+            10 * GB,
+            10 * GB,
+        );
+        assert!(plan.is_empty());
+    }
+
+    #[test]
+    fn target_bytes_caps_at_limit() {
+        assert_eq!(target_bytes(100, 80), 80);
+        assert_eq!(target_bytes(100, 150), 100);
+    }
+
+    #[test]
+    fn projected_growth_uses_ecosystem_average() {
+        let complete = [
+            (Ecosystem::Npm, 4 * GB),
+            (Ecosystem::Npm, 2 * GB),
+            (Ecosystem::Cargo, 10 * GB),
+        ];
+        let building = [(Ecosystem::Npm, GB), (Ecosystem::Go, 0)];
+        assert_eq!(projected_growth(&building, &complete), 2 * GB);
+    }
+}
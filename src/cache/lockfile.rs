@@ -4,6 +4,7 @@
 //! based on the lockfile contents. Same lockfile = same cache.
 
 use crate::error::{MinoError, MinoResult};
+use crate::portable::normalize_text_bytes;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use std::fmt;
@@ -31,6 +32,20 @@ pub enum Ecosystem {
     Uv,
     /// Go modules (go.sum)
     Go,
+    /// Gradle/JVM (gradle.lockfile)
+    Gradle,
+    /// Maven/JVM (pom.xml, which pins versions in place of a lockfile)
+    Maven,
+    /// Composer/PHP (composer.lock)
+    Composer,
+    /// NuGet/.NET (packages.lock.json)
+    Nuget,
+    /// Bundler/Ruby (Gemfile.lock)
+    Bundler,
+    /// Bun (bun.lock, bun.lockb)
+    Bun,
+    /// Mix/Elixir (mix.lock)
+    Mix,
 }
 
 impl Ecosystem {
@@ -42,6 +57,13 @@ impl Ecosystem {
             Self::Pip | Self::Poetry => "pip",
             Self::Uv => "uv",
             Self::Go => "go",
+            Self::Gradle => "gradle",
+            Self::Maven => "maven",
+            Self::Composer => "composer",
+            Self::Nuget => "nuget",
+            Self::Bundler => "bundler",
+            Self::Bun => "bun",
+            Self::Mix => "mix",
         }
     }
 
@@ -71,6 +93,19 @@ impl Ecosystem {
                 ("GOMODCACHE", "/cache/go/mod"),
                 ("GOCACHE", "/cache/go/build"),
             ],
+            Self::Gradle => vec![("GRADLE_USER_HOME", "/cache/gradle")],
+            Self::Maven => vec![("MAVEN_OPTS", "-Dmaven.repo.local=/cache/maven/repository")],
+            Self::Composer => vec![("COMPOSER_CACHE_DIR", "/cache/composer")],
+            Self::Nuget => vec![
+                ("NUGET_PACKAGES", "/cache/nuget/packages"),
+                ("NUGET_HTTP_CACHE_PATH", "/cache/nuget/http"),
+            ],
+            Self::Bundler => vec![
+                ("BUNDLE_PATH", "/cache/bundler"),
+                ("BUNDLE_USER_CACHE", "/cache/bundler/cache"),
+            ],
+            Self::Bun => vec![("BUN_INSTALL_CACHE_DIR", "/cache/bun")],
+            Self::Mix => vec![("MIX_HOME", "/cache/mix"), ("HEX_HOME", "/cache/hex")],
         }
     }
 
@@ -85,6 +120,13 @@ impl Ecosystem {
             Self::Poetry => &["poetry.lock"],
             Self::Uv => &["uv.lock"],
             Self::Go => &["go.sum"],
+            Self::Gradle => &["gradle.lockfile"],
+            Self::Maven => &["pom.xml"],
+            Self::Composer => &["composer.lock"],
+            Self::Nuget => &["packages.lock.json"],
+            Self::Bundler => &["Gemfile.lock"],
+            Self::Bun => &["bun.lock", "bun.lockb"],
+            Self::Mix => &["mix.lock"],
         }
     }
 
@@ -99,6 +141,13 @@ impl Ecosystem {
             Self::Poetry,
             Self::Uv,
             Self::Go,
+            Self::Gradle,
+            Self::Maven,
+            Self::Composer,
+            Self::Nuget,
+            Self::Bundler,
+            Self::Bun,
+            Self::Mix,
         ]
     }
 }
@@ -114,6 +163,13 @@ impl fmt::Display for Ecosystem {
             Self::Poetry => "poetry",
             Self::Uv => "uv",
             Self::Go => "go",
+            Self::Gradle => "gradle",
+            Self::Maven => "maven",
+            Self::Composer => "composer",
+            Self::Nuget => "nuget",
+            Self::Bundler => "bundler",
+            Self::Bun => "bun",
+            Self::Mix => "mix",
         };
         write!(f, "{}", name)
     }
@@ -126,18 +182,88 @@ pub struct LockfileInfo {
     pub ecosystem: Ecosystem,
     /// Path to the lockfile
     pub path: PathBuf,
-    /// SHA256 hash of the lockfile contents (first 12 chars)
+    /// Further lockfiles of the ecosystem in nested directories, sharing
+    /// this cache (empty unless `scan_depth` found several)
+    pub nested: Vec<PathBuf>,
+    /// SHA256 hash of the lockfile contents (first 12 chars), combined over
+    /// `path` and `nested` when there are several
     pub hash: String,
+    /// Project the cache is private to under `scope = "project"`; `None`
+    /// for caches shared by every project with the same lockfile
+    pub project: Option<PathBuf>,
 }
 
 impl LockfileInfo {
-    /// Generate the cache volume name for this lockfile
+    /// Generate the cache volume name for this lockfile: the lockfile hash,
+    /// salted with a hash of the project path for project-scoped caches
     pub fn volume_name(&self) -> String {
-        format!("mino-cache-{}-{}", self.ecosystem, self.hash)
+        match &self.project {
+            None => format!("mino-cache-{}-{}", self.ecosystem, self.hash),
+            Some(project) => format!(
+                "mino-cache-{}-{}-{}",
+                self.ecosystem,
+                self.hash,
+                crate::home::hash_project_path(project)
+            ),
+        }
+    }
+
+    /// Scope the cache to `project_dir` when `scope` is per project
+    pub fn scoped(mut self, scope: CacheScope, project_dir: &Path) -> Self {
+        self.project = match scope {
+            CacheScope::Global => None,
+            CacheScope::Project => Some(project_dir.to_path_buf()),
+        };
+        self
+    }
+
+    /// Fold a layer's cache key extra (e.g. a toolchain version) into the
+    /// hash, so caches are keyed by lockfile and toolchain
+    pub fn with_key_extra(mut self, extra: Option<&str>) -> Self {
+        if let Some(extra) = extra {
+            let mut hasher = Sha256::new();
+            hasher.update(self.hash.as_bytes());
+            hasher.update([0]);
+            hasher.update(extra.as_bytes());
+            self.hash = hex::encode(&hasher.finalize()[..6]);
+        }
+        self
+    }
+
+    /// Every lockfile keyed into this cache, `path` first
+    pub fn paths(&self) -> impl Iterator<Item = &PathBuf> {
+        std::iter::once(&self.path).chain(&self.nested)
+    }
+}
+
+/// Which projects share a cache volume (`[cache] scope`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CacheScope {
+    /// Projects with identical lockfiles share one cache
+    #[default]
+    Global,
+    /// Each project gets its own caches
+    Project,
+}
+
+impl CacheScope {
+    /// Parse the `[cache] scope` setting
+    pub fn parse(value: &str) -> MinoResult<Self> {
+        match value {
+            "global" => Ok(Self::Global),
+            "project" => Ok(Self::Project),
+            other => Err(MinoError::User(format!(
+                "Unknown cache.scope '{}' (expected \"global\" or \"project\")",
+                other
+            ))),
+        }
     }
 }
 
 /// Hash a lockfile's contents using SHA256, returning first 12 hex chars
+///
+/// Text lockfiles are hashed with LF line endings, so a Windows checkout
+/// shares caches with Unix ones.
 fn hash_file_contents(path: &Path) -> MinoResult<String> {
     let contents = fs::read(path).map_err(|e| MinoError::Io {
         context: format!("reading lockfile {}", path.display()),
@@ -145,7 +271,7 @@ fn hash_file_contents(path: &Path) -> MinoResult<String> {
     })?;
 
     let mut hasher = Sha256::new();
-    hasher.update(&contents);
+    hasher.update(normalize_text_bytes(&contents));
     let result = hasher.finalize();
 
     // Take first 12 hex characters (6 bytes)
@@ -153,36 +279,183 @@ fn hash_file_contents(path: &Path) -> MinoResult<String> {
     Ok(hash)
 }
 
+/// Directories never scanned for nested lockfiles: dependency trees and
+/// build output, which hold lockfiles of packages rather than the project
+const SKIPPED_DIRS: &[&str] = &["node_modules", "target", "vendor", "_build", "deps"];
+
 /// Detect all lockfiles in a project directory
 ///
 /// Scans the project root for known lockfile patterns and returns
 /// information about each detected lockfile, including a content hash.
 pub fn detect_lockfiles(project_dir: &Path) -> MinoResult<Vec<LockfileInfo>> {
-    let mut lockfiles = Vec::new();
+    detect_lockfiles_nested(project_dir, 0)
+}
 
+/// Detect lockfiles in the project root and in subdirectories up to
+/// `scan_depth` levels below it (`[cache] scan_depth`).
+///
+/// Nested lockfiles of one ecosystem (workspace members, monorepo
+/// packages) share a cache keyed by a combined hash of all of them. Git
+/// projects are scanned through `git ls-files`, so ignored directories are
+/// skipped; otherwise hidden and dependency directories are.
+pub fn detect_lockfiles_nested(
+    project_dir: &Path,
+    scan_depth: u32,
+) -> MinoResult<Vec<LockfileInfo>> {
+    let files = if scan_depth == 0 {
+        Vec::new()
+    } else {
+        git_candidate_files(project_dir)
+            .unwrap_or_else(|| walk_candidate_files(project_dir, scan_depth))
+    };
+
+    let mut lockfiles = Vec::new();
     for ecosystem in Ecosystem::all() {
-        for pattern in ecosystem.lockfile_patterns() {
-            let lockfile_path = project_dir.join(pattern);
-            if lockfile_path.exists() && lockfile_path.is_file() {
-                debug!("Found {} lockfile: {}", ecosystem, lockfile_path.display());
-
-                let hash = hash_file_contents(&lockfile_path)?;
-                lockfiles.push(LockfileInfo {
-                    ecosystem: *ecosystem,
-                    path: lockfile_path,
-                    hash,
-                });
-
-                // Only use first matching lockfile per ecosystem
-                break;
+        let mut paths = Vec::new();
+        for dir in lockfile_dirs(&files, scan_depth) {
+            // Only use first matching lockfile per ecosystem and directory
+            let found = ecosystem
+                .lockfile_patterns()
+                .iter()
+                .map(|pattern| project_dir.join(&dir).join(pattern))
+                .find(|path| path.is_file());
+            if let Some(path) = found {
+                debug!("Found {} lockfile: {}", ecosystem, path.display());
+                paths.push(path);
             }
         }
+        if paths.is_empty() {
+            continue;
+        }
+
+        let hash = if paths.len() == 1 {
+            hash_file_contents(&paths[0])?
+        } else {
+            hash_lockfile_set(project_dir, &paths)?
+        };
+        let path = paths.remove(0);
+        lockfiles.push(LockfileInfo {
+            ecosystem: *ecosystem,
+            path,
+            nested: paths,
+            hash,
+            project: None,
+        });
     }
 
     debug!("Detected {} lockfiles", lockfiles.len());
     Ok(lockfiles)
 }
 
+/// Directories (relative to the project, root first) that hold a file
+/// named like a lockfile, at most `scan_depth` levels down
+fn lockfile_dirs(files: &[PathBuf], scan_depth: u32) -> Vec<PathBuf> {
+    let names: Vec<&str> = Ecosystem::all()
+        .iter()
+        .flat_map(|e| e.lockfile_patterns().iter().copied())
+        .collect();
+
+    let mut dirs = vec![PathBuf::new()];
+    for file in files {
+        let (Some(name), Some(dir)) = (file.file_name().and_then(|n| n.to_str()), file.parent())
+        else {
+            continue;
+        };
+        let depth = dir.components().count();
+        let skipped = dir
+            .components()
+            .any(|c| SKIPPED_DIRS.iter().any(|s| c.as_os_str() == *s));
+        if depth == 0 || depth > scan_depth as usize || skipped || !names.contains(&name) {
+            continue;
+        }
+        if !dirs.iter().any(|d| d == dir) {
+            dirs.push(dir.to_path_buf());
+        }
+    }
+    dirs[1..].sort();
+    dirs
+}
+
+/// Files of a git project that are tracked or untracked but not ignored,
+/// relative to `project_dir`. `None` outside a git repository.
+fn git_candidate_files(project_dir: &Path) -> Option<Vec<PathBuf>> {
+    let output = std::process::Command::new("git")
+        .args([
+            "ls-files",
+            "-z",
+            "--cached",
+            "--others",
+            "--exclude-standard",
+        ])
+        .current_dir(project_dir)
+        .stdin(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(
+        output
+            .stdout
+            .split(|b| *b == 0)
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| PathBuf::from(String::from_utf8_lossy(entry).as_ref()))
+            .collect(),
+    )
+}
+
+/// Files up to `scan_depth` directories below `project_dir`, relative to
+/// it, skipping hidden and dependency directories
+fn walk_candidate_files(project_dir: &Path, scan_depth: u32) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let mut pending = vec![(PathBuf::new(), 0u32)];
+    while let Some((dir, depth)) = pending.pop() {
+        let Ok(entries) = fs::read_dir(project_dir.join(&dir)) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let name = entry.file_name();
+            let relative = dir.join(&name);
+            let Ok(file_type) = entry.file_type() else {
+                continue;
+            };
+            if file_type.is_file() {
+                files.push(relative);
+            } else if file_type.is_dir()
+                && depth < scan_depth
+                && !name.to_string_lossy().starts_with('.')
+                && !SKIPPED_DIRS.iter().any(|s| name == *s)
+            {
+                pending.push((relative, depth + 1));
+            }
+        }
+    }
+    files
+}
+
+/// Combined hash of several lockfiles: each file's project-relative path
+/// and contents, in order, so moving or editing any of them changes it
+fn hash_lockfile_set(project_dir: &Path, paths: &[PathBuf]) -> MinoResult<String> {
+    let mut hasher = Sha256::new();
+    for path in paths {
+        let contents = fs::read(path).map_err(|e| MinoError::Io {
+            context: format!("reading lockfile {}", path.display()),
+            source: e,
+        })?;
+        let relative = path.strip_prefix(project_dir).unwrap_or(path);
+        let relative: Vec<String> = relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy().into_owned())
+            .collect();
+        hasher.update(relative.join("/").as_bytes());
+        hasher.update([0]);
+        hasher.update(normalize_text_bytes(&contents));
+        hasher.update([0]);
+    }
+    Ok(hex::encode(&hasher.finalize()[..6]))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -218,6 +491,20 @@ mod tests {
         assert_eq!(hash1.len(), 12);
     }
 
+    #[test]
+    fn hash_ignores_line_endings() {
+        let dir = TempDir::new().unwrap();
+        let lf = dir.path().join("lf.lock");
+        let crlf = dir.path().join("crlf.lock");
+        fs::write(&lf, b"a = 1\nb = 2\n").unwrap();
+        fs::write(&crlf, b"a = 1\r\nb = 2\r\n").unwrap();
+
+        assert_eq!(
+            hash_file_contents(&lf).unwrap(),
+            hash_file_contents(&crlf).unwrap()
+        );
+    }
+
     #[test]
     fn hash_different_content() {
         let dir = TempDir::new().unwrap();
@@ -266,12 +553,58 @@ mod tests {
         let info = LockfileInfo {
             ecosystem: Ecosystem::Npm,
             path: PathBuf::from("/test/package-lock.json"),
+            nested: Vec::new(),
             hash: "a1b2c3d4e5f6".to_string(),
+            project: None,
         };
 
         assert_eq!(info.volume_name(), "mino-cache-npm-a1b2c3d4e5f6");
     }
 
+    #[test]
+    fn project_scope_salts_volume_name() {
+        let info = LockfileInfo {
+            ecosystem: Ecosystem::Npm,
+            path: PathBuf::from("/a/package-lock.json"),
+            nested: Vec::new(),
+            hash: "a1b2c3d4e5f6".to_string(),
+            project: None,
+        };
+
+        let global = info.clone().scoped(CacheScope::Global, Path::new("/a"));
+        assert_eq!(global.volume_name(), "mino-cache-npm-a1b2c3d4e5f6");
+
+        let a = info.clone().scoped(CacheScope::Project, Path::new("/a"));
+        let b = info.scoped(CacheScope::Project, Path::new("/b"));
+        assert!(a.volume_name().starts_with("mino-cache-npm-a1b2c3d4e5f6-"));
+        assert_ne!(a.volume_name(), b.volume_name());
+    }
+
+    #[test]
+    fn key_extra_changes_hash() {
+        let info = LockfileInfo {
+            ecosystem: Ecosystem::Cargo,
+            path: PathBuf::from("/a/Cargo.lock"),
+            nested: Vec::new(),
+            hash: "a1b2c3d4e5f6".to_string(),
+            project: None,
+        };
+
+        assert_eq!(info.clone().with_key_extra(None).hash, "a1b2c3d4e5f6");
+        let v74 = info.clone().with_key_extra(Some("rust-1.74")).hash;
+        let v82 = info.clone().with_key_extra(Some("rust-1.82")).hash;
+        assert_eq!(v74.len(), 12);
+        assert_ne!(v74, v82);
+        assert_eq!(v82, info.with_key_extra(Some("rust-1.82")).hash);
+    }
+
+    #[test]
+    fn cache_scope_parse() {
+        assert_eq!(CacheScope::parse("global").unwrap(), CacheScope::Global);
+        assert_eq!(CacheScope::parse("project").unwrap(), CacheScope::Project);
+        assert!(CacheScope::parse("team").is_err());
+    }
+
     #[test]
     fn detect_empty_dir() {
         let dir = TempDir::new().unwrap();
@@ -292,6 +625,132 @@ mod tests {
         assert_eq!(lockfiles[0].path, lockfile);
     }
 
+    #[test]
+    fn detect_jvm_php_dotnet_ruby_bun_elixir() {
+        let dir = TempDir::new().unwrap();
+        for name in [
+            "gradle.lockfile",
+            "pom.xml",
+            "composer.lock",
+            "packages.lock.json",
+            "Gemfile.lock",
+            "bun.lockb",
+            "mix.lock",
+        ] {
+            fs::write(dir.path().join(name), name).unwrap();
+        }
+
+        let ecosystems: Vec<Ecosystem> = detect_lockfiles(dir.path())
+            .unwrap()
+            .into_iter()
+            .map(|l| l.ecosystem)
+            .collect();
+        assert_eq!(
+            ecosystems,
+            vec![
+                Ecosystem::Gradle,
+                Ecosystem::Maven,
+                Ecosystem::Composer,
+                Ecosystem::Nuget,
+                Ecosystem::Bundler,
+                Ecosystem::Bun,
+                Ecosystem::Mix,
+            ]
+        );
+    }
+
+    #[test]
+    fn bun_prefers_text_lockfile() {
+        let dir = TempDir::new().unwrap();
+        fs::write(dir.path().join("bun.lock"), "{}").unwrap();
+        fs::write(dir.path().join("bun.lockb"), [0u8, 159, 146, 150]).unwrap();
+
+        let lockfiles = detect_lockfiles(dir.path()).unwrap();
+        assert_eq!(lockfiles.len(), 1);
+        assert_eq!(lockfiles[0].path, dir.path().join("bun.lock"));
+        assert!(lockfiles[0].volume_name().starts_with("mino-cache-bun-"));
+    }
+
+    #[test]
+    fn new_ecosystems_cache_under_their_own_dir() {
+        for ecosystem in [
+            Ecosystem::Gradle,
+            Ecosystem::Maven,
+            Ecosystem::Composer,
+            Ecosystem::Nuget,
+            Ecosystem::Bundler,
+            Ecosystem::Bun,
+            Ecosystem::Mix,
+        ] {
+            let dir = format!("/cache/{}", ecosystem.cache_dir());
+            let vars = ecosystem.cache_env_vars();
+            assert!(!vars.is_empty(), "{}", ecosystem);
+            assert!(
+                vars.iter().any(|(_, value)| value.contains(&dir)),
+                "{} env does not use {}",
+                ecosystem,
+                dir
+            );
+        }
+        assert_eq!(
+            Ecosystem::Maven.cache_env_vars(),
+            vec![("MAVEN_OPTS", "-Dmaven.repo.local=/cache/maven/repository")]
+        );
+    }
+
+    #[test]
+    fn detect_nested_lockfiles_within_depth() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        std::fs::write(root.join("package-lock.json"), "{}").unwrap();
+        for sub in ["packages/a", "packages/b/deep/er"] {
+            std::fs::create_dir_all(root.join(sub)).unwrap();
+            std::fs::write(root.join(sub).join("package-lock.json"), sub).unwrap();
+        }
+
+        let lockfiles = detect_lockfiles_nested(root, 3).unwrap();
+        assert_eq!(lockfiles.len(), 1);
+        assert_eq!(lockfiles[0].path, root.join("package-lock.json"));
+        assert_eq!(
+            lockfiles[0].nested,
+            vec![root.join("packages/a/package-lock.json")]
+        );
+
+        let root_only = detect_lockfiles_nested(root, 0).unwrap();
+        assert!(root_only[0].nested.is_empty());
+        assert_ne!(lockfiles[0].hash, root_only[0].hash);
+    }
+
+    #[test]
+    fn nested_scan_skips_dependency_and_hidden_dirs() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        for sub in ["node_modules/pkg", ".cache", "crates/core"] {
+            std::fs::create_dir_all(root.join(sub)).unwrap();
+        }
+        std::fs::write(root.join("node_modules/pkg/package-lock.json"), "{}").unwrap();
+        std::fs::write(root.join(".cache/Cargo.lock"), "").unwrap();
+        std::fs::write(root.join("crates/core/Cargo.lock"), "").unwrap();
+
+        let lockfiles = detect_lockfiles_nested(root, 3).unwrap();
+        assert_eq!(lockfiles.len(), 1);
+        assert_eq!(lockfiles[0].ecosystem, Ecosystem::Cargo);
+        assert_eq!(lockfiles[0].path, root.join("crates/core/Cargo.lock"));
+    }
+
+    #[test]
+    fn lockfile_set_hash_depends_on_paths() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        for sub in ["a", "b"] {
+            std::fs::create_dir_all(root.join(sub)).unwrap();
+            std::fs::write(root.join(sub).join("Cargo.lock"), "same").unwrap();
+        }
+        let a = hash_lockfile_set(root, &[root.join("a/Cargo.lock")]).unwrap();
+        let b = hash_lockfile_set(root, &[root.join("b/Cargo.lock")]).unwrap();
+        assert_ne!(a, b);
+    }
+
     #[test]
     fn uv_cache_env_vars() {
         let env_vars = Ecosystem::Uv.cache_env_vars();
@@ -15,14 +15,25 @@
 //! |-------|-------|-------------|
 //! | Miss | rw | No volume exists, creating new |
 //! | Building | rw | In progress or crashed, retryable |
-//! | Complete | rw | Finalized, skip re-finalization |
+//! | Complete | ro | Finalized, immutable (`--cache-rw` overrides) |
+//!
+//! With `[cache.remote]`, a miss is first filled from a shared store of
+//! prewarmed archives (see [`remote`]).
 
+pub mod evict;
 pub mod lockfile;
+pub mod remote;
 pub mod sidecar;
+pub mod stats;
+pub mod storage;
 pub mod volume;
 
-pub use lockfile::{detect_lockfiles, Ecosystem, LockfileInfo};
+pub use lockfile::{
+    detect_lockfiles, detect_lockfiles_nested, CacheScope, Ecosystem, LockfileInfo,
+};
+pub use remote::{RemoteCache, UploadOutcome};
 pub use sidecar::CacheSidecar;
+pub use stats::{CacheOutcome, CacheStatsLog, CacheUseRecord};
 pub use volume::{
     format_bytes, gb_to_bytes, labels, plan_cache_mounts, resolve_state, CacheMount,
     CacheSizeStatus, CacheState, CacheVolume,
@@ -0,0 +1,512 @@
+//! Remote store for prewarmed cache archives (`[cache.remote]`)
+//!
+//! Cache volumes are content-addressed by lockfile hash, so a volume built
+//! on one machine is valid on every other. The remote store holds volume
+//! archives (`podman volume export` tarballs) under the volume name: a
+//! local cache miss downloads the archive instead of rebuilding, and a
+//! cache finalized by `mino cache warm` is uploaded (with `upload = true`)
+//! unless the store already has it.
+//!
+//! Transfers are concurrency-safe:
+//!
+//! - **s3**: downloads pin the ETag read by `head-object` (`--if-match`), so
+//!   an object replaced mid-download fails instead of yielding a mix, and
+//!   uploads are conditional (`--if-none-match '*'`), so the first of two
+//!   machines finalizing the same cache wins and the other backs off.
+//! - **oci**: downloads pull the manifest digest resolved up front; uploads
+//!   skip tags that already exist (registries offer no conditional push).
+//!
+//! Archives carry their SHA-256 (S3 object metadata, OCI layer digest) and
+//! are verified before import; an S3 object without one is rejected.
+
+use crate::config::schema::RemoteCacheConfig;
+use crate::config::ConfigManager;
+use crate::error::{MinoError, MinoResult};
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+use std::process::{Output, Stdio};
+use tokio::io::AsyncReadExt;
+use tokio::process::Command;
+use tracing::debug;
+
+/// Media type of cache archive layers pushed to OCI registries
+const OCI_LAYER_MEDIA_TYPE: &str = "application/vnd.mino.cache.layer.v1.tar";
+
+/// S3 object metadata key holding the archive's SHA-256
+const SHA256_METADATA_KEY: &str = "sha256";
+
+/// Where cache archives are stored
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Backend {
+    S3 {
+        bucket: String,
+        prefix: String,
+        region: Option<String>,
+        endpoint_url: Option<String>,
+    },
+    Oci {
+        repository: String,
+    },
+}
+
+/// Result of uploading a cache archive
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UploadOutcome {
+    /// The archive was stored
+    Uploaded,
+    /// The store already held this cache (or another machine won the race)
+    AlreadyPresent,
+}
+
+/// Remote cache store configured by `[cache.remote]`
+#[derive(Debug, Clone)]
+pub struct RemoteCache {
+    backend: Backend,
+    upload: bool,
+}
+
+impl RemoteCache {
+    /// Store from config, or `None` when no backend is set
+    pub fn from_config(config: &RemoteCacheConfig) -> MinoResult<Option<Self>> {
+        let backend = match config.backend.as_str() {
+            "" => return Ok(None),
+            "s3" => {
+                if config.bucket.is_empty() {
+                    return Err(MinoError::User(
+                        "cache.remote.bucket is required for the s3 backend".to_string(),
+                    ));
+                }
+                Backend::S3 {
+                    bucket: config.bucket.clone(),
+                    prefix: config.prefix.clone(),
+                    region: config.region.clone(),
+                    endpoint_url: config.endpoint_url.clone(),
+                }
+            }
+            "oci" => {
+                if config.repository.is_empty() {
+                    return Err(MinoError::User(
+                        "cache.remote.repository is required for the oci backend".to_string(),
+                    ));
+                }
+                Backend::Oci {
+                    repository: config.repository.trim_end_matches('/').to_string(),
+                }
+            }
+            other => {
+                return Err(MinoError::User(format!(
+                    "Unknown cache.remote.backend '{}' (expected \"s3\" or \"oci\")",
+                    other
+                )))
+            }
+        };
+        Ok(Some(Self {
+            backend,
+            upload: config.upload,
+        }))
+    }
+
+    /// Whether caches finalized by `mino cache warm` are uploaded
+    pub fn uploads(&self) -> bool {
+        self.upload
+    }
+
+    /// Location of the archive for `volume_name`, for messages
+    pub fn location(&self, volume_name: &str) -> String {
+        match &self.backend {
+            Backend::S3 { bucket, prefix, .. } => {
+                format!("s3://{}/{}", bucket, s3_key(prefix, volume_name))
+            }
+            Backend::Oci { repository } => oci_reference(repository, volume_name),
+        }
+    }
+
+    /// Download the archive for `volume_name` to `dest`.
+    /// Returns `false` when the store has no archive for it.
+    pub async fn download(&self, volume_name: &str, dest: &Path) -> MinoResult<bool> {
+        debug!("Looking up remote cache {}", self.location(volume_name));
+        match &self.backend {
+            Backend::S3 { .. } => self.s3_download(volume_name, dest).await,
+            Backend::Oci { repository } => oci_download(repository, volume_name, dest).await,
+        }
+    }
+
+    /// Upload the archive for `volume_name` unless the store already has one
+    pub async fn upload(&self, volume_name: &str, archive: &Path) -> MinoResult<UploadOutcome> {
+        debug!("Uploading cache archive to {}", self.location(volume_name));
+        match &self.backend {
+            Backend::S3 { .. } => self.s3_upload(volume_name, archive).await,
+            Backend::Oci { repository } => oci_upload(repository, volume_name, archive).await,
+        }
+    }
+
+    /// `aws s3api` command for `operation` with bucket, key, region and
+    /// endpoint set
+    fn s3api(&self, operation: &str, volume_name: &str) -> Command {
+        let Backend::S3 {
+            bucket,
+            prefix,
+            region,
+            endpoint_url,
+        } = &self.backend
+        else {
+            unreachable!("s3api called for a non-s3 remote cache");
+        };
+        let mut cmd = Command::new("aws");
+        cmd.args(["s3api", operation]);
+        cmd.args(["--bucket", bucket]);
+        cmd.args(["--key", &s3_key(prefix, volume_name)]);
+        cmd.args(["--output", "json"]);
+        if let Some(region) = region {
+            cmd.args(["--region", region]);
+        }
+        if let Some(endpoint) = endpoint_url {
+            cmd.args(["--endpoint-url", endpoint]);
+        }
+        cmd
+    }
+
+    async fn s3_download(&self, volume_name: &str, dest: &Path) -> MinoResult<bool> {
+        let head = run(
+            self.s3api("head-object", volume_name),
+            "aws s3api head-object",
+        )
+        .await?;
+        if !head.status.success() {
+            let stderr = String::from_utf8_lossy(&head.stderr);
+            if is_s3_not_found(&stderr) {
+                return Ok(false);
+            }
+            return Err(MinoError::command_exec("aws s3api head-object", stderr));
+        }
+        let (etag, expected) = parse_head_object(&head.stdout)?;
+
+        let mut get = self.s3api("get-object", volume_name);
+        get.args(["--if-match", &etag]);
+        get.arg(dest);
+        let output = run(get, "aws s3api get-object").await?;
+        if !output.status.success() {
+            return Err(MinoError::command_exec(
+                "aws s3api get-object",
+                String::from_utf8_lossy(&output.stderr),
+            ));
+        }
+
+        verify_sha256(dest, &expected).await?;
+        Ok(true)
+    }
+
+    async fn s3_upload(&self, volume_name: &str, archive: &Path) -> MinoResult<UploadOutcome> {
+        let digest = sha256_file(archive).await?;
+        let mut put = self.s3api("put-object", volume_name);
+        put.arg("--body").arg(archive);
+        put.args(["--if-none-match", "*"]);
+        put.args(["--metadata", &format!("{}={}", SHA256_METADATA_KEY, digest)]);
+        let output = run(put, "aws s3api put-object").await?;
+        if output.status.success() {
+            return Ok(UploadOutcome::Uploaded);
+        }
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if is_s3_precondition_failed(&stderr) {
+            return Ok(UploadOutcome::AlreadyPresent);
+        }
+        Err(MinoError::command_exec("aws s3api put-object", stderr))
+    }
+}
+
+/// Scratch directory for archives in transit
+pub fn transfer_dir() -> PathBuf {
+    ConfigManager::cache_state_dir().join("transfer")
+}
+
+/// Object key of the archive for `volume_name`
+fn s3_key(prefix: &str, volume_name: &str) -> String {
+    format!("{}{}.tar", prefix, volume_name)
+}
+
+/// Tagged reference of the archive for `volume_name`
+fn oci_reference(repository: &str, volume_name: &str) -> String {
+    format!("{}:{}", repository, volume_name)
+}
+
+/// ETag and recorded SHA-256 from `aws s3api head-object` output. An
+/// object without a checksum is rejected rather than imported unverified.
+fn parse_head_object(stdout: &[u8]) -> MinoResult<(String, String)> {
+    let head: serde_json::Value = serde_json::from_slice(stdout)
+        .map_err(|e| MinoError::Internal(format!("parsing aws s3api head-object: {}", e)))?;
+    let etag = head["ETag"]
+        .as_str()
+        .ok_or_else(|| MinoError::Internal("head-object returned no ETag".to_string()))?
+        .to_string();
+    let sha256 = head["Metadata"][SHA256_METADATA_KEY]
+        .as_str()
+        .ok_or_else(|| {
+            MinoError::User(format!(
+                "cache archive has no {} metadata; refusing to import it",
+                SHA256_METADATA_KEY
+            ))
+        })?
+        .to_string();
+    Ok((etag, sha256))
+}
+
+/// Whether `aws s3api` stderr reports a missing object
+fn is_s3_not_found(stderr: &str) -> bool {
+    stderr.contains("(404)") || stderr.contains("Not Found") || stderr.contains("NoSuchKey")
+}
+
+/// Whether `aws s3api` stderr reports a failed conditional write: the
+/// object exists, or a concurrent upload of it is in flight
+fn is_s3_precondition_failed(stderr: &str) -> bool {
+    stderr.contains("PreconditionFailed")
+        || stderr.contains("(412)")
+        || stderr.contains("ConditionalRequestConflict")
+}
+
+/// Pull the archive for `volume_name` pinned to the digest its tag points at
+async fn oci_download(repository: &str, volume_name: &str, dest: &Path) -> MinoResult<bool> {
+    let reference = oci_reference(repository, volume_name);
+    let mut fetch = Command::new("oras");
+    fetch.args(["manifest", "fetch", "--descriptor", &reference]);
+    let output = run(fetch, "oras manifest fetch").await?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if stderr.contains("not found") {
+            return Ok(false);
+        }
+        return Err(MinoError::command_exec("oras manifest fetch", stderr));
+    }
+    let descriptor: serde_json::Value = serde_json::from_slice(&output.stdout)
+        .map_err(|e| MinoError::Internal(format!("parsing oras descriptor: {}", e)))?;
+    let digest = descriptor["digest"]
+        .as_str()
+        .ok_or_else(|| MinoError::Internal("oras descriptor has no digest".to_string()))?;
+
+    // oras names pulled files after their title annotation, so pull into a
+    // directory of our own and take the one archive from it
+    let pull_dir = dest.with_extension("pull");
+    tokio::fs::create_dir_all(&pull_dir)
+        .await
+        .map_err(|e| MinoError::io(format!("creating {}", pull_dir.display()), e))?;
+    let result = async {
+        let mut pull = Command::new("oras");
+        pull.args(["pull", &format!("{}@{}", repository, digest), "--output"]);
+        pull.arg(&pull_dir);
+        let output = run(pull, "oras pull").await?;
+        if !output.status.success() {
+            return Err(MinoError::command_exec(
+                "oras pull",
+                String::from_utf8_lossy(&output.stderr),
+            ));
+        }
+        let pulled = single_file(&pull_dir).await?;
+        tokio::fs::rename(&pulled, dest)
+            .await
+            .map_err(|e| MinoError::io(format!("moving {}", pulled.display()), e))
+    }
+    .await;
+    tokio::fs::remove_dir_all(&pull_dir).await.ok();
+    result.map(|()| true)
+}
+
+/// Push the archive for `volume_name` unless its tag already exists
+async fn oci_upload(
+    repository: &str,
+    volume_name: &str,
+    archive: &Path,
+) -> MinoResult<UploadOutcome> {
+    let reference = oci_reference(repository, volume_name);
+    let mut exists = Command::new("oras");
+    exists.args(["manifest", "fetch", "--descriptor", &reference]);
+    if run(exists, "oras manifest fetch").await?.status.success() {
+        return Ok(UploadOutcome::AlreadyPresent);
+    }
+
+    let (Some(dir), Some(file)) = (archive.parent(), archive.file_name()) else {
+        return Err(MinoError::Internal(format!(
+            "invalid cache archive path {}",
+            archive.display()
+        )));
+    };
+    let mut push = Command::new("oras");
+    push.current_dir(dir);
+    push.args(["push", &reference]);
+    push.arg(format!(
+        "{}:{}",
+        file.to_string_lossy(),
+        OCI_LAYER_MEDIA_TYPE
+    ));
+    let output = run(push, "oras push").await?;
+    if !output.status.success() {
+        return Err(MinoError::command_exec(
+            "oras push",
+            String::from_utf8_lossy(&output.stderr),
+        ));
+    }
+    Ok(UploadOutcome::Uploaded)
+}
+
+/// The only file in `dir`
+async fn single_file(dir: &Path) -> MinoResult<PathBuf> {
+    let mut entries = tokio::fs::read_dir(dir)
+        .await
+        .map_err(|e| MinoError::io(format!("reading {}", dir.display()), e))?;
+    let mut files = Vec::new();
+    while let Some(entry) = entries
+        .next_entry()
+        .await
+        .map_err(|e| MinoError::io(format!("reading {}", dir.display()), e))?
+    {
+        files.push(entry.path());
+    }
+    match files.as_slice() {
+        [file] => Ok(file.clone()),
+        _ => Err(MinoError::Internal(format!(
+            "expected one cache archive in the pulled artifact, found {}",
+            files.len()
+        ))),
+    }
+}
+
+/// Run `cmd` capturing its output
+async fn run(mut cmd: Command, name: &str) -> MinoResult<Output> {
+    cmd.stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    cmd.output()
+        .await
+        .map_err(|e| MinoError::command_failed(name, e))
+}
+
+/// Hex SHA-256 of the file at `path`
+async fn sha256_file(path: &Path) -> MinoResult<String> {
+    let mut file = tokio::fs::File::open(path)
+        .await
+        .map_err(|e| MinoError::io(format!("opening {}", path.display()), e))?;
+    let mut hasher = Sha256::new();
+    let mut buf = vec![0u8; 1 << 16];
+    loop {
+        let n = file
+            .read(&mut buf)
+            .await
+            .map_err(|e| MinoError::io(format!("reading {}", path.display()), e))?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buf[..n]);
+    }
+    Ok(hex::encode(hasher.finalize()))
+}
+
+/// Fail unless the file at `path` hashes to `expected`
+async fn verify_sha256(path: &Path, expected: &str) -> MinoResult<()> {
+    let actual = sha256_file(path).await?;
+    if !actual.eq_ignore_ascii_case(expected) {
+        return Err(MinoError::Internal(format!(
+            "cache archive checksum mismatch (expected {}, got {})",
+            expected, actual
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn s3_config() -> RemoteCacheConfig {
+        RemoteCacheConfig {
+            backend: "s3".to_string(),
+            bucket: "team-caches".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn from_config_disabled_without_backend() {
+        assert!(RemoteCache::from_config(&RemoteCacheConfig::default())
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn from_config_validates_backend_settings() {
+        let mut config = s3_config();
+        config.bucket.clear();
+        assert!(RemoteCache::from_config(&config).is_err());
+
+        let oci = RemoteCacheConfig {
+            backend: "oci".to_string(),
+            ..Default::default()
+        };
+        assert!(RemoteCache::from_config(&oci).is_err());
+
+        let unknown = RemoteCacheConfig {
+            backend: "gcs".to_string(),
+            ..Default::default()
+        };
+        assert!(RemoteCache::from_config(&unknown).is_err());
+    }
+
+    #[test]
+    fn location_keys_archives_by_volume_name() {
+        let s3 = RemoteCache::from_config(&s3_config()).unwrap().unwrap();
+        assert_eq!(
+            s3.location("mino-cache-npm-abc123"),
+            "s3://team-caches/mino-cache/mino-cache-npm-abc123.tar"
+        );
+
+        let oci = RemoteCache::from_config(&RemoteCacheConfig {
+            backend: "oci".to_string(),
+            repository: "ghcr.io/acme/mino-cache/".to_string(),
+            ..Default::default()
+        })
+        .unwrap()
+        .unwrap();
+        assert_eq!(
+            oci.location("mino-cache-npm-abc123"),
+            "ghcr.io/acme/mino-cache:mino-cache-npm-abc123"
+        );
+    }
+
+    #[test]
+    fn parse_head_object_reads_etag_and_checksum() {
+        let (etag, sha) = parse_head_object(
+            br#"{"ETag": "\"9b2cf535f27731c974343645a3985328\"", "Metadata": {"sha256": "ab12"}}"#,
+        )
+        .unwrap();
+        assert_eq!(etag, "\"9b2cf535f27731c974343645a3985328\"");
+        assert_eq!(sha, "ab12");
+
+        assert!(parse_head_object(br#"{"ETag": "\"x\"", "Metadata": {}}"#).is_err());
+        assert!(parse_head_object(b"{}").is_err());
+    }
+
+    #[test]
+    fn s3_errors_are_classified() {
+        assert!(is_s3_not_found(
+            "An error occurred (404) when calling the HeadObject operation: Not Found"
+        ));
+        assert!(is_s3_precondition_failed(
+            "An error occurred (PreconditionFailed) when calling the PutObject operation"
+        ));
+        assert!(!is_s3_precondition_failed(
+            "An error occurred (AccessDenied) when calling the PutObject operation"
+        ));
+    }
+
+    #[tokio::test]
+    async fn verify_sha256_rejects_mismatch() {
+        let dir = std::env::temp_dir().join(format!("mino-remote-{}", uuid::Uuid::new_v4()));
+        tokio::fs::create_dir_all(&dir).await.unwrap();
+        let path = dir.join("cache.tar");
+        tokio::fs::write(&path, b"hello").await.unwrap();
+
+        let expected = "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824";
+        assert_eq!(sha256_file(&path).await.unwrap(), expected);
+        assert!(verify_sha256(&path, expected).await.is_ok());
+        assert!(verify_sha256(&path, "00").await.is_err());
+
+        tokio::fs::remove_dir_all(&dir).await.ok();
+    }
+}
@@ -30,6 +30,15 @@ pub struct CacheSidecar {
     pub created_at: DateTime<Utc>,
     /// When the sidecar was last updated
     pub updated_at: DateTime<Utc>,
+    /// Lockfiles this cache was created or reused for (identical lockfiles
+    /// in several projects share one cache). Empty for caches created before
+    /// origins were tracked.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub lockfiles: Vec<PathBuf>,
+    /// When a session last mounted the cache (for LRU eviction). Unset for
+    /// caches not used since usage was tracked.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_used_at: Option<DateTime<Utc>>,
 }
 
 impl CacheSidecar {
@@ -43,9 +52,39 @@ impl CacheSidecar {
             state,
             created_at: now,
             updated_at: now,
+            lockfiles: Vec::new(),
+            last_used_at: None,
         }
     }
 
+    /// Record that a session is using the cache now
+    pub fn touch(&mut self) {
+        self.last_used_at = Some(Utc::now());
+    }
+
+    /// When the cache was last used, falling back to its last state change
+    pub fn last_used(&self) -> DateTime<Utc> {
+        self.last_used_at.unwrap_or(self.updated_at)
+    }
+
+    /// Record `path` as an origin of this cache. Returns false if it was
+    /// already recorded.
+    pub fn record_lockfile(&mut self, path: &Path) -> bool {
+        if self.lockfiles.iter().any(|p| p == path) {
+            return false;
+        }
+        self.lockfiles.push(path.to_path_buf());
+        true
+    }
+
+    /// Whether every recorded lockfile is gone from disk.
+    ///
+    /// Caches with no recorded origin are never orphaned: there is nothing
+    /// to check them against.
+    pub fn is_orphaned(&self) -> bool {
+        !self.lockfiles.is_empty() && self.lockfiles.iter().all(|p| !p.exists())
+    }
+
     /// Get the file path for a volume's sidecar
     pub fn file_path(volume_name: &str) -> PathBuf {
         ConfigManager::cache_state_dir().join(format!("{}.json", volume_name))
@@ -157,6 +196,26 @@ impl CacheSidecar {
         self.state = CacheState::Complete;
         self.save().await
     }
+
+    /// The complete cache to seed a new cache for `lockfiles` from: the most
+    /// recently used complete cache of the same ecosystem recorded for one
+    /// of the same lockfiles, i.e. the cache of the previous lockfile version
+    pub fn seed_for<'a>(
+        sidecars: &'a [Self],
+        volume_name: &str,
+        ecosystem: Ecosystem,
+        lockfiles: &[&Path],
+    ) -> Option<&'a Self> {
+        sidecars
+            .iter()
+            .filter(|s| {
+                s.volume_name != volume_name
+                    && s.ecosystem == ecosystem
+                    && s.state == CacheState::Complete
+                    && s.lockfiles.iter().any(|p| lockfiles.contains(&p.as_path()))
+            })
+            .max_by_key(|s| s.last_used())
+    }
 }
 
 #[cfg(test)]
@@ -334,6 +393,130 @@ mod tests {
         assert_eq!(parsed.state, CacheState::Complete);
     }
 
+    #[test]
+    fn sidecar_last_used_falls_back_to_updated_at() {
+        let mut sidecar: CacheSidecar = serde_json::from_str(
+            r#"{"volume_name":"mino-cache-npm-abc123","ecosystem":"npm","hash":"abc123",
+                "state":"complete","created_at":"2026-01-01T00:00:00Z",
+                "updated_at":"2026-01-02T00:00:00Z"}"#,
+        )
+        .unwrap();
+        assert_eq!(sidecar.last_used(), sidecar.updated_at);
+
+        sidecar.touch();
+        assert!(sidecar.last_used() > sidecar.updated_at);
+        assert!(serde_json::to_string(&sidecar)
+            .unwrap()
+            .contains("last_used_at"));
+    }
+
+    #[test]
+    fn sidecar_record_lockfile_dedupes() {
+        let mut sidecar = CacheSidecar::new(
+            "mino-cache-npm-abc123".to_string(),
+            Ecosystem::Npm,
+            "abc123".to_string(),
+            CacheState::Complete,
+        );
+
+        assert!(sidecar.record_lockfile(Path::new("/a/package-lock.json")));
+        assert!(!sidecar.record_lockfile(Path::new("/a/package-lock.json")));
+        assert!(sidecar.record_lockfile(Path::new("/b/package-lock.json")));
+        assert_eq!(sidecar.lockfiles.len(), 2);
+    }
+
+    #[test]
+    fn sidecar_orphaned_when_all_lockfiles_gone() {
+        let temp = TempDir::new().unwrap();
+        let live = temp.path().join("Cargo.lock");
+        std::fs::write(&live, "").unwrap();
+        let gone = temp.path().join("deleted").join("Cargo.lock");
+
+        let mut sidecar = CacheSidecar::new(
+            "mino-cache-cargo-def456".to_string(),
+            Ecosystem::Cargo,
+            "def456".to_string(),
+            CacheState::Complete,
+        );
+        // No recorded origin: unknown, not orphaned
+        assert!(!sidecar.is_orphaned());
+
+        sidecar.record_lockfile(&gone);
+        assert!(sidecar.is_orphaned());
+
+        sidecar.record_lockfile(&live);
+        assert!(!sidecar.is_orphaned());
+    }
+
+    #[test]
+    fn sidecar_without_lockfiles_field_deserializes() {
+        let json = r#"{
+            "volume_name": "mino-cache-npm-old",
+            "ecosystem": "npm",
+            "hash": "old",
+            "state": "complete",
+            "created_at": "2026-01-01T00:00:00Z",
+            "updated_at": "2026-01-01T00:00:00Z"
+        }"#;
+        let sidecar: CacheSidecar = serde_json::from_str(json).unwrap();
+        assert!(sidecar.lockfiles.is_empty());
+    }
+
+    #[test]
+    fn seed_for_picks_most_recent_complete_cache_of_same_lockfile() {
+        let lockfile = Path::new("/proj/Cargo.lock");
+        let sidecar = |name: &str, ecosystem, state, path: &str, days_ago| {
+            let mut s = CacheSidecar::new(name.to_string(), ecosystem, name.to_string(), state);
+            s.record_lockfile(Path::new(path));
+            s.last_used_at = Some(Utc::now() - chrono::Duration::days(days_ago));
+            s
+        };
+        let sidecars = vec![
+            sidecar(
+                "old",
+                Ecosystem::Cargo,
+                CacheState::Complete,
+                "/proj/Cargo.lock",
+                9,
+            ),
+            sidecar(
+                "recent",
+                Ecosystem::Cargo,
+                CacheState::Complete,
+                "/proj/Cargo.lock",
+                1,
+            ),
+            sidecar(
+                "building",
+                Ecosystem::Cargo,
+                CacheState::Building,
+                "/proj/Cargo.lock",
+                0,
+            ),
+            sidecar(
+                "other",
+                Ecosystem::Cargo,
+                CacheState::Complete,
+                "/other/Cargo.lock",
+                0,
+            ),
+            sidecar(
+                "npm",
+                Ecosystem::Npm,
+                CacheState::Complete,
+                "/proj/Cargo.lock",
+                0,
+            ),
+        ];
+
+        let seed = CacheSidecar::seed_for(&sidecars, "new", Ecosystem::Cargo, &[lockfile]);
+        assert_eq!(seed.unwrap().volume_name, "recent");
+
+        // Never seeds a cache from itself
+        let seed = CacheSidecar::seed_for(&sidecars[1..2], "recent", Ecosystem::Cargo, &[lockfile]);
+        assert!(seed.is_none());
+    }
+
     #[tokio::test]
     async fn sidecar_updated_at_changes_on_save() {
         let temp = TempDir::new().unwrap();
@@ -0,0 +1,285 @@
+//! Cache hit/miss statistics
+//!
+//! `mino run` appends one JSON line per mounted cache to
+//! `<state_dir>/mino/cache-stats.log`, recording whether the cache was
+//! reused and how many bytes of it. `mino cache stats` summarizes the log
+//! per ecosystem, per project, and per week.
+
+use crate::cache::lockfile::Ecosystem;
+use crate::config::ConfigManager;
+use crate::error::{MinoError, MinoResult};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use tokio::fs::OpenOptions;
+use tokio::io::AsyncWriteExt;
+use tracing::warn;
+
+/// How a session got its cache
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CacheOutcome {
+    /// A complete cache was reused
+    Hit,
+    /// The cache was filled from `[cache.remote]`
+    Remote,
+    /// An incomplete cache from an earlier session was resumed
+    Resume,
+    /// A new cache was seeded from the previous cache of the lockfile
+    Seeded,
+    /// A new, empty cache was created
+    Miss,
+}
+
+impl CacheOutcome {
+    /// Whether the session started with the complete cache
+    pub fn is_hit(self) -> bool {
+        matches!(self, Self::Hit | Self::Remote)
+    }
+
+    /// Whether the session started with part of the cache
+    pub fn is_partial(self) -> bool {
+        matches!(self, Self::Resume | Self::Seeded)
+    }
+}
+
+/// One cache mounted by one session
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CacheUseRecord {
+    /// When the session set up the cache
+    pub at: DateTime<Utc>,
+    /// Project directory the lockfile belongs to
+    pub project_dir: PathBuf,
+    pub ecosystem: Ecosystem,
+    /// Cache volume name
+    pub volume: String,
+    pub outcome: CacheOutcome,
+    /// Cache contents present at session start (unknown if sizes could not
+    /// be measured)
+    pub bytes_reused: Option<u64>,
+}
+
+/// Aggregated outcomes of a group of cache uses
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct CacheStatsSummary {
+    pub uses: u64,
+    /// Complete caches reused, locally or from the remote store
+    pub hits: u64,
+    /// Resumed or seeded caches
+    pub partial: u64,
+    pub misses: u64,
+    pub bytes_reused: u64,
+}
+
+impl CacheStatsSummary {
+    fn add(&mut self, record: &CacheUseRecord) {
+        self.uses += 1;
+        if record.outcome.is_hit() {
+            self.hits += 1;
+        } else if record.outcome.is_partial() {
+            self.partial += 1;
+        } else {
+            self.misses += 1;
+        }
+        self.bytes_reused += record.bytes_reused.unwrap_or(0);
+    }
+
+    /// Share of uses that found the complete cache, in percent
+    pub fn hit_rate(&self) -> f64 {
+        if self.uses == 0 {
+            return 0.0;
+        }
+        self.hits as f64 / self.uses as f64 * 100.0
+    }
+}
+
+/// Summarize all records
+pub fn summarize(records: &[CacheUseRecord]) -> CacheStatsSummary {
+    let mut summary = CacheStatsSummary::default();
+    for record in records {
+        summary.add(record);
+    }
+    summary
+}
+
+/// Summarize records grouped by `key`, in key order
+pub fn summarize_by<K: Ord>(
+    records: &[CacheUseRecord],
+    key: impl Fn(&CacheUseRecord) -> K,
+) -> BTreeMap<K, CacheStatsSummary> {
+    let mut groups: BTreeMap<K, CacheStatsSummary> = BTreeMap::new();
+    for record in records {
+        groups.entry(key(record)).or_default().add(record);
+    }
+    groups
+}
+
+/// Monday of the (UTC) week `at` falls in
+pub fn week_start(at: DateTime<Utc>) -> NaiveDate {
+    let date = at.date_naive();
+    date - Duration::days(i64::from(date.weekday().num_days_from_monday()))
+}
+
+/// Append-only file of [`CacheUseRecord`]s, one JSON object per line
+pub struct CacheStatsLog {
+    path: PathBuf,
+}
+
+impl Default for CacheStatsLog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CacheStatsLog {
+    pub fn new() -> Self {
+        Self {
+            path: ConfigManager::cache_stats_path(),
+        }
+    }
+
+    /// Record the caches of a starting session
+    ///
+    /// Failures are logged and dropped — statistics must never block a
+    /// session.
+    pub async fn record(&self, records: &[CacheUseRecord]) {
+        if records.is_empty() {
+            return;
+        }
+        if let Err(e) = self.append(records).await {
+            warn!("Failed to write cache stats: {}", e);
+        }
+    }
+
+    async fn append(&self, records: &[CacheUseRecord]) -> MinoResult<()> {
+        let mut lines = String::new();
+        for record in records {
+            lines.push_str(&serde_json::to_string(record)?);
+            lines.push('\n');
+        }
+
+        if let Some(parent) = self.path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(|e| MinoError::io("creating state directory", e))?;
+        }
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .await
+            .map_err(|e| MinoError::io(format!("opening {}", self.path.display()), e))?;
+        file.write_all(lines.as_bytes())
+            .await
+            .map_err(|e| MinoError::io(format!("writing {}", self.path.display()), e))
+    }
+
+    /// Records on or after `since`, oldest first. Lines that do not parse
+    /// (e.g. a torn final write) are skipped.
+    pub async fn read_since(
+        &self,
+        since: Option<DateTime<Utc>>,
+    ) -> MinoResult<Vec<CacheUseRecord>> {
+        let content = match tokio::fs::read_to_string(&self.path).await {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+            Err(e) => return Err(MinoError::io(format!("reading {}", self.path.display()), e)),
+        };
+
+        let mut records: Vec<CacheUseRecord> = content
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .filter_map(|line| match serde_json::from_str(line) {
+                Ok(record) => Some(record),
+                Err(e) => {
+                    warn!("Skipping malformed cache stats line: {}", e);
+                    None
+                }
+            })
+            .filter(|r: &CacheUseRecord| since.is_none_or(|s| r.at >= s))
+            .collect();
+        records.sort_by_key(|r| r.at);
+        Ok(records)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use tempfile::TempDir;
+
+    fn record(
+        project: &str,
+        ecosystem: Ecosystem,
+        outcome: CacheOutcome,
+        bytes: u64,
+    ) -> CacheUseRecord {
+        CacheUseRecord {
+            at: Utc::now(),
+            project_dir: PathBuf::from(project),
+            ecosystem,
+            volume: format!("mino-cache-{}-abc123", ecosystem),
+            outcome,
+            bytes_reused: Some(bytes),
+        }
+    }
+
+    #[tokio::test]
+    async fn record_and_read_back() {
+        let dir = TempDir::new().unwrap();
+        let log = CacheStatsLog {
+            path: dir.path().join("cache-stats.log"),
+        };
+        let mut old = record("/p", Ecosystem::Npm, CacheOutcome::Miss, 0);
+        old.at = Utc.with_ymd_and_hms(2026, 3, 1, 12, 0, 0).unwrap();
+        let mut new = record("/p", Ecosystem::Cargo, CacheOutcome::Hit, 2048);
+        new.at = Utc.with_ymd_and_hms(2026, 3, 11, 12, 0, 0).unwrap();
+        // `append` rather than `record`, so a failed write fails the test
+        // with its cause instead of surfacing as missing records
+        log.append(&[old.clone(), new.clone()]).await.unwrap();
+
+        assert_eq!(log.read_since(None).await.unwrap(), vec![old, new.clone()]);
+        let recent = log
+            .read_since(Some(new.at - Duration::days(1)))
+            .await
+            .unwrap();
+        assert_eq!(recent, vec![new]);
+    }
+
+    #[test]
+    fn summary_counts_outcomes_and_bytes() {
+        let records = [
+            record("/a", Ecosystem::Npm, CacheOutcome::Hit, 100),
+            record("/a", Ecosystem::Npm, CacheOutcome::Remote, 50),
+            record("/a", Ecosystem::Npm, CacheOutcome::Seeded, 10),
+            record("/b", Ecosystem::Npm, CacheOutcome::Miss, 0),
+            record("/b", Ecosystem::Cargo, CacheOutcome::Resume, 5),
+        ];
+
+        let by_ecosystem = summarize_by(&records, |r| r.ecosystem.to_string());
+        let npm = &by_ecosystem["npm"];
+        assert_eq!((npm.uses, npm.hits, npm.partial, npm.misses), (4, 2, 1, 1));
+        assert_eq!(npm.bytes_reused, 160);
+        assert_eq!(npm.hit_rate(), 50.0);
+
+        let by_project = summarize_by(&records, |r| r.project_dir.clone());
+        assert_eq!(by_project[&PathBuf::from("/b")].hit_rate(), 0.0);
+    }
+
+    #[test]
+    fn weeks_start_on_monday() {
+        let sunday = Utc.with_ymd_and_hms(2026, 3, 15, 23, 0, 0).unwrap();
+        assert_eq!(
+            week_start(sunday),
+            NaiveDate::from_ymd_opt(2026, 3, 9).unwrap()
+        );
+        let monday = Utc.with_ymd_and_hms(2026, 3, 16, 0, 0, 0).unwrap();
+        assert_eq!(
+            week_start(monday),
+            NaiveDate::from_ymd_opt(2026, 3, 16).unwrap()
+        );
+    }
+}
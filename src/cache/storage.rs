@@ -0,0 +1,139 @@
+//! Persistent container storage for in-sandbox image builds
+//!
+//! With `[cache] container_storage = true`, sessions mount a named volume
+//! over the podman/buildah storage directory, so images pulled or built
+//! inside the sandbox keep their layers across sessions. Each project gets
+//! its own volume, whatever `[cache] scope` says: a store shared across
+//! projects would let one project plant images another one runs. The
+//! volume carries the cache label: it counts toward `max_total_gb` and is
+//! removed by `mino cache clear --volumes`, like the dependency caches.
+
+use crate::cache::volume::labels;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Volume name prefix shared by all container storage volumes
+pub const STORAGE_PREFIX: &str = "mino-container-storage";
+
+/// Information about a container storage volume
+#[derive(Debug, Clone)]
+pub struct ContainerStorageVolume {
+    /// Volume name (mino-container-storage-{project hash})
+    pub name: String,
+    /// Project the volume is private to; `None` for the shared volume of
+    /// earlier versions
+    pub project: Option<String>,
+    /// Container user the volume was chowned to when it was created
+    pub owner: Option<String>,
+    /// When the volume was created
+    pub created_at: DateTime<Utc>,
+}
+
+impl ContainerStorageVolume {
+    /// Try to parse a ContainerStorageVolume from volume labels.
+    pub fn from_labels(name: &str, volume_labels: &HashMap<String, String>) -> Option<Self> {
+        if volume_labels.get(labels::CONTAINER_STORAGE) != Some(&"true".to_string()) {
+            return None;
+        }
+
+        let created_at = volume_labels
+            .get(labels::CREATED_AT)
+            .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+            .map(|dt| dt.with_timezone(&Utc))
+            .unwrap_or_else(Utc::now);
+
+        Some(Self {
+            name: name.to_string(),
+            project: volume_labels.get(crate::labels::PROJECT).cloned(),
+            owner: volume_labels.get(labels::STORAGE_OWNER).cloned(),
+            created_at,
+        })
+    }
+
+    /// Generate labels for volume creation. `owner` is the container user
+    /// the new volume is chowned to on its first mount.
+    pub fn labels(project: &Path, owner: &str) -> HashMap<String, String> {
+        let mut map = crate::labels::resource_labels(None, Some(project));
+        map.insert(labels::MINO_CACHE.to_string(), "true".to_string());
+        map.insert(labels::CONTAINER_STORAGE.to_string(), "true".to_string());
+        map.insert(labels::STORAGE_OWNER.to_string(), owner.to_string());
+        map.insert(labels::CREATED_AT.to_string(), Utc::now().to_rfc3339());
+        map
+    }
+}
+
+/// Compute the container storage volume name of a project
+pub fn container_storage_volume_name(project: &Path) -> String {
+    format!(
+        "{}-{}",
+        STORAGE_PREFIX,
+        crate::home::hash_project_path(project)
+    )
+}
+
+/// Where podman and buildah keep images for the container user: rootful
+/// storage for root, `~/.local/share/containers` otherwise.
+pub fn container_storage_path(home: &str, is_root: bool) -> String {
+    if is_root {
+        "/var/lib/containers".to_string()
+    } else {
+        format!("{}/.local/share/containers", home.trim_end_matches('/'))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn volume_name_per_project() {
+        let a = container_storage_volume_name(Path::new("/src/a"));
+        let b = container_storage_volume_name(Path::new("/src/b"));
+        assert!(a.starts_with("mino-container-storage-"));
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn from_labels_roundtrip() {
+        let volume_labels = ContainerStorageVolume::labels(Path::new("/src/a"), "developer");
+        assert_eq!(
+            volume_labels.get(labels::MINO_CACHE),
+            Some(&"true".to_string())
+        );
+        let name = container_storage_volume_name(Path::new("/src/a"));
+        let vol = ContainerStorageVolume::from_labels(&name, &volume_labels).unwrap();
+        assert_eq!(vol.name, name);
+        assert!(vol.project.is_some());
+        assert_eq!(vol.owner.as_deref(), Some("developer"));
+    }
+
+    #[test]
+    fn from_labels_reads_shared_volumes_of_earlier_versions() {
+        let volume_labels =
+            HashMap::from([(labels::CONTAINER_STORAGE.to_string(), "true".to_string())]);
+        let vol =
+            ContainerStorageVolume::from_labels("mino-container-storage", &volume_labels).unwrap();
+        assert!(vol.project.is_none());
+        assert!(vol.owner.is_none());
+    }
+
+    #[test]
+    fn from_labels_rejects_dependency_caches() {
+        let vol = crate::cache::CacheVolume::new(
+            crate::cache::Ecosystem::Npm,
+            "abc123".to_string(),
+            crate::cache::CacheState::Complete,
+        );
+        assert!(ContainerStorageVolume::from_labels(&vol.name, &vol.labels()).is_none());
+    }
+
+    #[test]
+    fn storage_path_for_user() {
+        assert_eq!(
+            container_storage_path("/home/developer", false),
+            "/home/developer/.local/share/containers"
+        );
+        assert_eq!(container_storage_path("/root", true), "/var/lib/containers");
+    }
+}
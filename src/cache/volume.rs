@@ -79,6 +79,10 @@ pub mod labels {
     pub const STATE: &str = "io.mino.cache.state";
     /// Creation timestamp (RFC3339)
     pub const CREATED_AT: &str = "io.mino.cache.created_at";
+    /// Marks a cache volume as in-sandbox container storage
+    pub const CONTAINER_STORAGE: &str = "io.mino.cache.container_storage";
+    /// Container user a container storage volume was chowned to
+    pub const STORAGE_OWNER: &str = "io.mino.cache.container_storage.owner";
 }
 
 /// State of a cache volume
@@ -138,6 +142,9 @@ pub struct CacheVolume {
     pub created_at: DateTime<Utc>,
     /// Size in bytes (if known)
     pub size_bytes: Option<u64>,
+    /// Project the cache is private to (`scope = "project"`); `None` for
+    /// shared caches
+    pub project: Option<String>,
 }
 
 impl CacheVolume {
@@ -150,17 +157,24 @@ impl CacheVolume {
             state,
             created_at: Utc::now(),
             size_bytes: None,
+            project: None,
         }
     }
 
     /// Create from lockfile info (for a new cache)
     pub fn from_lockfile(info: &LockfileInfo, state: CacheState) -> Self {
-        Self::new(info.ecosystem, info.hash.clone(), state)
+        Self {
+            name: info.volume_name(),
+            project: info.project.as_ref().map(|p| p.display().to_string()),
+            ..Self::new(info.ecosystem, info.hash.clone(), state)
+        }
     }
 
-    /// Generate labels for volume creation
+    /// Generate labels for volume creation. Project-scoped caches carry the
+    /// project label; shared caches do not.
     pub fn labels(&self) -> HashMap<String, String> {
-        let mut labels = HashMap::new();
+        let mut labels =
+            crate::labels::resource_labels(None, self.project.as_deref().map(std::path::Path::new));
         labels.insert(labels::MINO_CACHE.to_string(), "true".to_string());
         labels.insert(labels::ECOSYSTEM.to_string(), self.ecosystem.to_string());
         labels.insert(labels::HASH.to_string(), self.hash.clone());
@@ -170,7 +184,7 @@ impl CacheVolume {
     }
 
     /// Parse ecosystem from string
-    fn parse_ecosystem(s: &str) -> Option<Ecosystem> {
+    pub fn parse_ecosystem(s: &str) -> Option<Ecosystem> {
         match s {
             "npm" => Some(Ecosystem::Npm),
             "yarn" => Some(Ecosystem::Yarn),
@@ -180,6 +194,13 @@ impl CacheVolume {
             "poetry" => Some(Ecosystem::Poetry),
             "uv" => Some(Ecosystem::Uv),
             "go" => Some(Ecosystem::Go),
+            "gradle" => Some(Ecosystem::Gradle),
+            "maven" => Some(Ecosystem::Maven),
+            "composer" => Some(Ecosystem::Composer),
+            "nuget" => Some(Ecosystem::Nuget),
+            "bundler" => Some(Ecosystem::Bundler),
+            "bun" => Some(Ecosystem::Bun),
+            "mix" => Some(Ecosystem::Mix),
             _ => None,
         }
     }
@@ -215,6 +236,7 @@ impl CacheVolume {
             state,
             created_at,
             size_bytes: None,
+            project: labels.get(crate::labels::PROJECT).cloned(),
         })
     }
 
@@ -234,12 +256,18 @@ pub struct CacheMount {
     pub container_path: String,
     /// Ecosystem for setting env vars
     pub ecosystem: Ecosystem,
+    /// Mount read-only (complete caches are immutable)
+    pub read_only: bool,
 }
 
 impl CacheMount {
     /// Generate the volume mount string for podman
     pub fn volume_arg(&self) -> String {
-        format!("{}:{}", self.volume_name, self.container_path)
+        if self.read_only {
+            format!("{}:{}:ro", self.volume_name, self.container_path)
+        } else {
+            format!("{}:{}", self.volume_name, self.container_path)
+        }
     }
 }
 
@@ -251,6 +279,7 @@ pub fn plan_cache_mounts(lockfiles: &[LockfileInfo]) -> Vec<CacheMount> {
             volume_name: info.volume_name(),
             container_path: "/cache".to_string(),
             ecosystem: info.ecosystem,
+            read_only: false,
         })
         .collect()
 }
@@ -299,7 +328,9 @@ mod tests {
         let info = LockfileInfo {
             ecosystem: Ecosystem::Cargo,
             path: PathBuf::from("/test/Cargo.lock"),
+            nested: Vec::new(),
             hash: "a1b2c3d4e5f6".to_string(),
+            project: None,
         };
 
         let vol = CacheVolume::from_lockfile(&info, CacheState::Complete);
@@ -308,6 +339,33 @@ mod tests {
         assert_eq!(vol.ecosystem, Ecosystem::Cargo);
     }
 
+    #[test]
+    fn project_scoped_cache_volume_roundtrips_project_label() {
+        let info = LockfileInfo {
+            ecosystem: Ecosystem::Cargo,
+            path: PathBuf::from("/nonexistent/app/Cargo.lock"),
+            nested: Vec::new(),
+            hash: "a1b2c3d4e5f6".to_string(),
+            project: Some(PathBuf::from("/nonexistent/app")),
+        };
+
+        let vol = CacheVolume::from_lockfile(&info, CacheState::Building);
+        assert_eq!(vol.name, info.volume_name());
+        let labels = vol.labels();
+        assert_eq!(
+            labels.get(crate::labels::PROJECT).map(String::as_str),
+            Some("/nonexistent/app")
+        );
+
+        let parsed = CacheVolume::from_labels(&vol.name, &labels).unwrap();
+        assert_eq!(parsed.project.as_deref(), Some("/nonexistent/app"));
+        assert!(
+            !CacheVolume::new(Ecosystem::Cargo, "x".to_string(), CacheState::Building)
+                .labels()
+                .contains_key(crate::labels::PROJECT)
+        );
+    }
+
     #[test]
     fn cache_volume_labels() {
         let vol = CacheVolume::new(Ecosystem::Npm, "abc123".to_string(), CacheState::Building);
@@ -344,17 +402,32 @@ mod tests {
             volume_name: "mino-cache-npm-abc123".to_string(),
             container_path: "/cache".to_string(),
             ecosystem: Ecosystem::Npm,
+            read_only: false,
         };
 
         assert_eq!(mount.volume_arg(), "mino-cache-npm-abc123:/cache");
     }
 
+    #[test]
+    fn cache_mount_volume_arg_read_only() {
+        let mount = CacheMount {
+            volume_name: "mino-cache-npm-abc123".to_string(),
+            container_path: "/cache".to_string(),
+            ecosystem: Ecosystem::Npm,
+            read_only: true,
+        };
+
+        assert_eq!(mount.volume_arg(), "mino-cache-npm-abc123:/cache:ro");
+    }
+
     #[test]
     fn plan_cache_mounts_creates_mounts() {
         let lockfiles = vec![LockfileInfo {
             ecosystem: Ecosystem::Npm,
             path: PathBuf::from("/test/package-lock.json"),
+            nested: Vec::new(),
             hash: "abc123def456".to_string(),
+            project: None,
         }];
 
         let mounts = plan_cache_mounts(&lockfiles);
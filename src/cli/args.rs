@@ -1,8 +1,10 @@
 //! CLI argument definitions using clap derive
 
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
 use clap::{ArgAction, Parser, Subcommand, ValueEnum};
 use clap_complete::Shell;
 use std::path::PathBuf;
+use std::time::Duration;
 
 /// Mino - Secure AI Agent Sandbox
 ///
@@ -32,6 +34,14 @@ pub struct Cli {
     /// Trust project-local .mino.toml without prompting
     #[arg(long, global = true, env = "MINO_TRUST_LOCAL")]
     pub trust_local: bool,
+
+    /// How to report progress: spinners on the terminal, or JSON lines on stderr
+    #[arg(long, global = true, value_enum, default_value_t = ProgressMode::Auto, env = "MINO_PROGRESS")]
+    pub progress: ProgressMode,
+
+    /// Print long output directly instead of through $PAGER
+    #[arg(long, global = true, env = "MINO_NO_PAGER")]
+    pub no_pager: bool,
 }
 
 /// Available commands
@@ -44,6 +54,15 @@ pub enum Commands {
     /// Execute a command in a running session
     Exec(ExecArgs),
 
+    /// Copy files between the host and a session
+    Cp(CpArgs),
+
+    /// Exchange clipboard text with a session (requires session.clipboard)
+    Clip(ClipArgs),
+
+    /// Open a shell in a session, starting one for the current project if needed
+    Shell(ShellArgs),
+
     /// Initialize a project-local .mino.toml config
     Init(InitArgs),
 
@@ -53,21 +72,57 @@ pub enum Commands {
     /// Stop a running session
     Stop(StopArgs),
 
+    /// Send a signal to a session's main process
+    Kill(KillArgs),
+
+    /// Freeze a running session without losing its in-memory state
+    Pause(PauseArgs),
+
+    /// Resume a paused session
+    Resume(ResumeArgs),
+
+    /// Save a running session's state to disk (Podman with CRIU)
+    Checkpoint(CheckpointArgs),
+
+    /// Restore a session from its latest checkpoint
+    Restore(RestoreArgs),
+
     /// View session logs
     Logs(LogsArgs),
 
+    /// Log in to a container registry to pull private images
+    Login(LoginArgs),
+
     /// Check system health and dependencies
-    Status,
+    Status(StatusArgs),
+
+    /// Measure sandbox startup latency per phase
+    Bench(BenchArgs),
 
     /// Interactive setup wizard - install prerequisites
     Setup(SetupArgs),
 
+    /// Install layer toolchains directly on a machine, without a container
+    SetupEnv(SetupEnvArgs),
+
     /// Show or edit configuration
     Config(ConfigArgs),
 
     /// Manage dependency caches
     Cache(CacheArgs),
 
+    /// Inspect, test, and clear provider credentials
+    Creds(CredsArgs),
+
+    /// Inspect composed layer images
+    Images(ImagesArgs),
+
+    /// List, inspect, and scaffold layers
+    Layer(LayerArgs),
+
+    /// Inspect recorded session data
+    Session(SessionArgs),
+
     /// Generate shell completions
     Completions(CompletionsArgs),
 }
@@ -83,6 +138,55 @@ pub struct ExecArgs {
     pub command: Vec<String>,
 }
 
+/// Arguments for the cp command
+#[derive(Parser, Debug)]
+pub struct CpArgs {
+    /// Source: a host path or <session>:<path>
+    pub source: String,
+
+    /// Destination: a host path or <session>:<path>
+    pub destination: String,
+}
+
+/// Arguments for the clip command
+#[derive(Parser, Debug)]
+pub struct ClipArgs {
+    /// Subcommand for clip
+    #[command(subcommand)]
+    pub action: ClipAction,
+}
+
+/// Clip subcommands
+#[derive(Subcommand, Debug)]
+pub enum ClipAction {
+    /// Copy the session's clipboard (/tmp/mino-clipboard) to the host clipboard
+    Get {
+        /// Session name
+        session: String,
+
+        /// Print the text to stdout instead of setting the host clipboard
+        #[arg(long)]
+        print: bool,
+    },
+
+    /// Put the host clipboard into the session at /tmp/mino-clipboard
+    Put {
+        /// Session name
+        session: String,
+
+        /// Read the text from stdin instead of the host clipboard
+        #[arg(long)]
+        stdin: bool,
+    },
+}
+
+/// Arguments for the shell command
+#[derive(Parser, Debug)]
+pub struct ShellArgs {
+    /// Session name (defaults to the running session for the current project)
+    pub session: Option<String>,
+}
+
 /// Arguments for the setup command
 #[derive(Parser, Debug)]
 pub struct SetupArgs {
@@ -107,6 +211,22 @@ pub struct SetupArgs {
     pub uninstall: bool,
 }
 
+/// Arguments for the setup-env command
+#[derive(Parser, Debug)]
+pub struct SetupEnvArgs {
+    /// Layers to install, comma-separated (default: MINO_LAYERS, then container.layers)
+    #[arg(long, value_delimiter = ',')]
+    pub layers: Vec<String>,
+
+    /// Run the provisioning script on this machine instead of printing it
+    #[arg(long)]
+    pub inside: bool,
+
+    /// Skip the confirmation prompt for --inside
+    #[arg(short, long, requires = "inside")]
+    pub yes: bool,
+}
+
 /// Arguments for the init command
 #[derive(Parser, Debug)]
 pub struct InitArgs {
@@ -126,9 +246,10 @@ pub struct RunArgs {
     #[arg(short, long)]
     pub name: Option<String>,
 
-    /// Project directory to mount (defaults to current directory)
+    /// Project directory to mount (defaults to current directory).
+    /// Repeat to mount additional projects under the workdir.
     #[arg(short, long)]
-    pub project: Option<PathBuf>,
+    pub project: Vec<PathBuf>,
 
     /// Include AWS credentials
     #[arg(long)]
@@ -154,10 +275,31 @@ pub struct RunArgs {
     #[arg(long = "no-github")]
     pub no_github: bool,
 
+    /// Include a GitLab token
+    #[arg(long)]
+    pub gitlab: bool,
+
+    /// Include a Bitbucket token
+    #[arg(long)]
+    pub bitbucket: bool,
+
+    /// Include a kubeconfig with a short-lived ServiceAccount token
+    #[arg(long)]
+    pub kube: bool,
+
+    /// Serve a per-session GitHub deploy key instead of your SSH agent
+    #[arg(long, conflicts_with = "no_ssh_agent")]
+    pub deploy_key: bool,
+
     /// Fail if any requested credentials cannot be loaded
     #[arg(long)]
     pub strict_credentials: bool,
 
+    /// Pass cloud credentials as files that mino re-issues before they
+    /// expire, for as long as it runs (keeps `--detach` in the foreground)
+    #[arg(long)]
+    pub refresh_credentials: bool,
+
     /// Container image to use
     #[arg(long)]
     pub image: Option<String>,
@@ -166,6 +308,15 @@ pub struct RunArgs {
     #[arg(long, value_delimiter = ',', conflicts_with = "image")]
     pub layers: Vec<String>,
 
+    /// Override a layer build argument (LAYER.ARG=VALUE, repeatable)
+    #[arg(long = "layer-arg", value_name = "LAYER.ARG=VALUE", value_parser = parse_layer_arg)]
+    pub layer_args: Vec<(String, String, String)>,
+
+    /// Compose layers that set the same env var, PATH dir, cache path, or
+    /// runtime, letting later layers win instead of failing
+    #[arg(long)]
+    pub allow_layer_conflicts: bool,
+
     /// Additional environment variables (KEY=VALUE)
     #[arg(short, long, value_parser = parse_env_var)]
     pub env: Vec<(String, String)>,
@@ -174,10 +325,32 @@ pub struct RunArgs {
     #[arg(long)]
     pub volume: Vec<String>,
 
+    /// Working directory inside the container (relative paths resolve
+    /// against the project mount; defaults to the current subdirectory)
+    #[arg(short, long)]
+    pub workdir: Option<String>,
+
     /// Run in detached mode
     #[arg(short, long)]
     pub detach: bool,
 
+    /// Restart a detached session's container when it exits non-zero: on-failure[:MAX]
+    #[arg(long, value_name = "POLICY", requires = "detach")]
+    pub restart: Option<String>,
+
+    /// Kill the command and its container after this long, e.g. 90s, 30m, 1h30m
+    #[arg(long, value_name = "DURATION", value_parser = parse_duration, conflicts_with = "detach")]
+    pub timeout: Option<Duration>,
+
+    /// Pipe host stdin into the command without a TTY, e.g. `echo plan | mino run --stdin -- claude -p`
+    #[arg(long, conflicts_with = "detach")]
+    pub stdin: bool,
+
+    /// Print a progress line this often in CI mode so log-silence watchdogs
+    /// don't kill the job (default: 5m, 0 disables)
+    #[arg(long, value_name = "DURATION", value_parser = parse_duration)]
+    pub heartbeat: Option<Duration>,
+
     /// Mount the container root filesystem as read-only
     #[arg(long)]
     pub read_only: bool,
@@ -190,10 +363,65 @@ pub struct RunArgs {
     #[arg(long)]
     pub no_home: bool,
 
-    /// Force fresh cache (ignore existing caches)
+    /// Force fresh cache (remove and recreate existing cache volumes)
     #[arg(long, conflicts_with = "no_cache")]
     pub cache_fresh: bool,
 
+    /// Mount complete caches read-write (recorded in the audit log)
+    #[arg(long, conflicts_with = "no_cache")]
+    pub cache_rw: bool,
+
+    /// Mount a persistent per-session scratch volume at /scratch
+    #[arg(long)]
+    pub scratch: bool,
+
+    /// Mount a new git worktree on branch mino/<session> instead of the
+    /// working tree; removed on stop unless it has uncommitted changes
+    #[arg(long)]
+    pub worktree: bool,
+
+    /// CPU limit in cores, e.g. 2 or 1.5 (overrides container.cpus)
+    #[arg(long, value_name = "N")]
+    pub cpus: Option<f64>,
+
+    /// Memory limit in MB, or with a unit: 512m, 4g (overrides container.memory_mb)
+    #[arg(long, value_name = "SIZE", value_parser = parse_memory_mb)]
+    pub memory: Option<u64>,
+
+    /// Max processes in the container, 0 for no limit (overrides container.pids_limit)
+    #[arg(long, value_name = "N")]
+    pub pids_limit: Option<u32>,
+
+    /// Set a resource limit, e.g. nofile=65535:65535 (repeatable; overrides
+    /// container.ulimits entries for the same resource)
+    #[arg(long, value_name = "NAME=SOFT[:HARD]")]
+    pub ulimit: Vec<String>,
+
+    /// Give the session the host's NVIDIA GPUs (CDI on Podman, --gpus on Docker)
+    #[arg(long)]
+    pub gpus: bool,
+
+    /// Pass a host device through, e.g. /dev/ttyUSB0 or /dev/sdc:/dev/xvdc:r (repeatable)
+    #[arg(long, value_name = "DEVICE")]
+    pub device: Vec<String>,
+
+    /// Mount a tmpfs in the container, e.g. /tmp:size=1g (repeatable)
+    #[arg(long, value_name = "PATH[:OPTS]")]
+    pub tmpfs: Vec<String>,
+
+    /// User namespace mode, e.g. keep-id to map your uid/gid into the container (Podman)
+    #[arg(long, value_name = "MODE")]
+    pub userns: Option<String>,
+
+    /// Image platform, e.g. linux/amd64 to match CI from an arm64 Mac
+    /// (overrides container.platform; cross-arch runs use emulation)
+    #[arg(long, value_name = "OS/ARCH")]
+    pub platform: Option<String>,
+
+    /// Image pull policy: always, missing (default), never
+    #[arg(long, value_name = "POLICY", value_parser = clap::builder::PossibleValuesParser::new(["always", "missing", "never"]))]
+    pub pull: Option<String>,
+
     /// Network mode: bridge (default), host, none
     #[arg(long)]
     pub network: Option<String>,
@@ -207,10 +435,25 @@ pub struct RunArgs {
     #[arg(long, value_parser = clap::builder::PossibleValuesParser::new(["dev", "registries"]), conflicts_with = "network_allow")]
     pub network_preset: Option<String>,
 
-    /// Runtime mode: container (default), native
+    /// Runtime mode: container (default), native; or a container backend:
+    /// podman, docker, remote, k8s (overrides orchestration.backend)
     #[arg(long)]
     pub runtime: Option<String>,
 
+    /// Run without a container in the native sandbox (shorthand for
+    /// --runtime native)
+    #[arg(long, conflicts_with = "runtime")]
+    pub native: bool,
+
+    /// Fall back to bubblewrap or sandbox-exec when the full native sandbox
+    /// isn't set up, instead of failing
+    #[arg(long)]
+    pub allow_degraded: bool,
+
+    /// kubeconfig context for the k8s backend (overrides orchestration.kubernetes.context)
+    #[arg(long, value_name = "CONTEXT")]
+    pub context: Option<String>,
+
     /// Command and arguments to run (defaults to shell)
     #[arg(last = true)]
     pub command: Vec<String>,
@@ -231,6 +474,10 @@ pub struct ListArgs {
     #[arg(short, long)]
     pub all: bool,
 
+    /// Show at most N sessions, newest first (0 = no limit; table default: 20)
+    #[arg(short = 'n', long, value_name = "N")]
+    pub limit: Option<usize>,
+
     /// Output format
     #[arg(short, long, default_value = "table")]
     pub format: OutputFormat,
@@ -245,13 +492,77 @@ pub struct StopArgs {
     /// Force stop without cleanup
     #[arg(short, long)]
     pub force: bool,
+
+    /// Also remove the session's scratch volume (kept by default)
+    #[arg(long)]
+    pub remove_scratch: bool,
+}
+
+/// Arguments for the kill command
+#[derive(Parser, Debug)]
+pub struct KillArgs {
+    /// Session name
+    pub session: String,
+
+    /// Signal to send, by name (SIGUSR1, usr1) or number
+    #[arg(short, long, default_value = "SIGKILL")]
+    pub signal: String,
+}
+
+/// Arguments for the pause command
+#[derive(Parser, Debug)]
+pub struct PauseArgs {
+    /// Session name
+    pub session: String,
+}
+
+/// Arguments for the resume command
+#[derive(Parser, Debug)]
+pub struct ResumeArgs {
+    /// Session name
+    pub session: String,
+}
+
+/// Arguments for the checkpoint command
+#[derive(Parser, Debug)]
+pub struct CheckpointArgs {
+    /// Session name
+    pub session: String,
+
+    /// Keep the session running after the checkpoint is written
+    #[arg(long)]
+    pub leave_running: bool,
+}
+
+/// Arguments for the restore command
+#[derive(Parser, Debug)]
+pub struct RestoreArgs {
+    /// Session name
+    pub session: String,
+}
+
+/// Arguments for the status command
+#[derive(Parser, Debug)]
+pub struct StatusArgs {
+    /// Print the report as JSON; exits non-zero if a critical check fails
+    #[arg(long)]
+    pub json: bool,
 }
 
 /// Arguments for the logs command
 #[derive(Parser, Debug)]
 pub struct LogsArgs {
     /// Session name or ID
-    pub session: String,
+    #[arg(required_unless_present = "all", conflicts_with = "all")]
+    pub session: Option<String>,
+
+    /// Follow logs from every running session, prefixed by session name
+    #[arg(short, long)]
+    pub all: bool,
+
+    /// Show logs of a `[services]` container instead of the main one
+    #[arg(short, long, conflicts_with = "all")]
+    pub service: Option<String>,
 
     /// Follow log output
     #[arg(short, long)]
@@ -262,6 +573,49 @@ pub struct LogsArgs {
     pub lines: u32,
 }
 
+/// Arguments for the login command
+#[derive(Parser, Debug)]
+pub struct LoginArgs {
+    /// Registry host (e.g. ghcr.io)
+    #[arg(required_unless_present_any = ["list", "ecr", "gar"])]
+    pub registry: Option<String>,
+
+    /// Registry username (prompted for when omitted)
+    #[arg(short, long, conflicts_with = "logout")]
+    pub username: Option<String>,
+
+    /// Read the password or token from stdin
+    #[arg(long, conflicts_with = "logout")]
+    pub password_stdin: bool,
+
+    /// Remove the stored credentials instead
+    #[arg(long)]
+    pub logout: bool,
+
+    /// Log in to Amazon ECR in REGION with mino's AWS credentials
+    #[arg(
+        long,
+        value_name = "REGION",
+        conflicts_with_all = ["registry", "username", "password_stdin", "logout", "gar"]
+    )]
+    pub ecr: Option<String>,
+
+    /// Log in to GCP Artifact Registry in REGION with mino's GCP token
+    #[arg(
+        long,
+        value_name = "REGION",
+        conflicts_with_all = ["registry", "username", "password_stdin", "logout"]
+    )]
+    pub gar: Option<String>,
+
+    /// List registries logged in with mino
+    #[arg(
+        long,
+        conflicts_with_all = ["registry", "username", "password_stdin", "logout", "ecr", "gar"]
+    )]
+    pub list: bool,
+}
+
 /// Arguments for the config command
 #[derive(Parser, Debug)]
 pub struct ConfigArgs {
@@ -309,6 +663,63 @@ pub enum OutputFormat {
     Plain,
 }
 
+/// `mino cache clear` filters select cache volumes, so they only go with
+/// `--volumes`
+const CLEAR_FILTER_CONFLICTS: [&str; 4] = ["images", "home", "scratch", "all"];
+
+/// Cache state selected by `mino cache clear --state`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum CacheStateFilter {
+    /// Incomplete caches (session crashed or still running)
+    Building,
+    /// Finalized caches
+    Complete,
+}
+
+/// Order in which `mino cache gc --max-size` removes caches
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum GcStrategy {
+    /// Least recently used first
+    Lru,
+    /// Largest first
+    Largest,
+    /// Oldest first, by creation time
+    Oldest,
+}
+
+/// Progress reporting for long-running steps
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ProgressMode {
+    /// Spinners in a terminal, plain lines otherwise
+    Auto,
+    /// One JSON event per line on stderr
+    Json,
+}
+
+/// Arguments for the bench command
+#[derive(Parser, Debug)]
+pub struct BenchArgs {
+    /// Number of measured startups, after one unmeasured warm-up run
+    #[arg(short = 'n', long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    pub runs: u32,
+
+    /// Remove the session image before each run, so pulls (or layer builds) are measured
+    #[arg(long)]
+    pub cold: bool,
+
+    /// Container image to start (default: container.image)
+    #[arg(long)]
+    pub image: Option<String>,
+
+    /// Layers to compose, comma-separated
+    #[arg(long, value_delimiter = ',', conflicts_with = "image")]
+    pub layers: Vec<String>,
+
+    /// Output format
+    #[arg(short, long, default_value = "table")]
+    pub format: OutputFormat,
+}
+
 /// Arguments for the cache command
 #[derive(Parser, Debug)]
 pub struct CacheArgs {
@@ -325,6 +736,10 @@ pub enum CacheAction {
         /// Output format
         #[arg(short, long, default_value = "table")]
         format: OutputFormat,
+
+        /// Skip measuring volume sizes for an instant listing
+        #[arg(long)]
+        no_size: bool,
     },
 
     /// Show cache info for current project
@@ -334,6 +749,36 @@ pub enum CacheAction {
         project: Option<PathBuf>,
     },
 
+    /// Prefetch dependencies into the project's caches
+    Warm {
+        /// Project directory (defaults to current directory)
+        #[arg(short, long)]
+        project: Option<PathBuf>,
+
+        /// Container image to install with (default: layers of the detected ecosystems)
+        #[arg(long)]
+        image: Option<String>,
+
+        /// Layers to compose, comma-separated
+        #[arg(long, value_delimiter = ',', conflicts_with = "image")]
+        layers: Vec<String>,
+    },
+
+    /// Show cache hit rates per ecosystem, project, and week
+    Stats {
+        /// Only sessions started on or after this date (YYYY-MM-DD or RFC 3339, UTC)
+        #[arg(long, value_name = "DATE", value_parser = parse_date)]
+        since: Option<DateTime<Utc>>,
+
+        /// Only caches of this project directory
+        #[arg(short, long)]
+        project: Option<PathBuf>,
+
+        /// Print the summaries as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
     /// Remove orphaned and old caches
     Gc {
         /// Remove caches older than N days (default: from config)
@@ -343,31 +788,280 @@ pub enum CacheAction {
         /// Dry run - show what would be removed
         #[arg(long)]
         dry_run: bool,
+
+        /// Only remove caches whose recorded lockfiles no longer exist
+        #[arg(long, conflicts_with = "days")]
+        orphaned: bool,
+
+        /// Skip confirmation prompt (with --orphaned)
+        #[arg(short, long, requires = "orphaned")]
+        yes: bool,
+
+        /// Also remove complete caches until total usage is under this many GB
+        #[arg(long, value_name = "GB", conflicts_with = "orphaned")]
+        max_size: Option<u32>,
+
+        /// Which caches --max-size removes first
+        #[arg(long, default_value = "lru", requires = "max_size")]
+        strategy: GcStrategy,
     },
 
-    /// Clear caches
-    #[command(group(clap::ArgGroup::new("target").required(true).args(["volumes", "images", "home", "all"])))]
-    Clear {
-        /// Clear cache volumes
-        #[arg(long)]
-        volumes: bool,
+    /// Clear caches
+    #[command(group(clap::ArgGroup::new("target").required(true).args(["volumes", "images", "home", "scratch", "all"])))]
+    Clear {
+        /// Clear cache volumes
+        #[arg(long)]
+        volumes: bool,
+
+        /// Clear composed layer images
+        #[arg(long)]
+        images: bool,
+
+        /// Clear home volumes
+        #[arg(long)]
+        home: bool,
+
+        /// Clear session scratch volumes
+        #[arg(long)]
+        scratch: bool,
+
+        /// Clear all artifacts (volumes + images + home + scratch)
+        #[arg(long, conflicts_with_all = ["volumes", "images", "home", "scratch"])]
+        all: bool,
+
+        /// Only cache volumes of this ecosystem (e.g. npm, cargo)
+        #[arg(long, conflicts_with_all = CLEAR_FILTER_CONFLICTS)]
+        ecosystem: Option<String>,
+
+        /// Only cache volumes used by this project directory
+        #[arg(short, long, conflicts_with_all = CLEAR_FILTER_CONFLICTS)]
+        project: Option<PathBuf>,
+
+        /// Only cache volumes in this state
+        #[arg(long, conflicts_with_all = CLEAR_FILTER_CONFLICTS)]
+        state: Option<CacheStateFilter>,
+
+        /// Skip confirmation prompt
+        #[arg(short, long)]
+        yes: bool,
+    },
+}
+
+/// Arguments for the images command
+#[derive(Parser, Debug)]
+pub struct ImagesArgs {
+    /// Subcommand for images
+    #[command(subcommand)]
+    pub action: ImagesAction,
+}
+
+/// Images subcommands
+#[derive(Subcommand, Debug)]
+pub enum ImagesAction {
+    /// Show the build log of a composed image
+    Log {
+        /// Composed image tag (e.g. mino-composed-a1b2c3d4e5f6, or just the hash)
+        tag: String,
+    },
+}
+
+/// Arguments for the layer command
+#[derive(Parser, Debug)]
+pub struct LayerArgs {
+    /// Subcommand for layer
+    #[command(subcommand)]
+    pub action: LayerAction,
+}
+
+/// Layer subcommands
+#[derive(Subcommand, Debug)]
+pub enum LayerAction {
+    /// List available layers and where they come from
+    List {
+        /// Project directory (defaults to current directory)
+        #[arg(short, long)]
+        project: Option<PathBuf>,
+
+        /// Output format
+        #[arg(short, long, default_value = "table")]
+        format: OutputFormat,
+    },
+
+    /// Show a layer's manifest and install script
+    Show {
+        /// Layer name
+        name: String,
+
+        /// Project directory (defaults to current directory)
+        #[arg(short, long)]
+        project: Option<PathBuf>,
+    },
+
+    /// Scaffold a project-local layer in .mino/layers/<name>/
+    New {
+        /// Layer name
+        name: String,
+
+        /// Project directory (defaults to current directory)
+        #[arg(short, long)]
+        project: Option<PathBuf>,
+    },
+
+    /// Compose a layer onto the base image and run its [verify] commands
+    Test {
+        /// Layer name
+        name: String,
+
+        /// Project directory (defaults to current directory)
+        #[arg(short, long)]
+        project: Option<PathBuf>,
+
+        /// Override a layer build argument (LAYER.ARG=VALUE, repeatable)
+        #[arg(long = "layer-arg", value_name = "LAYER.ARG=VALUE", value_parser = parse_layer_arg)]
+        layer_args: Vec<(String, String, String)>,
+    },
+}
+
+/// Arguments for the creds command
+#[derive(Parser, Debug)]
+pub struct CredsArgs {
+    /// Subcommand for creds
+    #[command(subcommand)]
+    pub action: CredsAction,
+}
+
+/// Creds subcommands
+#[derive(Subcommand, Debug)]
+pub enum CredsAction {
+    /// List providers, whether each is enabled, and what is cached
+    List {
+        /// Output format
+        #[arg(short, long, default_value = "table")]
+        format: OutputFormat,
+    },
+
+    /// Show cached credentials and when they expire
+    Status {
+        /// Only this provider
+        provider: Option<CredentialProvider>,
+
+        /// Output format
+        #[arg(short, long, default_value = "table")]
+        format: OutputFormat,
+    },
+
+    /// Remove cached credentials so the next run fetches new ones
+    Clear {
+        /// Only this provider (default: all)
+        provider: Option<CredentialProvider>,
+    },
+
+    /// Fetch a provider's credentials once to check that they work
+    Test {
+        /// Provider to test
+        provider: CredentialProvider,
+    },
+}
+
+/// Credential provider
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum CredentialProvider {
+    Aws,
+    Gcp,
+    Azure,
+    Github,
+    Gitlab,
+    Bitbucket,
+    Kubernetes,
+}
+
+impl CredentialProvider {
+    /// Every provider, in display order
+    pub const ALL: [Self; 7] = [
+        Self::Aws,
+        Self::Gcp,
+        Self::Azure,
+        Self::Github,
+        Self::Gitlab,
+        Self::Bitbucket,
+        Self::Kubernetes,
+    ];
+
+    /// Name used in config and the credential cache
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Aws => "aws",
+            Self::Gcp => "gcp",
+            Self::Azure => "azure",
+            Self::Github => "github",
+            Self::Gitlab => "gitlab",
+            Self::Bitbucket => "bitbucket",
+            Self::Kubernetes => "kubernetes",
+        }
+    }
+}
+
+/// Arguments for the session command
+#[derive(Parser, Debug)]
+pub struct SessionArgs {
+    /// Subcommand for session
+    #[command(subcommand)]
+    pub action: SessionAction,
+}
+
+/// Session subcommands
+#[derive(Subcommand, Debug)]
+pub enum SessionAction {
+    /// Resource usage recorded when sessions stop
+    Stats {
+        /// Subcommand for stats
+        #[command(subcommand)]
+        action: StatsAction,
+    },
+}
+
+/// Session stats subcommands
+#[derive(Subcommand, Debug)]
+pub enum StatsAction {
+    /// Export per-session CPU, memory, network, and wall time
+    Export(StatsExportArgs),
+}
+
+/// Arguments for `session stats export`
+#[derive(Parser, Debug)]
+pub struct StatsExportArgs {
+    /// Output format
+    #[arg(short, long, default_value = "csv")]
+    pub format: ExportFormat,
+
+    /// Only sessions stopped on or after this date (YYYY-MM-DD or RFC 3339, UTC)
+    #[arg(long, value_name = "DATE", value_parser = parse_date)]
+    pub since: Option<DateTime<Utc>>,
+
+    /// Only sessions stopped before this date (YYYY-MM-DD or RFC 3339, UTC)
+    #[arg(long, value_name = "DATE", value_parser = parse_date)]
+    pub until: Option<DateTime<Utc>>,
 
-        /// Clear composed layer images
-        #[arg(long)]
-        images: bool,
+    /// Only sessions of this project directory
+    #[arg(short, long)]
+    pub project: Option<PathBuf>,
 
-        /// Clear home volumes
-        #[arg(long)]
-        home: bool,
+    /// Only sessions started by this user
+    #[arg(short, long)]
+    pub user: Option<String>,
 
-        /// Clear all artifacts (volumes + images + home)
-        #[arg(long, conflicts_with_all = ["volumes", "images", "home"])]
-        all: bool,
+    /// Write to a file instead of stdout
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+}
 
-        /// Skip confirmation prompt
-        #[arg(short, long)]
-        yes: bool,
-    },
+/// Output format for usage exports
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ExportFormat {
+    /// Comma-separated values with a header row
+    Csv,
+    /// JSON array of records
+    Json,
 }
 
 /// Arguments for the completions command
@@ -385,6 +1079,89 @@ fn parse_env_var(s: &str) -> Result<(String, String), String> {
     Ok((s[..pos].to_string(), s[pos + 1..].to_string()))
 }
 
+/// Parse a `LAYER.ARG=VALUE` layer build argument override
+fn parse_layer_arg(s: &str) -> Result<(String, String, String), String> {
+    let (key, value) = s
+        .split_once('=')
+        .ok_or_else(|| format!("invalid LAYER.ARG=VALUE format: no '=' found in '{s}'"))?;
+    match key.split_once('.') {
+        Some((layer, arg)) if !layer.is_empty() && !arg.is_empty() => {
+            Ok((layer.to_string(), arg.to_string(), value.to_string()))
+        }
+        _ => Err(format!(
+            "invalid LAYER.ARG=VALUE format: '{key}' does not name a layer and argument"
+        )),
+    }
+}
+
+/// Parse a memory size into MB: a bare number is MB, `m`/`g` suffixes
+/// (optionally with `b`) are MB/GB.
+fn parse_memory_mb(s: &str) -> Result<u64, String> {
+    let lower = s.trim().to_lowercase();
+    let lower = lower.strip_suffix('b').unwrap_or(&lower);
+    let (digits, multiplier) = if let Some(n) = lower.strip_suffix('g') {
+        (n, 1024)
+    } else if let Some(n) = lower.strip_suffix('m') {
+        (n, 1)
+    } else {
+        (lower, 1)
+    };
+    digits
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .and_then(|n| n.checked_mul(multiplier))
+        .ok_or_else(|| format!("invalid memory size '{s}' (expected e.g. 512m, 4g, or MB)"))
+}
+
+/// Parse a date: `YYYY-MM-DD` (midnight UTC) or an RFC 3339 timestamp.
+fn parse_date(s: &str) -> Result<DateTime<Utc>, String> {
+    let trimmed = s.trim();
+    if let Ok(date) = NaiveDate::parse_from_str(trimmed, "%Y-%m-%d") {
+        return Ok(date.and_time(NaiveTime::MIN).and_utc());
+    }
+    DateTime::parse_from_rfc3339(trimmed)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|_| {
+            format!("invalid date '{s}' (expected e.g. 2026-03-01 or 2026-03-01T12:00:00Z)")
+        })
+}
+
+/// Parse a duration such as `90s`, `30m`, `2h`, or `1h30m`; a bare number is seconds.
+fn parse_duration(s: &str) -> Result<Duration, String> {
+    let invalid = || format!("invalid duration '{s}' (expected e.g. 90s, 30m, 1h30m)");
+    let trimmed = s.trim();
+    if let Ok(secs) = trimmed.parse::<u64>() {
+        return Ok(Duration::from_secs(secs));
+    }
+
+    let mut total: u64 = 0;
+    let mut digits = String::new();
+    for c in trimmed.chars() {
+        if c.is_ascii_digit() {
+            digits.push(c);
+            continue;
+        }
+        let unit = match c.to_ascii_lowercase() {
+            's' => 1,
+            'm' => 60,
+            'h' => 3600,
+            'd' => 86_400,
+            _ => return Err(invalid()),
+        };
+        let n: u64 = digits.parse().map_err(|_| invalid())?;
+        total = n
+            .checked_mul(unit)
+            .and_then(|secs| total.checked_add(secs))
+            .ok_or_else(invalid)?;
+        digits.clear();
+    }
+    if !digits.is_empty() || trimmed.is_empty() {
+        return Err(invalid());
+    }
+    Ok(Duration::from_secs(total))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -408,6 +1185,21 @@ mod tests {
         assert!(parse_env_var("FOO").is_err());
     }
 
+    #[test]
+    fn parse_layer_arg_splits_layer_and_arg() {
+        assert_eq!(
+            parse_layer_arg("typescript.node_version=20").unwrap(),
+            (
+                "typescript".to_string(),
+                "node_version".to_string(),
+                "20".to_string()
+            )
+        );
+        assert!(parse_layer_arg("node_version=20").is_err());
+        assert!(parse_layer_arg("typescript.node_version").is_err());
+        assert!(parse_layer_arg(".x=1").is_err());
+    }
+
     #[test]
     fn cli_parses_run() {
         let cli = Cli::parse_from(["mino", "run", "--aws", "--", "bash"]);
@@ -420,10 +1212,253 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_memory_mb_units() {
+        assert_eq!(parse_memory_mb("2048").unwrap(), 2048);
+        assert_eq!(parse_memory_mb("512m").unwrap(), 512);
+        assert_eq!(parse_memory_mb("4g").unwrap(), 4096);
+        assert_eq!(parse_memory_mb("4GB").unwrap(), 4096);
+        assert!(parse_memory_mb("lots").is_err());
+        assert!(parse_memory_mb("-1g").is_err());
+    }
+
+    #[test]
+    fn parse_duration_units() {
+        assert_eq!(parse_duration("90").unwrap(), Duration::from_secs(90));
+        assert_eq!(parse_duration("90s").unwrap(), Duration::from_secs(90));
+        assert_eq!(parse_duration("30m").unwrap(), Duration::from_secs(1800));
+        assert_eq!(parse_duration("1h30m").unwrap(), Duration::from_secs(5400));
+        assert_eq!(parse_duration("0").unwrap(), Duration::ZERO);
+        assert!(parse_duration("").is_err());
+        assert!(parse_duration("30x").is_err());
+        assert!(parse_duration("1h30").is_err());
+        assert!(parse_duration("m").is_err());
+    }
+
+    #[test]
+    fn parse_date_formats() {
+        assert_eq!(
+            parse_date("2026-03-01").unwrap().to_rfc3339(),
+            "2026-03-01T00:00:00+00:00"
+        );
+        assert_eq!(
+            parse_date("2026-03-01T14:00:00+02:00")
+                .unwrap()
+                .to_rfc3339(),
+            "2026-03-01T12:00:00+00:00"
+        );
+        assert!(parse_date("03/01/2026").is_err());
+        assert!(parse_date("").is_err());
+    }
+
+    #[test]
+    fn cli_parses_session_stats_export() {
+        let cli = Cli::parse_from([
+            "mino",
+            "session",
+            "stats",
+            "export",
+            "--format",
+            "json",
+            "--since",
+            "2026-03-01",
+            "--user",
+            "dev",
+        ]);
+        match cli.command {
+            Commands::Session(args) => match args.action {
+                SessionAction::Stats {
+                    action: StatsAction::Export(export),
+                } => {
+                    assert_eq!(export.format, ExportFormat::Json);
+                    assert!(export.since.is_some());
+                    assert!(export.until.is_none());
+                    assert_eq!(export.user.as_deref(), Some("dev"));
+                }
+            },
+            _ => panic!("expected Session command"),
+        }
+    }
+
+    #[test]
+    fn cli_run_stdin_conflicts_with_detach() {
+        let cli = Cli::parse_from(["mino", "run", "--stdin", "--", "claude", "-p"]);
+        match cli.command {
+            Commands::Run(args) => assert!(args.stdin),
+            _ => panic!("expected Run command"),
+        }
+        assert!(Cli::try_parse_from(["mino", "run", "-d", "--stdin", "--", "cat"]).is_err());
+    }
+
+    #[test]
+    fn cli_run_timeout_conflicts_with_detach() {
+        let cli = Cli::parse_from(["mino", "run", "--timeout", "30m", "--", "make"]);
+        match cli.command {
+            Commands::Run(args) => assert_eq!(args.timeout, Some(Duration::from_secs(1800))),
+            _ => panic!("expected Run command"),
+        }
+        assert!(
+            Cli::try_parse_from(["mino", "run", "-d", "--timeout", "30m", "--", "make"]).is_err()
+        );
+    }
+
+    #[test]
+    fn cli_parses_progress_mode() {
+        let cli = Cli::parse_from(["mino", "list"]);
+        assert_eq!(cli.progress, ProgressMode::Auto);
+        let cli = Cli::parse_from(["mino", "run", "--progress", "json", "--", "make"]);
+        assert_eq!(cli.progress, ProgressMode::Json);
+        assert!(Cli::try_parse_from(["mino", "list", "--progress", "fancy"]).is_err());
+    }
+
+    #[test]
+    fn cli_parses_login() {
+        let cli = Cli::parse_from(["mino", "login", "ghcr.io", "-u", "me", "--password-stdin"]);
+        match cli.command {
+            Commands::Login(args) => {
+                assert_eq!(args.registry.as_deref(), Some("ghcr.io"));
+                assert_eq!(args.username.as_deref(), Some("me"));
+                assert!(args.password_stdin);
+            }
+            _ => panic!("expected Login command"),
+        }
+        assert!(Cli::try_parse_from(["mino", "login", "--list"]).is_ok());
+        assert!(Cli::try_parse_from(["mino", "login"]).is_err());
+        assert!(Cli::try_parse_from(["mino", "login", "ghcr.io", "--list"]).is_err());
+        assert!(Cli::try_parse_from(["mino", "login", "ghcr.io", "--logout", "-u", "me"]).is_err());
+    }
+
+    #[test]
+    fn cli_parses_cloud_registry_login() {
+        let cli = Cli::parse_from(["mino", "login", "--ecr", "eu-west-1"]);
+        match cli.command {
+            Commands::Login(args) => {
+                assert_eq!(args.ecr.as_deref(), Some("eu-west-1"));
+                assert!(args.registry.is_none());
+            }
+            _ => panic!("expected Login command"),
+        }
+        assert!(Cli::try_parse_from(["mino", "login", "--gar", "us-central1"]).is_ok());
+        assert!(Cli::try_parse_from(["mino", "login", "--ecr", "a", "--gar", "b"]).is_err());
+        assert!(Cli::try_parse_from(["mino", "login", "ghcr.io", "--ecr", "eu-west-1"]).is_err());
+        assert!(Cli::try_parse_from(["mino", "login", "--gar", "x", "-u", "me"]).is_err());
+    }
+
+    #[test]
+    fn cli_parses_images_log() {
+        let cli = Cli::parse_from(["mino", "images", "log", "mino-composed-abc"]);
+        match cli.command {
+            Commands::Images(args) => match args.action {
+                ImagesAction::Log { tag } => assert_eq!(tag, "mino-composed-abc"),
+            },
+            _ => panic!("expected Images command"),
+        }
+    }
+
+    #[test]
+    fn cli_parses_layer_subcommands() {
+        let cli = Cli::parse_from(["mino", "layer", "list", "--format", "json"]);
+        match cli.command {
+            Commands::Layer(args) => match args.action {
+                LayerAction::List { project, format } => {
+                    assert!(project.is_none());
+                    assert!(matches!(format, OutputFormat::Json));
+                }
+                _ => panic!("expected List action"),
+            },
+            _ => panic!("expected Layer command"),
+        }
+
+        let cli = Cli::parse_from(["mino", "layer", "new", "protoc", "-p", "/src/app"]);
+        match cli.command {
+            Commands::Layer(args) => match args.action {
+                LayerAction::New { name, project } => {
+                    assert_eq!(name, "protoc");
+                    assert_eq!(project, Some(PathBuf::from("/src/app")));
+                }
+                _ => panic!("expected New action"),
+            },
+            _ => panic!("expected Layer command"),
+        }
+
+        let cli = Cli::parse_from([
+            "mino",
+            "layer",
+            "test",
+            "node",
+            "--layer-arg",
+            "node.node_version=20",
+        ]);
+        match cli.command {
+            Commands::Layer(args) => match args.action {
+                LayerAction::Test {
+                    name, layer_args, ..
+                } => {
+                    assert_eq!(name, "node");
+                    assert_eq!(layer_args[0].2, "20");
+                }
+                _ => panic!("expected Test action"),
+            },
+            _ => panic!("expected Layer command"),
+        }
+
+        assert!(Cli::try_parse_from(["mino", "layer", "show"]).is_err());
+    }
+
+    #[test]
+    fn cli_parses_cp() {
+        let cli = Cli::parse_from(["mino", "cp", "swift-fox:/workspace/dist", "./dist"]);
+        match cli.command {
+            Commands::Cp(args) => {
+                assert_eq!(args.source, "swift-fox:/workspace/dist");
+                assert_eq!(args.destination, "./dist");
+            }
+            _ => panic!("expected Cp command"),
+        }
+    }
+
+    #[test]
+    fn cli_parses_pause_and_resume() {
+        let cli = Cli::parse_from(["mino", "pause", "swift-fox"]);
+        match cli.command {
+            Commands::Pause(args) => assert_eq!(args.session, "swift-fox"),
+            _ => panic!("expected Pause command"),
+        }
+        let cli = Cli::parse_from(["mino", "resume", "swift-fox"]);
+        match cli.command {
+            Commands::Resume(args) => assert_eq!(args.session, "swift-fox"),
+            _ => panic!("expected Resume command"),
+        }
+    }
+
+    #[test]
+    fn cli_parses_checkpoint() {
+        let cli = Cli::parse_from(["mino", "checkpoint", "swift-fox", "--leave-running"]);
+        match cli.command {
+            Commands::Checkpoint(args) => {
+                assert_eq!(args.session, "swift-fox");
+                assert!(args.leave_running);
+            }
+            _ => panic!("expected Checkpoint command"),
+        }
+    }
+
     #[test]
     fn cli_parses_status() {
         let cli = Cli::parse_from(["mino", "status"]);
-        assert!(matches!(cli.command, Commands::Status));
+        match cli.command {
+            Commands::Status(args) => assert!(!args.json),
+            _ => panic!("expected Status command"),
+        }
+    }
+
+    #[test]
+    fn cli_parses_status_json() {
+        let cli = Cli::parse_from(["mino", "status", "--json"]);
+        match cli.command {
+            Commands::Status(args) => assert!(args.json),
+            _ => panic!("expected Status command"),
+        }
     }
 
     #[test]
@@ -604,6 +1639,50 @@ mod tests {
         }
     }
 
+    #[test]
+    fn cli_parses_workdir() {
+        let cli = Cli::parse_from(["mino", "run", "--workdir", "packages/api", "--", "bash"]);
+        match cli.command {
+            Commands::Run(args) => assert_eq!(args.workdir.as_deref(), Some("packages/api")),
+            _ => panic!("expected Run command"),
+        }
+    }
+
+    #[test]
+    fn cli_workdir_default_none() {
+        let cli = Cli::parse_from(["mino", "run", "--", "bash"]);
+        match cli.command {
+            Commands::Run(args) => assert!(args.workdir.is_none()),
+            _ => panic!("expected Run command"),
+        }
+    }
+
+    #[test]
+    fn cli_parses_repeated_project() {
+        let cli = Cli::parse_from(["mino", "run", "-p", "/a", "--project", "/b"]);
+        match cli.command {
+            Commands::Run(args) => {
+                assert_eq!(args.project, vec![PathBuf::from("/a"), PathBuf::from("/b")])
+            }
+            _ => panic!("expected Run command"),
+        }
+    }
+
+    #[test]
+    fn cli_parses_cache_rw() {
+        let cli = Cli::parse_from(["mino", "run", "--cache-rw", "--", "bash"]);
+        match cli.command {
+            Commands::Run(args) => assert!(args.cache_rw),
+            _ => panic!("expected Run command"),
+        }
+    }
+
+    #[test]
+    fn cli_cache_rw_conflicts_with_no_cache() {
+        let result = Cli::try_parse_from(["mino", "run", "--cache-rw", "--no-cache"]);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn cli_parses_completions_bash() {
         let cli = Cli::parse_from(["mino", "completions", "bash"]);
@@ -711,6 +1790,161 @@ mod tests {
         }
     }
 
+    #[test]
+    fn cli_parses_cache_warm() {
+        let cli = Cli::parse_from([
+            "mino", "cache", "warm", "-p", "/src/app", "--layers", "rust",
+        ]);
+        match cli.command {
+            Commands::Cache(args) => match args.action {
+                CacheAction::Warm {
+                    project,
+                    image,
+                    layers,
+                } => {
+                    assert_eq!(project, Some(PathBuf::from("/src/app")));
+                    assert!(image.is_none());
+                    assert_eq!(layers, vec!["rust"]);
+                }
+                _ => panic!("expected Warm action"),
+            },
+            _ => panic!("expected Cache command"),
+        }
+    }
+
+    #[test]
+    fn cli_parses_cache_stats() {
+        let cli = Cli::parse_from(["mino", "cache", "stats", "--since", "2026-01-01", "--json"]);
+        match cli.command {
+            Commands::Cache(args) => match args.action {
+                CacheAction::Stats {
+                    since,
+                    project,
+                    json,
+                } => {
+                    assert!(since.is_some());
+                    assert!(project.is_none());
+                    assert!(json);
+                }
+                _ => panic!("expected Stats action"),
+            },
+            _ => panic!("expected Cache command"),
+        }
+    }
+
+    #[test]
+    fn cli_parses_cache_gc_max_size() {
+        let cli = Cli::parse_from([
+            "mino",
+            "cache",
+            "gc",
+            "--max-size",
+            "20",
+            "--strategy",
+            "largest",
+        ]);
+        match cli.command {
+            Commands::Cache(args) => match args.action {
+                CacheAction::Gc {
+                    max_size, strategy, ..
+                } => {
+                    assert_eq!(max_size, Some(20));
+                    assert_eq!(strategy, GcStrategy::Largest);
+                }
+                _ => panic!("expected Gc action"),
+            },
+            _ => panic!("expected Cache command"),
+        }
+
+        // The strategy only orders size-based removal
+        assert!(Cli::try_parse_from(["mino", "cache", "gc", "--strategy", "oldest"]).is_err());
+        assert!(
+            Cli::try_parse_from(["mino", "cache", "gc", "--orphaned", "--max-size", "5"]).is_err()
+        );
+    }
+
+    #[test]
+    fn cli_parses_cache_clear_filters() {
+        let cli = Cli::parse_from([
+            "mino",
+            "cache",
+            "clear",
+            "--volumes",
+            "--ecosystem",
+            "npm",
+            "--project",
+            "/src/app",
+            "--state",
+            "building",
+        ]);
+        match cli.command {
+            Commands::Cache(args) => match args.action {
+                CacheAction::Clear {
+                    volumes,
+                    ecosystem,
+                    project,
+                    state,
+                    ..
+                } => {
+                    assert!(volumes);
+                    assert_eq!(ecosystem.as_deref(), Some("npm"));
+                    assert_eq!(project, Some(PathBuf::from("/src/app")));
+                    assert_eq!(state, Some(CacheStateFilter::Building));
+                }
+                _ => panic!("expected Clear action"),
+            },
+            _ => panic!("expected Cache command"),
+        }
+
+        // Filters narrow the cache volumes only
+        assert!(
+            Cli::try_parse_from(["mino", "cache", "clear", "--images", "--state", "building"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn cli_parses_cache_gc_orphaned() {
+        let cli = Cli::parse_from(["mino", "cache", "gc", "--orphaned", "-y"]);
+        match cli.command {
+            Commands::Cache(args) => match args.action {
+                CacheAction::Gc { orphaned, yes, .. } => {
+                    assert!(orphaned);
+                    assert!(yes);
+                }
+                _ => panic!("expected Gc action"),
+            },
+            _ => panic!("expected Cache command"),
+        }
+
+        // --yes only applies to the confirmed orphan mode
+        assert!(Cli::try_parse_from(["mino", "cache", "gc", "--yes"]).is_err());
+        assert!(Cli::try_parse_from(["mino", "cache", "gc", "--orphaned", "--days", "7"]).is_err());
+    }
+
+    #[test]
+    fn cli_parses_creds_actions() {
+        let cli = Cli::parse_from(["mino", "creds", "test", "aws"]);
+        match cli.command {
+            Commands::Creds(args) => match args.action {
+                CredsAction::Test { provider } => assert_eq!(provider, CredentialProvider::Aws),
+                _ => panic!("expected Test action"),
+            },
+            _ => panic!("expected Creds command"),
+        }
+
+        let cli = Cli::parse_from(["mino", "creds", "clear"]);
+        match cli.command {
+            Commands::Creds(args) => {
+                assert!(matches!(args.action, CredsAction::Clear { provider: None }))
+            }
+            _ => panic!("expected Creds command"),
+        }
+
+        assert!(Cli::try_parse_from(["mino", "creds", "test"]).is_err());
+        assert!(Cli::try_parse_from(["mino", "creds", "clear", "docker"]).is_err());
+    }
+
     #[test]
     fn cli_parses_runtime_flag() {
         let cli = Cli::parse_from(["mino", "run", "--runtime", "native", "--", "bash"]);
@@ -722,6 +1956,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn cli_parses_native_allow_degraded() {
+        let cli = Cli::parse_from(["mino", "run", "--native", "--allow-degraded"]);
+        match cli.command {
+            Commands::Run(args) => {
+                assert!(args.native);
+                assert!(args.allow_degraded);
+            }
+            _ => panic!("expected Run command"),
+        }
+    }
+
     #[test]
     fn cli_runtime_default_none() {
         let cli = Cli::parse_from(["mino", "run", "--", "bash"]);
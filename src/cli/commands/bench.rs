@@ -0,0 +1,316 @@
+//! Bench command - measure sandbox startup overhead per phase
+
+use crate::cli::args::{BenchArgs, OutputFormat, RunArgs};
+use crate::cli::commands::run::bench::{measure_startup, Phase, StartupSample};
+use crate::config::Config;
+use crate::error::{MinoError, MinoResult};
+use crate::orchestration::{create_runtime, ContainerRuntime};
+use crate::ui::{TaskSpinner, UiContext};
+use clap::Parser;
+use console::{pad_str, style, Alignment};
+use serde::Serialize;
+use std::env;
+use std::fmt::Write;
+use std::time::Duration;
+use tracing::debug;
+
+/// Percentiles reported for each phase
+const PERCENTILES: [u8; 3] = [50, 90, 99];
+
+/// Latency statistics of one phase (or the total) across runs
+#[derive(Debug, Serialize, PartialEq)]
+struct PhaseStats {
+    phase: &'static str,
+    min_ms: f64,
+    mean_ms: f64,
+    p50_ms: f64,
+    p90_ms: f64,
+    p99_ms: f64,
+    max_ms: f64,
+}
+
+/// Versioned JSON report
+#[derive(Debug, Serialize)]
+struct BenchReport {
+    schema_version: u32,
+    runtime: &'static str,
+    image: String,
+    mode: &'static str,
+    runs: usize,
+    phases: Vec<PhaseStats>,
+    total: PhaseStats,
+}
+
+/// Execute the bench command
+pub async fn execute(args: BenchArgs, config: &Config) -> MinoResult<()> {
+    let runtime = create_runtime(config)?;
+    let run_args = bench_run_args(&args)?;
+    let project_dir =
+        env::current_dir().map_err(|e| MinoError::io("getting current directory", e))?;
+
+    let ctx = UiContext::detect();
+    let mut spinner = TaskSpinner::new(&ctx);
+
+    // The warm-up run pulls or builds the image and creates caches, so warm
+    // runs measure steady-state startup
+    spinner.start("Warm-up run...");
+    let (_, mut image) = measure_startup(&*runtime, &run_args, config, &project_dir).await?;
+
+    let mut samples = Vec::with_capacity(args.runs as usize);
+    for run in 1..=args.runs {
+        if args.cold {
+            spinner.message(&format!("Removing {}...", image));
+            remove_image(&*runtime, &image).await?;
+        }
+        spinner.message(&format!("Run {}/{}...", run, args.runs));
+        let (sample, resolved) =
+            measure_startup(&*runtime, &run_args, config, &project_dir).await?;
+        debug!("Run {}: {:?}", run, sample);
+        samples.push(sample);
+        image = resolved;
+    }
+    spinner.clear();
+
+    let report = BenchReport {
+        schema_version: 1,
+        runtime: runtime.runtime_name(),
+        image,
+        mode: if args.cold { "cold" } else { "warm" },
+        runs: samples.len(),
+        phases: Phase::ALL
+            .iter()
+            .map(|&phase| phase_stats(phase.name(), samples.iter().map(|s| s.get(phase))))
+            .collect(),
+        total: phase_stats("total", samples.iter().map(StartupSample::total)),
+    };
+
+    match args.format {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&report)?),
+        OutputFormat::Plain => print!("{}", format_plain(&report)),
+        OutputFormat::Table => print!("{}", format_table(&report)),
+    }
+    Ok(())
+}
+
+/// `mino run` arguments for the benchmark sessions: no home volume or SSH
+/// agent, since those are per-project state rather than startup cost.
+fn bench_run_args(args: &BenchArgs) -> MinoResult<RunArgs> {
+    let mut run_args = RunArgs::try_parse_from(["mino-run", "--no-home", "--no-ssh-agent"])
+        .map_err(|e| MinoError::Internal(format!("benchmark run arguments: {}", e)))?;
+    run_args.image = args.image.clone();
+    run_args.layers = args.layers.clone();
+    if args.cold {
+        // Pulls must not be skipped for an image that was just removed
+        run_args.pull = Some("missing".to_string());
+    }
+    Ok(run_args)
+}
+
+/// Remove the image so the next run pulls (or composes) it again
+async fn remove_image(runtime: &dyn ContainerRuntime, image: &str) -> MinoResult<()> {
+    if runtime.image_exists(image).await? {
+        runtime.image_remove(image).await?;
+    }
+    Ok(())
+}
+
+/// Nearest-rank percentile of sorted values
+fn percentile(sorted: &[Duration], pct: u8) -> Duration {
+    if sorted.is_empty() {
+        return Duration::ZERO;
+    }
+    let rank = (usize::from(pct) * sorted.len()).div_ceil(100).max(1);
+    sorted[rank.min(sorted.len()) - 1]
+}
+
+fn millis(d: Duration) -> f64 {
+    d.as_secs_f64() * 1000.0
+}
+
+/// Min, mean, percentiles, and max of one phase
+fn phase_stats(phase: &'static str, values: impl Iterator<Item = Duration>) -> PhaseStats {
+    let mut sorted: Vec<Duration> = values.collect();
+    sorted.sort();
+    let mean = if sorted.is_empty() {
+        Duration::ZERO
+    } else {
+        sorted.iter().sum::<Duration>() / sorted.len() as u32
+    };
+    let [p50, p90, p99] = PERCENTILES.map(|pct| millis(percentile(&sorted, pct)));
+
+    PhaseStats {
+        phase,
+        min_ms: millis(sorted.first().copied().unwrap_or_default()),
+        mean_ms: millis(mean),
+        p50_ms: p50,
+        p90_ms: p90,
+        p99_ms: p99,
+        max_ms: millis(sorted.last().copied().unwrap_or_default()),
+    }
+}
+
+/// Format milliseconds for the table: one decimal below 100ms
+fn format_ms(ms: f64) -> String {
+    if ms < 100.0 {
+        format!("{:.1}ms", ms)
+    } else {
+        format!("{:.0}ms", ms)
+    }
+}
+
+/// Format the report table (header, separator, one row per phase, total).
+fn format_table(report: &BenchReport) -> String {
+    const W_PHASE: usize = 10;
+    const W_VALUE: usize = 10;
+    const COLUMNS: [&str; 6] = ["MIN", "MEAN", "P50", "P90", "P99", "MAX"];
+
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "{} runs of {} on {} ({})\n",
+        report.runs,
+        style(&report.image).cyan(),
+        report.runtime,
+        report.mode
+    );
+
+    let mut header = pad_str(
+        &style("PHASE").bold().to_string(),
+        W_PHASE,
+        Alignment::Left,
+        None,
+    )
+    .into_owned();
+    for column in COLUMNS {
+        header.push(' ');
+        header.push_str(&pad_str(
+            &style(column).bold().to_string(),
+            W_VALUE,
+            Alignment::Right,
+            None,
+        ));
+    }
+    let _ = writeln!(out, "{}", header);
+    let _ = writeln!(
+        out,
+        "{}",
+        "-".repeat(W_PHASE + COLUMNS.len() * (W_VALUE + 1))
+    );
+
+    let row = |stats: &PhaseStats| {
+        let mut line = pad_str(stats.phase, W_PHASE, Alignment::Left, None).into_owned();
+        for value in [
+            stats.min_ms,
+            stats.mean_ms,
+            stats.p50_ms,
+            stats.p90_ms,
+            stats.p99_ms,
+            stats.max_ms,
+        ] {
+            line.push(' ');
+            line.push_str(&pad_str(&format_ms(value), W_VALUE, Alignment::Right, None));
+        }
+        line
+    };
+    for stats in &report.phases {
+        let _ = writeln!(out, "{}", row(stats));
+    }
+    let _ = writeln!(out, "{}", style(row(&report.total)).bold());
+
+    out
+}
+
+/// One tab-separated line per phase: name, min, mean, p50, p90, p99, max (ms)
+fn format_plain(report: &BenchReport) -> String {
+    let mut out = String::new();
+    for stats in report.phases.iter().chain(std::iter::once(&report.total)) {
+        let _ = writeln!(
+            out,
+            "{}\t{:.1}\t{:.1}\t{:.1}\t{:.1}\t{:.1}\t{:.1}",
+            stats.phase,
+            stats.min_ms,
+            stats.mean_ms,
+            stats.p50_ms,
+            stats.p90_ms,
+            stats.p99_ms,
+            stats.max_ms
+        );
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&v| Duration::from_millis(v)).collect()
+    }
+
+    #[test]
+    fn percentile_nearest_rank() {
+        let sorted = ms(&[10, 20, 30, 40, 50, 60, 70, 80, 90, 100]);
+        assert_eq!(percentile(&sorted, 50), Duration::from_millis(50));
+        assert_eq!(percentile(&sorted, 90), Duration::from_millis(90));
+        assert_eq!(percentile(&sorted, 99), Duration::from_millis(100));
+
+        let single = ms(&[7]);
+        for pct in PERCENTILES {
+            assert_eq!(percentile(&single, pct), Duration::from_millis(7));
+        }
+        assert_eq!(percentile(&[], 50), Duration::ZERO);
+    }
+
+    #[test]
+    fn phase_stats_summarize_unsorted_values() {
+        let stats = phase_stats("create", ms(&[30, 10, 20]).into_iter());
+        assert_eq!(
+            stats,
+            PhaseStats {
+                phase: "create",
+                min_ms: 10.0,
+                mean_ms: 20.0,
+                p50_ms: 20.0,
+                p90_ms: 30.0,
+                p99_ms: 30.0,
+                max_ms: 30.0,
+            }
+        );
+    }
+
+    #[test]
+    fn format_ms_precision() {
+        assert_eq!(format_ms(4.25), "4.2ms");
+        assert_eq!(format_ms(1234.5), "1234ms");
+    }
+
+    #[test]
+    fn bench_run_args_skip_per_project_state() {
+        let args =
+            BenchArgs::try_parse_from(["bench", "--cold", "--layers", "rust,typescript"]).unwrap();
+        let run_args = bench_run_args(&args).unwrap();
+        assert!(run_args.no_home);
+        assert!(run_args.no_ssh_agent);
+        assert_eq!(run_args.layers, vec!["rust", "typescript"]);
+        assert_eq!(run_args.pull.as_deref(), Some("missing"));
+        assert!(!run_args.detach);
+    }
+
+    #[test]
+    fn format_plain_includes_total() {
+        let stats = |phase| phase_stats(phase, ms(&[5]).into_iter());
+        let report = BenchReport {
+            schema_version: 1,
+            runtime: "Podman",
+            image: "fedora:43".to_string(),
+            mode: "warm",
+            runs: 1,
+            phases: vec![stats("vm")],
+            total: stats("total"),
+        };
+        assert_eq!(
+            format_plain(&report),
+            "vm\t5.0\t5.0\t5.0\t5.0\t5.0\t5.0\ntotal\t5.0\t5.0\t5.0\t5.0\t5.0\t5.0\n"
+        );
+    }
+}
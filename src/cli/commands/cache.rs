@@ -1,19 +1,32 @@
 //! Cache command - manage dependency caches
 
+use crate::cache::evict::{plan_removal, EvictionCandidate, RemovalOrder};
+use crate::cache::stats::{summarize, summarize_by, week_start, CacheStatsSummary};
+use crate::cache::storage::ContainerStorageVolume;
+use crate::cache::volume::labels as cache_labels;
 use crate::cache::{
-    detect_lockfiles, format_bytes, gb_to_bytes, resolve_state, CacheSidecar, CacheSizeStatus,
-    CacheState, CacheVolume,
+    detect_lockfiles_nested, format_bytes, gb_to_bytes, resolve_state, CacheScope, CacheSidecar,
+    CacheSizeStatus, CacheState, CacheStatsLog, CacheUseRecord, CacheVolume, Ecosystem,
+    LockfileInfo,
 };
-use crate::cli::args::{CacheAction, CacheArgs, OutputFormat};
-use crate::cli::commands::run::image::LAYER_BASE_IMAGE;
+use crate::cli::args::{
+    CacheAction, CacheArgs, CacheStateFilter, GcStrategy, OutputFormat, RunArgs,
+};
+use crate::cli::commands::run::image::{configured_cache_key_extra, LAYER_BASE_IMAGE};
+use crate::cli::commands::run::warm::{warm_caches, WarmOutcome, WarmResult};
 use crate::config::Config;
 use crate::error::{MinoError, MinoResult};
-use crate::home::HomeVolume;
+use crate::home::{labels as home_labels, HomeVolume};
 use crate::orchestration::{create_runtime, ContainerRuntime};
-use crate::ui::{self, UiContext};
-use chrono::Utc;
-use console::{pad_str, style, Alignment};
+use crate::scratch::{labels as scratch_labels, ScratchVolume};
+use crate::session::SessionManager;
+use crate::ui::{self, Column, Table, UiContext};
+use chrono::{DateTime, Utc};
+use clap::Parser;
+use console::style;
+use std::collections::HashMap;
 use std::env;
+use std::fmt::Write;
 use std::path::PathBuf;
 use tracing::debug;
 
@@ -22,42 +35,98 @@ pub async fn execute(args: CacheArgs, config: &Config) -> MinoResult<()> {
     let runtime = create_runtime(config)?;
 
     match args.action {
-        CacheAction::List { format } => list_caches(&*runtime, format, config).await,
+        CacheAction::List { format, no_size } => {
+            list_caches(&*runtime, format, !no_size, config).await
+        }
         CacheAction::Info { project } => show_project_info(&*runtime, project, config).await,
-        CacheAction::Gc { days, dry_run } => gc_caches(&*runtime, config, days, dry_run).await,
+        CacheAction::Warm {
+            project,
+            image,
+            layers,
+        } => warm_project(&*runtime, project, image, layers, config).await,
+        CacheAction::Stats {
+            since,
+            project,
+            json,
+        } => show_cache_stats(since, project, json).await,
+        CacheAction::Gc {
+            orphaned: true,
+            dry_run,
+            yes,
+            ..
+        } => gc_orphaned_caches(&*runtime, dry_run, yes).await,
+        CacheAction::Gc {
+            days,
+            dry_run,
+            max_size,
+            strategy,
+            ..
+        } => {
+            let size_limit = max_size.map(|gb| (gb, strategy));
+            gc_caches(&*runtime, config, days, dry_run, size_limit).await
+        }
         CacheAction::Clear {
             all,
             volumes,
             images,
             home,
+            scratch,
             yes,
-        } => clear_artifacts(&*runtime, all || volumes, all || images, all || home, yes).await,
+            ecosystem,
+            project,
+            state,
+        } => {
+            let filter = CacheVolumeFilter::new(ecosystem.as_deref(), project, state)?;
+            clear_artifacts(
+                &*runtime,
+                all || volumes,
+                all || images,
+                all || home,
+                all || scratch,
+                &filter,
+                yes,
+            )
+            .await
+        }
     }
 }
 
-/// List all cache volumes with sizes
+/// List all cache volumes, with sizes unless `measure` is false
 async fn list_caches(
     runtime: &dyn ContainerRuntime,
     format: OutputFormat,
+    measure: bool,
     config: &Config,
 ) -> MinoResult<()> {
-    let volumes = runtime.volume_list("mino-cache-").await?;
-    let home_volumes = runtime.volume_list("mino-home-").await?;
+    let volumes = runtime.volume_list(cache_labels::MINO_CACHE).await?;
+    let home_volumes = runtime.volume_list(home_labels::MINO_HOME).await?;
 
     if volumes.is_empty() && home_volumes.is_empty() {
         match format {
-            OutputFormat::Json => println!("{{\"caches\":[],\"home_volumes\":[]}}"),
+            OutputFormat::Json => {
+                println!("{{\"caches\":[],\"container_storage\":[],\"home_volumes\":[]}}")
+            }
             OutputFormat::Plain => {}
             OutputFormat::Table => println!("No cache or home volumes found."),
         }
         return Ok(());
     }
 
-    // Get disk usage for all cache volumes
-    let sizes = if !volumes.is_empty() {
-        runtime.volume_disk_usage("mino-cache-").await?
+    // Get disk usage for all cache volumes; the spinner would corrupt
+    // JSON and plain output, so only the table shows it
+    let sizes = if measure && !volumes.is_empty() {
+        let ctx = UiContext::detect();
+        let mut spinner = ui::TaskSpinner::new(&ctx);
+        if matches!(format, OutputFormat::Table) {
+            spinner.start(&format!("Measuring {} cache volume(s)...", volumes.len()));
+        }
+        let sizes = runtime.volume_disk_usage(cache_labels::MINO_CACHE).await;
+        spinner.clear();
+        Some(sizes?)
+    } else if measure {
+        Some(HashMap::new())
     } else {
-        std::collections::HashMap::new()
+        None
     };
 
     // Parse into CacheVolume structs with sizes, resolving state via sidecar
@@ -65,31 +134,60 @@ async fn list_caches(
     for v in &volumes {
         if let Some(mut cache) = CacheVolume::from_labels(&v.name, &v.labels) {
             cache.state = resolve_state(&cache.name, cache.state).await;
-            let size = *sizes.get(&cache.name).unwrap_or(&0);
+            let size = sizes
+                .as_ref()
+                .and_then(|s| s.get(&cache.name))
+                .copied()
+                .unwrap_or(0);
             caches.push((cache, size));
         }
     }
 
+    // Container storage volumes carry the cache label but no ecosystem
+    let storage_vols: Vec<(ContainerStorageVolume, u64)> = volumes
+        .iter()
+        .filter_map(|v| ContainerStorageVolume::from_labels(&v.name, &v.labels))
+        .map(|vol| {
+            let size = sizes
+                .as_ref()
+                .and_then(|s| s.get(&vol.name))
+                .copied()
+                .unwrap_or(0);
+            (vol, size)
+        })
+        .collect();
+
     // Parse home volumes
     let home_vols: Vec<HomeVolume> = home_volumes
         .iter()
         .filter_map(|v| HomeVolume::from_labels(&v.name, &v.labels))
         .collect();
 
-    // Calculate total size
-    let total_size: u64 = caches.iter().map(|(_, s)| s).sum();
+    // Calculate total size; unknown when sizes were skipped
+    let total_size = sizes.is_some().then(|| {
+        caches.iter().map(|(_, s)| s).sum::<u64>()
+            + storage_vols.iter().map(|(_, s)| s).sum::<u64>()
+    });
     let limit_bytes = gb_to_bytes(config.cache.max_total_gb);
 
     match format {
         OutputFormat::Table => {
             print_cache_table(&caches, total_size, limit_bytes);
+            if !storage_vols.is_empty() {
+                print_storage_table(&storage_vols, sizes.is_some());
+            }
             if !home_vols.is_empty() {
                 print_home_table(&home_vols);
             }
         }
-        OutputFormat::Json => print_cache_json(&caches, &home_vols, total_size, limit_bytes)?,
+        OutputFormat::Json => {
+            print_cache_json(&caches, &storage_vols, &home_vols, total_size, limit_bytes)?
+        }
         OutputFormat::Plain => {
             print_cache_plain(&caches);
+            for (vol, _) in &storage_vols {
+                println!("{}", vol.name);
+            }
             for hv in &home_vols {
                 println!("{}", hv.name);
             }
@@ -99,29 +197,41 @@ async fn list_caches(
     Ok(())
 }
 
-fn print_cache_table(caches: &[(CacheVolume, u64)], total_size: u64, limit_bytes: u64) {
-    const W_VOLUME: usize = 40;
-    const W_ECO: usize = 10;
-    const W_STATE: usize = 10;
-    const W_SIZE: usize = 10;
-    const W_CREATED: usize = 16;
-
+/// Print caches as a table; `total_size` is `None` when sizes were skipped
+fn print_cache_table(caches: &[(CacheVolume, u64)], total_size: Option<u64>, limit_bytes: u64) {
     let ctx = UiContext::detect();
-
     ui::intro(&ctx, "Cache Volumes");
+    ui::page(&ctx, &format_cache_table(caches, total_size, limit_bytes));
+}
 
-    println!(
-        "{} {} {} {} {}",
-        pad_str("VOLUME", W_VOLUME, Alignment::Left, None),
-        pad_str("ECOSYSTEM", W_ECO, Alignment::Left, None),
-        pad_str("STATE", W_STATE, Alignment::Left, None),
-        pad_str("SIZE", W_SIZE, Alignment::Left, None),
-        pad_str("CREATED", W_CREATED, Alignment::Left, None),
-    );
-    println!(
-        "{}",
-        "-".repeat(W_VOLUME + 1 + W_ECO + 1 + W_STATE + 1 + W_SIZE + 1 + W_CREATED)
-    );
+/// "global" for shared caches, the project directory name for
+/// project-scoped ones
+fn scope_display(cache: &CacheVolume) -> String {
+    project_scope_display(cache.project.as_deref())
+}
+
+fn project_scope_display(project: Option<&str>) -> String {
+    match project {
+        None => "global".to_string(),
+        Some(project) => std::path::Path::new(project)
+            .file_name()
+            .map_or_else(|| project.to_string(), |n| n.to_string_lossy().into_owned()),
+    }
+}
+
+fn format_cache_table(
+    caches: &[(CacheVolume, u64)],
+    total_size: Option<u64>,
+    limit_bytes: u64,
+) -> String {
+    let mut table = Table::new(vec![
+        Column::new("VOLUME", 40),
+        Column::new("ECOSYSTEM", 10).fixed(),
+        Column::new("STATE", 10).fixed(),
+        Column::new("SCOPE", 16),
+        Column::new("SIZE", 10).fixed(),
+        Column::new("CREATED", 16).fixed(),
+    ]);
 
     for (cache, size) in caches {
         let state_display = match cache.state {
@@ -136,19 +246,23 @@ fn print_cache_table(caches: &[(CacheVolume, u64)], total_size: u64, limit_bytes
             "-".to_string()
         };
 
-        let created = cache.created_at.format("%Y-%m-%d %H:%M").to_string();
-
-        println!(
-            "{} {} {} {} {}",
-            pad_str(&cache.name, W_VOLUME, Alignment::Left, None),
-            pad_str(&cache.ecosystem.to_string(), W_ECO, Alignment::Left, None),
-            pad_str(&state_display, W_STATE, Alignment::Left, None),
-            pad_str(&size_display, W_SIZE, Alignment::Left, None),
-            pad_str(&created, W_CREATED, Alignment::Left, None),
-        );
+        table.row(vec![
+            cache.name.clone(),
+            cache.ecosystem.to_string(),
+            state_display,
+            scope_display(cache),
+            size_display,
+            cache.created_at.format("%Y-%m-%d %H:%M").to_string(),
+        ]);
     }
 
-    println!();
+    let mut out = table.render();
+    out.push('\n');
+
+    let Some(total_size) = total_size else {
+        let _ = writeln!(out, "{} cache(s) (sizes skipped)", caches.len());
+        return out;
+    };
 
     // Show total and limit
     let status = CacheSizeStatus::from_usage(total_size, limit_bytes);
@@ -161,58 +275,90 @@ fn print_cache_table(caches: &[(CacheVolume, u64)], total_size: u64, limit_bytes
         percent
     );
 
-    match status {
-        CacheSizeStatus::Ok => println!("{}", total_display),
-        CacheSizeStatus::Warning => println!(
+    let _ = match status {
+        CacheSizeStatus::Ok => writeln!(out, "{}", total_display),
+        CacheSizeStatus::Warning => writeln!(
+            out,
             "{} {} - consider running: mino cache gc",
             style("!").yellow(),
             total_display
         ),
-        CacheSizeStatus::Exceeded => println!(
+        CacheSizeStatus::Exceeded => writeln!(
+            out,
             "{} {} - run: mino cache gc",
             style("!").red().bold(),
             total_display
         ),
+    };
+
+    let _ = writeln!(out, "{} cache(s)", caches.len());
+    out
+}
+
+fn print_storage_table(storage_vols: &[(ContainerStorageVolume, u64)], measured: bool) {
+    let ctx = UiContext::detect();
+    ui::intro(&ctx, "Container Storage");
+    ui::page(&ctx, &format_storage_table(storage_vols, measured));
+}
+
+fn format_storage_table(storage_vols: &[(ContainerStorageVolume, u64)], measured: bool) -> String {
+    let mut table = Table::new(vec![
+        Column::new("VOLUME", 40),
+        Column::new("SCOPE", 16),
+        Column::new("SIZE", 10).fixed(),
+        Column::new("CREATED", 16).fixed(),
+    ]);
+
+    for (vol, size) in storage_vols {
+        table.row(vec![
+            vol.name.clone(),
+            project_scope_display(vol.project.as_deref()),
+            if measured && *size > 0 {
+                format_bytes(*size)
+            } else {
+                "-".to_string()
+            },
+            vol.created_at.format("%Y-%m-%d %H:%M").to_string(),
+        ]);
     }
 
-    println!("{} cache(s)", caches.len());
+    let mut out = table.render();
+    let _ = writeln!(out, "\n{} container storage volume(s)", storage_vols.len());
+    out
 }
 
 fn print_home_table(home_vols: &[HomeVolume]) {
-    const W_VOLUME: usize = 40;
-    const W_PROJECT: usize = 40;
-    const W_CREATED: usize = 16;
-
     let ctx = UiContext::detect();
-
     ui::intro(&ctx, "Home Volumes");
+    ui::page(&ctx, &format_home_table(home_vols));
+}
 
-    println!(
-        "{} {} {}",
-        pad_str("VOLUME", W_VOLUME, Alignment::Left, None),
-        pad_str("PROJECT", W_PROJECT, Alignment::Left, None),
-        pad_str("CREATED", W_CREATED, Alignment::Left, None),
-    );
-    println!("{}", "-".repeat(W_VOLUME + 1 + W_PROJECT + 1 + W_CREATED));
+fn format_home_table(home_vols: &[HomeVolume]) -> String {
+    let mut table = Table::new(vec![
+        Column::new("VOLUME", 40),
+        Column::new("PROJECT", 40),
+        Column::new("CREATED", 16).fixed(),
+    ]);
 
     for hv in home_vols {
-        let created = hv.created_at.format("%Y-%m-%d %H:%M").to_string();
-        println!(
-            "{} {} {}",
-            pad_str(&hv.name, W_VOLUME, Alignment::Left, None),
-            pad_str(&hv.project_path, W_PROJECT, Alignment::Left, Some("...")),
-            pad_str(&created, W_CREATED, Alignment::Left, None),
-        );
+        table.row(vec![
+            hv.name.clone(),
+            hv.project_path.clone(),
+            hv.created_at.format("%Y-%m-%d %H:%M").to_string(),
+        ]);
     }
 
-    println!();
-    println!("{} home volume(s)", home_vols.len());
+    let mut out = table.render();
+    let _ = writeln!(out, "\n{} home volume(s)", home_vols.len());
+    out
 }
 
+/// Print caches as JSON; size fields are omitted when `total_size` is `None`
 fn print_cache_json(
     caches: &[(CacheVolume, u64)],
+    storage_vols: &[(ContainerStorageVolume, u64)],
     home_vols: &[HomeVolume],
-    total_size: u64,
+    total_size: Option<u64>,
     limit_bytes: u64,
 ) -> MinoResult<()> {
     #[derive(serde::Serialize)]
@@ -221,7 +367,21 @@ fn print_cache_json(
         ecosystem: String,
         hash: String,
         state: String,
-        size_bytes: u64,
+        /// Project of a project-scoped cache
+        #[serde(skip_serializing_if = "Option::is_none")]
+        project: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        size_bytes: Option<u64>,
+        created_at: String,
+    }
+
+    #[derive(serde::Serialize)]
+    struct StorageJson {
+        name: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        project: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        size_bytes: Option<u64>,
         created_at: String,
     }
 
@@ -235,10 +395,13 @@ fn print_cache_json(
     #[derive(serde::Serialize)]
     struct Output {
         caches: Vec<CacheJson>,
+        container_storage: Vec<StorageJson>,
         home_volumes: Vec<HomeJson>,
-        total_size_bytes: u64,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        total_size_bytes: Option<u64>,
         limit_bytes: u64,
-        usage_percent: f64,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        usage_percent: Option<f64>,
     }
 
     let json_caches: Vec<CacheJson> = caches
@@ -248,11 +411,22 @@ fn print_cache_json(
             ecosystem: c.ecosystem.to_string(),
             hash: c.hash.clone(),
             state: c.state.to_string(),
-            size_bytes: *size,
+            project: c.project.clone(),
+            size_bytes: total_size.map(|_| *size),
             created_at: c.created_at.to_rfc3339(),
         })
         .collect();
 
+    let json_storage: Vec<StorageJson> = storage_vols
+        .iter()
+        .map(|(vol, size)| StorageJson {
+            name: vol.name.clone(),
+            project: vol.project.clone(),
+            size_bytes: total_size.map(|_| *size),
+            created_at: vol.created_at.to_rfc3339(),
+        })
+        .collect();
+
     let json_home: Vec<HomeJson> = home_vols
         .iter()
         .map(|hv| HomeJson {
@@ -264,10 +438,11 @@ fn print_cache_json(
 
     let output = Output {
         caches: json_caches,
+        container_storage: json_storage,
         home_volumes: json_home,
         total_size_bytes: total_size,
         limit_bytes,
-        usage_percent: CacheSizeStatus::percentage(total_size, limit_bytes),
+        usage_percent: total_size.map(|total| CacheSizeStatus::percentage(total, limit_bytes)),
     };
 
     println!("{}", serde_json::to_string_pretty(&output)?);
@@ -296,12 +471,27 @@ async fn show_project_info(
     ui::intro(&ctx, "Project Cache Info");
     ui::key_value(&ctx, "Project", &project_dir.display().to_string());
 
-    // Detect lockfiles
-    let lockfiles = {
+    // Detect lockfiles, keyed by the configured layers like a session
+    let run_args = RunArgs::try_parse_from(["mino-run"])
+        .map_err(|e| MinoError::Internal(format!("cache info run arguments: {}", e)))?;
+    let key_extra = configured_cache_key_extra(&run_args, config, &project_dir).await?;
+    let lockfiles: Vec<LockfileInfo> = {
         let dir = project_dir.clone();
-        tokio::task::spawn_blocking(move || detect_lockfiles(&dir))
-            .await
-            .map_err(|e| MinoError::Internal(format!("lockfile detection task failed: {e}")))?
+        let scan_depth = config.cache.scan_depth;
+        let scope = CacheScope::parse(&config.cache.scope)?;
+        tokio::task::spawn_blocking(move || {
+            detect_lockfiles_nested(&dir, scan_depth).map(|found| {
+                found
+                    .into_iter()
+                    .map(|l| {
+                        let extra = key_extra.get(&l.ecosystem).map(String::as_str);
+                        l.scoped(scope, &dir).with_key_extra(extra)
+                    })
+                    .collect()
+            })
+        })
+        .await
+        .map_err(|e| MinoError::Internal(format!("lockfile detection task failed: {e}")))?
     }?;
 
     if lockfiles.is_empty() {
@@ -311,18 +501,26 @@ async fn show_project_info(
 
     ui::section(&ctx, "Detected lockfiles");
     for info in &lockfiles {
+        let nested = match info.nested.len() {
+            0 => String::new(),
+            n => format!(", +{} nested", n),
+        };
         ui::step_info(
             &ctx,
             &format!(
-                "{} (hash: {})",
-                info.path.file_name().unwrap_or_default().to_string_lossy(),
-                &info.hash
+                "{} (hash: {}{})",
+                info.path
+                    .strip_prefix(&project_dir)
+                    .unwrap_or(&info.path)
+                    .display(),
+                &info.hash,
+                nested
             ),
         );
     }
 
     // Get disk usage
-    let sizes = runtime.volume_disk_usage("mino-cache-").await?;
+    let sizes = runtime.volume_disk_usage(cache_labels::MINO_CACHE).await?;
 
     // Check cache states
     ui::section(&ctx, "Cache status");
@@ -401,18 +599,180 @@ async fn show_project_info(
     Ok(())
 }
 
+/// Prefetch dependencies into every incomplete cache of the project
+async fn warm_project(
+    runtime: &dyn ContainerRuntime,
+    project: Option<PathBuf>,
+    image: Option<String>,
+    layers: Vec<String>,
+    config: &Config,
+) -> MinoResult<()> {
+    let ctx = UiContext::detect();
+
+    let project_dir = match project {
+        Some(p) => p.canonicalize().unwrap_or(p),
+        None => env::current_dir().map_err(|e| MinoError::io("getting current directory", e))?,
+    };
+
+    let mut run_args = RunArgs::try_parse_from(["mino-run", "--no-home", "--no-ssh-agent"])
+        .map_err(|e| MinoError::Internal(format!("cache warm run arguments: {}", e)))?;
+    run_args.image = image;
+    run_args.layers = layers;
+
+    ui::intro(&ctx, "Warming Caches");
+    ui::key_value(&ctx, "Project", &project_dir.display().to_string());
+
+    let mut spinner = ui::TaskSpinner::new(&ctx);
+    spinner.start("Detecting lockfiles...");
+    let results = warm_caches(runtime, run_args, config, &project_dir, &ctx, &mut spinner).await;
+    spinner.clear();
+    let results = results?;
+
+    if results.is_empty() {
+        ui::step_info(&ctx, "No lockfiles detected in this project.");
+        return Ok(());
+    }
+
+    ui::section(&ctx, "Caches");
+    for result in &results {
+        print_warm_result(&ctx, result);
+    }
+
+    let failed = results
+        .iter()
+        .filter(|r| matches!(r.outcome, WarmOutcome::Failed(_)))
+        .count();
+    if failed > 0 {
+        return Err(MinoError::User(format!(
+            "{} cache(s) failed to warm; they stay incomplete and are built by the next session",
+            failed
+        )));
+    }
+    ui::outro_success(&ctx, "Caches warm");
+    Ok(())
+}
+
+fn print_warm_result(ctx: &UiContext, result: &WarmResult) {
+    let ecosystem = result.ecosystem.to_string();
+    match &result.outcome {
+        WarmOutcome::Warmed => {
+            ui::step_ok_detail(ctx, &ecosystem, &format!("warmed ({})", result.volume_name))
+        }
+        WarmOutcome::AlreadyWarm => ui::step_ok_detail(ctx, &ecosystem, "already complete"),
+        WarmOutcome::Unsupported => ui::step_warn_hint(
+            ctx,
+            &ecosystem,
+            &format!("no download-only install for {}", result.lockfile),
+        ),
+        WarmOutcome::Failed(reason) => ui::step_error_detail(ctx, &ecosystem, reason),
+    }
+}
+
 /// Garbage collect old and orphaned caches
+/// Summarize recorded cache outcomes per ecosystem, project, and week
+async fn show_cache_stats(
+    since: Option<DateTime<Utc>>,
+    project: Option<PathBuf>,
+    json: bool,
+) -> MinoResult<()> {
+    let project = project.map(|p| p.canonicalize().unwrap_or(p));
+    let records: Vec<CacheUseRecord> = CacheStatsLog::new()
+        .read_since(since)
+        .await?
+        .into_iter()
+        .filter(|r| project.as_ref().is_none_or(|p| &r.project_dir == p))
+        .collect();
+
+    let by_ecosystem = summarize_by(&records, |r| r.ecosystem.to_string());
+    let by_project = summarize_by(&records, |r| r.project_dir.display().to_string());
+    let by_week = summarize_by(&records, |r| week_start(r.at).to_string());
+
+    if json {
+        let out = serde_json::json!({
+            "total": summarize(&records),
+            "by_ecosystem": by_ecosystem,
+            "by_project": by_project,
+            "by_week": by_week,
+        });
+        println!("{}", serde_json::to_string_pretty(&out)?);
+        return Ok(());
+    }
+
+    let ctx = UiContext::detect();
+    ui::intro(&ctx, "Cache Statistics");
+    if records.is_empty() {
+        ui::step_info(
+            &ctx,
+            "No cache use recorded yet. Sessions record it on start.",
+        );
+        return Ok(());
+    }
+
+    let mut out = String::new();
+    for (title, groups) in [
+        ("ECOSYSTEM", &by_ecosystem),
+        ("PROJECT", &by_project),
+        ("WEEK OF", &by_week),
+    ] {
+        out.push_str(&format_stats_table(title, groups));
+        out.push('\n');
+    }
+    ui::page(&ctx, &out);
+    Ok(())
+}
+
+/// Hit/partial/miss counts per group, with the hit rate and bytes reused
+fn format_stats_table(
+    title: &'static str,
+    groups: &std::collections::BTreeMap<String, CacheStatsSummary>,
+) -> String {
+    let mut table = Table::new(vec![
+        Column::new(title, 40),
+        Column::new("USES", 6).fixed(),
+        Column::new("HITS", 6).fixed(),
+        Column::new("PARTIAL", 7).fixed(),
+        Column::new("MISSES", 6).fixed(),
+        Column::new("HIT RATE", 8).fixed(),
+        Column::new("REUSED", 10).fixed(),
+    ]);
+    for (key, summary) in groups {
+        table.row(vec![
+            key.clone(),
+            summary.uses.to_string(),
+            summary.hits.to_string(),
+            summary.partial.to_string(),
+            summary.misses.to_string(),
+            format!("{:.0}%", summary.hit_rate()),
+            format_bytes(summary.bytes_reused),
+        ]);
+    }
+    table.render()
+}
+
+/// A cache `mino cache gc` removes, and why
+struct GcRemoval<'a> {
+    cache: &'a CacheVolume,
+    size: u64,
+    last_used: DateTime<Utc>,
+    reason: String,
+}
+
+/// Remove caches older than `gc_days`, then, with `size_limit` (GB and
+/// strategy), complete caches in strategy order until usage is under the
+/// limit. Also removes home and scratch volumes of deleted projects and
+/// sessions.
 async fn gc_caches(
     runtime: &dyn ContainerRuntime,
     config: &Config,
     days_override: Option<u32>,
     dry_run: bool,
+    size_limit: Option<(u32, GcStrategy)>,
 ) -> MinoResult<()> {
     let ctx = UiContext::detect();
     let gc_days = days_override.unwrap_or(config.cache.gc_days);
 
     // Get current cache size
-    let sizes = runtime.volume_disk_usage("mino-cache-").await?;
+    let sizes = runtime.volume_disk_usage(cache_labels::MINO_CACHE).await?;
     let total_size: u64 = sizes.values().sum();
     let limit_bytes = gb_to_bytes(config.cache.max_total_gb);
 
@@ -428,20 +788,32 @@ async fn gc_caches(
         ),
     );
 
-    let volumes = runtime.volume_list("mino-cache-").await?;
+    let volumes = runtime.volume_list(cache_labels::MINO_CACHE).await?;
     let caches: Vec<CacheVolume> = volumes
         .iter()
         .filter_map(|v| CacheVolume::from_labels(&v.name, &v.labels))
         .collect();
 
+    let sidecars = CacheSidecar::list_all().await?;
+    let last_used = |cache: &CacheVolume| {
+        sidecars
+            .iter()
+            .find(|s| s.volume_name == cache.name)
+            .map_or(cache.created_at, CacheSidecar::last_used)
+    };
+
     // Find caches to remove (age-based)
-    let mut to_remove: Vec<(&CacheVolume, u64)> = Vec::new();
+    let mut to_remove: Vec<GcRemoval> = Vec::new();
 
     if gc_days > 0 {
         for cache in &caches {
             if cache.is_older_than_days(gc_days) {
-                let size = sizes.get(&cache.name).copied().unwrap_or(0);
-                to_remove.push((cache, size));
+                to_remove.push(GcRemoval {
+                    cache,
+                    size: sizes.get(&cache.name).copied().unwrap_or(0),
+                    last_used: last_used(cache),
+                    reason: format!("older than {} days", gc_days),
+                });
             }
         }
     }
@@ -452,35 +824,94 @@ async fn gc_caches(
         } else {
             ui::step_info(&ctx, "Cache GC by age is disabled (gc_days = 0).");
         }
-        return Ok(());
     }
 
-    let bytes_to_free: u64 = to_remove.iter().map(|(_, s)| s).sum();
+    // Then free space down to --max-size, among complete caches
+    if let Some((max_gb, strategy)) = size_limit {
+        let remaining = total_size.saturating_sub(to_remove.iter().map(|r| r.size).sum());
+        let candidates = caches
+            .iter()
+            .filter(|c| !to_remove.iter().any(|r| r.cache.name == c.name))
+            .filter(|c| {
+                sidecars
+                    .iter()
+                    .find(|s| s.volume_name == c.name)
+                    .map_or(c.state, |s| s.state)
+                    == CacheState::Complete
+            })
+            .map(|c| EvictionCandidate {
+                volume_name: c.name.clone(),
+                ecosystem: c.ecosystem,
+                size: sizes.get(&c.name).copied().unwrap_or(0),
+                last_used: last_used(c),
+                created_at: c.created_at,
+            })
+            .collect();
+        let plan = plan_removal(
+            candidates,
+            removal_order(strategy),
+            remaining,
+            gb_to_bytes(max_gb),
+        );
 
-    ui::section(
-        &ctx,
-        &format!(
-            "Found {} cache(s) to remove ({})",
-            to_remove.len(),
-            format_bytes(bytes_to_free)
-        ),
-    );
+        if plan.is_empty() {
+            ui::step_ok(
+                &ctx,
+                &format!(
+                    "Cache usage is within {}.",
+                    format_bytes(gb_to_bytes(max_gb))
+                ),
+            );
+        }
+        for planned in plan {
+            let Some(cache) = caches.iter().find(|c| c.name == planned.volume_name) else {
+                continue;
+            };
+            to_remove.push(GcRemoval {
+                cache,
+                size: planned.size,
+                last_used: planned.last_used,
+                reason: format!("over {} GB ({})", max_gb, strategy_name(strategy)),
+            });
+        }
+    }
 
-    for (cache, size) in &to_remove {
-        let age_days = (Utc::now() - cache.created_at).num_days();
-        let size_str = if *size > 0 {
-            format!(" ({})", format_bytes(*size))
-        } else {
-            String::new()
-        };
-        ui::step_warn(
+    let bytes_to_free: u64 = to_remove.iter().map(|r| r.size).sum();
+
+    if !to_remove.is_empty() {
+        ui::section(
             &ctx,
-            &format!("{} - {} days old{}", cache.name, age_days, size_str),
+            &format!(
+                "Found {} cache(s) to remove ({})",
+                to_remove.len(),
+                format_bytes(bytes_to_free)
+            ),
         );
     }
 
+    if dry_run && !to_remove.is_empty() {
+        to_remove.sort_by_key(|r| std::cmp::Reverse(r.size));
+        ui::page(&ctx, &format_gc_table(&to_remove));
+    } else {
+        for removal in &to_remove {
+            let age_days = (Utc::now() - removal.cache.created_at).num_days();
+            let size_str = if removal.size > 0 {
+                format!(" ({})", format_bytes(removal.size))
+            } else {
+                String::new()
+            };
+            ui::step_warn(
+                &ctx,
+                &format!(
+                    "{} - {} days old{}, {}",
+                    removal.cache.name, age_days, size_str, removal.reason
+                ),
+            );
+        }
+    }
+
     // Check home volumes for deleted projects
-    let home_volumes = runtime.volume_list("mino-home-").await?;
+    let home_volumes = runtime.volume_list(home_labels::MINO_HOME).await?;
     let mut home_to_remove: Vec<HomeVolume> = Vec::new();
 
     for v in &home_volumes {
@@ -505,13 +936,37 @@ async fn gc_caches(
         }
     }
 
-    if dry_run {
-        println!();
-        ui::note(&ctx, "Dry run", "No caches removed.");
+    // Check scratch volumes for sessions that no longer exist
+    let scratch_volumes = runtime.volume_list(scratch_labels::MINO_SCRATCH).await?;
+    let session_names: Vec<String> = SessionManager::new()
+        .await?
+        .list()
+        .await?
+        .into_iter()
+        .map(|s| s.name)
+        .collect();
+    let scratch_to_remove = orphaned_scratch_volumes(&scratch_volumes, &session_names);
+
+    if !scratch_to_remove.is_empty() {
+        ui::section(
+            &ctx,
+            &format!(
+                "Found {} orphaned scratch volume(s) (session deleted)",
+                scratch_to_remove.len()
+            ),
+        );
+        for sv in &scratch_to_remove {
+            ui::step_warn(&ctx, &format!("{} ({})", sv.name, sv.session));
+        }
+    }
+
+    if to_remove.is_empty() && home_to_remove.is_empty() && scratch_to_remove.is_empty() {
         return Ok(());
     }
 
-    if to_remove.is_empty() && home_to_remove.is_empty() {
+    if dry_run {
+        println!();
+        ui::note(&ctx, "Dry run", "No caches removed.");
         return Ok(());
     }
 
@@ -520,10 +975,13 @@ async fn gc_caches(
     spinner.start("Removing caches...");
 
     let mut removed = 0;
-    for (cache, _) in to_remove {
-        debug!("Removing cache: {}", cache.name);
-        runtime.volume_remove(&cache.name).await?;
-        CacheSidecar::delete(&cache.name).await.ok();
+    for removal in to_remove {
+        debug!(
+            "Removing cache: {} ({})",
+            removal.cache.name, removal.reason
+        );
+        runtime.volume_remove(&removal.cache.name).await?;
+        CacheSidecar::delete(&removal.cache.name).await.ok();
         removed += 1;
     }
 
@@ -534,6 +992,13 @@ async fn gc_caches(
         home_removed += 1;
     }
 
+    let mut scratch_removed = 0;
+    for sv in scratch_to_remove {
+        debug!("Removing orphaned scratch volume: {}", sv.name);
+        runtime.volume_remove(&sv.name).await?;
+        scratch_removed += 1;
+    }
+
     let mut summary_parts = Vec::new();
     if removed > 0 {
         summary_parts.push(format!(
@@ -545,32 +1010,253 @@ async fn gc_caches(
     if home_removed > 0 {
         summary_parts.push(format!("{} orphaned home volume(s)", home_removed));
     }
+    if scratch_removed > 0 {
+        summary_parts.push(format!("{} orphaned scratch volume(s)", scratch_removed));
+    }
     spinner.stop(&format!("Removed {}", summary_parts.join(" + ")));
 
     Ok(())
 }
 
-/// Clear cache artifacts (volumes, images, home volumes, or all)
+fn removal_order(strategy: GcStrategy) -> RemovalOrder {
+    match strategy {
+        GcStrategy::Lru => RemovalOrder::LeastRecentlyUsed,
+        GcStrategy::Largest => RemovalOrder::Largest,
+        GcStrategy::Oldest => RemovalOrder::Oldest,
+    }
+}
+
+fn strategy_name(strategy: GcStrategy) -> &'static str {
+    match strategy {
+        GcStrategy::Lru => "least recently used",
+        GcStrategy::Largest => "largest",
+        GcStrategy::Oldest => "oldest",
+    }
+}
+
+/// Dry-run summary of the caches gc would remove, in the given order, with
+/// the total reclaimable space
+fn format_gc_table(removals: &[GcRemoval]) -> String {
+    let mut table = Table::new(vec![
+        Column::new("VOLUME", 40),
+        Column::new("ECOSYSTEM", 10).fixed(),
+        Column::new("SIZE", 10).fixed(),
+        Column::new("CREATED", 10).fixed(),
+        Column::new("LAST USED", 10).fixed(),
+        Column::new("REASON", 30),
+    ]);
+    for removal in removals {
+        table.row(vec![
+            removal.cache.name.clone(),
+            removal.cache.ecosystem.to_string(),
+            if removal.size > 0 {
+                format_bytes(removal.size)
+            } else {
+                "-".to_string()
+            },
+            removal.cache.created_at.format("%Y-%m-%d").to_string(),
+            removal.last_used.format("%Y-%m-%d").to_string(),
+            removal.reason.clone(),
+        ]);
+    }
+
+    let mut out = table.render();
+    out.push('\n');
+    let _ = writeln!(
+        out,
+        "Reclaimable: {}",
+        format_bytes(removals.iter().map(|r| r.size).sum())
+    );
+    out
+}
+
+/// Remove caches whose recorded lockfiles have all been deleted, after
+/// confirmation.
+async fn gc_orphaned_caches(
+    runtime: &dyn ContainerRuntime,
+    dry_run: bool,
+    skip_confirm: bool,
+) -> MinoResult<()> {
+    let ctx = UiContext::detect();
+    ui::intro(&ctx, "Orphaned Cache Collection");
+
+    let volumes = runtime.volume_list(cache_labels::MINO_CACHE).await?;
+    let caches: Vec<CacheVolume> = volumes
+        .iter()
+        .filter_map(|v| CacheVolume::from_labels(&v.name, &v.labels))
+        .collect();
+    let sidecars = CacheSidecar::list_all().await?;
+    let orphans = orphaned_caches(&caches, &sidecars);
+
+    if orphans.is_empty() {
+        ui::step_ok(&ctx, "No orphaned caches.");
+        return Ok(());
+    }
+
+    let sizes = runtime.volume_disk_usage(cache_labels::MINO_CACHE).await?;
+    let bytes_to_free: u64 = orphans
+        .iter()
+        .map(|o| sizes.get(&o.volume_name).copied().unwrap_or(0))
+        .sum();
+
+    ui::section(
+        &ctx,
+        &format!(
+            "Found {} orphaned cache(s) ({}): lockfiles deleted",
+            orphans.len(),
+            format_bytes(bytes_to_free)
+        ),
+    );
+    for orphan in &orphans {
+        ui::step_warn(&ctx, &orphan.volume_name);
+        for lockfile in &orphan.lockfiles {
+            ui::remark(&ctx, &lockfile.display().to_string());
+        }
+    }
+
+    if dry_run {
+        println!();
+        ui::note(&ctx, "Dry run", "No caches removed.");
+        return Ok(());
+    }
+
+    if !skip_confirm {
+        let confirmed = ui::confirm(&ctx, "Remove these caches?", false).await?;
+        if !confirmed {
+            ui::outro_warn(&ctx, "Aborted.");
+            return Ok(());
+        }
+    }
+
+    let mut spinner = ui::TaskSpinner::new(&ctx);
+    spinner.start("Removing orphaned caches...");
+
+    let removed = orphans.len();
+    for orphan in orphans {
+        debug!("Removing orphaned cache: {}", orphan.volume_name);
+        runtime.volume_remove(&orphan.volume_name).await?;
+        CacheSidecar::delete(&orphan.volume_name).await.ok();
+    }
+
+    spinner.stop(&format!(
+        "Removed {} orphaned cache(s), freed {}",
+        removed,
+        format_bytes(bytes_to_free)
+    ));
+
+    Ok(())
+}
+
+/// Existing cache volumes whose sidecar reports every origin lockfile gone.
+///
+/// Sidecars of already-removed volumes are ignored, and caches without a
+/// recorded origin are kept.
+fn orphaned_caches(caches: &[CacheVolume], sidecars: &[CacheSidecar]) -> Vec<CacheSidecar> {
+    sidecars
+        .iter()
+        .filter(|s| caches.iter().any(|c| c.name == s.volume_name))
+        .filter(|s| s.is_orphaned())
+        .cloned()
+        .collect()
+}
+
+/// Scratch volumes whose session record no longer exists.
+fn orphaned_scratch_volumes(
+    volumes: &[crate::orchestration::VolumeInfo],
+    session_names: &[String],
+) -> Vec<ScratchVolume> {
+    volumes
+        .iter()
+        .filter_map(|v| ScratchVolume::from_labels(&v.name, &v.labels))
+        .filter(|sv| !session_names.contains(&sv.session))
+        .collect()
+}
+
+/// Selection of cache volumes for `mino cache clear --volumes`
+#[derive(Debug, Default)]
+struct CacheVolumeFilter {
+    ecosystem: Option<Ecosystem>,
+    /// Canonical project directory
+    project: Option<PathBuf>,
+    state: Option<CacheState>,
+}
+
+impl CacheVolumeFilter {
+    fn new(
+        ecosystem: Option<&str>,
+        project: Option<PathBuf>,
+        state: Option<CacheStateFilter>,
+    ) -> MinoResult<Self> {
+        let ecosystem = ecosystem
+            .map(|name| {
+                CacheVolume::parse_ecosystem(name)
+                    .ok_or_else(|| MinoError::User(format!("Unknown ecosystem: {}", name)))
+            })
+            .transpose()?;
+        Ok(Self {
+            ecosystem,
+            project: project.map(|p| p.canonicalize().unwrap_or(p)),
+            state: state.map(|s| match s {
+                CacheStateFilter::Building => CacheState::Building,
+                CacheStateFilter::Complete => CacheState::Complete,
+            }),
+        })
+    }
+
+    fn is_empty(&self) -> bool {
+        self.ecosystem.is_none() && self.project.is_none() && self.state.is_none()
+    }
+
+    /// Whether the cache matches every filter. A cache belongs to a project
+    /// when it is scoped to it or its sidecar recorded a lockfile inside it.
+    fn matches(&self, cache: &CacheVolume, sidecar: Option<&CacheSidecar>) -> bool {
+        let state = sidecar.map_or(cache.state, |s| s.state);
+        self.ecosystem.is_none_or(|e| e == cache.ecosystem)
+            && self.state.is_none_or(|s| s == state)
+            && self.project.as_ref().is_none_or(|project| {
+                cache.project.as_deref() == Some(&*project.to_string_lossy())
+                    || sidecar.is_some_and(|s| s.lockfiles.iter().any(|l| l.starts_with(project)))
+            })
+    }
+}
+
+/// Clear cache artifacts (volumes, images, home volumes, scratch volumes, or all)
 async fn clear_artifacts(
     runtime: &dyn ContainerRuntime,
     clear_volumes: bool,
     clear_images: bool,
     clear_home: bool,
+    clear_scratch: bool,
+    filter: &CacheVolumeFilter,
     skip_confirm: bool,
 ) -> MinoResult<()> {
     let ctx = UiContext::detect();
 
     // Gather what will be deleted
-    let volumes = if clear_volumes {
-        runtime.volume_list("mino-cache-").await?
+    let mut volumes = if clear_volumes {
+        runtime.volume_list(cache_labels::MINO_CACHE).await?
     } else {
         vec![]
     };
 
+    // Filters select dependency caches only, never container storage
+    if !filter.is_empty() && !volumes.is_empty() {
+        let sidecars: HashMap<String, CacheSidecar> = CacheSidecar::list_all()
+            .await
+            .unwrap_or_default()
+            .into_iter()
+            .map(|s| (s.volume_name.clone(), s))
+            .collect();
+        volumes.retain(|v| {
+            CacheVolume::from_labels(&v.name, &v.labels)
+                .is_some_and(|cache| filter.matches(&cache, sidecars.get(&v.name)))
+        });
+    }
+
     let sizes = if !volumes.is_empty() {
-        runtime.volume_disk_usage("mino-cache-").await?
+        runtime.volume_disk_usage(cache_labels::MINO_CACHE).await?
     } else {
-        std::collections::HashMap::new()
+        HashMap::new()
     };
     let total_volume_size: u64 = volumes
         .iter()
@@ -578,18 +1264,29 @@ async fn clear_artifacts(
         .sum();
 
     let images = if clear_images {
-        runtime.image_list_prefixed("mino-composed-").await?
+        crate::version::list_composed_images(runtime).await?
     } else {
         vec![]
     };
 
     let home_volumes = if clear_home {
-        runtime.volume_list("mino-home-").await?
+        runtime.volume_list(home_labels::MINO_HOME).await?
     } else {
         vec![]
     };
 
-    if volumes.is_empty() && images.is_empty() && home_volumes.is_empty() && !clear_images {
+    let scratch_volumes = if clear_scratch {
+        runtime.volume_list(scratch_labels::MINO_SCRATCH).await?
+    } else {
+        vec![]
+    };
+
+    if volumes.is_empty()
+        && images.is_empty()
+        && home_volumes.is_empty()
+        && scratch_volumes.is_empty()
+        && !clear_images
+    {
         ui::intro(&ctx, "Cache Clear");
         ui::step_info(&ctx, "Nothing to clear.");
         return Ok(());
@@ -644,6 +1341,19 @@ async fn clear_artifacts(
         }
     }
 
+    if !scratch_volumes.is_empty() {
+        ui::step_warn(
+            &ctx,
+            &format!(
+                "This will remove {} scratch volume(s)",
+                scratch_volumes.len()
+            ),
+        );
+        for vol in &scratch_volumes {
+            ui::remark(&ctx, &vol.name);
+        }
+    }
+
     // Single confirmation
     if !skip_confirm {
         let confirmed = ui::confirm(&ctx, "Are you sure you want to proceed?", false).await?;
@@ -682,6 +1392,12 @@ async fn clear_artifacts(
         runtime.volume_remove(&vol.name).await?;
     }
 
+    // Remove scratch volumes
+    let scratch_count = scratch_volumes.len();
+    for vol in scratch_volumes {
+        runtime.volume_remove(&vol.name).await?;
+    }
+
     // Summary
     let mut parts = Vec::new();
     if vol_count > 0 {
@@ -700,6 +1416,9 @@ async fn clear_artifacts(
     if home_count > 0 {
         parts.push(format!("{} home volume(s)", home_count));
     }
+    if scratch_count > 0 {
+        parts.push(format!("{} scratch volume(s)", scratch_count));
+    }
     if parts.is_empty() {
         spinner.stop("Nothing to clear");
     } else {
@@ -712,9 +1431,10 @@ async fn clear_artifacts(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::orchestration::mock::{MockResponse, MockRuntime};
-    use crate::orchestration::VolumeInfo;
+    use crate::orchestration::mock::{MockResponse, MockRuntime, RecordingExecutor};
+    use crate::orchestration::{NativePodmanRuntime, VolumeInfo};
     use std::collections::HashMap;
+    use std::sync::Arc;
 
     #[test]
     fn cache_state_display() {
@@ -753,13 +1473,28 @@ mod tests {
         let mock = MockRuntime::new();
         let config = Config::default();
 
-        list_caches(&mock, OutputFormat::Plain, &config)
+        list_caches(&mock, OutputFormat::Plain, true, &config)
             .await
             .unwrap();
-        // Called twice: once for mino-cache-, once for mino-home-
+        // Called twice: once for cache volumes, once for home volumes
         mock.assert_called("volume_list", 2);
     }
 
+    #[tokio::test]
+    async fn list_without_sizes_skips_disk_usage() {
+        let mock = MockRuntime::new().on(
+            "volume_list",
+            Ok(MockResponse::VolumeInfoVec(vec![mino_cache_volume(
+                "mino-cache-npm-abc123",
+            )])),
+        );
+
+        list_caches(&mock, OutputFormat::Plain, false, &Config::default())
+            .await
+            .unwrap();
+        mock.assert_called("volume_disk_usage", 0);
+    }
+
     #[tokio::test]
     async fn clear_volumes_removes_all() {
         let volumes = vec![
@@ -775,15 +1510,75 @@ mod tests {
             .on("volume_list", Ok(MockResponse::VolumeInfoVec(volumes)))
             .on("volume_disk_usage", Ok(MockResponse::DiskUsageMap(sizes)));
 
-        clear_artifacts(&mock, true, false, false, true)
-            .await
-            .unwrap();
+        clear_artifacts(
+            &mock,
+            true,
+            false,
+            false,
+            false,
+            &CacheVolumeFilter::default(),
+            true,
+        )
+        .await
+        .unwrap();
 
         mock.assert_called("volume_remove", 2);
         mock.assert_called_with("volume_remove", &["mino-cache-npm-abc123"]);
         mock.assert_called_with("volume_remove", &["mino-cache-cargo-def456"]);
     }
 
+    #[tokio::test]
+    async fn clear_volumes_filtered_by_ecosystem() {
+        let mut cargo = mino_cache_volume("mino-cache-cargo-def456");
+        cargo
+            .labels
+            .insert(cache_labels::ECOSYSTEM.to_string(), "cargo".to_string());
+        let volumes = vec![mino_cache_volume("mino-cache-npm-abc123"), cargo];
+        let mock = MockRuntime::new()
+            .on("volume_list", Ok(MockResponse::VolumeInfoVec(volumes)))
+            .on(
+                "volume_disk_usage",
+                Ok(MockResponse::DiskUsageMap(HashMap::new())),
+            );
+        let filter = CacheVolumeFilter::new(Some("cargo"), None, None).unwrap();
+
+        clear_artifacts(&mock, true, false, false, false, &filter, true)
+            .await
+            .unwrap();
+
+        mock.assert_called("volume_remove", 1);
+        mock.assert_called_with("volume_remove", &["mino-cache-cargo-def456"]);
+    }
+
+    #[test]
+    fn cache_volume_filter_matches() {
+        let mut cache =
+            CacheVolume::new(Ecosystem::Npm, "abc123".to_string(), CacheState::Complete);
+        let mut sidecar = CacheSidecar::new(
+            cache.name.clone(),
+            Ecosystem::Npm,
+            "abc123".to_string(),
+            CacheState::Building,
+        );
+        sidecar.lockfiles = vec![PathBuf::from("/src/app/package-lock.json")];
+
+        let filter = |state, project: Option<&str>| CacheVolumeFilter {
+            ecosystem: Some(Ecosystem::Npm),
+            project: project.map(PathBuf::from),
+            state,
+        };
+        // The sidecar state wins over the label
+        assert!(filter(Some(CacheState::Building), None).matches(&cache, Some(&sidecar)));
+        assert!(!filter(Some(CacheState::Building), None).matches(&cache, None));
+        // Shared caches belong to the projects whose lockfiles they recorded
+        assert!(filter(None, Some("/src/app")).matches(&cache, Some(&sidecar)));
+        assert!(!filter(None, Some("/src/other")).matches(&cache, Some(&sidecar)));
+        cache.project = Some("/src/other".to_string());
+        assert!(filter(None, Some("/src/other")).matches(&cache, None));
+
+        assert!(CacheVolumeFilter::new(Some("npmx"), None, None).is_err());
+    }
+
     #[tokio::test]
     async fn clear_images_removes_composed_and_base() {
         let images = vec![
@@ -794,9 +1589,17 @@ mod tests {
         let mock =
             MockRuntime::new().on("image_list_prefixed", Ok(MockResponse::StringVec(images)));
 
-        clear_artifacts(&mock, false, true, false, true)
-            .await
-            .unwrap();
+        clear_artifacts(
+            &mock,
+            false,
+            true,
+            false,
+            false,
+            &CacheVolumeFilter::default(),
+            true,
+        )
+        .await
+        .unwrap();
 
         mock.assert_called("container_prune", 1);
         // 2 composed + 1 base image
@@ -827,9 +1630,17 @@ mod tests {
             Ok(MockResponse::VolumeInfoVec(vec![home_vol])),
         );
 
-        clear_artifacts(&mock, false, false, true, true)
-            .await
-            .unwrap();
+        clear_artifacts(
+            &mock,
+            false,
+            false,
+            true,
+            false,
+            &CacheVolumeFilter::default(),
+            true,
+        )
+        .await
+        .unwrap();
 
         mock.assert_called("volume_remove", 1);
         mock.assert_called_with("volume_remove", &["mino-home-abc123def456"]);
@@ -842,9 +1653,17 @@ mod tests {
         let mock =
             MockRuntime::new().on("image_list_prefixed", Ok(MockResponse::StringVec(images)));
 
-        clear_artifacts(&mock, false, true, false, true)
-            .await
-            .unwrap();
+        clear_artifacts(
+            &mock,
+            false,
+            true,
+            false,
+            false,
+            &CacheVolumeFilter::default(),
+            true,
+        )
+        .await
+        .unwrap();
 
         mock.assert_called("container_prune", 1);
         mock.assert_called("image_remove", 2);
@@ -864,8 +1683,221 @@ mod tests {
             .on("volume_list", Ok(MockResponse::VolumeInfoVec(vec![vol])));
 
         let config = Config::default();
-        gc_caches(&mock, &config, Some(30), true).await.unwrap();
+        gc_caches(&mock, &config, Some(30), true, None)
+            .await
+            .unwrap();
 
         mock.assert_called("volume_remove", 0);
     }
+
+    #[tokio::test]
+    async fn gc_max_size_removes_largest_first() {
+        const GB: u64 = 1024 * 1024 * 1024;
+        let sizes = HashMap::from([
+            ("mino-cache-npm-large".to_string(), 3 * GB),
+            ("mino-cache-npm-small".to_string(), GB),
+        ]);
+        let mock = MockRuntime::new()
+            .on("volume_disk_usage", Ok(MockResponse::DiskUsageMap(sizes)))
+            .on(
+                "volume_list",
+                Ok(MockResponse::VolumeInfoVec(vec![
+                    mino_cache_volume("mino-cache-npm-small"),
+                    mino_cache_volume("mino-cache-npm-large"),
+                ])),
+            );
+
+        let config = Config::default();
+        gc_caches(
+            &mock,
+            &config,
+            Some(0),
+            false,
+            Some((2, GcStrategy::Largest)),
+        )
+        .await
+        .unwrap();
+
+        mock.assert_called("volume_remove", 1);
+        mock.assert_called_with("volume_remove", &["mino-cache-npm-large"]);
+    }
+
+    #[test]
+    fn stats_table_shows_hit_rate() {
+        let summary = CacheStatsSummary {
+            uses: 4,
+            hits: 3,
+            partial: 0,
+            misses: 1,
+            bytes_reused: 3 * 1024 * 1024,
+        };
+        let groups = std::collections::BTreeMap::from([("cargo".to_string(), summary)]);
+        let out = format_stats_table("ECOSYSTEM", &groups);
+        assert!(out.contains("ECOSYSTEM"));
+        assert!(out.contains("75%"));
+        assert!(out.contains("3.0 MB"));
+    }
+
+    #[test]
+    fn gc_table_totals_reclaimable_space() {
+        let cache = CacheVolume::new(Ecosystem::Cargo, "abc123".to_string(), CacheState::Complete);
+        let removals = [GcRemoval {
+            cache: &cache,
+            size: 2048,
+            last_used: cache.created_at,
+            reason: "older than 30 days".to_string(),
+        }];
+        let out = format_gc_table(&removals);
+        assert!(out.contains("mino-cache-cargo-abc123"));
+        assert!(out.contains("older than 30 days"));
+        assert!(out.contains("Reclaimable: 2.0 KB"));
+    }
+
+    #[tokio::test]
+    async fn clear_scratch_removes_scratch_volumes() {
+        let scratch_vol = VolumeInfo {
+            name: "mino-scratch-s1".to_string(),
+            labels: ScratchVolume::labels("s1"),
+            mountpoint: None,
+            created_at: None,
+            size_bytes: None,
+        };
+        let mock = MockRuntime::new().on(
+            "volume_list",
+            Ok(MockResponse::VolumeInfoVec(vec![scratch_vol])),
+        );
+
+        clear_artifacts(
+            &mock,
+            false,
+            false,
+            false,
+            true,
+            &CacheVolumeFilter::default(),
+            true,
+        )
+        .await
+        .unwrap();
+
+        mock.assert_called_with("volume_remove", &["mino-scratch-s1"]);
+    }
+
+    #[test]
+    fn orphaned_scratch_skips_live_sessions() {
+        let vol = |session: &str| VolumeInfo {
+            name: format!("mino-scratch-{session}"),
+            labels: ScratchVolume::labels(session),
+            mountpoint: None,
+            created_at: None,
+            size_bytes: None,
+        };
+        let volumes = vec![vol("live"), vol("gone")];
+
+        let orphans = orphaned_scratch_volumes(&volumes, &["live".to_string()]);
+        assert_eq!(orphans.len(), 1);
+        assert_eq!(orphans[0].name, "mino-scratch-gone");
+    }
+
+    #[test]
+    fn orphaned_caches_need_existing_volume_and_deleted_lockfiles() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let live_lockfile = temp.path().join("package-lock.json");
+        std::fs::write(&live_lockfile, "{}").unwrap();
+        let deleted_lockfile = temp.path().join("gone").join("package-lock.json");
+
+        let sidecar = |name: &str, lockfiles: &[&std::path::Path]| {
+            let mut s = CacheSidecar::new(
+                name.to_string(),
+                crate::cache::Ecosystem::Npm,
+                "abcdef123456".to_string(),
+                CacheState::Complete,
+            );
+            for lockfile in lockfiles {
+                s.record_lockfile(lockfile);
+            }
+            s
+        };
+        let sidecars = vec![
+            sidecar("mino-cache-npm-orphan", &[&deleted_lockfile]),
+            sidecar("mino-cache-npm-live", &[&deleted_lockfile, &live_lockfile]),
+            sidecar("mino-cache-npm-legacy", &[]),
+            sidecar("mino-cache-npm-removed", &[&deleted_lockfile]),
+        ];
+        let caches: Vec<CacheVolume> = [
+            "mino-cache-npm-orphan",
+            "mino-cache-npm-live",
+            "mino-cache-npm-legacy",
+        ]
+        .iter()
+        .filter_map(|name| {
+            let v = mino_cache_volume(name);
+            CacheVolume::from_labels(&v.name, &v.labels)
+        })
+        .collect();
+
+        let orphans = orphaned_caches(&caches, &sidecars);
+        let names: Vec<&str> = orphans.iter().map(|o| o.volume_name.as_str()).collect();
+        assert_eq!(names, vec!["mino-cache-npm-orphan"]);
+    }
+
+    // -- End-to-end through the podman CLI runtime --
+
+    const PODMAN_VOLUME_LS: &str = r#"[{
+        "Name": "mino-cache-npm-abc123",
+        "Labels": {"io.mino.cache": "true", "io.mino.cache.ecosystem": "npm",
+                   "io.mino.cache.hash": "abcdef123456", "io.mino.cache.state": "complete"},
+        "Mountpoint": "/var/lib/volumes/mino-cache-npm-abc123/_data",
+        "CreatedAt": "2026-01-01T00:00:00Z"
+    }]"#;
+
+    #[tokio::test]
+    async fn list_sizes_volumes_through_podman_cli() {
+        let exec = Arc::new(
+            RecordingExecutor::new()
+                .on_stdout("podman volume ls", PODMAN_VOLUME_LS)
+                .on_stdout("podman volume ls", "[]")
+                .on_stdout("podman volume ls", PODMAN_VOLUME_LS)
+                .on_stdout(
+                    "podman volume inspect",
+                    "/var/lib/volumes/mino-cache-npm-abc123/_data\n",
+                )
+                .on_stdout(
+                    "du -sb",
+                    "4096\t/var/lib/volumes/mino-cache-npm-abc123/_data\n",
+                ),
+        );
+        let runtime = NativePodmanRuntime::with_executor(exec.clone());
+
+        list_caches(&runtime, OutputFormat::Plain, true, &Config::default())
+            .await
+            .unwrap();
+
+        exec.assert_ran("podman volume ls --format json");
+        exec.assert_ran("podman volume inspect mino-cache-npm-abc123 --format {{.Mountpoint}}");
+        exec.assert_ran("du -sb /var/lib/volumes/mino-cache-npm-abc123/_data");
+    }
+
+    #[tokio::test]
+    async fn clear_volumes_removes_through_podman_cli() {
+        let exec = Arc::new(
+            RecordingExecutor::new()
+                .on_stdout("podman volume ls", PODMAN_VOLUME_LS)
+                .on_stdout("podman volume ls", PODMAN_VOLUME_LS),
+        );
+        let runtime = NativePodmanRuntime::with_executor(exec.clone());
+
+        clear_artifacts(
+            &runtime,
+            true,
+            false,
+            false,
+            false,
+            &CacheVolumeFilter::default(),
+            true,
+        )
+        .await
+        .unwrap();
+
+        exec.assert_ran("podman volume rm -f mino-cache-npm-abc123");
+    }
 }
@@ -0,0 +1,133 @@
+//! Checkpoint command - save a running session's state to disk
+
+use crate::audit::AuditLog;
+use crate::cli::args::CheckpointArgs;
+use crate::config::{Config, ConfigManager};
+use crate::error::{MinoError, MinoResult};
+use crate::orchestration::{create_runtime_with_backend, ContainerRuntime};
+use crate::sandbox::RuntimeMode;
+use crate::session::{Session, SessionManager, SessionStatus};
+use crate::ui::{self, TaskSpinner, UiContext};
+use console::style;
+use std::path::{Path, PathBuf};
+
+/// Execute the checkpoint command
+pub async fn execute(args: CheckpointArgs, config: &Config) -> MinoResult<()> {
+    let ctx = UiContext::detect();
+    let manager = SessionManager::new().await?;
+    let session = manager
+        .get(&args.session)
+        .await?
+        .ok_or_else(|| MinoError::SessionNotFound(args.session.clone()))?;
+
+    if session.runtime_mode == Some(RuntimeMode::Native) {
+        return Err(MinoError::NativeUnsupported {
+            feature: "mino checkpoint".to_string(),
+        });
+    }
+
+    let archive = archive_path(&session.name);
+    if let Some(parent) = archive.parent() {
+        tokio::fs::create_dir_all(parent)
+            .await
+            .map_err(|e| MinoError::io("creating checkpoints directory", e))?;
+    }
+
+    let styled_name = style(&session.name).cyan();
+    let runtime = create_runtime_with_backend(config, session.backend)?;
+    let mut spinner = TaskSpinner::new(&ctx);
+    spinner.start(&format!("Checkpointing session {}...", styled_name));
+    checkpoint_session(&session, &*runtime, &archive, args.leave_running).await?;
+
+    manager.set_checkpoint(&session.name, &archive).await?;
+    if !args.leave_running {
+        manager
+            .update_status(&session.name, SessionStatus::Stopped)
+            .await?;
+    }
+
+    AuditLog::new(config)
+        .log(
+            "session.checkpointed",
+            &serde_json::json!({
+                "name": &session.name,
+                "archive": archive.display().to_string(),
+                "leave_running": args.leave_running,
+            }),
+        )
+        .await;
+
+    spinner.stop(&format!(
+        "Session {} checkpointed to {}",
+        styled_name,
+        archive.display()
+    ));
+    ui::remark(
+        &ctx,
+        &format!("Restore with: mino restore {}", session.name),
+    );
+    Ok(())
+}
+
+/// Archive location for a session's checkpoint; a new checkpoint replaces the old one
+fn archive_path(session_name: &str) -> PathBuf {
+    ConfigManager::checkpoints_dir().join(format!("{}.tar.gz", session_name))
+}
+
+/// Checkpoint a running session's container into `archive`
+async fn checkpoint_session(
+    session: &Session,
+    runtime: &dyn ContainerRuntime,
+    archive: &Path,
+    leave_running: bool,
+) -> MinoResult<()> {
+    if session.status != SessionStatus::Running {
+        return Err(MinoError::User(format!(
+            "Session '{}' is {}; only running sessions can be checkpointed",
+            session.name, session.status
+        )));
+    }
+    let container_id = session
+        .container_id
+        .as_deref()
+        .ok_or_else(|| MinoError::ContainerNotFound(session.name.clone()))?;
+
+    runtime
+        .checkpoint(container_id, archive, leave_running)
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::orchestration::mock::{test_session, MockRuntime};
+
+    #[tokio::test]
+    async fn checkpoint_session_exports_container() {
+        let session = test_session("s", SessionStatus::Running, Some("cid"));
+        let mock = MockRuntime::new();
+
+        checkpoint_session(&session, &mock, Path::new("/tmp/s.tar.gz"), true)
+            .await
+            .unwrap();
+        mock.assert_called_with("checkpoint", &["cid", "/tmp/s.tar.gz", "true"]);
+    }
+
+    #[tokio::test]
+    async fn checkpoint_session_requires_running() {
+        let session = test_session("s", SessionStatus::Paused, Some("cid"));
+        let mock = MockRuntime::new();
+
+        let err = checkpoint_session(&session, &mock, Path::new("/tmp/s.tar.gz"), false)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("is paused"));
+        mock.assert_no_calls();
+    }
+
+    #[test]
+    fn archive_path_is_per_session() {
+        let path = archive_path("swift-fox");
+        assert!(path.ends_with("checkpoints/swift-fox.tar.gz"));
+    }
+}
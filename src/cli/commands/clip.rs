@@ -0,0 +1,317 @@
+//! Clip command - opt-in clipboard bridge between the host and a session
+//!
+//! Text moves through a file inside the container: agents write what they
+//! want to hand over to [`CLIPBOARD_PATH`] and read what was put there.
+//! Disabled unless `session.clipboard = true`; every transfer is audited.
+
+use crate::audit::AuditLog;
+use crate::cli::args::{ClipAction, ClipArgs};
+use crate::config::Config;
+use crate::error::{MinoError, MinoResult};
+use crate::orchestration::{create_runtime_with_backend, ContainerRuntime};
+use crate::sandbox::RuntimeMode;
+use crate::session::SessionManager;
+use crate::ui::{self, UiContext};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use std::io::{IsTerminal, Read, Write};
+use std::process::Stdio;
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+use tracing::debug;
+
+/// File inside the container that holds the session's side of the clipboard
+pub const CLIPBOARD_PATH: &str = "/tmp/mino-clipboard";
+
+/// Largest clipboard transfer, in either direction
+const MAX_CLIPBOARD_BYTES: usize = 1024 * 1024;
+
+/// Execute the clip command
+pub async fn execute(args: ClipArgs, config: &Config) -> MinoResult<()> {
+    if !config.session.clipboard {
+        return Err(MinoError::User(
+            "The clipboard bridge is disabled. Enable it with: mino config set session.clipboard true"
+                .to_string(),
+        ));
+    }
+
+    let ctx = UiContext::detect();
+    let name = match &args.action {
+        ClipAction::Get { session, .. } | ClipAction::Put { session, .. } => session,
+    };
+
+    let manager = SessionManager::new().await?;
+    let session = manager
+        .get(name)
+        .await?
+        .ok_or_else(|| MinoError::SessionNotFound(name.to_string()))?;
+
+    if session.runtime_mode == Some(RuntimeMode::Native) {
+        return Err(MinoError::NativeUnsupported {
+            feature: "mino clip".to_string(),
+        });
+    }
+    let container_id = session
+        .container_id
+        .as_deref()
+        .ok_or_else(|| MinoError::ContainerNotFound(session.name.clone()))?;
+    let runtime = create_runtime_with_backend(config, session.backend)?;
+
+    let (direction, bytes, message) = match args.action {
+        ClipAction::Get { print, .. } => {
+            let text = read_session_clipboard(&*runtime, container_id).await?;
+            // --print keeps stdout to the text alone, for pipes
+            let message = if print {
+                print!("{}", text);
+                None
+            } else {
+                write_host_clipboard(&text).await?;
+                Some(format!(
+                    "Copied {} bytes from {}'s clipboard",
+                    text.len(),
+                    session.name
+                ))
+            };
+            ("get", text.len(), message)
+        }
+        ClipAction::Put { stdin, .. } => {
+            let text = if stdin {
+                let mut text = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut text)
+                    .map_err(|e| MinoError::io("reading stdin", e))?;
+                text
+            } else {
+                read_host_clipboard().await?
+            };
+            check_size(&text)?;
+            write_session_clipboard(&*runtime, container_id, &text).await?;
+            let message = format!(
+                "Put {} bytes on {}'s clipboard ({})",
+                text.len(),
+                session.name,
+                CLIPBOARD_PATH
+            );
+            ("put", text.len(), Some(message))
+        }
+    };
+
+    // Sizes only: clipboard contents may be secrets
+    AuditLog::new(config)
+        .log(
+            "session.clipboard",
+            &serde_json::json!({
+                "name": &session.name,
+                "direction": direction,
+                "bytes": bytes,
+            }),
+        )
+        .await;
+
+    if let Some(message) = message {
+        ui::step_ok(&ctx, &message);
+    }
+    Ok(())
+}
+
+/// Copy [`CLIPBOARD_PATH`] out of the container
+async fn read_session_clipboard(
+    runtime: &dyn ContainerRuntime,
+    container_id: &str,
+) -> MinoResult<String> {
+    let tmp = scratch_file();
+    let copied = runtime
+        .copy_from_container(container_id, CLIPBOARD_PATH, &tmp)
+        .await;
+    let read = match copied {
+        Ok(()) => tokio::fs::read(&tmp)
+            .await
+            .map_err(|e| MinoError::io(format!("reading {}", tmp.display()), e)),
+        Err(e) => Err(e),
+    };
+    let _ = tokio::fs::remove_file(&tmp).await;
+
+    let bytes = read.map_err(|e| {
+        debug!("Reading session clipboard failed: {}", e);
+        MinoError::User(format!(
+            "Nothing on the session clipboard. Write text to {} inside the session first.",
+            CLIPBOARD_PATH
+        ))
+    })?;
+    let text = String::from_utf8(bytes)
+        .map_err(|_| MinoError::User("The session clipboard is not UTF-8 text".to_string()))?;
+    check_size(&text)?;
+    Ok(text)
+}
+
+/// Copy `text` into the container at [`CLIPBOARD_PATH`]
+async fn write_session_clipboard(
+    runtime: &dyn ContainerRuntime,
+    container_id: &str,
+    text: &str,
+) -> MinoResult<()> {
+    let tmp = scratch_file();
+    tokio::fs::write(&tmp, text)
+        .await
+        .map_err(|e| MinoError::io(format!("writing {}", tmp.display()), e))?;
+    let result = runtime
+        .copy_to_container(container_id, &tmp, CLIPBOARD_PATH)
+        .await;
+    let _ = tokio::fs::remove_file(&tmp).await;
+    result
+}
+
+fn scratch_file() -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("mino-clipboard-{}", uuid::Uuid::new_v4().simple()))
+}
+
+fn check_size(text: &str) -> MinoResult<()> {
+    if text.len() > MAX_CLIPBOARD_BYTES {
+        return Err(MinoError::User(format!(
+            "Clipboard text is {} bytes; the limit is {} bytes. Use mino cp for files.",
+            text.len(),
+            MAX_CLIPBOARD_BYTES
+        )));
+    }
+    Ok(())
+}
+
+/// Host clipboard programs: (copy command, paste command)
+type ClipboardTool = (&'static [&'static str], &'static [&'static str]);
+
+/// Clipboard tools to try for this host, best first
+fn clipboard_tools(os: &str, wayland: bool, x11: bool) -> Vec<ClipboardTool> {
+    let mut tools: Vec<ClipboardTool> = Vec::new();
+    if os == "macos" {
+        tools.push((&["pbcopy"], &["pbpaste"]));
+    }
+    if wayland {
+        tools.push((&["wl-copy"], &["wl-paste", "--no-newline"]));
+    }
+    if x11 {
+        tools.push((
+            &["xclip", "-selection", "clipboard"],
+            &["xclip", "-selection", "clipboard", "-o"],
+        ));
+        tools.push((
+            &["xsel", "--clipboard", "--input"],
+            &["xsel", "--clipboard", "--output"],
+        ));
+    }
+    tools
+}
+
+fn host_clipboard_tools() -> Vec<ClipboardTool> {
+    clipboard_tools(
+        std::env::consts::OS,
+        std::env::var_os("WAYLAND_DISPLAY").is_some(),
+        std::env::var_os("DISPLAY").is_some(),
+    )
+}
+
+/// Read the host clipboard with the first tool that works
+async fn read_host_clipboard() -> MinoResult<String> {
+    for (_, paste) in host_clipboard_tools() {
+        let output = Command::new(paste[0])
+            .args(&paste[1..])
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .output()
+            .await;
+        match output {
+            Ok(out) if out.status.success() => {
+                return Ok(String::from_utf8_lossy(&out.stdout).into_owned());
+            }
+            Ok(_) => debug!("{} failed", paste[0]),
+            Err(e) => debug!("{} unavailable: {}", paste[0], e),
+        }
+    }
+    Err(MinoError::User(
+        "No clipboard tool found (pbpaste, wl-paste, xclip, or xsel). \
+         Pipe the text in instead: mino clip put <session> --stdin"
+            .to_string(),
+    ))
+}
+
+/// Set the host clipboard, falling back to an OSC 52 escape sequence
+/// (understood by most terminals, including over SSH)
+async fn write_host_clipboard(text: &str) -> MinoResult<()> {
+    for (copy, _) in host_clipboard_tools() {
+        let child = Command::new(copy[0])
+            .args(&copy[1..])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn();
+        let Ok(mut child) = child else {
+            debug!("{} unavailable", copy[0]);
+            continue;
+        };
+        if let Some(mut stdin) = child.stdin.take() {
+            let _ = stdin.write_all(text.as_bytes()).await;
+        }
+        if child.wait().await.map(|s| s.success()).unwrap_or(false) {
+            return Ok(());
+        }
+    }
+
+    if !std::io::stdout().is_terminal() {
+        return Err(MinoError::User(
+            "No clipboard tool found (pbcopy, wl-copy, xclip, or xsel). \
+             Print the text instead: mino clip get <session> --print"
+                .to_string(),
+        ));
+    }
+    let mut stdout = std::io::stdout();
+    write!(stdout, "{}", osc52(text))
+        .and_then(|()| stdout.flush())
+        .map_err(|e| MinoError::io("writing to terminal", e))
+}
+
+/// OSC 52 "set clipboard" escape sequence for `text`
+fn osc52(text: &str) -> String {
+    format!("\x1b]52;c;{}\x07", STANDARD.encode(text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::orchestration::mock::MockRuntime;
+
+    #[test]
+    fn osc52_wraps_encoded_text() {
+        assert_eq!(osc52("hi"), "\x1b]52;c;aGk=\x07");
+    }
+
+    #[test]
+    fn clipboard_tools_follow_the_display() {
+        assert_eq!(clipboard_tools("macos", false, false)[0].0, ["pbcopy"]);
+        assert!(clipboard_tools("linux", false, false).is_empty());
+        assert_eq!(clipboard_tools("linux", true, true)[0].0, ["wl-copy"]);
+        assert_eq!(clipboard_tools("linux", false, true).len(), 2);
+    }
+
+    #[test]
+    fn oversized_text_is_rejected() {
+        assert!(check_size("diff").is_ok());
+        assert!(check_size(&"x".repeat(MAX_CLIPBOARD_BYTES + 1)).is_err());
+    }
+
+    #[tokio::test]
+    async fn put_copies_into_the_clipboard_path() {
+        let mock = MockRuntime::new();
+        write_session_clipboard(&mock, "abc123", "token")
+            .await
+            .unwrap();
+        mock.assert_called("copy_to_container", 1);
+    }
+
+    #[tokio::test]
+    async fn get_without_clipboard_file_is_a_user_error() {
+        let mock = MockRuntime::new();
+        // The mock copies nothing, so there is no file to read back
+        let err = read_session_clipboard(&mock, "abc123").await.unwrap_err();
+        assert!(err.to_string().contains(CLIPBOARD_PATH));
+    }
+}
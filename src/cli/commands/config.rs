@@ -2,8 +2,13 @@
 
 use crate::cli::args::{ConfigAction, ConfigArgs};
 use crate::config::{Config, ConfigManager};
+use crate::credentials::files::CredentialDelivery;
 use crate::error::{MinoError, MinoResult};
-use crate::ui::{self, UiContext};
+use crate::orchestration::{
+    device_host_path, parse_extra_host, parse_hostname, parse_platform, parse_userns, tmpfs_target,
+    ulimit_name, Backend, PullPolicy, VmProvider,
+};
+use crate::ui::{self, StyleMode, UiContext};
 use tokio::fs;
 
 /// Execute the config command
@@ -68,6 +73,12 @@ async fn set_value(
     value: &str,
 ) -> MinoResult<()> {
     let ctx = UiContext::detect();
+
+    // Arrays and tables go through the schema-checked generic path
+    if is_compound_literal(value) && !is_document_key(key) {
+        return set_generic_value(manager, config, key, value).await;
+    }
+
     let mut config = config.clone();
 
     // Parse dot-separated key path
@@ -77,13 +88,137 @@ async fn set_value(
         ["general", "verbose"] => config.general.verbose = parse_bool(value)?,
         ["general", "log_format"] => config.general.log_format = value.to_string(),
         ["general", "audit_log"] => config.general.audit_log = parse_bool(value)?,
+        ["general", "redact_patterns"] => {
+            config.general.redact_patterns = value
+                .split(',')
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect();
+        }
 
+        ["vm", "provider"] => config.vm.provider = value.parse::<VmProvider>()?.to_string(),
         ["vm", "name"] => config.vm.name = value.to_string(),
         ["vm", "distro"] => config.vm.distro = value.to_string(),
+        ["vm", "cpus"] => config.vm.cpus = parse_u32(value)?,
+        ["vm", "memory_gb"] => config.vm.memory_gb = parse_u32(value)?,
+
+        ["orchestration", "backend"] => {
+            config.orchestration.backend = value.parse::<Backend>()?.to_string()
+        }
+        ["orchestration", "remote", "host"] => config.orchestration.remote.host = value.to_string(),
+        ["orchestration", "remote", "port"] => {
+            config.orchestration.remote.port = Some(
+                value
+                    .parse()
+                    .map_err(|_| MinoError::User(format!("Invalid port: {}", value)))?,
+            )
+        }
+        ["orchestration", "remote", "identity_file"] => {
+            config.orchestration.remote.identity_file = Some(value.to_string())
+        }
+        ["orchestration", "remote", "sync_dir"] => {
+            config.orchestration.remote.sync_dir = value.to_string()
+        }
+        ["orchestration", "kubernetes", "context"] => {
+            config.orchestration.kubernetes.context = value.to_string()
+        }
+        ["orchestration", "kubernetes", "namespace"] => {
+            config.orchestration.kubernetes.namespace = value.to_string()
+        }
+        ["orchestration", "kubernetes", "storage_class"] => {
+            config.orchestration.kubernetes.storage_class = value.to_string()
+        }
+        ["orchestration", "kubernetes", "volume_size"] => {
+            config.orchestration.kubernetes.volume_size = value.to_string()
+        }
+        ["orchestration", "kubernetes", "volume_access_mode"] => {
+            config.orchestration.kubernetes.volume_access_mode = value.to_string()
+        }
+        ["orchestration", "kubernetes", "sync_image"] => {
+            config.orchestration.kubernetes.sync_image = value.to_string()
+        }
+        ["orchestration", "kubernetes", "start_timeout_secs"] => {
+            config.orchestration.kubernetes.start_timeout_secs = parse_u32(value)?.into()
+        }
 
         ["container", "image"] => config.container.image = value.to_string(),
         ["container", "network"] => config.container.network = value.to_string(),
         ["container", "workdir"] => config.container.workdir = value.to_string(),
+        ["container", "user"] => config.container.user = Some(value.to_string()),
+        ["container", "home"] => config.container.home = Some(value.to_string()),
+        ["container", "propagate"] => {
+            config.container.propagate = value
+                .split(',')
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect();
+        }
+        ["container", "env_passthrough"] => {
+            config.container.env_passthrough = value
+                .split(',')
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect();
+        }
+        ["container", "pull"] => config.container.pull = value.parse::<PullPolicy>()?.to_string(),
+        ["container", "cpus"] => {
+            config.container.cpus = value
+                .parse()
+                .ok()
+                .filter(|n: &f64| n.is_finite() && *n >= 0.0)
+                .ok_or_else(|| MinoError::User(format!("Invalid CPU limit: {}", value)))?
+        }
+        ["container", "memory_mb"] => {
+            config.container.memory_mb = value
+                .parse()
+                .map_err(|_| MinoError::User(format!("Invalid number: {}", value)))?
+        }
+        ["container", "pids_limit"] => config.container.pids_limit = parse_u32(value)?,
+        ["container", "ulimits"] => {
+            let ulimits: Vec<String> = value
+                .split(',')
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect();
+            for ulimit in &ulimits {
+                ulimit_name(ulimit)?;
+            }
+            config.container.ulimits = ulimits;
+        }
+        ["container", "gpus"] => config.container.gpus = parse_bool(value)?,
+        ["container", "devices"] => {
+            let devices: Vec<String> = value
+                .split(',')
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect();
+            for device in &devices {
+                device_host_path(device)?;
+            }
+            config.container.devices = devices;
+        }
+        ["container", "tmpfs"] => {
+            let mounts = split_tmpfs_list(value);
+            for mount in &mounts {
+                tmpfs_target(mount)?;
+            }
+            config.container.tmpfs = mounts;
+        }
+        ["container", "userns"] => config.container.userns = Some(parse_userns(value)?),
+        ["container", "platform"] => config.container.platform = Some(parse_platform(value)?),
+        ["container", "hostname"] => config.container.hostname = Some(parse_hostname(value)?),
+        ["container", "extra_hosts"] => {
+            let hosts: Vec<String> = value
+                .split(',')
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect();
+            for host in &hosts {
+                parse_extra_host(host)?;
+            }
+            config.container.extra_hosts = hosts;
+        }
+        ["container", "session_dns"] => config.container.session_dns = parse_bool(value)?,
         ["container", "network_allow"] => {
             config.container.network_allow = value
                 .split(',')
@@ -92,6 +227,10 @@ async fn set_value(
                 .collect();
         }
 
+        ["credentials", "delivery"] => {
+            config.credentials.delivery = value.parse::<CredentialDelivery>()?.to_string()
+        }
+        ["credentials", "refresh"] => config.credentials.refresh = parse_bool(value)?,
         ["credentials", "aws", "enabled"] => config.credentials.aws.enabled = parse_bool(value)?,
         ["credentials", "aws", "session_duration_secs"] => {
             config.credentials.aws.session_duration_secs = parse_u32(value)?
@@ -99,15 +238,36 @@ async fn set_value(
         ["credentials", "aws", "role_arn"] => {
             config.credentials.aws.role_arn = Some(value.to_string())
         }
+        ["credentials", "aws", "external_id"] => {
+            config.credentials.aws.external_id = Some(value.to_string())
+        }
+        ["credentials", "aws", "mfa_serial"] => {
+            config.credentials.aws.mfa_serial = Some(value.to_string())
+        }
+        ["credentials", "aws", "source_profile"] => {
+            config.credentials.aws.source_profile = Some(value.to_string())
+        }
         ["credentials", "aws", "profile"] => {
             config.credentials.aws.profile = Some(value.to_string())
         }
         ["credentials", "aws", "region"] => config.credentials.aws.region = Some(value.to_string()),
+        ["credentials", "aws", "policy", "inline_session_policy"] => {
+            config.credentials.aws.policy.inline_session_policy = Some(value.to_string())
+        }
+        ["credentials", "aws", "policy", "allowed_actions"] => {
+            config.credentials.aws.policy.allowed_actions = split_list(value)
+        }
+        ["credentials", "aws", "policy", "managed_policy_arns"] => {
+            config.credentials.aws.policy.managed_policy_arns = split_list(value)
+        }
 
         ["credentials", "gcp", "enabled"] => config.credentials.gcp.enabled = parse_bool(value)?,
         ["credentials", "gcp", "project"] => {
             config.credentials.gcp.project = Some(value.to_string())
         }
+        ["credentials", "gcp", "policy", "scopes"] => {
+            config.credentials.gcp.policy.scopes = split_list(value)
+        }
 
         ["credentials", "azure", "enabled"] => {
             config.credentials.azure.enabled = parse_bool(value)?
@@ -118,16 +278,75 @@ async fn set_value(
         ["credentials", "azure", "tenant"] => {
             config.credentials.azure.tenant = Some(value.to_string())
         }
+        ["credentials", "azure", "policy", "scopes"] => {
+            config.credentials.azure.policy.scopes = split_list(value)
+        }
+        ["credentials", "github", "enabled"] => {
+            config.credentials.github.enabled = parse_bool(value)?
+        }
+        ["credentials", "github", "app_id"] => {
+            config.credentials.github.app_id = Some(parse_u64(value)?)
+        }
+        ["credentials", "github", "installation_id"] => {
+            config.credentials.github.installation_id = Some(parse_u64(value)?)
+        }
+        ["credentials", "github", "private_key_path"] => {
+            config.credentials.github.private_key_path = Some(value.to_string())
+        }
+        ["credentials", "github", "repositories"] => {
+            config.credentials.github.repositories = split_list(value)
+        }
+        ["credentials", "gitlab", "enabled"] => {
+            config.credentials.gitlab.enabled = parse_bool(value)?
+        }
+        ["credentials", "gitlab", "host"] => config.credentials.gitlab.host = value.to_string(),
+        ["credentials", "kubernetes", "enabled"] => {
+            config.credentials.kubernetes.enabled = parse_bool(value)?
+        }
+        ["credentials", "kubernetes", "context"] => {
+            config.credentials.kubernetes.context = value.to_string()
+        }
+        ["credentials", "kubernetes", "namespace"] => {
+            config.credentials.kubernetes.namespace = value.to_string()
+        }
+        ["credentials", "kubernetes", "service_account"] => {
+            config.credentials.kubernetes.service_account = value.to_string()
+        }
+        ["credentials", "kubernetes", "token_duration_secs"] => {
+            config.credentials.kubernetes.token_duration_secs = parse_u32(value)?
+        }
+        ["credentials", "bitbucket", "enabled"] => {
+            config.credentials.bitbucket.enabled = parse_bool(value)?
+        }
+        ["credentials", "bitbucket", "username"] => {
+            config.credentials.bitbucket.username = Some(value.to_string())
+        }
+        ["credentials", "deploy_key", "enabled"] => {
+            config.credentials.deploy_key.enabled = parse_bool(value)?
+        }
+        ["credentials", "deploy_key", "read_only"] => {
+            config.credentials.deploy_key.read_only = parse_bool(value)?
+        }
+        ["credentials", "deploy_key", "repos"] => {
+            config.credentials.deploy_key.repos = split_list(value)
+        }
+        ["git", "user_name"] => config.git.user_name = Some(value.to_string()),
+        ["git", "user_email"] => config.git.user_email = Some(value.to_string()),
+        ["git", "signing_key"] => config.git.signing_key = Some(value.to_string()),
+        ["git", "include_host_gitconfig"] => config.git.include_host_gitconfig = parse_bool(value)?,
 
         ["session", "shell"] => config.session.shell = value.to_string(),
+        ["session", "command"] => {
+            config.session.command = value.split_whitespace().map(String::from).collect()
+        }
         ["session", "auto_cleanup_hours"] => config.session.auto_cleanup_hours = parse_u32(value)?,
+        ["session", "cleanup_resources"] => config.session.cleanup_resources = parse_bool(value)?,
+        ["session", "usage_log"] => config.session.usage_log = parse_bool(value)?,
+        ["session", "clipboard"] => config.session.clipboard = parse_bool(value)?,
 
-        _ => {
-            ui::step_error_detail(&ctx, "Unknown config key", key);
-            ui::remark(&ctx, "Valid keys:");
-            print_valid_keys();
-            return Ok(());
-        }
+        ["ui", "style"] => config.ui.style = value.parse::<StyleMode>()?.to_string(),
+
+        _ => return set_generic_value(manager, &config, key, value).await,
     }
 
     manager.save(&config).await?;
@@ -136,14 +355,46 @@ async fn set_value(
     Ok(())
 }
 
+/// Set any schema key from a TOML/JSON literal, validating its type.
+async fn set_generic_value(
+    manager: &ConfigManager,
+    config: &Config,
+    key: &str,
+    value: &str,
+) -> MinoResult<()> {
+    let ctx = UiContext::detect();
+
+    let Some(config) = set_literal(config, key, parse_value_literal(value)?)? else {
+        ui::step_error_detail(&ctx, "Unknown config key", key);
+        ui::remark(&ctx, "Valid keys:");
+        print_valid_keys();
+        return Ok(());
+    };
+
+    manager.save(&config).await?;
+    ui::step_ok(&ctx, &format!("Set {} = {}", key, value));
+
+    Ok(())
+}
+
 async fn set_local_value(key: &str, value: &str) -> MinoResult<()> {
     let ctx = UiContext::detect();
 
     let cwd = std::env::current_dir().map_err(|e| MinoError::io("getting current directory", e))?;
     let local_path = cwd.join(".mino.toml");
 
-    // Validate the key before touching the file
-    validate_config_key(key)?;
+    // Keys without a dedicated parser take a literal checked against the schema
+    let literal = if (is_compound_literal(value) && !is_document_key(key))
+        || validate_config_key(key).is_err()
+    {
+        let literal = parse_value_literal(value)?;
+        if set_literal(&Config::default(), key, literal.clone())?.is_none() {
+            return Err(MinoError::User(format!("Unknown config key: {}", key)));
+        }
+        Some(literal)
+    } else {
+        None
+    };
 
     // Load existing local config or start with an empty document (preserves comments)
     let mut doc: toml_edit::DocumentMut = if local_path.exists() {
@@ -161,7 +412,14 @@ async fn set_local_value(key: &str, value: &str) -> MinoResult<()> {
     };
 
     // Set the key in the TOML tree
-    set_toml_edit_value(&mut doc, key, value)?;
+    match literal {
+        Some(literal) => set_toml_edit_item(
+            &mut doc,
+            key,
+            toml_edit::Item::Value(to_edit_value(&literal)),
+        )?,
+        None => set_toml_edit_value(&mut doc, key, value)?,
+    }
 
     // Write back preserving comments and formatting
     fs::write(&local_path, doc.to_string())
@@ -180,23 +438,182 @@ async fn set_local_value(key: &str, value: &str) -> MinoResult<()> {
 fn validate_config_key(key: &str) -> MinoResult<()> {
     let parts: Vec<&str> = key.split('.').collect();
     match parts.as_slice() {
-        ["general", "verbose" | "log_format" | "audit_log"]
-        | ["vm", "name" | "distro"]
-        | ["container", "image" | "network" | "workdir" | "network_allow"]
-        | ["credentials", "aws", "enabled" | "session_duration_secs" | "role_arn" | "profile" | "region"]
+        ["general", "verbose" | "log_format" | "audit_log" | "redact_patterns"]
+        | ["vm", "provider" | "name" | "distro" | "cpus" | "memory_gb"]
+        | ["orchestration", "backend"]
+        | ["orchestration", "remote", "host" | "port" | "identity_file" | "sync_dir"]
+        | ["container", "image" | "network" | "workdir" | "network_allow" | "user" | "home" | "pull"
+        | "propagate" | "env_passthrough" | "cpus" | "memory_mb" | "pids_limit" | "ulimits"
+        | "gpus" | "devices" | "tmpfs" | "userns" | "platform" | "hostname" | "extra_hosts"
+        | "session_dns"]
+        | ["credentials", "delivery" | "refresh"]
+        | ["credentials", "aws", "enabled"
+        | "session_duration_secs"
+        | "role_arn"
+        | "external_id"
+        | "mfa_serial"
+        | "source_profile"
+        | "profile"
+        | "region"]
+        | ["credentials", "aws", "policy", "inline_session_policy" | "allowed_actions" | "managed_policy_arns"]
         | ["credentials", "gcp", "enabled" | "project"]
+        | ["credentials", "gcp", "policy", "scopes"]
         | ["credentials", "azure", "enabled" | "subscription" | "tenant"]
-        | ["session", "shell" | "auto_cleanup_hours"] => Ok(()),
+        | ["credentials", "azure", "policy", "scopes"]
+        | ["credentials", "github", "enabled" | "app_id" | "installation_id" | "private_key_path" | "repositories"]
+        | ["credentials", "gitlab", "enabled" | "host"]
+        | ["credentials", "kubernetes", "enabled" | "context" | "namespace" | "service_account" | "token_duration_secs"]
+        | ["credentials", "bitbucket", "enabled" | "username"]
+        | ["credentials", "deploy_key", "enabled" | "read_only" | "repos"]
+        | ["git", "user_name" | "user_email" | "signing_key" | "include_host_gitconfig"]
+        | ["session", "shell" | "command" | "auto_cleanup_hours" | "cleanup_resources" | "usage_log"
+        | "clipboard"]
+        | ["ui", "style"] => Ok(()),
         _ => Err(MinoError::User(format!("Unknown config key: {}", key))),
     }
 }
 
+/// Whether a `config set` value is an array or table literal
+fn is_compound_literal(value: &str) -> bool {
+    let value = value.trim_start();
+    value.starts_with('[') || value.starts_with('{')
+}
+
+/// Keys holding a JSON document as a string, which must not be read as a
+/// table literal
+fn is_document_key(key: &str) -> bool {
+    key == "credentials.aws.policy.inline_session_policy"
+}
+
+/// Split a comma-separated `config set` list, dropping empty entries
+fn split_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+/// Parse a `config set` value into a TOML value.
+///
+/// Arrays and tables may be written as TOML inline literals or JSON. Other
+/// values are read as TOML scalars (numbers, booleans, quoted strings) and
+/// fall back to a plain string.
+fn parse_value_literal(value: &str) -> MinoResult<toml::Value> {
+    let as_toml = toml::from_str::<toml::Table>(&format!("v = {}", value))
+        .ok()
+        .and_then(|mut t| t.remove("v"));
+
+    if is_compound_literal(value) {
+        if let Some(v) = as_toml {
+            return Ok(v);
+        }
+        return serde_json::from_str::<serde_json::Value>(value)
+            .ok()
+            .and_then(|json| toml::Value::try_from(json).ok())
+            .ok_or_else(|| {
+                MinoError::User(format!(
+                    "Invalid value: {} (expected a TOML or JSON array/table)",
+                    value
+                ))
+            });
+    }
+
+    Ok(match as_toml {
+        Some(toml::Value::Datetime(_)) | None => toml::Value::String(value.to_string()),
+        Some(v) => v,
+    })
+}
+
+/// Set `key` in a copy of `config` and check the result against the schema.
+///
+/// Returns `None` when the key is not part of the schema, and an error when
+/// the value has the wrong type or a table value contains unknown keys.
+fn set_literal(config: &Config, key: &str, value: toml::Value) -> MinoResult<Option<Config>> {
+    let parts: Vec<&str> = key.split('.').collect();
+    let mut root = toml::Value::try_from(config)?;
+
+    let mut table = root
+        .as_table_mut()
+        .ok_or_else(|| MinoError::Internal("config did not serialize to a table".to_string()))?;
+    for &part in &parts[..parts.len() - 1] {
+        table = table
+            .entry(part)
+            .or_insert_with(|| toml::Value::Table(toml::Table::new()))
+            .as_table_mut()
+            .ok_or_else(|| MinoError::User(format!("Expected table at key: {}", part)))?;
+    }
+    table.insert(parts[parts.len() - 1].to_string(), value.clone());
+
+    let updated: Config = root.try_into().map_err(|e: toml::de::Error| {
+        MinoError::User(format!("Invalid value for {}: {}", key, e.message()))
+    })?;
+
+    // Keys serde does not know are dropped on the way through
+    let kept = toml::Value::try_from(&updated)?;
+    let Some(kept) = parts.iter().try_fold(&kept, |v, part| v.get(part)) else {
+        return Ok(None);
+    };
+    if let Some(unknown) = first_unknown_key(&value, kept) {
+        return Err(MinoError::User(format!(
+            "Unknown config key: {}.{}",
+            key, unknown
+        )));
+    }
+
+    Ok(Some(updated))
+}
+
+/// First key of a table value that did not survive the schema round trip
+fn first_unknown_key(set: &toml::Value, kept: &toml::Value) -> Option<String> {
+    let set = set.as_table()?;
+    set.iter().find_map(|(k, v)| match kept.get(k) {
+        Some(kept) => first_unknown_key(v, kept).map(|sub| format!("{}.{}", k, sub)),
+        None => Some(k.clone()),
+    })
+}
+
+/// Convert a parsed TOML value into an inline toml_edit value
+fn to_edit_value(value: &toml::Value) -> toml_edit::Value {
+    match value {
+        toml::Value::String(s) => s.as_str().into(),
+        toml::Value::Integer(i) => (*i).into(),
+        toml::Value::Float(f) => (*f).into(),
+        toml::Value::Boolean(b) => (*b).into(),
+        toml::Value::Datetime(d) => d
+            .to_string()
+            .parse()
+            .unwrap_or_else(|_| d.to_string().into()),
+        toml::Value::Array(items) => {
+            toml_edit::Value::Array(items.iter().map(to_edit_value).collect())
+        }
+        toml::Value::Table(table) => toml_edit::Value::InlineTable(
+            table
+                .iter()
+                .map(|(k, v)| (k.as_str(), to_edit_value(v)))
+                .collect(),
+        ),
+    }
+}
+
 /// Set a dot-separated key in a toml_edit document, creating intermediate tables as needed.
 /// Preserves comments and formatting in the original document.
-fn set_toml_edit_value(doc: &mut toml_edit::DocumentMut, key: &str, value: &str) -> MinoResult<()> {
+fn set_toml_edit_item(
+    doc: &mut toml_edit::DocumentMut,
+    key: &str,
+    item: toml_edit::Item,
+) -> MinoResult<()> {
     let parts: Vec<&str> = key.split('.').collect();
+    let table = edit_parent_table(doc, &parts)?;
+    table.insert(parts[parts.len() - 1], item);
+    Ok(())
+}
 
-    // Navigate/create intermediate tables
+/// Navigate to (creating as needed) the table holding the last key part
+fn edit_parent_table<'a>(
+    doc: &'a mut toml_edit::DocumentMut,
+    parts: &[&str],
+) -> MinoResult<&'a mut toml_edit::Table> {
     let mut table = doc.as_table_mut();
     for &part in &parts[..parts.len() - 1] {
         if !table.contains_key(part) {
@@ -206,12 +623,29 @@ fn set_toml_edit_value(doc: &mut toml_edit::DocumentMut, key: &str, value: &str)
             .as_table_mut()
             .ok_or_else(|| MinoError::User(format!("Expected table at key: {}", part)))?;
     }
+    Ok(table)
+}
+
+/// Set a dot-separated key in a toml_edit document from a raw CLI value,
+/// creating intermediate tables as needed.
+/// Preserves comments and formatting in the original document.
+fn set_toml_edit_value(doc: &mut toml_edit::DocumentMut, key: &str, value: &str) -> MinoResult<()> {
+    let parts: Vec<&str> = key.split('.').collect();
+    let table = edit_parent_table(doc, &parts)?;
 
     let leaf = *parts.last().unwrap();
 
     // Keys that store as arrays
-    let is_list_key =
-        key.ends_with("network_allow") || key.ends_with("layers") || key.ends_with("volumes");
+    let is_list_key = key.ends_with("network_allow")
+        || key.ends_with("layers")
+        || key.ends_with("packages.dnf")
+        || key.ends_with("volumes")
+        || key.ends_with("redact_patterns")
+        || key.ends_with("propagate")
+        || key.ends_with("env_passthrough")
+        || key.ends_with("allowed_actions")
+        || key.ends_with("managed_policy_arns")
+        || key.ends_with("scopes");
 
     if is_list_key {
         let mut arr = toml_edit::Array::new();
@@ -223,6 +657,12 @@ fn set_toml_edit_value(doc: &mut toml_edit::DocumentMut, key: &str, value: &str)
         table.insert(leaf, toml_edit::value(parse_bool(value)?));
     } else if let Ok(n) = value.parse::<i64>() {
         table.insert(leaf, toml_edit::value(n));
+    } else if let Some(f) = value
+        .contains('.')
+        .then(|| value.parse::<f64>().ok())
+        .flatten()
+    {
+        table.insert(leaf, toml_edit::value(f));
     } else {
         table.insert(leaf, toml_edit::value(value));
     }
@@ -230,6 +670,23 @@ fn set_toml_edit_value(doc: &mut toml_edit::DocumentMut, key: &str, value: &str)
     Ok(())
 }
 
+/// Split a comma-separated tmpfs list. Mount options contain commas too
+/// (`/tmp:size=1g,mode=1777`), so a piece not starting with `/` continues
+/// the previous mount's options.
+fn split_tmpfs_list(value: &str) -> Vec<String> {
+    let mut mounts: Vec<String> = Vec::new();
+    for piece in value.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+        match mounts.last_mut() {
+            Some(last) if !piece.starts_with('/') => {
+                last.push(',');
+                last.push_str(piece);
+            }
+            _ => mounts.push(piece.to_string()),
+        }
+    }
+    mounts
+}
+
 fn parse_bool(value: &str) -> MinoResult<bool> {
     match value.to_lowercase().as_str() {
         "true" | "1" | "yes" => Ok(true),
@@ -247,40 +704,127 @@ fn parse_u32(value: &str) -> MinoResult<u32> {
         .map_err(|_| MinoError::User(format!("Invalid number: {}", value)))
 }
 
+fn parse_u64(value: &str) -> MinoResult<u64> {
+    value
+        .parse()
+        .map_err(|_| MinoError::User(format!("Invalid number: {}", value)))
+}
+
 fn print_valid_keys() {
     let keys = [
         "general.verbose",
         "general.log_format",
         "general.audit_log",
+        "general.redact_patterns",
+        "vm.provider",
         "vm.name",
         "vm.distro",
+        "vm.cpus",
+        "vm.memory_gb",
+        "orchestration.backend",
+        "orchestration.remote.host",
+        "orchestration.remote.port",
+        "orchestration.remote.identity_file",
+        "orchestration.remote.sync_dir",
+        "orchestration.kubernetes.context",
+        "orchestration.kubernetes.namespace",
+        "orchestration.kubernetes.storage_class",
+        "orchestration.kubernetes.volume_size",
+        "orchestration.kubernetes.volume_access_mode",
+        "orchestration.kubernetes.sync_image",
+        "orchestration.kubernetes.start_timeout_secs",
         "container.image",
         "container.network",
         "container.workdir",
         "container.network_allow",
+        "container.user",
+        "container.home",
+        "container.pull",
+        "container.propagate",
+        "container.env_passthrough",
+        "container.cpus",
+        "container.memory_mb",
+        "container.pids_limit",
+        "container.ulimits",
+        "container.gpus",
+        "container.devices",
+        "container.tmpfs",
+        "container.userns",
+        "container.platform",
+        "container.hostname",
+        "container.extra_hosts",
+        "container.session_dns",
+        "credentials.delivery",
+        "credentials.refresh",
         "credentials.aws.enabled",
         "credentials.aws.session_duration_secs",
         "credentials.aws.role_arn",
+        "credentials.aws.external_id",
+        "credentials.aws.mfa_serial",
+        "credentials.aws.source_profile",
         "credentials.aws.profile",
         "credentials.aws.region",
+        "credentials.aws.policy.inline_session_policy",
+        "credentials.aws.policy.allowed_actions",
+        "credentials.aws.policy.managed_policy_arns",
         "credentials.gcp.enabled",
         "credentials.gcp.project",
+        "credentials.gcp.policy.scopes",
         "credentials.azure.enabled",
         "credentials.azure.subscription",
         "credentials.azure.tenant",
+        "credentials.azure.policy.scopes",
+        "credentials.github.enabled",
+        "credentials.github.app_id",
+        "credentials.github.installation_id",
+        "credentials.github.private_key_path",
+        "credentials.github.repositories",
+        "credentials.gitlab.enabled",
+        "credentials.gitlab.host",
+        "credentials.kubernetes.enabled",
+        "credentials.kubernetes.context",
+        "credentials.kubernetes.namespace",
+        "credentials.kubernetes.service_account",
+        "credentials.kubernetes.token_duration_secs",
+        "credentials.bitbucket.enabled",
+        "credentials.bitbucket.username",
+        "credentials.deploy_key.enabled",
+        "credentials.deploy_key.read_only",
+        "credentials.deploy_key.repos",
+        "git.user_name",
+        "git.user_email",
+        "git.signing_key",
+        "git.include_host_gitconfig",
         "session.shell",
+        "session.command",
         "session.auto_cleanup_hours",
+        "session.cleanup_resources",
+        "session.usage_log",
+        "session.clipboard",
+        "ui.style",
     ];
 
     for key in keys {
         eprintln!("  {}", key);
     }
+    eprintln!();
+    eprintln!("Other config keys accept TOML or JSON values, e.g.:");
+    eprintln!("  mino config set container.volumes '[\"/data:/data\"]'");
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn split_tmpfs_list_keeps_mount_options_together() {
+        assert_eq!(
+            split_tmpfs_list("/tmp:size=1g,mode=1777, /run"),
+            vec!["/tmp:size=1g,mode=1777", "/run"]
+        );
+        assert!(split_tmpfs_list("").is_empty());
+    }
+
     #[test]
     fn set_toml_edit_value_preserves_comments() {
         let input = "# Top-level comment\n[container]\n# Network comment\nnetwork = \"none\"\n";
@@ -336,6 +880,130 @@ mod tests {
         );
     }
 
+    #[test]
+    fn set_toml_edit_value_handles_float() {
+        let mut doc = toml_edit::DocumentMut::new();
+        set_toml_edit_value(&mut doc, "container.cpus", "1.5").unwrap();
+        let parsed: toml::Value = doc.to_string().parse().unwrap();
+        assert_eq!(parsed["container"]["cpus"].as_float().unwrap(), 1.5);
+    }
+
+    #[test]
+    fn parse_value_literal_accepts_toml_and_json() {
+        let toml_arr = parse_value_literal(r#"["/data:/data", '/a:/b']"#).unwrap();
+        assert_eq!(toml_arr.as_array().unwrap().len(), 2);
+
+        let json_table =
+            parse_value_literal(r#"{"enabled": true, "region": "eu-west-1"}"#).unwrap();
+        assert_eq!(json_table["enabled"].as_bool(), Some(true));
+
+        assert!(parse_value_literal("[unterminated").is_err());
+    }
+
+    #[test]
+    fn parse_value_literal_scalars() {
+        assert_eq!(parse_value_literal("48").unwrap().as_integer(), Some(48));
+        assert_eq!(parse_value_literal("true").unwrap().as_bool(), Some(true));
+        assert_eq!(
+            parse_value_literal("fedora:43").unwrap().as_str(),
+            Some("fedora:43")
+        );
+        assert_eq!(
+            parse_value_literal("2024-01-01").unwrap().as_str(),
+            Some("2024-01-01")
+        );
+    }
+
+    #[test]
+    fn set_literal_sets_array_key() {
+        let value = parse_value_literal(r#"["/data:/data"]"#).unwrap();
+        let config = set_literal(&Config::default(), "container.volumes", value)
+            .unwrap()
+            .unwrap();
+        assert_eq!(config.container.volumes, vec!["/data:/data"]);
+    }
+
+    #[test]
+    fn set_literal_sets_table_key() {
+        let value = parse_value_literal(r#"{ enabled = true, region = "eu-west-1" }"#).unwrap();
+        let config = set_literal(&Config::default(), "credentials.aws", value)
+            .unwrap()
+            .unwrap();
+        assert!(config.credentials.aws.enabled);
+        assert_eq!(config.credentials.aws.region.as_deref(), Some("eu-west-1"));
+    }
+
+    #[test]
+    fn set_literal_sets_map_entry() {
+        let value = parse_value_literal("bar").unwrap();
+        let config = set_literal(&Config::default(), "container.env.FOO", value)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            config.container.env.get("FOO").map(String::as_str),
+            Some("bar")
+        );
+    }
+
+    #[test]
+    fn set_literal_rejects_wrong_type() {
+        let value = parse_value_literal(r#"[1, 2]"#).unwrap();
+        let err = set_literal(&Config::default(), "container.image", value).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Invalid value for container.image"));
+    }
+
+    #[test]
+    fn set_literal_reports_unknown_keys() {
+        let value = parse_value_literal("1").unwrap();
+        assert!(set_literal(&Config::default(), "container.bogus", value)
+            .unwrap()
+            .is_none());
+
+        let value = parse_value_literal(r#"{ enabled = true, bogus = 1 }"#).unwrap();
+        let err = set_literal(&Config::default(), "credentials.aws", value).unwrap_err();
+        assert!(err.to_string().contains("credentials.aws.bogus"));
+    }
+
+    #[test]
+    fn set_toml_edit_item_writes_inline_literal() {
+        let mut doc = toml_edit::DocumentMut::new();
+        let value = parse_value_literal(r#"{"FOO": "bar", "N": 1}"#).unwrap();
+        set_toml_edit_item(
+            &mut doc,
+            "container.env",
+            toml_edit::Item::Value(to_edit_value(&value)),
+        )
+        .unwrap();
+        let parsed: toml::Value = doc.to_string().parse().unwrap();
+        assert_eq!(parsed["container"]["env"]["FOO"].as_str(), Some("bar"));
+        assert_eq!(parsed["container"]["env"]["N"].as_integer(), Some(1));
+    }
+
+    #[test]
+    fn inline_session_policy_is_set_as_string() {
+        let policy = r#"{"Version": "2012-10-17", "Statement": []}"#;
+        assert!(is_compound_literal(policy));
+        assert!(is_document_key(
+            "credentials.aws.policy.inline_session_policy"
+        ));
+        assert!(!is_document_key("credentials.aws"));
+
+        let mut doc = toml_edit::DocumentMut::new();
+        set_toml_edit_value(
+            &mut doc,
+            "credentials.aws.policy.inline_session_policy",
+            policy,
+        )
+        .unwrap();
+        let parsed: toml::Value = doc.to_string().parse().unwrap();
+        assert_eq!(
+            parsed["credentials"]["aws"]["policy"]["inline_session_policy"].as_str(),
+            Some(policy)
+        );
+    }
+
     #[test]
     fn validate_config_key_rejects_unknown() {
         assert!(validate_config_key("container.nonexistent").is_err());
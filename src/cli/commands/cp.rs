@@ -0,0 +1,210 @@
+//! Cp command - copy files between the host and a session
+
+use crate::audit::AuditLog;
+use crate::cli::args::CpArgs;
+use crate::config::Config;
+use crate::error::{MinoError, MinoResult};
+use crate::orchestration::{create_runtime_with_backend, ContainerRuntime};
+use crate::sandbox::RuntimeMode;
+use crate::session::SessionManager;
+use crate::ui::{self, UiContext};
+use std::path::{Path, PathBuf};
+
+/// A copy between the host and one session's container
+#[derive(Debug, PartialEq, Eq)]
+enum Transfer<'a> {
+    /// Host path into `<session>:<path>`
+    ToSession {
+        session: &'a str,
+        host: &'a str,
+        path: &'a str,
+    },
+    /// `<session>:<path>` out to a host path
+    FromSession {
+        session: &'a str,
+        path: &'a str,
+        host: &'a str,
+    },
+}
+
+impl<'a> Transfer<'a> {
+    fn session(&self) -> &'a str {
+        match self {
+            Transfer::ToSession { session, .. } | Transfer::FromSession { session, .. } => session,
+        }
+    }
+}
+
+/// Execute the cp command
+pub async fn execute(args: CpArgs, config: &Config) -> MinoResult<()> {
+    let ctx = UiContext::detect();
+    let transfer = parse_transfer(&args.source, &args.destination)?;
+
+    let manager = SessionManager::new().await?;
+    let name = transfer.session();
+    let session = manager
+        .get(name)
+        .await?
+        .ok_or_else(|| MinoError::SessionNotFound(name.to_string()))?;
+
+    if session.runtime_mode == Some(RuntimeMode::Native) {
+        return Err(MinoError::NativeUnsupported {
+            feature: "mino cp".to_string(),
+        });
+    }
+    let container_id = session
+        .container_id
+        .as_deref()
+        .ok_or_else(|| MinoError::ContainerNotFound(session.name.clone()))?;
+
+    let runtime = create_runtime_with_backend(config, session.backend)?;
+    copy(&*runtime, container_id, &transfer).await?;
+
+    AuditLog::new(config)
+        .log(
+            "session.copy",
+            &serde_json::json!({
+                "name": &session.name,
+                "source": &args.source,
+                "destination": &args.destination,
+            }),
+        )
+        .await;
+
+    ui::step_ok(
+        &ctx,
+        &format!("Copied {} to {}", args.source, args.destination),
+    );
+    Ok(())
+}
+
+/// Run the copy through the session's runtime
+async fn copy(
+    runtime: &dyn ContainerRuntime,
+    container_id: &str,
+    transfer: &Transfer<'_>,
+) -> MinoResult<()> {
+    match *transfer {
+        Transfer::ToSession { host, path, .. } => {
+            let host = absolute(host)?;
+            if !host.exists() {
+                return Err(MinoError::User(format!(
+                    "No such file or directory: {}",
+                    host.display()
+                )));
+            }
+            runtime.copy_to_container(container_id, &host, path).await
+        }
+        Transfer::FromSession { path, host, .. } => {
+            let host = absolute(host)?;
+            runtime.copy_from_container(container_id, path, &host).await
+        }
+    }
+}
+
+/// Absolute form of a host path; runtimes may not share our working directory
+fn absolute(path: &str) -> MinoResult<PathBuf> {
+    std::path::absolute(Path::new(path))
+        .map_err(|e| MinoError::io(format!("resolving {}", path), e))
+}
+
+/// Split `<session>:<path>` into its parts.
+///
+/// The session part must be non-empty and contain no `/`, so host paths
+/// such as `./a:b` are never mistaken for session references.
+fn parse_session_ref(arg: &str) -> Option<(&str, &str)> {
+    let (session, path) = arg.split_once(':')?;
+    if session.is_empty() || session.contains('/') || path.is_empty() {
+        return None;
+    }
+    Some((session, path))
+}
+
+/// Work out which side of the copy is the session.
+fn parse_transfer<'a>(source: &'a str, destination: &'a str) -> MinoResult<Transfer<'a>> {
+    match (parse_session_ref(source), parse_session_ref(destination)) {
+        (None, Some((session, path))) => Ok(Transfer::ToSession {
+            session,
+            host: source,
+            path,
+        }),
+        (Some((session, path)), None) => Ok(Transfer::FromSession {
+            session,
+            path,
+            host: destination,
+        }),
+        (Some(_), Some(_)) => Err(MinoError::User(
+            "Copying between two sessions is not supported; copy through the host".to_string(),
+        )),
+        (None, None) => Err(MinoError::User(
+            "One side of mino cp must be <session>:<path>".to_string(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::orchestration::mock::MockRuntime;
+
+    #[test]
+    fn parse_transfer_detects_direction() {
+        assert_eq!(
+            parse_transfer("./dist", "swift-fox:/workspace/dist").unwrap(),
+            Transfer::ToSession {
+                session: "swift-fox",
+                host: "./dist",
+                path: "/workspace/dist",
+            }
+        );
+        assert_eq!(
+            parse_transfer("swift-fox:/tmp/out.log", "out.log").unwrap(),
+            Transfer::FromSession {
+                session: "swift-fox",
+                path: "/tmp/out.log",
+                host: "out.log",
+            }
+        );
+    }
+
+    #[test]
+    fn parse_transfer_rejects_ambiguous_sides() {
+        assert!(parse_transfer("a:/x", "b:/y").is_err());
+        assert!(parse_transfer("./a", "/b").is_err());
+        // A slash before the colon makes it a host path
+        assert!(parse_transfer("./dir:odd", "./other").is_err());
+    }
+
+    #[tokio::test]
+    async fn copy_resolves_host_path_to_absolute() {
+        let mock = MockRuntime::new();
+        let transfer = Transfer::FromSession {
+            session: "s1",
+            path: "/workspace/target",
+            host: "artifacts",
+        };
+        copy(&mock, "abc123", &transfer).await.unwrap();
+
+        let expected = std::env::current_dir().unwrap().join("artifacts");
+        mock.assert_called_with(
+            "copy_from_container",
+            &[
+                "abc123",
+                "/workspace/target",
+                &expected.display().to_string(),
+            ],
+        );
+    }
+
+    #[tokio::test]
+    async fn copy_into_session_requires_existing_source() {
+        let mock = MockRuntime::new();
+        let transfer = Transfer::ToSession {
+            session: "s1",
+            host: "/definitely/not/here",
+            path: "/tmp",
+        };
+        assert!(copy(&mock, "abc123", &transfer).await.is_err());
+        mock.assert_called("copy_to_container", 0);
+    }
+}
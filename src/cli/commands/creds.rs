@@ -0,0 +1,447 @@
+//! Creds command - inspect, test, and clear provider credentials
+
+use crate::cli::args::{CredentialProvider, CredsAction, CredsArgs, OutputFormat};
+use crate::config::Config;
+use crate::credentials::cache::CachedCredential;
+use crate::credentials::{
+    AwsCredentials, AzureCredentials, BitbucketCredentials, CredentialCache, GcpCredentials,
+    GithubCredentials, GitlabCredentials, KubernetesCredentials,
+};
+use crate::error::{MinoError, MinoResult};
+use crate::session::Session;
+use crate::ui::{self, Column, Table, TaskSpinner, UiContext};
+use chrono::{DateTime, Utc};
+
+/// Execute the creds command
+pub async fn execute(args: CredsArgs, config: &Config) -> MinoResult<()> {
+    let cache = CredentialCache::new().await?;
+
+    match args.action {
+        CredsAction::List { format } => list_providers(&cache, config, format).await,
+        CredsAction::Status { provider, format } => show_status(&cache, provider, format).await,
+        CredsAction::Clear { provider } => clear_cached(&cache, provider).await,
+        CredsAction::Test { provider } => test_provider(&cache, config, provider).await,
+    }
+}
+
+/// Display name of a provider
+fn label(provider: CredentialProvider) -> &'static str {
+    match provider {
+        CredentialProvider::Aws => "AWS",
+        CredentialProvider::Gcp => "GCP",
+        CredentialProvider::Azure => "Azure",
+        CredentialProvider::Github => "GitHub",
+        CredentialProvider::Gitlab => "GitLab",
+        CredentialProvider::Bitbucket => "Bitbucket",
+        CredentialProvider::Kubernetes => "Kubernetes",
+    }
+}
+
+/// Where a provider's credentials come from
+fn source(provider: CredentialProvider, config: &Config) -> &'static str {
+    match provider {
+        CredentialProvider::Aws => "STS via the aws CLI",
+        CredentialProvider::Gcp => "gcloud access token",
+        CredentialProvider::Azure => "az access token",
+        CredentialProvider::Github if config.credentials.github.app_mode() => {
+            "GitHub App installation token"
+        }
+        CredentialProvider::Github => "gh auth token",
+        CredentialProvider::Gitlab => "GITLAB_TOKEN or glab",
+        CredentialProvider::Bitbucket => "BITBUCKET_TOKEN",
+        CredentialProvider::Kubernetes => "kubectl create token",
+    }
+}
+
+/// Whether config injects the provider into sessions without flags
+fn enabled(provider: CredentialProvider, config: &Config) -> bool {
+    let credentials = &config.credentials;
+    match provider {
+        CredentialProvider::Aws => credentials.aws.enabled,
+        CredentialProvider::Gcp => credentials.gcp.enabled,
+        CredentialProvider::Azure => credentials.azure.enabled,
+        CredentialProvider::Github => credentials.github.enabled,
+        CredentialProvider::Gitlab => credentials.gitlab.enabled,
+        CredentialProvider::Bitbucket => credentials.bitbucket.enabled,
+        CredentialProvider::Kubernetes => credentials.kubernetes.enabled,
+    }
+}
+
+/// Latest expiry among the valid cached entries of `provider`
+fn cached_until(
+    entries: &[(String, CachedCredential)],
+    provider: CredentialProvider,
+) -> Option<DateTime<Utc>> {
+    entries
+        .iter()
+        .filter(|(_, cred)| cred.provider == provider.name() && !cred.is_expired())
+        .map(|(_, cred)| cred.expires_at)
+        .max()
+}
+
+/// Minutes left under which a session's credentials show as expiring
+const EXPIRY_WARN_MINUTES: i64 = 10;
+
+/// How close a session's credentials are to expiring
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum ExpiryState {
+    Valid,
+    Expiring,
+    Expired,
+}
+
+/// `aws: 38m left` or `gcp: expired` for each provider whose credentials a
+/// session got, with how close they are to expiring
+pub(super) fn session_expiry(session: &Session, now: DateTime<Utc>) -> Vec<(String, ExpiryState)> {
+    session
+        .credential_expiry
+        .iter()
+        .map(|(provider, expires_at)| {
+            let minutes = (*expires_at - now).num_minutes();
+            if minutes <= 0 {
+                (format!("{}: expired", provider), ExpiryState::Expired)
+            } else {
+                let state = if minutes < EXPIRY_WARN_MINUTES {
+                    ExpiryState::Expiring
+                } else {
+                    ExpiryState::Valid
+                };
+                let left = format_remaining(*expires_at, now);
+                (format!("{}: {} left", provider, left), state)
+            }
+        })
+        .collect()
+}
+
+/// Time left until `expires_at`, e.g. `42m` or `3h 5m`
+fn format_remaining(expires_at: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let minutes = (expires_at - now).num_minutes();
+    if minutes <= 0 {
+        "expired".to_string()
+    } else if minutes < 60 {
+        format!("{}m", minutes)
+    } else {
+        format!("{}h {}m", minutes / 60, minutes % 60)
+    }
+}
+
+fn format_time(time: DateTime<Utc>) -> String {
+    time.format("%Y-%m-%d %H:%M UTC").to_string()
+}
+
+/// Prompt for an AWS MFA code when fetching credentials will need one.
+/// `command` is what to rerun in a terminal when there is none.
+pub(super) async fn aws_mfa_code(
+    ctx: &UiContext,
+    cache: &CredentialCache,
+    config: &Config,
+    command: &str,
+) -> MinoResult<Option<String>> {
+    let aws = &config.credentials.aws;
+    if !AwsCredentials::needs_mfa_code(aws, cache).await? {
+        return Ok(None);
+    }
+    if !ctx.is_interactive() {
+        return Err(MinoError::User(format!(
+            "AWS credentials need an MFA code; run `{}` in a terminal",
+            command
+        )));
+    }
+    let serial = aws.mfa_serial.as_deref().unwrap_or("MFA");
+    Ok(Some(
+        ui::input(ctx, &format!("MFA code for {}", serial), "").await?,
+    ))
+}
+
+/// List every provider with its config state and cached credentials
+async fn list_providers(
+    cache: &CredentialCache,
+    config: &Config,
+    format: OutputFormat,
+) -> MinoResult<()> {
+    let entries = cache.entries().await?;
+
+    match format {
+        OutputFormat::Table => {
+            let mut table = Table::new(vec![
+                Column::new("PROVIDER", 12),
+                Column::new("ENABLED", 8).fixed(),
+                Column::new("CACHED UNTIL", 20).fixed(),
+                Column::new("SOURCE", 32),
+            ]);
+            for provider in CredentialProvider::ALL {
+                table.row(vec![
+                    provider.name().to_string(),
+                    if enabled(provider, config) {
+                        "yes"
+                    } else {
+                        "no"
+                    }
+                    .to_string(),
+                    cached_until(&entries, provider).map_or_else(|| "-".to_string(), format_time),
+                    source(provider, config).to_string(),
+                ]);
+            }
+            let ctx = UiContext::detect();
+            ui::intro(&ctx, "Credential Providers");
+            ui::page(&ctx, &table.render());
+        }
+        OutputFormat::Json => {
+            let providers: Vec<_> = CredentialProvider::ALL
+                .into_iter()
+                .map(|provider| {
+                    serde_json::json!({
+                        "provider": provider.name(),
+                        "enabled": enabled(provider, config),
+                        "cached_until": cached_until(&entries, provider),
+                        "source": source(provider, config),
+                    })
+                })
+                .collect();
+            println!("{}", serde_json::to_string_pretty(&providers)?);
+        }
+        OutputFormat::Plain => {
+            for provider in CredentialProvider::ALL {
+                if enabled(provider, config) {
+                    println!("{}", provider.name());
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Show cached credentials (never their values) and when they expire
+async fn show_status(
+    cache: &CredentialCache,
+    provider: Option<CredentialProvider>,
+    format: OutputFormat,
+) -> MinoResult<()> {
+    let entries: Vec<_> = cache
+        .entries()
+        .await?
+        .into_iter()
+        .filter(|(_, cred)| provider.is_none_or(|p| cred.provider == p.name()))
+        .collect();
+    let now = Utc::now();
+
+    match format {
+        OutputFormat::Table if entries.is_empty() => {
+            ui::step_info(&UiContext::detect(), "No cached credentials");
+        }
+        OutputFormat::Table => {
+            let mut table = Table::new(vec![
+                Column::new("PROVIDER", 12),
+                Column::new("ENTRY", 40),
+                Column::new("EXPIRES", 20).fixed(),
+                Column::new("REMAINING", 10).fixed(),
+            ]);
+            for (key, cred) in &entries {
+                let row = vec![
+                    cred.provider.clone(),
+                    key.clone(),
+                    format_time(cred.expires_at),
+                    format_remaining(cred.expires_at, now),
+                ];
+                if cred.is_expired() {
+                    table.dim_row(row);
+                } else {
+                    table.row(row);
+                }
+            }
+            let ctx = UiContext::detect();
+            ui::intro(&ctx, "Cached Credentials");
+            ui::page(&ctx, &table.render());
+        }
+        OutputFormat::Json => {
+            let cached: Vec<_> = entries
+                .iter()
+                .map(|(key, cred)| {
+                    serde_json::json!({
+                        "provider": cred.provider,
+                        "entry": key,
+                        "expires_at": cred.expires_at,
+                        "expired": cred.is_expired(),
+                    })
+                })
+                .collect();
+            println!("{}", serde_json::to_string_pretty(&cached)?);
+        }
+        OutputFormat::Plain => {
+            for (key, cred) in &entries {
+                println!("{} {} {}", cred.provider, key, cred.expires_at.to_rfc3339());
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Remove cached credentials of `provider`, or all of them
+async fn clear_cached(
+    cache: &CredentialCache,
+    provider: Option<CredentialProvider>,
+) -> MinoResult<()> {
+    let ctx = UiContext::detect();
+    let entries = cache.entries().await?;
+
+    let removed = match provider {
+        Some(provider) => {
+            let mut removed = 0;
+            for (key, _) in entries
+                .iter()
+                .filter(|(_, c)| c.provider == provider.name())
+            {
+                cache.remove(key).await?;
+                removed += 1;
+            }
+            removed
+        }
+        None => {
+            cache.clear().await?;
+            entries.len()
+        }
+    };
+
+    let what = match provider {
+        Some(provider) => format!("cached {} credentials", label(provider)),
+        None => "cached credentials".to_string(),
+    };
+    if removed == 0 {
+        ui::step_info(&ctx, &format!("No {}", what));
+    } else {
+        ui::step_ok(&ctx, &format!("Removed {} {}", removed, what));
+    }
+    Ok(())
+}
+
+/// Fetch `provider`'s credentials the way `mino run` does, reporting
+/// whether they work. Fresh credentials land in the cache for the next run.
+async fn test_provider(
+    cache: &CredentialCache,
+    config: &Config,
+    provider: CredentialProvider,
+) -> MinoResult<()> {
+    let ctx = UiContext::detect();
+    let credentials = &config.credentials;
+
+    let mfa_code = if provider == CredentialProvider::Aws {
+        aws_mfa_code(&ctx, cache, config, "mino creds test aws").await?
+    } else {
+        None
+    };
+
+    let mut spinner = TaskSpinner::new(&ctx);
+    spinner.start(&format!("Testing {} credentials...", label(provider)));
+    let result = match provider {
+        CredentialProvider::Aws => {
+            AwsCredentials::get_session_token(&credentials.aws, cache, mfa_code.as_deref())
+                .await
+                .map(|creds| creds.expires_at)
+        }
+        CredentialProvider::Gcp => GcpCredentials::get_access_token(&credentials.gcp, cache)
+            .await
+            .map(|_| None),
+        CredentialProvider::Azure => AzureCredentials::get_access_token(&credentials.azure, cache)
+            .await
+            .map(|_| None),
+        CredentialProvider::Github => {
+            GithubCredentials::get_session_token(&credentials.github, cache)
+                .await
+                .map(|_| None)
+        }
+        CredentialProvider::Gitlab => GitlabCredentials::get_token(&credentials.gitlab)
+            .await
+            .map(|_| None),
+        CredentialProvider::Bitbucket => {
+            BitbucketCredentials::get_token(&credentials.bitbucket).map(|_| None)
+        }
+        CredentialProvider::Kubernetes => {
+            KubernetesCredentials::get_kubeconfig(&credentials.kubernetes, cache)
+                .await
+                .map(|_| None)
+        }
+    };
+    spinner.clear();
+
+    let message = format!("{} credentials work", label(provider));
+    match result? {
+        Some(expires_at) => ui::step_ok_detail(
+            &ctx,
+            &message,
+            &format!("until {}", format_time(expires_at)),
+        ),
+        None => ui::step_ok(&ctx, &message),
+    }
+    if !enabled(provider, config) {
+        ui::step_info(
+            &ctx,
+            &format!(
+                "Not enabled for sessions; set credentials.{}.enabled = true or pass its run flag",
+                provider.name()
+            ),
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::orchestration::mock::test_session;
+    use crate::session::SessionStatus;
+    use chrono::Duration;
+
+    #[test]
+    fn remaining_time_is_compact() {
+        let now = Utc::now();
+        assert_eq!(format_remaining(now + Duration::minutes(42), now), "42m");
+        assert_eq!(format_remaining(now + Duration::minutes(185), now), "3h 5m");
+        assert_eq!(format_remaining(now - Duration::minutes(1), now), "expired");
+    }
+
+    #[test]
+    fn session_expiry_flags_short_and_expired_credentials() {
+        let now = Utc::now();
+        let mut session = test_session("s", SessionStatus::Running, Some("c1"));
+        session.credential_expiry = [
+            ("aws", now + Duration::seconds(38 * 60 + 30)),
+            ("azure", now + Duration::minutes(5)),
+            ("gcp", now - Duration::minutes(3)),
+        ]
+        .into_iter()
+        .map(|(provider, at)| (provider.to_string(), at))
+        .collect();
+
+        assert_eq!(
+            session_expiry(&session, now),
+            vec![
+                ("aws: 38m left".to_string(), ExpiryState::Valid),
+                ("azure: 5m left".to_string(), ExpiryState::Expiring),
+                ("gcp: expired".to_string(), ExpiryState::Expired),
+            ]
+        );
+    }
+
+    #[test]
+    fn cached_until_ignores_expired_and_other_providers() {
+        let now = Utc::now();
+        let entry = |provider: &str, expires_at| {
+            (
+                format!("{}-key", provider),
+                CachedCredential::new(provider, "secret".to_string(), expires_at),
+            )
+        };
+        let entries = vec![
+            entry("aws", now + Duration::hours(1)),
+            entry("aws", now + Duration::hours(2)),
+            entry("gcp", now - Duration::hours(1)),
+        ];
+
+        assert_eq!(
+            cached_until(&entries, CredentialProvider::Aws),
+            Some(now + Duration::hours(2))
+        );
+        assert_eq!(cached_until(&entries, CredentialProvider::Gcp), None);
+        assert_eq!(cached_until(&entries, CredentialProvider::Azure), None);
+    }
+}
@@ -1,9 +1,10 @@
 //! Exec command - execute a command in a running session
 
+use crate::audit::AuditLog;
 use crate::cli::args::ExecArgs;
 use crate::config::Config;
 use crate::error::{MinoError, MinoResult};
-use crate::orchestration::{create_runtime, ContainerRuntime};
+use crate::orchestration::{create_runtime_with_backend, ContainerRuntime};
 use crate::sandbox::RuntimeMode;
 use crate::session::{Session, SessionManager, SessionStatus};
 use crate::ui::{self, UiContext};
@@ -35,13 +36,17 @@ pub async fn execute(mut args: ExecArgs, config: &Config) -> MinoResult<()> {
         args.command
     };
 
+    let tty = std::io::stdin().is_terminal();
+    AuditLog::new(config)
+        .log("session.exec", &exec_audit_data(&session, &command, tty))
+        .await;
+
     let exit_code = if session.runtime_mode == Some(RuntimeMode::Native) {
         let code = exec_native(&session, &command).await?;
         debug!(code, "Native exec finished");
         code
     } else {
-        let runtime = create_runtime(config)?;
-        let tty = std::io::stdin().is_terminal();
+        let runtime = create_runtime_with_backend(config, session.backend)?;
         let code = exec_in_session(&session, &*runtime, &command, tty).await?;
         debug!(code, "Container exec finished");
         code
@@ -54,6 +59,16 @@ pub async fn execute(mut args: ExecArgs, config: &Config) -> MinoResult<()> {
     Ok(())
 }
 
+/// Audit entry for a command executed in a session
+fn exec_audit_data(session: &Session, command: &[String], tty: bool) -> serde_json::Value {
+    serde_json::json!({
+        "name": session.name,
+        "command": command,
+        "tty": tty,
+        "runtime_mode": session.runtime_mode.unwrap_or(RuntimeMode::Container).to_string(),
+    })
+}
+
 /// Resolve which session to exec into.
 async fn resolve_session(manager: &SessionManager, name: Option<&str>) -> MinoResult<Session> {
     match name {
@@ -74,6 +89,12 @@ async fn resolve_session(manager: &SessionManager, name: Option<&str>) -> MinoRe
 
 /// Validate that a named session is in Running state.
 fn validate_session_running(session: &Session) -> MinoResult<()> {
+    if session.status == SessionStatus::Paused {
+        return Err(MinoError::User(format!(
+            "Session '{}' is paused. Resume it with 'mino resume {}'.",
+            session.name, session.name
+        )));
+    }
     if session.status != SessionStatus::Running {
         return Err(MinoError::User(format!(
             "Session '{}' is not running (status: {}). Use 'mino list' to see active sessions.",
@@ -111,7 +132,7 @@ async fn exec_in_session(
 /// Uses the `SandboxPlatform` trait for platform dispatch, removing all
 /// `#[cfg]` blocks from this function.
 async fn exec_native(session: &Session, command: &[String]) -> MinoResult<i32> {
-    let platform = crate::sandbox::native::create_sandbox_platform()?;
+    let platform = crate::sandbox::native::sandbox_platform_for(session)?;
     let pid = session
         .process_id
         .ok_or_else(|| MinoError::User("No process ID for this session".to_string()))?;
@@ -176,6 +197,13 @@ mod tests {
         assert!(validate_session_running(&session).is_ok());
     }
 
+    #[test]
+    fn validate_running_rejects_paused_with_resume_hint() {
+        let session = test_session("s", SessionStatus::Paused, Some("cid"));
+        let err = validate_session_running(&session).unwrap_err();
+        assert!(err.to_string().contains("mino resume s"));
+    }
+
     #[test]
     fn validate_running_rejects_stopped() {
         let session = test_session("s", SessionStatus::Stopped, None);
@@ -201,6 +229,19 @@ mod tests {
         assert!(msg.contains("failed"));
     }
 
+    // -- exec_audit_data tests --
+
+    #[test]
+    fn exec_audit_data_records_session_and_command() {
+        let session = test_session("s", SessionStatus::Running, Some("cid"));
+        let cmd = vec!["cargo".to_string(), "test".to_string()];
+        let data = exec_audit_data(&session, &cmd, true);
+        assert_eq!(data["name"], "s");
+        assert_eq!(data["command"], serde_json::json!(["cargo", "test"]));
+        assert_eq!(data["tty"], true);
+        assert_eq!(data["runtime_mode"], "container");
+    }
+
     // -- exec_in_session tests (MockRuntime) --
 
     #[tokio::test]
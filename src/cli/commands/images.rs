@@ -0,0 +1,29 @@
+//! Images command - inspect composed layer images
+
+use crate::cli::args::{ImagesAction, ImagesArgs};
+use crate::config::Config;
+use crate::error::{MinoError, MinoResult};
+use crate::layer::{normalize_composed_tag, read_build_log};
+use crate::ui::{self, UiContext};
+
+/// Execute the images command
+pub async fn execute(args: ImagesArgs, _config: &Config) -> MinoResult<()> {
+    match args.action {
+        ImagesAction::Log { tag } => show_build_log(&tag).await,
+    }
+}
+
+/// Print the persisted build log of a composed image
+async fn show_build_log(tag: &str) -> MinoResult<()> {
+    match read_build_log(tag).await? {
+        Some(log) => {
+            ui::page(&UiContext::detect(), &log);
+            Ok(())
+        }
+        None => Err(MinoError::User(format!(
+            "No build log for {}. Logs are recorded when mino builds a composed image; \
+             images built before this version have none.",
+            normalize_composed_tag(tag)
+        ))),
+    }
+}
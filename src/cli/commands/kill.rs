@@ -0,0 +1,254 @@
+//! Kill command - send a signal to a session's main process
+
+use crate::audit::AuditLog;
+use crate::cli::args::KillArgs;
+use crate::config::Config;
+use crate::error::{MinoError, MinoResult};
+use crate::orchestration::{create_runtime_with_backend, ContainerRuntime};
+use crate::sandbox::RuntimeMode;
+use crate::session::{Session, SessionManager, SessionStatus};
+use crate::ui::{self, UiContext};
+use console::style;
+use std::time::Duration;
+
+/// Signal names accepted by `mino kill`, without the `SIG` prefix
+const SIGNAL_NAMES: &[&str] = &[
+    "HUP", "INT", "QUIT", "ABRT", "KILL", "USR1", "USR2", "PIPE", "ALRM", "TERM", "CONT", "STOP",
+    "TSTP", "WINCH",
+];
+
+/// Signals whose default action leaves the process running
+const NON_TERMINATING: &[&str] = &["SIGCONT", "SIGSTOP", "SIGTSTP", "SIGWINCH"];
+
+/// How often, and how many times, to check whether a signaled container exited
+const EXIT_POLL_INTERVAL: Duration = Duration::from_millis(200);
+const EXIT_POLLS: u32 = 5;
+
+/// Execute the kill command
+pub async fn execute(args: KillArgs, config: &Config) -> MinoResult<()> {
+    let ctx = UiContext::detect();
+    let signal = parse_signal(&args.signal)?;
+    let manager = SessionManager::new().await?;
+    let session = manager
+        .get(&args.session)
+        .await?
+        .ok_or_else(|| MinoError::SessionNotFound(args.session.clone()))?;
+
+    if session.runtime_mode == Some(RuntimeMode::Native) {
+        ensure_running(&session)?;
+        let pid = session.process_id.ok_or_else(|| {
+            MinoError::User(format!(
+                "Session '{}' has no recorded process",
+                session.name
+            ))
+        })?;
+        signal_native_session(pid, &signal)?;
+    } else {
+        let runtime = create_runtime_with_backend(config, session.backend)?;
+        signal_session(&session, &*runtime, &signal).await?;
+        // Nothing else notices a detached session's container exiting
+        if exited_after_signal(&session, &*runtime, &signal).await {
+            manager
+                .update_status(&session.name, SessionStatus::Stopped)
+                .await?;
+        }
+    }
+
+    AuditLog::new(config)
+        .log(
+            "session.signaled",
+            &serde_json::json!({
+                "name": &session.name,
+                "signal": &signal,
+            }),
+        )
+        .await;
+
+    ui::step_ok(
+        &ctx,
+        &format!("Sent {} to session {}", signal, style(&session.name).cyan()),
+    );
+    Ok(())
+}
+
+/// Normalize a signal to `SIGNAME`, or keep a plain number as given
+fn parse_signal(value: &str) -> MinoResult<String> {
+    let upper = value.trim().to_uppercase();
+    if let Ok(number) = upper.parse::<u32>() {
+        if (1..=64).contains(&number) {
+            return Ok(number.to_string());
+        }
+    }
+
+    let name = upper.strip_prefix("SIG").unwrap_or(&upper);
+    if SIGNAL_NAMES.contains(&name) {
+        Ok(format!("SIG{}", name))
+    } else {
+        Err(MinoError::User(format!(
+            "Unknown signal '{}'. Use a name like SIGUSR1 or a number from 1 to 64",
+            value
+        )))
+    }
+}
+
+/// Signals reach only live processes
+fn ensure_running(session: &Session) -> MinoResult<()> {
+    if matches!(
+        session.status,
+        SessionStatus::Running | SessionStatus::Starting | SessionStatus::Paused
+    ) {
+        Ok(())
+    } else {
+        Err(MinoError::User(format!(
+            "Session '{}' is {}; only running sessions can be signaled",
+            session.name, session.status
+        )))
+    }
+}
+
+/// Send `signal` to a container session's main process
+async fn signal_session(
+    session: &Session,
+    runtime: &dyn ContainerRuntime,
+    signal: &str,
+) -> MinoResult<()> {
+    ensure_running(session)?;
+    let container_id = session
+        .container_id
+        .as_deref()
+        .ok_or_else(|| MinoError::ContainerNotFound(session.name.clone()))?;
+    runtime.signal(container_id, signal).await
+}
+
+/// Whether the session's container exited shortly after `signal`
+async fn exited_after_signal(
+    session: &Session,
+    runtime: &dyn ContainerRuntime,
+    signal: &str,
+) -> bool {
+    let Some(container_id) = session.container_id.as_deref() else {
+        return false;
+    };
+    if NON_TERMINATING.contains(&signal) {
+        return false;
+    }
+    for _ in 0..EXIT_POLLS {
+        match runtime.container_is_running(container_id).await {
+            Ok(false) => return true,
+            Ok(true) => tokio::time::sleep(EXIT_POLL_INTERVAL).await,
+            Err(_) => return false,
+        }
+    }
+    false
+}
+
+/// Signal the native sandbox helper, which relays it to the sandboxed process
+#[cfg(unix)]
+fn signal_native_session(pid: u32, signal: &str) -> MinoResult<()> {
+    let raw_pid = crate::sandbox::process::pid_to_pid_t(pid)?;
+    let signum = match signal.parse::<libc::c_int>() {
+        Ok(number) => number,
+        Err(_) => host_signal(signal),
+    };
+    // SAFETY: libc::kill sends a signal to a process identified by PID.
+    // The PID comes from the session record and the signal number from
+    // libc's constants or a validated 1-64 number.
+    let result = unsafe { libc::kill(raw_pid, signum) };
+    if result != 0 {
+        let err = std::io::Error::last_os_error();
+        if err.raw_os_error() == Some(libc::ESRCH) {
+            return Err(MinoError::User(format!(
+                "Process {} of the session has already exited",
+                pid
+            )));
+        }
+        return Err(MinoError::io(format!("signaling PID {}", pid), err));
+    }
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn signal_native_session(pid: u32, signal: &str) -> MinoResult<()> {
+    let _ = (pid, signal);
+    Err(MinoError::NativeUnsupported {
+        feature: "process signals".to_string(),
+    })
+}
+
+/// Host signal number for a name produced by [`parse_signal`]
+#[cfg(unix)]
+fn host_signal(name: &str) -> libc::c_int {
+    match name {
+        "SIGHUP" => libc::SIGHUP,
+        "SIGINT" => libc::SIGINT,
+        "SIGQUIT" => libc::SIGQUIT,
+        "SIGABRT" => libc::SIGABRT,
+        "SIGUSR1" => libc::SIGUSR1,
+        "SIGUSR2" => libc::SIGUSR2,
+        "SIGPIPE" => libc::SIGPIPE,
+        "SIGALRM" => libc::SIGALRM,
+        "SIGTERM" => libc::SIGTERM,
+        "SIGCONT" => libc::SIGCONT,
+        "SIGSTOP" => libc::SIGSTOP,
+        "SIGTSTP" => libc::SIGTSTP,
+        "SIGWINCH" => libc::SIGWINCH,
+        // SIGKILL, the only name left in SIGNAL_NAMES
+        _ => libc::SIGKILL,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::orchestration::mock::{test_session, MockResponse, MockRuntime};
+
+    #[test]
+    fn parse_signal_normalizes_names_and_numbers() {
+        assert_eq!(parse_signal("SIGUSR1").unwrap(), "SIGUSR1");
+        assert_eq!(parse_signal("usr1").unwrap(), "SIGUSR1");
+        assert_eq!(parse_signal(" SigHup ").unwrap(), "SIGHUP");
+        assert_eq!(parse_signal("10").unwrap(), "10");
+        assert!(parse_signal("0").is_err());
+        assert!(parse_signal("SIGFOO").is_err());
+    }
+
+    #[tokio::test]
+    async fn signal_session_sends_to_container() {
+        let session = test_session("s", SessionStatus::Running, Some("cid"));
+        let mock = MockRuntime::new();
+
+        signal_session(&session, &mock, "SIGUSR1").await.unwrap();
+        mock.assert_called_with("signal", &["cid", "SIGUSR1"]);
+    }
+
+    #[tokio::test]
+    async fn exit_after_signal_is_detected() {
+        let session = test_session("s", SessionStatus::Running, Some("cid"));
+
+        let exited = MockRuntime::new();
+        assert!(exited_after_signal(&session, &exited, "SIGKILL").await);
+        exited.assert_called_with("container_is_running", &["cid"]);
+
+        let running = (0..EXIT_POLLS).fold(MockRuntime::new(), |mock, _| {
+            mock.on("container_is_running", Ok(MockResponse::Bool(true)))
+        });
+        assert!(!exited_after_signal(&session, &running, "SIGTERM").await);
+        running.assert_called("container_is_running", EXIT_POLLS as usize);
+
+        let resumed = MockRuntime::new();
+        assert!(!exited_after_signal(&session, &resumed, "SIGCONT").await);
+        resumed.assert_no_calls();
+    }
+
+    #[tokio::test]
+    async fn signal_session_rejects_stopped_session() {
+        let session = test_session("s", SessionStatus::Stopped, Some("cid"));
+        let mock = MockRuntime::new();
+
+        let err = signal_session(&session, &mock, "SIGUSR1")
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("only running sessions"));
+        mock.assert_no_calls();
+    }
+}
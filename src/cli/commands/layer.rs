@@ -0,0 +1,420 @@
+//! Layer command - list, inspect, scaffold, and test layers
+
+use crate::cli::args::{LayerAction, LayerArgs, OutputFormat};
+use crate::cli::commands::run::image::{compose_layers, layer_arg_overrides, ComposeOptions};
+use crate::config::Config;
+use crate::error::{MinoError, MinoResult};
+use crate::labels;
+use crate::layer::{
+    apply_layer_args, find_layer, list_available_layers, read_build_log, resolve_layers,
+    scaffold_layer, AvailableLayer, LayerFiles,
+};
+use crate::network::shell_escape;
+use crate::orchestration::{create_runtime, ContainerConfig, ContainerRuntime};
+use crate::ui::{self, Column, Table, TaskSpinner, UiContext};
+use std::env;
+use std::path::PathBuf;
+use tracing::warn;
+
+/// Prefix of the lines the verify script prints around each command
+const VERIFY_MARKER: &str = "::mino-verify";
+
+/// Lines of build log or command output shown for a failure
+const TAIL_LINES: usize = 20;
+
+/// Execute the layer command
+pub async fn execute(args: LayerArgs, config: &Config) -> MinoResult<()> {
+    match args.action {
+        LayerAction::List { project, format } => list_layers(project, format).await,
+        LayerAction::Show { name, project } => show_layer(&name, project).await,
+        LayerAction::New { name, project } => new_layer(&name, project).await,
+        LayerAction::Test {
+            name,
+            project,
+            layer_args,
+        } => test_layer(&name, project, &layer_args, config).await,
+    }
+}
+
+/// Project directory from `--project`, defaulting to the current directory
+fn project_dir(project: Option<PathBuf>) -> MinoResult<PathBuf> {
+    match project {
+        Some(p) => Ok(p.canonicalize().unwrap_or(p)),
+        None => env::current_dir().map_err(|e| MinoError::io("getting current directory", e)),
+    }
+}
+
+async fn list_layers(project: Option<PathBuf>, format: OutputFormat) -> MinoResult<()> {
+    let layers = list_available_layers(&project_dir(project)?).await?;
+
+    match format {
+        OutputFormat::Table => {
+            let ctx = UiContext::detect();
+            ui::intro(&ctx, "Layers");
+            ui::page(&ctx, &format_layer_table(&layers));
+        }
+        OutputFormat::Json => {
+            let json: Vec<_> = layers
+                .iter()
+                .map(|l| {
+                    serde_json::json!({
+                        "name": l.name,
+                        "description": l.description,
+                        "source": l.source.label(),
+                    })
+                })
+                .collect();
+            println!("{}", serde_json::to_string_pretty(&json)?);
+        }
+        OutputFormat::Plain => {
+            for layer in &layers {
+                println!("{}", layer.name);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn format_layer_table(layers: &[AvailableLayer]) -> String {
+    let mut table = Table::new(vec![
+        Column::new("NAME", 24),
+        Column::new("SOURCE", 8).fixed(),
+        Column::new("DESCRIPTION", 60),
+    ]);
+    for layer in layers {
+        table.row(vec![
+            layer.name.clone(),
+            layer.source.label().to_string(),
+            layer.description.clone(),
+        ]);
+    }
+    table.render()
+}
+
+async fn show_layer(name: &str, project: Option<PathBuf>) -> MinoResult<()> {
+    let files = find_layer(name, &project_dir(project)?).await?;
+    let script = files.layer.install_script.content().await?;
+
+    let ctx = UiContext::detect();
+    ui::intro(&ctx, &format!("Layer {}", files.layer.manifest.layer.name));
+    ui::key_value(&ctx, "Description", &files.layer.manifest.layer.description);
+    let source = match files.dir {
+        Some(ref dir) => format!("{} ({})", files.layer.source.label(), dir.display()),
+        None => files.layer.source.label().to_string(),
+    };
+    ui::key_value(&ctx, "Source", &source);
+    ui::page(&ctx, &format_layer_files(&files, &script));
+    Ok(())
+}
+
+/// Manifest and install script, each under a heading
+fn format_layer_files(files: &LayerFiles, script: &str) -> String {
+    let mut out = String::from("# layer.toml\n\n");
+    out.push_str(files.manifest_text.trim_end());
+    out.push_str("\n\n# install.sh\n\n");
+    if files.layer.install_script.has_content() {
+        out.push_str(script.trim_end());
+        out.push('\n');
+    } else {
+        out.push_str("(none: [user_install] runs through bootstrap at container start)\n");
+    }
+    out
+}
+
+async fn new_layer(name: &str, project: Option<PathBuf>) -> MinoResult<()> {
+    let dir = scaffold_layer(&project_dir(project)?, name).await?;
+
+    let ctx = UiContext::detect();
+    ui::step_ok(
+        &ctx,
+        &format!("Created {}/{{layer.toml,install.sh}}", dir.display()),
+    );
+    ui::remark(
+        &ctx,
+        &format!("Edit them, then run: mino run --layers {}", name),
+    );
+    Ok(())
+}
+
+async fn test_layer(
+    name: &str,
+    project: Option<PathBuf>,
+    layer_args: &[(String, String, String)],
+    config: &Config,
+) -> MinoResult<()> {
+    let project_dir = project_dir(project)?;
+    let ctx = UiContext::detect();
+    ui::intro(&ctx, &format!("Test layer {}", name));
+
+    // Dependencies resolve first, so the layer under test is last
+    let mut resolved = resolve_layers(&[name.to_string()], &project_dir).await?;
+    let overrides = layer_arg_overrides(layer_args, config, &resolved);
+    apply_layer_args(&mut resolved, &overrides)?;
+    let commands = resolved
+        .last()
+        .map(|l| l.manifest.verify.commands.clone())
+        .unwrap_or_default();
+
+    let runtime = create_runtime(config)?;
+    runtime.ensure_ready().await?;
+
+    let mut spinner = TaskSpinner::new(&ctx);
+    spinner.start("Composing layer...");
+    let composed = compose_layers(
+        &*runtime,
+        &ctx,
+        &mut spinner,
+        &resolved,
+        name,
+        &ComposeOptions::default(),
+    )
+    .await;
+    let (image, env) = match composed {
+        Ok(composed) => composed,
+        Err(e) => {
+            spinner.clear();
+            if let MinoError::ImageBuild { ref tag, .. } = e {
+                if let Ok(Some(log)) = read_build_log(tag).await {
+                    ui::note(&ctx, "Build log (tail)", &tail(&log, TAIL_LINES));
+                }
+            }
+            return Err(e);
+        }
+    };
+    spinner.stop(&format!("Composed {}", image));
+
+    if commands.is_empty() {
+        ui::outro_warn(
+            &ctx,
+            "Layer composed, but its layer.toml declares no [verify] commands",
+        );
+        return Ok(());
+    }
+
+    spinner.start("Running verify commands...");
+    let results = run_verify(&*runtime, &image, env, &commands).await;
+    spinner.clear();
+    let results = results?;
+
+    let mut failed = 0;
+    for result in &results {
+        match result.exit {
+            Some(0) => ui::step_ok(&ctx, &result.command),
+            exit => {
+                failed += 1;
+                let detail = match exit {
+                    Some(code) => format!("exit {}", code),
+                    None => "did not run".to_string(),
+                };
+                ui::step_error_detail(&ctx, &result.command, &detail);
+                if !result.output.trim().is_empty() {
+                    ui::note(&ctx, "Output (tail)", &tail(&result.output, TAIL_LINES));
+                }
+            }
+        }
+    }
+
+    if failed > 0 {
+        return Err(MinoError::User(format!(
+            "{} of {} verify commands failed for layer {}",
+            failed,
+            results.len(),
+            name
+        )));
+    }
+    ui::outro_success(&ctx, &format!("{} verify commands passed", results.len()));
+    Ok(())
+}
+
+/// Outcome of one verify command
+#[derive(Debug, PartialEq)]
+struct VerifyResult {
+    command: String,
+    /// Exit code; `None` when the command never finished
+    exit: Option<i32>,
+    /// Combined stdout and stderr
+    output: String,
+}
+
+/// Run the verify commands in a throwaway container of `image`
+async fn run_verify(
+    runtime: &dyn ContainerRuntime,
+    image: &str,
+    env: std::collections::HashMap<String, String>,
+    commands: &[String],
+) -> MinoResult<Vec<VerifyResult>> {
+    let config = ContainerConfig {
+        image: image.to_string(),
+        workdir: "/workspace".to_string(),
+        volumes: vec![],
+        env,
+        network: "bridge".to_string(),
+        network_aliases: vec![],
+        interactive: false,
+        tty: false,
+        cap_add: vec![],
+        cap_drop: vec!["ALL".to_string()],
+        security_opt: vec!["no-new-privileges".to_string()],
+        pids_limit: 0,
+        ulimits: vec![],
+        cpus: 0.0,
+        memory_mb: 0,
+        gpus: false,
+        devices: vec![],
+        auto_remove: false,
+        read_only: false,
+        tmpfs: vec![],
+        user: None,
+        userns: None,
+        restart: None,
+        labels: labels::resource_labels(None, None),
+        pod: None,
+        platform: None,
+        hostname: None,
+        extra_hosts: vec![],
+    };
+    let command = vec!["sh".to_string(), "-c".to_string(), verify_script(commands)];
+
+    let container_id = runtime.create(&config, &command).await?;
+    let result = async {
+        runtime.start_detached(&container_id).await?;
+        runtime.get_container_exit_code(&container_id).await?;
+        runtime.logs(&container_id, 0).await
+    }
+    .await;
+    if let Err(e) = runtime.remove(&container_id).await {
+        warn!("Failed to remove layer test container: {}", e);
+    }
+    Ok(parse_verify_output(commands, &result?))
+}
+
+/// Shell script running each command with its output and exit code
+/// delimited by marker lines
+fn verify_script(commands: &[String]) -> String {
+    let mut lines = Vec::new();
+    for (i, command) in commands.iter().enumerate() {
+        lines.push(format!("echo '{} start {}'", VERIFY_MARKER, i));
+        lines.push(format!("sh -c '{}' 2>&1 </dev/null", shell_escape(command)));
+        lines.push(format!("echo \"{} exit {} $?\"", VERIFY_MARKER, i));
+    }
+    lines.join("\n")
+}
+
+/// Split container output into per-command results
+fn parse_verify_output(commands: &[String], logs: &str) -> Vec<VerifyResult> {
+    let mut results: Vec<VerifyResult> = commands
+        .iter()
+        .map(|command| VerifyResult {
+            command: command.clone(),
+            exit: None,
+            output: String::new(),
+        })
+        .collect();
+    let mut current: Option<usize> = None;
+    for line in logs.lines() {
+        let marker = line
+            .strip_prefix(VERIFY_MARKER)
+            .map(|rest| rest.split_whitespace().collect::<Vec<_>>());
+        match marker.as_deref() {
+            Some(["start", i]) => current = i.parse().ok().filter(|i| *i < results.len()),
+            Some(["exit", i, code]) => {
+                if let (Some(result), Ok(code)) = (
+                    i.parse::<usize>().ok().and_then(|i| results.get_mut(i)),
+                    code.parse(),
+                ) {
+                    result.exit = Some(code);
+                }
+                current = None;
+            }
+            _ => {
+                if let Some(result) = current.and_then(|i| results.get_mut(i)) {
+                    result.output.push_str(line);
+                    result.output.push('\n');
+                }
+            }
+        }
+    }
+    results
+}
+
+/// Last `n` lines of `text`
+fn tail(text: &str, n: usize) -> String {
+    let lines: Vec<&str> = text.lines().collect();
+    lines[lines.len().saturating_sub(n)..].join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layer::LayerSource;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn show_marks_bootstrap_only_layers() {
+        let temp = TempDir::new().unwrap();
+        let files = find_layer("rust", temp.path()).await.unwrap();
+        let out = format_layer_files(&files, "");
+        assert!(out.starts_with("# layer.toml\n\n[layer]\nname = \"rust\""));
+        assert!(out.contains("# install.sh\n\n(none: [user_install]"));
+    }
+
+    #[test]
+    fn verify_output_splits_per_command() {
+        let commands = vec![
+            "node --version".to_string(),
+            "pnpm --version".to_string(),
+            "tsc -v".to_string(),
+        ];
+        let script = verify_script(&commands);
+        assert!(script.contains("sh -c 'node --version' 2>&1"));
+
+        let logs = "bootstrap: installing\n\
+                    ::mino-verify start 0\nv22.1.0\n::mino-verify exit 0 0\n\
+                    ::mino-verify start 1\nsh: pnpm: not found\n::mino-verify exit 1 127\n\
+                    ::mino-verify start 2\n";
+        let results = parse_verify_output(&commands, logs);
+        assert_eq!(results[0].exit, Some(0));
+        assert_eq!(results[0].output, "v22.1.0\n");
+        assert_eq!(results[1].exit, Some(127));
+        assert!(results[1].output.contains("not found"));
+        assert_eq!(results[2].exit, None);
+    }
+
+    #[tokio::test]
+    async fn run_verify_removes_container() {
+        use crate::orchestration::mock::{MockResponse, MockRuntime};
+        let runtime = MockRuntime::new().on(
+            "logs",
+            Ok(MockResponse::String(
+                "::mino-verify start 0\nok\n::mino-verify exit 0 0\n".to_string(),
+            )),
+        );
+        let results = run_verify(
+            &runtime,
+            "mino-composed-abc",
+            Default::default(),
+            &["true".to_string()],
+        )
+        .await
+        .unwrap();
+        assert_eq!(results[0].exit, Some(0));
+        runtime.assert_called("remove", 1);
+    }
+
+    #[test]
+    fn tail_keeps_last_lines() {
+        assert_eq!(tail("a\nb\nc", 2), "b\nc");
+        assert_eq!(tail("a", 5), "a");
+    }
+
+    #[test]
+    fn table_lists_source() {
+        let out = format_layer_table(&[AvailableLayer {
+            name: "protoc".to_string(),
+            description: "Protocol buffers".to_string(),
+            source: LayerSource::ProjectLocal,
+        }]);
+        assert!(out.contains("protoc"));
+        assert!(out.contains("project"));
+    }
+}
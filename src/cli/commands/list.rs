@@ -1,15 +1,23 @@
 //! List command - show active sessions
 
+use super::creds::{session_expiry, ExpiryState};
 use crate::cli::args::{ListArgs, OutputFormat};
 use crate::config::Config;
 use crate::error::MinoResult;
+use crate::orchestration::create_runtime_with_backend;
 use crate::sandbox::RuntimeMode;
 use crate::session::{Session, SessionManager, SessionStatus};
-use crate::ui::{self, UiContext};
-use console::{pad_str, style, Alignment};
+use crate::ui::{self, Column, Table, UiContext};
+use chrono::{DateTime, Utc};
+use console::style;
+use std::fmt::Write;
+use tracing::debug;
+
+/// Rows shown by the table format when `--limit` is not given
+const DEFAULT_TABLE_LIMIT: usize = 20;
 
 /// Execute the list command
-pub async fn execute(args: ListArgs, _config: &Config) -> MinoResult<()> {
+pub async fn execute(args: ListArgs, config: &Config) -> MinoResult<()> {
     let manager = SessionManager::new().await?;
     let sessions = manager.list().await?;
 
@@ -27,14 +35,29 @@ pub async fn execute(args: ListArgs, _config: &Config) -> MinoResult<()> {
         return Ok(());
     }
 
+    let limit = match (args.limit, args.format) {
+        (Some(limit), _) => limit,
+        (None, OutputFormat::Table) => DEFAULT_TABLE_LIMIT,
+        (None, _) => 0,
+    };
+    let mut shown = apply_limit(&filtered, limit).to_vec();
+    load_restart_counts(&mut shown, config).await;
+
     match args.format {
-        OutputFormat::Table => print_table(&filtered),
+        OutputFormat::Table => {
+            let ctx = UiContext::detect();
+            ui::intro(&ctx, "Sessions");
+            let mut table = format_table(&shown, Utc::now());
+            table.push('\n');
+            table.push_str(&format_footer(&filtered, shown.len()));
+            ui::page(&ctx, &table);
+        }
         OutputFormat::Json => {
-            let json = format_json(&filtered)?;
+            let json = format_json(&shown)?;
             println!("{}", json);
         }
         OutputFormat::Plain => {
-            let plain = format_plain(&filtered);
+            let plain = format_plain(&shown);
             print!("{}", plain);
         }
     }
@@ -42,18 +65,70 @@ pub async fn execute(args: ListArgs, _config: &Config) -> MinoResult<()> {
     Ok(())
 }
 
-/// Filter sessions by active status (Running/Starting) unless `show_all` is true.
+/// Filter sessions by active status (Running/Starting/Paused) unless `show_all` is true.
 fn filter_sessions(sessions: Vec<Session>, show_all: bool) -> Vec<Session> {
     if show_all {
         sessions
     } else {
         sessions
             .into_iter()
-            .filter(|s| matches!(s.status, SessionStatus::Running | SessionStatus::Starting))
+            .filter(|s| {
+                matches!(
+                    s.status,
+                    SessionStatus::Running | SessionStatus::Starting | SessionStatus::Paused
+                )
+            })
             .collect()
     }
 }
 
+/// Keep the first `limit` sessions (0 = no limit). Sessions are listed newest first.
+fn apply_limit(sessions: &[Session], limit: usize) -> &[Session] {
+    if limit == 0 {
+        sessions
+    } else {
+        &sessions[..limit.min(sessions.len())]
+    }
+}
+
+/// Ask the runtime how often each live session with a restart policy has
+/// been restarted. Best-effort: sessions whose count cannot be read show none.
+async fn load_restart_counts(sessions: &mut [Session], config: &Config) {
+    for session in sessions.iter_mut() {
+        let Some(container_id) = &session.container_id else {
+            continue;
+        };
+        if session.restart_policy.is_none()
+            || !matches!(
+                session.status,
+                SessionStatus::Running | SessionStatus::Paused
+            )
+        {
+            continue;
+        }
+        let count = match create_runtime_with_backend(config, session.backend) {
+            Ok(runtime) => runtime.container_restart_count(container_id).await,
+            Err(e) => Err(e),
+        };
+        match count {
+            Ok(count) => session.restart_count = count,
+            Err(e) => debug!("Restart count of session {}: {}", session.name, e),
+        }
+    }
+}
+
+/// Restarts column: `count/max` for `on-failure:max`, the bare count for
+/// unlimited retries, and `-` without a policy or a known count.
+fn restarts_label(session: &Session) -> String {
+    let (Some(policy), Some(count)) = (&session.restart_policy, session.restart_count) else {
+        return "-".to_string();
+    };
+    match policy.split_once(':') {
+        Some((_, max)) => format!("{}/{}", count, max),
+        None => count.to_string(),
+    }
+}
+
 /// Format sessions as pretty-printed JSON.
 fn format_json(sessions: &[Session]) -> MinoResult<String> {
     Ok(serde_json::to_string_pretty(sessions)?)
@@ -72,82 +147,126 @@ fn runtime_label(session: &Session) -> String {
         .to_string()
 }
 
-fn print_table(sessions: &[Session]) {
-    const W_NAME: usize = 20;
-    const W_STATUS: usize = 12;
-    const W_RUNTIME: usize = 10;
-    const W_STARTED: usize = 15;
-    const W_PROJECT: usize = 30;
-
-    let ctx = UiContext::detect();
-    ui::intro(&ctx, "Sessions");
-
-    println!(
-        "{} {} {} {} {}",
-        pad_str(
-            &style("NAME").bold().to_string(),
-            W_NAME,
-            Alignment::Left,
-            None
-        ),
-        pad_str(
-            &style("STATUS").bold().to_string(),
-            W_STATUS,
-            Alignment::Left,
-            None
-        ),
-        pad_str(
-            &style("RUNTIME").bold().to_string(),
-            W_RUNTIME,
-            Alignment::Left,
-            None
-        ),
-        pad_str(
-            &style("STARTED").bold().to_string(),
-            W_STARTED,
-            Alignment::Left,
-            None
-        ),
-        pad_str(
-            &style("PROJECT").bold().to_string(),
-            W_PROJECT,
-            Alignment::Left,
-            None
-        ),
-    );
-    println!(
-        "{}",
-        "-".repeat(W_NAME + 1 + W_STATUS + 1 + W_RUNTIME + 1 + W_STARTED + 1 + W_PROJECT)
-    );
+/// Human-friendly age of a timestamp ("just now", "5m ago", "2h ago", "3d ago").
+///
+/// Falls back to the date once a timestamp is more than 30 days old.
+fn relative_time(at: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let secs = (now - at).num_seconds().max(0);
+    match secs {
+        0..=59 => "just now".to_string(),
+        60..=3599 => format!("{}m ago", secs / 60),
+        3600..=86_399 => format!("{}h ago", secs / 3600),
+        86_400..=2_591_999 => format!("{}d ago", secs / 86_400),
+        _ => at.format("%Y-%m-%d").to_string(),
+    }
+}
 
-    for session in sessions {
-        let status_styled = match session.status {
-            SessionStatus::Running => style("running").green().to_string(),
-            SessionStatus::Starting => style("starting").yellow().to_string(),
-            SessionStatus::Stopped => style("stopped").dim().to_string(),
-            SessionStatus::Failed => style("failed").red().to_string(),
-        };
+/// Status with its color: green running, yellow starting, cyan paused, dim
+/// stopped, red failed
+fn styled_status(status: SessionStatus) -> String {
+    let label = status.to_string();
+    match status {
+        SessionStatus::Running => style(label).green().to_string(),
+        SessionStatus::Starting => style(label).yellow().to_string(),
+        SessionStatus::Paused => style(label).cyan().to_string(),
+        SessionStatus::Stopped => style(label).dim().to_string(),
+        SessionStatus::Failed => style(label).red().to_string(),
+    }
+}
+
+/// Credentials column: time left per provider, yellow when under ten
+/// minutes, red once expired. `-` for finished sessions and sessions
+/// without expiring credentials.
+fn credentials_label(session: &Session, now: DateTime<Utc>) -> String {
+    let expiry = session_expiry(session, now);
+    if expiry.is_empty()
+        || matches!(
+            session.status,
+            SessionStatus::Stopped | SessionStatus::Failed
+        )
+    {
+        return "-".to_string();
+    }
+    expiry
+        .into_iter()
+        .map(|(label, state)| match state {
+            ExpiryState::Valid => label,
+            ExpiryState::Expiring => style(label).yellow().to_string(),
+            ExpiryState::Expired => style(label).red().to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
 
-        let runtime = runtime_label(session);
-        let started = session.created_at.format("%Y-%m-%d %H:%M").to_string();
+/// Format the session table (header, separator, one row per session).
+fn format_table(sessions: &[Session], now: DateTime<Utc>) -> String {
+    let mut table = Table::new(vec![
+        Column::new("NAME", 20),
+        Column::new("STATUS", 12).fixed(),
+        Column::new("RESTARTS", 9).fixed(),
+        Column::new("RUNTIME", 10).fixed(),
+        Column::new("STARTED", 15).fixed(),
+        Column::new("HOSTNAME", 25),
+        Column::new("CREDENTIALS", 30),
+        Column::new("PROJECT", 30),
+    ]);
+
+    for session in sessions {
         let project = session
             .project_dir
             .file_name()
             .and_then(|n| n.to_str())
             .unwrap_or("unknown");
+        let cells = vec![
+            session.name.clone(),
+            styled_status(session.status),
+            restarts_label(session),
+            runtime_label(session),
+            relative_time(session.created_at, now),
+            session.hostname.clone().unwrap_or_else(|| "-".to_string()),
+            credentials_label(session, now),
+            project.to_string(),
+        ];
+        // Dim finished sessions so running ones stand out in long lists
+        match session.status {
+            SessionStatus::Stopped => table.dim_row(cells),
+            _ => table.row(cells),
+        }
+    }
 
-        println!(
-            "{} {} {} {} {}",
-            pad_str(&session.name, W_NAME, Alignment::Left, None),
-            pad_str(&status_styled, W_STATUS, Alignment::Left, None),
-            pad_str(&runtime, W_RUNTIME, Alignment::Left, None),
-            pad_str(&started, W_STARTED, Alignment::Left, None),
-            pad_str(project, W_PROJECT, Alignment::Left, None),
+    table.render()
+}
+
+/// Summary footer: per-status counts, and a note when rows were cut by `--limit`.
+fn format_footer(sessions: &[Session], shown: usize) -> String {
+    let count = |status: SessionStatus| sessions.iter().filter(|s| s.status == status).count();
+    let counts: Vec<String> = [
+        SessionStatus::Running,
+        SessionStatus::Starting,
+        SessionStatus::Paused,
+        SessionStatus::Stopped,
+        SessionStatus::Failed,
+    ]
+    .into_iter()
+    .filter_map(|status| match count(status) {
+        0 => None,
+        n => Some(format!("{} {}", n, styled_status(status))),
+    })
+    .collect();
+
+    let mut out = format!("{} session(s): {}\n", sessions.len(), counts.join(", "));
+    if shown < sessions.len() {
+        let _ = writeln!(
+            out,
+            "{}",
+            style(format!(
+                "Showing the {} newest. Use --limit 0 to show all.",
+                shown
+            ))
+            .dim()
         );
     }
-
-    println!();
-    println!("{} session(s)", sessions.len());
+    out
 }
 
 #[cfg(test)]
@@ -246,4 +365,119 @@ mod tests {
         session.runtime_mode = Some(RuntimeMode::Native);
         assert_eq!(runtime_label(&session), "native");
     }
+
+    // -- apply_limit tests --
+
+    #[test]
+    fn apply_limit_truncates_and_zero_means_all() {
+        let sessions: Vec<Session> = (0..5)
+            .map(|i| test_session(&format!("s{i}"), SessionStatus::Stopped, None))
+            .collect();
+        assert_eq!(apply_limit(&sessions, 2).len(), 2);
+        assert_eq!(apply_limit(&sessions, 2)[0].name, "s0");
+        assert_eq!(apply_limit(&sessions, 10).len(), 5);
+        assert_eq!(apply_limit(&sessions, 0).len(), 5);
+    }
+
+    // -- relative_time tests --
+
+    #[test]
+    fn relative_time_buckets() {
+        let now = Utc::now();
+        let ago = |secs: i64| relative_time(now - chrono::Duration::seconds(secs), now);
+        assert_eq!(ago(5), "just now");
+        assert_eq!(ago(5 * 60), "5m ago");
+        assert_eq!(ago(2 * 3600), "2h ago");
+        assert_eq!(ago(3 * 86_400), "3d ago");
+
+        let old = now - chrono::Duration::days(45);
+        assert_eq!(relative_time(old, now), old.format("%Y-%m-%d").to_string());
+    }
+
+    #[test]
+    fn relative_time_future_is_just_now() {
+        let now = Utc::now();
+        assert_eq!(
+            relative_time(now + chrono::Duration::seconds(30), now),
+            "just now"
+        );
+    }
+
+    // -- format_footer tests --
+
+    #[test]
+    fn footer_counts_statuses_and_notes_truncation() {
+        let sessions = vec![
+            test_session("a", SessionStatus::Running, None),
+            test_session("b", SessionStatus::Stopped, None),
+            test_session("c", SessionStatus::Stopped, None),
+            test_session("d", SessionStatus::Failed, None),
+        ];
+
+        let footer = console::strip_ansi_codes(&format_footer(&sessions, 4)).into_owned();
+        assert!(footer.starts_with("4 session(s): 1 running, 2 stopped, 1 failed\n"));
+        assert!(!footer.contains("--limit"));
+
+        let footer = console::strip_ansi_codes(&format_footer(&sessions, 2)).into_owned();
+        assert!(footer.contains("Showing the 2 newest. Use --limit 0 to show all."));
+    }
+
+    #[test]
+    fn table_has_row_per_session() {
+        let sessions = vec![
+            test_session("a", SessionStatus::Running, None),
+            test_session("b", SessionStatus::Failed, None),
+        ];
+        let table = format_table(&sessions, Utc::now());
+        // header + separator + rows
+        assert_eq!(table.lines().count(), 4);
+        assert!(table.contains("just now"));
+    }
+
+    #[test]
+    fn table_shows_session_hostname() {
+        let mut dns = test_session("api", SessionStatus::Running, Some("c1"));
+        dns.hostname = Some("api.mino".to_string());
+        let sessions = vec![dns, test_session("b", SessionStatus::Running, None)];
+
+        let table = console::strip_ansi_codes(&format_table(&sessions, Utc::now())).into_owned();
+        assert!(table.lines().next().unwrap().contains("HOSTNAME"));
+        assert!(table.contains("api.mino"));
+        assert!(table.lines().nth(3).unwrap().contains(" - "));
+    }
+
+    #[test]
+    fn credentials_label_shows_time_left_while_active() {
+        let now = Utc::now();
+        let mut session = test_session("a", SessionStatus::Running, Some("c1"));
+        assert_eq!(credentials_label(&session, now), "-");
+
+        session.credential_expiry = [
+            ("aws".to_string(), now + chrono::Duration::minutes(45)),
+            ("gcp".to_string(), now - chrono::Duration::minutes(1)),
+        ]
+        .into();
+        let label = console::strip_ansi_codes(&credentials_label(&session, now)).into_owned();
+        assert_eq!(label, "aws: 45m left, gcp: expired");
+
+        session.status = SessionStatus::Stopped;
+        assert_eq!(credentials_label(&session, now), "-");
+    }
+
+    #[test]
+    fn restarts_label_formats() {
+        let mut session = test_session("a", SessionStatus::Running, Some("c1"));
+        assert_eq!(restarts_label(&session), "-");
+
+        session.restart_policy = Some("on-failure:5".to_string());
+        assert_eq!(restarts_label(&session), "-");
+        session.restart_count = Some(2);
+        assert_eq!(restarts_label(&session), "2/5");
+
+        session.restart_policy = Some("on-failure".to_string());
+        assert_eq!(restarts_label(&session), "2");
+
+        let table = console::strip_ansi_codes(&format_table(&[session], Utc::now())).into_owned();
+        assert!(table.lines().next().unwrap().contains("RESTARTS"));
+    }
 }
@@ -0,0 +1,177 @@
+//! Login command - authenticate to container registries
+
+use crate::audit::AuditLog;
+use crate::cli::args::LoginArgs;
+use crate::config::Config;
+use crate::credentials::gcp::gar_registry;
+use crate::credentials::registry::{normalize_registry, RegistryStore};
+use crate::credentials::{AwsCredentials, CredentialCache, GcpCredentials};
+use crate::error::{MinoError, MinoResult};
+use crate::orchestration::create_runtime;
+use crate::ui::{self, UiContext};
+use console::style;
+
+/// Execute the login command
+pub async fn execute(args: LoginArgs, config: &Config) -> MinoResult<()> {
+    let ctx = UiContext::detect();
+    let mut store = RegistryStore::load().await;
+
+    if args.list {
+        print_logins(&store);
+        return Ok(());
+    }
+
+    // clap guarantees a registry unless --list, --ecr or --gar is given
+    let registry = normalize_registry(args.registry.as_deref().unwrap_or_default());
+    let runtime = create_runtime(config)?;
+
+    if args.logout {
+        runtime.registry_logout(&registry).await?;
+        if store.remove(&registry) {
+            store.save().await?;
+        }
+        AuditLog::new(config)
+            .log(
+                "registry.logout",
+                &serde_json::json!({ "registry": &registry }),
+            )
+            .await;
+        ui::step_ok(&ctx, &format!("Logged out of {}", style(&registry).cyan()));
+        return Ok(());
+    }
+
+    let (registry, username, provider) = match cloud_login(&ctx, &args, config).await? {
+        Some(login) => {
+            runtime
+                .registry_login_password(&login.registry, login.username, &login.password)
+                .await?;
+            (
+                login.registry,
+                Some(login.username.to_string()),
+                Some(login.provider),
+            )
+        }
+        None => {
+            runtime
+                .registry_login(&registry, args.username.as_deref(), args.password_stdin)
+                .await?;
+            (registry, args.username, None)
+        }
+    };
+    store.record(&registry, username.as_deref(), runtime.runtime_name());
+    store.save().await?;
+    AuditLog::new(config)
+        .log(
+            "registry.login",
+            &serde_json::json!({
+                "registry": &registry,
+                "username": &username,
+                "provider": provider,
+                "runtime": runtime.runtime_name(),
+            }),
+        )
+        .await;
+
+    ui::step_ok(
+        &ctx,
+        &format!(
+            "Logged in to {} ({})",
+            style(&registry).cyan(),
+            runtime.runtime_name()
+        ),
+    );
+    Ok(())
+}
+
+/// Registry login obtained from a cloud provider's credentials
+struct CloudLogin {
+    provider: &'static str,
+    registry: String,
+    username: &'static str,
+    password: String,
+}
+
+/// For `--ecr`/`--gar`, fetch the provider's credentials the way
+/// `mino run` does and turn them into a registry login
+async fn cloud_login(
+    ctx: &UiContext,
+    args: &LoginArgs,
+    config: &Config,
+) -> MinoResult<Option<CloudLogin>> {
+    let credentials = &config.credentials;
+    if let Some(region) = &args.ecr {
+        let region = cloud_region(region)?;
+        let cache = CredentialCache::new().await?;
+        let command = format!("mino login --ecr {}", region);
+        let mfa_code = super::creds::aws_mfa_code(ctx, &cache, config, &command).await?;
+        let creds =
+            AwsCredentials::get_session_token(&credentials.aws, &cache, mfa_code.as_deref())
+                .await?;
+        let (registry, password) = AwsCredentials::ecr_login(&creds, region).await?;
+        return Ok(Some(CloudLogin {
+            provider: "ecr",
+            registry,
+            username: "AWS",
+            password,
+        }));
+    }
+    if let Some(region) = &args.gar {
+        let region = cloud_region(region)?;
+        let cache = CredentialCache::new().await?;
+        let password = GcpCredentials::get_access_token(&credentials.gcp, &cache).await?;
+        return Ok(Some(CloudLogin {
+            provider: "gar",
+            registry: gar_registry(region),
+            username: "oauth2accesstoken",
+            password,
+        }));
+    }
+    Ok(None)
+}
+
+/// Check a cloud region name before it becomes part of a registry host
+fn cloud_region(region: &str) -> MinoResult<&str> {
+    let valid = !region.is_empty()
+        && region
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+    if valid {
+        Ok(region)
+    } else {
+        Err(MinoError::User(format!(
+            "Invalid region '{}': expected a name like us-east-1 or europe-west1",
+            region
+        )))
+    }
+}
+
+fn print_logins(store: &RegistryStore) {
+    let mut any = false;
+    for (registry, login) in store.entries() {
+        any = true;
+        println!(
+            "{}  {}  {}  {}",
+            style(registry).cyan(),
+            login.username.as_deref().unwrap_or("-"),
+            login.runtime,
+            style(&login.logged_in_at).dim()
+        );
+    }
+    if !any {
+        println!("No registries logged in. Run: mino login <registry>");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cloud_region_rejects_host_syntax() {
+        assert_eq!(cloud_region("eu-west-1").unwrap(), "eu-west-1");
+        assert_eq!(cloud_region("europe-west1").unwrap(), "europe-west1");
+        assert!(cloud_region("").is_err());
+        assert!(cloud_region("us-east-1.evil.com/x").is_err());
+        assert!(cloud_region("US-EAST-1").is_err());
+    }
+}
@@ -3,35 +3,56 @@
 use crate::cli::args::LogsArgs;
 use crate::config::Config;
 use crate::error::{MinoError, MinoResult};
-use crate::orchestration::{create_runtime, ContainerRuntime};
+use crate::orchestration::{create_runtime, create_runtime_with_backend, ContainerRuntime};
 use crate::sandbox::RuntimeMode;
-use crate::session::{Session, SessionManager};
+use crate::session::{Session, SessionManager, SessionStatus};
+use console::{style, Color};
 use std::path::Path;
 
+/// Prefix colors cycled across sessions in `--all` mode
+const PREFIX_COLORS: &[Color] = &[
+    Color::Cyan,
+    Color::Green,
+    Color::Yellow,
+    Color::Magenta,
+    Color::Blue,
+    Color::Red,
+];
+
 /// Execute the logs command
 pub async fn execute(args: LogsArgs, config: &Config) -> MinoResult<()> {
     let manager = SessionManager::new().await?;
 
-    // Find session
+    if args.all {
+        let runtime = create_runtime(config)?;
+        return follow_all(&manager.list().await?, args.lines, &*runtime).await;
+    }
+
+    // Find session (clap guarantees a name when --all is absent)
+    let name = args.session.clone().unwrap_or_default();
     let session = manager
-        .get(&args.session)
+        .get(&name)
         .await?
-        .ok_or_else(|| MinoError::SessionNotFound(args.session.clone()))?;
+        .ok_or_else(|| MinoError::SessionNotFound(name.clone()))?;
 
     if session.runtime_mode == Some(RuntimeMode::Native) {
+        if let Some(service) = &args.service {
+            // Native sessions never have services; report it the same way
+            service_container(&session, service)?;
+        }
         let log_path = session
             .log_file
             .as_ref()
             .ok_or_else(|| MinoError::User("No log file for this session".to_string()))?;
 
         if args.follow {
-            tail_follow(log_path).await?;
+            tail_follow(log_path, &|line| print!("{}", line)).await?;
         } else {
             let output = read_log_tail(log_path, args.lines).await?;
             print!("{}", output);
         }
     } else {
-        let runtime = create_runtime(config)?;
+        let runtime = create_runtime_with_backend(config, session.backend)?;
         let output = get_container_logs(&args, &session, &*runtime).await?;
         if let Some(logs) = output {
             print!("{}", logs);
@@ -64,9 +85,9 @@ async fn read_log_tail(path: &Path, lines: u32) -> MinoResult<String> {
         }))
 }
 
-/// Follow a log file, printing new lines as they appear.
+/// Follow a log file, passing each line (with its newline) to `on_line`.
 /// This function runs indefinitely until interrupted.
-async fn tail_follow(path: &Path) -> MinoResult<()> {
+async fn tail_follow(path: &Path, on_line: &(dyn Fn(&str) + Send + Sync)) -> MinoResult<()> {
     use tokio::io::AsyncBufReadExt;
 
     let file = tokio::fs::File::open(path)
@@ -85,7 +106,7 @@ async fn tail_follow(path: &Path) -> MinoResult<()> {
         if n == 0 {
             break;
         }
-        print!("{}", line);
+        on_line(&line);
     }
 
     // Follow new content
@@ -99,8 +120,70 @@ async fn tail_follow(path: &Path) -> MinoResult<()> {
             tokio::time::sleep(std::time::Duration::from_millis(100)).await;
             continue;
         }
-        print!("{}", line);
+        on_line(&line);
+    }
+}
+
+/// Follow logs from every running session at once, docker-compose style.
+///
+/// Each line is prefixed with the session name in a per-session color.
+/// Returns once all followed containers have stopped (native log files are
+/// followed until interrupted).
+async fn follow_all(
+    sessions: &[Session],
+    lines: u32,
+    runtime: &dyn ContainerRuntime,
+) -> MinoResult<()> {
+    let running: Vec<&Session> = sessions
+        .iter()
+        .filter(|s| s.status == SessionStatus::Running)
+        .collect();
+
+    if running.is_empty() {
+        return Err(MinoError::User("No running sessions".to_string()));
     }
+
+    let width = running.iter().map(|s| s.name.len()).max().unwrap_or(0);
+
+    let follows = running.iter().enumerate().map(|(i, session)| {
+        let prefix = log_prefix(&session.name, width, i);
+        async move {
+            let on_line = |line: &str| println!("{} {}", prefix, line.trim_end_matches('\n'));
+            let result = if session.runtime_mode == Some(RuntimeMode::Native) {
+                match session.log_file.as_ref() {
+                    Some(path) => tail_follow(path, &on_line).await,
+                    None => Ok(()),
+                }
+            } else {
+                match session.container_id.as_ref() {
+                    Some(id) => {
+                        runtime
+                            .logs_follow_lines(id, lines, &|line: String| on_line(&line))
+                            .await
+                    }
+                    None => Ok(()),
+                }
+            };
+            if let Err(e) = result {
+                eprintln!(
+                    "{} {}",
+                    prefix,
+                    style(format!("log stream failed: {}", e)).red()
+                );
+            }
+        }
+    });
+
+    futures_util::future::join_all(follows).await;
+    Ok(())
+}
+
+/// Build a colored `name |` prefix padded to `width`.
+fn log_prefix(name: &str, width: usize, index: usize) -> String {
+    let color = PREFIX_COLORS[index % PREFIX_COLORS.len()];
+    style(format!("{:<width$} |", name, width = width))
+        .fg(color)
+        .to_string()
 }
 
 /// Fetch container logs for a session. Returns `Some(content)` for normal fetch,
@@ -110,10 +193,13 @@ async fn get_container_logs(
     session: &Session,
     runtime: &dyn ContainerRuntime,
 ) -> MinoResult<Option<String>> {
-    let container_id = session
-        .container_id
-        .as_ref()
-        .ok_or_else(|| MinoError::ContainerNotFound(session.name.clone()))?;
+    let container_id = match &args.service {
+        Some(service) => service_container(session, service)?,
+        None => session
+            .container_id
+            .as_ref()
+            .ok_or_else(|| MinoError::ContainerNotFound(session.name.clone()))?,
+    };
 
     if args.follow {
         runtime.logs_follow(container_id).await?;
@@ -124,6 +210,27 @@ async fn get_container_logs(
     }
 }
 
+/// Container ID of the session's `service`
+fn service_container<'a>(session: &'a Session, service: &str) -> MinoResult<&'a String> {
+    session.services.get(service).ok_or_else(|| {
+        if session.services.is_empty() {
+            MinoError::User(format!("Session {} has no services", session.name))
+        } else {
+            MinoError::User(format!(
+                "Session {} has no service '{}'. Services: {}",
+                session.name,
+                service,
+                session
+                    .services
+                    .keys()
+                    .map(String::as_str)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ))
+        }
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -132,7 +239,9 @@ mod tests {
 
     fn test_logs_args(session: &str, follow: bool, lines: u32) -> LogsArgs {
         LogsArgs {
-            session: session.to_string(),
+            session: Some(session.to_string()),
+            all: false,
+            service: None,
             follow,
             lines,
         }
@@ -189,6 +298,27 @@ mod tests {
         mock.assert_called("logs", 0);
     }
 
+    #[tokio::test]
+    async fn logs_of_service_use_its_container() {
+        let mut session = test_session("test", SessionStatus::Running, Some("main-id"));
+        session
+            .services
+            .insert("db".to_string(), "db-id".to_string());
+        let mock = MockRuntime::new().on("logs", Ok(MockResponse::String("ready".to_string())));
+        let mut args = test_logs_args("test", false, 10);
+        args.service = Some("db".to_string());
+
+        let result = get_container_logs(&args, &session, &mock).await.unwrap();
+        assert_eq!(result.as_deref(), Some("ready"));
+        mock.assert_called_with("logs", &["db-id", "10"]);
+
+        args.service = Some("redis".to_string());
+        let err = get_container_logs(&args, &session, &mock)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("Services: db"));
+    }
+
     #[tokio::test]
     async fn logs_runtime_error_propagates() {
         let session = test_session("test", SessionStatus::Running, Some("container-abc123"));
@@ -201,6 +331,39 @@ mod tests {
         assert!(result.unwrap_err().to_string().contains("runtime failure"));
     }
 
+    // -- Multiplexed logs tests --
+
+    #[tokio::test]
+    async fn follow_all_streams_only_running_containers() {
+        let sessions = vec![
+            test_session("a", SessionStatus::Running, Some("ctr-a")),
+            test_session("b", SessionStatus::Stopped, Some("ctr-b")),
+            test_session("c", SessionStatus::Running, Some("ctr-c")),
+        ];
+        let mock = MockRuntime::new();
+
+        follow_all(&sessions, 10, &mock).await.unwrap();
+
+        mock.assert_called("logs_follow_lines", 2);
+        mock.assert_called_with("logs_follow_lines", &["ctr-a", "10"]);
+    }
+
+    #[tokio::test]
+    async fn follow_all_without_running_sessions_errors() {
+        let sessions = vec![test_session("a", SessionStatus::Stopped, Some("ctr-a"))];
+        let mock = MockRuntime::new();
+
+        let err = follow_all(&sessions, 10, &mock).await.unwrap_err();
+        assert!(err.to_string().contains("No running sessions"));
+        mock.assert_no_calls();
+    }
+
+    #[test]
+    fn log_prefix_pads_to_width() {
+        let prefix = log_prefix("ab", 5, 0);
+        assert_eq!(console::strip_ansi_codes(&prefix), "ab    |");
+    }
+
     // -- Native log file tests --
 
     #[tokio::test]
@@ -1,25 +1,57 @@
 //! CLI command implementations
 
+pub mod bench;
 pub mod cache;
+pub mod checkpoint;
+pub mod clip;
 pub mod completions;
 pub mod config;
+pub mod cp;
+pub mod creds;
 pub mod exec;
+pub mod images;
 pub mod init;
+pub mod kill;
+pub mod layer;
 pub mod list;
+pub mod login;
 pub mod logs;
+pub mod onboard;
+pub mod pause;
+pub mod restore;
+pub mod resume;
 pub mod run;
+pub mod session;
 pub mod setup;
+pub mod setup_env;
+pub mod shell;
 pub mod status;
 pub mod stop;
 
+pub use bench::execute as bench;
 pub use cache::execute as cache;
+pub use checkpoint::execute as checkpoint;
+pub use clip::execute as clip;
 pub use completions::execute as completions;
 pub use config::execute as config;
+pub use cp::execute as cp;
+pub use creds::execute as creds;
 pub use exec::execute as exec;
+pub use images::execute as images;
 pub use init::execute as init;
+pub use kill::execute as kill;
+pub use layer::execute as layer;
 pub use list::execute as list;
+pub use login::execute as login;
 pub use logs::execute as logs;
+pub use onboard::execute as onboard;
+pub use pause::execute as pause;
+pub use restore::execute as restore;
+pub use resume::execute as resume;
 pub use run::execute as run;
+pub use session::execute as session;
 pub use setup::execute as setup;
+pub use setup_env::execute as setup_env;
+pub use shell::execute as shell;
 pub use status::execute as status;
 pub use stop::execute as stop;
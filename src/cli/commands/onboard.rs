@@ -0,0 +1,201 @@
+//! First-run onboarding - guided setup when no global config exists
+//!
+//! Runs once, before the first command on a machine without
+//! `~/.config/mino/config.toml`: detects the platform, offers `mino setup`
+//! when the container runtime isn't ready, writes the global config, and
+//! optionally runs a hello-world sandbox. Writing the config is what marks
+//! onboarding as done, so it is saved even when the user skips every step.
+
+use crate::cli::args::{Commands, RunArgs, SetupArgs};
+use crate::config::{Config, ConfigManager};
+use crate::error::{MinoError, MinoResult};
+use crate::orchestration::{create_runtime, Lima, OrbStack, Platform, VmProvider};
+use crate::ui::{self, UiContext};
+use clap::Parser;
+
+/// Command the hello-world sandbox runs
+const HELLO_ARGS: [&str; 8] = [
+    "run",
+    "--no-ssh-agent",
+    "--no-github",
+    "--no-cache",
+    "--no-home",
+    "--",
+    "echo",
+    "Hello from your first mino sandbox!",
+];
+
+/// Whether `command` should be preceded by the onboarding flow
+///
+/// Skipped when a config already exists or was passed with `--config`, in
+/// non-interactive environments, and for commands that configure mino
+/// themselves (`setup`, `config`, `status`).
+pub fn should_onboard(
+    command: &Commands,
+    config_exists: bool,
+    explicit_config: bool,
+    interactive: bool,
+) -> bool {
+    if config_exists || explicit_config || !interactive {
+        return false;
+    }
+    !matches!(
+        command,
+        Commands::Setup(_)
+            | Commands::Config(_)
+            | Commands::Status(_)
+            | Commands::Init(_)
+            | Commands::Completions(_)
+    )
+}
+
+/// Run the onboarding flow, leaving a global config at `manager.path()`
+pub async fn execute(manager: &ConfigManager) -> MinoResult<()> {
+    let ctx = UiContext::detect();
+    let mut config = Config::default();
+
+    ui::intro(&ctx, "Welcome to mino");
+    ui::remark(
+        &ctx,
+        "No configuration found. A few quick steps get your first sandbox running.",
+    );
+
+    let platform = Platform::detect();
+    match platform {
+        Platform::MacOS => {
+            ui::step_ok_detail(&ctx, "Platform", "macOS (containers run in a VM)");
+            config.vm.provider = choose_vm_provider(&ctx).await?.to_string();
+        }
+        Platform::Linux => ui::step_ok_detail(&ctx, "Platform", "Linux (rootless Podman)"),
+        Platform::Unsupported => {
+            return Err(MinoError::UnsupportedPlatform(
+                std::env::consts::OS.to_string(),
+            ))
+        }
+    }
+
+    let mut ready = runtime_ready(&config).await;
+    if ready {
+        ui::step_ok(&ctx, "Container runtime is ready");
+    } else {
+        ui::step_warn(&ctx, "Container runtime is not set up yet");
+        if ui::confirm(&ctx, "Run `mino setup` now to install it?", true).await? {
+            let args = SetupArgs {
+                yes: false,
+                check: false,
+                upgrade: false,
+                native: false,
+                uninstall: false,
+            };
+            if let Err(e) = super::setup(args, &config).await {
+                ui::step_warn_hint(
+                    &ctx,
+                    &format!("Setup did not finish: {}", e),
+                    "Re-run: mino setup",
+                );
+            }
+            ready = runtime_ready(&config).await;
+        }
+    }
+
+    manager.save(&config).await?;
+    ui::step_ok_detail(
+        &ctx,
+        "Created global config",
+        &manager.path().display().to_string(),
+    );
+
+    if !ready {
+        ui::outro_warn(
+            &ctx,
+            "Run `mino setup` when you're ready, then `mino status` to verify.",
+        );
+        return Ok(());
+    }
+
+    if ui::confirm(&ctx, "Run a hello-world sandbox to try it out?", true).await? {
+        ConfigManager::ensure_state_dirs().await?;
+        let args = RunArgs::try_parse_from(HELLO_ARGS)
+            .map_err(|e| MinoError::User(format!("hello-world arguments: {}", e)))?;
+        super::run(args, &config).await?;
+    }
+
+    ui::outro_success(&ctx, "You're all set. Start a session with `mino run`.");
+    Ok(())
+}
+
+/// Ask which VM manager to use, offering an installed one first
+async fn choose_vm_provider(ctx: &UiContext) -> MinoResult<VmProvider> {
+    let orbstack = OrbStack::is_installed().await;
+    let lima = Lima::is_installed().await;
+
+    let orbstack_hint = if orbstack {
+        "installed"
+    } else {
+        "fast, paid for commercial use"
+    };
+    let lima_hint = if lima {
+        "installed"
+    } else {
+        "free, open source"
+    };
+    let mut options = vec![
+        (VmProvider::OrbStack, "OrbStack", orbstack_hint),
+        (VmProvider::Lima, "Lima", lima_hint),
+    ];
+    if lima && !orbstack {
+        options.reverse();
+    }
+
+    ui::select(
+        ctx,
+        "Which VM manager should run your containers?",
+        &options,
+    )
+    .await
+}
+
+/// Whether the configured runtime answers right now
+async fn runtime_ready(config: &Config) -> bool {
+    match create_runtime(config) {
+        Ok(runtime) => runtime.is_available().await.unwrap_or(false),
+        Err(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::Cli;
+
+    fn command(args: &[&str]) -> Commands {
+        Cli::try_parse_from(args).unwrap().command
+    }
+
+    #[test]
+    fn onboards_only_on_first_interactive_run() {
+        let run = command(&["mino", "run"]);
+        assert!(should_onboard(&run, false, false, true));
+        assert!(!should_onboard(&run, true, false, true));
+        assert!(!should_onboard(&run, false, true, true));
+        assert!(!should_onboard(&run, false, false, false));
+    }
+
+    #[test]
+    fn configuration_commands_skip_onboarding() {
+        for args in [
+            &["mino", "setup"][..],
+            &["mino", "config", "show"],
+            &["mino", "status"],
+        ] {
+            assert!(!should_onboard(&command(args), false, false, true));
+        }
+    }
+
+    #[test]
+    fn hello_world_args_parse() {
+        let args = RunArgs::try_parse_from(HELLO_ARGS).unwrap();
+        assert!(args.no_cache);
+        assert_eq!(args.command.first().map(String::as_str), Some("echo"));
+    }
+}
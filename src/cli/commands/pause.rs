@@ -0,0 +1,124 @@
+//! Pause command - freeze a running session
+
+use crate::audit::AuditLog;
+use crate::cli::args::PauseArgs;
+use crate::config::Config;
+use crate::error::{MinoError, MinoResult};
+use crate::orchestration::{create_runtime_with_backend, ContainerRuntime};
+use crate::sandbox::RuntimeMode;
+use crate::session::{Session, SessionManager, SessionStatus};
+use crate::ui::{self, UiContext};
+use console::style;
+
+/// Execute the pause command
+pub async fn execute(args: PauseArgs, config: &Config) -> MinoResult<()> {
+    set_paused(&args.session, config, true).await
+}
+
+/// Pause or resume a container session and record the new status.
+///
+/// Shared by `mino pause` and `mino resume`.
+pub(crate) async fn set_paused(name: &str, config: &Config, paused: bool) -> MinoResult<()> {
+    let ctx = UiContext::detect();
+    let manager = SessionManager::new().await?;
+    let session = manager
+        .get(name)
+        .await?
+        .ok_or_else(|| MinoError::SessionNotFound(name.to_string()))?;
+
+    if session.runtime_mode == Some(RuntimeMode::Native) {
+        let command = if paused { "mino pause" } else { "mino resume" };
+        return Err(MinoError::NativeUnsupported {
+            feature: command.to_string(),
+        });
+    }
+
+    let runtime = create_runtime_with_backend(config, session.backend)?;
+    let status = transition(&session, &*runtime, paused).await?;
+    manager.update_status(&session.name, status).await?;
+
+    let event = if paused {
+        "session.paused"
+    } else {
+        "session.resumed"
+    };
+    AuditLog::new(config)
+        .log(event, &serde_json::json!({ "name": &session.name }))
+        .await;
+
+    ui::step_ok(
+        &ctx,
+        &format!("Session {} {}", style(&session.name).cyan(), status),
+    );
+    Ok(())
+}
+
+/// Freeze or thaw the session's container, returning the status to record
+async fn transition(
+    session: &Session,
+    runtime: &dyn ContainerRuntime,
+    paused: bool,
+) -> MinoResult<SessionStatus> {
+    let (from, to) = if paused {
+        (SessionStatus::Running, SessionStatus::Paused)
+    } else {
+        (SessionStatus::Paused, SessionStatus::Running)
+    };
+    if session.status != from {
+        return Err(MinoError::User(format!(
+            "Session '{}' is {}, not {}",
+            session.name, session.status, from
+        )));
+    }
+
+    let container_id = session
+        .container_id
+        .as_deref()
+        .ok_or_else(|| MinoError::ContainerNotFound(session.name.clone()))?;
+
+    if paused {
+        runtime.pause(container_id).await?;
+    } else {
+        runtime.unpause(container_id).await?;
+    }
+    Ok(to)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::orchestration::mock::{test_session, MockRuntime};
+
+    #[tokio::test]
+    async fn transition_pauses_running_session() {
+        let session = test_session("s", SessionStatus::Running, Some("cid"));
+        let mock = MockRuntime::new();
+
+        let status = transition(&session, &mock, true).await.unwrap();
+        assert_eq!(status, SessionStatus::Paused);
+        mock.assert_called_with("pause", &["cid"]);
+    }
+
+    #[tokio::test]
+    async fn transition_resumes_paused_session() {
+        let session = test_session("s", SessionStatus::Paused, Some("cid"));
+        let mock = MockRuntime::new();
+
+        let status = transition(&session, &mock, false).await.unwrap();
+        assert_eq!(status, SessionStatus::Running);
+        mock.assert_called_with("unpause", &["cid"]);
+    }
+
+    #[tokio::test]
+    async fn transition_rejects_wrong_status() {
+        let mock = MockRuntime::new();
+
+        let stopped = test_session("s", SessionStatus::Stopped, Some("cid"));
+        let err = transition(&stopped, &mock, true).await.unwrap_err();
+        assert!(err.to_string().contains("is stopped, not running"));
+
+        let running = test_session("s", SessionStatus::Running, Some("cid"));
+        assert!(transition(&running, &mock, false).await.is_err());
+        mock.assert_no_calls();
+    }
+}
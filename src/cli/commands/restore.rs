@@ -0,0 +1,123 @@
+//! Restore command - bring a checkpointed session back to life
+
+use crate::audit::AuditLog;
+use crate::cli::args::RestoreArgs;
+use crate::config::Config;
+use crate::error::{MinoError, MinoResult};
+use crate::orchestration::{create_runtime_with_backend, ContainerRuntime};
+use crate::session::{Session, SessionManager, SessionStatus};
+use crate::ui::{self, TaskSpinner, UiContext};
+use console::style;
+use tracing::debug;
+
+/// Execute the restore command
+pub async fn execute(args: RestoreArgs, config: &Config) -> MinoResult<()> {
+    let ctx = UiContext::detect();
+    let manager = SessionManager::new().await?;
+    let session = manager
+        .get(&args.session)
+        .await?
+        .ok_or_else(|| MinoError::SessionNotFound(args.session.clone()))?;
+
+    let styled_name = style(&session.name).cyan();
+    let runtime = create_runtime_with_backend(config, session.backend)?;
+    let mut spinner = TaskSpinner::new(&ctx);
+    spinner.start(&format!("Restoring session {}...", styled_name));
+    let container_id = restore_session(&session, &*runtime).await?;
+
+    manager
+        .set_container_id(&session.name, &container_id)
+        .await?;
+    manager
+        .update_status(&session.name, SessionStatus::Running)
+        .await?;
+
+    AuditLog::new(config)
+        .log(
+            "session.restored",
+            &serde_json::json!({
+                "name": &session.name,
+                "container_id": &container_id,
+            }),
+        )
+        .await;
+
+    spinner.stop(&format!("Session {} restored", styled_name));
+    ui::remark(
+        &ctx,
+        &format!("Open a shell with: mino shell {}", session.name),
+    );
+    Ok(())
+}
+
+/// Restore the session's latest checkpoint, returning the new container ID.
+///
+/// The checkpointed container is removed first: it still holds the name the
+/// restored container takes over.
+async fn restore_session(session: &Session, runtime: &dyn ContainerRuntime) -> MinoResult<String> {
+    let archive = session.checkpoint.as_deref().ok_or_else(|| {
+        MinoError::User(format!(
+            "Session '{}' has no checkpoint. Create one with: mino checkpoint {}",
+            session.name, session.name
+        ))
+    })?;
+    if matches!(
+        session.status,
+        SessionStatus::Running | SessionStatus::Starting | SessionStatus::Paused
+    ) {
+        return Err(MinoError::User(format!(
+            "Session '{}' is {}. Stop it before restoring: mino stop {}",
+            session.name, session.status, session.name
+        )));
+    }
+    if !archive.exists() {
+        return Err(MinoError::PathNotFound(archive.to_path_buf()));
+    }
+
+    if let Some(old) = &session.container_id {
+        if let Err(e) = runtime.remove(old).await {
+            debug!("Checkpointed container already gone: {}", e);
+        }
+    }
+    runtime.restore(archive).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::orchestration::mock::{test_session, MockRuntime};
+
+    #[tokio::test]
+    async fn restore_session_replaces_old_container() {
+        let archive = tempfile::NamedTempFile::new().unwrap();
+        let mut session = test_session("s", SessionStatus::Stopped, Some("old-cid"));
+        session.checkpoint = Some(archive.path().to_path_buf());
+        let mock = MockRuntime::new();
+
+        let id = restore_session(&session, &mock).await.unwrap();
+        assert_eq!(id, "mock-restored-id");
+        mock.assert_called_with("remove", &["old-cid"]);
+        mock.assert_called("restore", 1);
+    }
+
+    #[tokio::test]
+    async fn restore_session_requires_checkpoint() {
+        let session = test_session("s", SessionStatus::Stopped, Some("cid"));
+        let mock = MockRuntime::new();
+
+        let err = restore_session(&session, &mock).await.unwrap_err();
+        assert!(err.to_string().contains("no checkpoint"));
+        mock.assert_no_calls();
+    }
+
+    #[tokio::test]
+    async fn restore_session_rejects_running_session() {
+        let archive = tempfile::NamedTempFile::new().unwrap();
+        let mut session = test_session("s", SessionStatus::Running, Some("cid"));
+        session.checkpoint = Some(archive.path().to_path_buf());
+        let mock = MockRuntime::new();
+
+        assert!(restore_session(&session, &mock).await.is_err());
+        mock.assert_no_calls();
+    }
+}
@@ -0,0 +1,10 @@
+//! Resume command - thaw a paused session
+
+use crate::cli::args::ResumeArgs;
+use crate::config::Config;
+use crate::error::MinoResult;
+
+/// Execute the resume command
+pub async fn execute(args: ResumeArgs, config: &Config) -> MinoResult<()> {
+    super::pause::set_paused(&args.session, config, false).await
+}
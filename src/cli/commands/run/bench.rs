@@ -0,0 +1,272 @@
+//! Phase-timed sandbox startups for `mino bench`
+//!
+//! Drives the same steps as `mino run` (minus session records, prompts, and
+//! hooks) and records how long each phase takes.
+
+use std::future::Future;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use super::cache::setup_caches;
+use super::container::{build_container_config, requested_platform, ContainerBuildParams};
+use super::credentials::{gather_credentials, GatheredCredentials, ProviderSelection};
+use super::image::{apply_pull_policy, resolve_image, resolve_pull_policy};
+use super::propagate::Propagation;
+use super::user::resolve_container_user;
+use crate::cli::args::RunArgs;
+use crate::config::Config;
+use crate::error::{MinoError, MinoResult};
+use crate::labels;
+use crate::network::{
+    generate_iptables_wrapper, resolve_network_mode, NetworkMode, NetworkResolutionInput,
+};
+use crate::orchestration::ContainerRuntime;
+use crate::ui::{TaskSpinner, UiContext};
+use tracing::warn;
+
+/// Command each benchmark container runs: startup cost only
+const BENCH_COMMAND: &str = "true";
+
+/// A timed step of session startup
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Phase {
+    /// VM or daemon readiness (`ensure_ready`)
+    Vm,
+    /// Image pull per the pull policy
+    Pull,
+    /// Layer resolution and composed image build
+    Compose,
+    /// Cache volume setup
+    Cache,
+    /// Cloud and GitHub credential gathering
+    Creds,
+    /// User resolution and container creation
+    Create,
+    /// Container start through command exit
+    Start,
+}
+
+impl Phase {
+    /// Every phase, in report order
+    pub const ALL: [Phase; 7] = [
+        Phase::Vm,
+        Phase::Pull,
+        Phase::Compose,
+        Phase::Cache,
+        Phase::Creds,
+        Phase::Create,
+        Phase::Start,
+    ];
+
+    /// Lowercase name used in tables and JSON
+    pub fn name(self) -> &'static str {
+        match self {
+            Phase::Vm => "vm",
+            Phase::Pull => "pull",
+            Phase::Compose => "compose",
+            Phase::Cache => "cache",
+            Phase::Creds => "creds",
+            Phase::Create => "create",
+            Phase::Start => "start",
+        }
+    }
+}
+
+/// Phase durations of one startup
+#[derive(Debug, Default, Clone)]
+pub(crate) struct StartupSample {
+    durations: [Duration; Phase::ALL.len()],
+}
+
+impl StartupSample {
+    /// Add time spent in `phase`
+    pub fn record(&mut self, phase: Phase, elapsed: Duration) {
+        self.durations[phase as usize] += elapsed;
+    }
+
+    /// Time spent in `phase`
+    pub fn get(&self, phase: Phase) -> Duration {
+        self.durations[phase as usize]
+    }
+
+    /// Sum of all phases
+    pub fn total(&self) -> Duration {
+        self.durations.iter().sum()
+    }
+}
+
+/// Await `fut`, adding its wall-clock time to `phase`
+async fn timed<T>(
+    sample: &mut StartupSample,
+    phase: Phase,
+    fut: impl Future<Output = MinoResult<T>>,
+) -> MinoResult<T> {
+    let started = Instant::now();
+    let result = fut.await;
+    sample.record(phase, started.elapsed());
+    result
+}
+
+/// Start one sandbox running a no-op command and time each phase.
+///
+/// The container is removed afterwards. Returns the sample and the image
+/// the session resolved to, so cold runs know what to remove.
+pub(crate) async fn measure_startup(
+    runtime: &dyn ContainerRuntime,
+    args: &RunArgs,
+    config: &Config,
+    project_dir: &Path,
+) -> MinoResult<(StartupSample, String)> {
+    let ctx = UiContext::non_interactive();
+    let mut spinner = TaskSpinner::new(&ctx);
+    let mut sample = StartupSample::default();
+
+    timed(&mut sample, Phase::Vm, runtime.ensure_ready()).await?;
+
+    let (resolution, _) = timed(
+        &mut sample,
+        Phase::Compose,
+        resolve_image(args, config, &ctx, &mut spinner, runtime, project_dir),
+    )
+    .await?;
+
+    let pull_policy = resolve_pull_policy(args, config)?;
+    let platform = requested_platform(args, config)?;
+    timed(
+        &mut sample,
+        Phase::Pull,
+        apply_pull_policy(
+            runtime,
+            &resolution.image,
+            pull_policy,
+            platform.as_deref(),
+            &ctx,
+            &mut spinner,
+        ),
+    )
+    .await?;
+
+    let project_dirs = [project_dir.to_path_buf()];
+    let (cache_mounts, cache_env, _) = timed(
+        &mut sample,
+        Phase::Cache,
+        setup_caches(
+            runtime,
+            args,
+            config,
+            &project_dirs,
+            &resolution.cache_key_extra,
+        ),
+    )
+    .await?;
+
+    let GatheredCredentials {
+        env: credentials,
+        git_config,
+        ..
+    } = timed(
+        &mut sample,
+        Phase::Creds,
+        gather_credentials(args, config, ProviderSelection::resolve(args, config), None),
+    )
+    .await?;
+
+    let network_mode = resolve_network_mode(&NetworkResolutionInput {
+        cli_network: args.network.as_deref(),
+        cli_allow_rules: &args.network_allow,
+        cli_preset: args.network_preset.as_deref(),
+        config_network: &config.container.network,
+        config_network_allow: &config.container.network_allow,
+        config_preset: config.container.network_preset.as_deref(),
+    })?;
+    let shell_command = vec![BENCH_COMMAND.to_string()];
+    let command = if let NetworkMode::Allow(ref rules) = network_mode {
+        generate_iptables_wrapper(rules, &shell_command)
+    } else {
+        shell_command
+    };
+
+    let propagation = Propagation::default();
+    let container_id = timed(&mut sample, Phase::Create, async {
+        let user = resolve_container_user(runtime, config, &resolution.image).await?;
+        let mut container_config = build_container_config(&ContainerBuildParams {
+            args,
+            config,
+            project_dir,
+            extra_projects: &[],
+            cwd_subdir: None,
+            resolution: &resolution,
+            env_vars: credentials,
+            git_config,
+            cache_mounts: &cache_mounts,
+            cache_env,
+            propagation: &propagation,
+            passthrough_env: Default::default(),
+            network_mode: &network_mode,
+            user: &user,
+            home_mount: None,
+            scratch_mount: None,
+            container_storage_mount: None,
+            deploy_key_agent: None,
+        })?;
+        container_config.interactive = false;
+        container_config.tty = false;
+        container_config.labels = labels::resource_labels(None, Some(project_dir));
+        container_config
+            .env
+            .insert("MINO_QUIET_BOOTSTRAP".to_string(), "1".to_string());
+        runtime.create(&container_config, &command).await
+    })
+    .await?;
+
+    let exit = timed(
+        &mut sample,
+        Phase::Start,
+        runtime.start_attached(&container_id),
+    )
+    .await;
+
+    if let Err(e) = runtime.remove(&container_id).await {
+        warn!(
+            "Failed to remove benchmark container {}: {}",
+            &container_id[..12.min(container_id.len())],
+            e
+        );
+    }
+    let exit_code = exit?;
+    if exit_code != 0 {
+        return Err(MinoError::ContainerStart(format!(
+            "benchmark container exited with code {} (`{}` should always succeed)",
+            exit_code, BENCH_COMMAND
+        )));
+    }
+
+    Ok((sample, resolution.image))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_records_per_phase_and_total() {
+        let mut sample = StartupSample::default();
+        sample.record(Phase::Vm, Duration::from_millis(5));
+        sample.record(Phase::Create, Duration::from_millis(20));
+        sample.record(Phase::Create, Duration::from_millis(10));
+
+        assert_eq!(sample.get(Phase::Vm), Duration::from_millis(5));
+        assert_eq!(sample.get(Phase::Create), Duration::from_millis(30));
+        assert_eq!(sample.get(Phase::Pull), Duration::ZERO);
+        assert_eq!(sample.total(), Duration::from_millis(35));
+    }
+
+    #[test]
+    fn phase_names_follow_report_order() {
+        let names: Vec<&str> = Phase::ALL.iter().map(|p| p.name()).collect();
+        assert_eq!(
+            names,
+            ["vm", "pull", "compose", "cache", "creds", "create", "start"]
+        );
+    }
+}
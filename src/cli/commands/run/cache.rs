@@ -1,18 +1,28 @@
 //! Cache setup and finalization
 
+use crate::audit::AuditLog;
+use crate::cache::evict::{plan_eviction, projected_growth, target_bytes, EvictionCandidate};
+use crate::cache::remote::transfer_dir;
+use crate::cache::storage::{
+    container_storage_path, container_storage_volume_name, ContainerStorageVolume,
+};
 use crate::cache::{
-    detect_lockfiles, format_bytes, gb_to_bytes, resolve_state, CacheMount, CacheSidecar,
-    CacheSizeStatus, CacheState, CacheVolume, LockfileInfo,
+    detect_lockfiles_nested, format_bytes, gb_to_bytes, resolve_state, CacheMount, CacheOutcome,
+    CacheScope, CacheSidecar, CacheSizeStatus, CacheState, CacheStatsLog, CacheUseRecord,
+    CacheVolume, Ecosystem, LockfileInfo, RemoteCache, UploadOutcome,
 };
 use crate::cli::args::RunArgs;
 use crate::config::Config;
 use crate::error::{MinoError, MinoResult};
-use crate::orchestration::ContainerRuntime;
+use crate::orchestration::{ContainerRuntime, VolumeInfo};
+use crate::session::{Session, SessionManager, SessionStatus};
 use console::style;
-use std::collections::HashMap;
-use std::path::Path;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
 use tracing::{debug, warn};
 
+use super::container::chown_volume_arg;
+use super::user::ContainerUser;
 use super::CacheSession;
 
 /// Setup cache volumes and environment variables
@@ -20,7 +30,8 @@ pub(super) async fn setup_caches(
     runtime: &dyn ContainerRuntime,
     args: &RunArgs,
     config: &Config,
-    project_dir: &Path,
+    project_dirs: &[PathBuf],
+    key_extra: &HashMap<Ecosystem, String>,
 ) -> MinoResult<(Vec<CacheMount>, HashMap<String, String>, CacheSession)> {
     let mut cache_session = CacheSession::default();
     let mut cache_mounts = Vec::new();
@@ -32,10 +43,15 @@ pub(super) async fn setup_caches(
     }
 
     let lockfiles = {
-        let dir = project_dir.to_path_buf();
-        tokio::task::spawn_blocking(move || detect_lockfiles(&dir))
-            .await
-            .map_err(|e| MinoError::Internal(format!("lockfile detection task failed: {e}")))?
+        let dirs = project_dirs.to_vec();
+        let scan_depth = config.cache.scan_depth;
+        let scope = CacheScope::parse(&config.cache.scope)?;
+        let key_extra = key_extra.clone();
+        tokio::task::spawn_blocking(move || {
+            detect_project_lockfiles(&dirs, scan_depth, scope, &key_extra)
+        })
+        .await
+        .map_err(|e| MinoError::Internal(format!("lockfile detection task failed: {e}")))?
     }?;
     if lockfiles.is_empty() {
         debug!("No lockfiles detected, skipping cache setup");
@@ -44,9 +60,41 @@ pub(super) async fn setup_caches(
 
     debug!("Detected {} lockfile(s)", lockfiles.len());
 
+    cache_session.remote = RemoteCache::from_config(&config.cache.remote)?;
+    if cache_session.remote.is_some() && !runtime.supports_volume_archives() {
+        debug!(
+            "{} cannot import volume archives, skipping cache.remote",
+            runtime.runtime_name()
+        );
+        cache_session.remote = None;
+    }
+    // A fresh rebuild must not be satisfied from the remote store
+    let fill_from = if args.cache_fresh {
+        None
+    } else {
+        cache_session.remote.as_ref()
+    };
+
+    let seed = config.cache.seed_from_previous && !args.cache_fresh;
+    // A fresh rebuild force-removes the volume, and with it any container
+    // of another session mounting it
+    let in_use = if args.cache_fresh {
+        active_session_caches().await?
+    } else {
+        HashSet::new()
+    };
+
     for info in &lockfiles {
-        let (mount, should_finalize) =
-            setup_cache_for_lockfile(runtime, info, args.cache_fresh).await?;
+        let (mount, should_finalize, outcome) = setup_cache_for_lockfile(
+            runtime,
+            info,
+            args.cache_fresh,
+            &in_use,
+            args.cache_rw,
+            fill_from,
+            seed,
+        )
+        .await?;
 
         for (key, value) in info.ecosystem.cache_env_vars() {
             cache_env.insert(key.to_string(), value.to_string());
@@ -56,8 +104,23 @@ pub(super) async fn setup_caches(
             cache_session
                 .volumes_to_finalize
                 .push(mount.volume_name.clone());
+        } else if args.cache_rw {
+            cache_session.rw_overrides.push(mount.volume_name.clone());
         }
 
+        let project_dir = project_dirs
+            .iter()
+            .find(|dir| info.path.starts_with(dir))
+            .unwrap_or(&project_dirs[0]);
+        cache_session.uses.push(CacheUseRecord {
+            at: chrono::Utc::now(),
+            project_dir: project_dir.clone(),
+            ecosystem: info.ecosystem,
+            volume: mount.volume_name.clone(),
+            outcome,
+            bytes_reused: None,
+        });
+
         cache_mounts.push(mount);
     }
 
@@ -66,15 +129,138 @@ pub(super) async fn setup_caches(
     Ok((cache_mounts, cache_env, cache_session))
 }
 
-/// Setup cache for a single lockfile, returns (mount, should_finalize)
+/// Set up the container storage volume, if `[cache] container_storage` is
+/// enabled. Returns the volume argument mounting it over the container
+/// user's podman/buildah storage; the volume is shared, or per project
+/// under `scope = "project"`.
+pub(super) async fn setup_container_storage(
+    runtime: &dyn ContainerRuntime,
+    args: &RunArgs,
+    config: &Config,
+    project_dir: &Path,
+    user: &ContainerUser,
+) -> MinoResult<Option<String>> {
+    if args.no_cache || !config.cache.enabled || !config.cache.container_storage {
+        return Ok(None);
+    }
+
+    let volume_name = container_storage_volume_name(project_dir);
+    // Two podman instances writing one store corrupt it
+    match active_session_caches().await {
+        Ok(in_use) if in_use.contains(&volume_name) => {
+            eprintln!(
+                "{} Container storage {} is in use by another session; \
+                 this session starts with empty image storage",
+                style("!").yellow(),
+                volume_name
+            );
+            return Ok(None);
+        }
+        Ok(_) => {}
+        Err(e) => {
+            warn!(
+                "Not mounting container storage: cannot list sessions: {}",
+                e
+            );
+            return Ok(None);
+        }
+    }
+
+    let existing = runtime.volume_inspect(&volume_name).await?;
+    if existing.is_none() {
+        debug!("Creating container storage volume: {}", volume_name);
+        runtime
+            .volume_create(
+                &volume_name,
+                &ContainerStorageVolume::labels(project_dir, &user.name),
+            )
+            .await?;
+    }
+    let mount = format!(
+        "{}:{}",
+        volume_name,
+        container_storage_path(&user.home, user.is_root())
+    );
+    Ok(Some(if storage_needs_chown(existing.as_ref(), user) {
+        chown_volume_arg(&mount)
+    } else {
+        mount
+    }))
+}
+
+/// Whether the storage volume must be chowned to `user` on this mount.
+///
+/// A new volume is an empty root-owned directory, so it is chowned on its
+/// first mount, which is cheap, and then labeled with its owner. Later
+/// mounts by the same user skip `:U`, which would walk the whole store.
+fn storage_needs_chown(existing: Option<&VolumeInfo>, user: &ContainerUser) -> bool {
+    if user.is_root() {
+        return false;
+    }
+    match existing {
+        None => true,
+        Some(info) => {
+            let owner =
+                ContainerStorageVolume::from_labels(&info.name, &info.labels).and_then(|v| v.owner);
+            owner.as_deref() != Some(user.name.as_str())
+        }
+    }
+}
+
+/// Detect lockfiles across every project root, scoped per `scope`,
+/// skipping lockfiles that map to an already-seen cache volume (identical
+/// content in two projects sharing caches).
+pub(super) fn detect_project_lockfiles(
+    project_dirs: &[PathBuf],
+    scan_depth: u32,
+    scope: CacheScope,
+    key_extra: &HashMap<Ecosystem, String>,
+) -> MinoResult<Vec<LockfileInfo>> {
+    let mut lockfiles: Vec<LockfileInfo> = Vec::new();
+    for dir in project_dirs {
+        for info in detect_lockfiles_nested(dir, scan_depth)? {
+            let extra = key_extra.get(&info.ecosystem).map(String::as_str);
+            let info = info.scoped(scope, dir).with_key_extra(extra);
+            if !lockfiles
+                .iter()
+                .any(|l| l.volume_name() == info.volume_name())
+            {
+                lockfiles.push(info);
+            }
+        }
+    }
+    Ok(lockfiles)
+}
+
+/// Setup cache for a single lockfile, returns (mount, should_finalize, outcome)
+///
+/// Complete caches are mounted read-only unless `allow_rw` is set.
+/// `force_fresh` removes any existing volume so it is rebuilt from scratch,
+/// refusing when the volume is `in_use` by another session.
+/// On a miss, a `remote` archive of the cache fills the new volume, which
+/// then counts as complete. Without one, `seed` copies the previous cache
+/// of the same lockfile into the new volume before it is built.
 async fn setup_cache_for_lockfile(
     runtime: &dyn ContainerRuntime,
     info: &LockfileInfo,
     force_fresh: bool,
-) -> MinoResult<(CacheMount, bool)> {
+    in_use: &HashSet<String>,
+    allow_rw: bool,
+    remote: Option<&RemoteCache>,
+    seed: bool,
+) -> MinoResult<(CacheMount, bool, CacheOutcome)> {
     let volume_name = info.volume_name();
 
     if force_fresh {
+        if in_use.contains(&volume_name) {
+            return Err(MinoError::User(format!(
+                "Cache {} is in use by a running session; stop that session \
+                 before rebuilding it with --cache-fresh",
+                volume_name
+            )));
+        }
+        debug!("Removing cache {} for fresh rebuild", volume_name);
+        runtime.volume_remove(&volume_name).await?;
         CacheSidecar::delete(&volume_name).await.ok();
     }
 
@@ -84,7 +270,7 @@ async fn setup_cache_for_lockfile(
         runtime.volume_inspect(&volume_name).await?
     };
 
-    let should_finalize = match existing {
+    let (should_finalize, outcome) = match existing {
         Some(vol_info) => {
             let label_state = CacheVolume::from_labels(&vol_info.name, &vol_info.labels)
                 .map(|c| c.state)
@@ -100,7 +286,7 @@ async fn setup_cache_for_lockfile(
                         info.ecosystem,
                         &info.hash[..8]
                     );
-                    false
+                    (false, CacheOutcome::Hit)
                 }
                 CacheState::Building | CacheState::Miss => {
                     debug!(
@@ -125,7 +311,7 @@ async fn setup_cache_for_lockfile(
                             warn!("Failed to backfill sidecar for {}: {}", volume_name, e);
                         }
                     }
-                    true
+                    (true, CacheOutcome::Resume)
                 }
             }
         }
@@ -149,6 +335,22 @@ async fn setup_cache_for_lockfile(
                 warn!("Failed to create sidecar for {}: {}", volume_name, e);
             }
 
+            let mut outcome = CacheOutcome::Miss;
+            if let Some(remote) = remote {
+                if fill_from_remote(runtime, remote, &volume_name, &cache).await {
+                    outcome = CacheOutcome::Remote;
+                    if let Err(e) = sidecar.mark_complete().await {
+                        warn!("Failed to finalize cache sidecar {}: {}", volume_name, e);
+                    }
+                }
+            }
+            if seed
+                && outcome == CacheOutcome::Miss
+                && seed_from_previous(runtime, info, &volume_name, &cache).await
+            {
+                outcome = CacheOutcome::Seeded;
+            }
+
             // Re-inspect: another process may have created it first with different state
             let resolved = match runtime.volume_inspect(&volume_name).await? {
                 Some(vol_info) => {
@@ -160,17 +362,231 @@ async fn setup_cache_for_lockfile(
                 None => CacheState::Building,
             };
 
-            resolved != CacheState::Complete
+            (resolved != CacheState::Complete, outcome)
         }
     };
 
+    let state = if should_finalize {
+        CacheState::Building
+    } else {
+        CacheState::Complete
+    };
+    record_cache_use(&volume_name, info, state).await;
+
     let mount = CacheMount {
         volume_name,
         container_path: "/cache".to_string(),
         ecosystem: info.ecosystem,
+        read_only: !should_finalize && !allow_rw,
     };
 
-    Ok((mount, should_finalize))
+    Ok((mount, should_finalize, outcome))
+}
+
+/// Fill the freshly created volume from the remote archive of the cache.
+///
+/// Returns whether the volume now holds the complete cache. Failures are
+/// logged and leave an empty volume for the session to build, as without a
+/// remote store.
+async fn fill_from_remote(
+    runtime: &dyn ContainerRuntime,
+    remote: &RemoteCache,
+    volume_name: &str,
+    cache: &CacheVolume,
+) -> bool {
+    let result = with_transfer_archive(volume_name, |archive| async move {
+        if !remote.download(volume_name, &archive).await? {
+            return Ok(false);
+        }
+        runtime.volume_import(volume_name, &archive).await?;
+        Ok(true)
+    })
+    .await;
+
+    match result {
+        Ok(true) => {
+            debug!(
+                "Filled cache {} from {}",
+                volume_name,
+                remote.location(volume_name)
+            );
+            true
+        }
+        Ok(false) => {
+            debug!("No remote archive for cache {}", volume_name);
+            false
+        }
+        Err(e) => {
+            warn!(
+                "Failed to restore cache {} from {}: {}",
+                volume_name,
+                remote.location(volume_name),
+                e
+            );
+            reset_cache_volume(runtime, volume_name, cache).await;
+            false
+        }
+    }
+}
+
+/// Seed the freshly created volume with the contents of the previous
+/// complete cache of the same lockfile, so a lockfile change only downloads
+/// what changed. The new cache is still built and finalized by the session.
+/// Best-effort: failures leave an empty volume. Returns whether the volume
+/// was seeded.
+async fn seed_from_previous(
+    runtime: &dyn ContainerRuntime,
+    info: &LockfileInfo,
+    volume_name: &str,
+    cache: &CacheVolume,
+) -> bool {
+    let sidecars = match CacheSidecar::list_all().await {
+        Ok(sidecars) => sidecars,
+        Err(e) => {
+            warn!("Failed to list cache sidecars for seeding: {}", e);
+            return false;
+        }
+    };
+    let lockfiles: Vec<&Path> = info.paths().map(PathBuf::as_path).collect();
+    let Some(seed) = CacheSidecar::seed_for(&sidecars, volume_name, info.ecosystem, &lockfiles)
+    else {
+        debug!("No previous cache to seed {} from", volume_name);
+        return false;
+    };
+    // The sidecar may outlive a volume removed outside mino
+    if !matches!(runtime.volume_inspect(&seed.volume_name).await, Ok(Some(_))) {
+        debug!("Previous cache {} is gone, not seeding", seed.volume_name);
+        return false;
+    }
+
+    let source = seed.volume_name.as_str();
+    let result = with_transfer_archive(volume_name, |archive| async move {
+        runtime.volume_export(source, &archive).await?;
+        runtime.volume_import(volume_name, &archive).await
+    })
+    .await;
+
+    match result {
+        Ok(()) => {
+            eprintln!(
+                "{} Seeded {} cache from {}",
+                style("+").dim(),
+                info.ecosystem,
+                source
+            );
+            true
+        }
+        Err(MinoError::BackendUnsupported { runtime, .. }) => {
+            debug!("Cache seeding is not supported by {}", runtime);
+            false
+        }
+        Err(e) => {
+            warn!(
+                "Failed to seed cache {} from {}: {}",
+                volume_name, source, e
+            );
+            reset_cache_volume(runtime, volume_name, cache).await;
+            false
+        }
+    }
+}
+
+/// Recreate an empty cache volume, so a partial import is not mistaken for
+/// cache contents
+async fn reset_cache_volume(
+    runtime: &dyn ContainerRuntime,
+    volume_name: &str,
+    cache: &CacheVolume,
+) {
+    let recreated = async {
+        runtime.volume_remove(volume_name).await?;
+        runtime.volume_create(volume_name, &cache.labels()).await
+    }
+    .await;
+    if let Err(e) = recreated {
+        warn!("Failed to reset cache volume {}: {}", volume_name, e);
+    }
+}
+
+/// Upload a finalized cache to the remote store, best-effort
+async fn upload_to_remote(runtime: &dyn ContainerRuntime, remote: &RemoteCache, volume_name: &str) {
+    let result = with_transfer_archive(volume_name, |archive| async move {
+        runtime.volume_export(volume_name, &archive).await?;
+        remote.upload(volume_name, &archive).await
+    })
+    .await;
+
+    match result {
+        Ok(UploadOutcome::Uploaded) => {
+            debug!(
+                "Uploaded cache {} to {}",
+                volume_name,
+                remote.location(volume_name)
+            );
+        }
+        Ok(UploadOutcome::AlreadyPresent) => {
+            debug!("Remote store already has cache {}", volume_name);
+        }
+        Err(e) => {
+            warn!(
+                "Failed to upload cache {} to {}: {}",
+                volume_name,
+                remote.location(volume_name),
+                e
+            );
+        }
+    }
+}
+
+/// Run `transfer` with a scratch archive path for `volume_name`, removing
+/// the archive afterwards
+async fn with_transfer_archive<T, F, Fut>(volume_name: &str, transfer: F) -> MinoResult<T>
+where
+    F: FnOnce(PathBuf) -> Fut,
+    Fut: std::future::Future<Output = MinoResult<T>>,
+{
+    let dir = transfer_dir();
+    tokio::fs::create_dir_all(&dir)
+        .await
+        .map_err(|e| MinoError::io(format!("creating {}", dir.display()), e))?;
+    let archive = transfer_archive_path(&dir, volume_name);
+    let result = transfer(archive.clone()).await;
+    tokio::fs::remove_file(&archive).await.ok();
+    result
+}
+
+/// Scratch archive path, unique per transfer so concurrent sessions on the
+/// same cache do not collide
+fn transfer_archive_path(dir: &Path, volume_name: &str) -> PathBuf {
+    dir.join(format!("{}-{}.tar", volume_name, uuid::Uuid::new_v4()))
+}
+
+/// Record the lockfile in the cache's sidecar, so `mino cache gc --orphaned`
+/// can tell when every project using the cache is gone, and stamp the use
+/// for LRU eviction. Sidecars missing for older caches are backfilled with
+/// `state`.
+async fn record_cache_use(volume_name: &str, info: &LockfileInfo, state: CacheState) {
+    let mut sidecar = match CacheSidecar::load(volume_name).await {
+        Ok(Some(sidecar)) => sidecar,
+        Ok(None) => CacheSidecar::new(
+            volume_name.to_string(),
+            info.ecosystem,
+            info.hash.clone(),
+            state,
+        ),
+        Err(e) => {
+            warn!("Failed to load cache sidecar {}: {}", volume_name, e);
+            return;
+        }
+    };
+
+    for path in info.paths() {
+        sidecar.record_lockfile(path);
+    }
+    sidecar.touch();
+    if let Err(e) = sidecar.save().await {
+        warn!("Failed to record use of cache {}: {}", volume_name, e);
+    }
 }
 
 /// Finalize cache volumes by marking their sidecar state as complete.
@@ -178,7 +594,14 @@ async fn setup_cache_for_lockfile(
 /// This is the fix for the original bug: Podman volume labels are immutable
 /// after creation, so state transitions are now tracked via sidecar JSON files.
 /// Finalization is best-effort -- failures are logged but do not fail the session.
-pub(super) async fn finalize_caches(cache_session: &CacheSession) {
+/// Finalized caches are then uploaded when the session is marked for upload
+/// (`mino cache warm`) and `[cache.remote]` allows it.
+pub(super) async fn finalize_caches(runtime: &dyn ContainerRuntime, cache_session: &CacheSession) {
+    let upload_to = cache_session
+        .remote
+        .as_ref()
+        .filter(|r| cache_session.upload && r.uploads());
+
     for volume_name in &cache_session.volumes_to_finalize {
         debug!("Finalizing cache: {}", volume_name);
 
@@ -188,6 +611,9 @@ pub(super) async fn finalize_caches(cache_session: &CacheSession) {
                     warn!("Failed to finalize cache sidecar {}: {}", volume_name, e);
                 } else {
                     debug!("Cache {} finalized (complete via sidecar)", volume_name);
+                    if let Some(remote) = upload_to {
+                        upload_to_remote(runtime, remote, volume_name).await;
+                    }
                 }
             }
             Ok(None) => {
@@ -203,18 +629,174 @@ pub(super) async fn finalize_caches(cache_session: &CacheSession) {
     }
 }
 
-/// Check cache size and print warning if approaching or exceeding limit
-pub(super) async fn check_cache_size_warning(runtime: &dyn ContainerRuntime, config: &Config) {
-    let sizes = match runtime.volume_disk_usage("mino-cache-").await {
+/// Cache volumes of sessions that are starting, running, or paused.
+///
+/// An error when the session records cannot be read, since no cache can
+/// then be known to be unused.
+async fn active_session_caches() -> MinoResult<HashSet<String>> {
+    let sessions = SessionManager::new().await?.list().await?;
+    Ok(caches_in_use(sessions))
+}
+
+/// Cache volumes mounted by the starting, running, or paused `sessions`
+fn caches_in_use(sessions: Vec<Session>) -> HashSet<String> {
+    sessions
+        .into_iter()
+        .filter(|s| {
+            matches!(
+                s.status,
+                SessionStatus::Starting | SessionStatus::Running | SessionStatus::Paused
+            )
+        })
+        .flat_map(|s| s.cache_volumes)
+        .collect()
+}
+
+/// Eviction candidates among `complete` caches, leaving out `in_use` volumes
+fn eviction_candidates(
+    complete: &[&CacheSidecar],
+    sizes: &HashMap<String, u64>,
+    in_use: &HashSet<String>,
+) -> Vec<EvictionCandidate> {
+    complete
+        .iter()
+        .filter(|s| !in_use.contains(&s.volume_name))
+        .map(|s| EvictionCandidate {
+            volume_name: s.volume_name.clone(),
+            ecosystem: s.ecosystem,
+            size: sizes.get(&s.volume_name).copied().unwrap_or(0),
+            last_used: s.last_used(),
+            created_at: s.created_at,
+        })
+        .collect()
+}
+
+/// Evict least-recently-used complete caches until usage, counting the
+/// expected size of the caches this run builds, is under the high-water
+/// mark. Returns the bytes freed.
+async fn evict_lru_caches(
+    runtime: &dyn ContainerRuntime,
+    config: &Config,
+    sizes: &HashMap<String, u64>,
+    cache_mounts: &[CacheMount],
+    cache_session: &CacheSession,
+    usage: u64,
+    limit_bytes: u64,
+) -> u64 {
+    let sidecars = match CacheSidecar::list_all().await {
+        Ok(sidecars) => sidecars,
+        Err(e) => {
+            warn!("Failed to list cache sidecars for eviction: {}", e);
+            return 0;
+        }
+    };
+
+    let size_of = |name: &str| sizes.get(name).copied().unwrap_or(0);
+    let complete: Vec<&CacheSidecar> = sidecars
+        .iter()
+        .filter(|s| s.state == CacheState::Complete && sizes.contains_key(&s.volume_name))
+        .collect();
+    let building: Vec<(Ecosystem, u64)> = sidecars
+        .iter()
+        .filter(|s| cache_session.volumes_to_finalize.contains(&s.volume_name))
+        .map(|s| (s.ecosystem, size_of(&s.volume_name)))
+        .collect();
+    let complete_sizes: Vec<(Ecosystem, u64)> = complete
+        .iter()
+        .map(|s| (s.ecosystem, size_of(&s.volume_name)))
+        .collect();
+    let projected = usage + projected_growth(&building, &complete_sizes);
+
+    // Volume removal is forced, which would also remove any container
+    // using the volume: never consider caches another session has mounted
+    let mut in_use = match active_session_caches().await {
+        Ok(in_use) => in_use,
+        Err(e) => {
+            warn!("Skipping cache eviction: cannot list sessions: {}", e);
+            return 0;
+        }
+    };
+    in_use.extend(cache_mounts.iter().map(|m| m.volume_name.clone()));
+    let candidates = eviction_candidates(&complete, sizes, &in_use);
+    let target = target_bytes(limit_bytes, config.cache.evict_target_percent);
+    let plan = plan_eviction(candidates, projected, limit_bytes, target);
+    if plan.is_empty() {
+        return 0;
+    }
+
+    let audit = AuditLog::new(config);
+    let mut freed = 0;
+    for cache in plan {
+        if let Err(e) = runtime.volume_remove(&cache.volume_name).await {
+            warn!("Failed to evict cache {}: {}", cache.volume_name, e);
+            continue;
+        }
+        CacheSidecar::delete(&cache.volume_name).await.ok();
+        freed += cache.size;
+        eprintln!(
+            "{} Evicted {} cache {} ({}, last used {})",
+            style("-").dim(),
+            cache.ecosystem,
+            cache.volume_name,
+            format_bytes(cache.size),
+            cache.last_used.format("%Y-%m-%d")
+        );
+        audit
+            .log(
+                "cache.evicted",
+                &serde_json::json!({
+                    "volume": &cache.volume_name,
+                    "ecosystem": cache.ecosystem,
+                    "bytes": cache.size,
+                    "last_used": cache.last_used.to_rfc3339(),
+                    "usage": projected,
+                    "limit": limit_bytes,
+                }),
+            )
+            .await;
+    }
+    freed
+}
+
+/// Enforce the cache size limit: with `auto_evict`, evict least-recently-used
+/// complete caches once the limit is reached, then warn if usage is still
+/// approaching or exceeding it.
+///
+/// `cache_mounts` are the caches this run mounts, which are never evicted.
+/// Returns the measured cache sizes, `None` if they could not be measured.
+pub(super) async fn enforce_cache_limit(
+    runtime: &dyn ContainerRuntime,
+    config: &Config,
+    cache_mounts: &[CacheMount],
+    cache_session: &CacheSession,
+) -> Option<HashMap<String, u64>> {
+    let sizes = match runtime
+        .volume_disk_usage(crate::cache::volume::labels::MINO_CACHE)
+        .await
+    {
         Ok(s) => s,
-        Err(_) => return, // Silently skip if we can't get sizes
+        Err(_) => return None, // Silently skip if we can't get sizes
     };
 
-    let total_size: u64 = sizes.values().sum();
+    let mut total_size: u64 = sizes.values().sum();
     let limit_bytes = gb_to_bytes(config.cache.max_total_gb);
 
     if limit_bytes == 0 {
-        return;
+        return Some(sizes);
+    }
+
+    if config.cache.auto_evict {
+        let freed = evict_lru_caches(
+            runtime,
+            config,
+            &sizes,
+            cache_mounts,
+            cache_session,
+            total_size,
+            limit_bytes,
+        )
+        .await;
+        total_size = total_size.saturating_sub(freed);
     }
 
     let status = CacheSizeStatus::from_usage(total_size, limit_bytes);
@@ -241,4 +823,136 @@ pub(super) async fn check_cache_size_warning(runtime: &dyn ContainerRuntime, con
             );
         }
     }
+
+    Some(sizes)
+}
+
+/// Append the outcome of each cache this session mounted to the cache
+/// stats log, with the bytes each held at session start
+pub(super) async fn record_cache_stats(
+    cache_session: &CacheSession,
+    sizes: Option<&HashMap<String, u64>>,
+) {
+    let records: Vec<CacheUseRecord> = cache_session
+        .uses
+        .iter()
+        .map(|record| CacheUseRecord {
+            bytes_reused: sizes.map(|s| s.get(&record.volume).copied().unwrap_or(0)),
+            ..record.clone()
+        })
+        .collect();
+    CacheStatsLog::new().record(&records).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::orchestration::mock::{test_session, MockRuntime};
+    use tempfile::TempDir;
+
+    fn user(name: &str, home: &str) -> ContainerUser {
+        ContainerUser {
+            user_override: None,
+            name: name.to_string(),
+            home: home.to_string(),
+            home_override: false,
+        }
+    }
+
+    fn storage_volume(owner: &str) -> VolumeInfo {
+        VolumeInfo {
+            name: "mino-container-storage-abc".to_string(),
+            labels: ContainerStorageVolume::labels(Path::new("/src/a"), owner),
+            mountpoint: None,
+            created_at: None,
+            size_bytes: None,
+        }
+    }
+
+    #[test]
+    fn storage_is_chowned_only_when_new_or_owned_by_another_user() {
+        let developer = user("developer", "/home/developer");
+        assert!(storage_needs_chown(None, &developer));
+        assert!(!storage_needs_chown(
+            Some(&storage_volume("developer")),
+            &developer
+        ));
+        assert!(storage_needs_chown(
+            Some(&storage_volume("node")),
+            &developer
+        ));
+
+        let root = user("root", "/root");
+        assert!(!storage_needs_chown(None, &root));
+    }
+
+    #[test]
+    fn identical_lockfiles_share_a_cache_only_in_global_scope() {
+        let projects = [TempDir::new().unwrap(), TempDir::new().unwrap()];
+        for project in &projects {
+            std::fs::write(project.path().join("Cargo.lock"), "same").unwrap();
+        }
+        let dirs: Vec<PathBuf> = projects.iter().map(|p| p.path().to_path_buf()).collect();
+
+        let global =
+            detect_project_lockfiles(&dirs, 0, CacheScope::Global, &HashMap::new()).unwrap();
+        assert_eq!(global.len(), 1);
+
+        let scoped =
+            detect_project_lockfiles(&dirs, 0, CacheScope::Project, &HashMap::new()).unwrap();
+        assert_eq!(scoped.len(), 2);
+        assert_ne!(scoped[0].volume_name(), scoped[1].volume_name());
+    }
+
+    #[test]
+    fn caches_in_use_survive_eviction() {
+        let old = chrono::Utc::now() - chrono::Duration::days(30);
+        let mut in_use_cache = CacheSidecar::new(
+            "mino-cache-cargo-aaa".to_string(),
+            Ecosystem::Cargo,
+            "aaa".to_string(),
+            CacheState::Complete,
+        );
+        in_use_cache.created_at = old;
+        in_use_cache.last_used_at = Some(old);
+        let idle_cache = CacheSidecar::new(
+            "mino-cache-npm-bbb".to_string(),
+            Ecosystem::Npm,
+            "bbb".to_string(),
+            CacheState::Complete,
+        );
+        let sizes = HashMap::from([
+            (in_use_cache.volume_name.clone(), 600),
+            (idle_cache.volume_name.clone(), 600),
+        ]);
+        let in_use = HashSet::from([in_use_cache.volume_name.clone()]);
+
+        let candidates = eviction_candidates(&[&in_use_cache, &idle_cache], &sizes, &in_use);
+        let plan = plan_eviction(candidates, 1200, 1000, 0);
+
+        let evicted: Vec<&str> = plan.iter().map(|c| c.volume_name.as_str()).collect();
+        assert_eq!(evicted, vec!["mino-cache-npm-bbb"]);
+    }
+
+    #[tokio::test]
+    async fn fresh_rebuild_refuses_a_cache_another_session_mounts() {
+        let project = TempDir::new().unwrap();
+        std::fs::write(project.path().join("Cargo.lock"), "locked").unwrap();
+        let dirs = [project.path().to_path_buf()];
+        let info = detect_project_lockfiles(&dirs, 0, CacheScope::Global, &HashMap::new())
+            .unwrap()
+            .remove(0);
+
+        let mut other = test_session("other", SessionStatus::Running, Some("cid"));
+        other.cache_volumes = vec![info.volume_name()];
+        let stopped = test_session("stopped", SessionStatus::Stopped, None);
+        let in_use = caches_in_use(vec![other, stopped]);
+
+        let mock = MockRuntime::new();
+        let err = setup_cache_for_lockfile(&mock, &info, true, &in_use, false, None, false)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, MinoError::User(_)), "{err}");
+        mock.assert_called("volume_remove", 0);
+    }
 }
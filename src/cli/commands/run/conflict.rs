@@ -0,0 +1,125 @@
+//! Session name conflict resolution
+
+use crate::config::Config;
+use crate::error::{MinoError, MinoResult};
+use crate::orchestration::create_runtime_with_backend;
+use crate::sandbox::RuntimeMode;
+use crate::session::{Session, SessionManager, SessionStatus};
+use crate::ui::{self, UiContext};
+use console::style;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NameConflictChoice {
+    Rename,
+    Replace,
+    Cancel,
+}
+
+/// Return a session name that is free to use, starting from `name`.
+///
+/// When `name` is taken, interactive runs prompt to pick another name or
+/// replace the existing session; CI and `--yes` runs fail with
+/// [`MinoError::SessionExists`] so a script never destroys a session.
+pub(super) async fn resolve_session_name(
+    ctx: &UiContext,
+    manager: &SessionManager,
+    config: &Config,
+    mut name: String,
+) -> MinoResult<String> {
+    while let Some(existing) = manager.get(&name).await? {
+        if !ctx.is_interactive() || ctx.auto_yes() {
+            return Err(MinoError::SessionExists(name));
+        }
+
+        ui::step_warn(
+            ctx,
+            &format!(
+                "Session {} already exists ({})",
+                style(&name).cyan(),
+                existing.status
+            ),
+        );
+
+        let taken: Vec<String> = manager.list().await?.into_iter().map(|s| s.name).collect();
+        let suggestion = suggest_name(&name, &taken);
+        let rename_hint = format!("e.g. {}", suggestion);
+        let choice = ui::select(
+            ctx,
+            "How do you want to continue?",
+            &[
+                (
+                    NameConflictChoice::Rename,
+                    "Pick a new name",
+                    rename_hint.as_str(),
+                ),
+                (
+                    NameConflictChoice::Replace,
+                    "Replace the existing session",
+                    "stops and removes it",
+                ),
+                (NameConflictChoice::Cancel, "Cancel", ""),
+            ],
+        )
+        .await?;
+
+        match choice {
+            NameConflictChoice::Rename => {
+                name = ui::input(ctx, "Session name", &suggestion).await?;
+            }
+            NameConflictChoice::Replace => {
+                replace_session(manager, config, &existing).await?;
+                ui::step_ok(ctx, &format!("Removed session {}", style(&name).cyan()));
+            }
+            NameConflictChoice::Cancel => {
+                return Err(MinoError::User("Cancelled".to_string()));
+            }
+        }
+    }
+    Ok(name)
+}
+
+/// Stop and remove an existing session so its name can be reused
+async fn replace_session(
+    manager: &SessionManager,
+    config: &Config,
+    existing: &Session,
+) -> MinoResult<()> {
+    let active = matches!(
+        existing.status,
+        SessionStatus::Running | SessionStatus::Starting | SessionStatus::Paused
+    );
+
+    if existing.runtime_mode == Some(RuntimeMode::Native) {
+        if active {
+            return Err(MinoError::User(format!(
+                "Session {} is a running native sandbox; run `mino stop {}` first",
+                existing.name, existing.name
+            )));
+        }
+    } else {
+        let runtime = create_runtime_with_backend(config, existing.backend)?;
+        crate::cli::commands::stop::discard_session(existing, &*runtime).await?;
+    }
+
+    manager.delete(&existing.name).await
+}
+
+/// First `<name>-N` (N >= 2) not present in `taken`
+fn suggest_name(name: &str, taken: &[String]) -> String {
+    (2..)
+        .map(|n| format!("{}-{}", name, n))
+        .find(|candidate| !taken.contains(candidate))
+        .unwrap_or_else(|| name.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn suggest_name_skips_taken_suffixes() {
+        let taken = vec!["api".to_string(), "api-2".to_string()];
+        assert_eq!(suggest_name("api", &taken), "api-3");
+        assert_eq!(suggest_name("web", &taken), "web-2");
+    }
+}
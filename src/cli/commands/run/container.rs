@@ -2,14 +2,21 @@
 
 use crate::cache::CacheMount;
 use crate::cli::args::RunArgs;
-use crate::config::Config;
-use crate::error::MinoResult;
-use crate::network::NetworkMode;
-use crate::orchestration::ContainerConfig;
+use crate::config::{volume_host_source, Config};
+use crate::error::{MinoError, MinoResult};
+use crate::network::{session_hostname, NetworkMode, SESSION_NETWORK};
+use crate::orchestration::{
+    device_host_path, parse_extra_host, parse_hostname, parse_platform, parse_restart_policy,
+    parse_userns, tmpfs_target, ulimit_name, ContainerConfig, ContainerRuntime,
+};
 use std::collections::HashMap;
 use std::env;
-use std::path::Path;
+use std::path::{Component, Path, PathBuf};
+use tracing::debug;
 
+use super::gitconfig::GitConfigEnv;
+use super::propagate::Propagation;
+use super::user::ContainerUser;
 use super::ImageResolution;
 
 /// Parameters for building a container configuration.
@@ -17,12 +24,29 @@ pub(super) struct ContainerBuildParams<'a> {
     pub args: &'a RunArgs,
     pub config: &'a Config,
     pub project_dir: &'a Path,
+    /// Additional projects mounted alongside `project_dir`
+    pub extra_projects: &'a [PathBuf],
+    /// Host cwd relative to `project_dir`, when running from a subdirectory
+    pub cwd_subdir: Option<&'a Path>,
     pub resolution: &'a ImageResolution,
     pub env_vars: HashMap<String, String>,
+    /// Git config from `[git]` and credentials, which win over the
+    /// propagated identity
+    pub git_config: GitConfigEnv,
     pub cache_mounts: &'a [CacheMount],
     pub cache_env: HashMap<String, String>,
+    /// Host timezone/locale/identity settings from `container.propagate`
+    pub propagation: &'a Propagation,
+    /// Host variables selected by `container.env_passthrough`
+    pub passthrough_env: HashMap<String, String>,
     pub network_mode: &'a NetworkMode,
+    pub user: &'a ContainerUser,
     pub home_mount: Option<String>,
+    pub scratch_mount: Option<String>,
+    /// Cache volume for the podman/buildah storage of in-sandbox builds
+    pub container_storage_mount: Option<String>,
+    /// Socket of the session's deploy key agent, mounted instead of the host's
+    pub deploy_key_agent: Option<&'a Path>,
 }
 
 /// Derive container workdir from project directory name.
@@ -67,10 +91,118 @@ fn resolve_workdir(config_workdir: &str, project_dir: &Path) -> String {
     format!("/{folder_name}")
 }
 
+/// Compute project bind mounts, returning the main project's mount point and
+/// the `host:container` volume args.
+///
+/// A single project keeps its derived mount point. With extra projects, every
+/// project is mounted side by side at `<workdir>/<folder name>`.
+fn project_mounts(
+    config_workdir: &str,
+    project_dir: &Path,
+    extra_projects: &[PathBuf],
+) -> MinoResult<(String, Vec<String>)> {
+    if extra_projects.is_empty() {
+        let mount_point = resolve_workdir(config_workdir, project_dir);
+        let volume = format!("{}:{}", project_dir.display(), mount_point);
+        return Ok((mount_point, vec![volume]));
+    }
+
+    let base = config_workdir.trim_end_matches('/');
+    let mut targets: Vec<String> = Vec::new();
+    let mut volumes = Vec::new();
+    for dir in std::iter::once(project_dir).chain(extra_projects.iter().map(PathBuf::as_path)) {
+        let name = dir.file_name().and_then(|n| n.to_str()).ok_or_else(|| {
+            MinoError::User(format!("Cannot derive mount name for {}", dir.display()))
+        })?;
+        let target = format!("{base}/{name}");
+        if targets.contains(&target) {
+            return Err(MinoError::User(format!(
+                "Multiple projects would mount at {target}; rename one of the directories"
+            )));
+        }
+        volumes.push(format!("{}:{}", dir.display(), target));
+        targets.push(target);
+    }
+
+    Ok((targets.swap_remove(0), volumes))
+}
+
+/// Resolve the directory the container process starts in.
+///
+/// An explicit `--workdir` wins: absolute paths are used as-is, relative paths
+/// resolve against the project mount. Otherwise the host cwd's position inside
+/// the project is mirrored under the mount so the agent starts where you are.
+fn resolve_start_dir(
+    mount_point: &str,
+    workdir_override: Option<&str>,
+    cwd_subdir: Option<&Path>,
+) -> MinoResult<String> {
+    let relative = match workdir_override {
+        Some(dir) if dir.starts_with('/') => return Ok(dir.to_string()),
+        Some(dir) => Path::new(dir),
+        None => match cwd_subdir {
+            Some(sub) => sub,
+            None => return Ok(mount_point.to_string()),
+        },
+    };
+
+    let mut start = mount_point.trim_end_matches('/').to_string();
+    for component in relative.components() {
+        match component {
+            Component::Normal(part) => {
+                let part = part.to_str().ok_or_else(|| {
+                    MinoError::User(format!(
+                        "Working directory is not valid UTF-8: {}",
+                        relative.display()
+                    ))
+                })?;
+                start.push('/');
+                start.push_str(part);
+            }
+            Component::CurDir => {}
+            _ => {
+                return Err(MinoError::User(format!(
+                    "Relative --workdir must stay inside the project mount: {}",
+                    relative.display()
+                )))
+            }
+        }
+    }
+
+    if start.is_empty() {
+        start.push('/');
+    }
+    Ok(start)
+}
+
+/// Compute the host cwd relative to the project directory.
+/// Returns `None` when cwd is the project root or lies outside it.
+pub(super) fn cwd_subdir(project_dir: &Path, cwd: &Path) -> Option<std::path::PathBuf> {
+    let cwd = cwd.canonicalize().unwrap_or_else(|_| cwd.to_path_buf());
+    let project = project_dir
+        .canonicalize()
+        .unwrap_or_else(|_| project_dir.to_path_buf());
+    let sub = cwd.strip_prefix(&project).ok()?;
+    if sub.as_os_str().is_empty() {
+        None
+    } else {
+        Some(sub.to_path_buf())
+    }
+}
+
 /// Build the container configuration from resolved parameters.
 pub(super) fn build_container_config(params: &ContainerBuildParams) -> MinoResult<ContainerConfig> {
     let image = params.resolution.image.clone();
-    let workdir = resolve_workdir(&params.config.container.workdir, params.project_dir);
+    let (mount_point, project_volumes) = project_mounts(
+        &params.config.container.workdir,
+        params.project_dir,
+        params.extra_projects,
+    )?;
+    let workdir = resolve_start_dir(
+        &mount_point,
+        params.args.workdir.as_deref(),
+        params.cwd_subdir,
+    )?;
 
     let mut volumes = Vec::new();
 
@@ -79,39 +211,101 @@ pub(super) fn build_container_config(params: &ContainerBuildParams) -> MinoResul
         volumes.push(home.clone());
     }
 
-    volumes.push(format!("{}:{}", params.project_dir.display(), workdir));
+    volumes.extend(project_volumes);
 
-    volumes.extend(params.cache_mounts.iter().map(|m| m.volume_arg()));
+    // Non-mino, non-root users cannot write root-owned volumes
+    let chown = params.user.needs_volume_chown();
+    let owned = |arg: String| if chown { chown_volume_arg(&arg) } else { arg };
 
-    if !params.args.no_ssh_agent {
-        if let Ok(sock) = env::var("SSH_AUTH_SOCK") {
-            volumes.push(format!("{}:/ssh-agent", sock));
-        }
+    if let Some(ref scratch) = params.scratch_mount {
+        volumes.push(owned(scratch.clone()));
+    }
+
+    volumes.extend(params.cache_mounts.iter().map(|m| owned(m.volume_arg())));
+
+    if let Some(ref storage) = params.container_storage_mount {
+        volumes.push(storage.clone());
     }
 
+    let ssh_agent = match params.deploy_key_agent {
+        Some(socket) => Some(socket.display().to_string()),
+        None if !params.args.no_ssh_agent => env::var("SSH_AUTH_SOCK").ok(),
+        None => None,
+    };
+    if let Some(sock) = &ssh_agent {
+        volumes.push(format!("{}:/ssh-agent", sock));
+    }
+
+    volumes.extend(params.propagation.volumes.iter().cloned());
     volumes.extend(params.args.volume.iter().cloned());
+    check_config_volume_sources(&params.config.container.volumes)?;
     volumes.extend(params.config.container.volumes.iter().cloned());
 
-    // Env precedence: config < layer < cache < credential < CLI -e
-    let mut final_env = params.config.container.env.clone();
+    // Env precedence: propagated < config < layer < cache < credential < CLI -e
+    let mut final_env = params.propagation.env.clone();
+    final_env.extend(params.passthrough_env.clone());
+    final_env.extend(params.config.container.env.clone());
     final_env.extend(params.resolution.layer_env.clone());
     final_env.extend(params.cache_env.clone());
     final_env.extend(params.env_vars.clone());
+    let mut git_config = params.propagation.git_config.clone();
+    git_config.merge(&params.git_config);
+    final_env.extend(git_config.to_env());
 
-    if !params.args.no_ssh_agent && env::var("SSH_AUTH_SOCK").is_ok() {
+    if params.user.home_override {
+        final_env.insert("HOME".to_string(), params.user.home.clone());
+    }
+
+    if ssh_agent.is_some() {
         final_env.insert("SSH_AUTH_SOCK".to_string(), "/ssh-agent".to_string());
     }
 
     let read_only = params.args.read_only || params.config.container.read_only;
 
+    let cpus = params.args.cpus.unwrap_or(params.config.container.cpus);
+    if !cpus.is_finite() || cpus < 0.0 {
+        return Err(MinoError::User(format!(
+            "Invalid CPU limit: {} (expected a number of cores, 0 for no limit)",
+            cpus
+        )));
+    }
+
+    let userns = params
+        .args
+        .userns
+        .as_deref()
+        .or(params.config.container.userns.as_deref())
+        .map(parse_userns)
+        .transpose()?;
+
+    let mut default_tmpfs = Vec::new();
+    if read_only {
+        default_tmpfs = vec!["/tmp".to_string(), "/run".to_string(), "/root".to_string()];
+        // Only add a home tmpfs if no home volume is mounted
+        if params.home_mount.is_none() && !default_tmpfs.contains(&params.user.home) {
+            default_tmpfs.push(params.user.home.clone());
+        }
+    }
+    let tmpfs = requested_tmpfs(params.args, params.config, default_tmpfs)?;
+
+    let restart = params
+        .args
+        .restart
+        .as_deref()
+        .map(parse_restart_policy)
+        .transpose()?
+        .flatten();
+
     Ok(ContainerConfig {
         image,
         workdir,
         volumes,
         env: final_env,
         network: params.network_mode.to_podman_network().to_string(),
+        network_aliases: vec![],
         interactive: !params.args.detach,
-        tty: !params.args.detach,
+        // --stdin streams a pipe, which a TTY would mangle (echo, CRLF)
+        tty: !params.args.detach && !params.args.stdin,
         cap_drop: vec!["ALL".to_string()],
         cap_add: if params.network_mode.requires_cap_net_admin() {
             vec!["NET_ADMIN".to_string()]
@@ -119,63 +313,259 @@ pub(super) fn build_container_config(params: &ContainerBuildParams) -> MinoResul
             vec![]
         },
         security_opt: vec!["no-new-privileges".to_string()],
-        pids_limit: 4096,
-        auto_remove: params.args.detach,
+        pids_limit: params
+            .args
+            .pids_limit
+            .unwrap_or(params.config.container.pids_limit),
+        ulimits: requested_ulimits(params.args, params.config)?,
+        cpus,
+        memory_mb: params
+            .args
+            .memory
+            .unwrap_or(params.config.container.memory_mb),
+        gpus: gpus_requested(params.args, params.config),
+        devices: requested_devices(params.args, params.config),
+        // Podman rejects --rm with a restart policy; `mino stop` removes the container
+        auto_remove: params.args.detach && restart.is_none(),
         read_only,
-        tmpfs: if read_only {
-            let mut mounts = vec!["/tmp".to_string(), "/run".to_string(), "/root".to_string()];
-            // Only add /home/developer tmpfs if no home volume is mounted
-            if params.home_mount.is_none() {
-                mounts.push("/home/developer".to_string());
-            }
-            mounts
-        } else {
-            vec![]
-        },
+        tmpfs,
+        user: params.user.user_override.clone(),
+        userns,
+        restart,
+        labels: HashMap::new(),
+        pod: None,
+        platform: requested_platform(params.args, params.config)?,
+        hostname: params
+            .config
+            .container
+            .hostname
+            .as_deref()
+            .map(parse_hostname)
+            .transpose()?,
+        extra_hosts: requested_extra_hosts(params.config)?,
     })
 }
 
+/// `container.extra_hosts` entries, each checked to be `hostname:ip`
+fn requested_extra_hosts(config: &Config) -> MinoResult<Vec<String>> {
+    for host in &config.container.extra_hosts {
+        parse_extra_host(host)?;
+    }
+    Ok(config.container.extra_hosts.clone())
+}
+
+/// Reject `container.volumes` entries whose host source does not exist,
+/// naming the entry instead of leaving podman to fail on the mount.
+fn check_config_volume_sources(volumes: &[String]) -> MinoResult<()> {
+    for spec in volumes {
+        if let Some(source) = volume_host_source(spec) {
+            if !Path::new(source).exists() {
+                return Err(MinoError::User(format!(
+                    "Volume source does not exist: {} (container.volumes entry \"{}\")",
+                    source, spec
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Whether the session asked for GPUs (`--gpus` or `container.gpus`)
+pub(super) fn gpus_requested(args: &RunArgs, config: &Config) -> bool {
+    args.gpus || config.container.gpus
+}
+
+/// Image platform from `--platform` or `container.platform`, normalized
+pub(super) fn requested_platform(args: &RunArgs, config: &Config) -> MinoResult<Option<String>> {
+    args.platform
+        .as_deref()
+        .or(config.container.platform.as_deref())
+        .map(parse_platform)
+        .transpose()
+}
+
+/// Devices from `--device` and `container.devices`, CLI first, without duplicates
+pub(super) fn requested_devices(args: &RunArgs, config: &Config) -> Vec<String> {
+    let mut devices: Vec<String> = Vec::new();
+    for device in args.device.iter().chain(&config.container.devices) {
+        if !devices.contains(device) {
+            devices.push(device.clone());
+        }
+    }
+    devices
+}
+
+/// Tmpfs mounts from `--tmpfs`, `container.tmpfs`, and the read-only
+/// defaults, one per container path: CLI entries win over config, and both
+/// over a default for the same path (so `/tmp:size=1g` sizes the read-only
+/// `/tmp`).
+fn requested_tmpfs(
+    args: &RunArgs,
+    config: &Config,
+    defaults: Vec<String>,
+) -> MinoResult<Vec<String>> {
+    let mut mounts: Vec<String> = Vec::new();
+    let mut targets: Vec<String> = Vec::new();
+    for spec in args.tmpfs.iter().chain(&config.container.tmpfs) {
+        let target = tmpfs_target(spec)?;
+        if !targets.iter().any(|t| t == target) {
+            targets.push(target.to_string());
+            mounts.push(spec.clone());
+        }
+    }
+    for spec in defaults {
+        if !targets.contains(&spec) {
+            mounts.push(spec);
+        }
+    }
+    Ok(mounts)
+}
+
+/// Ulimits from `--ulimit` and `container.ulimits`; a CLI entry replaces
+/// the config entry for the same resource
+fn requested_ulimits(args: &RunArgs, config: &Config) -> MinoResult<Vec<String>> {
+    let mut ulimits: Vec<String> = Vec::new();
+    let mut names: Vec<&str> = Vec::new();
+    for spec in args.ulimit.iter().chain(&config.container.ulimits) {
+        let name = ulimit_name(spec)?;
+        if !names.contains(&name) {
+            names.push(name);
+            ulimits.push(spec.clone());
+        }
+    }
+    Ok(ulimits)
+}
+
+/// Check that every device path exists where the runtime runs containers.
+/// CDI device names are left to the runtime.
+pub(super) async fn check_devices(
+    runtime: &dyn ContainerRuntime,
+    devices: &[String],
+) -> MinoResult<()> {
+    for device in devices {
+        if let Some(path) = device_host_path(device)? {
+            runtime.check_device(path).await?;
+        }
+    }
+    Ok(())
+}
+
+/// Attach a bridged session to the shared session network under the alias
+/// `<session>.mino`, so other sessions can reach it by name.
+///
+/// Returns the hostname, or `None` when session DNS is disabled, the network
+/// mode has no container network, or the backend cannot create the network
+/// (the session then stays on the default bridge).
+pub(super) async fn join_session_network(
+    runtime: &dyn ContainerRuntime,
+    config: &Config,
+    network_mode: &NetworkMode,
+    session_name: &str,
+    container_config: &mut ContainerConfig,
+) -> Option<String> {
+    if !config.container.session_dns || !network_mode.supports_session_dns() {
+        return None;
+    }
+    if let Err(e) = runtime.network_create(SESSION_NETWORK).await {
+        debug!("Session DNS unavailable, using default bridge: {}", e);
+        return None;
+    }
+
+    let hostname = session_hostname(session_name);
+    container_config.network = SESSION_NETWORK.to_string();
+    container_config.network_aliases = vec![hostname.clone()];
+    Some(hostname)
+}
+
+/// Append podman's `U` option so a named volume is chowned to the container user.
+pub(super) fn chown_volume_arg(arg: &str) -> String {
+    if arg.matches(':').count() >= 2 {
+        format!("{arg},U")
+    } else {
+        format!("{arg}:U")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::cli::args::RunArgs;
-    use std::path::PathBuf;
+    use crate::orchestration::mock::{test_container_config, MockRuntime};
 
     fn test_run_args() -> RunArgs {
         RunArgs {
             name: None,
-            project: None,
+            project: vec![],
             aws: false,
             gcp: false,
             azure: false,
             all_clouds: false,
             no_ssh_agent: true, // disable to avoid SSH_AUTH_SOCK dependency
             no_github: false,
+            gitlab: false,
+            bitbucket: false,
+            kube: false,
+            deploy_key: false,
             strict_credentials: false,
+            refresh_credentials: false,
             image: None,
             layers: vec![],
+            layer_args: vec![],
+            allow_layer_conflicts: false,
             env: vec![],
             volume: vec![],
+            workdir: None,
             detach: false,
+            timeout: None,
+            heartbeat: None,
             read_only: false,
             no_cache: false,
             no_home: false,
             cache_fresh: false,
+            cache_rw: false,
+            scratch: false,
+            worktree: false,
+            cpus: None,
+            memory: None,
+            pids_limit: None,
+            ulimit: vec![],
+            gpus: false,
+            userns: None,
+            stdin: false,
+            device: vec![],
+            tmpfs: vec![],
+            restart: None,
+            platform: None,
+            pull: None,
             network: None,
             network_allow: vec![],
             network_preset: None,
             runtime: None,
+            native: false,
+            allow_degraded: false,
+            context: None,
             command: vec![],
         }
     }
 
     fn test_resolution() -> ImageResolution {
         ImageResolution {
+            cache_key_extra: HashMap::new(),
+            post_create: vec![],
             image: "fedora:43".to_string(),
             layer_env: HashMap::new(),
         }
     }
 
+    fn mino_user() -> ContainerUser {
+        ContainerUser {
+            user_override: None,
+            name: "developer".to_string(),
+            home: "/home/developer".to_string(),
+            home_override: false,
+        }
+    }
+
     fn build_with(args: &RunArgs, config: &Config) -> crate::orchestration::ContainerConfig {
         build_with_home(args, config, None)
     }
@@ -184,20 +574,39 @@ mod tests {
         args: &RunArgs,
         config: &Config,
         home_mount: Option<String>,
+    ) -> crate::orchestration::ContainerConfig {
+        build_with_user(args, config, home_mount, &mino_user())
+    }
+
+    fn build_with_user(
+        args: &RunArgs,
+        config: &Config,
+        home_mount: Option<String>,
+        user: &ContainerUser,
     ) -> crate::orchestration::ContainerConfig {
         let resolution = test_resolution();
         let project_dir = PathBuf::from("/tmp/project");
         let network_mode = NetworkMode::Bridge;
+        let propagation = Propagation::default();
         let params = ContainerBuildParams {
             args,
             config,
             project_dir: &project_dir,
+            extra_projects: &[],
+            cwd_subdir: None,
             resolution: &resolution,
             env_vars: HashMap::new(),
+            git_config: GitConfigEnv::default(),
             cache_mounts: &[],
             cache_env: HashMap::new(),
+            passthrough_env: HashMap::new(),
+            propagation: &propagation,
             network_mode: &network_mode,
+            user,
             home_mount,
+            scratch_mount: None,
+            container_storage_mount: None,
+            deploy_key_agent: None,
         };
         build_container_config(&params).unwrap()
     }
@@ -249,6 +658,272 @@ mod tests {
         assert!(!result.tmpfs.is_empty());
     }
 
+    #[test]
+    fn tmpfs_merges_cli_config_and_read_only_defaults() {
+        let mut args = test_run_args();
+        args.read_only = true;
+        args.tmpfs = vec!["/tmp:size=1g".to_string()];
+        let mut config = Config::default();
+        config.container.tmpfs = vec!["/tmp:size=64m".to_string(), "/scratch".to_string()];
+
+        let result = build_with(&args, &config);
+        assert_eq!(
+            result.tmpfs,
+            vec![
+                "/tmp:size=1g",
+                "/scratch",
+                "/run",
+                "/root",
+                "/home/developer"
+            ]
+        );
+    }
+
+    #[test]
+    fn restart_policy_disables_auto_remove() {
+        let mut args = test_run_args();
+        args.detach = true;
+        let result = build_with(&args, &Config::default());
+        assert!(result.auto_remove);
+        assert!(result.restart.is_none());
+
+        args.restart = Some("on-failure:3".to_string());
+        let result = build_with(&args, &Config::default());
+        assert!(!result.auto_remove);
+        assert_eq!(result.restart.as_deref(), Some("on-failure:3"));
+
+        args.restart = Some("no".to_string());
+        let result = build_with(&args, &Config::default());
+        assert!(result.auto_remove);
+        assert!(result.restart.is_none());
+    }
+
+    #[test]
+    fn tmpfs_without_read_only_and_invalid_spec() {
+        let mut args = test_run_args();
+        args.tmpfs = vec!["/run".to_string()];
+        let result = build_with(&args, &Config::default());
+        assert!(!result.read_only);
+        assert_eq!(result.tmpfs, vec!["/run"]);
+
+        args.tmpfs = vec!["run".to_string()];
+        assert!(requested_tmpfs(&args, &Config::default(), vec![]).is_err());
+    }
+
+    #[test]
+    fn resource_limits_default_to_pids_only() {
+        let result = build_with(&test_run_args(), &Config::default());
+        assert_eq!(result.pids_limit, 4096);
+        assert_eq!(result.cpus, 0.0);
+        assert_eq!(result.memory_mb, 0);
+    }
+
+    #[test]
+    fn resource_limits_cli_overrides_config() {
+        let mut args = test_run_args();
+        args.cpus = Some(1.5);
+        args.pids_limit = Some(512);
+        let mut config = Config::default();
+        config.container.cpus = 4.0;
+        config.container.memory_mb = 8192;
+        let result = build_with(&args, &config);
+        assert_eq!(result.cpus, 1.5);
+        assert_eq!(result.memory_mb, 8192);
+        assert_eq!(result.pids_limit, 512);
+    }
+
+    #[test]
+    fn gpus_from_cli_or_config() {
+        let config = Config::default();
+        assert!(!build_with(&test_run_args(), &config).gpus);
+
+        let mut args = test_run_args();
+        args.gpus = true;
+        assert!(build_with(&args, &config).gpus);
+
+        let mut config = Config::default();
+        config.container.gpus = true;
+        assert!(build_with(&test_run_args(), &config).gpus);
+    }
+
+    #[test]
+    fn ulimits_cli_overrides_config_per_resource() {
+        let mut args = test_run_args();
+        args.ulimit = vec!["nofile=65535:65535".to_string()];
+        let mut config = Config::default();
+        config.container.ulimits = vec!["nofile=1024".to_string(), "nproc=4096".to_string()];
+
+        assert_eq!(
+            build_with(&args, &config).ulimits,
+            vec!["nofile=65535:65535", "nproc=4096"]
+        );
+
+        args.ulimit = vec!["files=10".to_string()];
+        assert!(requested_ulimits(&args, &config).is_err());
+    }
+
+    #[test]
+    fn devices_merge_cli_and_config() {
+        let mut args = test_run_args();
+        args.device = vec!["/dev/ttyUSB0".to_string()];
+        let mut config = Config::default();
+        config.container.devices = vec!["/dev/ttyACM0".to_string(), "/dev/ttyUSB0".to_string()];
+
+        assert_eq!(
+            build_with(&args, &config).devices,
+            vec!["/dev/ttyUSB0", "/dev/ttyACM0"]
+        );
+    }
+
+    #[test]
+    fn hostname_and_extra_hosts_from_config() {
+        let args = test_run_args();
+        let mut config = Config::default();
+        config.container.hostname = Some("devbox".to_string());
+        config.container.extra_hosts = vec!["internal.corp:10.0.0.5".to_string()];
+
+        let result = build_with(&args, &config);
+        assert_eq!(result.hostname.as_deref(), Some("devbox"));
+        assert_eq!(result.extra_hosts, vec!["internal.corp:10.0.0.5"]);
+
+        config.container.extra_hosts = vec!["internal.corp".to_string()];
+        assert!(requested_extra_hosts(&config).is_err());
+    }
+
+    #[tokio::test]
+    async fn check_devices_checks_host_paths_only() {
+        let mock = MockRuntime::new();
+        let devices = vec![
+            "/dev/sdc:/dev/xvdc:r".to_string(),
+            "nvidia.com/gpu=0".to_string(),
+        ];
+
+        check_devices(&mock, &devices).await.unwrap();
+
+        mock.assert_called("check_device", 1);
+        mock.assert_called_with("check_device", &["/dev/sdc"]);
+    }
+
+    #[tokio::test]
+    async fn check_devices_reports_missing_device() {
+        let mock = MockRuntime::new().on_err(
+            "check_device",
+            MinoError::DeviceNotFound {
+                device: "/dev/ttyUSB0".to_string(),
+                location: "this host".to_string(),
+            },
+        );
+
+        let err = check_devices(&mock, &["/dev/ttyUSB0".to_string()])
+            .await
+            .unwrap_err();
+        assert!(matches!(err, MinoError::DeviceNotFound { .. }));
+    }
+
+    #[test]
+    fn stdin_attaches_without_tty() {
+        let result = build_with(&test_run_args(), &Config::default());
+        assert!(result.interactive && result.tty);
+
+        let mut args = test_run_args();
+        args.stdin = true;
+        let result = build_with(&args, &Config::default());
+        assert!(result.interactive);
+        assert!(!result.tty);
+    }
+
+    #[test]
+    fn userns_from_cli_over_config() {
+        assert_eq!(
+            build_with(&test_run_args(), &Config::default()).userns,
+            None
+        );
+
+        let mut config = Config::default();
+        config.container.userns = Some("keep-id".to_string());
+        assert_eq!(
+            build_with(&test_run_args(), &config).userns.as_deref(),
+            Some("keep-id")
+        );
+
+        let mut args = test_run_args();
+        args.userns = Some("auto".to_string());
+        assert_eq!(build_with(&args, &config).userns.as_deref(), Some("auto"));
+    }
+
+    #[test]
+    fn resource_limits_reject_negative_cpus() {
+        let mut args = test_run_args();
+        args.cpus = Some(-1.0);
+        let config = Config::default();
+        let resolution = test_resolution();
+        let project_dir = PathBuf::from("/tmp/project");
+        let propagation = Propagation::default();
+        let params = ContainerBuildParams {
+            args: &args,
+            config: &config,
+            project_dir: &project_dir,
+            extra_projects: &[],
+            cwd_subdir: None,
+            resolution: &resolution,
+            env_vars: HashMap::new(),
+            git_config: GitConfigEnv::default(),
+            cache_mounts: &[],
+            cache_env: HashMap::new(),
+            passthrough_env: HashMap::new(),
+            propagation: &propagation,
+            network_mode: &NetworkMode::Bridge,
+            user: &mino_user(),
+            home_mount: None,
+            scratch_mount: None,
+            container_storage_mount: None,
+            deploy_key_agent: None,
+        };
+        assert!(build_container_config(&params).is_err());
+    }
+
+    #[test]
+    fn deploy_key_agent_replaces_host_agent() {
+        let args = test_run_args();
+        let config = Config::default();
+        let resolution = test_resolution();
+        let project_dir = PathBuf::from("/tmp/project");
+        let propagation = Propagation::default();
+        let socket = PathBuf::from("/run/user/1000/mino/s1.ssh-agent/agent.sock");
+        let params = ContainerBuildParams {
+            args: &args,
+            config: &config,
+            project_dir: &project_dir,
+            extra_projects: &[],
+            cwd_subdir: None,
+            resolution: &resolution,
+            env_vars: HashMap::new(),
+            git_config: GitConfigEnv::default(),
+            cache_mounts: &[],
+            cache_env: HashMap::new(),
+            passthrough_env: HashMap::new(),
+            propagation: &propagation,
+            network_mode: &NetworkMode::Bridge,
+            user: &mino_user(),
+            home_mount: None,
+            scratch_mount: None,
+            container_storage_mount: None,
+            deploy_key_agent: Some(&socket),
+        };
+        let result = build_container_config(&params).unwrap();
+
+        let agent_mounts: Vec<_> = result
+            .volumes
+            .iter()
+            .filter(|v| v.ends_with(":/ssh-agent"))
+            .collect();
+        assert_eq!(
+            agent_mounts,
+            vec!["/run/user/1000/mino/s1.ssh-agent/agent.sock:/ssh-agent"]
+        );
+        assert_eq!(result.env["SSH_AUTH_SOCK"], "/ssh-agent");
+    }
+
     #[test]
     fn home_mount_appears_in_volumes() {
         let args = test_run_args();
@@ -290,6 +965,143 @@ mod tests {
         assert!(result.tmpfs.contains(&"/home/developer".to_string()));
     }
 
+    #[test]
+    fn custom_user_sets_flag_home_env_and_tmpfs() {
+        let mut args = test_run_args();
+        args.read_only = true;
+        let config = Config::default();
+        let user = ContainerUser {
+            user_override: Some("app".to_string()),
+            name: "app".to_string(),
+            home: "/home/app".to_string(),
+            home_override: true,
+        };
+        let result = build_with_user(&args, &config, None, &user);
+        assert_eq!(result.user.as_deref(), Some("app"));
+        assert_eq!(result.env.get("HOME").unwrap(), "/home/app");
+        assert!(result.tmpfs.contains(&"/home/app".to_string()));
+        assert!(!result.tmpfs.contains(&"/home/developer".to_string()));
+    }
+
+    #[test]
+    fn image_default_user_passes_no_flag() {
+        let result = build_with(&test_run_args(), &Config::default());
+        assert!(result.user.is_none());
+        assert!(!result.env.contains_key("HOME"));
+    }
+
+    #[test]
+    fn propagated_settings_yield_to_config_env() {
+        let args = test_run_args();
+        let mut config = Config::default();
+        config
+            .container
+            .env
+            .insert("LANG".to_string(), "C.UTF-8".to_string());
+        let propagation = Propagation {
+            volumes: vec!["/etc/localtime:/etc/localtime:ro".to_string()],
+            env: HashMap::from([
+                ("LANG".to_string(), "de_DE.UTF-8".to_string()),
+                ("TZ".to_string(), "Europe/Berlin".to_string()),
+            ]),
+            git_config: GitConfigEnv::default(),
+        };
+        let resolution = test_resolution();
+        let project_dir = PathBuf::from("/tmp/project");
+        let user = mino_user();
+        let result = build_container_config(&ContainerBuildParams {
+            args: &args,
+            config: &config,
+            project_dir: &project_dir,
+            extra_projects: &[],
+            cwd_subdir: None,
+            resolution: &resolution,
+            env_vars: HashMap::new(),
+            git_config: GitConfigEnv::default(),
+            cache_mounts: &[],
+            cache_env: HashMap::new(),
+            passthrough_env: HashMap::from([
+                ("LANG".to_string(), "en_US.UTF-8".to_string()),
+                ("TERM".to_string(), "xterm-256color".to_string()),
+            ]),
+            propagation: &propagation,
+            network_mode: &NetworkMode::Bridge,
+            user: &user,
+            home_mount: None,
+            scratch_mount: None,
+            container_storage_mount: None,
+            deploy_key_agent: None,
+        })
+        .unwrap();
+
+        assert_eq!(result.env.get("LANG").unwrap(), "C.UTF-8");
+        assert_eq!(result.env.get("TZ").unwrap(), "Europe/Berlin");
+        assert_eq!(result.env.get("TERM").unwrap(), "xterm-256color");
+        assert!(result
+            .volumes
+            .contains(&"/etc/localtime:/etc/localtime:ro".to_string()));
+    }
+
+    #[test]
+    fn propagated_identity_and_credential_helper_share_git_config() {
+        let args = test_run_args();
+        let config = Config::default();
+        let mut propagation = Propagation::default();
+        propagation.git_config.set("user.name", "Ada");
+        propagation.git_config.set("user.email", "ada@example.com");
+        let mut git_config = GitConfigEnv::default();
+        git_config.set("credential.https://github.com.helper", "!f() { :; }; f");
+        let resolution = test_resolution();
+        let project_dir = PathBuf::from("/tmp/project");
+        let user = mino_user();
+        let result = build_container_config(&ContainerBuildParams {
+            args: &args,
+            config: &config,
+            project_dir: &project_dir,
+            extra_projects: &[],
+            cwd_subdir: None,
+            resolution: &resolution,
+            env_vars: HashMap::new(),
+            git_config,
+            cache_mounts: &[],
+            cache_env: HashMap::new(),
+            passthrough_env: HashMap::new(),
+            propagation: &propagation,
+            network_mode: &NetworkMode::Bridge,
+            user: &user,
+            home_mount: None,
+            scratch_mount: None,
+            container_storage_mount: None,
+            deploy_key_agent: None,
+        })
+        .unwrap();
+
+        assert_eq!(result.env["GIT_CONFIG_COUNT"], "3");
+        assert_eq!(result.env["GIT_CONFIG_KEY_0"], "user.name");
+        assert_eq!(result.env["GIT_CONFIG_KEY_1"], "user.email");
+        assert_eq!(
+            result.env["GIT_CONFIG_KEY_2"],
+            "credential.https://github.com.helper"
+        );
+    }
+
+    #[test]
+    fn config_volume_with_missing_source_is_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let present = format!("{}:/data", dir.path().display());
+        assert!(check_config_volume_sources(&[present, "named:/n".to_string()]).is_ok());
+
+        let missing = format!("{}:/data", dir.path().join("gone").display());
+        let err = check_config_volume_sources(&[missing]).unwrap_err();
+        assert!(err.to_string().contains("Volume source does not exist"));
+    }
+
+    #[test]
+    fn chown_volume_arg_appends_option() {
+        assert_eq!(chown_volume_arg("vol:/cache"), "vol:/cache:U");
+        assert_eq!(chown_volume_arg("vol:/cache:ro"), "vol:/cache:ro,U");
+    }
+
     #[test]
     fn workdir_derived_from_project_dir() {
         let args = test_run_args();
@@ -348,4 +1160,144 @@ mod tests {
             "/minotaur"
         );
     }
+
+    #[test]
+    fn start_dir_defaults_to_mount_point() {
+        assert_eq!(resolve_start_dir("/repo", None, None).unwrap(), "/repo");
+    }
+
+    #[test]
+    fn start_dir_mirrors_cwd_subdir() {
+        let sub = Path::new("packages/api");
+        assert_eq!(
+            resolve_start_dir("/repo", None, Some(sub)).unwrap(),
+            "/repo/packages/api"
+        );
+    }
+
+    #[test]
+    fn start_dir_override_takes_precedence() {
+        let sub = Path::new("packages/api");
+        assert_eq!(
+            resolve_start_dir("/repo", Some("/opt/tools"), Some(sub)).unwrap(),
+            "/opt/tools"
+        );
+        assert_eq!(
+            resolve_start_dir("/repo", Some("./packages/web"), Some(sub)).unwrap(),
+            "/repo/packages/web"
+        );
+    }
+
+    #[test]
+    fn start_dir_rejects_parent_escape() {
+        assert!(resolve_start_dir("/repo", Some("../etc"), None).is_err());
+    }
+
+    #[test]
+    fn cwd_subdir_inside_and_outside_project() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let nested = tmp.path().join("packages").join("api");
+        std::fs::create_dir_all(&nested).unwrap();
+
+        assert_eq!(
+            cwd_subdir(tmp.path(), &nested),
+            Some(PathBuf::from("packages/api"))
+        );
+        assert_eq!(cwd_subdir(tmp.path(), tmp.path()), None);
+        assert_eq!(cwd_subdir(&nested, tmp.path()), None);
+    }
+
+    #[test]
+    fn project_mounted_at_root_when_starting_in_subdir() {
+        let mut args = test_run_args();
+        args.workdir = Some("src".to_string());
+        let config = Config::default();
+        let result = build_with(&args, &config);
+        assert_eq!(result.workdir, "/project/src");
+        assert!(result
+            .volumes
+            .contains(&"/tmp/project:/project".to_string()));
+    }
+
+    #[test]
+    fn single_project_keeps_derived_mount() {
+        let (mount, volumes) =
+            project_mounts("/workspace", Path::new("/home/dev/service"), &[]).unwrap();
+        assert_eq!(mount, "/service");
+        assert_eq!(volumes, vec!["/home/dev/service:/service"]);
+    }
+
+    #[test]
+    fn extra_projects_mount_side_by_side() {
+        let extras = vec![PathBuf::from("/home/dev/shared-lib")];
+        let (mount, volumes) =
+            project_mounts("/workspace", Path::new("/home/dev/service"), &extras).unwrap();
+        assert_eq!(mount, "/workspace/service");
+        assert_eq!(
+            volumes,
+            vec![
+                "/home/dev/service:/workspace/service",
+                "/home/dev/shared-lib:/workspace/shared-lib",
+            ]
+        );
+    }
+
+    #[test]
+    fn extra_projects_reject_name_collision() {
+        let extras = vec![PathBuf::from("/other/service")];
+        let err =
+            project_mounts("/workspace", Path::new("/home/dev/service"), &extras).unwrap_err();
+        assert!(err.to_string().contains("/workspace/service"));
+    }
+
+    #[tokio::test]
+    async fn bridged_session_joins_session_network() {
+        let mock = MockRuntime::new();
+        let mut container = test_container_config();
+
+        let hostname = join_session_network(
+            &mock,
+            &Config::default(),
+            &NetworkMode::Bridge,
+            "api",
+            &mut container,
+        )
+        .await;
+        assert_eq!(hostname.as_deref(), Some("api.mino"));
+        assert_eq!(container.network, "mino");
+        assert_eq!(container.network_aliases, vec!["api.mino"]);
+        mock.assert_called_with("network_create", &["mino"]);
+    }
+
+    #[tokio::test]
+    async fn session_network_skipped_without_bridge_or_on_failure() {
+        let mock = MockRuntime::new();
+        let mut container = test_container_config();
+        let none = join_session_network(
+            &mock,
+            &Config::default(),
+            &NetworkMode::None,
+            "api",
+            &mut container,
+        )
+        .await;
+        assert!(none.is_none());
+        mock.assert_no_calls();
+
+        let failing = MockRuntime::new().on(
+            "network_create",
+            Err(MinoError::User("unsupported".to_string())),
+        );
+        let hostname = join_session_network(
+            &failing,
+            &Config::default(),
+            &NetworkMode::Bridge,
+            "api",
+            &mut container,
+        )
+        .await;
+        assert!(hostname.is_none());
+        assert_eq!(container.network, "bridge");
+        assert!(container.network_aliases.is_empty());
+    }
 }
@@ -1,38 +1,127 @@
 //! Credential gathering for cloud providers and GitHub
 
+use super::gitconfig::GitConfigEnv;
 use crate::cli::args::RunArgs;
-use crate::config::Config;
+use crate::config::schema::GithubConfig;
+use crate::config::{Config, ConfigManager};
+use crate::credentials::deploy_key;
+use crate::credentials::files::{
+    aws_shared_credentials, SessionCredentialFiles, AWS_SHARED_CREDENTIALS_FILE, AZURE_TOKEN_FILE,
+    BITBUCKET_TOKEN_FILE, GCP_TOKEN_FILE, GITHUB_TOKEN_FILE, GITLAB_TOKEN_FILE,
+};
+use crate::credentials::github::APP_TOKEN_PATH;
 use crate::credentials::{
-    AwsCredentials, AzureCredentials, CredentialCache, GcpCredentials, GithubCredentials,
+    AwsCredentials, AzureCredentials, BitbucketCredentials, CredentialCache, CustomCredentials,
+    GcpCredentials, GithubCredentials, GitlabCredentials, KubernetesCredentials,
 };
-use crate::error::MinoResult;
-use std::collections::HashMap;
-use tracing::debug;
+use crate::error::{MinoError, MinoResult};
+use crate::orchestration::ContainerRuntime;
+use crate::session::SessionManager;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::{debug, warn};
+
+/// Wait before retrying a failed GitHub App token refresh
+const APP_REFRESH_RETRY: Duration = Duration::from_secs(60);
+
+/// Credential providers injected into a session
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub(super) struct ProviderSelection {
+    pub aws: bool,
+    pub gcp: bool,
+    pub azure: bool,
+    pub github: bool,
+    pub gitlab: bool,
+    pub bitbucket: bool,
+    pub kubernetes: bool,
+}
+
+impl ProviderSelection {
+    /// Providers enabled by CLI flags or config
+    pub(super) fn resolve(args: &RunArgs, config: &Config) -> Self {
+        let all = args.all_clouds;
+        Self {
+            aws: all || args.aws || config.credentials.aws.enabled,
+            gcp: all || args.gcp || config.credentials.gcp.enabled,
+            azure: all || args.azure || config.credentials.azure.enabled,
+            github: !args.no_github && config.credentials.github.enabled,
+            gitlab: args.gitlab || config.credentials.gitlab.enabled,
+            bitbucket: args.bitbucket || config.credentials.bitbucket.enabled,
+            kubernetes: args.kube || config.credentials.kubernetes.enabled,
+        }
+    }
+
+    /// Whether CLI flags pick providers for this run
+    pub(super) fn chosen_by_flags(args: &RunArgs) -> bool {
+        args.aws
+            || args.gcp
+            || args.azure
+            || args.all_clouds
+            || args.no_github
+            || args.gitlab
+            || args.bitbucket
+            || args.kube
+    }
+}
+
+/// Whether AWS credentials need an MFA code this run (nothing valid cached).
+/// Errors are left for [`gather_credentials`] to report.
+pub(super) async fn needs_aws_mfa_code(config: &Config) -> bool {
+    match CredentialCache::new().await {
+        Ok(cache) => AwsCredentials::needs_mfa_code(&config.credentials.aws, &cache)
+            .await
+            .unwrap_or(false),
+        Err(_) => false,
+    }
+}
 
-/// Returns (env_vars, successfully loaded providers, failed providers with errors)
+/// Credentials loaded for a session
+pub(super) struct GatheredCredentials {
+    /// Env vars for the container
+    pub env: HashMap<String, String>,
+    /// Git credential helpers
+    pub git_config: GitConfigEnv,
+    /// Providers that loaded
+    pub providers: Vec<String>,
+    /// Providers that failed, with the error
+    pub failures: Vec<(String, String)>,
+    /// Kubeconfig with a ServiceAccount token (`--kube`)
+    pub kubeconfig: Option<String>,
+    /// When the loaded credentials expire, for providers that issue
+    /// expiring ones
+    pub expiry: BTreeMap<String, DateTime<Utc>>,
+}
+
+/// Fetch credentials of the selected providers. A provider that fails is
+/// reported in [`GatheredCredentials::failures`], not returned as an error.
+///
+/// `aws_mfa_code` is the code entered for `credentials.aws.mfa_serial`, if any.
 pub(super) async fn gather_credentials(
     args: &RunArgs,
     config: &Config,
-) -> MinoResult<(HashMap<String, String>, Vec<String>, Vec<(String, String)>)> {
+    selection: ProviderSelection,
+    aws_mfa_code: Option<&str>,
+) -> MinoResult<GatheredCredentials> {
     let mut env_vars = HashMap::new();
+    let mut git_config = GitConfigEnv::default();
     let mut providers = Vec::new();
     let mut failures: Vec<(String, String)> = Vec::new();
+    let mut expiry = BTreeMap::new();
     let cache = CredentialCache::new().await?;
 
-    let (use_aws, use_gcp, use_azure) = if args.all_clouds {
-        (true, true, true)
-    } else {
-        (
-            args.aws || config.credentials.aws.enabled,
-            args.gcp || config.credentials.gcp.enabled,
-            args.azure || config.credentials.azure.enabled,
-        )
-    };
-
-    if use_aws {
+    if selection.aws {
         debug!("Fetching AWS credentials...");
-        match AwsCredentials::get_session_token(&config.credentials.aws, &cache).await {
+        match AwsCredentials::get_session_token(&config.credentials.aws, &cache, aws_mfa_code).await
+        {
             Ok(creds) => {
+                if let Some(expires_at) = creds.expires_at {
+                    expiry.insert("aws".to_string(), expires_at);
+                }
                 env_vars.insert("AWS_ACCESS_KEY_ID".to_string(), creds.access_key_id);
                 env_vars.insert("AWS_SECRET_ACCESS_KEY".to_string(), creds.secret_access_key);
                 if let Some(token) = creds.session_token {
@@ -51,7 +140,7 @@ pub(super) async fn gather_credentials(
         }
     }
 
-    if use_gcp {
+    if selection.gcp {
         debug!("Fetching GCP credentials...");
         match GcpCredentials::get_access_token(&config.credentials.gcp, &cache).await {
             Ok(token) => {
@@ -68,7 +157,7 @@ pub(super) async fn gather_credentials(
         }
     }
 
-    if use_azure {
+    if selection.azure {
         debug!("Fetching Azure credentials...");
         match AzureCredentials::get_access_token(&config.credentials.azure, &cache).await {
             Ok(token) => {
@@ -82,12 +171,16 @@ pub(super) async fn gather_credentials(
         }
     }
 
-    if !args.no_github {
+    if selection.github {
         debug!("Fetching GitHub token...");
-        match GithubCredentials::get_token(&config.credentials.github).await {
+        match GithubCredentials::get_session_token(&config.credentials.github, &cache).await {
             Ok(token) => {
                 env_vars.insert("GITHUB_TOKEN".to_string(), token.clone());
                 env_vars.insert("GH_TOKEN".to_string(), token);
+                if config.credentials.github.app_mode() {
+                    let (key, helper) = app_token_git_config(&config.credentials.github.host);
+                    git_config.set(key, helper);
+                }
                 providers.push("github".to_string());
                 debug!("GitHub token loaded");
             }
@@ -97,9 +190,355 @@ pub(super) async fn gather_credentials(
         }
     }
 
+    if selection.gitlab {
+        debug!("Fetching GitLab token...");
+        match GitlabCredentials::get_token(&config.credentials.gitlab).await {
+            Ok(token) => {
+                env_vars.insert("GITLAB_TOKEN".to_string(), token);
+                if config.credentials.gitlab.host != "gitlab.com" {
+                    env_vars.insert(
+                        "GITLAB_HOST".to_string(),
+                        config.credentials.gitlab.host.clone(),
+                    );
+                }
+                providers.push("gitlab".to_string());
+                debug!("GitLab token loaded");
+            }
+            Err(e) => {
+                failures.push(("GitLab".to_string(), e.to_string()));
+            }
+        }
+    }
+
+    if selection.bitbucket {
+        debug!("Fetching Bitbucket token...");
+        match BitbucketCredentials::get_token(&config.credentials.bitbucket) {
+            Ok(creds) => {
+                env_vars.insert("BITBUCKET_TOKEN".to_string(), creds.token);
+                if let Some(username) = creds.username {
+                    env_vars.insert("BITBUCKET_USERNAME".to_string(), username);
+                }
+                providers.push("bitbucket".to_string());
+                debug!("Bitbucket token loaded");
+            }
+            Err(e) => {
+                failures.push(("Bitbucket".to_string(), e.to_string()));
+            }
+        }
+    }
+
+    let mut kubeconfig = None;
+    if selection.kubernetes {
+        debug!("Fetching Kubernetes token...");
+        match KubernetesCredentials::get_kubeconfig(&config.credentials.kubernetes, &cache).await {
+            Ok(config) => {
+                kubeconfig = Some(config);
+                providers.push("kubernetes".to_string());
+                debug!("Kubernetes token loaded");
+            }
+            Err(e) => {
+                failures.push(("Kubernetes".to_string(), e.to_string()));
+            }
+        }
+    }
+
+    for (name, custom) in &config.credentials.custom {
+        debug!("Fetching custom credential {}...", name);
+        match CustomCredentials::get_value(name, custom).await {
+            Ok(value) => {
+                env_vars.insert(custom.env.clone(), value);
+                providers.push(format!("custom.{}", name));
+            }
+            Err(e) => {
+                failures.push((format!("custom.{}", name), e.to_string()));
+            }
+        }
+    }
+
     for (key, value) in &args.env {
         env_vars.insert(key.clone(), value.clone());
     }
 
-    Ok((env_vars, providers, failures))
+    // The other providers only return the token; the cache knows its expiry.
+    // `gh auth token` tokens don't expire and aren't cached.
+    for provider in ["gcp", "azure", "github", "kubernetes"] {
+        if !providers.iter().any(|p| p == provider)
+            || (provider == "github" && !config.credentials.github.app_mode())
+        {
+            continue;
+        }
+        if let Some(expires_at) = cache.expires_at(provider).await? {
+            expiry.insert(provider.to_string(), expires_at);
+        }
+    }
+
+    Ok(GatheredCredentials {
+        env: env_vars,
+        git_config,
+        providers,
+        failures,
+        kubeconfig,
+        expiry,
+    })
+}
+
+/// Repositories a `--deploy-key` session's key is added to: the configured
+/// ones, else the project's `origin` on the GitHub host
+pub(super) async fn deploy_key_repos(
+    config: &Config,
+    project_dir: &Path,
+) -> MinoResult<Vec<String>> {
+    let repos = &config.credentials.deploy_key.repos;
+    if repos.is_empty() {
+        let host = &config.credentials.github.host;
+        return Ok(vec![deploy_key::origin_repo(project_dir, host).await?]);
+    }
+    for repo in repos {
+        deploy_key::validate_repo(repo)?;
+    }
+    Ok(repos.clone())
+}
+
+/// Git config entry pointing git at a credential helper that reads the
+/// refreshed token from [`APP_TOKEN_PATH`], falling back to `GITHUB_TOKEN`
+/// until the first refresh lands
+fn app_token_git_config(host: &str) -> (String, String) {
+    github_git_config(host, "echo \"$GITHUB_TOKEN\"")
+}
+
+/// Git config entry for a credential helper that prints the token at
+/// [`APP_TOKEN_PATH`], or the output of `fallback` before it exists
+fn github_git_config(host: &str, fallback: &str) -> (String, String) {
+    let helper = format!(
+        "!f() {{ test \"$1\" = get || return 0; echo username=x-access-token; \
+         echo \"password=$(cat {} 2>/dev/null || {})\"; }}; f",
+        APP_TOKEN_PATH, fallback
+    );
+    (format!("credential.https://{}.helper", host), helper)
+}
+
+/// Move token env vars into `files` and point `env` at them
+/// (`credentials.delivery = "file"`), so no secret shows up in
+/// `podman inspect` or `/proc/<pid>/environ`.
+pub(super) async fn deliver_as_files(
+    files: &SessionCredentialFiles,
+    env: &mut HashMap<String, String>,
+    git_config: &mut GitConfigEnv,
+    config: &Config,
+) -> MinoResult<()> {
+    for (file, content) in file_delivery(env, git_config, &config.credentials.github.host) {
+        files.write_token(file, &content).await?;
+    }
+    Ok(())
+}
+
+/// Remove token env vars from `env`, add pointers in their place, and return
+/// the files to write
+fn file_delivery(
+    env: &mut HashMap<String, String>,
+    git_config: &mut GitConfigEnv,
+    github_host: &str,
+) -> Vec<(&'static str, String)> {
+    let mut files = Vec::new();
+    let pointer = |env: &mut HashMap<String, String>, key: &str, file: &'static str| {
+        env.insert(
+            key.to_string(),
+            SessionCredentialFiles::container_path(file),
+        );
+    };
+
+    if let (Some(key_id), Some(secret)) = (
+        env.remove("AWS_ACCESS_KEY_ID"),
+        env.remove("AWS_SECRET_ACCESS_KEY"),
+    ) {
+        let session_token = env.remove("AWS_SESSION_TOKEN");
+        files.push((
+            AWS_SHARED_CREDENTIALS_FILE,
+            aws_shared_credentials(&key_id, &secret, session_token.as_deref()),
+        ));
+        pointer(
+            env,
+            "AWS_SHARED_CREDENTIALS_FILE",
+            AWS_SHARED_CREDENTIALS_FILE,
+        );
+    }
+
+    // gcloud reads CLOUDSDK_AUTH_ACCESS_TOKEN_FILE itself; the rest are
+    // pointers for tools and scripts to read
+    for (key, file, pointer_key) in [
+        (
+            "CLOUDSDK_AUTH_ACCESS_TOKEN",
+            GCP_TOKEN_FILE,
+            "CLOUDSDK_AUTH_ACCESS_TOKEN_FILE",
+        ),
+        (
+            "AZURE_ACCESS_TOKEN",
+            AZURE_TOKEN_FILE,
+            "AZURE_ACCESS_TOKEN_FILE",
+        ),
+        ("GITLAB_TOKEN", GITLAB_TOKEN_FILE, "GITLAB_TOKEN_FILE"),
+        (
+            "BITBUCKET_TOKEN",
+            BITBUCKET_TOKEN_FILE,
+            "BITBUCKET_TOKEN_FILE",
+        ),
+    ] {
+        if let Some(token) = env.remove(key) {
+            files.push((file, token));
+            pointer(env, pointer_key, file);
+        }
+    }
+
+    let github_token = env.remove("GITHUB_TOKEN");
+    if let Some(token) = env.remove("GH_TOKEN").or(github_token) {
+        files.push((GITHUB_TOKEN_FILE, token));
+        pointer(env, "GITHUB_TOKEN_FILE", GITHUB_TOKEN_FILE);
+        // Git can't read a token file, so a credential helper does it; with
+        // a GitHub App the refreshed token still takes precedence
+        let fallback = format!(
+            "cat {}",
+            SessionCredentialFiles::container_path(GITHUB_TOKEN_FILE)
+        );
+        let (key, helper) = github_git_config(github_host, &fallback);
+        git_config.set(key, helper);
+    }
+
+    files
+}
+
+/// Keep the GitHub App installation token at [`APP_TOKEN_PATH`] fresh while
+/// the session is attached. Abort the returned task when the session ends.
+pub(super) fn spawn_github_app_refresh(
+    runtime: Arc<dyn ContainerRuntime>,
+    container_id: String,
+    session_name: String,
+    config: GithubConfig,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        loop {
+            let delay = match refresh_github_app_token(&*runtime, &container_id, &config).await {
+                Ok(expires_at) => {
+                    let expiry = BTreeMap::from([("github".to_string(), expires_at)]);
+                    record_credential_expiry(&session_name, &expiry).await;
+                    (expires_at - Utc::now())
+                        .to_std()
+                        .unwrap_or_default()
+                        .max(APP_REFRESH_RETRY)
+                }
+                Err(e) => {
+                    warn!("Refreshing the GitHub App token failed: {}", e);
+                    APP_REFRESH_RETRY
+                }
+            };
+            tokio::time::sleep(delay).await;
+        }
+    })
+}
+
+/// Update the credential expiry in a session's record after a refresh.
+/// Best-effort: the session may be gone already.
+pub(super) async fn record_credential_expiry(
+    session_name: &str,
+    expiry: &BTreeMap<String, DateTime<Utc>>,
+) {
+    let result = match SessionManager::new().await {
+        Ok(manager) => manager.set_credential_expiry(session_name, expiry).await,
+        Err(e) => Err(e),
+    };
+    if let Err(e) = result {
+        debug!("Recording credential expiry of {}: {}", session_name, e);
+    }
+}
+
+/// Write the current installation token into the container, minting a new
+/// one when the cached token is due for refresh. Returns when that is.
+async fn refresh_github_app_token(
+    runtime: &dyn ContainerRuntime,
+    container_id: &str,
+    config: &GithubConfig,
+) -> MinoResult<DateTime<Utc>> {
+    let cache = CredentialCache::new().await?;
+    let token = GithubCredentials::get_app_token(config, &cache).await?;
+
+    // Staged in the private credentials dir so the token never sits in /tmp
+    let staged = ConfigManager::credentials_dir().join(format!(
+        "github-app-token-{}.tmp",
+        uuid::Uuid::new_v4().simple()
+    ));
+    tokio::fs::write(&staged, &token.value)
+        .await
+        .map_err(|e| MinoError::io(format!("writing {}", staged.display()), e))?;
+    let result = runtime
+        .copy_to_container(container_id, &staged, APP_TOKEN_PATH)
+        .await;
+    let _ = tokio::fs::remove_file(&staged).await;
+    result?;
+
+    debug!("GitHub App token refreshed until {}", token.expires_at);
+    Ok(token.expires_at)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn app_token_helper_is_scoped_to_the_host() {
+        let (key, helper) = app_token_git_config("ghe.example.com");
+        assert_eq!(key, "credential.https://ghe.example.com.helper");
+        assert!(helper.starts_with('!'));
+        assert!(helper.contains(APP_TOKEN_PATH));
+    }
+
+    #[test]
+    fn file_delivery_leaves_only_pointers() {
+        let mut env = HashMap::from([
+            ("AWS_ACCESS_KEY_ID".to_string(), "ASIA123".to_string()),
+            ("AWS_SECRET_ACCESS_KEY".to_string(), "secret".to_string()),
+            ("AWS_SESSION_TOKEN".to_string(), "session".to_string()),
+            ("AWS_REGION".to_string(), "eu-west-1".to_string()),
+            ("CLOUDSDK_AUTH_ACCESS_TOKEN".to_string(), "ya29".to_string()),
+            ("GITHUB_TOKEN".to_string(), "ghp".to_string()),
+            ("GH_TOKEN".to_string(), "ghp".to_string()),
+            ("BITBUCKET_USERNAME".to_string(), "dev".to_string()),
+        ]);
+
+        let mut git_config = GitConfigEnv::default();
+        git_config.set("user.name", "Ada");
+        let (key, helper) = app_token_git_config("github.com");
+        git_config.set(key, helper);
+        let files = file_delivery(&mut env, &mut git_config, "github.com");
+
+        let names: Vec<&str> = files.iter().map(|(file, _)| *file).collect();
+        assert_eq!(names, ["aws-credentials", "gcp-token", "github-token"]);
+        assert!(files[0].1.contains("aws_session_token = session"));
+        for key in [
+            "AWS_ACCESS_KEY_ID",
+            "AWS_SECRET_ACCESS_KEY",
+            "AWS_SESSION_TOKEN",
+            "CLOUDSDK_AUTH_ACCESS_TOKEN",
+            "GITHUB_TOKEN",
+            "GH_TOKEN",
+        ] {
+            assert!(!env.contains_key(key), "{} still set", key);
+        }
+        assert_eq!(
+            env["AWS_SHARED_CREDENTIALS_FILE"],
+            "/run/mino/credentials/aws-credentials"
+        );
+        assert_eq!(
+            env["CLOUDSDK_AUTH_ACCESS_TOKEN_FILE"],
+            "/run/mino/credentials/gcp-token"
+        );
+        assert_eq!(
+            env["GITHUB_TOKEN_FILE"],
+            "/run/mino/credentials/github-token"
+        );
+        let git_env = git_config.to_env();
+        assert_eq!(git_env["GIT_CONFIG_COUNT"], "2");
+        assert_eq!(git_env["GIT_CONFIG_KEY_0"], "user.name");
+        assert!(git_env["GIT_CONFIG_VALUE_1"].contains("cat /run/mino/credentials/github-token"));
+        assert_eq!(env["AWS_REGION"], "eu-west-1");
+        assert_eq!(env["BITBUCKET_USERNAME"], "dev");
+    }
 }
@@ -0,0 +1,300 @@
+//! Git config injected into sessions through env vars
+//!
+//! `[git]` settings, the identity propagated by `container.propagate`, and
+//! credential helpers all end up in one [`GitConfigEnv`]. Nothing is
+//! mounted over `~/.gitconfig`, so `git config --global` keeps working in
+//! the container.
+
+use crate::config::schema::GitConfig;
+use std::collections::HashMap;
+use std::process::Stdio;
+use tokio::process::Command;
+use tracing::debug;
+
+/// Git config entries passed through git's `GIT_CONFIG_COUNT`,
+/// `GIT_CONFIG_KEY_<n>` and `GIT_CONFIG_VALUE_<n>` env vars.
+///
+/// Every source adds its entries here rather than setting the variables
+/// itself: each would count from 0 and overwrite the others' entries.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(super) struct GitConfigEnv(Vec<(String, String)>);
+
+impl GitConfigEnv {
+    /// Set `key`, replacing an earlier value for it
+    pub fn set(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        let (key, value) = (key.into(), value.into());
+        match self.0.iter_mut().find(|(k, _)| *k == key) {
+            Some(entry) => entry.1 = value,
+            None => self.0.push((key, value)),
+        }
+    }
+
+    /// Add an entry, keeping earlier values of a multi-valued key
+    pub fn add(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        self.0.push((key.into(), value.into()));
+    }
+
+    /// Add the entries of `other`, whose values win for keys set in both
+    pub fn merge(&mut self, other: &GitConfigEnv) {
+        for (key, value) in &other.0 {
+            self.set(key.as_str(), value.as_str());
+        }
+    }
+
+    /// The env vars carrying every entry; empty when there are none
+    pub fn to_env(&self) -> HashMap<String, String> {
+        let mut env = HashMap::new();
+        if self.0.is_empty() {
+            return env;
+        }
+        env.insert("GIT_CONFIG_COUNT".to_string(), self.0.len().to_string());
+        for (i, (key, value)) in self.0.iter().enumerate() {
+            env.insert(format!("GIT_CONFIG_KEY_{i}"), key.clone());
+            env.insert(format!("GIT_CONFIG_VALUE_{i}"), value.clone());
+        }
+        env
+    }
+}
+
+/// Git config for the `[git]` settings, on top of the host's global
+/// config when `include_host_gitconfig` is set
+pub(super) async fn session_git_config(config: &GitConfig) -> GitConfigEnv {
+    let mut git_config = GitConfigEnv::default();
+    if config.include_host_gitconfig {
+        if let Some(listing) = host_git_config_listing().await {
+            git_config = parse_host_listing(&listing);
+        }
+    }
+    apply_settings(&mut git_config, config);
+    git_config
+}
+
+/// `git config --global --list -z` output on the host, includes resolved
+async fn host_git_config_listing() -> Option<Vec<u8>> {
+    let output = Command::new("git")
+        .args(["config", "--global", "--includes", "--list", "-z"])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .output()
+        .await
+        .ok()?;
+    output.status.success().then_some(output.stdout)
+}
+
+/// Host git config sections passed into the sandbox. Everything else stays
+/// out: `credential.*` helpers, tokens in `http.*.extraheader` and
+/// `url.*.insteadOf`, and host programs such as `core.sshCommand`,
+/// `core.hooksPath`, `core.fsmonitor` and `gpg.program`.
+const HOST_SECTIONS: &[&str] = &["user", "alias", "init", "pull", "merge", "diff", "color"];
+
+/// Single host git config keys passed into the sandbox
+const HOST_KEYS: &[&str] = &["push.default"];
+
+/// Whether a host git config entry may be passed into the sandbox
+fn is_allowed_host_key(key: &str) -> bool {
+    let key = key.to_ascii_lowercase();
+    HOST_KEYS.contains(&key.as_str())
+        || key
+            .split_once('.')
+            .is_some_and(|(section, _)| HOST_SECTIONS.contains(&section))
+}
+
+/// Entries of a `--list -z` listing (`key\nvalue\0`) in the allowed
+/// sections, so no host secrets or host-path programs reach the sandbox
+fn parse_host_listing(listing: &[u8]) -> GitConfigEnv {
+    let mut git_config = GitConfigEnv::default();
+    for entry in String::from_utf8_lossy(listing).split('\0') {
+        // A key without a value (`[pull] rebase`) has no newline
+        let (key, value) = entry.split_once('\n').unwrap_or((entry, "true"));
+        if !is_allowed_host_key(key) {
+            continue;
+        }
+        git_config.add(key, value);
+    }
+    debug!("Including host git config ({} entries)", git_config.0.len());
+    git_config
+}
+
+/// The `[git]` settings, which win over host entries for the same key
+fn apply_settings(git_config: &mut GitConfigEnv, config: &GitConfig) {
+    if let Some(name) = &config.user_name {
+        git_config.set("user.name", name.as_str());
+    }
+    if let Some(email) = &config.user_email {
+        git_config.set("user.email", email.as_str());
+    }
+    if let Some(key) = config.signing_key.as_deref().map(signing_key_value) {
+        if key.starts_with("key::") {
+            git_config.set("gpg.format", "ssh");
+        }
+        git_config.set("user.signingkey", key);
+        git_config.set("commit.gpgsign", "true");
+        git_config.set("tag.gpgsign", "true");
+    }
+}
+
+/// `user.signingkey` value: SSH public keys become `key::` literals, which
+/// git signs with through the agent
+fn signing_key_value(key: &str) -> String {
+    if key.starts_with("ssh-") || key.starts_with("ecdsa-") {
+        format!("key::{}", key)
+    } else {
+        key.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn identity() -> GitConfig {
+        GitConfig {
+            user_name: Some("Ada Lovelace".to_string()),
+            user_email: Some("ada@example.com".to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn git_config_env_indexes_every_source() {
+        let mut identity = GitConfigEnv::default();
+        identity.set("user.name", "Ada");
+        identity.set("user.email", "ada@example.com");
+        let mut helper = GitConfigEnv::default();
+        helper.set("credential.https://github.com.helper", "!f() { :; }; f");
+
+        identity.merge(&helper);
+        let env = identity.to_env();
+        assert_eq!(env["GIT_CONFIG_COUNT"], "3");
+        assert_eq!(env["GIT_CONFIG_KEY_0"], "user.name");
+        assert_eq!(env["GIT_CONFIG_VALUE_1"], "ada@example.com");
+        assert_eq!(
+            env["GIT_CONFIG_KEY_2"],
+            "credential.https://github.com.helper"
+        );
+        assert!(GitConfigEnv::default().to_env().is_empty());
+    }
+
+    #[test]
+    fn git_config_env_set_replaces_the_value() {
+        let mut config = GitConfigEnv::default();
+        config.set("credential.helper", "env");
+        config.set("credential.helper", "file");
+        let env = config.to_env();
+        assert_eq!(env["GIT_CONFIG_COUNT"], "1");
+        assert_eq!(env["GIT_CONFIG_VALUE_0"], "file");
+    }
+
+    #[tokio::test]
+    async fn nothing_without_git_settings() {
+        assert_eq!(
+            session_git_config(&GitConfig::default()).await,
+            GitConfigEnv::default()
+        );
+    }
+
+    #[tokio::test]
+    async fn settings_set_the_identity() {
+        let env = session_git_config(&identity()).await.to_env();
+        assert_eq!(env["GIT_CONFIG_COUNT"], "2");
+        assert_eq!(env["GIT_CONFIG_KEY_0"], "user.name");
+        assert_eq!(env["GIT_CONFIG_VALUE_0"], "Ada Lovelace");
+        assert_eq!(env["GIT_CONFIG_VALUE_1"], "ada@example.com");
+    }
+
+    #[test]
+    fn host_listing_drops_credentials_and_keeps_multi_values() {
+        let listing = b"user.name\nHost\0credential.helper\nstore\0\
+                        credential.https://github.com.username\nada\0\
+                        alias.co\ncheckout\0diff.orderFile\n/a\0\
+                        diff.orderFile\n/b\0pull.rebase\0";
+        let mut git_config = parse_host_listing(listing);
+        apply_settings(&mut git_config, &identity());
+        let env = git_config.to_env();
+        assert_eq!(env["GIT_CONFIG_COUNT"], "6");
+        assert_eq!(env["GIT_CONFIG_KEY_0"], "user.name");
+        assert_eq!(env["GIT_CONFIG_VALUE_0"], "Ada Lovelace");
+        assert_eq!(env["GIT_CONFIG_KEY_1"], "alias.co");
+        assert_eq!(env["GIT_CONFIG_VALUE_3"], "/b");
+        assert_eq!(env["GIT_CONFIG_KEY_4"], "pull.rebase");
+        assert_eq!(env["GIT_CONFIG_VALUE_4"], "true");
+        assert_eq!(env["GIT_CONFIG_KEY_5"], "user.email");
+        assert!(!env.values().any(|v| v.contains("credential")));
+    }
+
+    fn host_keys(listing: &[u8]) -> Vec<String> {
+        parse_host_listing(listing)
+            .0
+            .into_iter()
+            .map(|(key, _)| key)
+            .collect()
+    }
+
+    #[test]
+    fn host_listing_drops_http_headers() {
+        let listing = b"http.https://github.com/.extraheader\nAUTHORIZATION: bearer ghp_x\0\
+                        http.sslVerify\nfalse\0";
+        assert!(host_keys(listing).is_empty());
+    }
+
+    #[test]
+    fn host_listing_drops_url_rewrites() {
+        let listing = b"url.https://ghp_x@github.com/.insteadOf\nhttps://github.com/\0\
+                        url.https://ghp_x@github.com/.pushInsteadOf\nhttps://github.com/\0";
+        assert!(host_keys(listing).is_empty());
+    }
+
+    #[test]
+    fn host_listing_drops_host_programs() {
+        let listing = b"core.sshCommand\nssh -i /Users/ada/.ssh/id\0\
+                        core.hooksPath\n/Users/ada/hooks\0core.fsmonitor\n/usr/local/bin/fsm\0\
+                        gpg.program\n/opt/homebrew/bin/gpg\0gpg.ssh.program\nssh-keygen\0";
+        assert!(host_keys(listing).is_empty());
+    }
+
+    #[test]
+    fn host_listing_keeps_allowed_sections_and_push_default() {
+        let listing = b"init.defaultBranch\nmain\0merge.conflictStyle\nzdiff3\0\
+                        color.ui\nauto\0push.default\nsimple\0push.autoSetupRemote\0\
+                        User.Email\nada@example.com\0";
+        assert_eq!(
+            host_keys(listing),
+            vec![
+                "init.defaultBranch",
+                "merge.conflictStyle",
+                "color.ui",
+                "push.default",
+                "User.Email"
+            ]
+        );
+    }
+
+    #[test]
+    fn ssh_signing_key_turns_on_ssh_signing() {
+        let mut git_config = GitConfigEnv::default();
+        apply_settings(
+            &mut git_config,
+            &GitConfig {
+                signing_key: Some("ssh-ed25519 AAAAC3Nza ada@laptop".to_string()),
+                ..Default::default()
+            },
+        );
+        let entries = &git_config.0;
+        assert!(entries.contains(&(
+            "user.signingkey".to_string(),
+            "key::ssh-ed25519 AAAAC3Nza ada@laptop".to_string()
+        )));
+        assert!(entries.contains(&("commit.gpgsign".to_string(), "true".to_string())));
+        assert!(entries.contains(&("gpg.format".to_string(), "ssh".to_string())));
+
+        let mut gpg = GitConfigEnv::default();
+        apply_settings(
+            &mut gpg,
+            &GitConfig {
+                signing_key: Some("3AA5C34371567BD2".to_string()),
+                ..Default::default()
+            },
+        );
+        assert!(!gpg.0.iter().any(|(key, _)| key == "gpg.format"));
+    }
+}
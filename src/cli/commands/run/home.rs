@@ -12,14 +12,15 @@ use super::image::LAYER_BASE_IMAGE;
 
 /// Set up a persistent home volume for the project, if applicable.
 ///
-/// Returns `Some("volume_name:/home/developer")` when a home volume should
-/// be mounted, or `None` when home volumes are disabled or not applicable.
+/// Returns `Some("volume_name:<home_dir>")` when a home volume should be
+/// mounted, or `None` when home volumes are disabled or not applicable.
 pub(super) async fn setup_home_volume(
     runtime: &dyn ContainerRuntime,
     args: &RunArgs,
     config: &Config,
     project_dir: &Path,
     image: &str,
+    home_dir: &str,
 ) -> MinoResult<Option<String>> {
     // Guard: disabled by CLI flag or config
     if args.no_home || !config.home.enabled {
@@ -33,9 +34,9 @@ pub(super) async fn setup_home_volume(
         return Ok(None);
     }
 
-    // Guard: user-specified volume already targets /home/developer
-    if has_home_mount(&args.volume, &config.container.volumes) {
-        debug!("Skipping home volume: user-specified mount at /home/developer");
+    // Guard: user-specified volume already targets the home directory
+    if has_home_mount(&args.volume, &config.container.volumes, home_dir) {
+        debug!("Skipping home volume: user-specified mount at {}", home_dir);
         return Ok(None);
     }
 
@@ -51,7 +52,7 @@ pub(super) async fn setup_home_volume(
         runtime.volume_create(&volume_name, &labels).await?;
     }
 
-    Ok(Some(format!("{}:/home/developer", volume_name)))
+    Ok(Some(format!("{}:{}", volume_name, home_dir)))
 }
 
 /// Check whether the resolved image is a mino-managed image.
@@ -61,12 +62,16 @@ pub(super) fn is_mino_image(image: &str) -> bool {
     image == LAYER_BASE_IMAGE || image.starts_with("mino-composed-")
 }
 
-/// Check whether user-specified volumes include a mount at /home/developer.
-pub(super) fn has_home_mount(cli_volumes: &[String], config_volumes: &[String]) -> bool {
+/// Check whether user-specified volumes include a mount at `home_dir`.
+pub(super) fn has_home_mount(
+    cli_volumes: &[String],
+    config_volumes: &[String],
+    home_dir: &str,
+) -> bool {
     cli_volumes
         .iter()
         .chain(config_volumes.iter())
-        .any(|v| v.contains(":/home/developer"))
+        .any(|v| v.split(':').nth(1) == Some(home_dir))
 }
 
 #[cfg(test)]
@@ -78,30 +83,60 @@ mod tests {
     use std::path::PathBuf;
     use std::sync::Arc;
 
+    const HOME: &str = "/home/developer";
+
     fn test_args() -> RunArgs {
         RunArgs {
             name: None,
-            project: None,
+            project: vec![],
             aws: false,
             gcp: false,
             azure: false,
             all_clouds: false,
             no_ssh_agent: false,
             no_github: false,
+            gitlab: false,
+            bitbucket: false,
+            kube: false,
+            deploy_key: false,
             strict_credentials: false,
+            refresh_credentials: false,
             image: None,
             layers: vec![],
+            layer_args: vec![],
+            allow_layer_conflicts: false,
             env: vec![],
             volume: vec![],
+            workdir: None,
             detach: false,
+            timeout: None,
+            heartbeat: None,
             read_only: false,
             no_cache: false,
             no_home: false,
             cache_fresh: false,
+            cache_rw: false,
+            scratch: false,
+            worktree: false,
+            cpus: None,
+            memory: None,
+            pids_limit: None,
+            ulimit: vec![],
+            gpus: false,
+            userns: None,
+            stdin: false,
+            device: vec![],
+            tmpfs: vec![],
+            restart: None,
+            platform: None,
+            pull: None,
             network: None,
             network_allow: vec![],
             network_preset: None,
             runtime: None,
+            native: false,
+            allow_degraded: false,
+            context: None,
             command: vec![],
         }
     }
@@ -115,7 +150,7 @@ mod tests {
         let config = Config::default();
         let project = PathBuf::from("/tmp/test-project");
 
-        let result = setup_home_volume(&*mock, &args, &config, &project, LAYER_BASE_IMAGE)
+        let result = setup_home_volume(&*mock, &args, &config, &project, LAYER_BASE_IMAGE, HOME)
             .await
             .unwrap();
 
@@ -143,7 +178,7 @@ mod tests {
         let config = Config::default();
         let project = PathBuf::from("/tmp/test-project");
 
-        let result = setup_home_volume(&*mock, &args, &config, &project, LAYER_BASE_IMAGE)
+        let result = setup_home_volume(&*mock, &args, &config, &project, LAYER_BASE_IMAGE, HOME)
             .await
             .unwrap();
 
@@ -160,7 +195,7 @@ mod tests {
         let config = Config::default();
         let project = PathBuf::from("/tmp/test-project");
 
-        let result = setup_home_volume(&*mock, &args, &config, &project, LAYER_BASE_IMAGE)
+        let result = setup_home_volume(&*mock, &args, &config, &project, LAYER_BASE_IMAGE, HOME)
             .await
             .unwrap();
 
@@ -177,7 +212,7 @@ mod tests {
         config.home.enabled = false;
         let project = PathBuf::from("/tmp/test-project");
 
-        let result = setup_home_volume(&*mock, &args, &config, &project, LAYER_BASE_IMAGE)
+        let result = setup_home_volume(&*mock, &args, &config, &project, LAYER_BASE_IMAGE, HOME)
             .await
             .unwrap();
 
@@ -192,7 +227,7 @@ mod tests {
         let config = Config::default();
         let project = PathBuf::from("/tmp/test-project");
 
-        let result = setup_home_volume(&*mock, &args, &config, &project, "fedora:43")
+        let result = setup_home_volume(&*mock, &args, &config, &project, "fedora:43", HOME)
             .await
             .unwrap();
 
@@ -213,6 +248,7 @@ mod tests {
             &config,
             &project,
             "mino-composed-abc123def456",
+            HOME,
         )
         .await
         .unwrap();
@@ -221,6 +257,27 @@ mod tests {
         mock.assert_called("volume_create", 1);
     }
 
+    #[tokio::test]
+    async fn setup_mounts_at_custom_home() {
+        let mock = Arc::new(MockRuntime::new());
+        let args = test_args();
+        let config = Config::default();
+        let project = PathBuf::from("/tmp/test-project");
+
+        let result = setup_home_volume(
+            &*mock,
+            &args,
+            &config,
+            &project,
+            LAYER_BASE_IMAGE,
+            "/home/app",
+        )
+        .await
+        .unwrap();
+
+        assert!(result.unwrap().ends_with(":/home/app"));
+    }
+
     #[tokio::test]
     async fn setup_skips_when_user_volume_at_home() {
         let mock = Arc::new(MockRuntime::new());
@@ -229,7 +286,7 @@ mod tests {
         let config = Config::default();
         let project = PathBuf::from("/tmp/test-project");
 
-        let result = setup_home_volume(&*mock, &args, &config, &project, LAYER_BASE_IMAGE)
+        let result = setup_home_volume(&*mock, &args, &config, &project, LAYER_BASE_IMAGE, HOME)
             .await
             .unwrap();
 
@@ -261,30 +318,44 @@ mod tests {
     #[test]
     fn has_home_mount_cli_volume() {
         let cli = vec!["/my/dir:/home/developer".to_string()];
-        assert!(has_home_mount(&cli, &[]));
+        assert!(has_home_mount(&cli, &[], HOME));
     }
 
     #[test]
     fn has_home_mount_cli_volume_with_options() {
         let cli = vec!["/my/dir:/home/developer:rw".to_string()];
-        assert!(has_home_mount(&cli, &[]));
+        assert!(has_home_mount(&cli, &[], HOME));
     }
 
     #[test]
     fn has_home_mount_config_volume() {
         let config = vec!["/my/dir:/home/developer".to_string()];
-        assert!(has_home_mount(&[], &config));
+        assert!(has_home_mount(&[], &config, HOME));
     }
 
     #[test]
     fn has_home_mount_no_match() {
         let cli = vec!["/my/dir:/workspace".to_string()];
         let config = vec!["/my/dir:/home/other".to_string()];
-        assert!(!has_home_mount(&cli, &config));
+        assert!(!has_home_mount(&cli, &config, HOME));
+    }
+
+    #[test]
+    fn has_home_mount_ignores_paths_sharing_the_prefix() {
+        let cli = vec!["/my/dir:/home/developerdata".to_string()];
+        let config = vec!["/data:/home/developer-cache:ro".to_string()];
+        assert!(!has_home_mount(&cli, &config, HOME));
+    }
+
+    #[test]
+    fn has_home_mount_custom_home() {
+        let cli = vec!["/my/dir:/home/node".to_string()];
+        assert!(has_home_mount(&cli, &[], "/home/node"));
+        assert!(!has_home_mount(&cli, &[], HOME));
     }
 
     #[test]
     fn has_home_mount_empty() {
-        assert!(!has_home_mount(&[], &[]));
+        assert!(!has_home_mount(&[], &[], HOME));
     }
 }
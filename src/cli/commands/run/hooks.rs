@@ -0,0 +1,218 @@
+//! Host-side `pre_run` / `post_run` hooks and in-container `post_create` hooks
+
+use super::template::{project_name, TemplateVars};
+use crate::error::{MinoError, MinoResult};
+use crate::network::shell_escape;
+use std::path::Path;
+use tokio::process::Command;
+use tracing::{debug, warn};
+
+/// Session metadata exported to hook commands.
+pub(super) struct HookContext<'a> {
+    pub session_name: &'a str,
+    pub project_dir: &'a Path,
+    /// Project's git branch for `{branch}` (empty outside a repo)
+    pub branch: &'a str,
+    pub image: &'a str,
+    /// Container exit code (post_run only)
+    pub exit_code: Option<i32>,
+}
+
+impl HookContext<'_> {
+    /// Env vars describing the session, exported to every hook.
+    fn env(&self, stage: &str) -> Vec<(&'static str, String)> {
+        let mut env = vec![
+            ("MINO_HOOK", stage.to_string()),
+            ("MINO_SESSION", self.session_name.to_string()),
+            ("MINO_PROJECT_DIR", self.project_dir.display().to_string()),
+            ("MINO_IMAGE", self.image.to_string()),
+        ];
+        if let Some(code) = self.exit_code {
+            env.push(("MINO_EXIT_CODE", code.to_string()));
+        }
+        env
+    }
+}
+
+/// Run `pre_run` hooks in order. The first failure aborts the session.
+pub(super) async fn run_pre_hooks(commands: &[String], ctx: &HookContext<'_>) -> MinoResult<()> {
+    for command in commands {
+        let code = run_hook("pre_run", command, ctx).await?;
+        if code != 0 {
+            return Err(MinoError::User(format!(
+                "pre_run hook failed with exit code {}: {}",
+                code, command
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Run `post_run` hooks in order. Failures are reported but do not stop
+/// later hooks, since the session has already ended.
+pub(super) async fn run_post_hooks(commands: &[String], ctx: &HookContext<'_>) {
+    for command in commands {
+        match run_hook("post_run", command, ctx).await {
+            Ok(0) => {}
+            Ok(code) => warn!("post_run hook exited with code {}: {}", code, command),
+            Err(e) => warn!("post_run hook failed: {}", e),
+        }
+    }
+}
+
+/// Run one hook through `sh -c` in the project directory, after expanding
+/// `{project}`, `{branch}`, and `{session}` placeholders to env var references.
+async fn run_hook(stage: &str, command: &str, ctx: &HookContext<'_>) -> MinoResult<i32> {
+    let project = project_name(ctx.project_dir);
+    let vars = TemplateVars {
+        project: &project,
+        branch: ctx.branch,
+        session: ctx.session_name,
+    };
+    let script = vars.expand_shell(command);
+    debug!("Running {} hook: {}", stage, script);
+
+    let status = Command::new("sh")
+        .args(["-c", &script])
+        .current_dir(ctx.project_dir)
+        .envs(vars.shell_env())
+        .envs(ctx.env(stage))
+        .status()
+        .await
+        .map_err(|e| MinoError::command_failed(format!("{stage} hook: {command}"), e))?;
+
+    Ok(status.code().unwrap_or(-1))
+}
+
+/// Printed to the container log once `post_create` hooks have run, so the
+/// two-phase shell startup knows when to hand over the TTY
+pub(super) const POST_CREATE_DONE: &str = "Post-create hooks complete.";
+
+/// Wrap `command` so `post_create` hooks run in the container first.
+///
+/// Each hook runs through `sh -c` after the entrypoint's bootstrap, with
+/// `MINO_HOOK=post_create` set. Output goes to the container's stdout and
+/// thus the session log. A failing hook is reported and the next one still
+/// runs; `command` is exec'd either way. Returns `command` unchanged when
+/// there are no hooks.
+pub(super) fn post_create_wrapper(hooks: &[String], command: &[String]) -> Vec<String> {
+    if hooks.is_empty() {
+        return command.to_vec();
+    }
+
+    let mut script = String::from("export MINO_HOOK=post_create; ");
+    for hook in hooks {
+        let escaped = shell_escape(hook);
+        script.push_str(&format!(
+            "echo 'mino: post_create: {escaped}'; \
+             sh -c '{escaped}' || echo \"mino: post_create hook exited with code $?:\" '{escaped}' >&2; "
+        ));
+    }
+    script.push_str("exec \"$@\"");
+
+    let mut wrapped = vec![
+        "/bin/sh".to_string(),
+        "-c".to_string(),
+        script,
+        "sh".to_string(),
+    ];
+    wrapped.extend(command.iter().cloned());
+    wrapped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ctx<'a>(dir: &'a Path, exit_code: Option<i32>) -> HookContext<'a> {
+        HookContext {
+            session_name: "s1",
+            project_dir: dir,
+            branch: "main",
+            image: "fedora:43",
+            exit_code,
+        }
+    }
+
+    #[tokio::test]
+    async fn pre_hooks_see_session_env_and_project_cwd() {
+        let dir = tempfile::tempdir().unwrap();
+        let hook = r#"printf '%s %s %s' "$MINO_HOOK" "$MINO_SESSION" "$MINO_IMAGE" > out.txt"#;
+
+        run_pre_hooks(&[hook.to_string()], &ctx(dir.path(), None))
+            .await
+            .unwrap();
+
+        let out = std::fs::read_to_string(dir.path().join("out.txt")).unwrap();
+        assert_eq!(out, "pre_run s1 fedora:43");
+    }
+
+    #[tokio::test]
+    async fn hooks_expand_placeholders() {
+        let dir = tempfile::tempdir().unwrap();
+        let hook = "echo {session} {branch} > out.txt".to_string();
+
+        run_pre_hooks(&[hook], &ctx(dir.path(), None))
+            .await
+            .unwrap();
+
+        let out = std::fs::read_to_string(dir.path().join("out.txt")).unwrap();
+        assert_eq!(out.trim(), "s1 main");
+    }
+
+    #[tokio::test]
+    async fn pre_hook_failure_aborts_remaining() {
+        let dir = tempfile::tempdir().unwrap();
+        let hooks = vec!["exit 3".to_string(), "touch ran.txt".to_string()];
+
+        let err = run_pre_hooks(&hooks, &ctx(dir.path(), None))
+            .await
+            .unwrap_err();
+
+        assert!(err.to_string().contains("exit code 3"));
+        assert!(!dir.path().join("ran.txt").exists());
+    }
+
+    #[tokio::test]
+    async fn post_hooks_continue_after_failure() {
+        let dir = tempfile::tempdir().unwrap();
+        let hooks = vec![
+            "exit 1".to_string(),
+            r#"echo "$MINO_EXIT_CODE" > code.txt"#.to_string(),
+        ];
+
+        run_post_hooks(&hooks, &ctx(dir.path(), Some(2))).await;
+
+        let out = std::fs::read_to_string(dir.path().join("code.txt")).unwrap();
+        assert_eq!(out.trim(), "2");
+    }
+
+    #[test]
+    fn post_create_wrapper_without_hooks_is_identity() {
+        let command = vec!["cargo".to_string(), "build".to_string()];
+        assert_eq!(post_create_wrapper(&[], &command), command);
+    }
+
+    #[tokio::test]
+    async fn post_create_wrapper_continues_after_failure_and_execs_command() {
+        let hooks = vec![
+            "exit 4".to_string(),
+            r#"echo "it's $MINO_HOOK""#.to_string(),
+        ];
+        let command = vec!["echo".to_string(), "a b".to_string()];
+        let wrapped = post_create_wrapper(&hooks, &command);
+
+        let output = Command::new(&wrapped[0])
+            .args(&wrapped[1..])
+            .output()
+            .await
+            .unwrap();
+        assert!(output.status.success());
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(stdout.contains("it's post_create\n"));
+        assert!(stdout.ends_with("a b\n"));
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.contains("exited with code 4: exit 4"));
+    }
+}
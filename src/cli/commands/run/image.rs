@@ -1,15 +1,18 @@
 //! Image and layer resolution
 
+use crate::cache::Ecosystem;
 use crate::cli::args::RunArgs;
+use crate::config::schema::ImageAlias;
 use crate::config::Config;
-use crate::error::MinoResult;
+use crate::error::{MinoError, MinoResult};
 use crate::layer::{
-    build_layer_manifest, compose_image, compute_path_prepend, merge_layer_env,
-    needs_compose_build, resolve_layers, ResolvedLayer,
+    apply_layer_args, build_layer_manifest, check_layer_conflicts, compose_image,
+    compute_path_prepend, merge_cache_key_extra, merge_layer_env, needs_compose_build,
+    packages_layer, resolve_layers, ResolvedLayer,
 };
-use crate::orchestration::ContainerRuntime;
-use crate::ui::{BuildProgress, TaskSpinner, UiContext};
-use std::collections::HashMap;
+use crate::orchestration::{ContainerRuntime, PullPolicy};
+use crate::ui::{self, BuildProgress, PullProgress, TaskSpinner, UiContext};
+use std::collections::{BTreeMap, HashMap};
 use std::path::Path;
 use tracing::debug;
 
@@ -24,7 +27,7 @@ pub(crate) const LAYER_BASE_IMAGE: &str = "ghcr.io/dean0x/mino-base:latest";
 /// Parse a comma-separated layer string into a list of layer names.
 ///
 /// Trims whitespace and filters empty segments.
-pub(super) fn parse_layers_env(val: &str) -> Vec<String> {
+pub(crate) fn parse_layers_env(val: &str) -> Vec<String> {
     val.split(',')
         .map(|s| s.trim().to_string())
         .filter(|s| !s.is_empty())
@@ -69,17 +72,38 @@ pub(super) fn image_alias_to_layer(image: &str) -> Option<&str> {
         "typescript" | "ts" | "node" => Some("typescript"),
         "rust" | "cargo" => Some("rust"),
         "python" | "py" => Some("python"),
+        "jvm" | "java" | "kotlin" => Some("jvm"),
         _ => None,
     }
 }
 
+/// Layer set for an image alias.
+///
+/// Configured `[images.aliases]` entries win over the built-in language
+/// aliases; an alias configured as an image reference never composes.
+pub(super) fn alias_layers(
+    image: &str,
+    aliases: &HashMap<String, ImageAlias>,
+) -> Option<Vec<String>> {
+    match aliases.get(image) {
+        Some(ImageAlias::Layers(layers)) => Some(layers.clone()),
+        Some(ImageAlias::Image(_)) => None,
+        None => image_alias_to_layer(image).map(|name| vec![name.to_string()]),
+    }
+}
+
 /// Resolve image aliases to full registry paths.
 ///
-/// Only `base` is a direct image alias. Language aliases (typescript, rust)
-/// are handled by `image_alias_to_layer()` and redirected to layer composition.
+/// Configured `[images.aliases]` image references are checked first. After
+/// that, only `base` is a built-in image alias. Language aliases (typescript,
+/// rust) are handled by `alias_layers()` and redirected to layer composition.
 ///
 /// Full image paths (containing `/` or `:`) are passed through unchanged.
-pub(super) fn resolve_image_alias(image: &str) -> String {
+pub(super) fn resolve_image_alias(image: &str, aliases: &HashMap<String, ImageAlias>) -> String {
+    if let Some(ImageAlias::Image(reference)) = aliases.get(image) {
+        return reference.clone();
+    }
+
     if image.contains('/') || image.contains(':') {
         return image.to_string();
     }
@@ -99,18 +123,68 @@ pub(super) fn is_default_image(args: &RunArgs, config: &Config) -> bool {
 ///
 /// When `base_only` is true, uses `LAYER_BASE_IMAGE` (user selected "Base only").
 /// Otherwise, resolves the raw image alias to a full path.
-pub(super) fn resolve_final_image(raw_image: &str, base_only: bool) -> ImageResolution {
+pub(super) fn resolve_final_image(
+    raw_image: &str,
+    base_only: bool,
+    aliases: &HashMap<String, ImageAlias>,
+) -> ImageResolution {
     let image = if base_only {
         debug!("Using base image without layers: {}", LAYER_BASE_IMAGE);
         LAYER_BASE_IMAGE.to_string()
     } else {
-        resolve_image_alias(raw_image)
+        resolve_image_alias(raw_image, aliases)
     };
 
     ImageResolution {
         image,
         layer_env: HashMap::new(),
+        cache_key_extra: HashMap::new(),
+        post_create: vec![],
+    }
+}
+
+/// Cache key extras of the layers a non-interactive session would use,
+/// for commands that key caches without resolving the image
+pub(crate) async fn configured_cache_key_extra(
+    args: &RunArgs,
+    config: &Config,
+    project_dir: &Path,
+) -> MinoResult<HashMap<Ecosystem, String>> {
+    let raw_image = args.image.as_ref().unwrap_or(&config.container.image);
+    let Some(names) = resolve_layer_names(args, config)
+        .or_else(|| alias_layers(raw_image, &config.images.aliases))
+    else {
+        return Ok(HashMap::new());
+    };
+    let resolved = resolve_layers(&names, project_dir).await?;
+    Ok(merge_cache_key_extra(&resolved))
+}
+
+/// Layer build argument overrides: `container.layer_args` for the session's
+/// layers, then `--layer-arg` (`cli`) on top.
+///
+/// Config entries for layers the session does not use are dropped, so one
+/// config can carry arguments for several layer sets; CLI entries are kept
+/// and rejected by [`apply_layer_args`] when they name no session layer.
+pub(crate) fn layer_arg_overrides(
+    cli: &[(String, String, String)],
+    config: &Config,
+    resolved: &[ResolvedLayer],
+) -> BTreeMap<String, BTreeMap<String, String>> {
+    let mut overrides: BTreeMap<String, BTreeMap<String, String>> = config
+        .container
+        .layer_args
+        .iter()
+        .filter(|(name, _)| resolved.iter().any(|l| &l.manifest.layer.name == *name))
+        .map(|(name, values)| (name.clone(), values.clone()))
+        .collect();
+    for (layer, arg, value) in cli {
+        overrides
+            .entry(layer.clone())
+            .or_default()
+            .insert(arg.clone(), value.clone());
     }
+    overrides
 }
 
 /// Inject bootstrap env vars (MINO_LAYER_MANIFEST, MINO_PATH_PREPEND) into the layer env.
@@ -151,7 +225,26 @@ pub(super) async fn resolve_image(
     // Resolve layers from CLI/config, then check image alias redirect
     // (e.g., --image typescript -> layer composition)
     let layer_names = resolve_layer_names(args, config)
-        .or_else(|| image_alias_to_layer(&raw_image).map(|name| vec![name.to_string()]));
+        .or_else(|| alias_layers(&raw_image, &config.images.aliases));
+
+    // `[container.packages]` composes onto the layer base image on its own,
+    // unless a specific image was chosen
+    let packages = &config.container.packages;
+    let layer_names = match layer_names {
+        None if !packages.is_empty() && is_default_image(args, config) => Some(vec![]),
+        None if !packages.is_empty() => {
+            ui::step_warn_hint(
+                ctx,
+                "container.packages ignored",
+                &format!(
+                    "it applies to layer composition, not to image {}",
+                    raw_image
+                ),
+            );
+            None
+        }
+        names => names,
+    };
 
     // Track whether the interactive prompt selected "Base only" (no layers but use mino-base)
     let (layer_names, base_only) =
@@ -172,55 +265,194 @@ pub(super) async fn resolve_image(
     let using_layers = layer_names.is_some() || base_only;
 
     let resolution = if let Some(names) = layer_names {
-        let mut resolved = Vec::new();
-        for name in &names {
-            spinner.message(&format!("Resolving layer: {}...", name));
-            let mut layers = resolve_layers(std::slice::from_ref(name), project_dir).await?;
-            resolved.append(&mut layers);
+        spinner.message(&format!("Resolving layers: {}...", names.join(", ")));
+        let mut resolved = resolve_layers(&names, project_dir).await?;
+        let overrides = layer_arg_overrides(&args.layer_args, config, &resolved);
+        apply_layer_args(&mut resolved, &overrides)?;
+        resolved.extend(packages_layer(&packages.dnf)?);
+
+        let label: Vec<&str> = resolved
+            .iter()
+            .map(|l| l.manifest.layer.name.as_str())
+            .collect();
+        let platform = super::container::requested_platform(args, config)?;
+        let (image, layer_env) = compose_layers(
+            runtime,
+            ctx,
+            spinner,
+            &resolved,
+            &label.join(", "),
+            &ComposeOptions {
+                platform: platform.as_deref(),
+                pull: resolve_pull_policy(args, config)?,
+                allow_conflicts: args.allow_layer_conflicts,
+            },
+        )
+        .await?;
+
+        ImageResolution {
+            image,
+            layer_env,
+            cache_key_extra: merge_cache_key_extra(&resolved),
+            post_create: resolved
+                .iter()
+                .flat_map(|l| l.manifest.hooks.post_create.iter().cloned())
+                .collect(),
         }
+    } else {
+        resolve_final_image(&raw_image, base_only, &config.images.aliases)
+    };
 
-        if needs_compose_build(&resolved) {
-            // At least one layer has root-level install script or root_install packages
-            spinner.clear();
+    Ok((resolution, using_layers))
+}
 
-            let label = names.join(", ");
-            let progress = BuildProgress::new(ctx, &label);
-            let result = compose_image(
-                runtime,
-                LAYER_BASE_IMAGE,
-                &resolved,
-                Some(&|line: String| progress.on_line(line)),
-            )
-            .await;
-            progress.finish();
-            let result = result?;
-
-            let action = if result.was_cached { "cached" } else { "built" };
-            debug!("Using {} composed image: {}", action, result.image_tag);
-
-            let mut layer_env = result.env;
-            inject_bootstrap_env(&mut layer_env, &resolved)?;
-
-            ImageResolution {
-                image: result.image_tag,
-                layer_env,
-            }
-        } else {
-            // All layers are pure user-install — skip compose entirely
-            spinner.message("Layers will install on first run via bootstrap...");
-            debug!("All layers are user-install only, skipping compose");
+/// How [`compose_layers`] builds the image
+#[derive(Debug, Default)]
+pub(crate) struct ComposeOptions<'a> {
+    /// Platform to build for (`--platform`), the host's when unset
+    pub platform: Option<&'a str>,
+    /// Pull policy for the base image the build starts from
+    pub pull: PullPolicy,
+    /// Let later layers win when layers claim the same settings
+    pub allow_conflicts: bool,
+}
 
-            let mut layer_env = merge_layer_env(&resolved, false);
-            inject_bootstrap_env(&mut layer_env, &resolved)?;
+/// Compose resolved layers onto the base image.
+///
+/// Returns the image to run and the layer env to inject. Layers that only
+/// have `[user_install]` skip the build and run on the base image, where
+/// bootstrap installs them at container start. Either way, layers claiming
+/// the same settings fail unless `allow_conflicts` is set.
+pub(crate) async fn compose_layers(
+    runtime: &dyn ContainerRuntime,
+    ctx: &UiContext,
+    spinner: &mut TaskSpinner,
+    resolved: &[ResolvedLayer],
+    label: &str,
+    options: &ComposeOptions<'_>,
+) -> MinoResult<(String, HashMap<String, String>)> {
+    check_layer_conflicts(resolved, options.allow_conflicts)?;
+    if needs_compose_build(resolved) {
+        // At least one layer has root-level install script or root_install packages
+        spinner.clear();
+
+        let progress = BuildProgress::new(ctx, label);
+        let result = compose_image(
+            runtime,
+            LAYER_BASE_IMAGE,
+            resolved,
+            options.platform,
+            options.pull,
+            Some(&|line: String| progress.on_line(line)),
+        )
+        .await;
+        progress.finish();
+        let result = result?;
+
+        let action = if result.was_cached { "cached" } else { "built" };
+        debug!("Using {} composed image: {}", action, result.image_tag);
+
+        let mut layer_env = result.env;
+        inject_bootstrap_env(&mut layer_env, resolved)?;
+        Ok((result.image_tag, layer_env))
+    } else {
+        // All layers are pure user-install — skip compose entirely
+        spinner.message("Layers will install on first run via bootstrap...");
+        debug!("All layers are user-install only, skipping compose");
+
+        let mut layer_env = merge_layer_env(resolved, false);
+        inject_bootstrap_env(&mut layer_env, resolved)?;
+        Ok((LAYER_BASE_IMAGE.to_string(), layer_env))
+    }
+}
 
-            ImageResolution {
-                image: LAYER_BASE_IMAGE.to_string(),
-                layer_env,
+/// Resolve the effective pull policy. CLI `--pull` overrides `container.pull`.
+pub(super) fn resolve_pull_policy(args: &RunArgs, config: &Config) -> MinoResult<PullPolicy> {
+    args.pull
+        .as_deref()
+        .unwrap_or(&config.container.pull)
+        .parse()
+}
+
+/// Make sure `image` is available locally according to `policy`.
+///
+/// Composed layer images are built locally and never pulled; their base
+/// image follows the policy when composing. Pulls show
+/// layer progress in place of the spinner. With a `platform`, a local copy
+/// built for another architecture counts as missing.
+pub(super) async fn apply_pull_policy(
+    runtime: &dyn ContainerRuntime,
+    image: &str,
+    policy: PullPolicy,
+    platform: Option<&str>,
+    ctx: &UiContext,
+    spinner: &mut TaskSpinner,
+) -> MinoResult<()> {
+    if image.starts_with("mino-composed-") {
+        debug!("Skipping pull policy for locally built image: {}", image);
+        return Ok(());
+    }
+
+    match policy {
+        PullPolicy::Always => {
+            debug!("Pull policy 'always': pulling {}", image);
+            pull_image(runtime, image, platform, ctx, spinner).await
+        }
+        PullPolicy::Missing => {
+            if !image_available(runtime, image, platform).await? {
+                pull_image(runtime, image, platform, ctx, spinner).await?;
             }
+            Ok(())
         }
-    } else {
-        resolve_final_image(&raw_image, base_only)
+        PullPolicy::Never => {
+            if image_available(runtime, image, platform).await? {
+                Ok(())
+            } else {
+                let platform_arg = platform
+                    .map(|p| format!("--platform {} ", p))
+                    .unwrap_or_default();
+                Err(MinoError::User(format!(
+                    "Image {} is not available locally and the pull policy is 'never'. \
+                     Pull it while online (podman pull {}{}) or use --pull=missing.",
+                    image, platform_arg, image
+                )))
+            }
+        }
+    }
+}
+
+/// Whether `image` is present locally, built for `platform` when one is set.
+/// An image whose platform can't be read is trusted.
+async fn image_available(
+    runtime: &dyn ContainerRuntime,
+    image: &str,
+    platform: Option<&str>,
+) -> MinoResult<bool> {
+    if !runtime.image_exists(image).await? {
+        return Ok(false);
+    }
+    let Some(platform) = platform else {
+        return Ok(true);
     };
+    let local = runtime.image_platform(image).await?;
+    debug!("Local {} is {:?}, want {}", image, local, platform);
+    Ok(local.is_none_or(|local| local == platform))
+}
 
-    Ok((resolution, using_layers))
+/// Pull `image` with a layer progress bar, resuming the spinner afterwards
+async fn pull_image(
+    runtime: &dyn ContainerRuntime,
+    image: &str,
+    platform: Option<&str>,
+    ctx: &UiContext,
+    spinner: &mut TaskSpinner,
+) -> MinoResult<()> {
+    spinner.clear();
+    let progress = PullProgress::new(ctx, image);
+    let result = runtime
+        .image_pull_with_progress(image, platform, &|line: String| progress.on_line(line))
+        .await;
+    progress.finish();
+    spinner.start("Initializing sandbox...");
+    result
 }
@@ -1,35 +1,78 @@
 //! Run command - start a sandboxed session
 
+pub(crate) mod bench;
 mod cache;
+mod conflict;
 mod container;
 mod credentials;
+mod gitconfig;
 mod home;
+mod hooks;
 pub(crate) mod image;
 mod native;
 mod prompts;
-
-use self::cache::{check_cache_size_warning, finalize_caches, setup_caches};
-use self::container::{build_container_config, ContainerBuildParams};
-use self::credentials::gather_credentials;
-use self::image::resolve_image;
-use self::prompts::{is_default_network, prompt_network_selection};
+mod propagate;
+mod reattach;
+mod refresh;
+mod rollback;
+mod scratch;
+mod services;
+mod template;
+mod timeout;
+mod usage;
+mod user;
+pub(crate) mod warm;
+
+use self::cache::{
+    enforce_cache_limit, finalize_caches, record_cache_stats, setup_caches, setup_container_storage,
+};
+use self::container::{
+    build_container_config, check_devices, cwd_subdir, gpus_requested, join_session_network,
+    requested_devices, requested_platform, ContainerBuildParams,
+};
+use self::credentials::{
+    deliver_as_files, deploy_key_repos, gather_credentials, needs_aws_mfa_code,
+    spawn_github_app_refresh, GatheredCredentials,
+};
+use self::image::{apply_pull_policy, resolve_image, resolve_pull_policy};
+use self::prompts::{
+    credential_prompt_needed, is_default_network, prompt_aws_mfa_code, prompt_credential_selection,
+    prompt_network_selection, resolve_provider_selection,
+};
+use self::refresh::{CredentialRefresh, RefreshedProviders};
+use self::rollback::{rollback_and_audit, rollback_on_error, StartArtifacts};
+use self::template::TemplateVars;
+use self::usage::UsageSampler;
+use self::user::resolve_container_user;
 
 use crate::audit::AuditLog;
+use crate::cache::{CacheUseRecord, Ecosystem, RemoteCache};
 use crate::cli::args::RunArgs;
+use crate::config::schema::GithubConfig;
 use crate::config::Config;
+use crate::credentials::deploy_key::SessionDeployKey;
+use crate::credentials::files::{CredentialDelivery, SessionCredentialFiles, KUBECONFIG_FILE};
+use crate::env_passthrough;
 use crate::error::{MinoError, MinoResult};
+use crate::labels;
 use crate::network::{
     generate_iptables_wrapper, resolve_network_mode, shell_escape, NetworkMode,
     NetworkResolutionInput,
 };
-use crate::orchestration::{create_runtime, ContainerConfig, ContainerRuntime, Platform};
-use crate::session::{Session, SessionManager, SessionStatus};
+use crate::orchestration::{
+    create_runtime_with_backend, Backend, ContainerConfig, ContainerRuntime, ContainerUsage,
+    Platform, VmProvider,
+};
+use crate::session::{Session, SessionManager, SessionStatus, UsageLog};
 use crate::ui::{self, TaskSpinner, UiContext};
+use crate::worktree;
 use console::style;
 use std::collections::HashMap;
 use std::env;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
 use tracing::{debug, warn};
 use uuid::Uuid;
 
@@ -37,6 +80,15 @@ use uuid::Uuid;
 #[derive(Default)]
 struct CacheSession {
     volumes_to_finalize: Vec<String>,
+    /// Complete caches mounted read-write via `--cache-rw`
+    rw_overrides: Vec<String>,
+    /// Remote store caches are filled from
+    remote: Option<RemoteCache>,
+    /// Upload finalized caches to `remote`. Only `mino cache warm` sets
+    /// this: caches built by a session hold whatever the sandbox wrote.
+    upload: bool,
+    /// How each mounted cache was obtained, for `mino cache stats`
+    uses: Vec<CacheUseRecord>,
 }
 
 /// Result of resolving the image to use
@@ -45,20 +97,49 @@ struct ImageResolution {
     image: String,
     /// Extra env vars from layers (empty if using single image)
     layer_env: HashMap<String, String>,
+    /// Cache key extras of the layers, per ecosystem
+    cache_key_extra: HashMap<Ecosystem, String>,
+    /// `post_create` hooks of the layers, in layer order
+    post_create: Vec<String>,
+}
+
+/// Whether `session.command` stands in for a command not given on the CLI
+fn uses_default_command(args: &RunArgs, config: &Config) -> bool {
+    args.command.is_empty() && !config.session.command.is_empty()
 }
 
 /// Execute the run command
 pub async fn execute(mut args: RunArgs, config: &Config) -> MinoResult<()> {
     crate::cli::args::strip_separator(&mut args.command);
+    let default_command = uses_default_command(&args, config);
+    if args.timeout.is_some() && args.command.is_empty() && !default_command {
+        return Err(MinoError::User(
+            "--timeout needs a command: mino run --timeout 30m -- <command>".to_string(),
+        ));
+    }
+    if args.stdin && args.command.is_empty() && !default_command {
+        return Err(MinoError::User(
+            "--stdin needs a command: mino run --stdin -- <command>".to_string(),
+        ));
+    }
 
     // Dispatch to native sandbox if requested
-    let runtime_mode =
-        crate::sandbox::resolve_runtime_mode(args.runtime.as_deref(), &config.general.runtime)?;
+    let (runtime_flag, cli_backend) = if args.native {
+        (Some("native"), None)
+    } else {
+        split_runtime_flag(args.runtime.as_deref())
+    };
+    let runtime_mode = crate::sandbox::resolve_runtime_mode(runtime_flag, &config.general.runtime)?;
 
     if matches!(runtime_mode, crate::sandbox::RuntimeMode::Native) {
         return native::execute_native(args, config).await;
     }
 
+    // Placeholders are expanded once the session is named
+    if default_command {
+        args.command = config.session.command.clone();
+    }
+
     // Container mode (default) — fall through to existing logic
     #[cfg(unix)]
     let _terminal_guard = crate::terminal::TerminalGuard::save();
@@ -68,18 +149,56 @@ pub async fn execute(mut args: RunArgs, config: &Config) -> MinoResult<()> {
 
     spinner.start("Initializing sandbox...");
 
-    let runtime: Arc<dyn ContainerRuntime> = Arc::from(create_runtime(config)?);
+    let backend = match cli_backend {
+        Some(backend) => backend,
+        None => config.orchestration.backend.parse()?,
+    };
+    let k8s_config;
+    let config = match args.context.as_deref() {
+        Some(_) if backend != Backend::Kubernetes => {
+            return Err(MinoError::User(
+                "--context only applies to the k8s backend: mino run --runtime k8s --context <ctx>"
+                    .to_string(),
+            ));
+        }
+        Some(context) => {
+            k8s_config = {
+                let mut config = config.clone();
+                config.orchestration.kubernetes.context = context.to_string();
+                config
+            };
+            &k8s_config
+        }
+        None => config,
+    };
+    let runtime: Arc<dyn ContainerRuntime> =
+        Arc::from(create_runtime_with_backend(config, Some(backend))?);
     debug!("Using runtime: {}", runtime.runtime_name());
 
     spinner.message(&format!("Checking {}...", runtime.runtime_name()));
-    validate_environment().await?;
+    validate_environment(backend, config).await?;
 
     let project_dir = resolve_project_dir(&args)?;
     debug!("Project directory: {}", project_dir.display());
+    let extra_projects = resolve_extra_projects(&args, config, &project_dir)?;
+    for extra in &extra_projects {
+        debug!("Extra project: {}", extra.display());
+    }
 
     spinner.message(&format!("Starting {}...", runtime.runtime_name()));
     runtime.ensure_ready().await?;
 
+    // Pods get a copy of the project that is never synced back
+    if backend == Backend::Kubernetes {
+        spinner.clear();
+        ui::step_warn_hint(
+            &ctx,
+            "The project is copied into the pod; changes are lost when the session stops",
+            "Copy results out first: mino cp <session>:/workspace/<path> <dest>",
+        );
+        spinner.start("Initializing sandbox...");
+    }
+
     // Version checks (interactive only, silent on failure)
     if ctx.is_interactive() {
         let stale = crate::version::check_stale_images(&*runtime).await;
@@ -126,9 +245,36 @@ pub async fn execute(mut args: RunArgs, config: &Config) -> MinoResult<()> {
         }
     }
 
+    if gpus_requested(&args, config) {
+        spinner.message("Checking GPU support...");
+        runtime.check_gpu_support().await?;
+    }
+
+    let devices = requested_devices(&args, config);
+    if !devices.is_empty() {
+        spinner.message("Checking devices...");
+        check_devices(&*runtime, &devices).await?;
+    }
+
+    let pull_policy = resolve_pull_policy(&args, config)?;
+    let platform = requested_platform(&args, config)?;
     let (resolution, using_layers) =
         resolve_image(&args, config, &ctx, &mut spinner, &*runtime, &project_dir).await?;
 
+    spinner.message(&format!("Checking image {}...", resolution.image));
+    apply_pull_policy(
+        &*runtime,
+        &resolution.image,
+        pull_policy,
+        platform.as_deref(),
+        &ctx,
+        &mut spinner,
+    )
+    .await?;
+
+    spinner.message("Resolving container user...");
+    let container_user = resolve_container_user(&*runtime, config, &resolution.image).await?;
+
     let network_mode = if is_default_network(&args, config) && ctx.is_interactive() {
         spinner.clear();
         let mode = prompt_network_selection(&ctx, &project_dir).await?;
@@ -146,20 +292,67 @@ pub async fn execute(mut args: RunArgs, config: &Config) -> MinoResult<()> {
     };
     debug!("Network mode: {:?}", network_mode);
 
+    let propagation = propagate::gather_propagation(config).await?;
+    let mut git_config = gitconfig::session_git_config(&config.git).await;
+
     spinner.message("Setting up caches...");
-    let (cache_mounts, cache_env, cache_session) =
-        setup_caches(&*runtime, &args, config, &project_dir).await?;
+    let project_dirs: Vec<PathBuf> = std::iter::once(project_dir.clone())
+        .chain(extra_projects.iter().cloned())
+        .collect();
+    let (cache_mounts, cache_env, cache_session) = setup_caches(
+        &*runtime,
+        &args,
+        config,
+        &project_dirs,
+        &resolution.cache_key_extra,
+    )
+    .await?;
 
     if !args.no_cache && config.cache.enabled {
-        check_cache_size_warning(&*runtime, config).await;
+        let sizes = enforce_cache_limit(&*runtime, config, &cache_mounts, &cache_session).await;
+        record_cache_stats(&cache_session, sizes.as_ref()).await;
     }
+    let container_storage_mount =
+        setup_container_storage(&*runtime, &args, config, &project_dir, &container_user).await?;
 
     spinner.message("Setting up home volume...");
-    let home_mount =
-        home::setup_home_volume(&*runtime, &args, config, &project_dir, &resolution.image).await?;
+    let home_mount = home::setup_home_volume(
+        &*runtime,
+        &args,
+        config,
+        &project_dir,
+        &resolution.image,
+        &container_user.home,
+    )
+    .await?;
+
+    let selection = if credential_prompt_needed(&args, &ctx, config, &project_dir).await? {
+        spinner.clear();
+        let selection = prompt_credential_selection(&ctx, &args, config, &project_dir).await?;
+        spinner.start("Initializing sandbox...");
+        selection
+    } else {
+        resolve_provider_selection(&args, config, &project_dir).await
+    };
+
+    let aws_mfa_code = if selection.aws && needs_aws_mfa_code(config).await {
+        spinner.clear();
+        let code = prompt_aws_mfa_code(&ctx, config).await?;
+        spinner.start("Initializing sandbox...");
+        Some(code)
+    } else {
+        None
+    };
 
     spinner.message("Gathering credentials...");
-    let (credentials, active_providers, cred_failures) = gather_credentials(&args, config).await?;
+    let GatheredCredentials {
+        env: mut credentials,
+        git_config: mut credential_git_config,
+        providers: active_providers,
+        failures: cred_failures,
+        kubeconfig,
+        expiry: credential_expiry,
+    } = gather_credentials(&args, config, selection, aws_mfa_code.as_deref()).await?;
     if !cred_failures.is_empty() {
         spinner.stop("Credentials");
         for (provider, error) in &cred_failures {
@@ -178,66 +371,297 @@ pub async fn execute(mut args: RunArgs, config: &Config) -> MinoResult<()> {
         spinner.start("Initializing sandbox...");
     }
 
-    let session_name = args.name.clone().unwrap_or_else(generate_session_name);
     let manager = SessionManager::new().await?;
 
     if config.session.auto_cleanup_hours > 0 {
         let cleaned = manager.cleanup(config.session.auto_cleanup_hours).await?;
-        if cleaned > 0 {
-            debug!("Cleaned up {} old session(s)", cleaned);
+        if !cleaned.is_empty() {
+            debug!("Cleaned up {} old session(s)", cleaned.len());
         }
+        release_cleaned_sessions(config, &cleaned).await;
     }
 
+    let mut session_name = args.name.clone().unwrap_or_else(generate_session_name);
+    if manager.get(&session_name).await?.is_some() {
+        spinner.clear();
+        session_name = conflict::resolve_session_name(&ctx, &manager, config, session_name).await?;
+        spinner.start("Initializing sandbox...");
+    }
+
+    let delivery = config.credentials.delivery.parse::<CredentialDelivery>()?;
+    let deploy_key_repos = if args.deploy_key || config.credentials.deploy_key.enabled {
+        Some(deploy_key_repos(config, &project_dir).await?)
+    } else {
+        None
+    };
     let audit = AuditLog::new(config);
+    let mut artifacts = StartArtifacts::default();
+    // Until the session is recorded, `mino stop` can't find what this start
+    // created, so any failure rolls it back here
+    let prepared = async {
+        // Where the host cwd sits in the project, mirrored in a worktree too
+        let cwd_subdir = env::current_dir()
+            .ok()
+            .and_then(|cwd| cwd_subdir(&project_dir, &cwd));
+
+        let worktree = if args.worktree {
+            spinner.message("Creating worktree...");
+            let worktree = worktree::create(&project_dir, &session_name).await?;
+            artifacts.worktree = Some(worktree.path.clone());
+            Some(worktree)
+        } else {
+            None
+        };
+        // The tree mounted into the container and handed to hooks
+        let mount_dir = worktree.as_ref().map_or(&project_dir, |w| &w.project_dir);
+
+        let branch = template::current_branch(mount_dir).await;
+        if default_command {
+            let project = template::project_name(&project_dir);
+            let vars = TemplateVars {
+                project: &project,
+                branch: &branch,
+                session: &session_name,
+            };
+            args.command = args.command.iter().map(|arg| vars.expand(arg)).collect();
+        }
 
-    let mut container_config = build_container_config(&ContainerBuildParams {
-        args: &args,
-        config,
-        project_dir: &project_dir,
-        resolution: &resolution,
-        env_vars: credentials,
-        cache_mounts: &cache_mounts,
-        cache_env,
-        network_mode: &network_mode,
-        home_mount: home_mount.clone(),
-    })?;
+        let refresh = if args.refresh_credentials || config.credentials.refresh {
+            let providers = RefreshedProviders::from_active(&active_providers);
+            if providers.is_empty() {
+                warn!("No AWS, GCP or Azure credentials loaded; nothing to refresh");
+                None
+            } else {
+                spinner.message("Writing credential files...");
+                let refresh =
+                    CredentialRefresh::setup(&session_name, providers, config, &mut credentials)
+                        .await?;
+                artifacts.credential_files = Some(refresh.files.clone());
+                Some(refresh)
+            }
+        } else {
+            None
+        };
+
+        let mut credential_files = refresh.as_ref().map(|r| r.files.clone());
+        let file_delivery = delivery == CredentialDelivery::File && !active_providers.is_empty();
+        if file_delivery || kubeconfig.is_some() {
+            spinner.message("Writing credential files...");
+            let files = credential_files
+                .get_or_insert_with(|| SessionCredentialFiles::for_session(&session_name));
+            artifacts.credential_files = Some(files.clone());
+            files.create().await?;
+            if let Some(kubeconfig) = &kubeconfig {
+                files.write_token(KUBECONFIG_FILE, kubeconfig).await?;
+            }
+            if file_delivery {
+                deliver_as_files(files, &mut credentials, &mut credential_git_config, config)
+                    .await?;
+            }
+        }
+        git_config.merge(&credential_git_config);
+
+        let scratch_mount = scratch::setup_scratch_volume(
+            &ctx,
+            &*runtime,
+            &args,
+            config,
+            &session_name,
+            &mut artifacts,
+        )
+        .await?;
 
-    // Suppress bootstrap output for detached mode or non-shell commands
-    if args.detach || !args.command.is_empty() {
-        container_config
-            .env
-            .insert("MINO_QUIET_BOOTSTRAP".to_string(), "1".to_string());
-    }
+        let passthrough = env_passthrough::from_host(&config.container.env_passthrough);
+        passthrough.warn_denied("container.env_passthrough");
+
+        // The agent is started just before the session is recorded, so a
+        // failed start leaves as little as possible registered with GitHub
+        let deploy_key_agent = deploy_key_repos
+            .as_ref()
+            .map(|_| SessionDeployKey::socket_for(&session_name));
+        let mut container_config = build_container_config(&ContainerBuildParams {
+            args: &args,
+            config,
+            project_dir: mount_dir,
+            extra_projects: &extra_projects,
+            cwd_subdir: cwd_subdir.as_deref(),
+            resolution: &resolution,
+            env_vars: credentials,
+            git_config,
+            cache_mounts: &cache_mounts,
+            cache_env,
+            propagation: &propagation,
+            passthrough_env: passthrough.env.clone(),
+            network_mode: &network_mode,
+            user: &container_user,
+            home_mount: home_mount.clone(),
+            scratch_mount: scratch_mount.clone(),
+            container_storage_mount: container_storage_mount.clone(),
+            deploy_key_agent: deploy_key_agent.as_deref(),
+        })?;
+        container_config.labels = labels::resource_labels(Some(&session_name), Some(&project_dir));
+        if let Some(worktree) = &worktree {
+            container_config.volumes.push(worktree.git_dir_mount());
+        }
+        if let Some(files) = &credential_files {
+            container_config.volumes.push(files.mount());
+            if kubeconfig.is_some() {
+                let target = format!("{}/.kube/config", container_user.home);
+                container_config
+                    .volumes
+                    .push(files.file_mount(KUBECONFIG_FILE, &target));
+            }
+        }
+        let hostname = join_session_network(
+            &*runtime,
+            config,
+            &network_mode,
+            &session_name,
+            &mut container_config,
+        )
+        .await;
 
-    // Layers compose on mino-base which has Oh My Zsh configured
-    let shell_command = if args.command.is_empty() {
-        if using_layers {
-            vec!["/bin/zsh".to_string()]
-        } else {
-            vec![config.session.shell.clone()]
+        if !config.hooks.pre_run.is_empty() {
+            spinner.clear();
+            hooks::run_pre_hooks(
+                &config.hooks.pre_run,
+                &hooks::HookContext {
+                    session_name: &session_name,
+                    project_dir: mount_dir,
+                    branch: &branch,
+                    image: &container_config.image,
+                    exit_code: None,
+                },
+            )
+            .await?;
+            spinner.start("Initializing sandbox...");
+        }
+        if args.detach && !config.hooks.post_run.is_empty() {
+            warn!("post_run hooks are skipped for detached sessions");
         }
-    } else {
-        args.command.clone()
-    };
 
-    let command = if let NetworkMode::Allow(ref rules) = network_mode {
-        generate_iptables_wrapper(rules, &shell_command)
-    } else {
-        shell_command.clone()
-    };
+        if !config.services.is_empty() {
+            spinner.message("Starting services...");
+        }
+        let services =
+            services::start_services(&*runtime, config, &session_name, &mut container_config)
+                .await?;
+        artifacts.pod = services.as_ref().map(|s| s.pod.clone());
+
+        // Suppress bootstrap output for detached mode or non-shell commands
+        if args.detach || !args.command.is_empty() {
+            container_config
+                .env
+                .insert("MINO_QUIET_BOOTSTRAP".to_string(), "1".to_string());
+        }
 
-    let is_shell_mode = args.command.is_empty();
+        // Layers compose on mino-base, whose developer user has Oh My Zsh configured
+        let shell_command = if args.command.is_empty() {
+            if using_layers || container_user.is_mino_user() {
+                vec!["/bin/zsh".to_string()]
+            } else {
+                vec![config.session.shell.clone()]
+            }
+        } else {
+            args.command.clone()
+        };
 
-    let mut session = Session::new(
-        session_name.clone(),
-        project_dir.clone(),
-        command.clone(),
-        SessionStatus::Starting,
-    );
-    session.home_volume = home_mount
-        .as_ref()
-        .map(|m| m.split(':').next().unwrap_or_default().to_string());
-    manager.create(&session).await?;
+        // Layer hooks first, then the project's own
+        let post_create: Vec<String> = resolution
+            .post_create
+            .iter()
+            .chain(&config.hooks.post_create)
+            .cloned()
+            .collect();
+        let hooked_command = hooks::post_create_wrapper(&post_create, &shell_command);
+        let command = if let NetworkMode::Allow(ref rules) = network_mode {
+            generate_iptables_wrapper(rules, &hooked_command)
+        } else {
+            hooked_command
+        };
+
+        let is_shell_mode = args.command.is_empty();
+
+        let deploy_key = match &deploy_key_repos {
+            Some(repos) => {
+                spinner.message("Adding deploy key...");
+                let deploy_key = SessionDeployKey::create(
+                    &session_name,
+                    &config.credentials.github.host,
+                    repos,
+                    config.credentials.deploy_key.read_only,
+                )
+                .await?;
+                artifacts.deploy_key = Some(deploy_key.clone());
+                Some(deploy_key)
+            }
+            None => None,
+        };
+
+        let mut session = Session::new(
+            session_name.clone(),
+            project_dir.clone(),
+            command.clone(),
+            SessionStatus::Starting,
+        );
+        session.home_volume = home_mount
+            .as_ref()
+            .map(|m| m.split(':').next().unwrap_or_default().to_string());
+        session.scratch_volume = scratch_mount
+            .as_ref()
+            .map(|m| m.split(':').next().unwrap_or_default().to_string());
+        session.cache_volumes = cache_mounts
+            .iter()
+            .map(|m| m.volume_name.clone())
+            .chain(
+                container_storage_mount
+                    .as_ref()
+                    .map(|m| m.split(':').next().unwrap_or_default().to_string()),
+            )
+            .collect();
+        session.backend = Some(backend);
+        session.hostname = hostname;
+        session.restart_policy = container_config.restart.clone();
+        session.worktree = worktree.as_ref().map(|w| w.path.clone());
+        session.deploy_key = deploy_key.clone();
+        session.credential_expiry = credential_expiry;
+        if let Some(services) = services {
+            session.pod = Some(services.pod);
+            session.services = services.containers;
+        }
+        manager.create(&session).await?;
+
+        Ok::<_, MinoError>(PreparedStart {
+            worktree,
+            branch,
+            refresh,
+            credential_files,
+            passthrough,
+            container_config,
+            command,
+            shell_command,
+            post_create,
+            is_shell_mode,
+            deploy_key,
+            session,
+        })
+    }
+    .await;
+    let PreparedStart {
+        worktree,
+        branch,
+        refresh,
+        credential_files,
+        passthrough,
+        container_config,
+        command,
+        shell_command,
+        post_create,
+        is_shell_mode,
+        deploy_key,
+        session,
+    } = rollback_on_error(&*runtime, &audit, &session_name, &artifacts, prepared).await?;
+    let mount_dir = worktree.as_ref().map_or(&project_dir, |w| &w.project_dir);
 
     audit
         .log(
@@ -245,13 +669,25 @@ pub async fn execute(mut args: RunArgs, config: &Config) -> MinoResult<()> {
             &serde_json::json!({
                 "name": &session_name,
                 "project_dir": project_dir.display().to_string(),
+                "extra_projects": extra_projects
+                    .iter()
+                    .map(|p| p.display().to_string())
+                    .collect::<Vec<_>>(),
                 "image": &container_config.image,
+                "user": &container_user.name,
                 "command": &command,
                 "network": format!("{:?}", network_mode),
                 "home_volume": session.home_volume,
+                "scratch_volume": session.scratch_volume,
+                "hostname": session.hostname,
+                "restart_policy": session.restart_policy,
+                "pod": session.pod,
+                "services": session.services,
+                "worktree": session.worktree,
             }),
         )
         .await;
+    passthrough.audit(&audit, &session_name).await;
 
     if !active_providers.is_empty() {
         audit
@@ -260,33 +696,64 @@ pub async fn execute(mut args: RunArgs, config: &Config) -> MinoResult<()> {
                 &serde_json::json!({
                     "session_name": &session_name,
                     "providers": &active_providers,
+                    "refreshed": refresh.as_ref().map(|r| r.providers.names()),
+                    "delivery": delivery.to_string(),
                 }),
             )
             .await;
     }
 
-    if !runtime
-        .image_exists(&container_config.image)
-        .await
-        .unwrap_or(false)
-    {
-        spinner.message(&format!("Pulling image {}...", container_config.image));
-    } else {
-        spinner.message("Starting container...");
+    if let Some(deploy_key) = &deploy_key {
+        audit
+            .log(
+                "credentials.deploy_key",
+                &serde_json::json!({
+                    "session_name": &session_name,
+                    "repos": deploy_key.repos(),
+                    "key_ids": deploy_key.keys.iter().map(|k| k.id).collect::<Vec<_>>(),
+                    "read_only": config.credentials.deploy_key.read_only,
+                }),
+            )
+            .await;
     }
 
+    if !cache_session.rw_overrides.is_empty() {
+        audit
+            .log(
+                "cache.rw_override",
+                &serde_json::json!({
+                    "session_name": &session_name,
+                    "volumes": &cache_session.rw_overrides,
+                }),
+            )
+            .await;
+    }
+
+    spinner.message("Starting container...");
+
     let mut run_ctx = RunContext {
         runtime: &runtime,
         container_config: &container_config,
         command: &command,
         session_name: &session_name,
+        project_dir: mount_dir,
+        branch: &branch,
         manager: &manager,
         audit: &audit,
         spinner: &mut spinner,
         config,
         is_shell_mode,
         shell_command,
+        post_create: &post_create,
         network_mode: &network_mode,
+        timeout: args.timeout,
+        heartbeat: timeout::heartbeat_interval(args.heartbeat, ctx.is_interactive()),
+        artifacts: &artifacts,
+        github_app: (config.credentials.github.app_mode()
+            && active_providers.iter().any(|p| p == "github"))
+        .then_some(&config.credentials.github),
+        refresh: refresh.as_ref(),
+        credential_files: credential_files.as_ref(),
     };
 
     if args.detach {
@@ -298,11 +765,30 @@ pub async fn execute(mut args: RunArgs, config: &Config) -> MinoResult<()> {
     Ok(())
 }
 
+/// What `execute` prepared before recording the session
+struct PreparedStart {
+    worktree: Option<worktree::Worktree>,
+    branch: String,
+    refresh: Option<CredentialRefresh>,
+    credential_files: Option<SessionCredentialFiles>,
+    passthrough: env_passthrough::Passthrough,
+    container_config: ContainerConfig,
+    command: Vec<String>,
+    shell_command: Vec<String>,
+    post_create: Vec<String>,
+    is_shell_mode: bool,
+    deploy_key: Option<SessionDeployKey>,
+    session: Session,
+}
+
 struct RunContext<'a> {
     runtime: &'a Arc<dyn ContainerRuntime>,
     container_config: &'a ContainerConfig,
     command: &'a [String],
     session_name: &'a str,
+    project_dir: &'a Path,
+    /// Project's git branch for `{branch}` in post_run hooks
+    branch: &'a str,
     manager: &'a SessionManager,
     audit: &'a AuditLog,
     spinner: &'a mut TaskSpinner,
@@ -311,12 +797,27 @@ struct RunContext<'a> {
     is_shell_mode: bool,
     /// The bare shell command for exec phase (e.g. ["/bin/zsh"])
     shell_command: Vec<String>,
+    /// In-container hooks run before the shell is exec'd (two-phase startup)
+    post_create: &'a [String],
     /// Resolved network mode (needed by two-phase startup for iptables wrapping)
     network_mode: &'a NetworkMode,
+    /// Kill an explicit command after this long (`--timeout`)
+    timeout: Option<Duration>,
+    /// Interval for "still running" lines during explicit command runs
+    heartbeat: Option<Duration>,
+    /// Session-scoped resources to roll back if the container fails to start
+    artifacts: &'a StartArtifacts,
+    /// GitHub App whose installation token is refreshed while attached
+    github_app: Option<&'a GithubConfig>,
+    /// Credential files kept fresh while mino runs (`--refresh-credentials`)
+    refresh: Option<&'a CredentialRefresh>,
+    /// Session's credential files, removed when an attached session ends
+    credential_files: Option<&'a SessionCredentialFiles>,
 }
 
 impl RunContext<'_> {
-    /// Record a container creation failure in session state and audit log, then return the error.
+    /// Record a container creation failure in session state and audit log,
+    /// roll back what the start created, then return the error.
     async fn record_failure<T>(&self, error: MinoError) -> MinoResult<T> {
         self.manager
             .update_status(self.session_name, SessionStatus::Failed)
@@ -330,9 +831,43 @@ impl RunContext<'_> {
                 }),
             )
             .await;
+
+        rollback_and_audit(
+            &**self.runtime,
+            self.audit,
+            self.session_name,
+            self.artifacts,
+        )
+        .await;
         Err(error)
     }
 
+    /// Start refreshing the GitHub App token inside the container, if the
+    /// session got one
+    fn start_github_refresh(&self, container_id: &str) -> Option<JoinHandle<()>> {
+        self.github_app.map(|github| {
+            spawn_github_app_refresh(
+                Arc::clone(self.runtime),
+                container_id.to_string(),
+                self.session_name.to_string(),
+                github.clone(),
+            )
+        })
+    }
+
+    /// Start the refresh tasks that run while mino waits on the container
+    fn start_refresh(&self, container_id: &str) -> RefreshTasks {
+        RefreshTasks(
+            [
+                self.start_github_refresh(container_id),
+                self.refresh.map(CredentialRefresh::spawn),
+            ]
+            .into_iter()
+            .flatten()
+            .collect(),
+        )
+    }
+
     /// Record a successful container start in session state and audit log.
     async fn record_start(&self, container_id: &str) -> MinoResult<()> {
         self.manager
@@ -354,6 +889,17 @@ impl RunContext<'_> {
     }
 }
 
+/// Background tasks refreshing credentials inside a running container
+struct RefreshTasks(Vec<JoinHandle<()>>);
+
+impl RefreshTasks {
+    fn stop(self) {
+        for task in self.0 {
+            task.abort();
+        }
+    }
+}
+
 /// Run container in detached mode with background cache finalization.
 async fn run_detached(ctx: &mut RunContext<'_>, cache_session: CacheSession) -> MinoResult<()> {
     let container_id = match ctx.runtime.run(ctx.container_config, ctx.command).await {
@@ -387,7 +933,7 @@ async fn run_detached(ctx: &mut RunContext<'_>, cache_session: CacheSession) ->
             match bg_runtime.get_container_exit_code(&bg_container_id).await {
                 Ok(Some(0)) => {
                     debug!("Container {} exited cleanly, finalizing caches", short_id);
-                    finalize_caches(&bg_cache_session).await;
+                    finalize_caches(&*bg_runtime, &bg_cache_session).await;
                 }
                 Ok(Some(code)) => {
                     debug!(
@@ -411,29 +957,71 @@ async fn run_detached(ctx: &mut RunContext<'_>, cache_session: CacheSession) ->
         });
     }
 
+    // Refreshing needs mino to outlive the start, so stay until the container exits
+    if ctx.refresh.is_some() {
+        println!(
+            "  Refreshing credentials until the session exits. Stopping mino stops the refresh."
+        );
+        let tasks = ctx.start_refresh(&container_id);
+        if let Err(e) = ctx.runtime.get_container_exit_code(&container_id).await {
+            warn!("Failed to wait for session {}: {}", ctx.session_name, e);
+        }
+        tasks.stop();
+        if let Some(files) = ctx.credential_files {
+            remove_credential_files(files).await;
+        }
+    }
+
     Ok(())
 }
 
+/// Remove a session's credential files once nothing reads them
+async fn remove_credential_files(files: &SessionCredentialFiles) {
+    if let Err(e) = files.remove().await {
+        warn!("Failed to remove credential files: {}", e);
+    }
+}
+
 /// Run container in interactive mode with synchronous cache finalization.
 ///
 /// Routes to either `run_interactive_shell` (two-phase: sleep + exec) for bare
 /// shell mode, or the existing `start_attached` flow for explicit commands.
 async fn run_interactive(ctx: &mut RunContext<'_>, cache_session: CacheSession) -> MinoResult<()> {
-    let exit_code = if ctx.is_shell_mode {
-        run_interactive_shell(ctx).await?
+    let (exit_code, timed_out, usage) = if ctx.is_shell_mode {
+        let (exit_code, usage) = run_interactive_shell(ctx).await?;
+        (exit_code, false, usage)
     } else {
-        run_interactive_command(ctx).await?
+        let (exit, usage) = run_interactive_command(ctx).await?;
+        (exit.exit_code, exit.timed_out, usage)
     };
 
     // Finalize caches on clean exit
     if exit_code == 0 && !cache_session.volumes_to_finalize.is_empty() {
-        finalize_caches(&cache_session).await;
+        finalize_caches(&**ctx.runtime, &cache_session).await;
+    }
+
+    if let Some(files) = ctx.credential_files {
+        remove_credential_files(files).await;
+    }
+    if let Some(deploy_key) = &ctx.artifacts.deploy_key {
+        if let Err(e) = deploy_key.remove().await {
+            warn!("{}", e);
+        }
     }
 
     // Clean up session state
     ctx.manager
         .update_status(ctx.session_name, SessionStatus::Stopped)
         .await?;
+    let session = ctx.manager.get(ctx.session_name).await?;
+    if let Some(session) = &session {
+        if ctx.artifacts.pod.is_some() {
+            SessionManager::remove_services(session, &**ctx.runtime).await;
+        }
+        UsageLog::new(ctx.config)
+            .record(session, usage.as_ref())
+            .await;
+    }
 
     ctx.audit
         .log(
@@ -441,11 +1029,12 @@ async fn run_interactive(ctx: &mut RunContext<'_>, cache_session: CacheSession)
             &serde_json::json!({
                 "name": ctx.session_name,
                 "exit_code": exit_code,
+                "timed_out": timed_out,
             }),
         )
         .await;
 
-    if exit_code != 0 {
+    if exit_code != 0 && !timed_out {
         println!(
             "{} Session exited with code {}",
             style("!").yellow(),
@@ -453,6 +1042,23 @@ async fn run_interactive(ctx: &mut RunContext<'_>, cache_session: CacheSession)
         );
     }
 
+    hooks::run_post_hooks(
+        &ctx.config.hooks.post_run,
+        &hooks::HookContext {
+            session_name: ctx.session_name,
+            project_dir: ctx.project_dir,
+            branch: ctx.branch,
+            image: &ctx.container_config.image,
+            exit_code: Some(exit_code),
+        },
+    )
+    .await;
+
+    // After post_run hooks, which may commit or push from the worktree
+    if let Some(session) = &session {
+        crate::cli::commands::stop::release_worktree(&UiContext::detect(), session).await;
+    }
+
     // Show update notification on exit (reads cached state from disk, picks up
     // any background refresh that completed during this session)
     if let Some(update) = crate::version::load_cached_update(ctx.config).await {
@@ -467,6 +1073,12 @@ async fn run_interactive(ctx: &mut RunContext<'_>, cache_session: CacheSession)
         );
     }
 
+    if timed_out {
+        return Err(MinoError::User(format!(
+            "Command timed out after {}",
+            timeout::format_duration(ctx.timeout.unwrap_or_default())
+        )));
+    }
     Ok(())
 }
 
@@ -475,7 +1087,11 @@ async fn run_interactive(ctx: &mut RunContext<'_>, cache_session: CacheSession)
 /// Non-interactive commands like `mino run -- cargo build` need the entrypoint's
 /// env setup (nvm, cargo sourcing), so they use `start_attached` which runs the
 /// full entrypoint.
-async fn run_interactive_command(ctx: &mut RunContext<'_>) -> MinoResult<i32> {
+///
+/// Returns the exit and the container's usage as last sampled while it ran.
+async fn run_interactive_command(
+    ctx: &mut RunContext<'_>,
+) -> MinoResult<(timeout::AttachedExit, Option<ContainerUsage>)> {
     let container_id = match ctx.runtime.create(ctx.container_config, ctx.command).await {
         Ok(id) => id,
         Err(e) => return ctx.record_failure(e).await,
@@ -485,7 +1101,19 @@ async fn run_interactive_command(ctx: &mut RunContext<'_>) -> MinoResult<i32> {
     ctx.spinner.clear();
 
     debug!("Starting container attached: {}", &container_id[..12]);
-    let exit_code = ctx.runtime.start_attached(&container_id).await?;
+    let refresh = ctx.start_refresh(&container_id);
+    let sampler = UsageSampler::spawn(Arc::clone(ctx.runtime), &container_id);
+    let exit = timeout::start_attached_supervised(
+        &**ctx.runtime,
+        &container_id,
+        ctx.session_name,
+        ctx.timeout,
+        ctx.heartbeat,
+    )
+    .await;
+    refresh.stop();
+    let usage = sampler.finish().await;
+    let exit = exit?;
 
     // Remove container (start_attached returns after it exits)
     if let Err(e) = ctx.runtime.remove(&container_id).await {
@@ -496,7 +1124,7 @@ async fn run_interactive_command(ctx: &mut RunContext<'_>) -> MinoResult<i32> {
         );
     }
 
-    Ok(exit_code)
+    Ok((exit, usage))
 }
 
 /// Two-phase shell startup: create with sleep infinity, bootstrap via spinner,
@@ -506,9 +1134,30 @@ async fn run_interactive_command(ctx: &mut RunContext<'_>) -> MinoResult<i32> {
 /// Instead, bootstrap output goes to a log file inside the container, and we
 /// show a spinner while monitoring `podman logs -f` for the "Bootstrap complete."
 /// marker.
-async fn run_interactive_shell(ctx: &mut RunContext<'_>) -> MinoResult<i32> {
-    // Phase 1: Create container with sleep infinity
-    let sleep_command = vec!["sleep".to_string(), "infinity".to_string()];
+///
+/// Returns the shell's exit code and the container's usage, sampled before
+/// the container is stopped.
+async fn run_interactive_shell(
+    ctx: &mut RunContext<'_>,
+) -> MinoResult<(i32, Option<ContainerUsage>)> {
+    // Phase 1: Create container with sleep infinity. With post_create hooks,
+    // wait for their completion marker instead of the bootstrap's.
+    let (sleep_command, ready_marker) = if ctx.post_create.is_empty() {
+        (
+            vec!["sleep".to_string(), "infinity".to_string()],
+            "Bootstrap complete.",
+        )
+    } else {
+        let sleep = vec![
+            "/bin/sh".to_string(),
+            "-c".to_string(),
+            format!("echo '{}'; exec sleep infinity", hooks::POST_CREATE_DONE),
+        ];
+        (
+            hooks::post_create_wrapper(ctx.post_create, &sleep),
+            hooks::POST_CREATE_DONE,
+        )
+    };
     let phase1_command = if let NetworkMode::Allow(ref rules) = ctx.network_mode {
         generate_iptables_wrapper(rules, &sleep_command)
     } else {
@@ -540,7 +1189,7 @@ async fn run_interactive_shell(ctx: &mut RunContext<'_>) -> MinoResult<i32> {
         .runtime
         .logs_follow_until(
             &container_id,
-            "Bootstrap complete.",
+            ready_marker,
             bootstrap_timeout,
             &|line: String| {
                 debug!("bootstrap: {}", line);
@@ -581,10 +1230,15 @@ async fn run_interactive_shell(ctx: &mut RunContext<'_>) -> MinoResult<i32> {
         &container_id[..12],
         exec_command
     );
+    let refresh = ctx.start_refresh(&container_id);
+    let sampler = UsageSampler::spawn(Arc::clone(ctx.runtime), &container_id);
     let exit_code = ctx
         .runtime
         .exec_in_container(&container_id, &exec_command, true)
-        .await?;
+        .await;
+    refresh.stop();
+    let usage = sampler.finish().await;
+    let exit_code = exit_code?;
 
     // Stop the sleep infinity process
     if let Err(e) = ctx.runtime.stop(&container_id).await {
@@ -600,11 +1254,52 @@ async fn run_interactive_shell(ctx: &mut RunContext<'_>) -> MinoResult<i32> {
         );
     }
 
-    Ok(exit_code)
+    Ok((exit_code, usage))
 }
 
-async fn validate_environment() -> MinoResult<()> {
+/// Release the credentials of expired sessions and, with
+/// `session.cleanup_resources`, their leftover containers and scratch volumes.
+///
+/// Each session is released on the backend it was started with.
+async fn release_cleaned_sessions(config: &Config, sessions: &[Session]) {
+    for session in sessions {
+        crate::cli::commands::stop::release_session_credentials(session).await;
+        if !config.session.cleanup_resources
+            || session.runtime_mode == Some(crate::sandbox::RuntimeMode::Native)
+        {
+            continue;
+        }
+        match create_runtime_with_backend(config, session.backend) {
+            Ok(runtime) => {
+                crate::cli::commands::stop::remove_session_resources(session, &*runtime).await
+            }
+            Err(e) => warn!(
+                "Cannot release resources of session {}: {}",
+                session.name, e
+            ),
+        }
+    }
+}
+
+/// Split `--runtime`: a backend name (`docker`, `podman`) selects container
+/// mode on that backend; anything else is a runtime mode.
+fn split_runtime_flag(flag: Option<&str>) -> (Option<&str>, Option<Backend>) {
+    match flag.map(str::parse::<Backend>) {
+        Some(Ok(backend)) => (Some("container"), Some(backend)),
+        _ => (flag, None),
+    }
+}
+
+async fn validate_environment(backend: Backend, config: &Config) -> MinoResult<()> {
     match Platform::detect() {
+        // Docker Desktop, remote hosts and clusters need no local VM; checked in ensure_ready()
+        Platform::MacOS if backend != Backend::Podman => {}
+        // The Lima VM is created and started on demand in ensure_ready()
+        Platform::MacOS if config.vm.provider.parse::<VmProvider>()? == VmProvider::Lima => {
+            if !crate::orchestration::Lima::is_installed().await {
+                return Err(MinoError::LimaNotFound);
+            }
+        }
         Platform::MacOS => {
             use crate::orchestration::OrbStack;
             if !OrbStack::is_installed().await {
@@ -625,7 +1320,7 @@ async fn validate_environment() -> MinoResult<()> {
 }
 
 fn resolve_project_dir(args: &RunArgs) -> MinoResult<PathBuf> {
-    if let Some(ref path) = args.project {
+    if let Some(path) = args.project.first() {
         let canonical = path
             .canonicalize()
             .map_err(|e| MinoError::io(format!("resolving project path {}", path.display()), e))?;
@@ -635,45 +1330,161 @@ fn resolve_project_dir(args: &RunArgs) -> MinoResult<PathBuf> {
     env::current_dir().map_err(|e| MinoError::io("getting current directory", e))
 }
 
+/// Resolve additional projects from repeated `--project` flags and
+/// `container.extra_projects`, canonicalized and deduplicated.
+///
+/// Relative config paths resolve against the main project directory.
+fn resolve_extra_projects(
+    args: &RunArgs,
+    config: &Config,
+    project_dir: &Path,
+) -> MinoResult<Vec<PathBuf>> {
+    let candidates = args.project.iter().skip(1).cloned().chain(
+        config
+            .container
+            .extra_projects
+            .iter()
+            .map(|p| project_dir.join(p)),
+    );
+
+    let mut extras: Vec<PathBuf> = Vec::new();
+    for path in candidates {
+        let canonical = path
+            .canonicalize()
+            .map_err(|e| MinoError::io(format!("resolving project path {}", path.display()), e))?;
+        if !canonical.is_dir() {
+            return Err(MinoError::PathNotFound(canonical));
+        }
+        if canonical != project_dir && !extras.contains(&canonical) {
+            extras.push(canonical);
+        }
+    }
+    Ok(extras)
+}
+
 pub(crate) fn generate_session_name() -> String {
     let short_id = &Uuid::new_v4().to_string()[..8];
     format!("session-{}", short_id)
 }
 
+/// `RunArgs` with every flag unset, for tests
+#[cfg(test)]
+pub(super) fn test_run_args() -> RunArgs {
+    RunArgs {
+        name: None,
+        project: vec![],
+        aws: false,
+        gcp: false,
+        azure: false,
+        all_clouds: false,
+        no_ssh_agent: false,
+        no_github: false,
+        gitlab: false,
+        bitbucket: false,
+        kube: false,
+        deploy_key: false,
+        strict_credentials: false,
+        refresh_credentials: false,
+        image: None,
+        layers: vec![],
+        layer_args: vec![],
+        allow_layer_conflicts: false,
+        env: vec![],
+        volume: vec![],
+        workdir: None,
+        detach: false,
+        timeout: None,
+        heartbeat: None,
+        read_only: false,
+        no_cache: false,
+        no_home: false,
+        cache_fresh: false,
+        cache_rw: false,
+        scratch: false,
+        worktree: false,
+        cpus: None,
+        memory: None,
+        pids_limit: None,
+        ulimit: vec![],
+        gpus: false,
+        userns: None,
+        stdin: false,
+        device: vec![],
+        tmpfs: vec![],
+        restart: None,
+        platform: None,
+        pull: None,
+        network: None,
+        network_allow: vec![],
+        network_preset: None,
+        runtime: None,
+        native: false,
+        allow_degraded: false,
+        context: None,
+        command: vec![],
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use self::credentials::ProviderSelection;
     use self::image::*;
-    use self::prompts::{is_default_network, upsert_container_toml_key, BASE_ONLY};
+    use self::prompts::{
+        is_default_network, project_chose_credentials, save_credential_selection,
+        upsert_container_toml_key, CredentialSelections, BASE_ONLY,
+    };
     use super::*;
-    use crate::orchestration::mock::{test_container_config, MockRuntime};
+    use crate::config::schema::ImageAlias;
+    use crate::orchestration::mock::{test_container_config, MockRuntime, RecordingExecutor};
+    use crate::orchestration::NativePodmanRuntime;
     use serial_test::serial;
 
-    fn test_run_args() -> RunArgs {
-        RunArgs {
-            name: None,
-            project: None,
-            aws: false,
-            gcp: false,
-            azure: false,
-            all_clouds: false,
-            no_ssh_agent: false,
-            no_github: false,
-            strict_credentials: false,
-            image: None,
-            layers: vec![],
-            env: vec![],
-            volume: vec![],
-            detach: false,
-            read_only: false,
-            no_cache: false,
-            no_home: false,
-            cache_fresh: false,
-            network: None,
-            network_allow: vec![],
-            network_preset: None,
-            runtime: None,
-            command: vec![],
+    #[test]
+    fn resolve_extra_projects_merges_cli_and_config() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let main = tmp.path().join("service");
+        let lib = tmp.path().join("shared-lib");
+        let tools = tmp.path().join("tools");
+        for dir in [&main, &lib, &tools] {
+            std::fs::create_dir_all(dir).unwrap();
         }
+        let main = main.canonicalize().unwrap();
+
+        let mut args = test_run_args();
+        args.project = vec![main.clone(), lib.clone(), main.clone()];
+        let mut config = Config::default();
+        config.container.extra_projects = vec!["../tools".to_string(), "../shared-lib".to_string()];
+
+        let extras = resolve_extra_projects(&args, &config, &main).unwrap();
+        assert_eq!(
+            extras,
+            vec![lib.canonicalize().unwrap(), tools.canonicalize().unwrap()]
+        );
+    }
+
+    #[test]
+    fn resolve_extra_projects_missing_path_errors() {
+        let mut args = test_run_args();
+        args.project = vec![PathBuf::from("/tmp"), PathBuf::from("/nonexistent/abc123")];
+        assert!(resolve_extra_projects(&args, &Config::default(), Path::new("/tmp")).is_err());
+    }
+
+    #[test]
+    fn split_runtime_flag_backends_imply_container() {
+        assert_eq!(
+            split_runtime_flag(Some("docker")),
+            (Some("container"), Some(Backend::Docker))
+        );
+        assert_eq!(
+            split_runtime_flag(Some("podman")),
+            (Some("container"), Some(Backend::Podman))
+        );
+        assert_eq!(
+            split_runtime_flag(Some("k8s")),
+            (Some("container"), Some(Backend::Kubernetes))
+        );
+        assert_eq!(split_runtime_flag(Some("native")), (Some("native"), None));
+        assert_eq!(split_runtime_flag(None), (None, None));
     }
 
     #[test]
@@ -693,6 +1504,7 @@ mod tests {
     fn image_alias_to_layer_python() {
         assert_eq!(image_alias_to_layer("python"), Some("python"));
         assert_eq!(image_alias_to_layer("py"), Some("python"));
+        assert_eq!(image_alias_to_layer("java"), Some("jvm"));
     }
 
     #[test]
@@ -705,7 +1517,7 @@ mod tests {
     #[test]
     fn resolve_image_alias_base() {
         assert_eq!(
-            resolve_image_alias("base"),
+            resolve_image_alias("base", &HashMap::new()),
             "ghcr.io/dean0x/mino-base:latest"
         );
     }
@@ -713,19 +1525,78 @@ mod tests {
     #[test]
     fn resolve_image_alias_passthrough_full_path() {
         assert_eq!(
-            resolve_image_alias("ghcr.io/custom/image:v1"),
+            resolve_image_alias("ghcr.io/custom/image:v1", &HashMap::new()),
             "ghcr.io/custom/image:v1"
         );
         assert_eq!(
-            resolve_image_alias("docker.io/library/fedora:43"),
+            resolve_image_alias("docker.io/library/fedora:43", &HashMap::new()),
             "docker.io/library/fedora:43"
         );
     }
 
     #[test]
     fn resolve_image_alias_passthrough_local() {
-        assert_eq!(resolve_image_alias("my-local-image"), "my-local-image");
-        assert_eq!(resolve_image_alias("fedora"), "fedora");
+        assert_eq!(
+            resolve_image_alias("my-local-image", &HashMap::new()),
+            "my-local-image"
+        );
+        assert_eq!(resolve_image_alias("fedora", &HashMap::new()), "fedora");
+    }
+
+    fn test_aliases() -> HashMap<String, ImageAlias> {
+        HashMap::from([
+            (
+                "corp-python".to_string(),
+                ImageAlias::Image("registry.corp.com/dev/python:3.12".to_string()),
+            ),
+            (
+                "corp-stack".to_string(),
+                ImageAlias::Layers(vec!["python".to_string(), "rust".to_string()]),
+            ),
+            (
+                "python".to_string(),
+                ImageAlias::Image("registry.corp.com/python:latest".to_string()),
+            ),
+        ])
+    }
+
+    #[test]
+    fn resolve_image_alias_configured_reference() {
+        let aliases = test_aliases();
+        assert_eq!(
+            resolve_image_alias("corp-python", &aliases),
+            "registry.corp.com/dev/python:3.12"
+        );
+        assert_eq!(resolve_image_alias("corp-stack", &aliases), "corp-stack");
+        assert_eq!(
+            resolve_image_alias("base", &aliases),
+            "ghcr.io/dean0x/mino-base:latest"
+        );
+    }
+
+    #[test]
+    fn alias_layers_configured_and_builtin() {
+        let aliases = test_aliases();
+        assert_eq!(
+            alias_layers("corp-stack", &aliases),
+            Some(vec!["python".to_string(), "rust".to_string()])
+        );
+        assert_eq!(alias_layers("corp-python", &aliases), None);
+        assert_eq!(
+            alias_layers("rust", &aliases),
+            Some(vec!["rust".to_string()])
+        );
+        assert_eq!(alias_layers("fedora", &aliases), None);
+    }
+
+    #[test]
+    fn configured_alias_overrides_builtin_layer_alias() {
+        let aliases = test_aliases();
+        assert_eq!(alias_layers("python", &aliases), None);
+        assert_eq!(
+            resolve_final_image("python", false, &aliases).image,
+            "registry.corp.com/python:latest"
+        );
     }
 
     #[test]
@@ -899,6 +1770,113 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn save_credential_selection_stays_out_of_project_config() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let project = temp.path().join("project");
+        tokio::fs::create_dir(&project).await.unwrap();
+        let local = project.join(".mino.toml");
+        tokio::fs::write(&local, "# project\n[container]\nimage = \"base\"\n")
+            .await
+            .unwrap();
+        let store = temp.path().join("credential_selections.json");
+
+        let selection = ProviderSelection {
+            aws: true,
+            gcp: false,
+            azure: false,
+            github: true,
+            gitlab: false,
+            bitbucket: true,
+            kubernetes: false,
+        };
+        save_credential_selection(&store, &project, selection)
+            .await
+            .unwrap();
+
+        let content = tokio::fs::read_to_string(&local).await.unwrap();
+        assert_eq!(content, "# project\n[container]\nimage = \"base\"\n");
+        let saved = CredentialSelections::load(&store).await;
+        assert_eq!(saved.get(&project), Some(selection));
+        assert_eq!(saved.get(temp.path()), None);
+        assert!(
+            project_chose_credentials(&Config::default(), &store, &project)
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn declined_local_credentials_do_not_skip_the_prompt() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let local = temp.path().join(".mino.toml");
+        tokio::fs::write(&local, "[credentials.aws]\nenabled = false\n")
+            .await
+            .unwrap();
+        let store = temp.path().join("credential_selections.json");
+
+        // Trust declined: load_merged never saw the local config
+        let declined = Config::default();
+        assert!(!project_chose_credentials(&declined, &store, temp.path())
+            .await
+            .unwrap());
+
+        let trusted = Config {
+            local_path: Some(local),
+            ..Config::default()
+        };
+        assert!(project_chose_credentials(&trusted, &store, temp.path())
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn credential_prompt_skipped_when_non_interactive_or_flagged() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let ctx = UiContext::non_interactive();
+        let args = test_run_args();
+        assert!(
+            !credential_prompt_needed(&args, &ctx, &Config::default(), temp.path())
+                .await
+                .unwrap()
+        );
+
+        let mut args = test_run_args();
+        args.no_github = true;
+        assert!(ProviderSelection::chosen_by_flags(&args));
+    }
+
+    #[test]
+    fn provider_selection_honours_flags_and_config() {
+        let mut config = Config::default();
+        config.credentials.gcp.enabled = true;
+        config.credentials.github.enabled = false;
+        let selection = ProviderSelection::resolve(&test_run_args(), &config);
+        assert_eq!(
+            selection,
+            ProviderSelection {
+                aws: false,
+                gcp: true,
+                azure: false,
+                github: false,
+                gitlab: false,
+                bitbucket: false,
+                kubernetes: false,
+            }
+        );
+
+        let mut args = test_run_args();
+        args.all_clouds = true;
+        let selection = ProviderSelection::resolve(&args, &Config::default());
+        assert!(selection.aws && selection.gcp && selection.azure && selection.github);
+        assert!(!selection.gitlab && !selection.bitbucket && !selection.kubernetes);
+
+        let mut args = test_run_args();
+        args.gitlab = true;
+        assert!(ProviderSelection::chosen_by_flags(&args));
+        assert!(ProviderSelection::resolve(&args, &Config::default()).gitlab);
+    }
+
     #[test]
     fn is_default_network_with_defaults() {
         let args = test_run_args();
@@ -973,7 +1951,9 @@ mod tests {
         spinner: TaskSpinner,
         is_shell_mode: bool,
         shell_command: Vec<String>,
+        post_create: Vec<String>,
         network_mode: NetworkMode,
+        artifacts: StartArtifacts,
     }
 
     impl SmokeTestFixture {
@@ -1004,6 +1984,7 @@ mod tests {
             let mut config = Config::default();
             config.general.audit_log = false;
             config.general.update_check = false;
+            config.session.usage_log = false;
             let audit = AuditLog::new(&config);
             let ctx = UiContext::detect();
             let spinner = TaskSpinner::new(&ctx);
@@ -1021,7 +2002,9 @@ mod tests {
                 spinner,
                 is_shell_mode: shell_mode,
                 shell_command: vec!["/bin/zsh".to_string()],
+                post_create: vec![],
                 network_mode: NetworkMode::Bridge,
+                artifacts: StartArtifacts::default(),
             }
         }
 
@@ -1031,13 +2014,22 @@ mod tests {
                 container_config: &self.container_config,
                 command: &self.command,
                 session_name: &self.session_name,
+                project_dir: Path::new("."),
+                branch: "",
                 manager: &self.manager,
                 audit: &self.audit,
                 spinner: &mut self.spinner,
                 config: &self.config,
                 is_shell_mode: self.is_shell_mode,
                 shell_command: self.shell_command.clone(),
+                post_create: &self.post_create,
                 network_mode: &self.network_mode,
+                timeout: None,
+                heartbeat: None,
+                artifacts: &self.artifacts,
+                github_app: None,
+                refresh: None,
+                credential_files: None,
             }
         }
     }
@@ -1061,6 +2053,48 @@ mod tests {
         assert_eq!(updated.status, SessionStatus::Stopped);
     }
 
+    #[tokio::test]
+    #[serial]
+    async fn e2e_run_interactive_command_through_podman_cli() {
+        let mut f = SmokeTestFixture::new("test-e2e-int").await;
+        let exec =
+            Arc::new(RecordingExecutor::new().on_stdout("podman create", "0123456789abcdef\n"));
+        f.runtime = Arc::new(NativePodmanRuntime::with_executor(exec.clone()));
+
+        run_interactive(&mut f.run_ctx(), CacheSession::default())
+            .await
+            .unwrap();
+
+        exec.assert_not_ran("podman pull");
+        exec.assert_ran("podman start --attach 0123456789abcdef");
+        exec.assert_ran("podman rm -f 0123456789abcdef");
+
+        let updated = f.manager.get(&f.session_name).await.unwrap().unwrap();
+        assert_eq!(updated.status, SessionStatus::Stopped);
+        assert_eq!(updated.container_id.as_deref(), Some("0123456789abcdef"));
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn e2e_run_create_failure_marks_session_failed() {
+        let mut f = SmokeTestFixture::new("test-e2e-fail").await;
+        let exec = Arc::new(RecordingExecutor::new().on_fail(
+            "podman create",
+            125,
+            "Error: invalid reference format",
+        ));
+        f.runtime = Arc::new(NativePodmanRuntime::with_executor(exec.clone()));
+
+        let err = run_interactive(&mut f.run_ctx(), CacheSession::default())
+            .await
+            .unwrap_err();
+
+        assert!(err.to_string().contains("invalid reference format"));
+        exec.assert_not_ran("podman start");
+        let updated = f.manager.get(&f.session_name).await.unwrap().unwrap();
+        assert_eq!(updated.status, SessionStatus::Failed);
+    }
+
     #[tokio::test]
     #[serial]
     async fn smoke_run_interactive_shell() {
@@ -1085,6 +2119,22 @@ mod tests {
         assert_eq!(updated.status, SessionStatus::Stopped);
     }
 
+    #[tokio::test]
+    #[serial]
+    async fn shell_waits_for_post_create_hooks() {
+        let mut f = SmokeTestFixture::with_shell_mode("test-smoke-hooks", true).await;
+        f.post_create = vec!["make setup".to_string()];
+
+        run_interactive(&mut f.run_ctx(), CacheSession::default())
+            .await
+            .unwrap();
+
+        f.mock.assert_called_with(
+            "logs_follow_until",
+            &["mock-container-id", hooks::POST_CREATE_DONE],
+        );
+    }
+
     #[tokio::test]
     #[serial]
     async fn smoke_run_detached() {
@@ -1138,7 +2188,7 @@ mod tests {
 
     #[test]
     fn resolve_final_image_base_only_uses_layer_base_image() {
-        let resolution = resolve_final_image("fedora:43", true);
+        let resolution = resolve_final_image("fedora:43", true, &HashMap::new());
 
         assert_eq!(resolution.image, LAYER_BASE_IMAGE);
         assert!(resolution.layer_env.is_empty());
@@ -1146,7 +2196,7 @@ mod tests {
 
     #[test]
     fn resolve_final_image_not_base_only_resolves_alias() {
-        let resolution = resolve_final_image("fedora:43", false);
+        let resolution = resolve_final_image("fedora:43", false, &HashMap::new());
 
         assert_eq!(resolution.image, "fedora:43");
         assert!(resolution.layer_env.is_empty());
@@ -1154,10 +2204,138 @@ mod tests {
 
     #[test]
     fn resolve_final_image_base_alias_resolves_to_layer_base() {
-        let resolution = resolve_final_image("base", false);
+        let resolution = resolve_final_image("base", false, &HashMap::new());
         assert_eq!(resolution.image, LAYER_BASE_IMAGE);
     }
 
+    #[test]
+    fn resolve_pull_policy_cli_overrides_config() {
+        let mut config = Config::default();
+        config.container.pull = "never".to_string();
+        let mut args = test_run_args();
+        assert_eq!(
+            resolve_pull_policy(&args, &config).unwrap(),
+            crate::orchestration::PullPolicy::Never
+        );
+
+        args.pull = Some("always".to_string());
+        assert_eq!(
+            resolve_pull_policy(&args, &config).unwrap(),
+            crate::orchestration::PullPolicy::Always
+        );
+    }
+
+    #[test]
+    fn resolve_pull_policy_rejects_invalid_config() {
+        let mut config = Config::default();
+        config.container.pull = "sometimes".to_string();
+        assert!(resolve_pull_policy(&test_run_args(), &config).is_err());
+    }
+
+    #[tokio::test]
+    async fn pull_policy_always_pulls_existing_image() {
+        use crate::orchestration::PullPolicy;
+        let mock = MockRuntime::new();
+        let ctx = UiContext::non_interactive();
+        let mut spinner = TaskSpinner::new(&ctx);
+        apply_pull_policy(
+            &mock,
+            "node:22",
+            PullPolicy::Always,
+            None,
+            &ctx,
+            &mut spinner,
+        )
+        .await
+        .unwrap();
+        mock.assert_called_with("image_pull_with_progress", &["node:22"]);
+        mock.assert_called("image_exists", 0);
+    }
+
+    #[tokio::test]
+    async fn pull_policy_missing_skips_present_image() {
+        use crate::orchestration::mock::MockResponse;
+        use crate::orchestration::PullPolicy;
+        let mock = MockRuntime::new().on("image_exists", Ok(MockResponse::Bool(true)));
+        let ctx = UiContext::non_interactive();
+        let mut spinner = TaskSpinner::new(&ctx);
+        apply_pull_policy(
+            &mock,
+            "node:22",
+            PullPolicy::Missing,
+            None,
+            &ctx,
+            &mut spinner,
+        )
+        .await
+        .unwrap();
+        mock.assert_called("image_pull_with_progress", 0);
+    }
+
+    #[tokio::test]
+    async fn pull_policy_missing_pulls_other_platform() {
+        use crate::orchestration::mock::MockResponse;
+        use crate::orchestration::PullPolicy;
+        let mock = MockRuntime::new()
+            .on("image_exists", Ok(MockResponse::Bool(true)))
+            .on(
+                "image_platform",
+                Ok(MockResponse::String("linux/arm64".to_string())),
+            );
+        let ctx = UiContext::non_interactive();
+        let mut spinner = TaskSpinner::new(&ctx);
+        apply_pull_policy(
+            &mock,
+            "node:22",
+            PullPolicy::Missing,
+            Some("linux/amd64"),
+            &ctx,
+            &mut spinner,
+        )
+        .await
+        .unwrap();
+        mock.assert_called_with("image_pull_with_progress", &["node:22", "linux/amd64"]);
+    }
+
+    #[tokio::test]
+    async fn pull_policy_never_errors_on_missing_image() {
+        use crate::orchestration::PullPolicy;
+        let mock = MockRuntime::new();
+        let ctx = UiContext::non_interactive();
+        let mut spinner = TaskSpinner::new(&ctx);
+        let err = apply_pull_policy(
+            &mock,
+            "node:22",
+            PullPolicy::Never,
+            None,
+            &ctx,
+            &mut spinner,
+        )
+        .await
+        .unwrap_err();
+        assert!(err.to_string().contains("pull policy is 'never'"));
+        mock.assert_called("image_pull_with_progress", 0);
+    }
+
+    #[tokio::test]
+    async fn pull_policy_skips_composed_images() {
+        use crate::orchestration::PullPolicy;
+        let mock = MockRuntime::new();
+        let ctx = UiContext::non_interactive();
+        let mut spinner = TaskSpinner::new(&ctx);
+        apply_pull_policy(
+            &mock,
+            "mino-composed-abc123",
+            PullPolicy::Never,
+            None,
+            &ctx,
+            &mut spinner,
+        )
+        .await
+        .unwrap();
+        mock.assert_called("image_exists", 0);
+    }
+
     impl SmokeTestFixture {
         /// Build a fixture with a pre-configured `MockRuntime`.
         ///
@@ -1186,6 +2364,7 @@ mod tests {
             let mut config = Config::default();
             config.general.audit_log = false;
             config.general.update_check = false;
+            config.session.usage_log = false;
             let audit = AuditLog::new(&config);
             let ctx = UiContext::detect();
             let spinner = TaskSpinner::new(&ctx);
@@ -1203,7 +2382,9 @@ mod tests {
                 spinner,
                 is_shell_mode: shell_mode,
                 shell_command: vec!["/bin/zsh".to_string()],
+                post_create: vec![],
                 network_mode: NetworkMode::Bridge,
+                artifacts: StartArtifacts::default(),
             }
         }
     }
@@ -1238,6 +2419,40 @@ mod tests {
         assert_eq!(updated.status, SessionStatus::Failed);
     }
 
+    #[tokio::test]
+    #[serial]
+    async fn failed_start_rolls_back_session_resources() {
+        use crate::orchestration::mock::MockResponse;
+        let mock = MockRuntime::new()
+            .on_err(
+                "run",
+                MinoError::ContainerStart("OCI runtime error".to_string()),
+            )
+            .on(
+                "container_list_labeled",
+                Ok(MockResponse::StringVec(vec!["half-created".to_string()])),
+            );
+
+        let mut f = SmokeTestFixture::with_mock("test-rollback", mock, false).await;
+        f.artifacts
+            .volumes
+            .push(format!("mino-scratch-{}", f.session_name));
+
+        let result = run_detached(&mut f.run_ctx(), CacheSession::default()).await;
+
+        assert!(result.is_err());
+        let selector = format!("io.mino.session={}", f.session_name);
+        f.mock
+            .assert_called_with("container_list_labeled", &[selector.as_str()]);
+        f.mock.assert_called_with("remove", &["half-created"]);
+        let scratch = format!("mino-scratch-{}", f.session_name);
+        f.mock
+            .assert_called_with("volume_remove", &[scratch.as_str()]);
+
+        let updated = f.manager.get(&f.session_name).await.unwrap().unwrap();
+        assert_eq!(updated.status, SessionStatus::Failed);
+    }
+
     #[tokio::test]
     #[serial]
     async fn shell_logs_follow_until_error_propagates() {
@@ -4,23 +4,33 @@
 //! instead of Podman containers. Shares credential gathering and session
 //! management with the container path.
 
+use super::credentials::{gather_credentials, needs_aws_mfa_code, GatheredCredentials};
+use super::prompts::{
+    credential_prompt_needed, prompt_aws_mfa_code, prompt_credential_selection,
+    resolve_provider_selection,
+};
+use super::template::{self, TemplateVars};
 use crate::audit::AuditLog;
 use crate::cli::args::RunArgs;
 use crate::config::Config;
+use crate::credentials::files::CredentialDelivery;
+use crate::env_passthrough::{self, Passthrough};
 use crate::error::{MinoError, MinoResult};
 use crate::network::{resolve_network_mode, NetworkMode, NetworkResolutionInput};
 use crate::sandbox::config::{
-    resolve_sandbox_network, validate_path_not_sensitive, validate_sandbox_paths,
-    SandboxConfig, DEFAULT_ENV_PASSTHROUGH,
+    resolve_sandbox_network, validate_path_not_sensitive, validate_sandbox_paths, SandboxConfig,
+    DEFAULT_ENV_PASSTHROUGH,
 };
+use crate::sandbox::degraded::DegradedSandbox;
 use crate::sandbox::dotfiles;
 use crate::sandbox::fs_copy;
-use crate::sandbox::native::{create_sandbox_platform, SandboxPlatform, SandboxSpawnConfig};
+use crate::sandbox::native::{select_sandbox_platform, SandboxPlatform, SandboxSpawnConfig};
 use crate::sandbox::process::SandboxProcess;
 use crate::session::{Session, SessionManager, SessionStatus};
 use crate::ui::{self, TaskSpinner, UiContext};
+use chrono::{DateTime, Utc};
 use console::style;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::{Path, PathBuf};
 use tracing::debug;
 
@@ -28,11 +38,15 @@ use tracing::debug;
 struct CredentialResult {
     env: HashMap<String, String>,
     providers: Vec<String>,
+    expiry: BTreeMap<String, DateTime<Utc>>,
+    passthrough: Passthrough,
 }
 
 /// Session context created during session setup.
 struct SessionContext {
     session_name: String,
+    /// Command to spawn, with a configured default's placeholders expanded
+    command: Vec<String>,
     manager: SessionManager,
     audit: AuditLog,
 }
@@ -47,20 +61,38 @@ pub async fn execute_native(args: RunArgs, config: &Config) -> MinoResult<()> {
     spinner.start("Initializing native sandbox...");
 
     // Phase 1: Validate prerequisites and resolve configuration
-    let platform = create_sandbox_platform()?;
+    let (platform, degraded_reason) = select_sandbox_platform(args.allow_degraded).await?;
+    if let Some(reason) = &degraded_reason {
+        spinner.clear();
+        ui::step_warn_hint(
+            &ctx,
+            &format!(
+                "Full native sandbox unavailable ({}); falling back to {} with weaker isolation",
+                reason,
+                platform.isolation()
+            ),
+            "Run mino setup --native for full isolation",
+        );
+        spinner.start("Initializing native sandbox...");
+    }
     let (project_dir, network_mode, home_dir) =
-        validate_and_resolve(&args, config, &*platform, &mut spinner).await?;
+        validate_and_resolve(&args, config, &mut spinner).await?;
+    if degraded_reason.is_some() {
+        DegradedSandbox::check_network(&network_mode)?;
+    }
 
     // Phase 2: Gather credentials and build environment
-    let cred_result =
+    let mut cred_result =
         gather_credentials_and_env(&args, config, &ctx, &mut spinner, &project_dir).await?;
 
     // Phase 3: Start proxy (if needed), prepare dotfiles, create session
-    let mut env = cred_result.env;
+    let mut env = std::mem::take(&mut cred_result.env);
     let (_proxy_handle, _denial_task) =
         start_proxy_if_needed(&network_mode, &mut env, config, &mut spinner).await?;
     let dotfile_dir = prepare_dotfiles(config, &project_dir).await?;
-    let command = if args.command.is_empty() {
+    let command = if super::uses_default_command(&args, config) {
+        config.session.command.clone()
+    } else if args.command.is_empty() {
         let shell = if cfg!(target_os = "macos") {
             "/bin/zsh"
         } else {
@@ -73,12 +105,20 @@ pub async fn execute_native(args: RunArgs, config: &Config) -> MinoResult<()> {
     let session_ctx = create_session_and_audit(
         &args,
         config,
+        &mut spinner,
         &project_dir,
-        &command,
-        &cred_result.providers,
+        command,
+        &cred_result,
         &network_mode,
     )
     .await?;
+    cred_result
+        .passthrough
+        .audit(&session_ctx.audit, &session_ctx.session_name)
+        .await;
+    if let Some(reason) = degraded_reason {
+        mark_degraded(&session_ctx, platform.isolation(), &reason).await?;
+    }
 
     // Auto-mount user-configured host directories (read-only) when present.
     // Runs after validate_and_resolve so only newly-added entries are re-validated.
@@ -88,7 +128,7 @@ pub async fn execute_native(args: RunArgs, config: &Config) -> MinoResult<()> {
     let spawn_config = SandboxSpawnConfig {
         session_id: session_ctx.session_name.clone(),
         project_dir: project_dir.clone(),
-        command,
+        command: session_ctx.command.clone(),
         env,
         network_mode,
         sandbox_config,
@@ -111,15 +151,17 @@ pub async fn execute_native(args: RunArgs, config: &Config) -> MinoResult<()> {
     .await
 }
 
-/// Validate native sandbox prerequisites and resolve project dir, network mode, and home dir.
+/// Validate native flags and resolve project dir, network mode, and home dir.
+/// Platform prerequisites are checked when the platform is selected.
 async fn validate_and_resolve(
     args: &RunArgs,
     config: &Config,
-    platform: &dyn SandboxPlatform,
     _spinner: &mut TaskSpinner,
 ) -> MinoResult<(PathBuf, NetworkMode, PathBuf)> {
-    platform.validate_setup().await?;
     validate_native_flags(args)?;
+    if !config.services.is_empty() {
+        tracing::warn!("[services] ignored in native mode (services need Podman pods)");
+    }
 
     let project_dir = resolve_project_dir(args)?;
     debug!("Project directory: {}", project_dir.display());
@@ -178,11 +220,52 @@ async fn gather_credentials_and_env(
     config: &Config,
     ctx: &UiContext,
     spinner: &mut TaskSpinner,
-    _project_dir: &Path,
+    project_dir: &Path,
 ) -> MinoResult<CredentialResult> {
+    let mut selection = if credential_prompt_needed(args, ctx, config, project_dir).await? {
+        spinner.clear();
+        let selection = prompt_credential_selection(ctx, args, config, project_dir).await?;
+        spinner.start("Initializing native sandbox...");
+        selection
+    } else {
+        resolve_provider_selection(args, config, project_dir).await
+    };
+    if selection.kubernetes {
+        tracing::warn!("credentials.kubernetes ignored in native mode (needs a container mount)");
+        selection.kubernetes = false;
+    }
+    if config.credentials.deploy_key.enabled {
+        tracing::warn!("credentials.deploy_key ignored in native mode (needs a container mount)");
+    }
+    if config.git.user_name.is_some()
+        || config.git.user_email.is_some()
+        || config.git.signing_key.is_some()
+    {
+        tracing::warn!("[git] ignored in native mode (the sandbox copies the host .gitconfig)");
+    }
+
+    let aws_mfa_code = if selection.aws && needs_aws_mfa_code(config).await {
+        spinner.clear();
+        let code = prompt_aws_mfa_code(ctx, config).await?;
+        spinner.start("Initializing native sandbox...");
+        Some(code)
+    } else {
+        None
+    };
+
+    if config.credentials.delivery.parse::<CredentialDelivery>()? == CredentialDelivery::File {
+        tracing::warn!("credentials.delivery = \"file\" ignored in native mode (using env vars)");
+    }
+
     spinner.message("Gathering credentials...");
-    let (credentials, active_providers, cred_failures) =
-        super::credentials::gather_credentials(args, config).await?;
+    let GatheredCredentials {
+        env: mut credentials,
+        git_config,
+        providers: active_providers,
+        failures: cred_failures,
+        expiry: credential_expiry,
+        ..
+    } = gather_credentials(args, config, selection, aws_mfa_code.as_deref()).await?;
 
     if !cred_failures.is_empty() {
         spinner.stop("Credentials");
@@ -202,11 +285,16 @@ async fn gather_credentials_and_env(
         spinner.start("Initializing native sandbox...");
     }
 
-    let env = build_sandbox_env(config, &credentials);
+    let passthrough = host_passthrough(config);
+    passthrough.warn_denied("sandbox.env_passthrough");
+    credentials.extend(git_config.to_env());
+    let env = build_sandbox_env(config, &passthrough.env, &credentials);
 
     Ok(CredentialResult {
         env,
         providers: active_providers,
+        expiry: credential_expiry,
+        passthrough,
     })
 }
 
@@ -255,24 +343,47 @@ async fn start_proxy_if_needed(
 async fn create_session_and_audit(
     args: &RunArgs,
     config: &Config,
+    spinner: &mut TaskSpinner,
     project_dir: &Path,
-    command: &[String],
-    active_providers: &[String],
+    command: Vec<String>,
+    credentials: &CredentialResult,
     network_mode: &NetworkMode,
 ) -> MinoResult<SessionContext> {
-    let session_name = args
-        .name
-        .clone()
-        .unwrap_or_else(super::generate_session_name);
     let manager = SessionManager::new().await?;
 
     if config.session.auto_cleanup_hours > 0 {
         let cleaned = manager.cleanup(config.session.auto_cleanup_hours).await?;
-        if cleaned > 0 {
-            debug!("Cleaned up {} old session(s)", cleaned);
+        if !cleaned.is_empty() {
+            debug!("Cleaned up {} old session(s)", cleaned.len());
         }
     }
 
+    let mut session_name = args
+        .name
+        .clone()
+        .unwrap_or_else(super::generate_session_name);
+    if manager.get(&session_name).await?.is_some() {
+        spinner.clear();
+        let ctx = UiContext::detect();
+        session_name =
+            super::conflict::resolve_session_name(&ctx, &manager, config, session_name).await?;
+        spinner.start("Initializing native sandbox...");
+    }
+
+    // Placeholders in `session.command` are expanded once the session is named
+    let command = if super::uses_default_command(args, config) {
+        let project = template::project_name(project_dir);
+        let branch = template::current_branch(project_dir).await;
+        let vars = TemplateVars {
+            project: &project,
+            branch: &branch,
+            session: &session_name,
+        };
+        command.iter().map(|arg| vars.expand(arg)).collect()
+    } else {
+        command
+    };
+
     tokio::spawn(async {
         match crate::cli::commands::status::cleanup_stale_native_sessions().await {
             Ok(n) if n > 0 => debug!("Cleaned up {} stale native session(s)", n),
@@ -284,11 +395,12 @@ async fn create_session_and_audit(
     let mut session = Session::new(
         session_name.clone(),
         project_dir.to_path_buf(),
-        command.to_vec(),
+        command.clone(),
         SessionStatus::Starting,
     );
     session.runtime_mode = Some(crate::sandbox::RuntimeMode::Native);
     session.sandbox_user = Some(config.sandbox.sandbox_user.clone());
+    session.credential_expiry = credentials.expiry.clone();
     manager.create(&session).await?;
 
     let audit = AuditLog::new(config);
@@ -305,6 +417,7 @@ async fn create_session_and_audit(
         )
         .await;
 
+    let active_providers = &credentials.providers;
     if !active_providers.is_empty() {
         audit
             .log(
@@ -319,11 +432,37 @@ async fn create_session_and_audit(
 
     Ok(SessionContext {
         session_name,
+        command,
         manager,
         audit,
     })
 }
 
+/// Record that the session runs in the degraded sandbox, so exec and stop
+/// dispatch to it, and audit the weaker isolation.
+async fn mark_degraded(
+    session_ctx: &SessionContext,
+    isolation: &str,
+    reason: &str,
+) -> MinoResult<()> {
+    if let Some(mut session) = session_ctx.manager.get(&session_ctx.session_name).await? {
+        session.degraded_sandbox = true;
+        session.save().await?;
+    }
+    session_ctx
+        .audit
+        .log(
+            "sandbox.degraded",
+            &serde_json::json!({
+                "session_id": &session_ctx.session_name,
+                "isolation": isolation,
+                "reason": reason,
+            }),
+        )
+        .await;
+    Ok(())
+}
+
 /// Context for spawn_and_monitor, bundling references that were previously
 /// passed as individual arguments.
 struct SpawnMonitorCtx<'a> {
@@ -352,6 +491,7 @@ async fn spawn_and_monitor(
         session_name,
         manager,
         audit,
+        ..
     } = session_ctx;
 
     spinner.message("Starting native sandbox (setting permissions)...");
@@ -382,7 +522,15 @@ async fn spawn_and_monitor(
     ));
 
     let exit_code = wait_with_signal_forwarding(&mut process).await?;
-    finalize_session(exit_code, &dotfile_dir, &session_name, &manager, &audit, config).await
+    finalize_session(
+        exit_code,
+        &dotfile_dir,
+        &session_name,
+        &manager,
+        &audit,
+        config,
+    )
+    .await
 }
 
 /// Clean up and record failure when the sandbox fails to spawn.
@@ -501,6 +649,11 @@ async fn handle_detach(
 
 /// Validate that no container-only flags are set
 fn validate_native_flags(args: &RunArgs) -> MinoResult<()> {
+    if args.timeout.is_some() {
+        return Err(MinoError::NativeUnsupported {
+            feature: "command timeouts (--timeout)".to_string(),
+        });
+    }
     if args.image.is_some() {
         return Err(MinoError::NativeUnsupported {
             feature: "custom images (--image)".to_string(),
@@ -511,9 +664,76 @@ fn validate_native_flags(args: &RunArgs) -> MinoResult<()> {
             feature: "read-only filesystem (--read-only)".to_string(),
         });
     }
-    if args.cache_fresh {
+    if args.project.len() > 1 {
+        return Err(MinoError::NativeUnsupported {
+            feature: "multiple projects (--project repeated)".to_string(),
+        });
+    }
+    if args.scratch {
+        return Err(MinoError::NativeUnsupported {
+            feature: "scratch volumes (--scratch)".to_string(),
+        });
+    }
+    if args.worktree {
+        return Err(MinoError::NativeUnsupported {
+            feature: "session worktrees (--worktree)".to_string(),
+        });
+    }
+    if args.refresh_credentials {
+        return Err(MinoError::NativeUnsupported {
+            feature: "credential refresh (--refresh-credentials)".to_string(),
+        });
+    }
+    if args.kube {
         return Err(MinoError::NativeUnsupported {
-            feature: "cache management (--cache-fresh)".to_string(),
+            feature: "Kubernetes credentials (--kube)".to_string(),
+        });
+    }
+    if args.deploy_key {
+        return Err(MinoError::NativeUnsupported {
+            feature: "deploy keys (--deploy-key)".to_string(),
+        });
+    }
+    if args.pull.is_some() {
+        return Err(MinoError::NativeUnsupported {
+            feature: "image pull policy (--pull)".to_string(),
+        });
+    }
+    if args.cache_fresh || args.cache_rw {
+        return Err(MinoError::NativeUnsupported {
+            feature: "cache management (--cache-fresh, --cache-rw)".to_string(),
+        });
+    }
+    if args.cpus.is_some() || args.memory.is_some() || args.pids_limit.is_some() {
+        return Err(MinoError::NativeUnsupported {
+            feature:
+                "container resource limits (--cpus, --memory, --pids-limit; use [sandbox] limits)"
+                    .to_string(),
+        });
+    }
+    if args.gpus {
+        return Err(MinoError::NativeUnsupported {
+            feature: "GPU passthrough (--gpus)".to_string(),
+        });
+    }
+    if !args.device.is_empty() {
+        return Err(MinoError::NativeUnsupported {
+            feature: "device passthrough (--device)".to_string(),
+        });
+    }
+    if args.restart.is_some() {
+        return Err(MinoError::NativeUnsupported {
+            feature: "restart policies (--restart)".to_string(),
+        });
+    }
+    if !args.tmpfs.is_empty() {
+        return Err(MinoError::NativeUnsupported {
+            feature: "tmpfs mounts (--tmpfs)".to_string(),
+        });
+    }
+    if args.userns.is_some() {
+        return Err(MinoError::NativeUnsupported {
+            feature: "user namespace modes (--userns)".to_string(),
         });
     }
     if !args.layers.is_empty() {
@@ -522,16 +742,25 @@ fn validate_native_flags(args: &RunArgs) -> MinoResult<()> {
     Ok(())
 }
 
+/// Select host variables for the sandbox from `sandbox.env_passthrough`
+/// (falls back to [`DEFAULT_ENV_PASSTHROUGH`] when unset).
+fn host_passthrough(config: &Config) -> Passthrough {
+    match config.sandbox.env_passthrough.as_deref() {
+        Some(patterns) => env_passthrough::from_host(patterns),
+        None => env_passthrough::from_host(DEFAULT_ENV_PASSTHROUGH),
+    }
+}
+
 /// Build sandbox environment variables.
 ///
 /// Resolution order (last writer wins):
 /// 1. Fixed sandbox identity vars (`HOME`, `USER`, `MINO_SANDBOX`, `PATH`)
-/// 2. Host env passthrough: keys from `sandbox.env_passthrough`
-///    (falls back to [`crate::sandbox::config::DEFAULT_ENV_PASSTHROUGH`] when unset)
+/// 2. Host env passthrough selected by [`host_passthrough`]
 /// 3. Credential env vars (from credential providers)
 /// 4. Explicit env vars: `sandbox.env` if set, else `container.env`
 fn build_sandbox_env(
     config: &Config,
+    passthrough: &HashMap<String, String>,
     credentials: &HashMap<String, String>,
 ) -> HashMap<String, String> {
     let mut env = HashMap::new();
@@ -543,27 +772,8 @@ fn build_sandbox_env(
     env.insert("USER".to_string(), config.sandbox.sandbox_user.clone());
     env.insert("MINO_SANDBOX".to_string(), "native".to_string());
 
-    // Inherit keys from the host environment. Uses the configured list when set,
-    // or the default list (locale vars + ANTHROPIC_API_KEY).
-    // Users can add other provider keys (OPENAI_API_KEY, GROQ_API_KEY, etc.) via
-    // `sandbox.env_passthrough` in config without requiring a code change.
-    let passthrough_keys: Vec<&str> = config
-        .sandbox
-        .env_passthrough
-        .as_deref()
-        .map(|v| v.iter().map(|s| s.as_str()).collect())
-        .unwrap_or_else(|| DEFAULT_ENV_PASSTHROUGH.to_vec());
-
-    for key in &passthrough_keys {
-        match std::env::var(key) {
-            Ok(val) => {
-                env.insert((*key).to_string(), val);
-            }
-            Err(_) => {
-                debug!(key = *key, "passthrough env key not set in host — skipping");
-            }
-        }
-    }
+    // Inherit matching keys from the host environment
+    env.extend(passthrough.clone());
 
     // PATH: Homebrew + system paths (toolchain paths added later based on passthrough mounts)
     env.insert(
@@ -583,7 +793,7 @@ fn build_sandbox_env(
 
 /// Resolve project directory from CLI args or current directory
 fn resolve_project_dir(args: &RunArgs) -> MinoResult<PathBuf> {
-    let dir = match &args.project {
+    let dir = match args.project.first() {
         Some(p) => p.clone(),
         None => {
             std::env::current_dir().map_err(|e| MinoError::io("getting current directory", e))?
@@ -846,6 +1056,14 @@ mod tests {
     use crate::cli::args::RunArgs;
     use serial_test::serial;
 
+    /// Build the sandbox env with passthrough taken from the live host env
+    fn host_sandbox_env(
+        config: &Config,
+        credentials: &HashMap<String, String>,
+    ) -> HashMap<String, String> {
+        build_sandbox_env(config, &host_passthrough(config).env, credentials)
+    }
+
     /// Guard that restores environment variables on drop, even on panic.
     ///
     /// Used by `#[serial]` tests that modify process environment: the Drop impl
@@ -886,27 +1104,55 @@ mod tests {
     fn test_run_args() -> RunArgs {
         RunArgs {
             name: None,
-            project: None,
+            project: vec![],
             aws: false,
             gcp: false,
             azure: false,
             all_clouds: false,
             no_ssh_agent: false,
             no_github: false,
+            gitlab: false,
+            bitbucket: false,
+            kube: false,
+            deploy_key: false,
             strict_credentials: false,
+            refresh_credentials: false,
             image: None,
             layers: vec![],
+            layer_args: vec![],
+            allow_layer_conflicts: false,
             env: vec![],
             volume: vec![],
+            workdir: None,
             detach: false,
+            timeout: None,
+            heartbeat: None,
             read_only: false,
             no_cache: false,
             no_home: false,
             cache_fresh: false,
+            cache_rw: false,
+            scratch: false,
+            worktree: false,
+            cpus: None,
+            memory: None,
+            pids_limit: None,
+            ulimit: vec![],
+            gpus: false,
+            userns: None,
+            stdin: false,
+            device: vec![],
+            tmpfs: vec![],
+            restart: None,
+            platform: None,
+            pull: None,
             network: None,
             network_allow: vec![],
             network_preset: None,
             runtime: None,
+            native: false,
+            allow_degraded: false,
+            context: None,
             command: vec![],
         }
     }
@@ -938,6 +1184,14 @@ mod tests {
         assert!(err.to_string().contains("cache management"));
     }
 
+    #[test]
+    fn validate_native_flags_resource_limits_return_error() {
+        let mut args = test_run_args();
+        args.memory = Some(4096);
+        let err = validate_native_flags(&args).unwrap_err();
+        assert!(err.to_string().contains("container resource limits"));
+    }
+
     #[test]
     fn validate_native_flags_no_flags_is_ok() {
         let args = test_run_args();
@@ -956,7 +1210,7 @@ mod tests {
 
     #[test]
     fn build_sandbox_env_includes_basic_vars_and_path() {
-        let env = build_sandbox_env(&Config::default(), &HashMap::new());
+        let env = host_sandbox_env(&Config::default(), &HashMap::new());
 
         assert_eq!(env.get("HOME").unwrap(), "/home/agent");
         assert_eq!(env.get("USER").unwrap(), "_mino_agent");
@@ -975,7 +1229,7 @@ mod tests {
         credentials.insert("AWS_ACCESS_KEY_ID".to_string(), "AKIA123".to_string());
         credentials.insert("AWS_SECRET_ACCESS_KEY".to_string(), "secret123".to_string());
 
-        let env = build_sandbox_env(&config, &credentials);
+        let env = host_sandbox_env(&config, &credentials);
         assert_eq!(env.get("AWS_ACCESS_KEY_ID").unwrap(), "AKIA123");
         assert_eq!(env.get("AWS_SECRET_ACCESS_KEY").unwrap(), "secret123");
     }
@@ -984,7 +1238,7 @@ mod tests {
     #[serial]
     fn build_sandbox_env_inherits_term_when_set() {
         unsafe { std::env::set_var("TERM", "xterm-256color") };
-        let env = build_sandbox_env(&Config::default(), &HashMap::new());
+        let env = host_sandbox_env(&Config::default(), &HashMap::new());
         assert_eq!(env.get("TERM").unwrap(), "xterm-256color");
         unsafe { std::env::remove_var("TERM") };
     }
@@ -993,7 +1247,7 @@ mod tests {
     #[serial]
     fn build_sandbox_env_omits_term_when_unset() {
         unsafe { std::env::remove_var("TERM") };
-        let env = build_sandbox_env(&Config::default(), &HashMap::new());
+        let env = host_sandbox_env(&Config::default(), &HashMap::new());
         assert!(!env.contains_key("TERM"));
         // Restore a reasonable default
         unsafe { std::env::set_var("TERM", "xterm-256color") };
@@ -1007,7 +1261,7 @@ mod tests {
             .env
             .insert("CUSTOM_VAR".to_string(), "custom_value".to_string());
         let credentials = HashMap::new();
-        let env = build_sandbox_env(&config, &credentials);
+        let env = host_sandbox_env(&config, &credentials);
         assert_eq!(env.get("CUSTOM_VAR").unwrap(), "custom_value");
     }
 
@@ -1023,7 +1277,7 @@ mod tests {
     #[test]
     fn resolve_project_dir_uses_explicit_path() {
         let mut args = test_run_args();
-        args.project = Some(PathBuf::from("/tmp"));
+        args.project = vec![PathBuf::from("/tmp")];
         let dir = resolve_project_dir(&args).unwrap();
         assert_eq!(dir, PathBuf::from("/tmp"));
     }
@@ -1031,7 +1285,7 @@ mod tests {
     #[test]
     fn resolve_project_dir_rejects_nonexistent() {
         let mut args = test_run_args();
-        args.project = Some(PathBuf::from("/nonexistent/path/abc123"));
+        args.project = vec![PathBuf::from("/nonexistent/path/abc123")];
         let err = resolve_project_dir(&args).unwrap_err();
         assert!(err.to_string().contains("Path not found"));
     }
@@ -1093,7 +1347,7 @@ mod tests {
         sandbox_env.insert("SHARED".to_string(), "from_sandbox".to_string());
         sandbox_env.insert("SANDBOX_ONLY".to_string(), "yes".to_string());
         config.sandbox.env = Some(sandbox_env);
-        let env = build_sandbox_env(&config, &HashMap::new());
+        let env = host_sandbox_env(&config, &HashMap::new());
         assert_eq!(env.get("SHARED").unwrap(), "from_sandbox");
         assert_eq!(env.get("SANDBOX_ONLY").unwrap(), "yes");
     }
@@ -1106,7 +1360,7 @@ mod tests {
             .env
             .insert("FROM_CONTAINER".to_string(), "hello".to_string());
         // sandbox.env is None (default)
-        let env = build_sandbox_env(&config, &HashMap::new());
+        let env = host_sandbox_env(&config, &HashMap::new());
         assert_eq!(env.get("FROM_CONTAINER").unwrap(), "hello");
     }
 
@@ -1118,7 +1372,7 @@ mod tests {
         let _guard = EnvGuard::set(&[("ANTHROPIC_API_KEY", "test-key-123")]);
         let config = Config::default();
         assert!(config.sandbox.env_passthrough.is_none());
-        let env = build_sandbox_env(&config, &HashMap::new());
+        let env = host_sandbox_env(&config, &HashMap::new());
         assert_eq!(env.get("ANTHROPIC_API_KEY").unwrap(), "test-key-123");
     }
 
@@ -1135,7 +1389,7 @@ mod tests {
         let mut config = Config::default();
         config.sandbox.env_passthrough = Some(vec!["MY_CUSTOM_KEY".to_string()]);
 
-        let env = build_sandbox_env(&config, &HashMap::new());
+        let env = host_sandbox_env(&config, &HashMap::new());
         assert_eq!(env.get("MY_CUSTOM_KEY").unwrap(), "custom-value");
         assert!(
             !env.contains_key("ANTHROPIC_API_KEY"),
@@ -1152,7 +1406,7 @@ mod tests {
         let mut config = Config::default();
         config.sandbox.env_passthrough = Some(vec![]);
 
-        let env = build_sandbox_env(&config, &HashMap::new());
+        let env = host_sandbox_env(&config, &HashMap::new());
         assert!(!env.contains_key("ANTHROPIC_API_KEY"));
         assert!(!env.contains_key("LANG"));
     }
@@ -1,5 +1,6 @@
-//! Interactive prompts for network and layer selection
+//! Interactive prompts for network, layer, and credential selection
 
+use super::credentials::ProviderSelection;
 use crate::cli::args::RunArgs;
 use crate::config::{Config, ConfigManager};
 use crate::error::{MinoError, MinoResult};
@@ -7,7 +8,10 @@ use crate::layer::list_available_layers;
 use crate::network::{resolve_preset, NetworkMode};
 use crate::ui::{self, UiContext};
 use console::style;
-use std::path::Path;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use tracing::warn;
 
 /// Network mode selection for the interactive prompt
 #[derive(Clone, PartialEq, Eq)]
@@ -37,6 +41,18 @@ pub(super) fn is_default_network(args: &RunArgs, config: &Config) -> bool {
         && config.container.network_preset.is_none()
 }
 
+/// Prompt for the code of the `credentials.aws.mfa_serial` device.
+/// Returns an empty string when there is no terminal to ask on.
+pub(super) async fn prompt_aws_mfa_code(ctx: &UiContext, config: &Config) -> MinoResult<String> {
+    let serial = config
+        .credentials
+        .aws
+        .mfa_serial
+        .as_deref()
+        .unwrap_or("MFA");
+    ui::input(ctx, &format!("MFA code for {}", serial), "").await
+}
+
 /// Prompt user to select network mode interactively.
 /// Returns the resolved `NetworkMode`.
 pub(super) async fn prompt_network_selection(
@@ -157,6 +173,16 @@ pub(super) async fn upsert_container_toml_key(
     path: &Path,
     key: &str,
     value: toml_edit::Value,
+) -> MinoResult<()> {
+    upsert_toml_keys(path, &["container"], &[(key, value)]).await
+}
+
+/// Insert or update keys under the table at `table` (e.g. `["credentials",
+/// "aws"]`) in a TOML config file, creating the file and tables as needed.
+async fn upsert_toml_keys(
+    path: &Path,
+    table: &[&str],
+    entries: &[(&str, toml_edit::Value)],
 ) -> MinoResult<()> {
     if let Some(parent) = path.parent() {
         tokio::fs::create_dir_all(parent).await.map_err(|e| {
@@ -181,18 +207,28 @@ pub(super) async fn upsert_container_toml_key(
         toml_edit::DocumentMut::new()
     };
 
-    if !doc.contains_key("container") {
-        doc.insert("container", toml_edit::Item::Table(toml_edit::Table::new()));
+    let mut target = doc.as_table_mut();
+    for (depth, name) in table.iter().enumerate() {
+        if !target.contains_key(name) {
+            let mut new_table = toml_edit::Table::new();
+            // Intermediate tables stay implicit so only `[a.b]` headers appear
+            new_table.set_implicit(depth + 1 < table.len());
+            target.insert(name, toml_edit::Item::Table(new_table));
+        }
+        target = target[*name]
+            .as_table_mut()
+            .ok_or_else(|| MinoError::ConfigInvalid {
+                path: path.to_path_buf(),
+                reason: format!(
+                    "'{}' key exists but is not a table",
+                    table[..=depth].join(".")
+                ),
+            })?;
     }
 
-    let container = doc["container"]
-        .as_table_mut()
-        .ok_or_else(|| MinoError::ConfigInvalid {
-            path: path.to_path_buf(),
-            reason: "'container' key exists but is not a table".to_string(),
-        })?;
-
-    container.insert(key, toml_edit::value(value));
+    for (key, value) in entries {
+        target.insert(key, toml_edit::value(value.clone()));
+    }
 
     tokio::fs::write(path, doc.to_string())
         .await
@@ -290,3 +326,214 @@ async fn prompt_save_config(
     )
     .await
 }
+
+/// Credential providers chosen at the prompt, per project.
+///
+/// Kept in the state directory rather than the project's `.mino.toml`, so
+/// saving a choice doesn't change the file's trust hash, and a declined
+/// local config can't pose as one.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub(super) struct CredentialSelections {
+    projects: HashMap<PathBuf, ProviderSelection>,
+}
+
+impl CredentialSelections {
+    /// Load the selections at `path`; missing or corrupt files read as empty
+    pub(super) async fn load(path: &Path) -> Self {
+        let bytes = match tokio::fs::read(path).await {
+            Ok(bytes) => bytes,
+            Err(_) => return Self::default(),
+        };
+        serde_json::from_slice(&bytes).unwrap_or_else(|e| {
+            warn!(
+                "Corrupt credential selections at {}, treating as empty: {}",
+                path.display(),
+                e
+            );
+            Self::default()
+        })
+    }
+
+    pub(super) async fn save(&self, path: &Path) -> MinoResult<()> {
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(|e| MinoError::io(format!("creating {}", parent.display()), e))?;
+        }
+        let json = serde_json::to_string_pretty(self)?;
+        tokio::fs::write(path, json)
+            .await
+            .map_err(|e| MinoError::io(format!("writing {}", path.display()), e))
+    }
+
+    pub(super) fn get(&self, project_dir: &Path) -> Option<ProviderSelection> {
+        self.projects.get(&project_key(project_dir)).copied()
+    }
+
+    pub(super) fn set(&mut self, project_dir: &Path, selection: ProviderSelection) {
+        self.projects.insert(project_key(project_dir), selection);
+    }
+}
+
+fn project_key(project_dir: &Path) -> PathBuf {
+    std::fs::canonicalize(project_dir).unwrap_or_else(|_| project_dir.to_path_buf())
+}
+
+/// Whether the credential prompt should run for this session.
+///
+/// It runs when the session is interactive, no CLI flag picks providers,
+/// and the project has no credential choice yet (see
+/// [`project_chose_credentials`]).
+pub(super) async fn credential_prompt_needed(
+    args: &RunArgs,
+    ctx: &UiContext,
+    config: &Config,
+    project_dir: &Path,
+) -> MinoResult<bool> {
+    if !ctx.is_interactive() || ctx.auto_yes() || ProviderSelection::chosen_by_flags(args) {
+        return Ok(false);
+    }
+    let chose = project_chose_credentials(
+        config,
+        &ConfigManager::credential_selections_path(),
+        project_dir,
+    )
+    .await?;
+    Ok(!chose)
+}
+
+/// Whether the project already has a credential choice: one saved at the
+/// prompt, or a `[credentials]` section in the local config that was loaded.
+/// A local config that was skipped (trust declined) doesn't count.
+pub(super) async fn project_chose_credentials(
+    config: &Config,
+    selections_path: &Path,
+    project_dir: &Path,
+) -> MinoResult<bool> {
+    if CredentialSelections::load(selections_path)
+        .await
+        .get(project_dir)
+        .is_some()
+    {
+        return Ok(true);
+    }
+    match &config.local_path {
+        Some(path) => local_specifies_credentials(path).await,
+        None => Ok(false),
+    }
+}
+
+/// Providers for a session that skips the prompt: CLI flags and config, or
+/// the selection saved for the project when no flag picks providers
+pub(super) async fn resolve_provider_selection(
+    args: &RunArgs,
+    config: &Config,
+    project_dir: &Path,
+) -> ProviderSelection {
+    if !ProviderSelection::chosen_by_flags(args) {
+        let saved = CredentialSelections::load(&ConfigManager::credential_selections_path())
+            .await
+            .get(project_dir);
+        if let Some(saved) = saved {
+            return saved;
+        }
+    }
+    ProviderSelection::resolve(args, config)
+}
+
+/// Prompt which credential providers to enable for this project and save the
+/// choice in the state directory.
+///
+/// Only chosen providers are injected, even if the global config enables
+/// others.
+pub(super) async fn prompt_credential_selection(
+    ctx: &UiContext,
+    args: &RunArgs,
+    config: &Config,
+    project_dir: &Path,
+) -> MinoResult<ProviderSelection> {
+    let configured = ProviderSelection::resolve(args, config);
+    let options: Vec<(&str, &str, &str)> = vec![
+        ("aws", "AWS", "temporary STS credentials from your AWS CLI"),
+        ("gcp", "GCP", "access token from gcloud"),
+        ("azure", "Azure", "access token from az"),
+        (
+            "github",
+            "GitHub",
+            "token from gh for git push and the gh CLI",
+        ),
+        ("gitlab", "GitLab", "token from GITLAB_TOKEN or glab"),
+        ("bitbucket", "Bitbucket", "token from BITBUCKET_TOKEN"),
+        (
+            "kubernetes",
+            "Kubernetes",
+            "kubeconfig with a short-lived ServiceAccount token",
+        ),
+    ];
+    let initial: Vec<&str> = [
+        ("aws", configured.aws),
+        ("gcp", configured.gcp),
+        ("azure", configured.azure),
+        ("github", configured.github),
+        ("gitlab", configured.gitlab),
+        ("bitbucket", configured.bitbucket),
+        ("kubernetes", configured.kubernetes),
+    ]
+    .into_iter()
+    .filter(|(_, on)| *on)
+    .map(|(name, _)| name)
+    .collect();
+
+    let selected = ui::multiselect_with_initial(
+        ctx,
+        "Select credentials for this project (space to toggle, enter to confirm)",
+        &options,
+        &initial,
+        false,
+    )
+    .await?;
+
+    let selection = ProviderSelection {
+        aws: selected.contains(&"aws"),
+        gcp: selected.contains(&"gcp"),
+        azure: selected.contains(&"azure"),
+        github: selected.contains(&"github"),
+        gitlab: selected.contains(&"gitlab"),
+        bitbucket: selected.contains(&"bitbucket"),
+        kubernetes: selected.contains(&"kubernetes"),
+    };
+    let path = ConfigManager::credential_selections_path();
+    save_credential_selection(&path, project_dir, selection).await?;
+    println!(
+        "  {} Saved for this project to {}",
+        style("✓").green(),
+        path.display()
+    );
+
+    Ok(selection)
+}
+
+/// Whether the config file at `path` has a `[credentials]` section
+pub(super) async fn local_specifies_credentials(path: &Path) -> MinoResult<bool> {
+    let content = match tokio::fs::read_to_string(path).await {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(false),
+        Err(e) => return Err(MinoError::io(format!("reading {}", path.display()), e)),
+    };
+    let doc: toml::Value = toml::from_str(&content).map_err(|e| MinoError::ConfigInvalid {
+        path: path.to_path_buf(),
+        reason: e.to_string(),
+    })?;
+    Ok(doc.get("credentials").is_some())
+}
+
+/// Record `selection` for `project_dir` in the selections file at `path`
+pub(super) async fn save_credential_selection(
+    path: &Path,
+    project_dir: &Path,
+    selection: ProviderSelection,
+) -> MinoResult<()> {
+    let mut selections = CredentialSelections::load(path).await;
+    selections.set(project_dir, selection);
+    selections.save(path).await
+}
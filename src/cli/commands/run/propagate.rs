@@ -0,0 +1,177 @@
+//! Host timezone, locale, and git identity propagation
+
+use super::gitconfig::GitConfigEnv;
+use crate::config::Config;
+use crate::error::{MinoError, MinoResult};
+use std::collections::HashMap;
+use std::env;
+use std::path::Path;
+use std::process::Stdio;
+use tokio::process::Command;
+use tracing::debug;
+
+/// Supported `container.propagate` entries
+const PROPAGATE_OPTIONS: &[&str] = &["timezone", "locale", "gitconfig"];
+
+/// Host zoneinfo file bind-mounted for `timezone`
+const LOCALTIME: &str = "/etc/localtime";
+
+/// Locale variables copied from the host for `locale`
+const LOCALE_VARS: &[&str] = &["LANG", "LANGUAGE", "LC_ALL", "LC_CTYPE", "LC_MESSAGES"];
+
+/// Host settings to inject into the container.
+#[derive(Debug, Default)]
+pub(super) struct Propagation {
+    /// Extra `host:container[:opts]` mounts
+    pub volumes: Vec<String>,
+    /// Extra env vars
+    pub env: HashMap<String, String>,
+    /// Git identity from the host
+    pub git_config: GitConfigEnv,
+}
+
+/// Collect host settings listed in `container.propagate`.
+pub(super) async fn gather_propagation(config: &Config) -> MinoResult<Propagation> {
+    let options = &config.container.propagate;
+    validate_options(options)?;
+
+    let mut propagation = Propagation::default();
+
+    if options.iter().any(|o| o == "timezone") {
+        if Path::new(LOCALTIME).exists() {
+            propagation
+                .volumes
+                .push(format!("{LOCALTIME}:{LOCALTIME}:ro"));
+        }
+        let tz = env::var("TZ").ok().or_else(|| {
+            std::fs::read_link(LOCALTIME)
+                .ok()
+                .and_then(|target| zone_from_localtime(&target))
+        });
+        if let Some(tz) = tz {
+            propagation.env.insert("TZ".to_string(), tz);
+        }
+    }
+
+    if options.iter().any(|o| o == "locale") {
+        for var in LOCALE_VARS {
+            if let Ok(value) = env::var(var) {
+                propagation.env.insert(var.to_string(), value);
+            }
+        }
+    }
+
+    if options.iter().any(|o| o == "gitconfig") {
+        let name = git_config_get("user.name").await;
+        let email = git_config_get("user.email").await;
+        propagation.git_config = git_identity(name, email);
+    }
+
+    debug!(
+        "Propagating {} mount(s), {} env var(s) from host",
+        propagation.volumes.len(),
+        propagation.env.len()
+    );
+    Ok(propagation)
+}
+
+fn validate_options(options: &[String]) -> MinoResult<()> {
+    match options
+        .iter()
+        .find(|o| !PROPAGATE_OPTIONS.contains(&o.as_str()))
+    {
+        Some(unknown) => Err(MinoError::User(format!(
+            "Unknown container.propagate entry '{}'. Valid entries: {}",
+            unknown,
+            PROPAGATE_OPTIONS.join(", ")
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Derive an IANA zone name (e.g. `Europe/Berlin`) from a `/etc/localtime`
+/// symlink target such as `/usr/share/zoneinfo/Europe/Berlin`.
+fn zone_from_localtime(target: &Path) -> Option<String> {
+    let target = target.to_str()?;
+    let (_, zone) = target.split_once("zoneinfo/")?;
+    (!zone.is_empty()).then(|| zone.to_string())
+}
+
+/// Read a single value from the host's global git config.
+async fn git_config_get(key: &str) -> Option<String> {
+    let output = Command::new("git")
+        .args(["config", "--global", "--get", key])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .output()
+        .await
+        .ok()?;
+
+    let value = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (output.status.success() && !value.is_empty()).then_some(value)
+}
+
+/// Build a sanitized git config carrying only the user identity, so
+/// nothing else from the host `.gitconfig` (credential helpers, aliases,
+/// includes) reaches the container.
+fn git_identity(name: Option<String>, email: Option<String>) -> GitConfigEnv {
+    let mut config = GitConfigEnv::default();
+    for (key, value) in [("user.name", name), ("user.email", email)] {
+        if let Some(value) = value {
+            config.set(key, value);
+        }
+    }
+    config
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn validate_rejects_unknown_entry() {
+        let err = validate_options(&["timezone".to_string(), "ssh".to_string()]).unwrap_err();
+        assert!(err.to_string().contains("'ssh'"));
+        assert!(validate_options(&["locale".to_string(), "gitconfig".to_string()]).is_ok());
+    }
+
+    #[test]
+    fn zone_from_localtime_symlink_targets() {
+        assert_eq!(
+            zone_from_localtime(&PathBuf::from("/usr/share/zoneinfo/Europe/Berlin")).as_deref(),
+            Some("Europe/Berlin")
+        );
+        assert_eq!(
+            zone_from_localtime(&PathBuf::from("/var/db/timezone/zoneinfo/America/New_York"))
+                .as_deref(),
+            Some("America/New_York")
+        );
+        assert!(zone_from_localtime(&PathBuf::from("/etc/timezone")).is_none());
+    }
+
+    #[test]
+    fn git_identity_only_name_and_email() {
+        let env = git_identity(Some("Ada".into()), Some("ada@example.com".into())).to_env();
+        assert_eq!(env.get("GIT_CONFIG_COUNT").unwrap(), "2");
+        assert_eq!(env.get("GIT_CONFIG_KEY_0").unwrap(), "user.name");
+        assert_eq!(env.get("GIT_CONFIG_VALUE_0").unwrap(), "Ada");
+        assert_eq!(env.get("GIT_CONFIG_KEY_1").unwrap(), "user.email");
+        assert_eq!(env.get("GIT_CONFIG_VALUE_1").unwrap(), "ada@example.com");
+    }
+
+    #[test]
+    fn git_identity_skips_missing_values() {
+        let env = git_identity(None, Some("ada@example.com".into())).to_env();
+        assert_eq!(env.get("GIT_CONFIG_COUNT").unwrap(), "1");
+        assert_eq!(env.get("GIT_CONFIG_KEY_0").unwrap(), "user.email");
+        assert_eq!(git_identity(None, None), GitConfigEnv::default());
+    }
+
+    #[tokio::test]
+    async fn gather_nothing_by_default() {
+        let propagation = gather_propagation(&Config::default()).await.unwrap();
+        assert!(propagation.volumes.is_empty());
+        assert!(propagation.env.is_empty());
+    }
+}
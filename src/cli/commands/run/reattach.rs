@@ -0,0 +1,156 @@
+//! Re-attaching to a running container after the attach connection drops
+//!
+//! On macOS the attach goes through the VM, so a transient OrbStack or Lima
+//! restart ends `podman start --attach` even though the container keeps
+//! running. The session only ends once the container itself has exited.
+
+use crate::error::{MinoError, MinoResult};
+use crate::orchestration::ContainerRuntime;
+use console::style;
+use std::time::Duration;
+use tokio::time::sleep;
+use tracing::debug;
+
+/// Attempts to reach a container that is still up (or an unreachable runtime)
+/// before giving up
+const MAX_REATTACH_ATTEMPTS: u32 = 5;
+
+/// Base delay between attempts; grows linearly (2s, 4s, ... 30s in total)
+const REATTACH_DELAY: Duration = Duration::from_secs(2);
+
+/// Start the container attached and keep re-attaching while it is still
+/// running. Returns the container's exit code.
+pub(super) async fn attach_until_exit(
+    runtime: &dyn ContainerRuntime,
+    container_id: &str,
+    session_name: &str,
+) -> MinoResult<i32> {
+    attach_with_retry(runtime, container_id, session_name, REATTACH_DELAY).await
+}
+
+async fn attach_with_retry(
+    runtime: &dyn ContainerRuntime,
+    container_id: &str,
+    session_name: &str,
+    delay: Duration,
+) -> MinoResult<i32> {
+    let mut result = runtime.start_attached(container_id).await;
+    let mut dropped = false;
+    let mut attempts = 0;
+
+    loop {
+        let running = runtime.container_is_running(container_id).await;
+        match running {
+            Ok(false) if !dropped => return result,
+            // The code seen by a dropped attach is the connection's, not the
+            // container's; ask the runtime for the real one
+            Ok(false) => {
+                return match runtime.get_container_exit_code(container_id).await {
+                    Ok(Some(code)) => Ok(code),
+                    _ => result,
+                }
+            }
+            Ok(true) | Err(_) if attempts >= MAX_REATTACH_ATTEMPTS => break,
+            Ok(true) => {
+                dropped = true;
+                attempts += 1;
+                eprintln!(
+                    "{} Lost connection to {}; the container is still running, reattaching...",
+                    style("!").yellow(),
+                    session_name
+                );
+                sleep(delay * attempts).await;
+                result = runtime.attach(container_id).await;
+            }
+            Err(e) => {
+                // The runtime itself is unreachable (VM restarting); wait it out
+                dropped = true;
+                attempts += 1;
+                debug!("Checking container state failed: {}", e);
+                sleep(delay * attempts).await;
+            }
+        }
+    }
+
+    Err(MinoError::User(format!(
+        "Lost connection to session {} and could not reattach. It may still be running: \
+         use `mino logs {}` to follow it or `mino stop {}` to end it",
+        session_name, session_name, session_name
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::orchestration::mock::{MockResponse, MockRuntime};
+
+    #[tokio::test]
+    async fn normal_exit_does_not_reattach() {
+        let mock = MockRuntime::new().on("start_attached", Ok(MockResponse::Int(2)));
+
+        let code = attach_with_retry(&mock, "cid", "s", Duration::ZERO)
+            .await
+            .unwrap();
+        assert_eq!(code, 2);
+        mock.assert_called("container_is_running", 1);
+        mock.assert_called("attach", 0);
+        mock.assert_called("get_container_exit_code", 0);
+    }
+
+    #[tokio::test]
+    async fn dropped_attach_reattaches_until_exit() {
+        let mock = MockRuntime::new()
+            .on("start_attached", Ok(MockResponse::Int(255)))
+            .on("container_is_running", Ok(MockResponse::Bool(true)))
+            .on("attach", Ok(MockResponse::Int(7)))
+            .on("container_is_running", Ok(MockResponse::Bool(false)))
+            .on(
+                "get_container_exit_code",
+                Ok(MockResponse::OptionalInt(Some(7))),
+            );
+
+        let code = attach_with_retry(&mock, "cid", "s", Duration::ZERO)
+            .await
+            .unwrap();
+        assert_eq!(code, 7);
+        mock.assert_called("attach", 1);
+    }
+
+    #[tokio::test]
+    async fn unreachable_runtime_is_retried() {
+        let mock = MockRuntime::new()
+            .on("start_attached", Ok(MockResponse::Int(255)))
+            .on(
+                "container_is_running",
+                Err(MinoError::command_exec("podman inspect", "VM not running")),
+            )
+            .on("container_is_running", Ok(MockResponse::Bool(false)))
+            .on(
+                "get_container_exit_code",
+                Ok(MockResponse::OptionalInt(Some(0))),
+            );
+
+        let code = attach_with_retry(&mock, "cid", "s", Duration::ZERO)
+            .await
+            .unwrap();
+        assert_eq!(
+            code, 0,
+            "exit code comes from the container, not the attach"
+        );
+        mock.assert_called("attach", 0);
+    }
+
+    #[tokio::test]
+    async fn gives_up_while_container_keeps_running() {
+        let mut mock = MockRuntime::new();
+        for _ in 0..=MAX_REATTACH_ATTEMPTS {
+            mock = mock.on("container_is_running", Ok(MockResponse::Bool(true)));
+        }
+
+        let err = attach_with_retry(&mock, "cid", "s", Duration::ZERO)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("mino stop s"));
+        mock.assert_called("attach", MAX_REATTACH_ATTEMPTS as usize);
+    }
+}
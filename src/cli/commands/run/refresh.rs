@@ -0,0 +1,283 @@
+//! In-session credential refresh (`--refresh-credentials`)
+//!
+//! Cloud tokens last about an hour, so agents running longer start failing
+//! once the env vars injected at start expire. With refresh on, AWS, GCP,
+//! and Azure credentials are written to [`SessionCredentialFiles`] instead
+//! and mino re-issues them before they expire for as long as it runs.
+
+use super::credentials::record_credential_expiry;
+use crate::config::Config;
+use crate::credentials::files::{
+    SessionCredentialFiles, AWS_CONFIG_FILE, AZURE_TOKEN_FILE, GCP_TOKEN_FILE,
+};
+use crate::credentials::{AwsCredentials, AzureCredentials, CredentialCache, GcpCredentials};
+use crate::error::{MinoError, MinoResult};
+use chrono::{DateTime, Utc};
+use std::collections::{BTreeMap, HashMap};
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::{debug, warn};
+
+/// How often the refresher checks for credentials due for renewal
+const REFRESH_POLL: Duration = Duration::from_secs(60);
+
+/// Credentials are re-issued once they expire within this many minutes
+const REFRESH_LEAD_MINUTES: i64 = 15;
+
+/// Env vars replaced by the AWS credentials file
+const AWS_ENV_KEYS: &[&str] = &[
+    "AWS_ACCESS_KEY_ID",
+    "AWS_SECRET_ACCESS_KEY",
+    "AWS_SESSION_TOKEN",
+];
+
+/// Providers whose credentials are kept fresh in files
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(super) struct RefreshedProviders {
+    pub aws: bool,
+    pub gcp: bool,
+    pub azure: bool,
+}
+
+impl RefreshedProviders {
+    /// Refreshable providers among those loaded this run
+    pub(super) fn from_active(active: &[String]) -> Self {
+        let has = |name: &str| active.iter().any(|p| p == name);
+        Self {
+            aws: has("aws"),
+            gcp: has("gcp"),
+            azure: has("azure"),
+        }
+    }
+
+    pub(super) fn is_empty(&self) -> bool {
+        !(self.aws || self.gcp || self.azure)
+    }
+
+    /// Provider names, for the audit log
+    pub(super) fn names(&self) -> Vec<&'static str> {
+        [("aws", self.aws), ("gcp", self.gcp), ("azure", self.azure)]
+            .into_iter()
+            .filter(|(_, on)| *on)
+            .map(|(name, _)| name)
+            .collect()
+    }
+}
+
+/// Credential files of a session and what keeps them fresh
+pub(super) struct CredentialRefresh {
+    pub files: SessionCredentialFiles,
+    pub providers: RefreshedProviders,
+    session_name: String,
+    config: Config,
+}
+
+impl CredentialRefresh {
+    /// Write the initial files for `session_name` and point `env` at them
+    /// in place of the token env vars.
+    pub(super) async fn setup(
+        session_name: &str,
+        providers: RefreshedProviders,
+        config: &Config,
+        env: &mut HashMap<String, String>,
+    ) -> MinoResult<Self> {
+        let refresh = Self {
+            files: SessionCredentialFiles::for_session(session_name),
+            providers,
+            session_name: session_name.to_string(),
+            config: config.clone(),
+        };
+        refresh.files.create().await?;
+        if let Some((provider, e)) = refresh.write_files().await.into_iter().next() {
+            let _ = refresh.files.remove().await;
+            return Err(MinoError::User(format!(
+                "Writing {} credential files failed: {}",
+                provider, e
+            )));
+        }
+        apply_file_env(env, providers);
+        Ok(refresh)
+    }
+
+    /// Keep the files fresh until the returned task is aborted
+    pub(super) fn spawn(&self) -> JoinHandle<()> {
+        let refresh = Self {
+            files: self.files.clone(),
+            providers: self.providers,
+            session_name: self.session_name.clone(),
+            config: self.config.clone(),
+        };
+        tokio::spawn(async move {
+            let mut failing: Vec<&str> = Vec::new();
+            let mut recorded = BTreeMap::new();
+            loop {
+                tokio::time::sleep(REFRESH_POLL).await;
+                let failures = refresh.write_files().await;
+                // Warn once per failure streak, not on every poll
+                for (provider, e) in &failures {
+                    if !failing.contains(provider) {
+                        warn!("Refreshing {} credentials failed: {}", provider, e);
+                    }
+                }
+                failing = failures.iter().map(|(provider, _)| *provider).collect();
+
+                let expiry = refresh.expiry().await;
+                if expiry != recorded {
+                    record_credential_expiry(&refresh.session_name, &expiry).await;
+                    recorded = expiry;
+                }
+            }
+        })
+    }
+
+    /// When the credentials now in the files expire
+    async fn expiry(&self) -> BTreeMap<String, DateTime<Utc>> {
+        let mut expiry = BTreeMap::new();
+        let Ok(cache) = CredentialCache::new().await else {
+            return expiry;
+        };
+        for provider in self.providers.names() {
+            if let Ok(Some(expires_at)) = cache.expires_at(provider).await {
+                expiry.insert(provider.to_string(), expires_at);
+            }
+        }
+        expiry
+    }
+
+    /// Re-issue credentials due for renewal and rewrite their files.
+    /// Returns the providers that failed.
+    async fn write_files(&self) -> Vec<(&'static str, MinoError)> {
+        let cache = match CredentialCache::new().await {
+            Ok(cache) => cache.with_min_validity(chrono::Duration::minutes(REFRESH_LEAD_MINUTES)),
+            Err(e) => return vec![("all", e)],
+        };
+        let credentials = &self.config.credentials;
+        let mut failures = Vec::new();
+
+        if self.providers.aws {
+            // An MFA code can't be asked for here; the write fails until
+            // the next interactive `mino run` caches new credentials
+            let result =
+                match AwsCredentials::get_session_token(&credentials.aws, &cache, None).await {
+                    Ok(creds) => {
+                        self.files
+                            .write_aws(&creds, credentials.aws.region.as_deref())
+                            .await
+                    }
+                    Err(e) => Err(e),
+                };
+            if let Err(e) = result {
+                failures.push(("AWS", e));
+            }
+        }
+
+        if self.providers.gcp {
+            let result = match GcpCredentials::get_access_token(&credentials.gcp, &cache).await {
+                Ok(token) => self.files.write_token(GCP_TOKEN_FILE, &token).await,
+                Err(e) => Err(e),
+            };
+            if let Err(e) = result {
+                failures.push(("GCP", e));
+            }
+        }
+
+        if self.providers.azure {
+            let result = match AzureCredentials::get_access_token(&credentials.azure, &cache).await
+            {
+                Ok(token) => self.files.write_token(AZURE_TOKEN_FILE, &token).await,
+                Err(e) => Err(e),
+            };
+            if let Err(e) = result {
+                failures.push(("Azure", e));
+            }
+        }
+
+        debug!("Credential files of {} checked", self.files.dir().display());
+        failures
+    }
+}
+
+/// Swap token env vars for pointers to the credential files. Azure has no
+/// standard token file, so `AZURE_ACCESS_TOKEN` keeps the initial token
+/// alongside `AZURE_ACCESS_TOKEN_FILE`.
+fn apply_file_env(env: &mut HashMap<String, String>, providers: RefreshedProviders) {
+    if providers.aws {
+        for key in AWS_ENV_KEYS {
+            env.remove(*key);
+        }
+        env.insert(
+            "AWS_CONFIG_FILE".to_string(),
+            SessionCredentialFiles::container_path(AWS_CONFIG_FILE),
+        );
+        // The v2 JavaScript SDK only reads the config file when asked to
+        env.insert("AWS_SDK_LOAD_CONFIG".to_string(), "1".to_string());
+    }
+    if providers.gcp {
+        env.remove("CLOUDSDK_AUTH_ACCESS_TOKEN");
+        env.insert(
+            "CLOUDSDK_AUTH_ACCESS_TOKEN_FILE".to_string(),
+            SessionCredentialFiles::container_path(GCP_TOKEN_FILE),
+        );
+    }
+    if providers.azure {
+        env.insert(
+            "AZURE_ACCESS_TOKEN_FILE".to_string(),
+            SessionCredentialFiles::container_path(AZURE_TOKEN_FILE),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_cloud_providers_are_refreshed() {
+        let active = vec!["aws".to_string(), "github".to_string()];
+        let providers = RefreshedProviders::from_active(&active);
+        assert_eq!(
+            providers,
+            RefreshedProviders {
+                aws: true,
+                gcp: false,
+                azure: false,
+            }
+        );
+        assert_eq!(providers.names(), ["aws"]);
+        assert!(RefreshedProviders::from_active(&["github".to_string()]).is_empty());
+    }
+
+    #[test]
+    fn file_env_replaces_token_vars() {
+        let mut env = HashMap::from([
+            ("AWS_ACCESS_KEY_ID".to_string(), "ASIA".to_string()),
+            ("AWS_SECRET_ACCESS_KEY".to_string(), "secret".to_string()),
+            ("AWS_REGION".to_string(), "eu-west-1".to_string()),
+            ("CLOUDSDK_AUTH_ACCESS_TOKEN".to_string(), "ya29".to_string()),
+            ("AZURE_ACCESS_TOKEN".to_string(), "eyJ".to_string()),
+        ]);
+        apply_file_env(
+            &mut env,
+            RefreshedProviders {
+                aws: true,
+                gcp: true,
+                azure: true,
+            },
+        );
+
+        assert!(!env.contains_key("AWS_ACCESS_KEY_ID"));
+        assert!(!env.contains_key("AWS_SECRET_ACCESS_KEY"));
+        assert_eq!(env["AWS_REGION"], "eu-west-1");
+        assert_eq!(env["AWS_CONFIG_FILE"], "/run/mino/credentials/aws-config");
+        assert!(!env.contains_key("CLOUDSDK_AUTH_ACCESS_TOKEN"));
+        assert_eq!(
+            env["CLOUDSDK_AUTH_ACCESS_TOKEN_FILE"],
+            "/run/mino/credentials/gcp-token"
+        );
+        assert_eq!(env["AZURE_ACCESS_TOKEN"], "eyJ");
+        assert_eq!(
+            env["AZURE_ACCESS_TOKEN_FILE"],
+            "/run/mino/credentials/azure-token"
+        );
+    }
+}
@@ -0,0 +1,311 @@
+//! Rollback of resources left behind by a failed session start
+
+use crate::audit::AuditLog;
+use crate::credentials::deploy_key::SessionDeployKey;
+use crate::credentials::files::SessionCredentialFiles;
+use crate::error::MinoResult;
+use crate::labels;
+use crate::orchestration::ContainerRuntime;
+use crate::worktree;
+use std::path::PathBuf;
+use tracing::{debug, warn};
+
+/// Session-scoped resources created while starting a session.
+///
+/// Shared resources (home volume, dependency caches, the session network)
+/// are never recorded: other sessions may be using them, and an incomplete
+/// cache resumes building on the next run.
+#[derive(Debug, Default)]
+pub(super) struct StartArtifacts {
+    /// Volumes this start created (not reused)
+    pub volumes: Vec<String>,
+    /// Pod created for `[services]`
+    pub pod: Option<String>,
+    /// Git worktree created for `--worktree`
+    pub worktree: Option<PathBuf>,
+    /// Credential files written for `--refresh-credentials` or file delivery
+    pub credential_files: Option<SessionCredentialFiles>,
+    /// Deploy key and agent created for `--deploy-key`
+    pub deploy_key: Option<SessionDeployKey>,
+}
+
+/// What a rollback removed, for the audit log
+#[derive(Debug, Default)]
+pub(super) struct RollbackReport {
+    /// Containers removed
+    pub containers: Vec<String>,
+    /// Volumes removed
+    pub volumes: Vec<String>,
+    /// Pod removed, with the service containers in it
+    pub pod: Option<String>,
+    /// Worktree removed (its branch is kept)
+    pub worktree: Option<PathBuf>,
+    /// Resources that could not be removed, with the reason
+    pub failed: Vec<String>,
+}
+
+impl RollbackReport {
+    /// Whether anything was found to clean up
+    pub fn is_empty(&self) -> bool {
+        self.containers.is_empty()
+            && self.volumes.is_empty()
+            && self.pod.is_none()
+            && self.worktree.is_none()
+            && self.failed.is_empty()
+    }
+}
+
+/// Remove containers labeled with the session, then the volumes this start
+/// created. Best-effort: failures are reported, never returned.
+///
+/// Containers are found by label because a failed `run` may have created
+/// one without returning its ID.
+pub(super) async fn rollback_start(
+    runtime: &dyn ContainerRuntime,
+    session_name: &str,
+    artifacts: &StartArtifacts,
+) -> RollbackReport {
+    let mut report = RollbackReport::default();
+
+    let selector = format!("{}={}", labels::SESSION, session_name);
+    match runtime.container_list_labeled(&selector).await {
+        Ok(containers) => {
+            for id in containers {
+                let short = &id[..12.min(id.len())];
+                match runtime.remove(&id).await {
+                    Ok(()) => {
+                        debug!("Rolled back container {}", short);
+                        report.containers.push(id);
+                    }
+                    Err(e) => {
+                        warn!("Failed to remove container {}: {}", short, e);
+                        report.failed.push(format!("container {}: {}", short, e));
+                    }
+                }
+            }
+        }
+        Err(e) => {
+            warn!(
+                "Failed to list containers of session {}: {}",
+                session_name, e
+            );
+            report.failed.push(format!("container list: {}", e));
+        }
+    }
+
+    if let Some(pod) = &artifacts.pod {
+        match runtime.pod_remove(pod).await {
+            Ok(()) => {
+                debug!("Rolled back pod {}", pod);
+                report.pod = Some(pod.clone());
+            }
+            Err(e) => {
+                warn!("Failed to remove pod {}: {}", pod, e);
+                report.failed.push(format!("pod {}: {}", pod, e));
+            }
+        }
+    }
+
+    if let Some(path) = &artifacts.worktree {
+        match worktree::remove(path).await {
+            Ok(()) => {
+                debug!("Rolled back worktree {}", path.display());
+                report.worktree = Some(path.clone());
+            }
+            Err(e) => {
+                warn!("Failed to remove worktree {}: {}", path.display(), e);
+                report
+                    .failed
+                    .push(format!("worktree {}: {}", path.display(), e));
+            }
+        }
+    }
+
+    if let Some(files) = &artifacts.credential_files {
+        if let Err(e) = files.remove().await {
+            warn!("Failed to remove credential files: {}", e);
+            report.failed.push(format!("credential files: {}", e));
+        }
+    }
+
+    if let Some(deploy_key) = &artifacts.deploy_key {
+        if let Err(e) = deploy_key.remove().await {
+            warn!("{}", e);
+            report.failed.push(format!("deploy key: {}", e));
+        }
+    }
+
+    // Volumes last: a container still holding one would block its removal
+    for volume in &artifacts.volumes {
+        match runtime.volume_remove(volume).await {
+            Ok(()) => {
+                debug!("Rolled back volume {}", volume);
+                report.volumes.push(volume.clone());
+            }
+            Err(e) => {
+                warn!("Failed to remove volume {}: {}", volume, e);
+                report.failed.push(format!("volume {}: {}", volume, e));
+            }
+        }
+    }
+
+    report
+}
+
+/// Roll back what a start created and record the rollback in the audit log
+pub(super) async fn rollback_and_audit(
+    runtime: &dyn ContainerRuntime,
+    audit: &AuditLog,
+    session_name: &str,
+    artifacts: &StartArtifacts,
+) {
+    let report = rollback_start(runtime, session_name, artifacts).await;
+    if !report.is_empty() {
+        audit
+            .log(
+                "session.rollback",
+                &serde_json::json!({
+                    "name": session_name,
+                    "containers": report.containers,
+                    "volumes": report.volumes,
+                    "pod": report.pod,
+                    "worktree": report.worktree,
+                    "failed": report.failed,
+                }),
+            )
+            .await;
+    }
+}
+
+/// Pass `result` through, rolling back what the start created first when
+/// it is an error
+pub(super) async fn rollback_on_error<T>(
+    runtime: &dyn ContainerRuntime,
+    audit: &AuditLog,
+    session_name: &str,
+    artifacts: &StartArtifacts,
+    result: MinoResult<T>,
+) -> MinoResult<T> {
+    if result.is_err() {
+        rollback_and_audit(runtime, audit, session_name, artifacts).await;
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::hooks::{run_pre_hooks, HookContext};
+    use super::*;
+    use crate::error::MinoError;
+    use crate::orchestration::mock::{MockResponse, MockRuntime};
+
+    fn artifacts(volumes: &[&str]) -> StartArtifacts {
+        StartArtifacts {
+            volumes: volumes.iter().map(|v| v.to_string()).collect(),
+            pod: None,
+            worktree: None,
+            credential_files: None,
+            deploy_key: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn rollback_removes_session_containers_and_created_volumes() {
+        let mock = MockRuntime::new().on(
+            "container_list_labeled",
+            Ok(MockResponse::StringVec(vec!["abc123".to_string()])),
+        );
+
+        let report =
+            rollback_start(&mock, "swift-fox", &artifacts(&["mino-scratch-swift-fox"])).await;
+
+        mock.assert_called_with("container_list_labeled", &["io.mino.session=swift-fox"]);
+        mock.assert_called_with("remove", &["abc123"]);
+        mock.assert_called_with("volume_remove", &["mino-scratch-swift-fox"]);
+        assert_eq!(report.containers, vec!["abc123"]);
+        assert_eq!(report.volumes, vec!["mino-scratch-swift-fox"]);
+        assert!(report.failed.is_empty());
+    }
+
+    #[tokio::test]
+    async fn rollback_removes_service_pod() {
+        let mock = MockRuntime::new();
+        let artifacts = StartArtifacts {
+            volumes: vec![],
+            pod: Some("mino-pod-swift-fox".to_string()),
+            worktree: None,
+            credential_files: None,
+            deploy_key: None,
+        };
+
+        let report = rollback_start(&mock, "swift-fox", &artifacts).await;
+
+        mock.assert_called_with("pod_remove", &["mino-pod-swift-fox"]);
+        assert_eq!(report.pod.as_deref(), Some("mino-pod-swift-fox"));
+        assert!(!report.is_empty());
+    }
+
+    #[tokio::test]
+    async fn rollback_with_nothing_created_is_empty() {
+        let mock = MockRuntime::new();
+
+        let report = rollback_start(&mock, "swift-fox", &StartArtifacts::default()).await;
+
+        assert!(report.is_empty());
+        mock.assert_called("remove", 0);
+        mock.assert_called("volume_remove", 0);
+    }
+
+    #[tokio::test]
+    async fn rollback_is_best_effort() {
+        let mock = MockRuntime::new()
+            .on_err(
+                "container_list_labeled",
+                MinoError::Internal("daemon unreachable".to_string()),
+            )
+            .on_err("volume_remove", MinoError::Internal("in use".to_string()));
+
+        let report = rollback_start(&mock, "swift-fox", &artifacts(&["v1", "v2"])).await;
+
+        // The second volume is still attempted after the first fails
+        mock.assert_called("volume_remove", 2);
+        assert_eq!(report.volumes, vec!["v2"]);
+        assert_eq!(report.failed.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn failed_pre_run_hook_rolls_back_scratch_and_credential_files() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let files = SessionCredentialFiles::in_dir(temp.path().join("swift-fox"));
+        files.create().await.unwrap();
+        files.write_token("gcp-token", "secret").await.unwrap();
+        let artifacts = StartArtifacts {
+            credential_files: Some(files.clone()),
+            ..artifacts(&["mino-scratch-swift-fox"])
+        };
+        let mut config = crate::config::Config::default();
+        config.general.audit_log = false;
+        let mock = MockRuntime::new();
+
+        let hook_ctx = HookContext {
+            session_name: "swift-fox",
+            project_dir: temp.path(),
+            branch: "main",
+            image: "fedora:43",
+            exit_code: None,
+        };
+        let result = run_pre_hooks(&["exit 1".to_string()], &hook_ctx).await;
+        let result = rollback_on_error(
+            &mock,
+            &AuditLog::new(&config),
+            "swift-fox",
+            &artifacts,
+            result,
+        )
+        .await;
+
+        assert!(result.is_err());
+        mock.assert_called_with("volume_remove", &["mino-scratch-swift-fox"]);
+        assert!(!files.dir().exists());
+    }
+}
@@ -0,0 +1,210 @@
+//! Scratch volume setup for per-session persistent scratch space
+
+use crate::cache::{format_bytes, gb_to_bytes};
+use crate::cli::args::RunArgs;
+use crate::config::Config;
+use crate::error::{MinoError, MinoResult};
+use crate::orchestration::ContainerRuntime;
+use crate::scratch::{self, ScratchVolume, SCRATCH_MOUNT};
+use crate::ui::{self, UiContext};
+use tracing::debug;
+
+use super::rollback::StartArtifacts;
+
+/// Set up the session's scratch volume, if enabled.
+///
+/// Returns `Some("volume_name:/scratch")` when a scratch volume should be
+/// mounted, or `None` when scratch space is disabled. A newly created volume
+/// is recorded in `artifacts`. A reused volume larger than
+/// `scratch.max_size_gb` is refused.
+pub(super) async fn setup_scratch_volume(
+    ctx: &UiContext,
+    runtime: &dyn ContainerRuntime,
+    args: &RunArgs,
+    config: &Config,
+    session_name: &str,
+    artifacts: &mut StartArtifacts,
+) -> MinoResult<Option<String>> {
+    if !args.scratch && !config.scratch.enabled {
+        debug!("Scratch volume disabled by flag/config");
+        return Ok(None);
+    }
+
+    let volume_name = scratch::scratch_volume_name(session_name);
+
+    if runtime.volume_inspect(&volume_name).await?.is_some() {
+        debug!("Reusing existing scratch volume: {}", volume_name);
+        check_scratch_size(ctx, runtime, config, &volume_name).await?;
+    } else {
+        debug!("Creating scratch volume: {}", volume_name);
+        let labels = ScratchVolume::labels(session_name);
+        runtime.volume_create(&volume_name, &labels).await?;
+        artifacts.volumes.push(volume_name.clone());
+    }
+
+    Ok(Some(format!("{}:{}", volume_name, SCRATCH_MOUNT)))
+}
+
+/// Refuse a reused scratch volume that exceeds `scratch.max_size_gb`.
+async fn check_scratch_size(
+    ctx: &UiContext,
+    runtime: &dyn ContainerRuntime,
+    config: &Config,
+    volume_name: &str,
+) -> MinoResult<()> {
+    let limit_bytes = gb_to_bytes(config.scratch.max_size_gb);
+    if limit_bytes == 0 {
+        return Ok(());
+    }
+
+    let sizes = match runtime
+        .volume_disk_usage(scratch::labels::MINO_SCRATCH)
+        .await
+    {
+        Ok(sizes) => sizes,
+        Err(e) => {
+            ui::step_warn(
+                ctx,
+                &format!("Cannot check the size of scratch volume {volume_name}: {e}"),
+            );
+            return Ok(());
+        }
+    };
+
+    let size = sizes.get(volume_name).copied().unwrap_or(0);
+    if size > limit_bytes {
+        return Err(MinoError::User(format!(
+            "Scratch volume {} is {} (limit {}). Run: mino cache clear --scratch, \
+             or raise scratch.max_size_gb",
+            volume_name,
+            format_bytes(size),
+            format_bytes(limit_bytes)
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_run_args;
+    use super::*;
+    use crate::orchestration::mock::{MockResponse, MockRuntime};
+    use crate::orchestration::VolumeInfo;
+    use std::collections::HashMap;
+
+    #[tokio::test]
+    async fn setup_disabled_by_default() {
+        let mock = MockRuntime::new();
+        let result = setup_scratch_volume(
+            &UiContext::non_interactive(),
+            &mock,
+            &test_run_args(),
+            &Config::default(),
+            "s1",
+            &mut StartArtifacts::default(),
+        )
+        .await
+        .unwrap();
+
+        assert!(result.is_none());
+        mock.assert_called("volume_inspect", 0);
+    }
+
+    #[tokio::test]
+    async fn setup_creates_volume_on_miss() {
+        let mock = MockRuntime::new();
+        let mut args = test_run_args();
+        args.scratch = true;
+
+        let mut artifacts = StartArtifacts::default();
+
+        let result = setup_scratch_volume(
+            &UiContext::non_interactive(),
+            &mock,
+            &args,
+            &Config::default(),
+            "s1",
+            &mut artifacts,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(result.as_deref(), Some("mino-scratch-s1:/scratch"));
+        mock.assert_called("volume_create", 1);
+        assert_eq!(artifacts.volumes, vec!["mino-scratch-s1"]);
+    }
+
+    #[tokio::test]
+    async fn setup_reuses_existing_volume() {
+        let vol = VolumeInfo {
+            name: "mino-scratch-s1".to_string(),
+            labels: ScratchVolume::labels("s1"),
+            mountpoint: None,
+            created_at: None,
+            size_bytes: None,
+        };
+        let mock = MockRuntime::new().on(
+            "volume_inspect",
+            Ok(MockResponse::OptionalVolumeInfo(Some(vol))),
+        );
+        let mut config = Config::default();
+        config.scratch.enabled = true;
+
+        let mut artifacts = StartArtifacts::default();
+
+        let result = setup_scratch_volume(
+            &UiContext::non_interactive(),
+            &mock,
+            &test_run_args(),
+            &config,
+            "s1",
+            &mut artifacts,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(result.as_deref(), Some("mino-scratch-s1:/scratch"));
+        mock.assert_called("volume_create", 0);
+        assert!(artifacts.volumes.is_empty());
+        mock.assert_called("volume_disk_usage", 1);
+    }
+
+    #[tokio::test]
+    async fn setup_refuses_volume_over_limit() {
+        let vol = VolumeInfo {
+            name: "mino-scratch-s1".to_string(),
+            labels: ScratchVolume::labels("s1"),
+            mountpoint: None,
+            created_at: None,
+            size_bytes: None,
+        };
+        let mock = MockRuntime::new()
+            .on(
+                "volume_inspect",
+                Ok(MockResponse::OptionalVolumeInfo(Some(vol))),
+            )
+            .on(
+                "volume_disk_usage",
+                Ok(MockResponse::DiskUsageMap(HashMap::from([(
+                    "mino-scratch-s1".to_string(),
+                    gb_to_bytes(3),
+                )]))),
+            );
+        let mut config = Config::default();
+        config.scratch.enabled = true;
+        config.scratch.max_size_gb = 2;
+
+        let err = setup_scratch_volume(
+            &UiContext::non_interactive(),
+            &mock,
+            &test_run_args(),
+            &config,
+            "s1",
+            &mut StartArtifacts::default(),
+        )
+        .await
+        .unwrap_err();
+
+        assert!(matches!(err, MinoError::User(_)), "{err}");
+    }
+}
@@ -0,0 +1,240 @@
+//! Service containers (`[services]`) sharing a pod with the session
+//!
+//! The session's main container and every service join one Podman pod, so
+//! services are reachable on `localhost:<port>` from the main container and
+//! all of them share the session's network mode, DNS alias, and user
+//! namespace. Removing the pod tears every service down with it.
+
+use crate::config::schema::ServiceConfig;
+use crate::config::Config;
+use crate::error::{MinoError, MinoResult};
+use crate::labels;
+use crate::orchestration::{ContainerConfig, ContainerRuntime, PodSpec};
+use std::collections::{BTreeMap, HashMap};
+use tracing::{debug, warn};
+
+/// Services started for a session
+#[derive(Debug)]
+pub(super) struct StartedServices {
+    /// Pod the session's containers share
+    pub pod: String,
+    /// Service name -> container ID
+    pub containers: BTreeMap<String, String>,
+}
+
+/// Name of a session's pod
+fn pod_name(session_name: &str) -> String {
+    format!("mino-pod-{}", session_name)
+}
+
+/// Create the session's pod and start every configured service in it, then
+/// move the main container into the pod. Returns `None` without services.
+///
+/// On failure the pod is removed again, along with any service already
+/// started in it.
+pub(super) async fn start_services(
+    runtime: &dyn ContainerRuntime,
+    config: &Config,
+    session_name: &str,
+    container_config: &mut ContainerConfig,
+) -> MinoResult<Option<StartedServices>> {
+    if config.services.is_empty() {
+        return Ok(None);
+    }
+    for (name, service) in &config.services {
+        if service.image.is_empty() {
+            return Err(MinoError::User(format!(
+                "services.{}.image is required",
+                name
+            )));
+        }
+    }
+
+    let pod = pod_name(session_name);
+    runtime
+        .pod_create(&PodSpec {
+            name: pod.clone(),
+            network: container_config.network.clone(),
+            network_aliases: container_config.network_aliases.clone(),
+            ports: config
+                .services
+                .values()
+                .flat_map(|s| s.ports.iter().cloned())
+                .collect(),
+            userns: container_config.userns.clone(),
+            hostname: container_config.hostname.clone(),
+            extra_hosts: container_config.extra_hosts.clone(),
+            labels: container_config.labels.clone(),
+        })
+        .await?;
+
+    let mut containers = BTreeMap::new();
+    for (name, service) in &config.services {
+        debug!("Starting service {} ({})", name, service.image);
+        let service_config =
+            service_container_config(name, service, &pod, &container_config.labels);
+        match runtime.run(&service_config, &[]).await {
+            Ok(id) => {
+                containers.insert(name.clone(), id);
+            }
+            Err(e) => {
+                if let Err(remove_err) = runtime.pod_remove(&pod).await {
+                    warn!("Failed to remove pod {}: {}", pod, remove_err);
+                }
+                return Err(MinoError::User(format!(
+                    "Failed to start service {}: {}",
+                    name, e
+                )));
+            }
+        }
+    }
+
+    container_config.pod = Some(pod.clone());
+    Ok(Some(StartedServices { pod, containers }))
+}
+
+/// Container config for a service: its own image, env, and labels, with
+/// the image's defaults (user, workdir, command) left alone.
+fn service_container_config(
+    name: &str,
+    service: &ServiceConfig,
+    pod: &str,
+    session_labels: &HashMap<String, String>,
+) -> ContainerConfig {
+    let mut labels = session_labels.clone();
+    labels.insert(labels::SERVICE.to_string(), name.to_string());
+
+    ContainerConfig {
+        image: service.image.clone(),
+        workdir: String::new(),
+        volumes: vec![],
+        env: service.env.clone(),
+        network: String::new(),
+        network_aliases: vec![],
+        interactive: false,
+        tty: false,
+        cap_add: vec![],
+        cap_drop: vec![],
+        security_opt: vec!["no-new-privileges".to_string()],
+        pids_limit: 0,
+        ulimits: vec![],
+        cpus: 0.0,
+        memory_mb: 0,
+        gpus: false,
+        devices: vec![],
+        auto_remove: false,
+        read_only: false,
+        tmpfs: vec![],
+        user: None,
+        userns: None,
+        restart: None,
+        labels,
+        pod: Some(pod.to_string()),
+        platform: None,
+        hostname: None,
+        extra_hosts: vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::orchestration::mock::{test_container_config, MockResponse, MockRuntime};
+
+    fn config_with_services() -> Config {
+        let mut config = Config::default();
+        config.services.insert(
+            "db".to_string(),
+            ServiceConfig {
+                image: "postgres:16".to_string(),
+                env: HashMap::from([("POSTGRES_PASSWORD".to_string(), "dev".to_string())]),
+                ports: vec!["5432:5432".to_string()],
+            },
+        );
+        config.services.insert(
+            "cache".to_string(),
+            ServiceConfig {
+                image: "redis:7".to_string(),
+                ..Default::default()
+            },
+        );
+        config
+    }
+
+    #[tokio::test]
+    async fn no_services_creates_no_pod() {
+        let mock = MockRuntime::new();
+        let mut container = test_container_config();
+
+        let started = start_services(&mock, &Config::default(), "s", &mut container)
+            .await
+            .unwrap();
+        assert!(started.is_none());
+        assert!(container.pod.is_none());
+        mock.assert_no_calls();
+    }
+
+    #[tokio::test]
+    async fn services_start_in_the_session_pod() {
+        let mock = MockRuntime::new()
+            .on("run", Ok(MockResponse::String("cache-id".to_string())))
+            .on("run", Ok(MockResponse::String("db-id".to_string())));
+        let mut container = test_container_config();
+
+        let started = start_services(&mock, &config_with_services(), "s", &mut container)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(started.pod, "mino-pod-s");
+        assert_eq!(started.containers["cache"], "cache-id");
+        assert_eq!(started.containers["db"], "db-id");
+        assert_eq!(container.pod.as_deref(), Some("mino-pod-s"));
+        mock.assert_called_with(
+            "pod_create",
+            &[
+                "pod",
+                "create",
+                "--name",
+                "mino-pod-s",
+                "--network",
+                "bridge",
+                "--publish",
+                "5432:5432",
+            ],
+        );
+        mock.assert_called("run", 2);
+    }
+
+    #[tokio::test]
+    async fn failed_service_removes_the_pod() {
+        let mock = MockRuntime::new()
+            .on("run", Ok(MockResponse::String("cache-id".to_string())))
+            .on_err("run", MinoError::ContainerStart("bad image".to_string()));
+        let mut container = test_container_config();
+
+        let err = start_services(&mock, &config_with_services(), "s", &mut container)
+            .await
+            .unwrap_err();
+
+        assert!(err.to_string().contains("service db"));
+        mock.assert_called_with("pod_remove", &["mino-pod-s"]);
+        assert!(container.pod.is_none());
+    }
+
+    #[test]
+    fn service_config_keeps_image_defaults() {
+        let service = ServiceConfig {
+            image: "redis:7".to_string(),
+            ..Default::default()
+        };
+        let config = service_container_config("cache", &service, "mino-pod-s", &HashMap::new());
+
+        let mut args = Vec::new();
+        config.push_args(&mut args, &[]);
+        assert!(!args.contains(&"-w".to_string()));
+        assert!(!args.contains(&"--network".to_string()));
+        assert!(args.contains(&"io.mino.service=cache".to_string()));
+        assert_eq!(args.last().unwrap(), "redis:7");
+    }
+}
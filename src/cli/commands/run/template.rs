@@ -0,0 +1,240 @@
+//! `{project}`, `{branch}`, and `{session}` placeholders in configured commands
+//!
+//! Expanded in `session.command` and in `pre_run` / `post_run` hooks once the
+//! session name is known. Commands typed on the CLI are never expanded. Hook
+//! scripts get env var references instead of the values, so a hostile branch
+//! name cannot inject shell syntax.
+
+use std::path::Path;
+use std::process::Stdio;
+use tokio::process::Command;
+
+/// Values substituted for placeholders
+pub(super) struct TemplateVars<'a> {
+    /// Project directory name
+    pub project: &'a str,
+    /// Current git branch of the project (empty outside a repo or when detached)
+    pub branch: &'a str,
+    /// Session name
+    pub session: &'a str,
+}
+
+impl TemplateVars<'_> {
+    fn lookup(&self, name: &str) -> Option<&str> {
+        match name {
+            "project" => Some(self.project),
+            "branch" => Some(self.branch),
+            "session" => Some(self.session),
+            _ => None,
+        }
+    }
+
+    /// Replace known placeholders in `template`; anything else in braces
+    /// (`{}`, `${HOME}`, `{unknown}`) is left as written.
+    pub fn expand(&self, template: &str) -> String {
+        let mut out = String::with_capacity(template.len());
+        let mut rest = template;
+        while let Some(start) = rest.find('{') {
+            out.push_str(&rest[..start]);
+            let after = &rest[start + 1..];
+            let value = after
+                .find('}')
+                .and_then(|end| self.lookup(&after[..end]).map(|v| (end, v)));
+            match value {
+                Some((end, value)) => {
+                    out.push_str(value);
+                    rest = &after[end + 1..];
+                }
+                None => {
+                    out.push('{');
+                    rest = after;
+                }
+            }
+        }
+        out.push_str(rest);
+        out
+    }
+
+    /// Env vars holding the values that [`expand_shell`](Self::expand_shell)
+    /// refers to; export them to the shell running the script
+    pub fn shell_env(&self) -> [(&'static str, String); 3] {
+        [
+            ("MINO_PROJECT", self.project.to_string()),
+            ("MINO_BRANCH", self.branch.to_string()),
+            ("MINO_SESSION", self.session.to_string()),
+        ]
+    }
+
+    /// Like [`expand`](Self::expand), for scripts run by `sh -c`: each
+    /// placeholder becomes a reference to its [`shell_env`](Self::shell_env)
+    /// variable, quoted for the context it appears in. Values are never part
+    /// of the script text, so branch names with `$(...)` or `;` stay inert
+    /// even inside the user's own quotes.
+    pub fn expand_shell(&self, template: &str) -> String {
+        #[derive(PartialEq)]
+        enum Quote {
+            None,
+            Single,
+            Double,
+        }
+
+        let mut out = String::with_capacity(template.len());
+        let mut quote = Quote::None;
+        let mut rest = template;
+        while let Some(c) = rest.chars().next() {
+            if c == '{' {
+                let var = rest[1..]
+                    .find('}')
+                    .and_then(|end| shell_var(&rest[1..1 + end]).map(|var| (end, var)));
+                if let Some((end, var)) = var {
+                    match quote {
+                        Quote::None => out.push_str(&format!("\"${}\"", var)),
+                        Quote::Double => out.push_str(&format!("${{{}}}", var)),
+                        Quote::Single => out.push_str(&format!("'\"${}\"'", var)),
+                    }
+                    rest = &rest[end + 2..];
+                    continue;
+                }
+            }
+
+            match (c, &quote) {
+                ('\'', Quote::None) => quote = Quote::Single,
+                ('\'', Quote::Single) => quote = Quote::None,
+                ('"', Quote::None) => quote = Quote::Double,
+                ('"', Quote::Double) => quote = Quote::None,
+                ('\\', Quote::None | Quote::Double) => {
+                    // Copy the escaped character verbatim
+                    let len = rest[1..].chars().next().map_or(0, char::len_utf8);
+                    out.push_str(&rest[..1 + len]);
+                    rest = &rest[1 + len..];
+                    continue;
+                }
+                _ => {}
+            }
+            out.push(c);
+            rest = &rest[c.len_utf8()..];
+        }
+        out
+    }
+}
+
+/// Env var standing in for a placeholder in shell scripts
+fn shell_var(name: &str) -> Option<&'static str> {
+    match name {
+        "project" => Some("MINO_PROJECT"),
+        "branch" => Some("MINO_BRANCH"),
+        "session" => Some("MINO_SESSION"),
+        _ => None,
+    }
+}
+
+/// Project name for `{project}`: the directory's final component
+pub(super) fn project_name(project_dir: &Path) -> String {
+    project_dir
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+/// Branch checked out in `project_dir`, or empty outside a git repository
+/// and on a detached HEAD
+pub(super) async fn current_branch(project_dir: &Path) -> String {
+    let output = Command::new("git")
+        .args(["branch", "--show-current"])
+        .current_dir(project_dir)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .output()
+        .await;
+    match output {
+        Ok(out) if out.status.success() => String::from_utf8_lossy(&out.stdout).trim().to_string(),
+        _ => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars<'a>(branch: &'a str) -> TemplateVars<'a> {
+        TemplateVars {
+            project: "my-app",
+            branch,
+            session: "swift-fox",
+        }
+    }
+
+    #[test]
+    fn expands_known_placeholders() {
+        assert_eq!(
+            vars("main").expand("claude --session {session} # {project}@{branch}"),
+            "claude --session swift-fox # my-app@main"
+        );
+    }
+
+    #[test]
+    fn leaves_other_braces_alone() {
+        assert_eq!(
+            vars("main").expand("find . -exec rm {} ; echo ${HOME} {nope} {session"),
+            "find . -exec rm {} ; echo ${HOME} {nope} {session"
+        );
+    }
+
+    #[test]
+    fn values_are_not_re_expanded() {
+        assert_eq!(vars("{session}").expand("{branch}"), "{session}");
+    }
+
+    #[test]
+    fn shell_expansion_references_env_vars_per_quote_context() {
+        let v = vars("main");
+        assert_eq!(
+            v.expand_shell("git log origin/{branch}"),
+            r#"git log origin/"$MINO_BRANCH""#
+        );
+        assert_eq!(
+            v.expand_shell(r#"echo "{project}@{branch}""#),
+            r#"echo "${MINO_PROJECT}@${MINO_BRANCH}""#
+        );
+        assert_eq!(
+            v.expand_shell("echo '{session}'"),
+            r#"echo ''"$MINO_SESSION"''"#
+        );
+        assert_eq!(
+            v.expand_shell(r#"echo \"{branch} {} ${HOME}"#),
+            r#"echo \""$MINO_BRANCH" {} ${HOME}"#
+        );
+    }
+
+    #[test]
+    fn hostile_branch_names_stay_inert() {
+        let dir = tempfile::tempdir().unwrap();
+        let branch = "x;$(touch pwned)`touch pwned2`'\"";
+        let v = vars(branch);
+        for template in [
+            "printf %s {branch}",
+            r#"printf %s "{branch}""#,
+            "printf %s '{branch}'",
+        ] {
+            let output = std::process::Command::new("sh")
+                .args(["-c", &v.expand_shell(template)])
+                .envs(v.shell_env())
+                .current_dir(dir.path())
+                .output()
+                .unwrap();
+            assert_eq!(
+                String::from_utf8_lossy(&output.stdout),
+                branch,
+                "{template}"
+            );
+        }
+        assert!(!dir.path().join("pwned").exists());
+        assert!(!dir.path().join("pwned2").exists());
+    }
+
+    #[tokio::test]
+    async fn branch_is_empty_outside_a_repo() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(current_branch(dir.path()).await, "");
+    }
+}
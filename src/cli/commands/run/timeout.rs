@@ -0,0 +1,148 @@
+//! Timeout and heartbeat for attached command runs
+
+use crate::error::MinoResult;
+use crate::orchestration::ContainerRuntime;
+use console::style;
+use std::time::Duration;
+use tokio::time::{interval_at, sleep, Instant};
+use tracing::warn;
+
+/// Exit code reported for a command killed by `--timeout`, as with coreutils `timeout`
+pub(super) const TIMEOUT_EXIT_CODE: i32 = 124;
+
+/// Heartbeat interval in CI mode when `--heartbeat` is not given
+const DEFAULT_HEARTBEAT: Duration = Duration::from_secs(5 * 60);
+
+/// How an attached command run ended
+pub(super) struct AttachedExit {
+    pub exit_code: i32,
+    pub timed_out: bool,
+}
+
+/// Effective heartbeat interval: `--heartbeat` when given (0 disables),
+/// otherwise the default in non-interactive runs only.
+pub(super) fn heartbeat_interval(flag: Option<Duration>, interactive: bool) -> Option<Duration> {
+    match flag {
+        Some(d) if d.is_zero() => None,
+        Some(d) => Some(d),
+        None if interactive => None,
+        None => Some(DEFAULT_HEARTBEAT),
+    }
+}
+
+/// Start the container attached (re-attaching if the connection drops),
+/// killing it once `timeout` elapses and printing a heartbeat line to stderr
+/// every `heartbeat`.
+pub(super) async fn start_attached_supervised(
+    runtime: &dyn ContainerRuntime,
+    container_id: &str,
+    session_name: &str,
+    timeout: Option<Duration>,
+    heartbeat: Option<Duration>,
+) -> MinoResult<AttachedExit> {
+    let started = Instant::now();
+    let attached = super::reattach::attach_until_exit(runtime, container_id, session_name);
+    tokio::pin!(attached);
+
+    let deadline = async {
+        match timeout.filter(|t| !t.is_zero()) {
+            Some(t) => sleep(t).await,
+            None => std::future::pending().await,
+        }
+    };
+    tokio::pin!(deadline);
+
+    let mut ticker = heartbeat.map(|h| interval_at(started + h, h));
+
+    loop {
+        tokio::select! {
+            exit_code = &mut attached => {
+                return Ok(AttachedExit { exit_code: exit_code?, timed_out: false });
+            }
+            () = &mut deadline => break,
+            _ = async {
+                match ticker.as_mut() {
+                    Some(t) => t.tick().await,
+                    None => std::future::pending().await,
+                }
+            } => {
+                eprintln!(
+                    "{} {} still running ({} elapsed)",
+                    style("[mino]").dim(),
+                    session_name,
+                    format_duration(started.elapsed())
+                );
+            }
+        }
+    }
+
+    eprintln!(
+        "{} Command exceeded --timeout {}; killing container",
+        style("!").yellow(),
+        format_duration(timeout.unwrap_or_default())
+    );
+    if let Err(e) = runtime.kill(container_id).await {
+        warn!("Failed to kill timed-out container: {}", e);
+    }
+    // Let the attached process observe the exit before the container is removed
+    if let Err(e) = attached.await {
+        warn!("Attached session ended with an error after timeout: {}", e);
+    }
+
+    Ok(AttachedExit {
+        exit_code: TIMEOUT_EXIT_CODE,
+        timed_out: true,
+    })
+}
+
+/// Compact human duration: `45s`, `12m`, `1h05m`
+pub(super) fn format_duration(d: Duration) -> String {
+    let secs = d.as_secs();
+    match secs {
+        0..=59 => format!("{}s", secs),
+        60..=3599 if secs % 60 == 0 => format!("{}m", secs / 60),
+        60..=3599 => format!("{}m{:02}s", secs / 60, secs % 60),
+        _ => format!("{}h{:02}m", secs / 3600, (secs % 3600) / 60),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::orchestration::mock::{MockResponse, MockRuntime};
+
+    #[test]
+    fn heartbeat_defaults_only_in_ci() {
+        assert_eq!(heartbeat_interval(None, false), Some(DEFAULT_HEARTBEAT));
+        assert_eq!(heartbeat_interval(None, true), None);
+        assert_eq!(heartbeat_interval(Some(Duration::ZERO), false), None);
+        let custom = Duration::from_secs(60);
+        assert_eq!(heartbeat_interval(Some(custom), true), Some(custom));
+    }
+
+    #[test]
+    fn format_duration_is_compact() {
+        assert_eq!(format_duration(Duration::from_secs(45)), "45s");
+        assert_eq!(format_duration(Duration::from_secs(1800)), "30m");
+        assert_eq!(format_duration(Duration::from_secs(90)), "1m30s");
+        assert_eq!(format_duration(Duration::from_secs(3900)), "1h05m");
+    }
+
+    #[tokio::test]
+    async fn supervised_run_returns_exit_code_before_timeout() {
+        let mock = MockRuntime::new().on("start_attached", Ok(MockResponse::Int(3)));
+
+        let exit = start_attached_supervised(
+            &mock,
+            "cid",
+            "s",
+            Some(Duration::from_secs(60)),
+            Some(Duration::from_secs(1)),
+        )
+        .await
+        .unwrap();
+        assert_eq!(exit.exit_code, 3);
+        assert!(!exit.timed_out);
+        mock.assert_called("kill", 0);
+    }
+}
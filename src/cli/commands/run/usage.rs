@@ -0,0 +1,128 @@
+//! Usage sampling for the usage log
+//!
+//! Counters are read from the container engine on the host while mino
+//! waits on the container. A command run's container has exited by the
+//! time mino regains control, so the last periodic sample stands in for
+//! its totals; peak memory is the highest use seen across samples.
+
+use crate::orchestration::{ContainerRuntime, ContainerUsage};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::debug;
+
+/// How often a running container's usage is sampled
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Background task sampling one container's usage
+pub(super) struct UsageSampler {
+    runtime: Arc<dyn ContainerRuntime>,
+    container_id: String,
+    latest: Arc<Mutex<Option<ContainerUsage>>>,
+    task: JoinHandle<()>,
+}
+
+impl UsageSampler {
+    /// Start sampling `container_id` now and every [`SAMPLE_INTERVAL`]
+    pub fn spawn(runtime: Arc<dyn ContainerRuntime>, container_id: &str) -> Self {
+        let latest = Arc::new(Mutex::new(None));
+        let task = {
+            let runtime = Arc::clone(&runtime);
+            let container_id = container_id.to_string();
+            let latest = Arc::clone(&latest);
+            tokio::spawn(async move {
+                loop {
+                    sample(&*runtime, &container_id, &latest).await;
+                    tokio::time::sleep(SAMPLE_INTERVAL).await;
+                }
+            })
+        };
+        Self {
+            runtime,
+            container_id: container_id.to_string(),
+            latest,
+            task,
+        }
+    }
+
+    /// Stop sampling and return the usage seen, with a final sample if the
+    /// container is still running. `None` when no sample succeeded.
+    pub async fn finish(self) -> Option<ContainerUsage> {
+        self.task.abort();
+        sample(&*self.runtime, &self.container_id, &self.latest).await;
+        let latest = self.latest.lock().unwrap().take();
+        latest
+    }
+}
+
+/// Take one sample and fold it into `latest`. Best-effort: a container
+/// that has exited or an unsupported backend keeps the previous sample.
+async fn sample(
+    runtime: &dyn ContainerRuntime,
+    container_id: &str,
+    latest: &Mutex<Option<ContainerUsage>>,
+) {
+    match runtime.container_usage(container_id).await {
+        Ok(usage) => {
+            let mut latest = latest.lock().unwrap();
+            *latest = Some(match latest.take() {
+                Some(previous) => previous.followed_by(usage),
+                None => usage,
+            });
+        }
+        Err(e) => debug!(
+            "No usage sample for container {}: {}",
+            &container_id[..12.min(container_id.len())],
+            e
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::MinoError;
+    use crate::orchestration::mock::{MockResponse, MockRuntime};
+
+    #[test]
+    fn later_sample_keeps_highest_memory() {
+        let first = ContainerUsage {
+            cpu_secs: 1.0,
+            peak_memory_bytes: Some(500),
+            net_rx_bytes: 10,
+            net_tx_bytes: 5,
+        };
+        let second = ContainerUsage {
+            cpu_secs: 3.0,
+            peak_memory_bytes: Some(200),
+            net_rx_bytes: 40,
+            net_tx_bytes: 20,
+        };
+        let usage = first.followed_by(second);
+        assert_eq!(usage.cpu_secs, 3.0);
+        assert_eq!(usage.peak_memory_bytes, Some(500));
+        assert_eq!(usage.net_rx_bytes, 40);
+    }
+
+    #[tokio::test]
+    async fn finish_samples_a_running_container() {
+        let mock = Arc::new(MockRuntime::new().on("container_usage", Ok(MockResponse::Unit)));
+        let sampler = UsageSampler::spawn(mock.clone(), "abc123def456");
+        assert_eq!(sampler.finish().await, Some(ContainerUsage::default()));
+    }
+
+    #[tokio::test]
+    async fn finish_without_any_sample_is_none() {
+        let unsupported = || {
+            Err(MinoError::BackendUnsupported {
+                runtime: "mock".to_string(),
+                feature: "Usage sampling".to_string(),
+            })
+        };
+        let mock = MockRuntime::new()
+            .on("container_usage", unsupported())
+            .on("container_usage", unsupported());
+        let sampler = UsageSampler::spawn(Arc::new(mock), "abc123def456");
+        assert_eq!(sampler.finish().await, None);
+    }
+}
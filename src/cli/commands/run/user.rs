@@ -0,0 +1,183 @@
+//! Container user and home directory resolution
+
+use crate::config::Config;
+use crate::error::MinoResult;
+use crate::orchestration::ContainerRuntime;
+use tracing::{debug, warn};
+
+use super::home::is_mino_image;
+
+/// User baked into mino-managed images
+pub(super) const MINO_USER: &str = "developer";
+
+/// Resolved user the container runs as.
+#[derive(Debug, Clone)]
+pub(super) struct ContainerUser {
+    /// Explicit `--user` value from config (`None` keeps the image default)
+    pub user_override: Option<String>,
+    /// Effective user name or UID, optionally with `:group`
+    pub name: String,
+    /// Home directory inside the container
+    pub home: String,
+    /// Whether `home` was set explicitly and must be exported as `HOME`
+    pub home_override: bool,
+}
+
+impl ContainerUser {
+    /// Whether this is the `developer` user of mino-managed images.
+    pub fn is_mino_user(&self) -> bool {
+        user_part(&self.name) == MINO_USER
+    }
+
+    /// Whether the container runs as root.
+    pub fn is_root(&self) -> bool {
+        matches!(user_part(&self.name), "" | "root" | "0")
+    }
+
+    /// Whether named volumes must be chowned to the user on mount.
+    ///
+    /// Mino images pre-create `/cache` owned by `developer` and root can
+    /// write anywhere; any other user needs podman's `U` mount option.
+    pub fn needs_volume_chown(&self) -> bool {
+        !self.is_root() && !self.is_mino_user()
+    }
+}
+
+/// Resolve the container user and home directory.
+///
+/// Precedence: `container.user` config, then `developer` for mino-managed
+/// images, then the image's declared `USER` (via image inspect), then root.
+pub(super) async fn resolve_container_user(
+    runtime: &dyn ContainerRuntime,
+    config: &Config,
+    image: &str,
+) -> MinoResult<ContainerUser> {
+    let user_override = config.container.user.clone();
+
+    let name = match user_override {
+        Some(ref user) => user.clone(),
+        None if is_mino_image(image) => MINO_USER.to_string(),
+        None => match runtime.image_user(image).await {
+            Ok(user) => user.unwrap_or_else(|| "root".to_string()),
+            Err(e) => {
+                warn!("Could not detect default user of {}: {}", image, e);
+                "root".to_string()
+            }
+        },
+    };
+
+    let home = config
+        .container
+        .home
+        .clone()
+        .unwrap_or_else(|| default_home(&name));
+    debug!("Container user: {} (home: {})", name, home);
+
+    Ok(ContainerUser {
+        home_override: config.container.home.is_some(),
+        user_override,
+        name,
+        home,
+    })
+}
+
+/// Derive a home directory for a user when none is configured.
+///
+/// Numeric UIDs usually have no passwd entry, so they get `/tmp`.
+fn default_home(user: &str) -> String {
+    match user_part(user) {
+        "" | "root" | "0" => "/root".to_string(),
+        uid if uid.chars().all(|c| c.is_ascii_digit()) => "/tmp".to_string(),
+        name => format!("/home/{name}"),
+    }
+}
+
+/// Strip an optional `:group` suffix from a `user[:group]` spec.
+fn user_part(spec: &str) -> &str {
+    spec.split(':').next().unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::commands::run::image::LAYER_BASE_IMAGE;
+    use crate::orchestration::mock::{MockResponse, MockRuntime};
+
+    #[tokio::test]
+    async fn mino_image_uses_developer() {
+        let mock = MockRuntime::new();
+        let user = resolve_container_user(&mock, &Config::default(), LAYER_BASE_IMAGE)
+            .await
+            .unwrap();
+
+        assert_eq!(user.name, "developer");
+        assert_eq!(user.home, "/home/developer");
+        assert!(user.user_override.is_none());
+        assert!(!user.home_override);
+        mock.assert_called("image_user", 0);
+    }
+
+    #[tokio::test]
+    async fn custom_image_detects_user() {
+        let mock = MockRuntime::new().on("image_user", Ok(MockResponse::String("node".into())));
+        let user = resolve_container_user(&mock, &Config::default(), "node:22")
+            .await
+            .unwrap();
+
+        assert_eq!(user.name, "node");
+        assert_eq!(user.home, "/home/node");
+        assert!(user.needs_volume_chown());
+        mock.assert_called_with("image_user", &["node:22"]);
+    }
+
+    #[tokio::test]
+    async fn custom_image_without_user_is_root() {
+        let mock = MockRuntime::new();
+        let user = resolve_container_user(&mock, &Config::default(), "fedora:43")
+            .await
+            .unwrap();
+
+        assert!(user.is_root());
+        assert_eq!(user.home, "/root");
+        assert!(!user.needs_volume_chown());
+    }
+
+    #[tokio::test]
+    async fn detection_failure_falls_back_to_root() {
+        let mock = MockRuntime::new().on(
+            "image_user",
+            Err(crate::error::MinoError::Internal("boom".into())),
+        );
+        let user = resolve_container_user(&mock, &Config::default(), "fedora:43")
+            .await
+            .unwrap();
+
+        assert!(user.is_root());
+    }
+
+    #[tokio::test]
+    async fn config_overrides_user_and_home() {
+        let mock = MockRuntime::new();
+        let mut config = Config::default();
+        config.container.user = Some("1000:1000".to_string());
+        config.container.home = Some("/home/app".to_string());
+
+        let user = resolve_container_user(&mock, &config, LAYER_BASE_IMAGE)
+            .await
+            .unwrap();
+
+        assert_eq!(user.user_override.as_deref(), Some("1000:1000"));
+        assert_eq!(user.home, "/home/app");
+        assert!(user.home_override);
+        assert!(!user.is_mino_user());
+        mock.assert_called("image_user", 0);
+    }
+
+    #[test]
+    fn default_home_by_user_kind() {
+        assert_eq!(default_home("root"), "/root");
+        assert_eq!(default_home("0:0"), "/root");
+        assert_eq!(default_home("1000"), "/tmp");
+        assert_eq!(default_home("app:staff"), "/home/app");
+    }
+}
@@ -0,0 +1,450 @@
+//! Cache prefetching for `mino cache warm`
+//!
+//! Sets caches up exactly as `mino run` would, then fills each incomplete
+//! one from a throwaway container running the ecosystem's download-only
+//! install, and finalizes it. Installs that write into the project
+//! (`node_modules`, virtualenvs) run on a copy of the manifests in a temp
+//! directory, so the project tree is left untouched.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use super::cache::{detect_project_lockfiles, finalize_caches, setup_caches};
+use super::container::{build_container_config, ContainerBuildParams};
+use super::image::{configured_cache_key_extra, resolve_image, resolve_layer_names};
+use super::propagate::Propagation;
+use super::user::resolve_container_user;
+use super::{CacheSession, ImageResolution};
+use crate::cache::{CacheMount, CacheScope, Ecosystem, LockfileInfo};
+use crate::cli::args::RunArgs;
+use crate::config::Config;
+use crate::error::{MinoError, MinoResult};
+use crate::labels;
+use crate::network::{
+    generate_iptables_wrapper, resolve_network_mode, NetworkMode, NetworkResolutionInput,
+};
+use crate::orchestration::ContainerRuntime;
+use crate::ui::{self, TaskSpinner, UiContext};
+use tracing::{debug, warn};
+
+/// Outcome of warming one cache
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum WarmOutcome {
+    /// The install ran and the cache was finalized
+    Warmed,
+    /// The cache was already complete (locally or from `[cache.remote]`)
+    AlreadyWarm,
+    /// The ecosystem has no download-only install for this lockfile
+    Unsupported,
+    /// The install failed; the cache stays incomplete
+    Failed(String),
+}
+
+/// One detected cache and what warming did to it
+#[derive(Debug, Clone)]
+pub(crate) struct WarmResult {
+    pub ecosystem: Ecosystem,
+    pub lockfile: String,
+    pub volume_name: String,
+    pub outcome: WarmOutcome,
+}
+
+/// Warm every cache of the project at `project_dir`.
+///
+/// Without an image or layers from `args` or config, the image is composed
+/// from the layers of the detected ecosystems.
+pub(crate) async fn warm_caches(
+    runtime: &dyn ContainerRuntime,
+    mut args: RunArgs,
+    config: &Config,
+    project_dir: &Path,
+    ctx: &UiContext,
+    spinner: &mut TaskSpinner,
+) -> MinoResult<Vec<WarmResult>> {
+    if !config.cache.enabled {
+        return Err(MinoError::User(
+            "Caching is disabled (cache.enabled = false)".to_string(),
+        ));
+    }
+
+    runtime.ensure_ready().await?;
+
+    spinner.message("Setting up cache volumes...");
+    let project_dirs = [project_dir.to_path_buf()];
+    // Key caches like the session that will use them, not by the layers
+    // picked below for the install
+    let key_extra = configured_cache_key_extra(&args, config, project_dir).await?;
+    let (cache_mounts, _, cache_session) =
+        setup_caches(runtime, &args, config, &project_dirs, &key_extra).await?;
+    let lockfiles = detect_project_lockfiles(
+        &project_dirs,
+        config.cache.scan_depth,
+        CacheScope::parse(&config.cache.scope)?,
+        &key_extra,
+    )?;
+
+    let mut results = Vec::new();
+    let mut pending = Vec::new();
+    for mount in &cache_mounts {
+        let Some(info) = lockfiles
+            .iter()
+            .find(|l| l.volume_name() == mount.volume_name)
+        else {
+            continue;
+        };
+        let outcome = if !cache_session
+            .volumes_to_finalize
+            .contains(&mount.volume_name)
+        {
+            Some(WarmOutcome::AlreadyWarm)
+        } else if warm_script(info, project_dir).is_none() {
+            Some(WarmOutcome::Unsupported)
+        } else {
+            None
+        };
+        match outcome {
+            Some(outcome) => results.push(warm_result(info, mount, outcome)),
+            None => pending.push((info, mount)),
+        }
+    }
+    if pending.is_empty() {
+        return Ok(results);
+    }
+
+    if args.image.is_none() && resolve_layer_names(&args, config).is_none() {
+        args.layers = ecosystem_layers(pending.iter().map(|(info, _)| info.ecosystem));
+    }
+    let (resolution, _) = resolve_image(
+        &args,
+        config,
+        &UiContext::non_interactive(),
+        spinner,
+        runtime,
+        project_dir,
+    )
+    .await?;
+    // Install output streams to the terminal from here on
+    spinner.clear();
+
+    for (info, mount) in pending {
+        ui::step_info(
+            ctx,
+            &format!(
+                "Warming {} cache ({}) from {}...",
+                info.ecosystem,
+                &info.hash[..8],
+                info.path.display()
+            ),
+        );
+        let outcome = match run_install(
+            runtime,
+            &args,
+            config,
+            project_dir,
+            &resolution,
+            info,
+            mount,
+        )
+        .await
+        {
+            Ok(0) => {
+                let session = CacheSession {
+                    volumes_to_finalize: vec![mount.volume_name.clone()],
+                    remote: cache_session.remote.clone(),
+                    upload: true,
+                    ..CacheSession::default()
+                };
+                finalize_caches(runtime, &session).await;
+                WarmOutcome::Warmed
+            }
+            Ok(code) => WarmOutcome::Failed(format!("install exited with code {}", code)),
+            Err(e) => WarmOutcome::Failed(e.to_string()),
+        };
+        results.push(warm_result(info, mount, outcome));
+    }
+
+    Ok(results)
+}
+
+fn warm_result(info: &LockfileInfo, mount: &CacheMount, outcome: WarmOutcome) -> WarmResult {
+    WarmResult {
+        ecosystem: info.ecosystem,
+        lockfile: info.path.display().to_string(),
+        volume_name: mount.volume_name.clone(),
+        outcome,
+    }
+}
+
+/// Run the install for one lockfile in a throwaway container with only its
+/// cache mounted, returning the exit code
+async fn run_install(
+    runtime: &dyn ContainerRuntime,
+    args: &RunArgs,
+    config: &Config,
+    project_dir: &Path,
+    resolution: &ImageResolution,
+    info: &LockfileInfo,
+    mount: &CacheMount,
+) -> MinoResult<i32> {
+    let script = warm_script(info, project_dir).ok_or_else(|| {
+        MinoError::Internal(format!("no install command for {}", info.path.display()))
+    })?;
+    let network_mode = resolve_network_mode(&NetworkResolutionInput {
+        cli_network: args.network.as_deref(),
+        cli_allow_rules: &args.network_allow,
+        cli_preset: args.network_preset.as_deref(),
+        config_network: &config.container.network,
+        config_network_allow: &config.container.network_allow,
+        config_preset: config.container.network_preset.as_deref(),
+    })?;
+    let install = vec!["sh".to_string(), "-c".to_string(), script];
+    let command = if let NetworkMode::Allow(ref rules) = network_mode {
+        generate_iptables_wrapper(rules, &install)
+    } else {
+        install
+    };
+
+    let mut cache_env: HashMap<String, String> = info
+        .ecosystem
+        .cache_env_vars()
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+    cache_env.insert("XDG_CACHE_HOME".to_string(), "/cache/xdg".to_string());
+
+    let user = resolve_container_user(runtime, config, &resolution.image).await?;
+    let propagation = Propagation::default();
+    let mut container_config = build_container_config(&ContainerBuildParams {
+        args,
+        config,
+        project_dir,
+        extra_projects: &[],
+        cwd_subdir: None,
+        resolution,
+        env_vars: HashMap::new(),
+        git_config: Default::default(),
+        cache_mounts: std::slice::from_ref(mount),
+        cache_env,
+        propagation: &propagation,
+        passthrough_env: Default::default(),
+        network_mode: &network_mode,
+        user: &user,
+        home_mount: None,
+        scratch_mount: None,
+        container_storage_mount: None,
+        deploy_key_agent: None,
+    })?;
+    container_config.interactive = false;
+    container_config.tty = false;
+    container_config.labels = labels::resource_labels(None, Some(project_dir));
+    container_config
+        .env
+        .insert("MINO_QUIET_BOOTSTRAP".to_string(), "1".to_string());
+
+    let container_id = runtime.create(&container_config, &command).await?;
+    debug!(
+        "Warming {} in container {}",
+        mount.volume_name,
+        &container_id[..12.min(container_id.len())]
+    );
+    let exit = runtime.start_attached(&container_id).await;
+    if let Err(e) = runtime.remove(&container_id).await {
+        warn!(
+            "Failed to remove cache warm container {}: {}",
+            &container_id[..12.min(container_id.len())],
+            e
+        );
+    }
+    exit
+}
+
+/// Layers providing the toolchains of `ecosystems`, deduplicated
+fn ecosystem_layers(ecosystems: impl Iterator<Item = Ecosystem>) -> Vec<String> {
+    let mut layers: Vec<String> = Vec::new();
+    for layer in ecosystems.filter_map(ecosystem_layer) {
+        if !layers.iter().any(|l| l == layer) {
+            layers.push(layer.to_string());
+        }
+    }
+    layers
+}
+
+/// Built-in layer with the ecosystem's toolchain
+fn ecosystem_layer(ecosystem: Ecosystem) -> Option<&'static str> {
+    match ecosystem {
+        Ecosystem::Npm | Ecosystem::Yarn | Ecosystem::Pnpm => Some("typescript"),
+        Ecosystem::Cargo => Some("rust"),
+        Ecosystem::Pip | Ecosystem::Poetry | Ecosystem::Uv => Some("python"),
+        Ecosystem::Gradle | Ecosystem::Maven => Some("jvm"),
+        Ecosystem::Go
+        | Ecosystem::Composer
+        | Ecosystem::Nuget
+        | Ecosystem::Bundler
+        | Ecosystem::Bun
+        | Ecosystem::Mix => None,
+    }
+}
+
+/// Shell script downloading the dependencies of every lockfile of the
+/// cache, run in the project root. `None` when a lockfile has no
+/// download-only install.
+fn warm_script(info: &LockfileInfo, project_dir: &Path) -> Option<String> {
+    let steps = info
+        .paths()
+        .map(|path| {
+            let install = install_script(info.ecosystem, path)?;
+            let dir = path.parent()?.strip_prefix(project_dir).ok()?;
+            Some(if dir.as_os_str().is_empty() {
+                install
+            } else {
+                format!(
+                    "(cd '{}' && {})",
+                    dir.display().to_string().replace('\'', "'\\''"),
+                    install
+                )
+            })
+        })
+        .collect::<Option<Vec<_>>>()?;
+    Some(steps.join(" && "))
+}
+
+/// Shell command downloading the dependencies of one lockfile, run in its
+/// directory
+fn install_script(ecosystem: Ecosystem, path: &Path) -> Option<String> {
+    let lockfile = path.file_name()?.to_str()?;
+    let script = match ecosystem {
+        Ecosystem::Npm => in_copy(&["package.json", lockfile], "npm ci --ignore-scripts"),
+        Ecosystem::Yarn => in_copy(
+            &["package.json", lockfile],
+            "yarn install --frozen-lockfile --ignore-scripts",
+        ),
+        Ecosystem::Pnpm => in_copy(&[lockfile], "pnpm fetch"),
+        Ecosystem::Cargo => "cargo fetch --locked".to_string(),
+        Ecosystem::Pip if lockfile == "requirements.txt" => {
+            "pip download --quiet -r requirements.txt -d \"$(mktemp -d)\"".to_string()
+        }
+        Ecosystem::Pip => return None,
+        Ecosystem::Poetry => in_copy(
+            &["pyproject.toml", lockfile],
+            "poetry install --no-root --no-interaction",
+        ),
+        Ecosystem::Uv => in_copy(
+            &["pyproject.toml", lockfile],
+            "uv sync --frozen --no-install-project",
+        ),
+        Ecosystem::Go => "go mod download".to_string(),
+        // Resolving Gradle dependencies runs the build scripts
+        Ecosystem::Gradle => return None,
+        Ecosystem::Maven => "mvn --batch-mode --quiet dependency:go-offline".to_string(),
+        Ecosystem::Composer => in_copy(
+            &["composer.json", lockfile],
+            "composer install --no-scripts --no-autoloader --no-interaction",
+        ),
+        Ecosystem::Nuget => in_copy(&["*.csproj", lockfile], "dotnet restore --locked-mode"),
+        Ecosystem::Bundler => in_copy(&["Gemfile", lockfile], "bundle install --frozen"),
+        Ecosystem::Bun => in_copy(
+            &["package.json", lockfile],
+            "bun install --frozen-lockfile --ignore-scripts",
+        ),
+        Ecosystem::Mix => in_copy(&["mix.exs", lockfile], "mix deps.get"),
+    };
+    Some(script)
+}
+
+/// Run `install` in a temp directory holding copies of `files`
+fn in_copy(files: &[&str], install: &str) -> String {
+    format!(
+        "d=$(mktemp -d) && cp {} \"$d\"/ && cd \"$d\" && {}",
+        files.join(" "),
+        install
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn lockfile(ecosystem: Ecosystem, path: &str) -> LockfileInfo {
+        LockfileInfo {
+            ecosystem,
+            path: PathBuf::from(path),
+            nested: Vec::new(),
+            hash: "abc123def456".to_string(),
+            project: None,
+        }
+    }
+
+    #[test]
+    fn warm_script_installs_node_deps_outside_the_project() {
+        let script = warm_script(
+            &lockfile(Ecosystem::Npm, "/p/package-lock.json"),
+            Path::new("/p"),
+        )
+        .unwrap();
+        assert_eq!(
+            script,
+            "d=$(mktemp -d) && cp package.json package-lock.json \"$d\"/ && cd \"$d\" && \
+             npm ci --ignore-scripts"
+        );
+    }
+
+    #[test]
+    fn warm_script_per_ecosystem() {
+        assert_eq!(
+            warm_script(
+                &lockfile(Ecosystem::Cargo, "/p/Cargo.lock"),
+                Path::new("/p")
+            )
+            .as_deref(),
+            Some("cargo fetch --locked")
+        );
+        assert!(warm_script(
+            &lockfile(Ecosystem::Pip, "/p/requirements.txt"),
+            Path::new("/p")
+        )
+        .unwrap()
+        .starts_with("pip download"));
+        assert!(
+            warm_script(&lockfile(Ecosystem::Uv, "/p/uv.lock"), Path::new("/p"))
+                .unwrap()
+                .ends_with("uv sync --frozen --no-install-project")
+        );
+        assert!(warm_script(
+            &lockfile(Ecosystem::Pip, "/p/Pipfile.lock"),
+            Path::new("/p")
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn warm_script_covers_nested_lockfiles() {
+        let mut info = lockfile(Ecosystem::Cargo, "/p/Cargo.lock");
+        info.nested = vec![PathBuf::from("/p/tools/it's/Cargo.lock")];
+        assert_eq!(
+            warm_script(&info, Path::new("/p")).as_deref(),
+            Some("cargo fetch --locked && (cd 'tools/it'\\''s' && cargo fetch --locked)")
+        );
+
+        info.nested = vec![PathBuf::from("/p/legacy/Pipfile.lock")];
+        info.ecosystem = Ecosystem::Pip;
+        info.path = PathBuf::from("/p/requirements.txt");
+        assert!(warm_script(&info, Path::new("/p")).is_none());
+    }
+
+    #[test]
+    fn ecosystem_layers_are_deduplicated() {
+        let layers = ecosystem_layers(
+            [
+                Ecosystem::Npm,
+                Ecosystem::Pnpm,
+                Ecosystem::Cargo,
+                Ecosystem::Go,
+                Ecosystem::Maven,
+                Ecosystem::Gradle,
+            ]
+            .into_iter(),
+        );
+        assert_eq!(layers, vec!["typescript", "rust", "jvm"]);
+    }
+}
@@ -0,0 +1,110 @@
+//! Session command - inspect recorded session data
+
+use crate::cli::args::{ExportFormat, SessionAction, SessionArgs, StatsAction, StatsExportArgs};
+use crate::config::Config;
+use crate::error::{MinoError, MinoResult};
+use crate::session::usage::{to_csv, UsageLog, UsageRecord};
+use std::path::Path;
+
+/// Execute the session command
+pub async fn execute(args: SessionArgs, config: &Config) -> MinoResult<()> {
+    match args.action {
+        SessionAction::Stats {
+            action: StatsAction::Export(export),
+        } => export_stats(export, config).await,
+    }
+}
+
+/// Export the usage log, filtered by date range, project, and user
+async fn export_stats(args: StatsExportArgs, config: &Config) -> MinoResult<()> {
+    if let (Some(since), Some(until)) = (args.since, args.until) {
+        if since >= until {
+            return Err(MinoError::User(
+                "--since must be earlier than --until".to_string(),
+            ));
+        }
+    }
+
+    let records = UsageLog::new(config)
+        .read_range(args.since, args.until)
+        .await?;
+    let records = filter_records(records, args.project.as_deref(), args.user.as_deref());
+
+    let rendered = match args.format {
+        ExportFormat::Csv => to_csv(&records),
+        ExportFormat::Json => {
+            let mut json = serde_json::to_string_pretty(&records)?;
+            json.push('\n');
+            json
+        }
+    };
+
+    match args.output {
+        Some(path) => tokio::fs::write(&path, rendered)
+            .await
+            .map_err(|e| MinoError::io(format!("writing {}", path.display()), e)),
+        None => {
+            print!("{}", rendered);
+            Ok(())
+        }
+    }
+}
+
+/// Keep records of `project` (compared canonically) and `user`
+fn filter_records(
+    records: Vec<UsageRecord>,
+    project: Option<&Path>,
+    user: Option<&str>,
+) -> Vec<UsageRecord> {
+    let project = project.map(|p| std::fs::canonicalize(p).unwrap_or_else(|_| p.to_path_buf()));
+    records
+        .into_iter()
+        .filter(|r| project.as_ref().is_none_or(|p| &r.project_dir == p))
+        .filter(|r| user.is_none_or(|u| r.user.as_deref() == Some(u)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use std::path::PathBuf;
+
+    fn record(project: &str, user: Option<&str>) -> UsageRecord {
+        UsageRecord {
+            session: "swift-fox".to_string(),
+            user: user.map(String::from),
+            project_dir: PathBuf::from(project),
+            backend: None,
+            started_at: Utc::now(),
+            stopped_at: Utc::now(),
+            wall_secs: 1.0,
+            cpu_secs: None,
+            peak_memory_bytes: None,
+            net_rx_bytes: None,
+            net_tx_bytes: None,
+        }
+    }
+
+    #[test]
+    fn filter_by_project_and_user() {
+        let records = vec![
+            record("/nonexistent/app", Some("alice")),
+            record("/nonexistent/app", Some("bob")),
+            record("/nonexistent/other", Some("alice")),
+            record("/nonexistent/app", None),
+        ];
+
+        let by_project = filter_records(records.clone(), Some(Path::new("/nonexistent/app")), None);
+        assert_eq!(by_project.len(), 3);
+
+        let by_both = filter_records(
+            records.clone(),
+            Some(Path::new("/nonexistent/app")),
+            Some("alice"),
+        );
+        assert_eq!(by_both.len(), 1);
+
+        assert_eq!(filter_records(records, None, None).len(), 4);
+    }
+}
@@ -29,6 +29,11 @@ pub(super) async fn setup_linux(ctx: &UiContext, args: &SetupArgs) -> MinoResult
         StepResult::Blocked
     };
 
+    // Step 4: Check cross-arch emulation (optional, not counted as an issue)
+    if userns_result.is_ok() {
+        check_emulation(ctx, args).await;
+    }
+
     // Summary
     let results = [podman_result, rootless_result, userns_result];
     let issues = results.iter().filter(|r| r.is_issue()).count();
@@ -186,3 +191,62 @@ async fn check_rootless_mode(ctx: &UiContext, args: &SetupArgs) -> StepResult {
         }
     }
 }
+
+/// Check for a binfmt_misc handler running foreign-architecture binaries
+///
+/// Only `mino run --platform` with a foreign image needs it, so the step
+/// is optional and never offered automatically with `--yes`.
+async fn check_emulation(ctx: &UiContext, args: &SetupArgs) -> StepResult {
+    let foreign = super::helpers::foreign_platform();
+    let output = Command::new("ls")
+        .args(["-1", "/proc/sys/fs/binfmt_misc"])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .output()
+        .await;
+
+    let listing = match output {
+        Ok(out) if out.status.success() => String::from_utf8_lossy(&out.stdout).to_string(),
+        _ => String::new(),
+    };
+    if super::helpers::has_binfmt_handler(&listing, foreign) {
+        ui::step_ok_detail(ctx, "Cross-arch emulation", foreign);
+        return StepResult::AlreadyOk;
+    }
+
+    let message = format!("No emulation for {} images (optional)", foreign);
+    if args.check {
+        ui::step_warn_hint(
+            ctx,
+            &message,
+            "Needed for --platform: install qemu-user-static",
+        );
+        return StepResult::Skipped;
+    }
+
+    ui::step_warn(ctx, &message);
+    let Some((name, install_args)) = detect_package_manager().await else {
+        ui::remark(ctx, "Install qemu-user-static to run --platform images");
+        return StepResult::Skipped;
+    };
+    if !ui::confirm_inline(&format!("Install qemu-user-static via {}?", name), false) {
+        ui::remark(ctx, "Skipped emulation setup");
+        return StepResult::Skipped;
+    }
+
+    let cmd_args: Vec<&str> = install_args
+        .into_iter()
+        .chain(std::iter::once("qemu-user-static"))
+        .collect();
+    ui::remark(
+        ctx,
+        &format!("Running: sudo {} {}", name, cmd_args.join(" ")),
+    );
+    if run_visible_sudo(name, &cmd_args).await {
+        ui::step_ok(ctx, "qemu-user-static installed");
+        StepResult::Installed
+    } else {
+        ui::step_error(ctx, "qemu-user-static installation failed");
+        StepResult::Failed
+    }
+}
@@ -1,10 +1,10 @@
-//! Container runtime setup for macOS (OrbStack + Podman-in-VM)
+//! Container runtime setup for macOS (OrbStack or Lima + Podman-in-VM)
 
-use super::{run_visible, run_visible_orb, vm_exists, StepResult};
+use super::{run_visible, run_visible_vm, vm_command, vm_exists, StepResult};
 use crate::cli::args::SetupArgs;
 use crate::config::Config;
 use crate::error::MinoResult;
-use crate::orchestration::OrbStack;
+use crate::orchestration::{Lima, OrbStack, VmProvider};
 use crate::ui::{self, UiContext};
 use std::process::Stdio;
 use tokio::process::Command;
@@ -19,35 +19,63 @@ pub(super) async fn setup_macos(
     // Step 1: Check Homebrew
     let homebrew_result = check_homebrew(ctx, args).await;
 
-    // Step 2: Check OrbStack
-    let orbstack_result = if homebrew_result.is_ok() {
-        check_orbstack(ctx, args).await
-    } else {
-        ui::step_blocked(ctx, "OrbStack", "Homebrew");
-        StepResult::Blocked
-    };
-
-    // Step 3: Check OrbStack running
-    let orbstack_running_result = if orbstack_result.is_ok() {
-        check_orbstack_running(ctx, args).await
-    } else {
-        ui::step_blocked(ctx, "OrbStack Service", "OrbStack");
-        StepResult::Blocked
-    };
-
-    // Step 4: Check VM exists
+    let provider: VmProvider = config.vm.provider.parse()?;
     let vm_name = &config.vm.name;
     let vm_distro = &config.vm.distro;
-    let vm_result = if orbstack_running_result.is_ok() {
-        check_vm(ctx, args, vm_name, vm_distro).await
-    } else {
-        ui::step_blocked(ctx, &format!("Mino VM ({})", vm_name), "OrbStack");
-        StepResult::Blocked
+
+    // Steps 2-4: Check the VM manager, its service, and the Mino VM
+    let (manager_result, service_result, vm_result) = match provider {
+        VmProvider::OrbStack => {
+            let orbstack_result = if homebrew_result.is_ok() {
+                check_orbstack(ctx, args).await
+            } else {
+                ui::step_blocked(ctx, "OrbStack", "Homebrew");
+                StepResult::Blocked
+            };
+
+            let orbstack_running_result = if orbstack_result.is_ok() {
+                check_orbstack_running(ctx, args).await
+            } else {
+                ui::step_blocked(ctx, "OrbStack Service", "OrbStack");
+                StepResult::Blocked
+            };
+
+            if orbstack_running_result.is_ok() {
+                check_orbstack_resources(ctx, args, config).await;
+            }
+
+            let vm_result = if orbstack_running_result.is_ok() {
+                check_vm(ctx, args, vm_name, vm_distro).await
+            } else {
+                ui::step_blocked(ctx, &format!("Mino VM ({})", vm_name), "OrbStack");
+                StepResult::Blocked
+            };
+
+            (orbstack_result, orbstack_running_result, vm_result)
+        }
+        VmProvider::Lima => {
+            let lima_result = if homebrew_result.is_ok() {
+                check_lima(ctx, args).await
+            } else {
+                ui::step_blocked(ctx, "Lima", "Homebrew");
+                StepResult::Blocked
+            };
+
+            // Lima has no background service; the VM itself is the service
+            let vm_result = if lima_result.is_ok() {
+                check_lima_vm(ctx, args, config).await
+            } else {
+                ui::step_blocked(ctx, &format!("Mino VM ({})", vm_name), "Lima");
+                StepResult::Blocked
+            };
+
+            (lima_result, StepResult::AlreadyOk, vm_result)
+        }
     };
 
     // Step 5: Check Podman in VM
     let podman_result = if vm_result.is_ok() {
-        check_podman_in_vm(ctx, args, vm_name, vm_distro).await
+        check_podman_in_vm(ctx, args, provider, vm_name, vm_distro).await
     } else {
         ui::step_blocked(ctx, "Podman (in VM)", "VM");
         StepResult::Blocked
@@ -55,17 +83,22 @@ pub(super) async fn setup_macos(
 
     // Step 6: Check rootless Podman in VM
     let rootless_result = if podman_result.is_ok() {
-        check_rootless_mode_in_vm(ctx, args, vm_name).await
+        check_rootless_mode_in_vm(ctx, args, provider, vm_name).await
     } else {
         ui::step_blocked(ctx, "Rootless Mode (in VM)", "Podman");
         StepResult::Blocked
     };
 
+    // Step 7: Check cross-arch emulation in VM (optional, not counted as an issue)
+    if rootless_result.is_ok() {
+        check_emulation_in_vm(ctx, args, provider, vm_name, vm_distro).await;
+    }
+
     // Summary
     let results = [
         homebrew_result,
-        orbstack_result,
-        orbstack_running_result,
+        manager_result,
+        service_result,
         vm_result,
         podman_result,
         rootless_result,
@@ -218,6 +251,69 @@ async fn check_orbstack_running(ctx: &UiContext, args: &SetupArgs) -> StepResult
     }
 }
 
+/// Apply `vm.cpus` / `vm.memory_gb` to OrbStack, restarting it when they change.
+/// Silent when neither is configured.
+async fn check_orbstack_resources(ctx: &UiContext, args: &SetupArgs, config: &Config) {
+    let detail = vm_resources_label(&config.vm);
+    if detail.is_empty() {
+        return;
+    }
+
+    let orb = OrbStack::new(config.vm.clone());
+    let pending = match orb.pending_resources().await {
+        Ok(pending) => pending,
+        Err(e) => {
+            ui::step_warn_hint(ctx, "Could not read OrbStack settings", &e.to_string());
+            return;
+        }
+    };
+    if pending.is_empty() {
+        ui::step_ok_detail(ctx, "OrbStack resources", &detail);
+        return;
+    }
+
+    if args.check {
+        ui::step_warn_hint(
+            ctx,
+            &format!("OrbStack resources differ from config ({})", detail),
+            "Run 'mino setup' to apply (restarts OrbStack)",
+        );
+        return;
+    }
+
+    ui::step_warn(
+        ctx,
+        &format!("OrbStack resources differ from config ({})", detail),
+    );
+    if !ui::confirm_inline("Apply and restart OrbStack?", args.yes) {
+        ui::remark(ctx, "Skipped OrbStack resource changes");
+        return;
+    }
+
+    let applied = async {
+        orb.apply_resources().await?;
+        OrbStack::stop().await?;
+        OrbStack::start().await
+    }
+    .await;
+    match applied {
+        Ok(()) => ui::step_ok_detail(ctx, "OrbStack resources applied", &detail),
+        Err(e) => ui::step_error_detail(ctx, "Failed to apply OrbStack resources", &e.to_string()),
+    }
+}
+
+/// "4 CPUs, 8 GB" for the configured VM sizing, empty when unset
+fn vm_resources_label(vm: &crate::config::schema::VmConfig) -> String {
+    let mut parts = Vec::new();
+    if vm.cpus > 0 {
+        parts.push(format!("{} CPUs", vm.cpus));
+    }
+    if vm.memory_gb > 0 {
+        parts.push(format!("{} GB", vm.memory_gb));
+    }
+    parts.join(", ")
+}
+
 async fn check_vm(ctx: &UiContext, args: &SetupArgs, vm_name: &str, vm_distro: &str) -> StepResult {
     if vm_exists(vm_name).await {
         ui::step_ok_detail(ctx, "Mino VM exists", vm_name);
@@ -252,14 +348,130 @@ async fn check_vm(ctx: &UiContext, args: &SetupArgs, vm_name: &str, vm_distro: &
     }
 }
 
+async fn check_lima(ctx: &UiContext, args: &SetupArgs) -> StepResult {
+    if Lima::is_installed().await {
+        if let Ok(version) = Lima::version().await {
+            ui::step_ok_detail(ctx, "Lima installed", &version);
+        } else {
+            ui::step_ok(ctx, "Lima installed");
+        }
+
+        // Upgrade if requested
+        if args.upgrade {
+            ui::remark(ctx, "Running: brew upgrade lima");
+            if run_visible("brew", &["upgrade", "lima"]).await {
+                if let Ok(new_version) = Lima::version().await {
+                    ui::step_ok_detail(ctx, "Lima upgraded", &new_version);
+                }
+            }
+            // Don't fail if upgrade fails - package might already be latest
+        }
+
+        return StepResult::AlreadyOk;
+    }
+
+    if args.check {
+        ui::step_error(ctx, "Lima not installed");
+        return StepResult::Failed;
+    }
+
+    ui::step_warn(ctx, "Lima not installed");
+
+    if ui::confirm_inline("Install Lima via Homebrew?", args.yes) {
+        ui::remark(ctx, "Running: brew install lima");
+
+        if run_visible("brew", &["install", "lima"]).await {
+            ui::step_ok(ctx, "Lima installed");
+            StepResult::Installed
+        } else {
+            ui::step_error_detail(ctx, "Lima installation failed", "https://lima-vm.io");
+            StepResult::Failed
+        }
+    } else {
+        ui::remark(ctx, "Skipped Lima installation");
+        StepResult::Skipped
+    }
+}
+
+/// Ensure the Lima VM exists and is running, creating it from the
+/// `vm.distro` template if needed.
+async fn check_lima_vm(ctx: &UiContext, args: &SetupArgs, config: &Config) -> StepResult {
+    let lima = Lima::new(config.vm.clone());
+    let vm_name = lima.vm_name();
+    let status = lima
+        .vm_status()
+        .await
+        .unwrap_or_else(|_| "unknown".to_string());
+
+    match status.as_str() {
+        "Running" => {
+            ui::step_ok_detail(ctx, "Mino VM running", vm_name);
+            StepResult::AlreadyOk
+        }
+        "unknown" => {
+            if args.check {
+                ui::step_error_detail(ctx, "Mino VM not found", vm_name);
+                return StepResult::Failed;
+            }
+
+            ui::step_warn_hint(ctx, "Mino VM not found", vm_name);
+
+            let prompt = format!("Create {} Lima VM '{}'?", config.vm.distro, vm_name);
+            if !ui::confirm_inline(&prompt, args.yes) {
+                ui::remark(ctx, "Skipped VM creation");
+                return StepResult::Skipped;
+            }
+
+            ui::remark(ctx, "Creating VM (first boot downloads the image)...");
+            let create_args = crate::orchestration::lima::create_args(&config.vm);
+            let create_refs: Vec<&str> = create_args.iter().map(String::as_str).collect();
+
+            if run_visible("limactl", &create_refs).await {
+                ui::step_ok_detail(ctx, "VM created", vm_name);
+                StepResult::Installed
+            } else {
+                ui::step_error(ctx, "VM creation failed");
+                ui::remark(
+                    ctx,
+                    &format!("Try: limactl delete {} && mino setup", vm_name),
+                );
+                StepResult::Failed
+            }
+        }
+        _ => {
+            if args.check {
+                ui::step_warn_hint(
+                    ctx,
+                    "Mino VM not running",
+                    &format!("Run: limactl start {}", vm_name),
+                );
+                return StepResult::Failed;
+            }
+
+            ui::remark(ctx, "Starting VM...");
+            match lima.start_vm().await {
+                Ok(()) => {
+                    ui::step_ok_detail(ctx, "VM started", vm_name);
+                    StepResult::Installed
+                }
+                Err(e) => {
+                    ui::step_error_detail(ctx, "Failed to start VM", &e.to_string());
+                    StepResult::Failed
+                }
+            }
+        }
+    }
+}
+
 async fn check_podman_in_vm(
     ctx: &UiContext,
     args: &SetupArgs,
+    provider: VmProvider,
     vm_name: &str,
     vm_distro: &str,
 ) -> StepResult {
-    let output = Command::new("orb")
-        .args(["-m", vm_name, "podman", "--version"])
+    let output = vm_command(provider, vm_name)
+        .args(["podman", "--version"])
         .stdout(Stdio::piped())
         .stderr(Stdio::null())
         .output()
@@ -274,11 +486,11 @@ async fn check_podman_in_vm(
             // Upgrade if requested
             if args.upgrade {
                 ui::remark(ctx, "Upgrading Podman in VM...");
-                let upgrade_success = upgrade_podman_in_vm(ctx, vm_name, vm_distro).await;
+                let upgrade_success = upgrade_podman_in_vm(ctx, provider, vm_name, vm_distro).await;
                 if upgrade_success {
                     // Show new version
-                    let new_output = Command::new("orb")
-                        .args(["-m", vm_name, "podman", "--version"])
+                    let new_output = vm_command(provider, vm_name)
+                        .args(["podman", "--version"])
                         .stdout(Stdio::piped())
                         .stderr(Stdio::null())
                         .output()
@@ -310,7 +522,7 @@ async fn check_podman_in_vm(
                 // For apt-based systems, we need to run update first
                 if super::helpers::is_apt_based_distro(vm_distro) {
                     let update_success =
-                        run_visible_orb(vm_name, &["sudo", "apt-get", "update"]).await;
+                        run_visible_vm(provider, vm_name, &["sudo", "apt-get", "update"]).await;
                     if !update_success {
                         ui::step_error(ctx, "Package update failed");
                         return StepResult::Failed;
@@ -322,7 +534,7 @@ async fn check_podman_in_vm(
                     .chain(install_cmd.iter().map(String::as_str))
                     .collect();
 
-                if run_visible_orb(vm_name, &install_args).await {
+                if run_visible_vm(provider, vm_name, &install_args).await {
                     ui::step_ok(ctx, "Podman installed");
                     return StepResult::Installed;
                 }
@@ -338,10 +550,16 @@ async fn check_podman_in_vm(
 }
 
 /// Upgrade Podman in VM using the appropriate package manager
-async fn upgrade_podman_in_vm(ctx: &UiContext, vm_name: &str, vm_distro: &str) -> bool {
+async fn upgrade_podman_in_vm(
+    ctx: &UiContext,
+    provider: VmProvider,
+    vm_name: &str,
+    vm_distro: &str,
+) -> bool {
     // For apt-based systems, run update first
     if super::helpers::is_apt_based_distro(vm_distro) {
-        let update_success = run_visible_orb(vm_name, &["sudo", "apt-get", "update"]).await;
+        let update_success =
+            run_visible_vm(provider, vm_name, &["sudo", "apt-get", "update"]).await;
         if !update_success {
             ui::remark(ctx, "Package update failed, skipping upgrade");
             return false;
@@ -353,17 +571,22 @@ async fn upgrade_podman_in_vm(ctx: &UiContext, vm_name: &str, vm_distro: &str) -
         .chain(upgrade_cmd.iter().map(String::as_str))
         .collect();
 
-    run_visible_orb(vm_name, &upgrade_args).await
+    run_visible_vm(provider, vm_name, &upgrade_args).await
 }
 
 /// Check and configure rootless Podman mode in VM
 ///
 /// Note: `podman info --format {{.Host.Security.Rootless}}` returns true even when
 /// subuid/subgid aren't configured, so we must explicitly check those files.
-async fn check_rootless_mode_in_vm(ctx: &UiContext, args: &SetupArgs, vm_name: &str) -> StepResult {
+async fn check_rootless_mode_in_vm(
+    ctx: &UiContext,
+    args: &SetupArgs,
+    provider: VmProvider,
+    vm_name: &str,
+) -> StepResult {
     // Get the username in the VM
-    let whoami_output = Command::new("orb")
-        .args(["-m", vm_name, "whoami"])
+    let whoami_output = vm_command(provider, vm_name)
+        .arg("whoami")
         .stdout(Stdio::piped())
         .stderr(Stdio::null())
         .output()
@@ -379,15 +602,8 @@ async fn check_rootless_mode_in_vm(ctx: &UiContext, args: &SetupArgs, vm_name: &
 
     // Check if subuid entry exists for the user
     // We grep for "^username:" to ensure exact match at start of line
-    let subuid_check = Command::new("orb")
-        .args([
-            "-m",
-            vm_name,
-            "grep",
-            "-q",
-            &format!("^{}:", username),
-            "/etc/subuid",
-        ])
+    let subuid_check = vm_command(provider, vm_name)
+        .args(["grep", "-q", &format!("^{}:", username), "/etc/subuid"])
         .stdout(Stdio::null())
         .stderr(Stdio::null())
         .status()
@@ -396,15 +612,8 @@ async fn check_rootless_mode_in_vm(ctx: &UiContext, args: &SetupArgs, vm_name: &
     let has_subuid = subuid_check.map(|s| s.success()).unwrap_or(false);
 
     // Check if subgid entry exists for the user
-    let subgid_check = Command::new("orb")
-        .args([
-            "-m",
-            vm_name,
-            "grep",
-            "-q",
-            &format!("^{}:", username),
-            "/etc/subgid",
-        ])
+    let subgid_check = vm_command(provider, vm_name)
+        .args(["grep", "-q", &format!("^{}:", username), "/etc/subgid"])
         .stdout(Stdio::null())
         .stderr(Stdio::null())
         .status()
@@ -438,7 +647,7 @@ async fn check_rootless_mode_in_vm(ctx: &UiContext, args: &SetupArgs, vm_name: &
             "echo '{}' | sudo tee -a /etc/subuid",
             super::helpers::generate_subid_entry(&username)
         );
-        let subuid_result = run_visible_orb(vm_name, &["sh", "-c", &subuid_cmd]).await;
+        let subuid_result = run_visible_vm(provider, vm_name, &["sh", "-c", &subuid_cmd]).await;
         if !subuid_result {
             ui::step_error(ctx, "Failed to configure /etc/subuid");
             return StepResult::Failed;
@@ -451,7 +660,7 @@ async fn check_rootless_mode_in_vm(ctx: &UiContext, args: &SetupArgs, vm_name: &
             "echo '{}' | sudo tee -a /etc/subgid",
             super::helpers::generate_subid_entry(&username)
         );
-        let subgid_result = run_visible_orb(vm_name, &["sh", "-c", &subgid_cmd]).await;
+        let subgid_result = run_visible_vm(provider, vm_name, &["sh", "-c", &subgid_cmd]).await;
         if !subgid_result {
             ui::step_error(ctx, "Failed to configure /etc/subgid");
             return StepResult::Failed;
@@ -460,7 +669,7 @@ async fn check_rootless_mode_in_vm(ctx: &UiContext, args: &SetupArgs, vm_name: &
 
     // Run podman system migrate to apply the configuration
     ui::remark(ctx, "Running: podman system migrate");
-    if run_visible_orb(vm_name, &["podman", "system", "migrate"]).await {
+    if run_visible_vm(provider, vm_name, &["podman", "system", "migrate"]).await {
         ui::step_ok(ctx, "Rootless mode configured in VM");
         StepResult::Installed
     } else {
@@ -468,3 +677,61 @@ async fn check_rootless_mode_in_vm(ctx: &UiContext, args: &SetupArgs, vm_name: &
         StepResult::Failed
     }
 }
+
+/// Check for a binfmt_misc handler in the VM running foreign-architecture
+/// binaries (qemu-user-static, or Rosetta when the VM provides it)
+///
+/// Only `mino run --platform` with a foreign image needs it, so the step
+/// is optional and never offered automatically with `--yes`.
+async fn check_emulation_in_vm(
+    ctx: &UiContext,
+    args: &SetupArgs,
+    provider: VmProvider,
+    vm_name: &str,
+    vm_distro: &str,
+) -> StepResult {
+    let foreign = super::helpers::foreign_platform();
+    let output = vm_command(provider, vm_name)
+        .args(["ls", "-1", "/proc/sys/fs/binfmt_misc"])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .output()
+        .await;
+
+    let listing = match output {
+        Ok(out) if out.status.success() => String::from_utf8_lossy(&out.stdout).to_string(),
+        _ => String::new(),
+    };
+    if super::helpers::has_binfmt_handler(&listing, foreign) {
+        ui::step_ok_detail(ctx, "Cross-arch emulation in VM", foreign);
+        return StepResult::AlreadyOk;
+    }
+
+    let message = format!("No emulation for {} images in VM (optional)", foreign);
+    if args.check {
+        ui::step_warn_hint(
+            ctx,
+            &message,
+            "Needed for --platform: install qemu-user-static",
+        );
+        return StepResult::Skipped;
+    }
+
+    ui::step_warn(ctx, &message);
+    if !ui::confirm_inline("Install qemu-user-static in VM?", false) {
+        ui::remark(ctx, "Skipped emulation setup");
+        return StepResult::Skipped;
+    }
+
+    let install_cmd = super::distro_install_cmd(vm_distro, "qemu-user-static");
+    let install_args: Vec<&str> = std::iter::once("sudo")
+        .chain(install_cmd.iter().map(String::as_str))
+        .collect();
+    if run_visible_vm(provider, vm_name, &install_args).await {
+        ui::step_ok(ctx, "qemu-user-static installed in VM");
+        StepResult::Installed
+    } else {
+        ui::step_error(ctx, "qemu-user-static installation failed");
+        StepResult::Failed
+    }
+}
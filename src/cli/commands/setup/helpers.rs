@@ -6,6 +6,7 @@
 use super::StepResult;
 use crate::cli::args::SetupArgs;
 use crate::config::ConfigManager;
+use crate::orchestration::{host_platform, PLATFORMS};
 use crate::sandbox::detection::{
     detect_claude_copy_candidate, detect_passthrough_candidates, detect_sensitive_candidates,
     CLAUDE_AUTO_COPY_CANDIDATE, SENSITIVE_BUT_USEFUL_CANDIDATES, TOOLCHAIN_PASSTHROUGH_CANDIDATES,
@@ -478,6 +479,34 @@ pub(super) fn anchor_registered(anchors_output: &str) -> bool {
     anchors_output.lines().any(|l| l.trim() == "mino")
 }
 
+/// The platform whose images need emulation on this host
+pub(super) fn foreign_platform() -> &'static str {
+    let host = host_platform();
+    PLATFORMS
+        .iter()
+        .copied()
+        .find(|p| *p != host)
+        .unwrap_or(host)
+}
+
+/// `binfmt_misc` entries that run `platform` binaries on a foreign host:
+/// qemu-user-static handlers, or Rosetta for amd64 in Apple Silicon VMs.
+fn binfmt_handlers(platform: &str) -> &'static [&'static str] {
+    match platform {
+        "linux/amd64" => &["qemu-x86_64", "rosetta"],
+        _ => &["qemu-aarch64"],
+    }
+}
+
+/// Whether a listing of `/proc/sys/fs/binfmt_misc` (one entry per line, as
+/// printed by `ls -1`) has a handler for `platform` binaries
+pub(super) fn has_binfmt_handler(listing: &str, platform: &str) -> bool {
+    let handlers = binfmt_handlers(platform);
+    listing
+        .lines()
+        .any(|entry| handlers.contains(&entry.trim()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -641,4 +670,23 @@ mod tests {
     fn anchor_partial_line_not_matched() {
         assert!(!anchor_registered("com.mino.anchor"));
     }
+
+    #[test]
+    fn binfmt_handler_for_amd64() {
+        let listing = "register\nstatus\nqemu-x86_64\n";
+        assert!(has_binfmt_handler(listing, "linux/amd64"));
+        assert!(has_binfmt_handler("rosetta\nstatus\n", "linux/amd64"));
+        assert!(!has_binfmt_handler(listing, "linux/arm64"));
+    }
+
+    #[test]
+    fn foreign_platform_differs_from_host() {
+        assert_ne!(foreign_platform(), host_platform());
+    }
+
+    #[test]
+    fn binfmt_handler_missing() {
+        assert!(!has_binfmt_handler("register\nstatus\n", "linux/arm64"));
+        assert!(has_binfmt_handler("qemu-aarch64\n", "linux/arm64"));
+    }
 }
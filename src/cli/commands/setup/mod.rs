@@ -1,7 +1,7 @@
 //! Setup command - interactive prerequisite installation
 //!
 //! Decomposed into domain-specific submodules:
-//! - `container_macos` — OrbStack + Podman-in-VM checks
+//! - `container_macos` — OrbStack or Lima VM + Podman-in-VM checks
 //! - `container_linux` — native Podman + rootless mode checks
 //! - `native_macos` — macOS sandbox user, helper, sudoers, pf
 //! - `native_linux` — Linux user namespace + unshare checks
@@ -16,7 +16,7 @@ mod helpers;
 use crate::cli::args::SetupArgs;
 use crate::config::Config;
 use crate::error::{MinoError, MinoResult};
-use crate::orchestration::Platform;
+use crate::orchestration::{Platform, VmProvider};
 use crate::ui::{self, UiContext};
 use std::process::Stdio;
 use tokio::process::Command;
@@ -298,10 +298,25 @@ pub(super) async fn run_visible(cmd: &str, args: &[&str]) -> bool {
         .unwrap_or(false)
 }
 
-/// Run a command in an OrbStack VM, showing output to user
-pub(super) async fn run_visible_orb(vm_name: &str, args: &[&str]) -> bool {
-    let mut cmd = Command::new("orb");
-    cmd.arg("-m").arg(vm_name);
+/// Command that runs a program inside the macOS VM (`orb -m` or `limactl shell`)
+pub(super) fn vm_command(provider: VmProvider, vm_name: &str) -> Command {
+    match provider {
+        VmProvider::OrbStack => {
+            let mut cmd = Command::new("orb");
+            cmd.arg("-m").arg(vm_name);
+            cmd
+        }
+        VmProvider::Lima => {
+            let mut cmd = Command::new("limactl");
+            cmd.args(["shell", "--workdir", "/", vm_name]);
+            cmd
+        }
+    }
+}
+
+/// Run a command in the macOS VM, showing output to user
+pub(super) async fn run_visible_vm(provider: VmProvider, vm_name: &str, args: &[&str]) -> bool {
+    let mut cmd = vm_command(provider, vm_name);
     cmd.args(args);
     cmd.stdin(Stdio::inherit())
         .stdout(Stdio::inherit())
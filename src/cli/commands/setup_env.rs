@@ -0,0 +1,142 @@
+//! Setup-env command - provision a bare machine with layer toolchains
+//!
+//! Prints a bash script that installs the selected layers directly on a
+//! host (no container), or runs it on this machine with `--inside`.
+
+use crate::cli::args::SetupEnvArgs;
+use crate::cli::commands::run::image::layer_arg_overrides;
+use crate::cli::commands::run::image::parse_layers_env;
+use crate::config::Config;
+use crate::error::{MinoError, MinoResult};
+use crate::layer::{apply_layer_args, packages_layer, provision_script, resolve_layers};
+use crate::ui::{self, UiContext};
+use std::process::Stdio;
+use tokio::process::Command;
+
+/// Execute the setup-env command
+pub async fn execute(args: SetupEnvArgs, config: &Config) -> MinoResult<()> {
+    let names = layer_names(&args.layers, std::env::var("MINO_LAYERS").ok(), config)?;
+    let project_dir =
+        std::env::current_dir().map_err(|e| MinoError::io("getting current directory", e))?;
+    let mut layers = resolve_layers(&names, &project_dir).await?;
+    let overrides = layer_arg_overrides(&[], config, &layers);
+    apply_layer_args(&mut layers, &overrides)?;
+    layers.extend(packages_layer(&config.container.packages.dnf)?);
+    let script = provision_script(&layers).await?;
+
+    if !args.inside {
+        print!("{}", script);
+        return Ok(());
+    }
+
+    if std::env::consts::OS != "linux" {
+        return Err(MinoError::User(
+            "setup-env --inside provisions Linux machines. Run it on the target VM, or pipe \
+             `mino setup-env` into ssh."
+                .to_string(),
+        ));
+    }
+
+    let ctx = UiContext::detect();
+    ui::intro(&ctx, "Provision this machine");
+    ui::remark(
+        &ctx,
+        &format!(
+            "Installs layers {} on this host, outside any sandbox (uses sudo)",
+            names.join(", ")
+        ),
+    );
+    if !ui::confirm_inline("Continue?", args.yes) {
+        ui::outro_warn(&ctx, "Cancelled");
+        return Ok(());
+    }
+
+    // From a file, not stdin: installers may read stdin themselves
+    let path = std::env::temp_dir().join(format!(
+        "mino-setup-env-{}.sh",
+        uuid::Uuid::new_v4().simple()
+    ));
+    tokio::fs::write(&path, &script)
+        .await
+        .map_err(|e| MinoError::io(format!("writing {}", path.display()), e))?;
+    let status = Command::new("bash")
+        .arg(&path)
+        .stdin(Stdio::inherit())
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .status()
+        .await;
+    let _ = tokio::fs::remove_file(&path).await;
+    let status = status.map_err(|e| MinoError::command_failed("bash", e))?;
+
+    if !status.success() {
+        return Err(MinoError::User(format!(
+            "Provisioning failed (exit {}). Re-run to retry; finished layers are skipped.",
+            status.code().unwrap_or(-1)
+        )));
+    }
+    ui::outro_success(
+        &ctx,
+        &format!(
+            "Provisioned {}. Open a new login shell to pick up the layer environment.",
+            names.join(", ")
+        ),
+    );
+    Ok(())
+}
+
+/// Layers to provision: `--layers`, then `MINO_LAYERS`, then `container.layers`.
+/// None at all is fine when `[container.packages]` has something to install.
+fn layer_names(cli: &[String], env: Option<String>, config: &Config) -> MinoResult<Vec<String>> {
+    if !cli.is_empty() {
+        return Ok(cli.to_vec());
+    }
+    if let Some(layers) = env.map(|v| parse_layers_env(&v)).filter(|l| !l.is_empty()) {
+        return Ok(layers);
+    }
+    if !config.container.layers.is_empty() {
+        return Ok(config.container.layers.clone());
+    }
+    if !config.container.packages.is_empty() {
+        return Ok(vec![]);
+    }
+    Err(MinoError::User(
+        "No layers to provision. Pass --layers (e.g. --layers rust,typescript) or set \
+         container.layers or container.packages."
+            .to_string(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layer_names_precedence() {
+        let mut config = Config::default();
+        config.container.layers = vec!["python".to_string()];
+
+        let cli = vec!["rust".to_string()];
+        assert_eq!(
+            layer_names(&cli, Some("typescript".to_string()), &config).unwrap(),
+            ["rust"]
+        );
+        assert_eq!(
+            layer_names(&[], Some("typescript, rust".to_string()), &config).unwrap(),
+            ["typescript", "rust"]
+        );
+        assert_eq!(
+            layer_names(&[], Some(String::new()), &config).unwrap(),
+            ["python"]
+        );
+    }
+
+    #[test]
+    fn layer_names_requires_a_layer() {
+        assert!(layer_names(&[], None, &Config::default()).is_err());
+
+        let mut config = Config::default();
+        config.container.packages.dnf = vec!["jq".to_string()];
+        assert!(layer_names(&[], None, &config).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,98 @@
+//! Shell command - attach a shell to a session, starting one if needed
+
+use crate::cli::args::{ExecArgs, RunArgs, ShellArgs};
+use crate::config::Config;
+use crate::error::{MinoError, MinoResult};
+use crate::session::{Session, SessionManager, SessionStatus};
+use clap::Parser;
+use std::env;
+use std::path::Path;
+use tracing::debug;
+
+/// Execute the shell command
+pub async fn execute(args: ShellArgs, config: &Config) -> MinoResult<()> {
+    // Named session: behave exactly like `mino exec <session>`
+    if let Some(name) = args.session {
+        return super::exec(
+            ExecArgs {
+                session: Some(name),
+                command: vec![],
+            },
+            config,
+        )
+        .await;
+    }
+
+    let cwd = env::current_dir().map_err(|e| MinoError::io("getting current directory", e))?;
+    let manager = SessionManager::new().await?;
+
+    match find_project_session(manager.list().await?, &cwd) {
+        Some(session) => {
+            debug!("Attaching to running session {}", session.name);
+            super::exec(
+                ExecArgs {
+                    session: Some(session.name),
+                    command: vec![],
+                },
+                config,
+            )
+            .await
+        }
+        None => {
+            debug!("No running session for {}, starting one", cwd.display());
+            let run_args = RunArgs::try_parse_from(["run"])
+                .map_err(|e| MinoError::Internal(format!("building default run args: {e}")))?;
+            super::run(run_args, config).await
+        }
+    }
+}
+
+/// Find the most recent running session whose project contains `cwd`
+/// (sessions are expected sorted newest-first).
+fn find_project_session(sessions: Vec<Session>, cwd: &Path) -> Option<Session> {
+    let cwd = cwd.canonicalize().unwrap_or_else(|_| cwd.to_path_buf());
+    sessions
+        .into_iter()
+        .filter(|s| s.status == SessionStatus::Running)
+        .find(|s| cwd.starts_with(&s.project_dir))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn session(name: &str, project: &str, status: SessionStatus) -> Session {
+        Session::new(
+            name.to_string(),
+            PathBuf::from(project),
+            vec!["/bin/zsh".to_string()],
+            status,
+        )
+    }
+
+    #[test]
+    fn finds_running_session_for_project_subdir() {
+        let sessions = vec![
+            session("other", "/work/other", SessionStatus::Running),
+            session("stopped", "/work/app", SessionStatus::Stopped),
+            session("live", "/work/app", SessionStatus::Running),
+        ];
+
+        let found = find_project_session(sessions, Path::new("/work/app/src")).unwrap();
+        assert_eq!(found.name, "live");
+    }
+
+    #[test]
+    fn no_session_for_unrelated_project() {
+        let sessions = vec![session("other", "/work/other", SessionStatus::Running)];
+        assert!(find_project_session(sessions, Path::new("/work/app")).is_none());
+    }
+
+    #[test]
+    fn default_run_args_start_a_shell() {
+        let args = RunArgs::try_parse_from(["run"]).unwrap();
+        assert!(args.command.is_empty());
+        assert!(!args.detach);
+    }
+}
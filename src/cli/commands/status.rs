@@ -1,142 +1,332 @@
 //! Status command - check system health and dependencies
 
+use super::creds::{session_expiry, ExpiryState};
+use crate::cli::args::StatusArgs;
 use crate::config::Config;
 use crate::error::MinoResult;
-use crate::orchestration::{create_runtime, OrbStack, Platform};
+use crate::orchestration::{
+    create_runtime, create_runtime_with_backend, Backend, Lima, OrbStack, Platform, VmProvider,
+};
 use crate::sandbox::RuntimeMode;
 use crate::session::{Session, SessionStatus};
 use crate::ui::{self, UiContext};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
 use std::process::Stdio;
 use tokio::process::Command;
 
-/// Execute the status command
-pub async fn execute(config: &Config) -> MinoResult<()> {
-    let ctx = UiContext::detect();
+/// Version of the `mino status --json` schema. Bump on breaking changes.
+const STATUS_SCHEMA_VERSION: u32 = 1;
+
+/// Outcome of a single status check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckState {
+    Ok,
+    Info,
+    Warn,
+    Error,
+}
+
+/// One line of the status report
+#[derive(Debug, Serialize)]
+pub struct Check {
+    /// Component the check belongs to (e.g. "Docker", "SSH Agent")
+    pub component: &'static str,
+    pub name: String,
+    pub state: CheckState,
+    pub detail: Option<String>,
+    pub hint: Option<String>,
+    /// Whether an error here makes the whole report unhealthy
+    pub critical: bool,
+}
+
+/// Full status report, rendered as UI steps or JSON
+#[derive(Debug, Serialize)]
+pub struct StatusReport {
+    pub schema_version: u32,
+    pub healthy: bool,
+    pub platform: &'static str,
+    pub backend: String,
+    pub checks: Vec<Check>,
+}
+
+/// Collects checks under the current component
+struct Report {
+    component: &'static str,
+    critical: bool,
+    checks: Vec<Check>,
+}
+
+impl Report {
+    fn new() -> Self {
+        Self {
+            component: "",
+            critical: false,
+            checks: Vec::new(),
+        }
+    }
 
-    ui::intro(&ctx, "Mino System Status");
+    /// Start a new component; `critical` components gate overall health
+    fn section(&mut self, component: &'static str, critical: bool) {
+        self.component = component;
+        self.critical = critical;
+    }
 
-    let mut all_ok = true;
+    fn push(
+        &mut self,
+        state: CheckState,
+        name: impl Into<String>,
+        detail: Option<&str>,
+        hint: Option<&str>,
+    ) {
+        self.checks.push(Check {
+            component: self.component,
+            name: name.into(),
+            state,
+            detail: detail.map(String::from),
+            hint: hint.map(String::from),
+            critical: self.critical,
+        });
+    }
+
+    fn ok(&mut self, name: impl Into<String>) {
+        self.push(CheckState::Ok, name, None, None);
+    }
+
+    fn ok_detail(&mut self, name: impl Into<String>, detail: &str) {
+        self.push(CheckState::Ok, name, Some(detail), None);
+    }
+
+    fn info(&mut self, name: impl Into<String>, detail: Option<&str>) {
+        self.push(CheckState::Info, name, detail, None);
+    }
+
+    fn warn(&mut self, name: impl Into<String>, hint: Option<&str>) {
+        self.push(CheckState::Warn, name, None, hint);
+    }
+
+    fn error_detail(&mut self, name: impl Into<String>, detail: &str) {
+        self.push(CheckState::Error, name, Some(detail), None);
+    }
+
+    fn error_hint(&mut self, name: impl Into<String>, hint: &str) {
+        self.push(CheckState::Error, name, None, Some(hint));
+    }
+
+    /// True when no critical component reported an error
+    fn healthy(&self) -> bool {
+        !self
+            .checks
+            .iter()
+            .any(|c| c.critical && c.state == CheckState::Error)
+    }
+}
+
+/// Execute the status command.
+///
+/// Returns whether all critical checks passed.
+pub async fn execute(args: StatusArgs, config: &Config) -> MinoResult<bool> {
     let platform = Platform::detect();
+    let backend: Backend = config.orchestration.backend.parse().unwrap_or_default();
 
-    // Show detected platform
-    ui::section(&ctx, "Platform");
-    ui::step_ok_detail(&ctx, "Detected", platform.name());
+    let mut report = Report::new();
+
+    report.section("Platform", true);
+    if platform == Platform::Unsupported {
+        report.error_detail("Unsupported platform", "Mino supports macOS and Linux only");
+    } else {
+        report.ok_detail("Detected", platform.name());
+    }
 
-    // Check runtime based on platform
+    // Check runtime based on backend and platform
     match platform {
+        Platform::Unsupported => {}
+        _ if backend == Backend::Docker => check_docker(&mut report).await,
+        _ if backend == Backend::Remote => check_remote(&mut report, config).await,
+        _ if backend == Backend::Kubernetes => check_kubernetes(&mut report, config).await,
+        Platform::MacOS if config.vm.provider == VmProvider::Lima.to_string() => {
+            check_lima(&mut report, config).await;
+            if Lima::is_installed().await {
+                check_podman_in_vm(&mut report, config).await;
+            }
+        }
         Platform::MacOS => {
-            all_ok &= check_orbstack(&ctx).await;
+            check_orbstack(&mut report).await;
             // Check Podman (if OrbStack is available)
             if OrbStack::is_installed().await {
-                all_ok &= check_podman_in_vm(&ctx, config).await;
+                check_podman_in_vm(&mut report, config).await;
             }
         }
-        Platform::Linux => {
-            all_ok &= check_native_podman(&ctx).await;
-        }
-        Platform::Unsupported => {
-            ui::step_error(
-                &ctx,
-                "Unsupported platform - Mino supports macOS and Linux only",
-            );
-            all_ok = false;
-        }
+        Platform::Linux => check_native_podman(&mut report).await,
     }
 
     // Check native sandbox
-    ui::section(&ctx, "Native Sandbox");
-    check_native_sandbox_status(&ctx, &platform).await;
+    report.section("Native Sandbox", false);
+    check_native_sandbox_status(&mut report, &platform).await;
 
     // Check cloud CLIs
-    ui::section(&ctx, "Cloud CLIs");
-    check_cli(&ctx, "aws", "aws --version", "brew install awscli").await;
+    report.section("Cloud CLIs", false);
+    check_cli(&mut report, "aws", "aws --version", "brew install awscli").await;
     check_cli(
-        &ctx,
+        &mut report,
         "gcloud",
         "gcloud --version",
         "brew install google-cloud-sdk",
     )
     .await;
-    check_cli(&ctx, "az", "az --version", "brew install azure-cli").await;
-    check_cli(&ctx, "gh", "gh --version", "brew install gh").await;
+    check_cli(&mut report, "az", "az --version", "brew install azure-cli").await;
+    check_cli(&mut report, "gh", "gh --version", "brew install gh").await;
+    check_cli(&mut report, "glab", "glab --version", "brew install glab").await;
+    check_cli(
+        &mut report,
+        "kubectl",
+        "kubectl version --client",
+        "brew install kubectl",
+    )
+    .await;
 
     // Check SSH agent
-    ui::section(&ctx, "SSH Agent");
-    check_ssh_agent(&ctx).await;
+    report.section("SSH Agent", false);
+    check_ssh_agent(&mut report).await;
+
+    if let Ok(sessions) = Session::list_all().await {
+        report.section("Session Credentials", false);
+        check_session_credentials(&mut report, &sessions, Utc::now());
+    }
 
-    if all_ok {
-        ui::outro_success(&ctx, "All critical checks passed");
+    let status = StatusReport {
+        schema_version: STATUS_SCHEMA_VERSION,
+        healthy: report.healthy(),
+        platform: platform.name(),
+        backend: backend.to_string(),
+        checks: report.checks,
+    };
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&status)?);
     } else {
-        ui::outro_warn(&ctx, "Some checks failed - see above for details");
+        render(&UiContext::detect(), &status);
     }
 
-    Ok(())
+    Ok(status.healthy)
 }
 
-async fn check_orbstack(ctx: &UiContext) -> bool {
-    ui::section(ctx, "OrbStack");
+/// Print the report as human-readable sections
+fn render(ctx: &UiContext, status: &StatusReport) {
+    ui::intro(ctx, "Mino System Status");
+
+    let mut component = "";
+    for check in &status.checks {
+        if check.component != component {
+            component = check.component;
+            ui::section(ctx, component);
+        }
+
+        let name = check.name.as_str();
+        let detail = check.detail.as_deref();
+        let hint = check.hint.as_deref();
+        match check.state {
+            CheckState::Ok => match detail {
+                Some(detail) => ui::step_ok_detail(ctx, name, detail),
+                None => ui::step_ok(ctx, name),
+            },
+            CheckState::Info => match detail {
+                Some(detail) => ui::key_value(ctx, name, detail),
+                None => ui::step_info(ctx, name),
+            },
+            CheckState::Warn => match hint {
+                Some(hint) => ui::step_warn_hint(ctx, name, hint),
+                None => ui::step_warn(ctx, name),
+            },
+            CheckState::Error => match detail.or(hint) {
+                Some(detail) => ui::step_error_detail(ctx, name, detail),
+                None => ui::step_error(ctx, name),
+            },
+        }
+    }
+
+    if status.healthy {
+        ui::outro_success(ctx, "All critical checks passed");
+    } else {
+        ui::outro_warn(ctx, "Some checks failed - see above for details");
+    }
+}
+
+async fn check_orbstack(report: &mut Report) {
+    report.section("OrbStack", true);
 
     if !OrbStack::is_installed().await {
-        ui::step_error_detail(ctx, "Not installed", "Install from https://orbstack.dev");
-        return false;
+        report.error_hint("Not installed", "Install from https://orbstack.dev");
+        return;
     }
 
-    ui::step_ok(ctx, "Installed");
+    report.ok("Installed");
 
     // Check if running
     match OrbStack::is_running().await {
-        Ok(true) => {
-            ui::step_ok(ctx, "Running");
-        }
+        Ok(true) => report.ok("Running"),
         Ok(false) => {
-            ui::step_warn_hint(ctx, "Not running", "Run: orb start");
-            return false;
+            report.error_hint("Not running", "Run: orb start");
+            return;
         }
         Err(e) => {
-            ui::step_error_detail(ctx, "Error checking status", &e.to_string());
-            return false;
+            report.error_detail("Error checking status", &e.to_string());
+            return;
         }
     }
 
     // Get version
     if let Ok(version) = OrbStack::version().await {
-        ui::step_ok_detail(ctx, "Version", &version);
+        report.ok_detail("Version", &version);
+    }
+}
+
+async fn check_lima(report: &mut Report, config: &Config) {
+    report.section("Lima", true);
+
+    if !Lima::is_installed().await {
+        report.error_hint("Not installed", "Run: brew install lima");
+        return;
+    }
+
+    match Lima::version().await {
+        Ok(version) => report.ok_detail("Installed", &version),
+        Err(_) => report.ok("Installed"),
     }
 
-    true
+    let lima = Lima::new(config.vm.clone());
+    match lima.vm_status().await {
+        Ok(status) if status == "Running" => report.ok_detail("VM running", lima.vm_name()),
+        Ok(status) if status == "unknown" => report.error_hint(
+            format!("VM '{}' not found", lima.vm_name()),
+            "Run: mino setup",
+        ),
+        Ok(_) => report.error_hint(
+            format!("VM '{}' not running", lima.vm_name()),
+            &format!("Run: limactl start {}", lima.vm_name()),
+        ),
+        Err(e) => report.error_detail("Error checking VM status", &e.to_string()),
+    }
 }
 
-async fn check_podman_in_vm(ctx: &UiContext, config: &Config) -> bool {
-    ui::section(ctx, "Podman (in VM)");
+async fn check_podman_in_vm(report: &mut Report, config: &Config) {
+    report.section("Podman (in VM)", true);
 
     match create_runtime(config) {
         Ok(runtime) => match runtime.is_available().await {
-            Ok(true) => {
-                ui::step_ok(ctx, "Available in VM");
-                true
-            }
+            Ok(true) => report.ok("Available in VM"),
             Ok(false) => {
-                ui::step_warn_hint(
-                    ctx,
-                    "Not installed in VM",
-                    "Run: mino setup (will auto-install)",
-                );
-                false
-            }
-            Err(e) => {
-                ui::step_error_detail(ctx, "Error", &e.to_string());
-                false
+                report.error_hint("Not installed in VM", "Run: mino setup (will auto-install)")
             }
+            Err(e) => report.error_detail("Error", &e.to_string()),
         },
-        Err(e) => {
-            ui::step_error_detail(ctx, "Error", &e.to_string());
-            false
-        }
+        Err(e) => report.error_detail("Error", &e.to_string()),
     }
 }
 
-async fn check_native_podman(ctx: &UiContext) -> bool {
-    ui::section(ctx, "Podman (native)");
+async fn check_native_podman(report: &mut Report) {
+    report.section("Podman (native)", true);
 
     // Check if podman is installed
     let installed = Command::new("podman")
@@ -150,15 +340,14 @@ async fn check_native_podman(ctx: &UiContext) -> bool {
         Ok(output) if output.status.success() => {
             let version = String::from_utf8_lossy(&output.stdout);
             let first_line = version.lines().next().unwrap_or("unknown");
-            ui::step_ok_detail(ctx, "Installed", first_line.trim());
+            report.ok_detail("Installed", first_line.trim());
         }
         _ => {
-            ui::step_error_detail(
-                ctx,
+            report.error_hint(
                 "Not installed",
                 "Install: sudo dnf install podman (or apt-get)",
             );
-            return false;
+            return;
         }
     }
 
@@ -174,21 +363,99 @@ async fn check_native_podman(ctx: &UiContext) -> bool {
         Ok(output) if output.status.success() => {
             let stdout = String::from_utf8_lossy(&output.stdout);
             if stdout.trim() == "true" {
-                ui::step_ok(ctx, "Rootless mode");
+                report.ok("Rootless mode");
             } else {
-                ui::step_warn_hint(ctx, "Not in rootless mode", "Run: podman system migrate");
-                return false;
+                report.error_hint("Not in rootless mode", "Run: podman system migrate");
             }
         }
+        _ => report.warn("Could not check rootless status", None),
+    }
+}
+
+async fn check_docker(report: &mut Report) {
+    report.section("Docker", true);
+
+    let installed = Command::new("docker")
+        .arg("--version")
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .output()
+        .await;
+
+    match installed {
+        Ok(output) if output.status.success() => {
+            let version = String::from_utf8_lossy(&output.stdout);
+            let first_line = version.lines().next().unwrap_or("unknown");
+            report.ok_detail("Installed", first_line.trim());
+        }
         _ => {
-            ui::step_warn(ctx, "Could not check rootless status");
+            report.error_hint(
+                "Not installed",
+                "Install from https://docs.docker.com/get-docker/",
+            );
+            return;
         }
     }
 
-    true
+    let daemon = Command::new("docker")
+        .args(["info", "--format", "{{.ServerVersion}}"])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .output()
+        .await;
+
+    match daemon {
+        Ok(output) if output.status.success() => {
+            let version = String::from_utf8_lossy(&output.stdout);
+            report.ok_detail("Daemon running", version.trim());
+        }
+        _ => report.error_hint(
+            "Daemon not running",
+            "Start Docker Desktop, or run: sudo systemctl start docker",
+        ),
+    }
+}
+
+async fn check_remote(report: &mut Report, config: &Config) {
+    report.section("Remote Host", true);
+
+    let runtime = match create_runtime_with_backend(config, Some(Backend::Remote)) {
+        Ok(runtime) => runtime,
+        Err(e) => {
+            report.error_detail("Not configured", &e.to_string());
+            return;
+        }
+    };
+    let host = &config.orchestration.remote.host;
+
+    match runtime.ensure_ready().await {
+        Ok(()) => report.ok_detail("Podman ready", host),
+        Err(e) => report.error_detail(format!("{} not ready", host), &e.to_string()),
+    }
+}
+
+async fn check_kubernetes(report: &mut Report, config: &Config) {
+    report.section("Kubernetes", true);
+
+    let runtime = match create_runtime_with_backend(config, Some(Backend::Kubernetes)) {
+        Ok(runtime) => runtime,
+        Err(e) => {
+            report.error_detail("Not configured", &e.to_string());
+            return;
+        }
+    };
+    let context = match config.orchestration.kubernetes.context.as_str() {
+        "" => "current context",
+        context => context,
+    };
+
+    match runtime.ensure_ready().await {
+        Ok(()) => report.ok_detail("Cluster ready", context),
+        Err(e) => report.error_detail(format!("{} not ready", context), &e.to_string()),
+    }
 }
 
-async fn check_cli(ctx: &UiContext, name: &str, version_cmd: &str, install_hint: &str) {
+async fn check_cli(report: &mut Report, name: &str, version_cmd: &str, install_hint: &str) {
     let parts: Vec<&str> = version_cmd.split_whitespace().collect();
     let result = Command::new(parts[0])
         .args(&parts[1..])
@@ -201,19 +468,16 @@ async fn check_cli(ctx: &UiContext, name: &str, version_cmd: &str, install_hint:
         Ok(output) if output.status.success() => {
             let version = String::from_utf8_lossy(&output.stdout);
             let first_line = version.lines().next().unwrap_or("unknown");
-            ui::step_ok_detail(ctx, name, first_line.trim());
-        }
-        _ => {
-            ui::step_warn_hint(
-                ctx,
-                &format!("{} not found", name),
-                &format!("Install: {}", install_hint),
-            );
+            report.ok_detail(name, first_line.trim());
         }
+        _ => report.warn(
+            format!("{} not found", name),
+            Some(&format!("Install: {}", install_hint)),
+        ),
     }
 }
 
-async fn check_ssh_agent(ctx: &UiContext) {
+async fn check_ssh_agent(report: &mut Report) {
     match std::env::var("SSH_AUTH_SOCK") {
         Ok(sock) => {
             // Try to list identities
@@ -229,39 +493,58 @@ async fn check_ssh_agent(ctx: &UiContext) {
                     let stdout = String::from_utf8_lossy(&output.stdout);
                     let key_count = stdout.lines().count();
                     if output.status.success() && key_count > 0 {
-                        ui::step_ok_detail(ctx, "Running", &format!("{} keys loaded", key_count));
+                        report.ok_detail("Running", &format!("{} keys loaded", key_count));
                     } else {
-                        ui::step_warn_hint(ctx, "Running", "No keys loaded. Run: ssh-add");
+                        report.warn("Running", Some("No keys loaded. Run: ssh-add"));
                     }
                 }
-                Err(_) => {
-                    ui::step_warn(ctx, "ssh-add failed");
-                }
+                Err(_) => report.warn("ssh-add failed", None),
             }
-            ui::key_value(ctx, "Socket", &sock);
+            report.info("Socket", Some(&sock));
         }
-        Err(_) => {
-            ui::step_error_detail(
-                ctx,
-                "Not running",
-                "SSH_AUTH_SOCK not set. Start ssh-agent.",
-            );
+        Err(_) => report.error_detail("Not running", "SSH_AUTH_SOCK not set. Start ssh-agent."),
+    }
+}
+
+/// Time left on the credentials of active sessions, warning when some are
+/// about to expire or already have
+fn check_session_credentials(report: &mut Report, sessions: &[Session], now: DateTime<Utc>) {
+    let active = sessions.iter().filter(|s| {
+        matches!(
+            s.status,
+            SessionStatus::Running | SessionStatus::Starting | SessionStatus::Paused
+        )
+    });
+    for session in active {
+        let expiry = session_expiry(session, now);
+        if expiry.is_empty() {
+            continue;
+        }
+        let summary = expiry
+            .iter()
+            .map(|(label, _)| label.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        if expiry.iter().all(|(_, state)| *state == ExpiryState::Valid) {
+            report.ok_detail(&session.name, &summary);
+        } else {
+            report.warn(&session.name, Some(&summary));
         }
     }
 }
 
 /// Check native sandbox prerequisites and stale sessions.
-async fn check_native_sandbox_status(ctx: &UiContext, platform: &Platform) {
+async fn check_native_sandbox_status(report: &mut Report, platform: &Platform) {
     match platform {
-        Platform::MacOS => check_native_sandbox_macos(ctx).await,
-        Platform::Linux => check_native_sandbox_linux(ctx).await,
+        Platform::MacOS => check_native_sandbox_macos(report).await,
+        Platform::Linux => check_native_sandbox_linux(report).await,
         Platform::Unsupported => {}
     }
 
-    check_stale_native_sessions(ctx).await;
+    check_stale_native_sessions(report).await;
 }
 
-async fn check_native_sandbox_macos(ctx: &UiContext) {
+async fn check_native_sandbox_macos(report: &mut Report) {
     // Check sandbox user exists
     let user_exists = Command::new("dscl")
         .args([
@@ -277,11 +560,11 @@ async fn check_native_sandbox_macos(ctx: &UiContext) {
         .unwrap_or(false);
 
     if user_exists {
-        ui::step_ok(ctx, "Sandbox user (_mino_agent)");
+        report.ok("Sandbox user (_mino_agent)");
     } else {
-        ui::step_info(
-            ctx,
+        report.info(
             "Sandbox user not configured (run: mino setup --native)",
+            None,
         );
     }
 
@@ -296,24 +579,24 @@ async fn check_native_sandbox_macos(ctx: &UiContext) {
         .unwrap_or(false);
 
     if helper_exists {
-        ui::step_ok(ctx, "Helper binary installed");
+        report.ok("Helper binary installed");
     } else {
-        ui::step_info(
-            ctx,
+        report.info(
             "Helper binary not installed (run: mino setup --native)",
+            None,
         );
     }
 
     // Check sudoers
     let sudoers_exists = std::path::Path::new("/etc/sudoers.d/mino").exists();
     if sudoers_exists {
-        ui::step_ok(ctx, "Sudoers configured");
+        report.ok("Sudoers configured");
     } else {
-        ui::step_info(ctx, "Sudoers not configured (run: mino setup --native)");
+        report.info("Sudoers not configured (run: mino setup --native)", None);
     }
 }
 
-async fn check_native_sandbox_linux(ctx: &UiContext) {
+async fn check_native_sandbox_linux(report: &mut Report) {
     // Check user namespaces
     let userns_output = Command::new("cat")
         .arg("/proc/sys/user/max_user_namespaces")
@@ -329,14 +612,12 @@ async fn check_native_sandbox_linux(ctx: &UiContext) {
                 .parse()
                 .unwrap_or(0);
             if val > 0 {
-                ui::step_ok_detail(ctx, "User namespaces enabled", &format!("max: {}", val));
+                report.ok_detail("User namespaces enabled", &format!("max: {}", val));
             } else {
-                ui::step_warn(ctx, "User namespaces disabled");
+                report.warn("User namespaces disabled", None);
             }
         }
-        _ => {
-            ui::step_ok(ctx, "User namespaces (could not check, assuming enabled)");
-        }
+        _ => report.ok("User namespaces (could not check, assuming enabled)"),
     }
 
     // Check unshare binary
@@ -350,23 +631,23 @@ async fn check_native_sandbox_linux(ctx: &UiContext) {
         .unwrap_or(false);
 
     if unshare_exists {
-        ui::step_ok(ctx, "unshare binary available");
+        report.ok("unshare binary available");
     } else {
-        ui::step_warn(ctx, "unshare not found (install util-linux)");
+        report.warn("unshare not found (install util-linux)", None);
     }
 }
 
 /// Check for stale native sessions where the PID is no longer alive.
-async fn check_stale_native_sessions(ctx: &UiContext) {
+async fn check_stale_native_sessions(report: &mut Report) {
     if let Ok(sessions) = Session::list_all().await {
         let stale_count = count_stale_native_sessions(&sessions);
         if stale_count > 0 {
-            ui::step_warn(
-                ctx,
-                &format!(
+            report.warn(
+                format!(
                     "{} stale native session(s) detected. Clean up with: mino list --all",
                     stale_count
                 ),
+                None,
             );
         }
     }
@@ -406,7 +687,6 @@ pub async fn cleanup_stale_native_sessions() -> crate::error::MinoResult<usize>
     }
 
     let manager = crate::session::SessionManager::new().await?;
-    let platform = crate::sandbox::native::create_sandbox_platform().ok();
     let mut cleaned = 0;
 
     for session in &stale {
@@ -418,7 +698,7 @@ pub async fn cleanup_stale_native_sessions() -> crate::error::MinoResult<usize>
 
         // Clean up sandbox resources (ACLs, pf rules) via trait dispatch.
         // On Linux this is a no-op; on macOS it removes ACLs and pf rules.
-        if let Some(ref platform) = platform {
+        if let Ok(platform) = crate::sandbox::native::sandbox_platform_for(session) {
             let sandbox_user = session
                 .sandbox_user
                 .as_deref()
@@ -477,6 +757,34 @@ mod tests {
     use super::*;
     use crate::orchestration::mock::test_session;
 
+    #[test]
+    fn session_credentials_warn_when_expiring() {
+        let now = Utc::now();
+        let with_expiry = |name: &str, status, minutes: i64| {
+            let mut session = test_session(name, status, Some("c1"));
+            session.credential_expiry =
+                [("aws".to_string(), now + chrono::Duration::minutes(minutes))].into();
+            session
+        };
+        let sessions = vec![
+            with_expiry("fresh", SessionStatus::Running, 40),
+            with_expiry("short", SessionStatus::Running, 5),
+            with_expiry("done", SessionStatus::Stopped, -30),
+            test_session("plain", SessionStatus::Running, Some("c2")),
+        ];
+
+        let mut report = Report::new();
+        report.section("Session Credentials", false);
+        check_session_credentials(&mut report, &sessions, now);
+
+        assert_eq!(report.checks.len(), 2);
+        assert_eq!(report.checks[0].state, CheckState::Ok);
+        assert_eq!(report.checks[0].detail.as_deref(), Some("aws: 40m left"));
+        assert_eq!(report.checks[1].state, CheckState::Warn);
+        assert_eq!(report.checks[1].hint.as_deref(), Some("aws: 5m left"));
+        assert!(report.healthy());
+    }
+
     #[test]
     fn is_pid_alive_none_returns_false() {
         assert!(!is_pid_alive(None));
@@ -566,6 +874,56 @@ mod tests {
         assert!(!is_stale_native_session(&session));
     }
 
+    // ---- report tests ----
+
+    #[test]
+    fn report_unhealthy_on_critical_error() {
+        let mut report = Report::new();
+        report.section("Docker", true);
+        report.ok_detail("Installed", "Docker version 27.0.1");
+        assert!(report.healthy());
+
+        report.error_hint("Daemon not running", "Start Docker Desktop");
+        assert!(!report.healthy());
+    }
+
+    #[test]
+    fn report_ignores_non_critical_failures() {
+        let mut report = Report::new();
+        report.section("Podman (native)", true);
+        report.warn("Could not check rootless status", None);
+        report.section("SSH Agent", false);
+        report.error_detail("Not running", "SSH_AUTH_SOCK not set. Start ssh-agent.");
+        assert!(report.healthy());
+    }
+
+    #[test]
+    fn status_report_json_schema() {
+        let mut report = Report::new();
+        report.section("Cloud CLIs", false);
+        report.warn("aws not found", Some("Install: brew install awscli"));
+
+        let status = StatusReport {
+            schema_version: STATUS_SCHEMA_VERSION,
+            healthy: report.healthy(),
+            platform: "Linux",
+            backend: "podman".to_string(),
+            checks: report.checks,
+        };
+        let json = serde_json::to_value(&status).unwrap();
+
+        assert_eq!(json["schema_version"], 1);
+        assert_eq!(json["healthy"], true);
+        assert_eq!(json["backend"], "podman");
+        let check = &json["checks"][0];
+        assert_eq!(check["component"], "Cloud CLIs");
+        assert_eq!(check["name"], "aws not found");
+        assert_eq!(check["state"], "warn");
+        assert!(check["detail"].is_null());
+        assert_eq!(check["hint"], "Install: brew install awscli");
+        assert_eq!(check["critical"], false);
+    }
+
     #[cfg(unix)]
     #[test]
     fn is_stale_native_running_live_pid_is_not_stale() {
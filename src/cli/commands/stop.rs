@@ -2,13 +2,15 @@
 
 use crate::cli::args::StopArgs;
 use crate::config::Config;
+use crate::credentials::files::SessionCredentialFiles;
 use crate::error::{MinoError, MinoResult};
-use crate::orchestration::{create_runtime, ContainerRuntime};
+use crate::orchestration::{create_runtime_with_backend, ContainerRuntime, ContainerUsage};
 use crate::sandbox::RuntimeMode;
-use crate::session::{Session, SessionManager, SessionStatus};
+use crate::session::{Session, SessionManager, SessionStatus, UsageLog};
 use crate::ui::{self, TaskSpinner, UiContext};
+use crate::worktree;
 use console::style;
-use tracing::warn;
+use tracing::{debug, warn};
 
 /// Execute the stop command
 pub async fn execute(args: StopArgs, config: &Config) -> MinoResult<()> {
@@ -25,15 +27,30 @@ pub async fn execute(args: StopArgs, config: &Config) -> MinoResult<()> {
 
     if !matches!(
         session.status,
-        SessionStatus::Running | SessionStatus::Starting
+        SessionStatus::Running | SessionStatus::Starting | SessionStatus::Paused
     ) {
+        // Sweep anything an exited container left behind
+        if (config.session.cleanup_resources || args.remove_scratch)
+            && session.runtime_mode != Some(RuntimeMode::Native)
+        {
+            let runtime = create_runtime_with_backend(config, session.backend)?;
+            if config.session.cleanup_resources {
+                remove_session_container(&session, &*runtime).await;
+            }
+            if args.remove_scratch {
+                remove_session_volumes(&session, &*runtime).await;
+            }
+        }
+        release_session_credentials(&session).await;
         ui::step_info(
             &ctx,
             &format!("Session {} is already {}", styled_name, session.status),
         );
+        release_worktree(&ctx, &session).await;
         return Ok(());
     }
 
+    let mut usage = None;
     if session.runtime_mode == Some(RuntimeMode::Native) {
         // Native mode: kill the process directly
         if let Some(pid) = session.process_id {
@@ -44,7 +61,7 @@ pub async fn execute(args: StopArgs, config: &Config) -> MinoResult<()> {
 
             // Clean up sandbox resources (ACLs, pf rules) even if the helper's
             // auto-cleanup didn't run (e.g., mino was killed externally)
-            if let Ok(platform) = crate::sandbox::native::create_sandbox_platform() {
+            if let Ok(platform) = crate::sandbox::native::sandbox_platform_for(&session) {
                 let sandbox_user = session
                     .sandbox_user
                     .as_deref()
@@ -63,26 +80,90 @@ pub async fn execute(args: StopArgs, config: &Config) -> MinoResult<()> {
         }
     } else if session.container_id.is_some() {
         // Container mode: existing logic
-        let runtime = create_runtime(config)?;
+        let runtime = create_runtime_with_backend(config, session.backend)?;
 
         let mut spinner = TaskSpinner::new(&ctx);
         spinner.start(&format!("Stopping session {}...", styled_name));
 
-        stop_container(&session, &*runtime, args.force).await?;
+        usage =
+            stop_running_container(&session, &*runtime, args.force, args.remove_scratch).await?;
 
         spinner.stop(&format!("Session {} stopped", styled_name));
     } else {
         ui::step_ok(&ctx, &format!("Session {} stopped", styled_name));
     }
+    release_session_credentials(&session).await;
 
     // Update session status
     manager
         .update_status(&args.session, SessionStatus::Stopped)
         .await?;
+    UsageLog::new(config).record(&session, usage.as_ref()).await;
+    release_worktree(&ctx, &session).await;
 
     Ok(())
 }
 
+/// Remove the session's `--worktree` checkout, keeping its branch.
+///
+/// A worktree git refuses to remove (uncommitted changes) is kept, with a
+/// hint for removing it by hand.
+pub(crate) async fn release_worktree(ctx: &UiContext, session: &Session) {
+    let Some(path) = &session.worktree else {
+        return;
+    };
+    if let Err(e) = worktree::remove(path).await {
+        debug!("Keeping worktree {}: {}", path.display(), e);
+        ui::step_warn_hint(
+            ctx,
+            &format!(
+                "Kept worktree {} (branch {}): it has uncommitted changes",
+                path.display(),
+                worktree::branch_name(&session.name)
+            ),
+            &format!(
+                "Commit or discard them, then run: git worktree remove {}",
+                path.display()
+            ),
+        );
+    }
+}
+
+/// Sample a running container's usage for the usage log before it is stopped.
+///
+/// Best-effort: a paused container or an unsupported backend yields `None`.
+async fn sample_usage(session: &Session, runtime: &dyn ContainerRuntime) -> Option<ContainerUsage> {
+    if session.status != SessionStatus::Running {
+        return None;
+    }
+    let container_id = session.container_id.as_deref()?;
+    match runtime.container_usage(container_id).await {
+        Ok(usage) => Some(usage),
+        Err(e) => {
+            debug!("No usage sample for session {}: {}", session.name, e);
+            None
+        }
+    }
+}
+
+/// Stop a running container session. Its scratch volume survives, so a
+/// later session with the same name picks it up, unless `remove_scratch`.
+///
+/// Returns the usage sampled just before the stop.
+async fn stop_running_container(
+    session: &Session,
+    runtime: &dyn ContainerRuntime,
+    force: bool,
+    remove_scratch: bool,
+) -> MinoResult<Option<ContainerUsage>> {
+    let usage = sample_usage(session, runtime).await;
+    stop_container(session, runtime, force).await?;
+    if remove_scratch {
+        remove_session_volumes(session, runtime).await;
+    }
+    Ok(usage)
+}
+
 /// Stop a native sandbox process by sending a signal.
 ///
 /// Sends SIGTERM (graceful) or SIGKILL (force). Tolerates ESRCH (process
@@ -126,7 +207,7 @@ async fn stop_container(
 ) -> MinoResult<bool> {
     if !matches!(
         session.status,
-        SessionStatus::Running | SessionStatus::Starting
+        SessionStatus::Running | SessionStatus::Starting | SessionStatus::Paused
     ) {
         return Ok(false);
     }
@@ -136,6 +217,13 @@ async fn stop_container(
         None => return Ok(true),
     };
 
+    // A frozen container cannot handle the stop signal; thaw it first
+    if session.status == SessionStatus::Paused {
+        if let Err(e) = runtime.unpause(container_id).await {
+            warn!("Failed to unpause container before stopping: {}", e);
+        }
+    }
+
     let stop_result = if force {
         runtime.kill(container_id).await
     } else {
@@ -156,14 +244,110 @@ async fn stop_container(
             e
         );
     }
+    SessionManager::remove_services(session, runtime).await;
 
     Ok(true)
 }
 
+/// Remove a finished session's container, session-scoped volumes, and
+/// worktree, as auto-cleanup does once the session record expires.
+///
+/// Best-effort: failures are logged, since the resources may already be gone.
+/// Credential files and deploy keys are released separately by
+/// [`release_session_credentials`], which does not depend on the runtime.
+pub(crate) async fn remove_session_resources(session: &Session, runtime: &dyn ContainerRuntime) {
+    remove_session_container(session, runtime).await;
+    remove_session_volumes(session, runtime).await;
+    remove_session_worktree(session).await;
+}
+
+/// Remove a finished session's container and services, keeping its scratch
+/// volume and worktree
+async fn remove_session_container(session: &Session, runtime: &dyn ContainerRuntime) {
+    if let Some(container_id) = &session.container_id {
+        if let Err(e) = runtime.remove(container_id).await {
+            warn!(
+                "Failed to remove container {}: {}",
+                &container_id[..12.min(container_id.len())],
+                e
+            );
+        }
+    }
+    SessionManager::remove_services(session, runtime).await;
+}
+
+/// Stop a session's container if it is still running, then remove the
+/// container, its session-scoped volumes, its worktree, and its credentials.
+pub(crate) async fn discard_session(
+    session: &Session,
+    runtime: &dyn ContainerRuntime,
+) -> MinoResult<()> {
+    if stop_container(session, runtime, false).await? {
+        remove_session_volumes(session, runtime).await;
+        remove_session_worktree(session).await;
+    } else {
+        remove_session_resources(session, runtime).await;
+    }
+    release_session_credentials(session).await;
+    Ok(())
+}
+
+/// Release what a session holds on the host outside the runtime: its
+/// credential files and its deploy keys and ssh-agent.
+///
+/// Runs on every stop path and on auto-cleanup. Each step tolerates
+/// resources that are already gone, so releasing twice is harmless.
+pub(crate) async fn release_session_credentials(session: &Session) {
+    remove_session_credential_files(session).await;
+    remove_session_deploy_key(session).await;
+}
+
+/// Remove volumes that belong to a single session (currently its scratch volume).
+///
+/// Per-project home volumes and shared caches are left alone.
+async fn remove_session_volumes(session: &Session, runtime: &dyn ContainerRuntime) {
+    if let Some(volume) = &session.scratch_volume {
+        if let Err(e) = runtime.volume_remove(volume).await {
+            warn!("Failed to remove scratch volume {}: {}", volume, e);
+        }
+    }
+}
+
+/// Remove a session's worktree unless it has uncommitted changes
+async fn remove_session_worktree(session: &Session) {
+    if let Some(path) = &session.worktree {
+        if let Err(e) = worktree::remove(path).await {
+            warn!("Kept worktree {}: {}", path.display(), e);
+        }
+    }
+}
+
+/// Remove the credential files written for a session (`delivery = "file"`,
+/// `--refresh-credentials`, git identity)
+async fn remove_session_credential_files(session: &Session) {
+    if let Err(e) = SessionCredentialFiles::for_session(&session.name)
+        .remove()
+        .await
+    {
+        warn!("Failed to remove credential files: {}", e);
+    }
+}
+
+/// Delete the deploy keys of a `--deploy-key` session and stop its agent
+async fn remove_session_deploy_key(session: &Session) {
+    if let Some(deploy_key) = &session.deploy_key {
+        if let Err(e) = deploy_key.remove().await {
+            warn!("{}", e);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::orchestration::mock::{test_session, MockRuntime};
+    use crate::orchestration::mock::{test_session, MockRuntime, RecordingExecutor};
+    use crate::orchestration::NativePodmanRuntime;
+    use std::sync::Arc;
 
     // -- Container stop tests --
 
@@ -211,6 +395,17 @@ mod tests {
         mock.assert_called("remove", 1);
     }
 
+    #[tokio::test]
+    async fn stop_paused_unpauses_first() {
+        let session = test_session("test", SessionStatus::Paused, Some("container-abc123"));
+        let mock = MockRuntime::new();
+
+        let stopped = stop_container(&session, &mock, false).await.unwrap();
+        assert!(stopped);
+        mock.assert_called_with("unpause", &["container-abc123"]);
+        mock.assert_called_with("stop", &["container-abc123"]);
+    }
+
     #[tokio::test]
     async fn stop_no_container_id() {
         let session = test_session("test", SessionStatus::Running, None);
@@ -247,6 +442,144 @@ mod tests {
             .contains("connection refused"));
     }
 
+    #[tokio::test]
+    async fn stop_running_session_releases_deploy_key() {
+        let dir = tempfile::tempdir().unwrap();
+        let key_dir = dir.path().join("deploy-key");
+        std::fs::create_dir_all(&key_dir).unwrap();
+        let mut session = test_session("test", SessionStatus::Running, Some("container-abc123"));
+        session.deploy_key = Some(crate::credentials::deploy_key::SessionDeployKey {
+            dir: key_dir.clone(),
+            agent_pid: u32::MAX,
+            keys: vec![],
+        });
+        let mock = MockRuntime::new();
+
+        stop_running_container(&session, &mock, false, false)
+            .await
+            .unwrap();
+        release_session_credentials(&session).await;
+
+        mock.assert_called("stop", 1);
+        assert!(!key_dir.exists());
+    }
+
+    #[tokio::test]
+    async fn stop_running_session_removes_credential_files() {
+        let name = format!("test-stop-creds-{}", uuid::Uuid::new_v4());
+        let files = SessionCredentialFiles::for_session(&name);
+        files.create().await.unwrap();
+        files.write_token("gh-token", "ghs_x").await.unwrap();
+        let session = test_session(&name, SessionStatus::Running, Some("container-abc123"));
+        let mock = MockRuntime::new();
+
+        stop_running_container(&session, &mock, false, false)
+            .await
+            .unwrap();
+        release_session_credentials(&session).await;
+
+        mock.assert_called("remove", 1);
+        assert!(!files.dir().exists());
+    }
+
+    #[tokio::test]
+    async fn stop_keeps_scratch_volume_unless_asked() {
+        let mut session = test_session("test", SessionStatus::Running, Some("container-abc123"));
+        session.scratch_volume = Some("mino-scratch-test".to_string());
+
+        let mock = MockRuntime::new();
+        stop_running_container(&session, &mock, false, false)
+            .await
+            .unwrap();
+        mock.assert_called("volume_remove", 0);
+
+        let mock = MockRuntime::new();
+        stop_running_container(&session, &mock, false, true)
+            .await
+            .unwrap();
+        mock.assert_called_with("volume_remove", &["mino-scratch-test"]);
+    }
+
+    // -- End-to-end through the podman CLI runtime --
+
+    #[tokio::test]
+    async fn stop_runs_podman_stop_and_rm() {
+        let session = test_session("test", SessionStatus::Running, Some("container-abc123"));
+        let exec = Arc::new(RecordingExecutor::new());
+        let runtime = NativePodmanRuntime::with_executor(exec.clone());
+
+        assert!(stop_container(&session, &runtime, false).await.unwrap());
+
+        exec.assert_ran("podman stop container-abc123");
+        exec.assert_ran("podman rm -f container-abc123");
+        exec.assert_not_ran("podman kill");
+    }
+
+    #[tokio::test]
+    async fn stop_tolerates_podman_no_such_container() {
+        let session = test_session("test", SessionStatus::Running, Some("container-abc123"));
+        let exec = Arc::new(RecordingExecutor::new().on_fail(
+            "podman stop",
+            125,
+            "Error: no such container container-abc123",
+        ));
+        let runtime = NativePodmanRuntime::with_executor(exec.clone());
+
+        assert!(stop_container(&session, &runtime, false).await.unwrap());
+        exec.assert_ran("podman rm -f container-abc123");
+    }
+
+    // -- Resource cleanup tests --
+
+    #[tokio::test]
+    async fn remove_session_resources_removes_container_and_scratch() {
+        let mut session = test_session("test", SessionStatus::Stopped, Some("container-abc123"));
+        session.scratch_volume = Some("mino-scratch-test".to_string());
+        let mock = MockRuntime::new();
+
+        remove_session_resources(&session, &mock).await;
+
+        mock.assert_called_with("remove", &["container-abc123"]);
+        mock.assert_called_with("volume_remove", &["mino-scratch-test"]);
+    }
+
+    #[tokio::test]
+    async fn remove_session_resources_is_best_effort() {
+        let mut session = test_session("test", SessionStatus::Failed, Some("container-abc123"));
+        session.scratch_volume = Some("mino-scratch-test".to_string());
+        let mock = MockRuntime::new().on_err(
+            "remove",
+            MinoError::Internal("connection refused".to_string()),
+        );
+
+        remove_session_resources(&session, &mock).await;
+
+        // Volume removal still attempted after the container error
+        mock.assert_called("volume_remove", 1);
+    }
+
+    #[tokio::test]
+    async fn remove_session_resources_without_resources_is_noop() {
+        let session = test_session("test", SessionStatus::Stopped, None);
+        let mock = MockRuntime::new();
+
+        remove_session_resources(&session, &mock).await;
+        mock.assert_no_calls();
+    }
+
+    #[tokio::test]
+    async fn discard_session_stops_running_container() {
+        let mut session = test_session("test", SessionStatus::Running, Some("container-abc123"));
+        session.scratch_volume = Some("mino-scratch-test".to_string());
+        let mock = MockRuntime::new();
+
+        discard_session(&session, &mock).await.unwrap();
+
+        mock.assert_called_with("stop", &["container-abc123"]);
+        mock.assert_called("remove", 1);
+        mock.assert_called_with("volume_remove", &["mino-scratch-test"]);
+    }
+
     // -- Native stop tests --
 
     #[cfg(unix)]
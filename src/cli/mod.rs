@@ -3,4 +3,4 @@
 pub mod args;
 pub mod commands;
 
-pub use args::{Cli, Commands, CompletionsArgs};
+pub use args::{Cli, Commands, CompletionsArgs, ProgressMode};
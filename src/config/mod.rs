@@ -8,7 +8,8 @@ pub use schema::Config;
 pub(crate) use toml_editor::TomlEditor;
 
 use crate::error::{MinoError, MinoResult};
-use std::path::{Path, PathBuf};
+use crate::portable::{is_path_separator, normalize_newlines, windows_drive_prefix};
+use std::path::{Component, Path, PathBuf};
 use tokio::fs;
 use toml::Value;
 use tracing::debug;
@@ -63,11 +64,30 @@ impl ConfigManager {
         Self::state_dir().join("sessions")
     }
 
+    /// Get the checkpoint archive directory path
+    pub fn checkpoints_dir() -> PathBuf {
+        Self::state_dir().join("checkpoints")
+    }
+
+    /// Get the per-session git worktree directory path
+    pub fn worktrees_dir() -> PathBuf {
+        Self::state_dir().join("worktrees")
+    }
+
     /// Get the credentials cache directory path
     pub fn credentials_dir() -> PathBuf {
         Self::state_dir().join("credentials")
     }
 
+    /// Get the directory of per-session credential files. Prefers the
+    /// tmpfs-backed `$XDG_RUNTIME_DIR` so the files never reach disk.
+    pub fn session_credentials_dir() -> PathBuf {
+        match std::env::var_os("XDG_RUNTIME_DIR") {
+            Some(dir) if !dir.is_empty() => PathBuf::from(dir).join("mino/session-credentials"),
+            _ => Self::state_dir().join("session-credentials"),
+        }
+    }
+
     /// Get the cache state directory path (sidecar JSON files)
     pub fn cache_state_dir() -> PathBuf {
         Self::state_dir().join("cache")
@@ -78,6 +98,21 @@ impl ConfigManager {
         Self::state_dir().join("audit.log")
     }
 
+    /// Get the session usage log file path
+    pub fn usage_log_path() -> PathBuf {
+        Self::state_dir().join("usage.log")
+    }
+
+    /// Get the path of the credential providers chosen per project
+    pub fn credential_selections_path() -> PathBuf {
+        Self::state_dir().join("credential_selections.json")
+    }
+
+    /// Get the cache hit/miss statistics file path
+    pub fn cache_stats_path() -> PathBuf {
+        Self::state_dir().join("cache-stats.log")
+    }
+
     /// Search from `start_dir` upward for `.mino.toml`.
     /// Stops at filesystem root. Returns the path if found.
     pub fn find_local_config(start_dir: &Path) -> Option<PathBuf> {
@@ -115,6 +150,9 @@ impl ConfigManager {
 
     /// Load merged configuration: global config merged with optional local config.
     ///
+    /// Host paths in each file's `container.volumes` resolve against that
+    /// file's directory, with `~` expanded.
+    ///
     /// Precedence: local `.mino.toml` > global `~/.config/mino/config.toml` > defaults.
     /// (CLI flags override the result separately at the call site.)
     pub async fn load_merged(&self, local_path: Option<&Path>) -> MinoResult<Config> {
@@ -126,12 +164,14 @@ impl ConfigManager {
                     e,
                 )
             })?;
-            content
-                .parse::<Value>()
-                .map_err(|e| MinoError::ConfigInvalid {
+            let mut value = normalize_newlines(&content).parse::<Value>().map_err(|e| {
+                MinoError::ConfigInvalid {
                     path: self.config_path.clone(),
                     reason: e.to_string(),
-                })?
+                }
+            })?;
+            resolve_volume_paths(&mut value, config_dir(&self.config_path));
+            value
         } else {
             debug!("Global config not found, using defaults");
             Value::Table(toml::map::Map::new())
@@ -143,13 +183,14 @@ impl ConfigManager {
                 let content = fs::read_to_string(path).await.map_err(|e| {
                     MinoError::io(format!("reading local config from {}", path.display()), e)
                 })?;
-                let local_value =
-                    content
-                        .parse::<Value>()
-                        .map_err(|e| MinoError::ConfigInvalid {
+                let mut local_value =
+                    normalize_newlines(&content).parse::<Value>().map_err(|e| {
+                        MinoError::ConfigInvalid {
                             path: path.to_path_buf(),
                             reason: e.to_string(),
-                        })?;
+                        }
+                    })?;
+                resolve_volume_paths(&mut local_value, config_dir(path));
                 debug!("Merging local config from {} over global", path.display());
                 Self::merge_toml(global_value, local_value)
             }
@@ -166,13 +207,14 @@ impl ConfigManager {
             None => self.config_path.display().to_string(),
         };
 
-        let config: Config =
+        let mut config: Config =
             merged_value
                 .try_into()
                 .map_err(|e: toml::de::Error| MinoError::ConfigInvalid {
                     path: local_path.unwrap_or(&self.config_path).to_path_buf(),
                     reason: format!("{} (source: {})", e, config_source),
                 })?;
+        config.local_path = local_path.map(Path::to_path_buf);
 
         // Validate sandbox config: reject overlapping auto_passthrough_dirs / auto_copy_dirs.
         // This mirrors `load_from_file`. Without it, the main CLI path (which uses
@@ -205,9 +247,11 @@ impl ConfigManager {
             .await
             .map_err(|e| MinoError::io(format!("reading config from {}", path.display()), e))?;
 
-        let config: Config = toml::from_str(&content).map_err(|e| MinoError::ConfigInvalid {
-            path: path.to_path_buf(),
-            reason: e.to_string(),
+        let config: Config = toml::from_str(&normalize_newlines(&content)).map_err(|e| {
+            MinoError::ConfigInvalid {
+                path: path.to_path_buf(),
+                reason: e.to_string(),
+            }
         })?;
 
         // Validate sandbox config: reject overlapping auto_passthrough_dirs / auto_copy_dirs.
@@ -344,6 +388,89 @@ impl Default for ConfigManager {
     }
 }
 
+/// Directory containing `config_path`, against which relative paths resolve
+fn config_dir(config_path: &Path) -> &Path {
+    config_path.parent().unwrap_or_else(|| Path::new("."))
+}
+
+/// Host path half of a `source:target[:opts]` volume spec.
+///
+/// Parsed from the right: the target is always an absolute container path,
+/// so a last segment that isn't one is the options suffix, and everything
+/// before the target is the source, colons included (`C:\data`, `./a:b`).
+/// Returns `None` for named volumes: a bare source with no path separator
+/// that does not start with `.` or `~`.
+pub(crate) fn volume_host_source(spec: &str) -> Option<&str> {
+    let (head, last) = spec.rsplit_once(':')?;
+    let without_opts = if last.starts_with('/') { spec } else { head };
+    let (source, _target) = without_opts.rsplit_once(':')?;
+    let is_path =
+        source.contains(is_path_separator) || source.starts_with('.') || source.starts_with('~');
+    is_path.then_some(source)
+}
+
+/// Expand `~` and resolve a relative volume source against `base_dir`.
+fn resolve_volume_source(source: &str, base_dir: &Path) -> PathBuf {
+    // Drive-letter paths are already absolute, even where `Path` doesn't know it
+    if windows_drive_prefix(source).is_some() {
+        return PathBuf::from(source);
+    }
+
+    let path = match source.strip_prefix('~') {
+        Some(rest) if rest.is_empty() || rest.starts_with(is_path_separator) => {
+            let home = dirs::home_dir().unwrap_or_else(|| PathBuf::from("/"));
+            home.join(rest.trim_start_matches(is_path_separator))
+        }
+        _ => base_dir.join(source),
+    };
+
+    // Lexically drop `.` and fold `..` so specs stay readable in `podman inspect`
+    let mut resolved = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                resolved.pop();
+            }
+            other => resolved.push(other),
+        }
+    }
+    resolved
+}
+
+/// Rewrite `container.volumes` host paths in a raw config tree to absolute
+/// paths, so a checked-in `.mino.toml` works wherever the project is cloned.
+fn resolve_volume_paths(value: &mut Value, base_dir: &Path) {
+    let Some(volumes) = value
+        .get_mut("container")
+        .and_then(|c| c.get_mut("volumes"))
+        .and_then(Value::as_array_mut)
+    else {
+        return;
+    };
+
+    for volume in volumes {
+        if let Some(spec) = volume.as_str() {
+            *volume = Value::String(resolve_volume_spec(spec, base_dir));
+        }
+    }
+}
+
+/// Resolve the host source of one volume spec; named volumes pass through.
+fn resolve_volume_spec(spec: &str, base_dir: &Path) -> String {
+    match volume_host_source(spec) {
+        Some(source) => {
+            let rest = &spec[source.len()..];
+            format!(
+                "{}{}",
+                resolve_volume_source(source, base_dir).display(),
+                rest
+            )
+        }
+        None => spec.to_string(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -613,6 +740,103 @@ mod tests {
         assert_eq!(config.vm.name, "mino");
     }
 
+    #[tokio::test]
+    async fn load_merged_normalizes_crlf() {
+        let temp = TempDir::new().unwrap();
+        let global_path = temp.path().join("nonexistent.toml");
+        let local_path = temp.path().join(".mino.toml");
+        std::fs::write(
+            &local_path,
+            "[container.env]\r\nBANNER = \"\"\"\r\nline one\r\nline two\"\"\"\r\n",
+        )
+        .unwrap();
+
+        let manager = ConfigManager::with_path(global_path);
+        let config = manager.load_merged(Some(&local_path)).await.unwrap();
+        assert_eq!(config.container.env["BANNER"], "line one\nline two");
+    }
+
+    #[tokio::test]
+    async fn load_merged_resolves_local_volume_paths() {
+        let temp = TempDir::new().unwrap();
+        let project = temp.path().join("project");
+        std::fs::create_dir_all(&project).unwrap();
+        let local_path = project.join(".mino.toml");
+        std::fs::write(
+            &local_path,
+            r#"
+            [container]
+            volumes = ["./data:/data:ro", "../shared:/shared", "cache:/cache", "~/.npmrc:/npmrc"]
+            "#,
+        )
+        .unwrap();
+
+        let manager = ConfigManager::with_path(temp.path().join("nonexistent.toml"));
+        let config = manager.load_merged(Some(&local_path)).await.unwrap();
+        let home = dirs::home_dir().unwrap();
+        assert_eq!(
+            config.container.volumes,
+            vec![
+                format!("{}:/data:ro", project.join("data").display()),
+                format!("{}:/shared", temp.path().join("shared").display()),
+                "cache:/cache".to_string(),
+                format!("{}:/npmrc", home.join(".npmrc").display()),
+            ]
+        );
+    }
+
+    #[test]
+    fn volume_host_source_skips_named_volumes() {
+        assert_eq!(volume_host_source("/abs:/x"), Some("/abs"));
+        assert_eq!(volume_host_source("./rel:/x"), Some("./rel"));
+        assert_eq!(volume_host_source("out/dist:/x"), Some("out/dist"));
+        assert_eq!(volume_host_source("~:/x"), Some("~"));
+        assert_eq!(volume_host_source("named:/x"), None);
+        assert_eq!(volume_host_source("no-target"), None);
+    }
+
+    #[test]
+    fn volume_host_source_splits_options_from_the_right() {
+        assert_eq!(volume_host_source("a:/data:ro"), None);
+        assert_eq!(volume_host_source("a:/data"), None);
+        assert_eq!(volume_host_source("./a:/data:ro"), Some("./a"));
+        assert_eq!(volume_host_source("/srv/x:y:/data:ro,z"), Some("/srv/x:y"));
+        assert_eq!(
+            volume_host_source("./dir:with:colons:/data"),
+            Some("./dir:with:colons")
+        );
+        assert_eq!(
+            resolve_volume_spec("a:/data:ro", Path::new("/work")),
+            "a:/data:ro"
+        );
+        assert_eq!(
+            resolve_volume_spec("./a:b:/data:ro", Path::new("/work")),
+            "/work/a:b:/data:ro"
+        );
+    }
+
+    #[test]
+    fn volume_host_source_windows_paths() {
+        assert_eq!(volume_host_source(r"C:\data:/data"), Some(r"C:\data"));
+        assert_eq!(volume_host_source("D:/src:/src:ro"), Some("D:/src"));
+        assert_eq!(volume_host_source(r"out\dist:/x"), Some(r"out\dist"));
+        assert_eq!(
+            resolve_volume_spec(r"C:\data:/data", Path::new("/work")),
+            r"C:\data:/data"
+        );
+    }
+
+    #[test]
+    fn resolve_volume_spec_keeps_absolute_and_options() {
+        let base = Path::new("/work/app");
+        assert_eq!(resolve_volume_spec("/srv:/srv:z", base), "/srv:/srv:z");
+        assert_eq!(
+            resolve_volume_spec("out/./dist:/d", base),
+            "/work/app/out/dist:/d"
+        );
+        assert_eq!(resolve_volume_spec("named:/n", base), "named:/n");
+    }
+
     #[tokio::test]
     async fn load_merged_rejects_overlapping_sandbox_dirs() {
         // Regression: load_merged() is the primary config-load entry point
@@ -4,7 +4,8 @@
 
 use crate::sandbox::config::SandboxConfig;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
 
 /// Root configuration structure
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -13,15 +14,27 @@ pub struct Config {
     /// General settings
     pub general: GeneralConfig,
 
-    /// OrbStack VM settings
+    /// macOS VM settings
     pub vm: VmConfig,
 
+    /// Container engine selection
+    pub orchestration: OrchestrationConfig,
+
     /// Container settings
     pub container: ContainerConfig,
 
+    /// Image aliases usable with `--image`
+    pub images: ImagesConfig,
+
+    /// Service containers (database, cache, ...) started next to each session
+    pub services: BTreeMap<String, ServiceConfig>,
+
     /// Cloud credential settings
     pub credentials: CredentialsConfig,
 
+    /// Git identity and config inside sessions
+    pub git: GitConfig,
+
     /// Session defaults
     pub session: SessionConfig,
 
@@ -31,8 +44,22 @@ pub struct Config {
     /// Home volume settings
     pub home: HomeConfig,
 
+    /// Session scratch volume settings
+    pub scratch: ScratchConfig,
+
+    /// Host-side hooks run around sessions
+    pub hooks: HooksConfig,
+
     /// Native sandbox settings
     pub sandbox: SandboxConfig,
+
+    /// Terminal output settings
+    pub ui: UiConfig,
+
+    /// Local config merged into this one, if it was loaded (set by
+    /// `load_merged`, never read from or written to a file)
+    #[serde(skip)]
+    pub local_path: Option<PathBuf>,
 }
 
 /// General application settings
@@ -53,6 +80,10 @@ pub struct GeneralConfig {
 
     /// Runtime mode: "container", "native", or "auto"
     pub runtime: String,
+
+    /// Extra env key patterns to redact from logs and audit entries
+    /// (`*` wildcard, e.g. "MYCORP_*")
+    pub redact_patterns: Vec<String>,
 }
 
 impl Default for GeneralConfig {
@@ -63,26 +94,134 @@ impl Default for GeneralConfig {
             audit_log: true,
             update_check: true,
             runtime: "container".to_string(),
+            redact_patterns: vec![],
         }
     }
 }
 
-/// OrbStack VM configuration
+/// macOS VM configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct VmConfig {
+    /// VM manager: "orbstack" (default) or "lima"
+    pub provider: String,
+
     /// VM name to use
     pub name: String,
 
-    /// VM distribution
+    /// VM distribution (Lima: template name)
     pub distro: String,
+
+    /// CPUs for the VM (0 = provider default). OrbStack applies this to
+    /// all of its machines; Lima only when creating the VM.
+    pub cpus: u32,
+
+    /// Memory for the VM in GB (0 = provider default)
+    pub memory_gb: u32,
 }
 
 impl Default for VmConfig {
     fn default() -> Self {
         Self {
+            provider: "orbstack".to_string(),
             name: "mino".to_string(),
             distro: "fedora".to_string(),
+            cpus: 0,
+            memory_gb: 0,
+        }
+    }
+}
+
+/// Container engine selection
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OrchestrationConfig {
+    /// Container backend: "podman" (default), "docker", "remote", or "k8s"
+    pub backend: String,
+
+    /// Remote host for the "remote" backend
+    pub remote: RemoteConfig,
+
+    /// Cluster settings for the "k8s" backend
+    pub kubernetes: KubernetesConfig,
+}
+
+impl Default for OrchestrationConfig {
+    fn default() -> Self {
+        Self {
+            backend: "podman".to_string(),
+            remote: RemoteConfig::default(),
+            kubernetes: KubernetesConfig::default(),
+        }
+    }
+}
+
+/// Remote Podman host reached over SSH
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RemoteConfig {
+    /// SSH destination (`user@host` or an ~/.ssh/config alias)
+    pub host: String,
+
+    /// SSH port (default: ssh's own default)
+    pub port: Option<u16>,
+
+    /// Private key passed to ssh with `-i`
+    pub identity_file: Option<String>,
+
+    /// Remote directory mirroring bind-mounted local paths
+    /// (relative paths are under the remote user's home)
+    pub sync_dir: String,
+}
+
+impl Default for RemoteConfig {
+    fn default() -> Self {
+        Self {
+            host: String::new(),
+            port: None,
+            identity_file: None,
+            sync_dir: ".cache/mino/sync".to_string(),
+        }
+    }
+}
+
+/// Kubernetes cluster running sandbox pods
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct KubernetesConfig {
+    /// kubeconfig context (empty = the current context)
+    pub context: String,
+
+    /// Namespace for sandbox pods (empty = the context's namespace)
+    pub namespace: String,
+
+    /// StorageClass for volume claims (empty = the cluster default)
+    pub storage_class: String,
+
+    /// Requested size of each volume claim
+    pub volume_size: String,
+
+    /// Access mode of volume claims; use ReadWriteMany to share caches
+    /// between sessions scheduled on different nodes
+    pub volume_access_mode: String,
+
+    /// Init container image receiving synced mounts (needs `sh` and `tar`)
+    pub sync_image: String,
+
+    /// Seconds to wait for a pod to start before giving up
+    pub start_timeout_secs: u64,
+}
+
+impl Default for KubernetesConfig {
+    fn default() -> Self {
+        Self {
+            context: String::new(),
+            namespace: String::new(),
+            storage_class: String::new(),
+            volume_size: "10Gi".to_string(),
+            volume_access_mode: "ReadWriteOnce".to_string(),
+            sync_image: "busybox:1.36".to_string(),
+            start_timeout_secs: 300,
         }
     }
 }
@@ -118,9 +257,112 @@ pub struct ContainerConfig {
     #[serde(default)]
     pub layers: Vec<String>,
 
+    /// Layer build argument overrides, by layer name then argument
+    /// (`[container.layer_args.typescript] node_version = "20"`)
+    #[serde(default)]
+    pub layer_args: BTreeMap<String, BTreeMap<String, String>>,
+
+    /// System packages composed into the image as an inline layer
+    #[serde(default)]
+    pub packages: PackagesConfig,
+
     /// Mount root filesystem as read-only (default: false)
     #[serde(default)]
     pub read_only: bool,
+
+    /// Additional project directories mounted alongside the main project
+    /// (relative paths resolve against the main project directory)
+    #[serde(default)]
+    pub extra_projects: Vec<String>,
+
+    /// User to run as inside the container (default: auto-detect from image)
+    #[serde(default)]
+    pub user: Option<String>,
+
+    /// Home directory of the container user (default: derived from user)
+    #[serde(default)]
+    pub home: Option<String>,
+
+    /// Image pull policy: "always", "missing", or "never"
+    #[serde(default = "default_pull_policy")]
+    pub pull: String,
+
+    /// Host settings to propagate: "timezone", "locale", "gitconfig"
+    #[serde(default)]
+    pub propagate: Vec<String>,
+
+    /// Host env var names to pass through; `*` wildcards allowed. Names
+    /// containing SECRET or TOKEN must be listed exactly.
+    #[serde(default)]
+    pub env_passthrough: Vec<String>,
+
+    /// CPU limit in cores, fractions allowed (0 = no limit)
+    pub cpus: f64,
+
+    /// Memory limit in MB (0 = no limit)
+    pub memory_mb: u64,
+
+    /// Max processes in the container (0 = no limit)
+    #[serde(default = "default_pids_limit")]
+    pub pids_limit: u32,
+
+    /// Resource limits passed as `--ulimit` (e.g. "nofile=65535:65535")
+    #[serde(default)]
+    pub ulimits: Vec<String>,
+
+    /// Give sessions the host's NVIDIA GPUs
+    pub gpus: bool,
+
+    /// Host devices passed through with `--device` (e.g. "/dev/ttyUSB0")
+    #[serde(default)]
+    pub devices: Vec<String>,
+
+    /// Extra tmpfs mounts passed as `--tmpfs` (e.g. "/tmp:size=1g")
+    #[serde(default)]
+    pub tmpfs: Vec<String>,
+
+    /// User namespace mode passed as `--userns` (e.g. "keep-id"; default: runtime default)
+    #[serde(default)]
+    pub userns: Option<String>,
+
+    /// Image platform, e.g. "linux/amd64" to match amd64 CI from an arm64
+    /// host (default: the host's architecture)
+    #[serde(default)]
+    pub platform: Option<String>,
+
+    /// Container hostname passed as `--hostname` (default: the runtime's)
+    #[serde(default)]
+    pub hostname: Option<String>,
+
+    /// Extra `/etc/hosts` entries passed as `--add-host` (e.g. "internal.corp:10.0.0.5")
+    #[serde(default)]
+    pub extra_hosts: Vec<String>,
+
+    /// Join bridged sessions to the shared `mino` network, reachable from
+    /// each other as `<session>.mino`
+    pub session_dns: bool,
+}
+
+fn default_pull_policy() -> String {
+    "missing".to_string()
+}
+
+fn default_pids_limit() -> u32 {
+    4096
+}
+
+/// Inline system packages (`[container.packages]`)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PackagesConfig {
+    /// Packages installed with `dnf install` on the layer base image
+    #[serde(default)]
+    pub dnf: Vec<String>,
+}
+
+impl PackagesConfig {
+    pub fn is_empty(&self) -> bool {
+        self.dnf.is_empty()
+    }
 }
 
 impl Default for ContainerConfig {
@@ -134,15 +376,43 @@ impl Default for ContainerConfig {
             network_allow: vec![],
             network_preset: None,
             layers: vec![],
+            layer_args: BTreeMap::new(),
+            packages: PackagesConfig::default(),
             read_only: false,
+            extra_projects: vec![],
+            user: None,
+            home: None,
+            pull: default_pull_policy(),
+            propagate: vec![],
+            env_passthrough: vec![],
+            cpus: 0.0,
+            memory_mb: 0,
+            pids_limit: default_pids_limit(),
+            ulimits: vec![],
+            gpus: false,
+            devices: vec![],
+            tmpfs: vec![],
+            userns: None,
+            platform: None,
+            hostname: None,
+            extra_hosts: vec![],
+            session_dns: true,
         }
     }
 }
 
 /// Cloud credentials configuration
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct CredentialsConfig {
+    /// How credentials reach the container: "env" (default) or "file"
+    /// (files under /run/mino/credentials, with only pointer env vars)
+    pub delivery: String,
+
+    /// Pass cloud credentials as files refreshed before they expire
+    /// (equivalent to --refresh-credentials)
+    pub refresh: bool,
+
     /// AWS settings
     pub aws: AwsConfig,
 
@@ -154,6 +424,39 @@ pub struct CredentialsConfig {
 
     /// GitHub settings
     pub github: GithubConfig,
+
+    /// GitLab settings
+    pub gitlab: GitlabConfig,
+
+    /// Bitbucket settings
+    pub bitbucket: BitbucketConfig,
+
+    /// Kubernetes ServiceAccount kubeconfig settings
+    pub kubernetes: KubernetesCredentialsConfig,
+
+    /// Per-session GitHub deploy key settings
+    pub deploy_key: DeployKeyConfig,
+
+    /// Secrets produced by host commands (`[credentials.custom.<name>]`)
+    pub custom: BTreeMap<String, CustomCredentialConfig>,
+}
+
+impl Default for CredentialsConfig {
+    fn default() -> Self {
+        Self {
+            delivery: "env".to_string(),
+            refresh: false,
+            aws: AwsConfig::default(),
+            gcp: GcpConfig::default(),
+            azure: AzureConfig::default(),
+            github: GithubConfig::default(),
+            gitlab: GitlabConfig::default(),
+            bitbucket: BitbucketConfig::default(),
+            kubernetes: KubernetesCredentialsConfig::default(),
+            deploy_key: DeployKeyConfig::default(),
+            custom: BTreeMap::new(),
+        }
+    }
 }
 
 /// AWS credential settings
@@ -172,11 +475,20 @@ pub struct AwsConfig {
     /// External ID for role assumption (optional)
     pub external_id: Option<String>,
 
+    /// MFA device ARN; mino prompts for a code when fetching new credentials
+    pub mfa_serial: Option<String>,
+
+    /// Profile whose credentials assume `role_arn` (defaults to `profile`)
+    pub source_profile: Option<String>,
+
     /// AWS profile to use
     pub profile: Option<String>,
 
     /// AWS region
     pub region: Option<String>,
+
+    /// Session policy narrowing what the issued credentials may do
+    pub policy: AwsPolicyConfig,
 }
 
 impl Default for AwsConfig {
@@ -186,12 +498,33 @@ impl Default for AwsConfig {
             session_duration_secs: 3600,
             role_arn: None,
             external_id: None,
+            mfa_serial: None,
+            source_profile: None,
             profile: None,
             region: None,
+            policy: AwsPolicyConfig::default(),
         }
     }
 }
 
+/// AWS session policy (`[credentials.aws.policy]`)
+///
+/// Session policies can only narrow the source identity's permissions,
+/// never widen them.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AwsPolicyConfig {
+    /// IAM policy document (JSON) applied as an inline session policy
+    pub inline_session_policy: Option<String>,
+
+    /// Actions to allow on any resource, e.g. "s3:GetObject". Shorthand for
+    /// an inline policy; cannot be combined with `inline_session_policy`.
+    pub allowed_actions: Vec<String>,
+
+    /// Managed policy ARNs applied as session policies
+    pub managed_policy_arns: Vec<String>,
+}
+
 /// GCP credential settings
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(default)]
@@ -204,6 +537,18 @@ pub struct GcpConfig {
 
     /// Service account to impersonate
     pub service_account: Option<String>,
+
+    /// OAuth scope restriction for the access token
+    pub policy: GcpPolicyConfig,
+}
+
+/// GCP token scoping (`[credentials.gcp.policy]`)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GcpPolicyConfig {
+    /// OAuth scopes to request, e.g.
+    /// "https://www.googleapis.com/auth/devstorage.read_only" (empty = gcloud default)
+    pub scopes: Vec<String>,
 }
 
 /// Azure credential settings
@@ -218,24 +563,171 @@ pub struct AzureConfig {
 
     /// Azure tenant ID
     pub tenant: Option<String>,
+
+    /// Audience restriction for the access token
+    pub policy: AzurePolicyConfig,
+}
+
+/// Azure token scoping (`[credentials.azure.policy]`)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AzurePolicyConfig {
+    /// Scopes the token is issued for, e.g. "https://storage.azure.com/.default"
+    /// (empty = Azure Resource Manager)
+    pub scopes: Vec<String>,
 }
 
 /// GitHub credential settings
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct GithubConfig {
+    /// Inject a GitHub token (default: true; `--no-github` overrides)
+    pub enabled: bool,
+
     /// GitHub host (for GitHub Enterprise)
     pub host: String,
+
+    /// GitHub App ID. When set, sessions get an installation token minted
+    /// for this app instead of the `gh auth token` of the logged-in user
+    pub app_id: Option<u64>,
+
+    /// Installation of the app to mint tokens for
+    pub installation_id: Option<u64>,
+
+    /// PEM private key of the app, used to sign the token request
+    pub private_key_path: Option<String>,
+
+    /// Repositories the token is limited to, as "name" or "owner/name"
+    /// (empty = every repository the installation can access)
+    pub repositories: Vec<String>,
+
+    /// Permissions the token is limited to, e.g. `contents = "read"`
+    /// (empty = every permission granted to the installation)
+    pub permissions: BTreeMap<String, String>,
+}
+
+impl GithubConfig {
+    /// Whether tokens come from a GitHub App installation
+    pub fn app_mode(&self) -> bool {
+        self.app_id.is_some()
+    }
 }
 
 impl Default for GithubConfig {
     fn default() -> Self {
         Self {
+            enabled: true,
             host: "github.com".to_string(),
+            app_id: None,
+            installation_id: None,
+            private_key_path: None,
+            repositories: Vec::new(),
+            permissions: BTreeMap::new(),
         }
     }
 }
 
+/// GitLab credential settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GitlabConfig {
+    /// Inject a GitLab token via config (equivalent to --gitlab flag)
+    pub enabled: bool,
+
+    /// GitLab host (for self-managed instances)
+    pub host: String,
+}
+
+impl Default for GitlabConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            host: "gitlab.com".to_string(),
+        }
+    }
+}
+
+/// Bitbucket credential settings
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BitbucketConfig {
+    /// Inject a Bitbucket token via config (equivalent to --bitbucket flag)
+    pub enabled: bool,
+
+    /// Bitbucket username the token belongs to (default: $BITBUCKET_USERNAME)
+    pub username: Option<String>,
+}
+
+/// Kubernetes credential settings: a kubeconfig with a short-lived
+/// ServiceAccount token, never the host's own kubeconfig
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct KubernetesCredentialsConfig {
+    /// Inject a kubeconfig via config (equivalent to --kube flag)
+    pub enabled: bool,
+
+    /// kubeconfig context of the cluster (empty = the current context)
+    pub context: String,
+
+    /// Namespace of the ServiceAccount (empty = the context's namespace)
+    pub namespace: String,
+
+    /// ServiceAccount whose token is issued
+    pub service_account: String,
+
+    /// Requested token lifetime in seconds (the cluster may shorten it)
+    pub token_duration_secs: u32,
+}
+
+impl Default for KubernetesCredentialsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            context: String::new(),
+            namespace: String::new(),
+            service_account: "default".to_string(),
+            token_duration_secs: 3600,
+        }
+    }
+}
+
+/// Ephemeral GitHub deploy key generated for each session, served to the
+/// container by its own ssh-agent instead of the host's
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DeployKeyConfig {
+    /// Use a deploy key via config (equivalent to --deploy-key)
+    pub enabled: bool,
+
+    /// Register the key read-only (false = the session can push)
+    pub read_only: bool,
+
+    /// Repositories ("owner/name") the key is added to
+    /// (empty = the project's `origin` on the GitHub host)
+    pub repos: Vec<String>,
+}
+
+impl Default for DeployKeyConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            read_only: true,
+            repos: Vec::new(),
+        }
+    }
+}
+
+/// A secret whose value a host command prints, e.g. `pass show openai`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CustomCredentialConfig {
+    /// Command run via `sh -c` on the host; its stdout is the secret
+    pub command: String,
+
+    /// Env var the secret is injected as
+    pub env: String,
+}
+
 /// Session configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
@@ -243,15 +735,37 @@ pub struct SessionConfig {
     /// Default shell inside container
     pub shell: String,
 
+    /// Default command for `mino run` without one (e.g. `["claude", "--session",
+    /// "{session}"]`); `{project}`, `{branch}`, and `{session}` are expanded
+    pub command: Vec<String>,
+
     /// Auto-cleanup stopped/failed sessions older than N hours (0 = disabled)
     pub auto_cleanup_hours: u32,
+
+    /// Remove a stopped session's leftover container on `mino stop`, and its
+    /// container and scratch volume when its record is auto-cleaned
+    /// (default: true). `mino stop` keeps the scratch volume unless given
+    /// `--remove-scratch`.
+    pub cleanup_resources: bool,
+
+    /// Record per-session resource usage for `mino session stats export`
+    /// (default: true)
+    pub usage_log: bool,
+
+    /// Allow `mino clip` to move text between the host clipboard and
+    /// sessions (default: false)
+    pub clipboard: bool,
 }
 
 impl Default for SessionConfig {
     fn default() -> Self {
         Self {
             shell: "/bin/bash".to_string(),
+            command: vec![],
             auto_cleanup_hours: 720,
+            cleanup_resources: true,
+            usage_log: true,
+            clipboard: false,
         }
     }
 }
@@ -270,6 +784,110 @@ impl Default for HomeConfig {
     }
 }
 
+/// Session scratch volume configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScratchConfig {
+    /// Mount a per-session scratch volume at /scratch (default: false)
+    pub enabled: bool,
+
+    /// Refuse to reuse a scratch volume larger than N GB (0 = no limit)
+    pub max_size_gb: u32,
+}
+
+impl Default for ScratchConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_size_gb: 10,
+        }
+    }
+}
+
+/// Terminal output configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UiConfig {
+    /// Output style: "auto" (detect colors and Unicode), "plain", or "rich"
+    pub style: String,
+}
+
+impl Default for UiConfig {
+    fn default() -> Self {
+        Self {
+            style: "auto".to_string(),
+        }
+    }
+}
+
+/// Image alias registry
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ImagesConfig {
+    /// Alias name -> image reference or layer set (`[images.aliases]`).
+    /// Takes precedence over built-in aliases like `base` or `python`.
+    pub aliases: HashMap<String, ImageAlias>,
+}
+
+/// Target of an image alias
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ImageAlias {
+    /// Full image reference, e.g. `"registry.corp.com/dev/python:3.12"`
+    Image(String),
+    /// Layers to compose, e.g. `["python", "rust"]`
+    Layers(Vec<String>),
+}
+
+/// A service container (`[services.<name>]`) sharing the session's pod
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ServiceConfig {
+    /// Image to run, e.g. `"postgres:16"`
+    pub image: String,
+
+    /// Environment variables for the service
+    pub env: HashMap<String, String>,
+
+    /// Ports published on the host (`host:container` or `container`)
+    pub ports: Vec<String>,
+}
+
+/// Hook commands run via `sh -c`: `pre_run` and `post_run` on the host in
+/// the project directory, `post_create` inside the container
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HooksConfig {
+    /// Commands run before the container starts; a failure aborts the session
+    pub pre_run: Vec<String>,
+
+    /// Commands run after an attached session exits
+    pub post_run: Vec<String>,
+
+    /// Commands run in the container after the bootstrap, before the session
+    /// command or shell starts; failures are reported and skipped
+    pub post_create: Vec<String>,
+}
+
+/// Git config written into sessions in place of the host's `.gitconfig`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GitConfig {
+    /// `user.name` for commits made in the session
+    pub user_name: Option<String>,
+
+    /// `user.email` for commits made in the session
+    pub user_email: Option<String>,
+
+    /// `user.signingkey`; turns on commit signing. SSH public keys sign
+    /// through the forwarded agent.
+    pub signing_key: Option<String>,
+
+    /// Start from the identity, alias, and merge/diff/pull/color sections
+    /// of the host `~/.gitconfig`, instead of an empty config
+    pub include_host_gitconfig: bool,
+}
+
 /// Cache configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
@@ -282,6 +900,33 @@ pub struct CacheConfig {
 
     /// Maximum total cache size in GB before triggering gc
     pub max_total_gb: u32,
+
+    /// Evict least-recently-used complete caches when `max_total_gb` is
+    /// reached (default: false, only warn)
+    pub auto_evict: bool,
+
+    /// Usage, in percent of `max_total_gb`, that eviction frees down to
+    pub evict_target_percent: u32,
+
+    /// Directory levels below the project root scanned for nested
+    /// lockfiles (0 = root only)
+    pub scan_depth: u32,
+
+    /// Seed a new cache for a changed lockfile with the contents of the
+    /// project's previous cache of the same ecosystem (default: true)
+    pub seed_from_previous: bool,
+
+    /// Which projects share a cache: "global" (default, identical
+    /// lockfiles share one volume) or "project" (volumes are salted with
+    /// the project path)
+    pub scope: String,
+
+    /// Persist the podman/buildah storage of sessions in a cache volume,
+    /// so in-sandbox image builds keep their layers (default: false)
+    pub container_storage: bool,
+
+    /// Shared store of prewarmed cache archives
+    pub remote: RemoteCacheConfig,
 }
 
 impl Default for CacheConfig {
@@ -290,6 +935,60 @@ impl Default for CacheConfig {
             enabled: true,
             gc_days: 30,
             max_total_gb: 50,
+            auto_evict: false,
+            evict_target_percent: 80,
+            scan_depth: 3,
+            seed_from_previous: true,
+            scope: "global".to_string(),
+            container_storage: false,
+            remote: RemoteCacheConfig::default(),
+        }
+    }
+}
+
+/// Remote cache store (`[cache.remote]`)
+///
+/// On a cache miss the volume is filled from an archive keyed by the
+/// lockfile hash. Caches finalized by `mino cache warm` can be uploaded for
+/// other machines.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RemoteCacheConfig {
+    /// Store kind: "s3" or "oci" (empty = disabled)
+    pub backend: String,
+
+    /// S3 bucket holding the archives (s3 backend)
+    pub bucket: String,
+
+    /// Key prefix inside the bucket (s3 backend)
+    pub prefix: String,
+
+    /// AWS region of the bucket (default: the AWS CLI's configured region)
+    pub region: Option<String>,
+
+    /// S3-compatible endpoint, e.g. a MinIO server (s3 backend)
+    pub endpoint_url: Option<String>,
+
+    /// Registry repository holding the archives, e.g.
+    /// "ghcr.io/acme/mino-cache" (oci backend)
+    pub repository: String,
+
+    /// Upload caches finalized by `mino cache warm` on this machine
+    /// (default: false). Caches built by `mino run` are never uploaded,
+    /// since the sandbox can write to them.
+    pub upload: bool,
+}
+
+impl Default for RemoteCacheConfig {
+    fn default() -> Self {
+        Self {
+            backend: String::new(),
+            bucket: String::new(),
+            prefix: "mino-cache/".to_string(),
+            region: None,
+            endpoint_url: None,
+            repository: String::new(),
+            upload: false,
         }
     }
 }
@@ -328,6 +1027,64 @@ mod tests {
         assert!(!config.container.read_only);
     }
 
+    #[test]
+    fn config_deserializes_user_and_home() {
+        let toml = r#"
+            [container]
+            user = "node"
+            home = "/home/node"
+        "#;
+        let config: Config = toml::from_str(toml).unwrap();
+        assert_eq!(config.container.user.as_deref(), Some("node"));
+        assert_eq!(config.container.home.as_deref(), Some("/home/node"));
+        assert!(Config::default().container.user.is_none());
+    }
+
+    #[test]
+    fn config_deserializes_git() {
+        let config: Config = toml::from_str(
+            r#"
+            [git]
+            user_name = "Ada"
+            user_email = "ada@example.com"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.git.user_name.as_deref(), Some("Ada"));
+        assert!(config.git.signing_key.is_none());
+        assert!(!config.git.include_host_gitconfig);
+    }
+
+    #[test]
+    fn config_deserializes_cache_remote() {
+        let config: Config = toml::from_str(
+            r#"
+            [cache.remote]
+            backend = "s3"
+            bucket = "team-caches"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.cache.remote.backend, "s3");
+        assert_eq!(config.cache.remote.bucket, "team-caches");
+        assert_eq!(config.cache.remote.prefix, "mino-cache/");
+        assert!(!config.cache.remote.upload);
+        assert!(config.cache.enabled);
+    }
+
+    #[test]
+    fn config_deserializes_hooks() {
+        let toml = r#"
+            [hooks]
+            pre_run = ["./scripts/fetch-schema.sh"]
+            post_create = ["npm ci"]
+        "#;
+        let config: Config = toml::from_str(toml).unwrap();
+        assert_eq!(config.hooks.pre_run, vec!["./scripts/fetch-schema.sh"]);
+        assert!(config.hooks.post_run.is_empty());
+        assert_eq!(config.hooks.post_create, vec!["npm ci"]);
+    }
+
     #[test]
     fn config_deserializes_update_check() {
         let toml = r#"
@@ -350,6 +1107,19 @@ mod tests {
         assert!(config.home.enabled);
     }
 
+    #[test]
+    fn config_deserializes_scratch() {
+        let toml = r#"
+            [scratch]
+            enabled = true
+            max_size_gb = 2
+        "#;
+        let config: Config = toml::from_str(toml).unwrap();
+        assert!(config.scratch.enabled);
+        assert_eq!(config.scratch.max_size_gb, 2);
+        assert!(!Config::default().scratch.enabled);
+    }
+
     #[test]
     fn config_deserializes_home_disabled() {
         let toml = r#"
@@ -376,6 +1146,111 @@ mod tests {
         assert_eq!(config.general.runtime, "native");
     }
 
+    #[test]
+    fn config_orchestration_backend() {
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config.orchestration.backend, "podman");
+
+        let toml = r#"
+            [orchestration]
+            backend = "docker"
+        "#;
+        let config: Config = toml::from_str(toml).unwrap();
+        assert_eq!(config.orchestration.backend, "docker");
+        assert!(config.orchestration.remote.host.is_empty());
+    }
+
+    #[test]
+    fn config_orchestration_remote() {
+        let toml = r#"
+            [orchestration]
+            backend = "remote"
+
+            [orchestration.remote]
+            host = "dev@build-box"
+            port = 2222
+        "#;
+        let config: Config = toml::from_str(toml).unwrap();
+        assert_eq!(config.orchestration.remote.host, "dev@build-box");
+        assert_eq!(config.orchestration.remote.port, Some(2222));
+        assert_eq!(config.orchestration.remote.sync_dir, ".cache/mino/sync");
+    }
+
+    #[test]
+    fn config_orchestration_kubernetes() {
+        let toml = r#"
+            [orchestration]
+            backend = "k8s"
+
+            [orchestration.kubernetes]
+            context = "staging"
+            namespace = "sandboxes"
+        "#;
+        let config: Config = toml::from_str(toml).unwrap();
+        let k8s = &config.orchestration.kubernetes;
+        assert_eq!(k8s.context, "staging");
+        assert_eq!(k8s.namespace, "sandboxes");
+        assert_eq!(k8s.volume_size, "10Gi");
+        assert_eq!(k8s.start_timeout_secs, 300);
+    }
+
+    #[test]
+    fn config_services() {
+        let toml = r#"
+            [services.db]
+            image = "postgres:16"
+            env = { POSTGRES_PASSWORD = "dev" }
+            ports = ["5432:5432"]
+
+            [services.cache]
+            image = "redis:7"
+        "#;
+        let config: Config = toml::from_str(toml).unwrap();
+        assert_eq!(
+            config.services.keys().collect::<Vec<_>>(),
+            vec!["cache", "db"]
+        );
+        assert_eq!(config.services["db"].env["POSTGRES_PASSWORD"], "dev");
+        assert_eq!(config.services["db"].ports, vec!["5432:5432"]);
+        assert!(config.services["cache"].ports.is_empty());
+    }
+
+    #[test]
+    fn config_custom_credentials() {
+        let toml = r#"
+            [credentials.custom.openai]
+            command = "pass show openai"
+            env = "OPENAI_API_KEY"
+        "#;
+        let config: Config = toml::from_str(toml).unwrap();
+        assert_eq!(
+            config.credentials.custom["openai"],
+            CustomCredentialConfig {
+                command: "pass show openai".to_string(),
+                env: "OPENAI_API_KEY".to_string(),
+            }
+        );
+        assert!(Config::default().credentials.custom.is_empty());
+    }
+
+    #[test]
+    fn config_image_aliases() {
+        let toml = r#"
+            [images.aliases]
+            corp-python = "registry.corp.com/dev/python:3.12"
+            corp-stack = ["python", "rust"]
+        "#;
+        let config: Config = toml::from_str(toml).unwrap();
+        assert_eq!(
+            config.images.aliases["corp-python"],
+            ImageAlias::Image("registry.corp.com/dev/python:3.12".to_string())
+        );
+        assert_eq!(
+            config.images.aliases["corp-stack"],
+            ImageAlias::Layers(vec!["python".to_string(), "rust".to_string()])
+        );
+    }
+
     #[test]
     fn config_sandbox_appears_in_serialized() {
         let config = Config::default();
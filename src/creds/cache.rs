@@ -4,6 +4,7 @@ use crate::config::ConfigManager;
 use crate::error::{MinoError, MinoResult};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::path::PathBuf;
 use tokio::fs;
 use tracing::debug;
@@ -17,7 +18,7 @@ pub struct CachedCredential {
     /// When the credential expires
     pub expires_at: DateTime<Utc>,
 
-    /// Provider name (aws, gcp, azure, github)
+    /// Provider name (aws, gcp, azure, github, kubernetes)
     pub provider: String,
 }
 
@@ -34,13 +35,30 @@ impl CachedCredential {
     /// Check if credential is expired
     pub fn is_expired(&self) -> bool {
         // Add 60 second buffer to prevent using almost-expired creds
-        Utc::now() >= self.expires_at - chrono::Duration::seconds(60)
+        self.expires_within(chrono::Duration::seconds(60))
+    }
+
+    /// Check if credential expires within `lead` from now
+    pub fn expires_within(&self, lead: chrono::Duration) -> bool {
+        Utc::now() >= self.expires_at - lead
     }
 }
 
+/// Cache key for credentials issued under `scope`, so tokens with different
+/// scopes never share an entry. An empty scope keeps the plain key.
+pub fn scoped_key(base: &str, scope: &[&str]) -> String {
+    if scope.iter().all(|s| s.is_empty()) {
+        return base.to_string();
+    }
+    let digest = Sha256::digest(scope.join("\n").as_bytes());
+    format!("{}-{}", base, &hex::encode(digest)[..12])
+}
+
 /// Credential cache manager
 pub struct CredentialCache {
     cache_dir: PathBuf,
+    /// Entries expiring sooner than this count as expired
+    min_validity: chrono::Duration,
 }
 
 impl CredentialCache {
@@ -60,7 +78,17 @@ impl CredentialCache {
                 .map_err(|e| MinoError::io("setting credentials dir permissions", e))?;
         }
 
-        Ok(Self { cache_dir })
+        Ok(Self {
+            cache_dir,
+            min_validity: chrono::Duration::seconds(60),
+        })
+    }
+
+    /// Treat entries expiring within `lead` as expired, so providers issue
+    /// fresh credentials well before the cached ones run out
+    pub fn with_min_validity(mut self, lead: chrono::Duration) -> Self {
+        self.min_validity = lead.max(self.min_validity);
+        self
     }
 
     /// Get a cached credential if valid
@@ -77,7 +105,7 @@ impl CredentialCache {
 
         let cred: CachedCredential = serde_json::from_str(&content)?;
 
-        if cred.is_expired() {
+        if cred.expires_within(self.min_validity) {
             debug!("Cached credential {} is expired", key);
             self.remove(key).await?;
             return Ok(None);
@@ -120,6 +148,53 @@ impl CredentialCache {
         Ok(())
     }
 
+    /// All cached credentials by key, expired ones included, sorted by key.
+    /// Unreadable entries are skipped.
+    pub async fn entries(&self) -> MinoResult<Vec<(String, CachedCredential)>> {
+        let mut entries = fs::read_dir(&self.cache_dir)
+            .await
+            .map_err(|e| MinoError::io("reading cache directory", e))?;
+
+        let mut found = Vec::new();
+        while let Some(entry) = entries
+            .next_entry()
+            .await
+            .map_err(|e| MinoError::io("reading cache entry", e))?
+        {
+            let path = entry.path();
+            let Some(key) = path
+                .extension()
+                .filter(|ext| *ext == "json")
+                .and(path.file_stem())
+                .and_then(|stem| stem.to_str())
+            else {
+                continue;
+            };
+            let parsed = fs::read_to_string(&path)
+                .await
+                .ok()
+                .and_then(|content| serde_json::from_str(&content).ok());
+            match parsed {
+                Some(cred) => found.push((key.to_string(), cred)),
+                None => debug!("Skipping unreadable cache entry {}", path.display()),
+            }
+        }
+
+        found.sort_by(|a, b| a.0.cmp(&b.0));
+        Ok(found)
+    }
+
+    /// Latest expiry among the valid cached credentials of `provider`
+    pub async fn expires_at(&self, provider: &str) -> MinoResult<Option<DateTime<Utc>>> {
+        Ok(self
+            .entries()
+            .await?
+            .into_iter()
+            .filter(|(_, cred)| cred.provider == provider && !cred.is_expired())
+            .map(|(_, cred)| cred.expires_at)
+            .max())
+    }
+
     /// Clear all cached credentials
     pub async fn clear(&self) -> MinoResult<()> {
         let mut entries = fs::read_dir(&self.cache_dir)
@@ -155,6 +230,7 @@ mod tests {
         let temp = TempDir::new().unwrap();
         let cache = CredentialCache {
             cache_dir: temp.path().to_path_buf(),
+            min_validity: chrono::Duration::seconds(60),
         };
         (cache, temp)
     }
@@ -192,6 +268,72 @@ mod tests {
         assert!(retrieved.is_none());
     }
 
+    #[test]
+    fn scoped_key_separates_scopes() {
+        assert_eq!(scoped_key("gcp-token", &[]), "gcp-token");
+        assert_eq!(scoped_key("gcp-token", &[""]), "gcp-token");
+
+        let read = scoped_key("gcp-token", &["devstorage.read_only"]);
+        let write = scoped_key("gcp-token", &["devstorage.read_write"]);
+        assert!(read.starts_with("gcp-token-"));
+        assert_ne!(read, write);
+        assert_eq!(read, scoped_key("gcp-token", &["devstorage.read_only"]));
+    }
+
+    #[tokio::test]
+    async fn min_validity_expires_entries_early() {
+        let (cache, _temp) = test_cache().await;
+        let cred = CachedCredential::new(
+            "test",
+            "secret123".to_string(),
+            Utc::now() + chrono::Duration::minutes(10),
+        );
+        cache.set("test-key", &cred).await.unwrap();
+        assert!(cache.get("test-key").await.unwrap().is_some());
+
+        let cache = cache.with_min_validity(chrono::Duration::minutes(15));
+        assert!(cache.get("test-key").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn entries_include_expired_and_skip_garbage() {
+        let (cache, temp) = test_cache().await;
+        let now = Utc::now();
+        for (key, expires_at) in [
+            ("gcp-token", now + chrono::Duration::hours(1)),
+            ("aws-session", now - chrono::Duration::hours(1)),
+        ] {
+            let cred = CachedCredential::new(&key[..3], "secret".to_string(), expires_at);
+            cache.set(key, &cred).await.unwrap();
+        }
+        fs::write(temp.path().join("broken.json"), "{")
+            .await
+            .unwrap();
+
+        let entries = cache.entries().await.unwrap();
+        let keys: Vec<&str> = entries.iter().map(|(key, _)| key.as_str()).collect();
+        assert_eq!(keys, ["aws-session", "gcp-token"]);
+        assert!(entries[0].1.is_expired());
+    }
+
+    #[tokio::test]
+    async fn expires_at_takes_latest_valid_entry() {
+        let (cache, _temp) = test_cache().await;
+        let now = Utc::now();
+        for (key, expires_at) in [
+            ("gcp-token", now + chrono::Duration::minutes(20)),
+            ("gcp-token-scoped", now + chrono::Duration::minutes(50)),
+            ("aws-session", now - chrono::Duration::hours(1)),
+        ] {
+            let cred = CachedCredential::new(&key[..3], "secret".to_string(), expires_at);
+            cache.set(key, &cred).await.unwrap();
+        }
+
+        let gcp = cache.expires_at("gcp").await.unwrap().unwrap();
+        assert_eq!(gcp, now + chrono::Duration::minutes(50));
+        assert!(cache.expires_at("aws").await.unwrap().is_none());
+    }
+
     #[tokio::test]
     async fn cache_missing_returns_none() {
         let (cache, _temp) = test_cache().await;
@@ -0,0 +1,116 @@
+//! Custom credential provider running host commands
+//!
+//! `[credentials.custom.<name>]` entries name a command (`pass show openai`,
+//! `op read op://dev/openai/key`, ...) whose stdout is the secret, and the
+//! env var it is injected as. Commands run via `sh -c` on the host at
+//! session start; the env var names are registered for redaction so the
+//! values never reach logs or audit entries.
+
+use crate::config::schema::CustomCredentialConfig;
+use crate::error::{MinoError, MinoResult};
+use std::process::Stdio;
+use tokio::process::Command;
+use tracing::debug;
+
+/// Custom credential provider
+pub struct CustomCredentials;
+
+impl CustomCredentials {
+    /// Run the command of entry `name` and return what it printed, without
+    /// the trailing newline
+    pub async fn get_value(name: &str, config: &CustomCredentialConfig) -> MinoResult<String> {
+        validate(name, config)?;
+        debug!("Running credentials.custom.{} command...", name);
+
+        let command = format!("credentials.custom.{}", name);
+        let output = Command::new("sh")
+            .args(["-c", &config.command])
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .output()
+            .await
+            .map_err(|e| MinoError::command_failed(&command, e))?;
+
+        if !output.status.success() {
+            return Err(MinoError::command_exec(
+                command,
+                String::from_utf8_lossy(&output.stderr),
+            ));
+        }
+
+        let value = String::from_utf8_lossy(&output.stdout)
+            .trim_end_matches(['\r', '\n'])
+            .to_string();
+        if value.is_empty() {
+            return Err(MinoError::User(format!(
+                "credentials.custom.{}: command printed nothing",
+                name
+            )));
+        }
+        Ok(value)
+    }
+}
+
+/// Check an entry has a command and a usable env var name
+fn validate(name: &str, config: &CustomCredentialConfig) -> MinoResult<()> {
+    if config.command.trim().is_empty() {
+        return Err(MinoError::User(format!(
+            "credentials.custom.{}: command is empty",
+            name
+        )));
+    }
+    let env = config.env.as_str();
+    let valid = env
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && env.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if !valid {
+        return Err(MinoError::User(format!(
+            "credentials.custom.{}: invalid env var name '{}'",
+            name, env
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    fn entry(command: &str, env: &str) -> CustomCredentialConfig {
+        CustomCredentialConfig {
+            command: command.to_string(),
+            env: env.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn value_is_stdout_without_trailing_newline() {
+        let value = CustomCredentials::get_value("openai", &entry("echo sk-123", "OPENAI_API_KEY"))
+            .await
+            .unwrap();
+        assert_eq!(value, "sk-123");
+    }
+
+    #[tokio::test]
+    async fn failing_or_silent_commands_are_errors() {
+        let err = CustomCredentials::get_value("vault", &entry("echo locked >&2; exit 1", "X"))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("locked"));
+        assert!(CustomCredentials::get_value("vault", &entry("true", "X"))
+            .await
+            .is_err());
+    }
+
+    #[test]
+    fn entries_need_command_and_env_name() {
+        assert!(validate("a", &entry("pass show a", "API_KEY")).is_ok());
+        assert!(validate("a", &entry(" ", "API_KEY")).is_err());
+        assert!(validate("a", &entry("pass show a", "")).is_err());
+        assert!(validate("a", &entry("pass show a", "1KEY")).is_err());
+        assert!(validate("a", &entry("pass show a", "API-KEY")).is_err());
+    }
+}
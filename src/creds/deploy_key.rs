@@ -0,0 +1,430 @@
+//! Ephemeral per-session GitHub deploy keys
+//!
+//! With `--deploy-key`, each session gets a fresh ed25519 keypair. The
+//! public half is added as a deploy key to the repositories the session
+//! needs (through `gh api`), the private half is loaded into an ssh-agent
+//! started for the session and deleted from disk, and that agent's socket
+//! is mounted where the host agent would be. The container can sign with
+//! the key but never read it, and the host's own SSH identities stay out of
+//! reach. `mino stop` deletes the deploy keys and stops the agent.
+
+use crate::config::ConfigManager;
+use crate::error::{MinoError, MinoResult};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use tokio::fs;
+use tokio::process::Command;
+use tracing::{debug, warn};
+
+/// Agent socket inside the session's deploy key directory
+const AGENT_SOCKET: &str = "agent.sock";
+
+/// Private key file, deleted once the agent holds the key
+const KEY_FILE: &str = "id_ed25519";
+
+/// A deploy key registered with one repository
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeployKey {
+    /// GitHub host the repository lives on
+    pub host: String,
+    /// Repository as "owner/name"
+    pub repo: String,
+    /// Key ID returned by GitHub, used to delete it
+    pub id: u64,
+}
+
+/// A session's ssh-agent and the deploy keys its key is registered as
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionDeployKey {
+    /// Host directory holding the agent socket
+    pub dir: PathBuf,
+    /// PID of the session's ssh-agent
+    pub agent_pid: u32,
+    /// Deploy keys to delete when the session stops
+    pub keys: Vec<DeployKey>,
+}
+
+impl SessionDeployKey {
+    /// Generate a key for `session_name`, load it into a new ssh-agent and
+    /// add it as a deploy key to each of `repos`. Anything created is
+    /// removed again when a step fails.
+    pub async fn create(
+        session_name: &str,
+        host: &str,
+        repos: &[String],
+        read_only: bool,
+    ) -> MinoResult<Self> {
+        let dir = Self::dir_for(session_name);
+        fs::create_dir_all(&dir)
+            .await
+            .map_err(|e| MinoError::io(format!("creating {}", dir.display()), e))?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&dir, std::fs::Permissions::from_mode(0o700))
+                .map_err(|e| MinoError::io("setting deploy key dir permissions", e))?;
+        }
+
+        let public_key = generate_key(&dir, &format!("mino-{}", session_name)).await?;
+        let agent_pid = start_agent(&dir.join(AGENT_SOCKET)).await?;
+        let mut deploy_key = Self {
+            dir,
+            agent_pid,
+            keys: Vec::new(),
+        };
+
+        let title = format!("mino session {}", session_name);
+        match deploy_key
+            .register(host, repos, &title, &public_key, read_only)
+            .await
+        {
+            Ok(()) => Ok(deploy_key),
+            Err(e) => {
+                if let Err(cleanup) = deploy_key.remove().await {
+                    warn!("Failed to clean up deploy key: {}", cleanup);
+                }
+                Err(e)
+            }
+        }
+    }
+
+    /// Agent socket `create` sets up for `session_name`
+    pub fn socket_for(session_name: &str) -> PathBuf {
+        Self::dir_for(session_name).join(AGENT_SOCKET)
+    }
+
+    /// Socket of the session's ssh-agent
+    pub fn socket(&self) -> PathBuf {
+        self.dir.join(AGENT_SOCKET)
+    }
+
+    /// Host directory of `session_name`'s agent, next to (never inside) its
+    /// mounted credential files
+    fn dir_for(session_name: &str) -> PathBuf {
+        ConfigManager::session_credentials_dir().join(format!("{}.ssh-agent", session_name))
+    }
+
+    /// Repositories the key was added to
+    pub fn repos(&self) -> Vec<&str> {
+        self.keys.iter().map(|k| k.repo.as_str()).collect()
+    }
+
+    /// Delete the deploy keys, stop the agent and remove its directory.
+    /// Every step is attempted; the failures are returned together.
+    pub async fn remove(&self) -> MinoResult<()> {
+        let mut failed = Vec::new();
+        for key in &self.keys {
+            match delete_deploy_key(key).await {
+                Ok(()) => debug!("Deleted deploy key {} of {}", key.id, key.repo),
+                Err(e) => failed.push(format!("{}: {}", key.repo, e)),
+            }
+        }
+        if let Err(e) = stop_agent(self.agent_pid).await {
+            failed.push(format!("ssh-agent {}: {}", self.agent_pid, e));
+        }
+        match fs::remove_dir_all(&self.dir).await {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+                failed.push(format!("{}: {}", self.dir.display(), e));
+            }
+            _ => {}
+        }
+
+        if failed.is_empty() {
+            Ok(())
+        } else {
+            Err(MinoError::User(format!(
+                "Deploy key cleanup incomplete: {}",
+                failed.join("; ")
+            )))
+        }
+    }
+
+    /// Load the private key into the agent, delete it from disk, then add
+    /// the public key to every repository
+    async fn register(
+        &mut self,
+        host: &str,
+        repos: &[String],
+        title: &str,
+        public_key: &str,
+        read_only: bool,
+    ) -> MinoResult<()> {
+        let key_path = self.dir.join(KEY_FILE);
+        let added = add_to_agent(&self.socket(), &key_path).await;
+        let _ = fs::remove_file(&key_path).await;
+        let _ = fs::remove_file(key_path.with_extension("pub")).await;
+        added?;
+
+        for repo in repos {
+            let id = add_deploy_key(host, repo, title, public_key, read_only).await?;
+            debug!("Added deploy key {} to {}", id, repo);
+            self.keys.push(DeployKey {
+                host: host.to_string(),
+                repo: repo.clone(),
+                id,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// `owner/name` of the project's `origin` remote on `host`
+pub async fn origin_repo(project_dir: &Path, host: &str) -> MinoResult<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(project_dir)
+        .args(["remote", "get-url", "origin"])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .await
+        .map_err(|e| MinoError::command_failed("git remote get-url origin", e))?;
+    let url = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if !output.status.success() || url.is_empty() {
+        return Err(MinoError::User(
+            "The project has no origin remote; set credentials.deploy_key.repos".to_string(),
+        ));
+    }
+    github_repo(&url, host).ok_or_else(|| {
+        MinoError::User(format!(
+            "origin ({}) is not a {} repository; set credentials.deploy_key.repos",
+            url, host
+        ))
+    })
+}
+
+/// Check a configured repository is "owner/name"
+pub fn validate_repo(repo: &str) -> MinoResult<()> {
+    let valid_part = |part: &str| {
+        !matches!(part, "" | "." | "..")
+            && part
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+    };
+    match repo.split_once('/') {
+        Some((owner, name)) if valid_part(owner) && valid_part(name) => Ok(()),
+        _ => Err(MinoError::User(format!(
+            "Invalid repository '{}' in credentials.deploy_key.repos: expected owner/name",
+            repo
+        ))),
+    }
+}
+
+/// `owner/name` from an SSH or HTTPS remote URL on `host`
+fn github_repo(url: &str, host: &str) -> Option<String> {
+    let path = url
+        .strip_prefix(&format!("git@{}:", host))
+        .or_else(|| url.strip_prefix(&format!("ssh://git@{}/", host)))
+        .or_else(|| url.strip_prefix(&format!("https://{}/", host)))?;
+    let repo = path.trim_end_matches('/').trim_end_matches(".git");
+    validate_repo(repo).ok()?;
+    Some(repo.to_string())
+}
+
+/// Generate an unencrypted ed25519 keypair in `dir`, returning the public key
+async fn generate_key(dir: &Path, comment: &str) -> MinoResult<String> {
+    let key_path = dir.join(KEY_FILE);
+    let output = Command::new("ssh-keygen")
+        .args(["-q", "-t", "ed25519", "-N", "", "-C", comment, "-f"])
+        .arg(&key_path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .await
+        .map_err(|e| MinoError::command_failed("ssh-keygen", e))?;
+    if !output.status.success() {
+        return Err(MinoError::command_exec(
+            "ssh-keygen",
+            String::from_utf8_lossy(&output.stderr),
+        ));
+    }
+
+    let public = key_path.with_extension("pub");
+    let key = fs::read_to_string(&public)
+        .await
+        .map_err(|e| MinoError::io(format!("reading {}", public.display()), e))?;
+    Ok(key.trim().to_string())
+}
+
+/// Start an ssh-agent listening on `socket`, returning its PID
+async fn start_agent(socket: &Path) -> MinoResult<u32> {
+    let output = Command::new("ssh-agent")
+        .arg("-s")
+        .arg("-a")
+        .arg(socket)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .await
+        .map_err(|e| MinoError::command_failed("ssh-agent", e))?;
+    if !output.status.success() {
+        return Err(MinoError::command_exec(
+            "ssh-agent",
+            String::from_utf8_lossy(&output.stderr),
+        ));
+    }
+    parse_agent_pid(&String::from_utf8_lossy(&output.stdout))
+        .ok_or_else(|| MinoError::Internal("ssh-agent printed no PID".to_string()))
+}
+
+/// PID from the `SSH_AGENT_PID=...;` line `ssh-agent -s` prints
+fn parse_agent_pid(output: &str) -> Option<u32> {
+    output
+        .split(';')
+        .find_map(|part| part.trim().strip_prefix("SSH_AGENT_PID="))
+        .and_then(|pid| pid.parse().ok())
+}
+
+/// Load `key_path` into the agent on `socket`
+async fn add_to_agent(socket: &Path, key_path: &Path) -> MinoResult<()> {
+    let output = Command::new("ssh-add")
+        .arg("-q")
+        .arg(key_path)
+        .env("SSH_AUTH_SOCK", socket)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .await
+        .map_err(|e| MinoError::command_failed("ssh-add", e))?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(MinoError::command_exec(
+            "ssh-add",
+            String::from_utf8_lossy(&output.stderr),
+        ))
+    }
+}
+
+/// Stop the agent with `pid`. An agent that is already gone is not an error.
+async fn stop_agent(pid: u32) -> MinoResult<()> {
+    let output = Command::new("ssh-agent")
+        .arg("-k")
+        .env("SSH_AGENT_PID", pid.to_string())
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .output()
+        .await
+        .map_err(|e| MinoError::command_failed("ssh-agent -k", e))?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    if output.status.success() || stderr.contains("No such process") {
+        Ok(())
+    } else {
+        Err(MinoError::command_exec("ssh-agent -k", stderr))
+    }
+}
+
+/// `gh api` command for `path` on `host`
+fn gh_api(host: &str, method: &str, path: &str) -> Command {
+    let mut cmd = Command::new("gh");
+    cmd.args(["api", "--method", method, path]);
+    if host != "github.com" {
+        cmd.args(["--hostname", host]);
+    }
+    cmd.stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    cmd
+}
+
+/// Add `public_key` as a deploy key of `repo`, returning its ID
+async fn add_deploy_key(
+    host: &str,
+    repo: &str,
+    title: &str,
+    public_key: &str,
+    read_only: bool,
+) -> MinoResult<u64> {
+    let mut cmd = gh_api(host, "POST", &format!("repos/{}/keys", repo));
+    cmd.arg("-f")
+        .arg(format!("title={}", title))
+        .arg("-f")
+        .arg(format!("key={}", public_key))
+        .arg("-F")
+        .arg(format!("read_only={}", read_only))
+        .args(["--jq", ".id"]);
+    let output = cmd
+        .output()
+        .await
+        .map_err(|e| MinoError::command_failed("gh api repos/keys", e))?;
+    if !output.status.success() {
+        return Err(MinoError::User(format!(
+            "Adding a deploy key to {} failed (needs admin access to the repository): {}",
+            repo,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    String::from_utf8_lossy(&output.stdout)
+        .trim()
+        .parse()
+        .map_err(|_| MinoError::Internal(format!("gh api returned no deploy key ID for {}", repo)))
+}
+
+/// Delete a deploy key. A key that is already gone is not an error.
+async fn delete_deploy_key(key: &DeployKey) -> MinoResult<()> {
+    let output = gh_api(
+        &key.host,
+        "DELETE",
+        &format!("repos/{}/keys/{}", key.repo, key.id),
+    )
+    .output()
+    .await
+    .map_err(|e| MinoError::command_failed("gh api repos/keys", e))?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    if output.status.success() || stderr.contains("HTTP 404") {
+        Ok(())
+    } else {
+        Err(MinoError::command_exec("gh api repos/keys", stderr))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn github_repo_from_remote_urls() {
+        for url in [
+            "git@github.com:dean0x/mino.git",
+            "ssh://git@github.com/dean0x/mino.git",
+            "https://github.com/dean0x/mino",
+            "https://github.com/dean0x/mino.git",
+        ] {
+            assert_eq!(
+                github_repo(url, "github.com").as_deref(),
+                Some("dean0x/mino"),
+                "{}",
+                url
+            );
+        }
+        assert_eq!(
+            github_repo("git@ghe.corp.com:team/app.git", "ghe.corp.com").as_deref(),
+            Some("team/app")
+        );
+        assert!(github_repo("git@gitlab.com:dean0x/mino.git", "github.com").is_none());
+        assert!(github_repo("https://github.com/dean0x", "github.com").is_none());
+    }
+
+    #[test]
+    fn repos_must_be_owner_slash_name() {
+        assert!(validate_repo("dean0x/mino").is_ok());
+        assert!(validate_repo("org/repo.js").is_ok());
+        assert!(validate_repo("mino").is_err());
+        assert!(validate_repo("a/b/c").is_err());
+        assert!(validate_repo("a/../b").is_err());
+        assert!(validate_repo("/mino").is_err());
+    }
+
+    #[test]
+    fn agent_pid_from_ssh_agent_output() {
+        let output = "SSH_AUTH_SOCK=/run/user/1000/a.sock; export SSH_AUTH_SOCK;\n\
+                      SSH_AGENT_PID=4242; export SSH_AGENT_PID;\necho Agent pid 4242;\n";
+        assert_eq!(parse_agent_pid(output), Some(4242));
+        assert_eq!(parse_agent_pid("garbage"), None);
+    }
+}
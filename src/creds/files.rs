@@ -0,0 +1,315 @@
+//! Per-session credential files
+//!
+//! With `--refresh-credentials` or `credentials.delivery = "file"`,
+//! credentials reach the container as files in a per-session host directory
+//! mounted read-only at [`MOUNT_PATH`], instead of as env vars that are
+//! fixed at start and visible in `podman inspect` and `/proc/<pid>/environ`.
+//! Each file is replaced atomically, so readers never see a partial write,
+//! and providers re-read their file when they need a token: AWS through a
+//! `credential_process` profile or shared credentials file, gcloud through
+//! `CLOUDSDK_AUTH_ACCESS_TOKEN_FILE`.
+
+use crate::config::ConfigManager;
+use crate::credentials::aws::AwsSessionCredentials;
+use crate::error::{MinoError, MinoResult};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use tokio::fs;
+use tracing::debug;
+
+/// Where the session's credential directory is mounted in the container
+pub const MOUNT_PATH: &str = "/run/mino/credentials";
+
+/// AWS config file with the `credential_process` profile
+pub const AWS_CONFIG_FILE: &str = "aws-config";
+
+/// Credentials in the `credential_process` JSON format
+pub const AWS_CREDENTIALS_FILE: &str = "aws.json";
+
+/// AWS shared credentials file
+pub const AWS_SHARED_CREDENTIALS_FILE: &str = "aws-credentials";
+
+/// GCP access token
+pub const GCP_TOKEN_FILE: &str = "gcp-token";
+
+/// Azure access token
+pub const AZURE_TOKEN_FILE: &str = "azure-token";
+
+/// GitHub token
+pub const GITHUB_TOKEN_FILE: &str = "github-token";
+
+/// GitLab token
+pub const GITLAB_TOKEN_FILE: &str = "gitlab-token";
+
+/// Bitbucket token
+pub const BITBUCKET_TOKEN_FILE: &str = "bitbucket-token";
+
+/// Kubeconfig with a ServiceAccount token
+pub const KUBECONFIG_FILE: &str = "kubeconfig";
+
+/// How credentials reach the container (`credentials.delivery`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CredentialDelivery {
+    /// Environment variables
+    #[default]
+    Env,
+    /// Files under [`MOUNT_PATH`], with only pointer env vars
+    File,
+}
+
+impl FromStr for CredentialDelivery {
+    type Err = MinoError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "env" => Ok(Self::Env),
+            "file" => Ok(Self::File),
+            other => Err(MinoError::User(format!(
+                "Invalid credential delivery '{}'. Valid modes: env, file",
+                other
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for CredentialDelivery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Env => write!(f, "env"),
+            Self::File => write!(f, "file"),
+        }
+    }
+}
+
+/// A session's credential directory on the host
+#[derive(Debug, Clone)]
+pub struct SessionCredentialFiles {
+    dir: PathBuf,
+}
+
+impl SessionCredentialFiles {
+    /// Credential directory of `session_name`
+    pub fn for_session(session_name: &str) -> Self {
+        Self {
+            dir: ConfigManager::session_credentials_dir().join(session_name),
+        }
+    }
+
+    /// Credential directory at `dir`, for tests outside this module
+    #[cfg(test)]
+    pub fn in_dir(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    /// Host directory holding the files
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Volume spec mounting the directory read-only at [`MOUNT_PATH`]
+    pub fn mount(&self) -> String {
+        format!("{}:{}:ro", self.dir.display(), MOUNT_PATH)
+    }
+
+    /// Volume spec mounting one of the files read-only at `target`
+    pub fn file_mount(&self, file: &str, target: &str) -> String {
+        format!("{}:{}:ro", self.dir.join(file).display(), target)
+    }
+
+    /// Container path of one of the files
+    pub fn container_path(file: &str) -> String {
+        format!("{}/{}", MOUNT_PATH, file)
+    }
+
+    /// Create the directory. The parent is private to the host user; the
+    /// session directory itself stays readable so whichever user the
+    /// container runs as can read the mounted files.
+    pub async fn create(&self) -> MinoResult<()> {
+        fs::create_dir_all(&self.dir)
+            .await
+            .map_err(|e| MinoError::io(format!("creating {}", self.dir.display()), e))?;
+
+        #[cfg(unix)]
+        if let Some(parent) = self.dir.parent() {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(parent, std::fs::Permissions::from_mode(0o700))
+                .map_err(|e| MinoError::io("setting session credentials dir permissions", e))?;
+        }
+        Ok(())
+    }
+
+    /// Write AWS credentials and the config profile that reads them
+    pub async fn write_aws(
+        &self,
+        creds: &AwsSessionCredentials,
+        region: Option<&str>,
+    ) -> MinoResult<()> {
+        self.write(AWS_CREDENTIALS_FILE, &aws_process_json(creds))
+            .await?;
+        self.write(AWS_CONFIG_FILE, &aws_config(region)).await
+    }
+
+    /// Write a bare token file
+    pub async fn write_token(&self, file: &str, token: &str) -> MinoResult<()> {
+        self.write(file, token).await
+    }
+
+    /// Remove the directory. A directory that is already gone is not an error.
+    pub async fn remove(&self) -> MinoResult<()> {
+        match fs::remove_dir_all(&self.dir).await {
+            Ok(()) => {
+                debug!("Removed credential files {}", self.dir.display());
+                Ok(())
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(MinoError::io(format!("removing {}", self.dir.display()), e)),
+        }
+    }
+
+    /// Replace `file` atomically: write a sibling, then rename over it
+    async fn write(&self, file: &str, content: &str) -> MinoResult<()> {
+        let path = self.dir.join(file);
+        let tmp = self.dir.join(format!(".{}.tmp", file));
+        fs::write(&tmp, content)
+            .await
+            .map_err(|e| MinoError::io(format!("writing {}", tmp.display()), e))?;
+        fs::rename(&tmp, &path)
+            .await
+            .map_err(|e| MinoError::io(format!("replacing {}", path.display()), e))
+    }
+}
+
+/// Credentials in the JSON format `credential_process` must print
+fn aws_process_json(creds: &AwsSessionCredentials) -> String {
+    let mut json = serde_json::json!({
+        "Version": 1,
+        "AccessKeyId": creds.access_key_id,
+        "SecretAccessKey": creds.secret_access_key,
+    });
+    if let Some(token) = &creds.session_token {
+        json["SessionToken"] = token.clone().into();
+    }
+    // Without an expiration SDKs never ask the process again
+    if let Some(expires_at) = creds.expires_at {
+        json["Expiration"] = expires_at.to_rfc3339().into();
+    }
+    json.to_string()
+}
+
+/// `[default]` profile of an AWS shared credentials file
+pub fn aws_shared_credentials(
+    access_key_id: &str,
+    secret_access_key: &str,
+    session_token: Option<&str>,
+) -> String {
+    let mut file = format!(
+        "[default]\naws_access_key_id = {}\naws_secret_access_key = {}\n",
+        access_key_id, secret_access_key
+    );
+    if let Some(token) = session_token {
+        file.push_str(&format!("aws_session_token = {}\n", token));
+    }
+    file
+}
+
+/// Default profile that reads the credentials file on every refresh
+fn aws_config(region: Option<&str>) -> String {
+    let mut config = format!(
+        "[default]\ncredential_process = cat {}\n",
+        SessionCredentialFiles::container_path(AWS_CREDENTIALS_FILE)
+    );
+    if let Some(region) = region {
+        config.push_str(&format!("region = {}\n", region));
+    }
+    config
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use tempfile::TempDir;
+
+    fn creds() -> AwsSessionCredentials {
+        AwsSessionCredentials {
+            access_key_id: "ASIA123".to_string(),
+            secret_access_key: "secret".to_string(),
+            session_token: Some("token".to_string()),
+            expires_at: Some(Utc.with_ymd_and_hms(2026, 10, 17, 12, 0, 0).unwrap()),
+        }
+    }
+
+    #[test]
+    fn aws_process_json_carries_expiration() {
+        let json: serde_json::Value = serde_json::from_str(&aws_process_json(&creds())).unwrap();
+        assert_eq!(json["Version"], 1);
+        assert_eq!(json["AccessKeyId"], "ASIA123");
+        assert_eq!(json["SessionToken"], "token");
+        assert_eq!(json["Expiration"], "2026-10-17T12:00:00+00:00");
+    }
+
+    #[test]
+    fn aws_config_reads_the_mounted_file() {
+        assert_eq!(
+            aws_config(Some("eu-west-1")),
+            "[default]\ncredential_process = cat /run/mino/credentials/aws.json\n\
+             region = eu-west-1\n"
+        );
+    }
+
+    #[test]
+    fn delivery_parses_both_modes() {
+        for mode in [CredentialDelivery::Env, CredentialDelivery::File] {
+            assert_eq!(
+                mode.to_string().parse::<CredentialDelivery>().unwrap(),
+                mode
+            );
+        }
+        assert!("proc".parse::<CredentialDelivery>().is_err());
+    }
+
+    #[test]
+    fn aws_shared_credentials_has_default_profile() {
+        assert_eq!(
+            aws_shared_credentials("ASIA123", "secret", Some("token")),
+            "[default]\naws_access_key_id = ASIA123\naws_secret_access_key = secret\n\
+             aws_session_token = token\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn writes_replace_files_and_remove_cleans_up() {
+        let temp = TempDir::new().unwrap();
+        let files = SessionCredentialFiles {
+            dir: temp.path().join("swift-fox"),
+        };
+        files.create().await.unwrap();
+        files.write_token(GCP_TOKEN_FILE, "one").await.unwrap();
+        files.write_token(GCP_TOKEN_FILE, "two").await.unwrap();
+        let token = fs::read_to_string(files.dir().join(GCP_TOKEN_FILE))
+            .await
+            .unwrap();
+        assert_eq!(token, "two");
+        assert!(!files.dir().join(".gcp-token.tmp").exists());
+
+        files.remove().await.unwrap();
+        assert!(!files.dir().exists());
+        files.remove().await.unwrap();
+    }
+
+    #[test]
+    fn mount_is_read_only() {
+        let files = SessionCredentialFiles {
+            dir: PathBuf::from("/state/session-credentials/swift-fox"),
+        };
+        assert_eq!(
+            files.mount(),
+            "/state/session-credentials/swift-fox:/run/mino/credentials:ro"
+        );
+        assert_eq!(
+            files.file_mount(KUBECONFIG_FILE, "/home/developer/.kube/config"),
+            "/state/session-credentials/swift-fox/kubeconfig:/home/developer/.kube/config:ro"
+        );
+    }
+}
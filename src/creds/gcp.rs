@@ -1,7 +1,7 @@
 //! GCP credential provider using gcloud CLI
 
 use crate::config::schema::GcpConfig;
-use crate::credentials::cache::{CachedCredential, CredentialCache};
+use crate::credentials::cache::{scoped_key, CachedCredential, CredentialCache};
 use crate::error::{MinoError, MinoResult};
 use chrono::{Duration, Utc};
 use std::process::Stdio;
@@ -19,8 +19,11 @@ impl GcpCredentials {
         config: &GcpConfig,
         cache: &CredentialCache,
     ) -> MinoResult<String> {
+        let scopes: Vec<&str> = config.policy.scopes.iter().map(String::as_str).collect();
+        let cache_key = scoped_key(Self::CACHE_KEY, &scopes);
+
         // Check cache first
-        if let Some(cached) = cache.get(Self::CACHE_KEY).await? {
+        if let Some(cached) = cache.get(&cache_key).await? {
             debug!("Using cached GCP access token");
             return Ok(cached.value);
         }
@@ -31,7 +34,7 @@ impl GcpCredentials {
         // Cache for 55 minutes (tokens are valid for 1 hour)
         let expires_at = Utc::now() + Duration::minutes(55);
         let cached = CachedCredential::new("gcp", token.clone(), expires_at);
-        cache.set(Self::CACHE_KEY, &cached).await?;
+        cache.set(&cache_key, &cached).await?;
 
         Ok(token)
     }
@@ -47,6 +50,10 @@ impl GcpCredentials {
             cmd.args(["--impersonate-service-account", account]);
         }
 
+        if !config.policy.scopes.is_empty() {
+            cmd.arg(format!("--scopes={}", config.policy.scopes.join(",")));
+        }
+
         cmd.stdout(Stdio::piped()).stderr(Stdio::piped());
 
         let output = cmd
@@ -106,6 +113,11 @@ impl GcpCredentials {
     }
 }
 
+/// Artifact Registry Docker host of `region` (e.g. `europe-west1`)
+pub fn gar_registry(region: &str) -> String {
+    format!("{}-docker.pkg.dev", region)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -115,4 +127,9 @@ mod tests {
         // This test just verifies the function doesn't panic
         let _ = GcpCredentials::get_project().await;
     }
+
+    #[test]
+    fn gar_registry_is_regional() {
+        assert_eq!(gar_registry("europe-west1"), "europe-west1-docker.pkg.dev");
+    }
 }
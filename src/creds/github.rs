@@ -1,15 +1,97 @@
-//! GitHub credential provider using gh CLI
+//! GitHub credential provider using gh CLI, or a GitHub App
+//!
+//! By default sessions get the logged-in user's `gh auth token`. With
+//! `credentials.github.app_id` set they instead get an installation token of
+//! that app, limited to the configured repositories and permissions and
+//! valid for one hour.
 
 use crate::config::schema::GithubConfig;
+use crate::credentials::cache::{scoped_key, CachedCredential, CredentialCache};
 use crate::error::{MinoError, MinoResult};
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::{DateTime, Duration, Utc};
+use serde::Deserialize;
 use std::process::Stdio;
+use tokio::io::AsyncWriteExt;
 use tokio::process::Command;
 use tracing::debug;
 
+/// Container path that refreshed installation tokens are written to
+pub const APP_TOKEN_PATH: &str = "/tmp/mino-github-token";
+
+/// Installation tokens are refreshed this long before GitHub expires them
+const APP_TOKEN_MARGIN_MINUTES: i64 = 10;
+
+/// Response of `POST /app/installations/{id}/access_tokens`
+#[derive(Debug, Deserialize)]
+struct AccessToken {
+    token: String,
+    expires_at: DateTime<Utc>,
+}
+
 /// GitHub credential provider
 pub struct GithubCredentials;
 
 impl GithubCredentials {
+    const APP_CACHE_KEY: &'static str = "github-app-token";
+
+    /// Token for a session: an installation token in app mode, else the
+    /// `gh auth token` of the logged-in user
+    pub async fn get_session_token(
+        config: &GithubConfig,
+        cache: &CredentialCache,
+    ) -> MinoResult<String> {
+        if config.app_mode() {
+            Ok(Self::get_app_token(config, cache).await?.value)
+        } else {
+            Self::get_token(config).await
+        }
+    }
+
+    /// Installation token of the configured GitHub App, using cache if valid.
+    ///
+    /// The cached entry expires [`APP_TOKEN_MARGIN_MINUTES`] before the token
+    /// does, so asking again at its `expires_at` mints a fresh token.
+    pub async fn get_app_token(
+        config: &GithubConfig,
+        cache: &CredentialCache,
+    ) -> MinoResult<CachedCredential> {
+        let (app_id, installation_id, private_key) = app_settings(config)?;
+        let cache_key = app_cache_key(config);
+
+        if let Some(cached) = cache.get(&cache_key).await? {
+            debug!("Using cached GitHub App installation token");
+            return Ok(cached);
+        }
+
+        debug!(
+            "Minting GitHub App installation token (app {}, installation {})...",
+            app_id, installation_id
+        );
+        let jwt = app_jwt(app_id, private_key).await?;
+        let url = format!(
+            "{}/app/installations/{}/access_tokens",
+            api_base(&config.host),
+            installation_id
+        );
+        let body = access_token_request(config).to_string();
+        let (status, response) =
+            tokio::task::spawn_blocking(move || post_access_token(&url, &jwt, &body))
+                .await
+                .map_err(|e| MinoError::Internal(format!("token request task failed: {}", e)))?
+                .map_err(|e| MinoError::User(format!("GitHub App token request failed: {}", e)))?;
+        let token = parse_access_token(status, &response)?;
+
+        let cached = CachedCredential::new(
+            "github",
+            token.token,
+            token.expires_at - Duration::minutes(APP_TOKEN_MARGIN_MINUTES),
+        );
+        cache.set(&cache_key, &cached).await?;
+        Ok(cached)
+    }
+
     /// Get GitHub token from gh CLI
     pub async fn get_token(config: &GithubConfig) -> MinoResult<String> {
         debug!("Getting GitHub token from gh CLI...");
@@ -92,10 +174,271 @@ impl GithubCredentials {
     }
 }
 
+/// App ID, installation ID and private key path, all required in app mode
+fn app_settings(config: &GithubConfig) -> MinoResult<(u64, u64, &str)> {
+    let missing = |key: &str| {
+        MinoError::User(format!(
+            "credentials.github.{} is required when credentials.github.app_id is set",
+            key
+        ))
+    };
+    let app_id = config.app_id.ok_or_else(|| missing("app_id"))?;
+    let installation_id = config
+        .installation_id
+        .ok_or_else(|| missing("installation_id"))?;
+    let private_key = config
+        .private_key_path
+        .as_deref()
+        .ok_or_else(|| missing("private_key_path"))?;
+    Ok((app_id, installation_id, private_key))
+}
+
+/// Cache key covering everything that shapes the minted token
+fn app_cache_key(config: &GithubConfig) -> String {
+    let app_id = config.app_id.map(|id| id.to_string()).unwrap_or_default();
+    let installation_id = config
+        .installation_id
+        .map(|id| id.to_string())
+        .unwrap_or_default();
+    let repositories = config.repositories.join(",");
+    let permissions = config
+        .permissions
+        .iter()
+        .map(|(name, level)| format!("{}={}", name, level))
+        .collect::<Vec<_>>()
+        .join(",");
+    scoped_key(
+        GithubCredentials::APP_CACHE_KEY,
+        &[
+            &config.host,
+            &app_id,
+            &installation_id,
+            &repositories,
+            &permissions,
+        ],
+    )
+}
+
+/// REST API root for a GitHub host
+fn api_base(host: &str) -> String {
+    if host == "github.com" {
+        "https://api.github.com".to_string()
+    } else {
+        format!("https://{}/api/v3", host)
+    }
+}
+
+/// Body of the access token request, limiting repositories and permissions
+fn access_token_request(config: &GithubConfig) -> serde_json::Value {
+    let mut body = serde_json::Map::new();
+    if !config.repositories.is_empty() {
+        // The installation fixes the owner; the API wants bare names
+        let names: Vec<&str> = config
+            .repositories
+            .iter()
+            .map(|repo| repo.rsplit('/').next().unwrap_or(repo))
+            .collect();
+        body.insert("repositories".to_string(), serde_json::json!(names));
+    }
+    if !config.permissions.is_empty() {
+        body.insert(
+            "permissions".to_string(),
+            serde_json::json!(config.permissions),
+        );
+    }
+    serde_json::Value::Object(body)
+}
+
+/// Header and claims of the app JWT, ready to sign. Backdated a minute for
+/// clock drift and valid for nine, under GitHub's ten minute limit.
+fn jwt_signing_input(app_id: u64, now: DateTime<Utc>) -> String {
+    let header = URL_SAFE_NO_PAD.encode(br#"{"alg":"RS256","typ":"JWT"}"#);
+    let claims = serde_json::json!({
+        "iat": now.timestamp() - 60,
+        "exp": now.timestamp() + 540,
+        "iss": app_id.to_string(),
+    });
+    format!(
+        "{}.{}",
+        header,
+        URL_SAFE_NO_PAD.encode(claims.to_string().as_bytes())
+    )
+}
+
+/// RS256 JWT authenticating as the app, signed with openssl
+async fn app_jwt(app_id: u64, private_key: &str) -> MinoResult<String> {
+    let input = jwt_signing_input(app_id, Utc::now());
+
+    let mut child = Command::new("openssl")
+        .args(["dgst", "-sha256", "-sign", private_key])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| MinoError::command_failed("openssl dgst", e))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin
+            .write_all(input.as_bytes())
+            .await
+            .map_err(|e| MinoError::io("writing JWT to openssl", e))?;
+    }
+    let output = child
+        .wait_with_output()
+        .await
+        .map_err(|e| MinoError::command_failed("openssl dgst", e))?;
+    if !output.status.success() {
+        return Err(MinoError::User(format!(
+            "Signing the GitHub App JWT with {} failed: {}",
+            private_key,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+
+    Ok(format!(
+        "{}.{}",
+        input,
+        URL_SAFE_NO_PAD.encode(&output.stdout)
+    ))
+}
+
+/// POST the token request, returning the status and response body
+fn post_access_token(url: &str, jwt: &str, body: &str) -> Result<(u16, String), String> {
+    use std::time::Duration;
+    use ureq::Agent;
+
+    let agent_config = Agent::config_builder()
+        .timeout_global(Some(Duration::from_secs(10)))
+        .http_status_as_error(false)
+        .build();
+    let agent: Agent = agent_config.new_agent();
+
+    let mut response = agent
+        .post(url)
+        .header("User-Agent", &format!("mino/{}", env!("CARGO_PKG_VERSION")))
+        .header("Accept", "application/vnd.github+json")
+        .header("Authorization", &format!("Bearer {}", jwt))
+        .send(body)
+        .map_err(|e| e.to_string())?;
+    let status = response.status().as_u16();
+    let body = response
+        .body_mut()
+        .read_to_string()
+        .map_err(|e| e.to_string())?;
+    Ok((status, body))
+}
+
+/// Read the token out of the API response, surfacing GitHub's error message
+fn parse_access_token(status: u16, body: &str) -> MinoResult<AccessToken> {
+    if status != 201 {
+        let message = serde_json::from_str::<serde_json::Value>(body)
+            .ok()
+            .and_then(|v| v["message"].as_str().map(str::to_string))
+            .unwrap_or_else(|| body.trim().to_string());
+        return Err(MinoError::User(format!(
+            "GitHub App token request failed (HTTP {}): {}",
+            status, message
+        )));
+    }
+    serde_json::from_str(body)
+        .map_err(|e| MinoError::User(format!("Unexpected GitHub App token response: {}", e)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn app_config() -> GithubConfig {
+        GithubConfig {
+            app_id: Some(1234),
+            installation_id: Some(5678),
+            private_key_path: Some("/keys/app.pem".to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn jwt_claims_name_the_app() {
+        let now = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let input = jwt_signing_input(1234, now);
+        let (header, claims) = input.split_once('.').unwrap();
+        assert_eq!(header, "eyJhbGciOiJSUzI1NiIsInR5cCI6IkpXVCJ9");
+        assert_eq!(
+            claims,
+            URL_SAFE_NO_PAD.encode(br#"{"exp":1700000540,"iat":1699999940,"iss":"1234"}"#)
+        );
+    }
+
+    #[test]
+    fn app_mode_requires_installation_and_key() {
+        assert!(app_settings(&app_config()).is_ok());
+
+        let mut config = app_config();
+        config.installation_id = None;
+        let err = app_settings(&config).unwrap_err().to_string();
+        assert!(err.contains("installation_id"), "{}", err);
+
+        let mut config = app_config();
+        config.private_key_path = None;
+        let err = app_settings(&config).unwrap_err().to_string();
+        assert!(err.contains("private_key_path"), "{}", err);
+    }
+
+    #[test]
+    fn token_request_limits_repositories_and_permissions() {
+        assert_eq!(access_token_request(&app_config()), serde_json::json!({}));
+
+        let mut config = app_config();
+        config.repositories = vec!["acme/api".to_string(), "web".to_string()];
+        config
+            .permissions
+            .insert("contents".to_string(), "read".to_string());
+        assert_eq!(
+            access_token_request(&config),
+            serde_json::json!({
+                "repositories": ["api", "web"],
+                "permissions": {"contents": "read"},
+            })
+        );
+    }
+
+    #[test]
+    fn cache_key_follows_scope() {
+        let config = app_config();
+        let mut narrower = app_config();
+        narrower.repositories = vec!["api".to_string()];
+        assert_ne!(app_cache_key(&config), app_cache_key(&narrower));
+        assert_eq!(app_cache_key(&config), app_cache_key(&app_config()));
+    }
+
+    #[test]
+    fn enterprise_hosts_use_the_v3_api() {
+        assert_eq!(api_base("github.com"), "https://api.github.com");
+        assert_eq!(
+            api_base("ghe.example.com"),
+            "https://ghe.example.com/api/v3"
+        );
+    }
+
+    #[test]
+    fn token_response_is_parsed() {
+        let token = parse_access_token(
+            201,
+            r#"{"token":"ghs_abc","expires_at":"2026-10-17T12:00:00Z"}"#,
+        )
+        .unwrap();
+        assert_eq!(token.token, "ghs_abc");
+        assert_eq!(token.expires_at.to_rfc3339(), "2026-10-17T12:00:00+00:00");
+
+        let err = parse_access_token(404, r#"{"message":"Not Found"}"#)
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("HTTP 404") && err.contains("Not Found"),
+            "{}",
+            err
+        );
+    }
+
     #[tokio::test]
     async fn is_authenticated_returns_bool() {
         let config = GithubConfig::default();
@@ -0,0 +1,66 @@
+//! GitLab credential provider using a host PAT or the glab CLI
+
+use crate::config::schema::GitlabConfig;
+use crate::error::{MinoError, MinoResult};
+use std::process::Stdio;
+use tokio::process::Command;
+use tracing::debug;
+
+/// Host env var holding a GitLab personal access token
+pub const TOKEN_ENV: &str = "GITLAB_TOKEN";
+
+/// GitLab credential provider
+pub struct GitlabCredentials;
+
+impl GitlabCredentials {
+    /// Get a GitLab token: `$GITLAB_TOKEN` on the host, else the token glab
+    /// stored for the configured host
+    pub async fn get_token(config: &GitlabConfig) -> MinoResult<String> {
+        if let Some(token) = env_token(std::env::var(TOKEN_ENV).ok()) {
+            debug!("Using GitLab token from ${}", TOKEN_ENV);
+            return Ok(token);
+        }
+
+        debug!("Getting GitLab token from glab CLI...");
+        let output = Command::new("glab")
+            .args(["config", "get", "token", "--host", &config.host])
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .output()
+            .await
+            .map_err(|e| MinoError::command_failed("glab config get token", e))?;
+
+        if !output.status.success() {
+            return Err(MinoError::GitlabNotAuthenticated);
+        }
+
+        let token = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        if token.is_empty() {
+            return Err(MinoError::GitlabNotAuthenticated);
+        }
+
+        Ok(token)
+    }
+}
+
+/// A token from the environment, ignoring blank values
+pub(crate) fn env_token(value: Option<String>) -> Option<String> {
+    value
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blank_env_tokens_are_ignored() {
+        assert_eq!(env_token(None), None);
+        assert_eq!(env_token(Some("  ".to_string())), None);
+        assert_eq!(
+            env_token(Some("glpat-abc\n".to_string())).as_deref(),
+            Some("glpat-abc")
+        );
+    }
+}
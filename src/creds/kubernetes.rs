@@ -0,0 +1,249 @@
+//! Kubernetes credential provider issuing ServiceAccount tokens via kubectl
+//!
+//! Sessions never see the host kubeconfig. mino asks the cluster for a
+//! short-lived token of one ServiceAccount (`kubectl create token`) and
+//! writes a kubeconfig holding only that token, the cluster endpoint and
+//! its CA, so the session can do exactly what the account's RBAC allows.
+
+use crate::config::schema::KubernetesCredentialsConfig;
+use crate::credentials::cache::{scoped_key, CachedCredential, CredentialCache};
+use crate::error::{MinoError, MinoResult};
+use crate::orchestration::Kubectl;
+use chrono::{Duration, Utc};
+use serde_json::{json, Value};
+use tracing::debug;
+
+/// Name of the cluster, user and context in generated kubeconfigs
+const KUBECONFIG_ENTRY: &str = "mino";
+
+/// Kubernetes credential provider
+pub struct KubernetesCredentials;
+
+impl KubernetesCredentials {
+    const CACHE_KEY: &'static str = "kubernetes-token";
+
+    /// Get a kubeconfig for the configured ServiceAccount, reusing a cached
+    /// token while it is valid
+    pub async fn get_kubeconfig(
+        config: &KubernetesCredentialsConfig,
+        cache: &CredentialCache,
+    ) -> MinoResult<String> {
+        let kubectl = Kubectl::new(&config.context, &config.namespace);
+        let selected = Self::current_cluster(&kubectl).await?;
+        let namespace = if config.namespace.is_empty() {
+            selected
+                .namespace
+                .clone()
+                .unwrap_or_else(|| "default".to_string())
+        } else {
+            config.namespace.clone()
+        };
+
+        let cache_key = token_cache_key(&selected, &namespace, &config.service_account);
+        let token = match cache.get(&cache_key).await? {
+            Some(cached) => {
+                debug!("Using cached Kubernetes token");
+                cached.value
+            }
+            None => {
+                let token = Self::create_token(&kubectl, config).await?;
+                let expires_at = Utc::now()
+                    + Duration::seconds(i64::from(config.token_duration_secs))
+                    - Duration::minutes(5);
+                let cached = CachedCredential::new("kubernetes", token.clone(), expires_at);
+                cache.set(&cache_key, &cached).await?;
+                token
+            }
+        };
+
+        Ok(kubeconfig(&selected.cluster, &namespace, &token))
+    }
+
+    /// Cluster entry and namespace of the selected context, CA inlined
+    async fn current_cluster(kubectl: &Kubectl) -> MinoResult<SelectedContext> {
+        let args = [
+            "config",
+            "view",
+            "--minify",
+            "--flatten",
+            "--raw",
+            "-o",
+            "json",
+        ];
+        let output = kubectl.exec(&args).await?;
+        if !output.status.success() {
+            return Err(MinoError::command_exec(
+                "kubectl config view",
+                String::from_utf8_lossy(&output.stderr),
+            ));
+        }
+        let view: Value = serde_json::from_slice(&output.stdout)
+            .map_err(|e| MinoError::Internal(format!("parsing kubectl config view: {}", e)))?;
+        minified_cluster(&view).ok_or_else(|| {
+            MinoError::User(format!(
+                "kubeconfig context '{}' has no cluster",
+                kubectl.context()
+            ))
+        })
+    }
+
+    /// Issue a token for the ServiceAccount
+    async fn create_token(
+        kubectl: &Kubectl,
+        config: &KubernetesCredentialsConfig,
+    ) -> MinoResult<String> {
+        debug!(
+            "Requesting token for ServiceAccount {}...",
+            config.service_account
+        );
+        let duration = format!("--duration={}s", config.token_duration_secs);
+        let output = kubectl
+            .exec(&["create", "token", &config.service_account, &duration])
+            .await?;
+        if !output.status.success() {
+            return Err(MinoError::command_exec(
+                "kubectl create token",
+                String::from_utf8_lossy(&output.stderr),
+            ));
+        }
+
+        let token = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        if token.is_empty() {
+            return Err(MinoError::Internal(
+                "kubectl create token printed no token".to_string(),
+            ));
+        }
+        Ok(token)
+    }
+}
+
+/// The context kubectl resolved, from `kubectl config view --minify`
+struct SelectedContext {
+    /// Context name (the current context when none is configured)
+    name: String,
+    /// Cluster settings, CA inlined
+    cluster: Value,
+    /// Namespace set on the context
+    namespace: Option<String>,
+}
+
+/// Context name, cluster settings and namespace from `kubectl config view
+/// --minify` output
+fn minified_cluster(view: &Value) -> Option<SelectedContext> {
+    let cluster = view["clusters"].get(0)?["cluster"].clone();
+    if !cluster.is_object() {
+        return None;
+    }
+    let context = view["contexts"].get(0);
+    let name = context
+        .and_then(|c| c["name"].as_str())
+        .unwrap_or_default()
+        .to_string();
+    let namespace = context
+        .and_then(|c| c["context"]["namespace"].as_str())
+        .map(str::to_string);
+    Some(SelectedContext {
+        name,
+        cluster,
+        namespace,
+    })
+}
+
+/// Cache key of a token: scoped to the resolved context and API server, so
+/// switching contexts (`kubectl config use-context`) or repointing one at
+/// another cluster never reuses a token issued elsewhere
+fn token_cache_key(selected: &SelectedContext, namespace: &str, service_account: &str) -> String {
+    let server = selected.cluster["server"].as_str().unwrap_or_default();
+    scoped_key(
+        KubernetesCredentials::CACHE_KEY,
+        &[&selected.name, server, namespace, service_account],
+    )
+}
+
+/// Kubeconfig (JSON, which kubectl reads like YAML) authenticating with
+/// `token` only
+fn kubeconfig(cluster: &Value, namespace: &str, token: &str) -> String {
+    json!({
+        "apiVersion": "v1",
+        "kind": "Config",
+        "clusters": [{ "name": KUBECONFIG_ENTRY, "cluster": cluster }],
+        "users": [{ "name": KUBECONFIG_ENTRY, "user": { "token": token } }],
+        "contexts": [{
+            "name": KUBECONFIG_ENTRY,
+            "context": {
+                "cluster": KUBECONFIG_ENTRY,
+                "user": KUBECONFIG_ENTRY,
+                "namespace": namespace,
+            },
+        }],
+        "current-context": KUBECONFIG_ENTRY,
+    })
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn view() -> Value {
+        json!({
+            "clusters": [{
+                "name": "prod",
+                "cluster": {
+                    "server": "https://k8s.example.com:6443",
+                    "certificate-authority-data": "LS0tLS1C",
+                },
+            }],
+            "contexts": [{
+                "name": "admin@prod",
+                "context": { "cluster": "prod", "user": "admin", "namespace": "team-a" },
+            }],
+            "users": [{
+                "name": "admin",
+                "user": { "client-key-data": "secret" },
+            }],
+        })
+    }
+
+    #[test]
+    fn minified_cluster_reads_cluster_and_namespace() {
+        let selected = minified_cluster(&view()).unwrap();
+        assert_eq!(selected.name, "admin@prod");
+        assert_eq!(selected.cluster["server"], "https://k8s.example.com:6443");
+        assert_eq!(selected.namespace.as_deref(), Some("team-a"));
+        assert!(minified_cluster(&json!({ "clusters": [] })).is_none());
+    }
+
+    #[test]
+    fn token_cache_key_follows_context_and_server() {
+        let prod = minified_cluster(&view()).unwrap();
+        let key = token_cache_key(&prod, "team-a", "agent");
+        assert_eq!(key, token_cache_key(&prod, "team-a", "agent"));
+
+        let mut staging = view();
+        staging["contexts"][0]["name"] = json!("admin@staging");
+        let staging = minified_cluster(&staging).unwrap();
+        assert_ne!(key, token_cache_key(&staging, "team-a", "agent"));
+
+        let mut moved = view();
+        moved["clusters"][0]["cluster"]["server"] = json!("https://other.example.com:6443");
+        let moved = minified_cluster(&moved).unwrap();
+        assert_ne!(key, token_cache_key(&moved, "team-a", "agent"));
+    }
+
+    #[test]
+    fn kubeconfig_holds_only_the_token() {
+        let selected = minified_cluster(&view()).unwrap();
+        let config: Value =
+            serde_json::from_str(&kubeconfig(&selected.cluster, "team-a", "eyJtoken")).unwrap();
+
+        assert_eq!(config["current-context"], "mino");
+        assert_eq!(config["users"][0]["user"], json!({ "token": "eyJtoken" }));
+        assert_eq!(
+            config["clusters"][0]["cluster"]["certificate-authority-data"],
+            "LS0tLS1C"
+        );
+        assert_eq!(config["contexts"][0]["context"]["namespace"], "team-a");
+        assert!(!config.to_string().contains("client-key-data"));
+    }
+}
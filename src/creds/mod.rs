@@ -2,12 +2,23 @@
 
 pub mod aws;
 pub mod azure;
+pub mod bitbucket;
 pub mod cache;
+pub mod custom;
+pub mod deploy_key;
+pub mod files;
 pub mod gcp;
 pub mod github;
+pub mod gitlab;
+pub mod kubernetes;
+pub mod registry;
 
 pub use aws::AwsCredentials;
 pub use azure::AzureCredentials;
+pub use bitbucket::BitbucketCredentials;
 pub use cache::CredentialCache;
+pub use custom::CustomCredentials;
 pub use gcp::GcpCredentials;
 pub use github::GithubCredentials;
+pub use gitlab::GitlabCredentials;
+pub use kubernetes::KubernetesCredentials;
@@ -0,0 +1,148 @@
+//! Registries authenticated with `mino login`
+//!
+//! Credentials themselves stay in the container runtime's auth file (inside
+//! the VM on macOS); mino only records which registries were logged in to,
+//! by whom and through which runtime, so `mino login --list` can show them.
+
+use crate::config::ConfigManager;
+use crate::error::{MinoError, MinoResult};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use tokio::fs;
+use tracing::{debug, warn};
+
+/// One authenticated registry
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RegistryLogin {
+    /// Username given to `mino login`, if any
+    pub username: Option<String>,
+    /// Runtime that holds the credentials
+    pub runtime: String,
+    /// RFC 3339 time of the login
+    pub logged_in_at: String,
+}
+
+/// Persisted map of registry hosts to logins
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct RegistryStore {
+    entries: BTreeMap<String, RegistryLogin>,
+}
+
+impl RegistryStore {
+    fn path() -> PathBuf {
+        ConfigManager::state_dir().join("registries.json")
+    }
+
+    /// Load the store, treating a missing or corrupt file as empty
+    pub async fn load() -> Self {
+        let path = Self::path();
+        let bytes = match fs::read(&path).await {
+            Ok(b) => b,
+            Err(_) => return Self::default(),
+        };
+        match serde_json::from_slice(&bytes) {
+            Ok(store) => store,
+            Err(e) => {
+                warn!(
+                    "Corrupt registry store at {}, treating as empty: {}",
+                    path.display(),
+                    e
+                );
+                Self::default()
+            }
+        }
+    }
+
+    /// Write the store to disk
+    pub async fn save(&self) -> MinoResult<()> {
+        let path = Self::path();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).await.map_err(|e| {
+                MinoError::io(
+                    format!("creating registry store directory {}", parent.display()),
+                    e,
+                )
+            })?;
+        }
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(&path, json).await.map_err(|e| {
+            MinoError::io(format!("writing registry store to {}", path.display()), e)
+        })?;
+        debug!("Registry store saved to {}", path.display());
+        Ok(())
+    }
+
+    /// Record a successful login, replacing any earlier one for `registry`
+    pub fn record(&mut self, registry: &str, username: Option<&str>, runtime: &str) {
+        self.entries.insert(
+            normalize_registry(registry),
+            RegistryLogin {
+                username: username.map(str::to_string),
+                runtime: runtime.to_string(),
+                logged_in_at: Utc::now().to_rfc3339(),
+            },
+        );
+    }
+
+    /// Forget `registry`. Returns whether it was recorded.
+    pub fn remove(&mut self, registry: &str) -> bool {
+        self.entries.remove(&normalize_registry(registry)).is_some()
+    }
+
+    /// Recorded logins, sorted by registry
+    pub fn entries(&self) -> impl Iterator<Item = (&str, &RegistryLogin)> {
+        self.entries.iter().map(|(k, v)| (k.as_str(), v))
+    }
+}
+
+/// Registry host as `podman login` keys it: no scheme, no trailing slash,
+/// lowercase
+pub fn normalize_registry(registry: &str) -> String {
+    let trimmed = registry
+        .trim()
+        .trim_start_matches("https://")
+        .trim_start_matches("http://")
+        .trim_end_matches('/');
+    trimmed.to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_strips_scheme_and_slash() {
+        assert_eq!(normalize_registry("https://GHCR.io/"), "ghcr.io");
+        assert_eq!(normalize_registry("quay.io"), "quay.io");
+        assert_eq!(
+            normalize_registry("registry.example.com:5000/team"),
+            "registry.example.com:5000/team"
+        );
+    }
+
+    #[test]
+    fn record_replaces_and_remove_forgets() {
+        let mut store = RegistryStore::default();
+        store.record("ghcr.io", Some("alice"), "podman");
+        store.record("https://ghcr.io", Some("bob"), "orbstack");
+        let entries: Vec<_> = store.entries().collect();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].1.username.as_deref(), Some("bob"));
+        assert_eq!(entries[0].1.runtime, "orbstack");
+
+        assert!(store.remove("GHCR.io"));
+        assert!(!store.remove("ghcr.io"));
+    }
+
+    #[test]
+    fn store_roundtrips_through_json() {
+        let mut store = RegistryStore::default();
+        store.record("quay.io", None, "docker");
+        let json = serde_json::to_string(&store).unwrap();
+        assert!(!json.contains("password"));
+        let loaded: RegistryStore = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.entries().count(), 1);
+    }
+}
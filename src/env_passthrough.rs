@@ -0,0 +1,137 @@
+//! Host environment passthrough
+//!
+//! `env_passthrough` lists host variable names to copy into a session. Entries
+//! may use `*` wildcards (`MY_APP_*`) and are matched against the host
+//! environment when the session starts. Names that look like secrets are only
+//! passed when listed verbatim, so a broad pattern never leaks them by accident.
+
+use crate::audit::AuditLog;
+use crate::redact::glob_match;
+use std::collections::HashMap;
+use tracing::warn;
+
+/// Names matching these are held back unless the config lists them exactly
+const DENIED_PATTERNS: &[&str] = &["*SECRET*", "*TOKEN*"];
+
+/// Host variables selected for a session
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Passthrough {
+    /// Variables to set in the session
+    pub env: HashMap<String, String>,
+    /// Names matched by a wildcard but held back as secrets, sorted
+    pub denied: Vec<String>,
+}
+
+impl Passthrough {
+    /// Names of the passed variables, sorted
+    pub fn passed(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.env.keys().map(String::as_str).collect();
+        names.sort_unstable();
+        names
+    }
+
+    /// Warn about held-back names; `setting` is the config key to list them in
+    pub fn warn_denied(&self, setting: &str) {
+        if !self.denied.is_empty() {
+            warn!(
+                "Not passing {} into the session: names containing SECRET or TOKEN must be listed exactly in {}",
+                self.denied.join(", "),
+                setting
+            );
+        }
+    }
+
+    /// Record which variables were passed and denied, by name only
+    pub async fn audit(&self, audit: &AuditLog, session_name: &str) {
+        if self.env.is_empty() && self.denied.is_empty() {
+            return;
+        }
+        audit
+            .log(
+                "env.passthrough",
+                &serde_json::json!({
+                    "session_name": session_name,
+                    "passed": self.passed(),
+                    "denied": &self.denied,
+                }),
+            )
+            .await;
+    }
+}
+
+/// Select variables from the current process environment
+pub fn from_host<S: AsRef<str>>(patterns: &[S]) -> Passthrough {
+    select(patterns, std::env::vars())
+}
+
+/// Select the variables in `vars` whose names match one of `patterns`.
+pub fn select<S, I>(patterns: &[S], vars: I) -> Passthrough
+where
+    S: AsRef<str>,
+    I: IntoIterator<Item = (String, String)>,
+{
+    let mut result = Passthrough::default();
+    for (key, value) in vars {
+        if !patterns.iter().any(|p| glob_match(p.as_ref(), &key)) {
+            continue;
+        }
+        let listed = patterns.iter().any(|p| p.as_ref() == key);
+        if !listed && is_denied(&key) {
+            result.denied.push(key);
+            continue;
+        }
+        result.env.insert(key, value);
+    }
+    result.denied.sort_unstable();
+    result
+}
+
+fn is_denied(key: &str) -> bool {
+    let key = key.to_ascii_uppercase();
+    DENIED_PATTERNS.iter().any(|p| glob_match(p, &key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn select_matches_exact_names_and_globs() {
+        let host = vars(&[
+            ("TERM", "xterm"),
+            ("MY_APP_HOST", "localhost"),
+            ("MY_APP_PORT", "8080"),
+            ("HOME", "/home/me"),
+        ]);
+        let result = select(&["TERM", "MY_APP_*"], host);
+        assert_eq!(result.passed(), ["MY_APP_HOST", "MY_APP_PORT", "TERM"]);
+        assert!(result.denied.is_empty());
+    }
+
+    #[test]
+    fn select_denies_secret_names_matched_by_glob() {
+        let host = vars(&[
+            ("MY_APP_URL", "https://example.com"),
+            ("MY_APP_SECRET", "s3cr3t"),
+            ("MY_APP_Token", "t0k3n"),
+        ]);
+        let result = select(&["MY_APP_*"], host);
+        assert_eq!(result.passed(), ["MY_APP_URL"]);
+        assert_eq!(result.denied, ["MY_APP_SECRET", "MY_APP_Token"]);
+    }
+
+    #[test]
+    fn select_passes_secret_names_listed_verbatim() {
+        let host = vars(&[("NPM_TOKEN", "abc"), ("MY_APP_SECRET", "s3cr3t")]);
+        let result = select(&["NPM_TOKEN", "MY_APP_*"], host);
+        assert_eq!(result.env["NPM_TOKEN"], "abc");
+        assert_eq!(result.denied, ["MY_APP_SECRET"]);
+    }
+}
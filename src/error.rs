@@ -2,6 +2,7 @@
 //!
 //! All modules use `MinoResult<T>` as their return type.
 
+use crate::redact;
 use std::path::PathBuf;
 use thiserror::Error;
 
@@ -18,9 +19,27 @@ pub enum MinoError {
     #[error("OrbStack is not running. Start it with: orb start")]
     OrbStackNotRunning,
 
+    #[error("Lima not found. Install with: brew install lima")]
+    LimaNotFound,
+
     #[error("Podman not available in OrbStack VM. Run: orb -m <vm> sudo dnf install -y podman")]
     PodmanNotFound,
 
+    #[error("Docker not found. Install Docker Desktop or the Docker Engine")]
+    DockerNotFound,
+
+    #[error("Docker daemon is not running")]
+    DockerNotRunning,
+
+    #[error("Cannot reach remote host {host} over SSH")]
+    RemoteUnreachable { host: String },
+
+    #[error("kubectl not found. Install it to use the k8s backend")]
+    KubectlNotFound,
+
+    #[error("Cannot reach Kubernetes cluster (context: {context}): {reason}")]
+    KubernetesUnreachable { context: String, reason: String },
+
     #[error("Unsupported platform: {0}. Mino supports macOS and Linux.")]
     UnsupportedPlatform(String),
 
@@ -66,6 +85,12 @@ pub enum MinoError {
     #[error("GitHub CLI not authenticated. Run: gh auth login")]
     GithubNotAuthenticated,
 
+    #[error("GitLab not authenticated. Run: glab auth login, or set GITLAB_TOKEN")]
+    GitlabNotAuthenticated,
+
+    #[error("Bitbucket token not found. Set BITBUCKET_TOKEN")]
+    BitbucketNotConfigured,
+
     #[error("Credential expired for {provider}, refresh required")]
     CredentialExpired { provider: String },
 
@@ -125,6 +150,18 @@ pub enum MinoError {
     #[error("Image build failed for '{tag}': {reason}")]
     ImageBuild { tag: String, reason: String },
 
+    #[error("GPU passthrough is not available with {runtime}: {reason}")]
+    GpuUnsupported { runtime: String, reason: String },
+
+    #[error("Device {device} does not exist on {location}")]
+    DeviceNotFound { device: String, location: String },
+
+    #[error("Checkpoint/restore is not available with {runtime}: {reason}")]
+    CheckpointUnsupported { runtime: String, reason: String },
+
+    #[error("{feature} is not available with {runtime}")]
+    BackendUnsupported { runtime: String, feature: String },
+
     // Network errors
     #[error("Network policy conflict: {0}")]
     NetworkPolicy(String),
@@ -206,7 +243,7 @@ impl MinoError {
     /// Create a command failed error
     pub fn command_failed(command: impl Into<String>, source: std::io::Error) -> Self {
         Self::CommandFailed {
-            command: command.into(),
+            command: redact::redact_text(&command.into()),
             source,
         }
     }
@@ -214,8 +251,8 @@ impl MinoError {
     /// Create a command execution error
     pub fn command_exec(command: impl Into<String>, stderr: impl Into<String>) -> Self {
         Self::CommandExecution {
-            command: command.into(),
-            stderr: stderr.into(),
+            command: redact::redact_text(&command.into()),
+            stderr: redact::redact_text(&stderr.into()),
         }
     }
 
@@ -225,6 +262,9 @@ impl MinoError {
             self,
             Self::CredentialExpired { .. }
                 | Self::OrbStackNotRunning
+                | Self::DockerNotRunning
+                | Self::RemoteUnreachable { .. }
+                | Self::KubernetesUnreachable { .. }
                 | Self::ContainerStart(_)
                 | Self::VmStart(_)
         )
@@ -235,15 +275,30 @@ impl MinoError {
         match self {
             Self::OrbStackNotFound => Some("Install OrbStack from https://orbstack.dev"),
             Self::OrbStackNotRunning => Some("Run: orb start"),
+            Self::LimaNotFound => Some("Install Lima from https://lima-vm.io, or run: brew install lima"),
+            Self::DockerNotFound => Some("Install from https://docs.docker.com/get-docker/"),
+            Self::DockerNotRunning => Some("Start Docker Desktop, or run: sudo systemctl start docker"),
+            Self::RemoteUnreachable { .. } => Some("Check orchestration.remote settings and that key-based `ssh <host>` works"),
+            Self::KubectlNotFound => Some("Install from https://kubernetes.io/docs/tasks/tools/"),
+            Self::KubernetesUnreachable { .. } => Some("Check orchestration.kubernetes settings and that `kubectl auth can-i create pods` answers yes"),
+            Self::BackendUnsupported { .. } => Some("Use the podman or docker backend: mino run --runtime podman"),
             Self::AwsNotConfigured => Some("Run: aws configure"),
             Self::GcpNotAuthenticated => Some("Run: gcloud auth login"),
             Self::AzureNotAuthenticated => Some("Run: az login"),
             Self::GithubNotAuthenticated => Some("Run: gh auth login"),
+            Self::GitlabNotAuthenticated => Some("Run: glab auth login"),
+            Self::BitbucketNotConfigured => Some("Create an API token or app password in Bitbucket and export it as BITBUCKET_TOKEN"),
             Self::LayerNotFound { .. } => Some("Create a layer with layer.toml + install.sh in .mino/layers/<name>/ or ~/.config/mino/layers/<name>/"),
             Self::ImageBuild { reason, .. } if reason.contains("subuid") || reason.contains("subgid") || reason.contains("insufficient UIDs") => {
                 Some("Rootless Podman not configured. Run: mino setup")
             }
             Self::ImageBuild { .. } => Some("Check build output above. Use -v for details."),
+            Self::ImagePull { reason, .. } if is_registry_auth_failure(reason) => {
+                Some("The registry needs credentials. Run: mino login <registry>")
+            }
+            Self::GpuUnsupported { .. } => Some("GPUs need a Linux host with the NVIDIA Container Toolkit and a CDI spec: sudo nvidia-ctk cdi generate --output=/etc/cdi/nvidia.yaml"),
+            Self::DeviceNotFound { .. } => Some("Check that the device is connected (ls -l /dev); on macOS it must also be attached to the VM"),
+            Self::CheckpointUnsupported { .. } => Some("Checkpoints need Podman with CRIU installed, running as root (rootless Podman cannot checkpoint)"),
             Self::PodmanRootlessSetup { .. } => Some("Run: mino setup"),
             Self::NoActiveSessions => Some("Start a session with: mino run"),
             Self::NetworkPolicy(_) => Some("Use --network bridge with --network-allow, or --network none without --network-allow."),
@@ -255,6 +310,21 @@ impl MinoError {
     }
 }
 
+/// Pull errors where the registry refused anonymous or wrong credentials
+fn is_registry_auth_failure(reason: &str) -> bool {
+    let reason = reason.to_lowercase();
+    [
+        "unauthorized",
+        "authentication required",
+        "denied",
+        "forbidden",
+        "401",
+        "403",
+    ]
+    .iter()
+    .any(|marker| reason.contains(marker))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -271,6 +341,24 @@ mod tests {
         assert_eq!(err.hint(), Some("Run: aws configure"));
     }
 
+    #[test]
+    fn image_pull_auth_failure_suggests_login() {
+        let err = MinoError::ImagePull {
+            image: "ghcr.io/org/private:latest".to_string(),
+            reason: "reading manifest: unauthorized: authentication required".to_string(),
+        };
+        assert_eq!(
+            err.hint(),
+            Some("The registry needs credentials. Run: mino login <registry>")
+        );
+
+        let err = MinoError::ImagePull {
+            image: "ghcr.io/org/missing:latest".to_string(),
+            reason: "manifest unknown".to_string(),
+        };
+        assert_eq!(err.hint(), None);
+    }
+
     #[test]
     fn error_retryable() {
         assert!(MinoError::OrbStackNotRunning.is_retryable());
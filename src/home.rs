@@ -54,7 +54,7 @@ impl HomeVolume {
 
     /// Generate labels for volume creation.
     pub fn labels(project_dir: &Path) -> HashMap<String, String> {
-        let mut map = HashMap::new();
+        let mut map = crate::labels::resource_labels(None, Some(project_dir));
         map.insert(labels::MINO_HOME.to_string(), "true".to_string());
         map.insert(
             labels::PROJECT.to_string(),
@@ -74,7 +74,7 @@ pub fn home_volume_name(project_dir: &Path) -> String {
 }
 
 /// Hash a project path to a 12-char hex string.
-fn hash_project_path(project_dir: &Path) -> String {
+pub(crate) fn hash_project_path(project_dir: &Path) -> String {
     let mut hasher = Sha256::new();
     hasher.update(project_dir.to_string_lossy().as_bytes());
     let hash = hex::encode(hasher.finalize());
@@ -0,0 +1,79 @@
+//! Labels stamped on every container, volume, and image mino creates
+//!
+//! Commands that enumerate mino resources match on labels rather than name
+//! prefixes, so resources the user has renamed or retagged are still found.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Session the resource belongs to
+pub const SESSION: &str = "io.mino.session";
+/// mino version that created the resource; present on every mino resource
+pub const VERSION: &str = "io.mino.version";
+/// Canonical project directory the resource was created for
+pub const PROJECT: &str = "io.mino.project";
+/// `[services]` entry a service container was started for
+pub const SERVICE: &str = "io.mino.service";
+
+/// Common labels for a new resource.
+///
+/// Shared resources (cache volumes, composed images) pass `None` for the
+/// session and project so they carry only the version label.
+pub fn resource_labels(session: Option<&str>, project: Option<&Path>) -> HashMap<String, String> {
+    let mut map = HashMap::new();
+    map.insert(VERSION.to_string(), env!("CARGO_PKG_VERSION").to_string());
+    if let Some(session) = session {
+        map.insert(SESSION.to_string(), session.to_string());
+    }
+    if let Some(project) = project {
+        let canonical = project
+            .canonicalize()
+            .unwrap_or_else(|_| project.to_path_buf());
+        map.insert(PROJECT.to_string(), canonical.display().to_string());
+    }
+    map
+}
+
+/// `--label key=value` arguments for `labels`, sorted for stable output
+pub fn label_args(labels: &HashMap<String, String>) -> Vec<String> {
+    let mut pairs: Vec<_> = labels.iter().collect();
+    pairs.sort();
+    pairs
+        .into_iter()
+        .flat_map(|(k, v)| ["--label".to_string(), format!("{}={}", k, v)])
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resource_labels_always_include_version() {
+        let labels = resource_labels(None, None);
+        assert_eq!(labels.len(), 1);
+        assert_eq!(labels[VERSION], env!("CARGO_PKG_VERSION"));
+    }
+
+    #[test]
+    fn resource_labels_include_session_and_project() {
+        let dir = tempfile::tempdir().unwrap();
+        let labels = resource_labels(Some("swift-fox"), Some(dir.path()));
+        assert_eq!(labels[SESSION], "swift-fox");
+        assert_eq!(
+            labels[PROJECT],
+            dir.path().canonicalize().unwrap().display().to_string()
+        );
+    }
+
+    #[test]
+    fn label_args_are_sorted_pairs() {
+        let mut labels = HashMap::new();
+        labels.insert("b".to_string(), "2".to_string());
+        labels.insert("a".to_string(), "1".to_string());
+        assert_eq!(
+            label_args(&labels),
+            vec!["--label", "a=1", "--label", "b=2"]
+        );
+    }
+}
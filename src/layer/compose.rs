@@ -6,10 +6,11 @@
 
 use crate::error::{MinoError, MinoResult};
 use crate::layer::resolve::ResolvedLayer;
-use crate::orchestration::ContainerRuntime;
+use crate::orchestration::{ContainerRuntime, PullPolicy};
 use sha2::{Digest, Sha256};
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 use tracing::debug;
 
 /// Result of composing an image from layers
@@ -89,29 +90,122 @@ pub(crate) fn merge_layer_env(
     env
 }
 
+/// Find settings claimed by more than one layer, which merging would
+/// otherwise resolve silently in favor of the later layer.
+///
+/// Reports env vars set to different values, PATH dirs prepended twice,
+/// equal or nested cache paths, and one runtime manager pinned to different
+/// versions. Returns one line per conflict, in layer order.
+pub(crate) fn layer_conflicts(layers: &[ResolvedLayer]) -> Vec<String> {
+    let mut conflicts = Vec::new();
+
+    for (i, later) in layers.iter().enumerate() {
+        let b = &later.manifest;
+        for earlier in &layers[..i] {
+            let a = &earlier.manifest;
+            let pair = format!("'{}' and '{}'", a.layer.name, b.layer.name);
+
+            let a_env = a.env_vars();
+            let mut b_env: Vec<(String, String)> = b.env_vars().into_iter().collect();
+            b_env.sort();
+            for (key, value) in b_env {
+                if let Some(other) = a_env.get(&key).filter(|v| **v != value) {
+                    conflicts.push(format!(
+                        "{} set {} to different values ({:?} vs {:?})",
+                        pair, key, other, value
+                    ));
+                }
+            }
+
+            for dir in &b.env.path_prepend.dirs {
+                if a.env.path_prepend.dirs.contains(dir) {
+                    conflicts.push(format!("{} both prepend {} to PATH", pair, dir));
+                }
+            }
+
+            for path in &b.cache.paths {
+                for other in &a.cache.paths {
+                    if Path::new(path).starts_with(other) || Path::new(other).starts_with(path) {
+                        conflicts.push(format!(
+                            "{} have overlapping cache paths {} and {}",
+                            pair, other, path
+                        ));
+                    }
+                }
+            }
+
+            let (ua, ub) = (&a.user_install, &b.user_install);
+            if let (Some(runtime), Some(other)) = (&ua.runtime, &ub.runtime) {
+                if runtime == other && ua.runtime_version != ub.runtime_version {
+                    conflicts.push(format!(
+                        "{} install {} at different versions ({} vs {})",
+                        pair,
+                        runtime,
+                        ua.runtime_version.as_deref().unwrap_or("default"),
+                        ub.runtime_version.as_deref().unwrap_or("default")
+                    ));
+                }
+            }
+        }
+    }
+
+    conflicts
+}
+
+/// Fail when [`layer_conflicts`] finds any, unless `allow` is set, in which
+/// case later layers win as in [`merge_layer_env`].
+pub(crate) fn check_layer_conflicts(layers: &[ResolvedLayer], allow: bool) -> MinoResult<()> {
+    let conflicts = layer_conflicts(layers);
+    if conflicts.is_empty() {
+        return Ok(());
+    }
+    if allow {
+        for conflict in &conflicts {
+            debug!("Allowed layer conflict: {}", conflict);
+        }
+        return Ok(());
+    }
+
+    Err(MinoError::User(format!(
+        "Conflicting layers:\n  {}\nFix the layer manifests, or pass --allow-layer-conflicts to let later layers win",
+        conflicts.join("\n  ")
+    )))
+}
+
 /// Compose a container image from multiple layers.
 ///
 /// Generates a Dockerfile that installs each layer in order, builds
 /// the image with a content-addressed tag, and returns the result.
 /// If the image already exists locally, the build is skipped.
 ///
-/// When `on_build_output` is provided, build output is streamed line-by-line
-/// through the callback for progress reporting. Otherwise uses batch build.
+/// Build output is always captured and persisted to [`build_log_path`], so
+/// it can be read back with `mino images log <tag>`. When `on_build_output`
+/// is provided, it is also streamed line-by-line through the callback for
+/// progress reporting.
+///
+/// `platform` (e.g. `linux/amd64`) builds for that architecture via
+/// `FROM --platform`, and gets its own tag so both variants can be cached.
+///
+/// `pull` applies to the base image: `always` rebuilds on a freshly pulled
+/// base even when the composed image is cached, and `never` fails when the
+/// base image is not available locally.
 pub async fn compose_image(
     runtime: &dyn ContainerRuntime,
     base_image: &str,
     layers: &[ResolvedLayer],
+    platform: Option<&str>,
+    pull: PullPolicy,
     on_build_output: Option<&(dyn Fn(String) + Send + Sync)>,
 ) -> MinoResult<ComposedImageResult> {
     // Compute content-addressed hash
-    let image_tag = compute_image_tag(base_image, layers).await?;
+    let image_tag = compute_image_tag(base_image, layers, platform).await?;
     debug!("Composed image tag: {}", image_tag);
 
     // Merge environment variables for the Dockerfile (baked into image)
     let build_env = merge_layer_env(layers, true);
 
     // Check if image already exists
-    if runtime.image_exists(&image_tag).await.unwrap_or(false) {
+    if pull != PullPolicy::Always && runtime.image_exists(&image_tag).await.unwrap_or(false) {
         debug!("Composed image already cached: {}", image_tag);
         return Ok(ComposedImageResult {
             image_tag,
@@ -122,21 +216,55 @@ pub async fn compose_image(
         });
     }
 
+    // Docker pulls a missing base image whatever the policy
+    if pull == PullPolicy::Never && !runtime.image_exists(base_image).await? {
+        return Err(MinoError::User(format!(
+            "Base image {} is not available locally and the pull policy is 'never'. \
+             Pull it while online or use --pull=missing.",
+            base_image
+        )));
+    }
+
     // Build the image
-    let build_dir = prepare_build_dir(base_image, layers, &build_env).await?;
+    let build_dir = prepare_build_dir(base_image, layers, platform, &build_env).await?;
 
-    let result = if let Some(callback) = on_build_output {
-        runtime
-            .build_image_with_progress(&build_dir, &image_tag, callback)
-            .await
-    } else {
-        runtime.build_image(&build_dir, &image_tag).await
+    let captured = Mutex::new(Vec::new());
+    let capture = |line: String| {
+        if let Some(callback) = on_build_output {
+            callback(line.clone());
+        }
+        captured
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(line);
     };
+    let result = runtime
+        .build_image_with_progress(&build_dir, &image_tag, pull, &capture)
+        .await;
 
     // Clean up build directory (best-effort)
     let _ = tokio::fs::remove_dir_all(&build_dir).await;
 
-    result?;
+    let output = captured.into_inner().unwrap_or_else(|e| e.into_inner());
+    let log = format_build_log(base_image, layers, result.is_ok(), &output);
+    let log_saved = match write_build_log(&image_tag, &log).await {
+        Ok(path) => {
+            debug!("Build log written to {}", path.display());
+            true
+        }
+        Err(e) => {
+            debug!("Failed to write build log: {}", e);
+            false
+        }
+    };
+
+    result.map_err(|e| match e {
+        MinoError::ImageBuild { tag, reason } if log_saved => MinoError::ImageBuild {
+            reason: format!("{}\nFull build log: mino images log {}", reason, tag),
+            tag,
+        },
+        e => e,
+    })?;
 
     Ok(ComposedImageResult {
         image_tag,
@@ -150,10 +278,18 @@ pub async fn compose_image(
 ///
 /// Hash inputs are sorted by layer name for determinism regardless of
 /// CLI argument order. The install order follows the user's specified order.
-async fn compute_image_tag(base_image: &str, layers: &[ResolvedLayer]) -> MinoResult<String> {
+async fn compute_image_tag(
+    base_image: &str,
+    layers: &[ResolvedLayer],
+    platform: Option<&str>,
+) -> MinoResult<String> {
     let mut hasher = Sha256::new();
 
     hasher.update(base_image.as_bytes());
+    // Host-platform builds keep the tags they had before --platform existed
+    if let Some(platform) = platform {
+        hasher.update(platform.as_bytes());
+    }
 
     // Sort by name for deterministic hash
     let mut sorted: Vec<&ResolvedLayer> = layers.iter().collect();
@@ -178,6 +314,11 @@ async fn compute_image_tag(base_image: &str, layers: &[ResolvedLayer]) -> MinoRe
         // also invalidate the cache
         let user_install_json = serde_json::to_string(&layer.manifest.user_install)?;
         hasher.update(user_install_json.as_bytes());
+
+        // Build arguments, so each set of values gets its own image
+        for (name, value) in &layer.manifest.args {
+            hasher.update(format!("{}={}\0", name, value).as_bytes());
+        }
     }
 
     let hash = hex::encode(hasher.finalize());
@@ -193,6 +334,7 @@ async fn compute_image_tag(base_image: &str, layers: &[ResolvedLayer]) -> MinoRe
 async fn prepare_build_dir(
     base_image: &str,
     layers: &[ResolvedLayer],
+    platform: Option<&str>,
     env: &HashMap<String, String>,
 ) -> MinoResult<PathBuf> {
     let state_dir = state_dir()?;
@@ -222,7 +364,7 @@ async fn prepare_build_dir(
     }
 
     // Generate and write Dockerfile
-    let dockerfile = generate_dockerfile(base_image, layers, env);
+    let dockerfile = generate_dockerfile(base_image, layers, platform, env);
     tokio::fs::write(build_dir.join("Dockerfile"), &dockerfile)
         .await
         .map_err(|e| MinoError::io("writing Dockerfile", e))?;
@@ -237,11 +379,15 @@ async fn prepare_build_dir(
 fn generate_dockerfile(
     base_image: &str,
     layers: &[ResolvedLayer],
+    platform: Option<&str>,
     env: &HashMap<String, String>,
 ) -> String {
     let mut lines = Vec::new();
 
-    lines.push(format!("FROM {}", base_image));
+    match platform {
+        Some(platform) => lines.push(format!("FROM --platform={} {}", platform, base_image)),
+        None => lines.push(format!("FROM {}", base_image)),
+    }
     lines.push(String::new());
 
     // Install each layer that has a compose-time script (skip user-install-only layers)
@@ -255,6 +401,9 @@ fn generate_dockerfile(
         lines.push(format!("# Layer: {}", name));
         lines.push("USER root".to_string());
         lines.push(format!("COPY {} /tmp/{}", script_name, script_name));
+        for (arg, value) in &layer.manifest.args {
+            lines.push(format!("ARG {}={}", arg, dockerfile_quote(value)));
+        }
         lines.push(format!(
             "RUN chmod +x /tmp/{script_name} && /tmp/{script_name} && rm /tmp/{script_name}"
         ));
@@ -291,6 +440,14 @@ fn generate_dockerfile(
     }
 
     lines.push(String::new());
+    // Labels last so a version bump does not invalidate the layer cache
+    let mut labels: Vec<_> = crate::labels::resource_labels(None, None)
+        .into_iter()
+        .collect();
+    labels.sort();
+    for (key, value) in labels {
+        lines.push(format!("LABEL {}={}", key, dockerfile_quote(&value)));
+    }
     lines.push("WORKDIR /workspace".to_string());
     // NOTE: ENTRYPOINT inherited from base image (mino-entrypoint → bootstrap)
     lines.push("CMD [\"/bin/zsh\"]".to_string());
@@ -311,6 +468,94 @@ fn dockerfile_quote(value: &str) -> String {
     }
 }
 
+/// Path of the persisted build log for a composed image tag
+/// (`~/.local/share/mino/builds/logs/<tag>.log`).
+pub fn build_log_path(tag: &str) -> MinoResult<PathBuf> {
+    Ok(state_dir()?
+        .join("builds")
+        .join("logs")
+        .join(format!("{}.log", tag)))
+}
+
+/// Normalize a user-supplied composed image reference to its bare tag.
+///
+/// Accepts the tag as printed by podman (`localhost/mino-composed-<hash>:latest`),
+/// the bare tag, or just the hash.
+pub fn normalize_composed_tag(reference: &str) -> String {
+    let tag = reference.trim();
+    let tag = tag.strip_prefix("localhost/").unwrap_or(tag);
+    let tag = tag.strip_suffix(":latest").unwrap_or(tag);
+    if tag.starts_with("mino-composed-") {
+        tag.to_string()
+    } else {
+        format!("mino-composed-{}", tag)
+    }
+}
+
+/// Read the persisted build log for a composed image.
+///
+/// Returns `None` when no log was recorded for the tag.
+pub async fn read_build_log(reference: &str) -> MinoResult<Option<String>> {
+    let tag = normalize_composed_tag(reference);
+    if tag.contains(['/', '\\']) || tag.contains("..") {
+        return Err(MinoError::User(format!(
+            "Invalid composed image tag: {}",
+            reference
+        )));
+    }
+
+    let path = build_log_path(&tag)?;
+    match tokio::fs::read_to_string(&path).await {
+        Ok(log) => Ok(Some(log)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(MinoError::io(
+            format!("reading build log {}", path.display()),
+            e,
+        )),
+    }
+}
+
+/// Write a build log, replacing any previous log for the tag.
+async fn write_build_log(tag: &str, log: &str) -> MinoResult<PathBuf> {
+    let path = build_log_path(tag)?;
+    if let Some(dir) = path.parent() {
+        tokio::fs::create_dir_all(dir)
+            .await
+            .map_err(|e| MinoError::io("creating build log directory", e))?;
+    }
+    tokio::fs::write(&path, log)
+        .await
+        .map_err(|e| MinoError::io(format!("writing {}", path.display()), e))?;
+    Ok(path)
+}
+
+/// Render a build log: a short header describing the build, then the
+/// captured build output.
+fn format_build_log(
+    base_image: &str,
+    layers: &[ResolvedLayer],
+    success: bool,
+    output: &[String],
+) -> String {
+    let layer_names: Vec<&str> = layers
+        .iter()
+        .map(|l| l.manifest.layer.name.as_str())
+        .collect();
+
+    let mut log = format!(
+        "# Built: {}\n# Base: {}\n# Layers: {}\n# Result: {}\n\n",
+        chrono::Utc::now().to_rfc3339(),
+        base_image,
+        layer_names.join(", "),
+        if success { "success" } else { "failed" },
+    );
+    for line in output {
+        log.push_str(line);
+        log.push('\n');
+    }
+    log
+}
+
 /// Get the mino state directory (`~/.local/share/mino/`)
 fn state_dir() -> MinoResult<PathBuf> {
     let dir = dirs::data_local_dir()
@@ -424,6 +669,85 @@ ONLY_B = "b_val"
         assert_eq!(env.get("ONLY_B").unwrap(), "b_val");
     }
 
+    #[test]
+    fn distinct_layers_do_not_conflict() {
+        let layers = vec![rust_layer(), ts_layer()];
+        assert!(layer_conflicts(&layers).is_empty());
+        assert!(check_layer_conflicts(&layers, false).is_ok());
+    }
+
+    #[test]
+    fn layer_conflicts_reports_env_path_cache_and_runtime() {
+        let layer_a = make_layer(
+            r#"
+[layer]
+name = "a"
+description = "A"
+version = "1"
+[env]
+SHARED = "from_a"
+SAME = "x"
+[env.path_prepend]
+dirs = ["/opt/bin"]
+[cache]
+paths = ["/cache/tool"]
+[user_install]
+runtime = "nvm"
+runtime_version = "20"
+"#,
+            "",
+        );
+        let layer_b = make_layer(
+            r#"
+[layer]
+name = "b"
+description = "B"
+version = "1"
+[env]
+SHARED = "from_b"
+SAME = "x"
+[env.path_prepend]
+dirs = ["/opt/bin"]
+[cache]
+paths = ["/cache/tool/sub"]
+[user_install]
+runtime = "nvm"
+runtime_version = "22"
+"#,
+            "",
+        );
+
+        let conflicts = layer_conflicts(&[layer_a, layer_b]);
+        assert_eq!(
+            conflicts,
+            vec![
+                r#"'a' and 'b' set SHARED to different values ("from_a" vs "from_b")"#,
+                "'a' and 'b' both prepend /opt/bin to PATH",
+                "'a' and 'b' have overlapping cache paths /cache/tool and /cache/tool/sub",
+                "'a' and 'b' install nvm at different versions (20 vs 22)",
+            ]
+        );
+    }
+
+    #[test]
+    fn check_layer_conflicts_fails_unless_allowed() {
+        let layer = |name: &str| {
+            make_layer(
+                &format!(
+                    "[layer]\nname = \"{}\"\ndescription = \"x\"\nversion = \"1\"\n[env]\nHOME_DIR = \"/{}\"\n",
+                    name, name
+                ),
+                "",
+            )
+        };
+        let layers = vec![layer("a"), layer("b")];
+
+        let err = check_layer_conflicts(&layers, false).unwrap_err();
+        assert!(err.to_string().contains("HOME_DIR"));
+        assert!(err.to_string().contains("--allow-layer-conflicts"));
+        assert!(check_layer_conflicts(&layers, true).is_ok());
+    }
+
     #[test]
     fn merge_env_accumulates_path() {
         let layers = vec![rust_layer(), ts_layer()];
@@ -440,7 +764,8 @@ ONLY_B = "b_val"
     fn generate_dockerfile_structure() {
         let layers = vec![rust_layer(), ts_layer()];
         let env = merge_layer_env(&layers, true);
-        let dockerfile = generate_dockerfile("ghcr.io/dean0x/mino-base:latest", &layers, &env);
+        let dockerfile =
+            generate_dockerfile("ghcr.io/dean0x/mino-base:latest", &layers, None, &env);
 
         assert!(dockerfile.contains("FROM ghcr.io/dean0x/mino-base:latest"));
         assert!(dockerfile.contains("# Layer: rust"));
@@ -451,6 +776,10 @@ ONLY_B = "b_val"
         assert!(dockerfile.contains("ENV CARGO_HOME=/home/developer/.cargo"));
         assert!(dockerfile.contains("ENV PNPM_HOME=/cache/pnpm"));
         assert!(dockerfile.contains("WORKDIR /workspace"));
+        assert!(dockerfile.contains(&format!(
+            "LABEL io.mino.version={}",
+            env!("CARGO_PKG_VERSION")
+        )));
 
         // Rust should come before TypeScript (user-specified order)
         let rust_pos = dockerfile.find("# Layer: rust").unwrap();
@@ -463,8 +792,12 @@ ONLY_B = "b_val"
         let layers_a = vec![rust_layer(), ts_layer()];
         let layers_b = vec![rust_layer(), ts_layer()];
 
-        let tag_a = compute_image_tag("base:latest", &layers_a).await.unwrap();
-        let tag_b = compute_image_tag("base:latest", &layers_b).await.unwrap();
+        let tag_a = compute_image_tag("base:latest", &layers_a, None)
+            .await
+            .unwrap();
+        let tag_b = compute_image_tag("base:latest", &layers_b, None)
+            .await
+            .unwrap();
 
         assert_eq!(tag_a, tag_b);
     }
@@ -475,8 +808,12 @@ ONLY_B = "b_val"
         let layers_rt = vec![rust_layer(), ts_layer()];
         let layers_tr = vec![ts_layer(), rust_layer()];
 
-        let tag_rt = compute_image_tag("base:latest", &layers_rt).await.unwrap();
-        let tag_tr = compute_image_tag("base:latest", &layers_tr).await.unwrap();
+        let tag_rt = compute_image_tag("base:latest", &layers_rt, None)
+            .await
+            .unwrap();
+        let tag_tr = compute_image_tag("base:latest", &layers_tr, None)
+            .await
+            .unwrap();
 
         assert_eq!(tag_rt, tag_tr);
     }
@@ -485,12 +822,61 @@ ONLY_B = "b_val"
     async fn hash_changes_with_base_image() {
         let layers = vec![rust_layer()];
 
-        let tag_a = compute_image_tag("base:v1", &layers).await.unwrap();
-        let tag_b = compute_image_tag("base:v2", &layers).await.unwrap();
+        let tag_a = compute_image_tag("base:v1", &layers, None).await.unwrap();
+        let tag_b = compute_image_tag("base:v2", &layers, None).await.unwrap();
 
         assert_ne!(tag_a, tag_b);
     }
 
+    #[tokio::test]
+    async fn platform_changes_tag_and_from_line() {
+        let layers = vec![rust_layer()];
+        let host = compute_image_tag("base:latest", &layers, None)
+            .await
+            .unwrap();
+        let amd64 = compute_image_tag("base:latest", &layers, Some("linux/amd64"))
+            .await
+            .unwrap();
+        assert_ne!(host, amd64);
+
+        let dockerfile =
+            generate_dockerfile("base:latest", &layers, Some("linux/amd64"), &HashMap::new());
+        assert!(dockerfile.starts_with("FROM --platform=linux/amd64 base:latest\n"));
+    }
+
+    #[test]
+    fn normalize_composed_tag_accepts_podman_forms() {
+        let expected = "mino-composed-a1b2c3d4e5f6";
+        assert_eq!(normalize_composed_tag("a1b2c3d4e5f6"), expected);
+        assert_eq!(normalize_composed_tag(expected), expected);
+        assert_eq!(
+            normalize_composed_tag("localhost/mino-composed-a1b2c3d4e5f6:latest"),
+            expected
+        );
+    }
+
+    #[test]
+    fn build_log_path_under_builds_logs() {
+        let path = build_log_path("mino-composed-abc").unwrap();
+        assert!(path.ends_with("mino/builds/logs/mino-composed-abc.log"));
+    }
+
+    #[test]
+    fn format_build_log_has_header_and_output() {
+        let output = vec!["STEP 1/4: FROM base".to_string(), "COMMIT".to_string()];
+        let log = format_build_log("base:latest", &[rust_layer(), ts_layer()], false, &output);
+
+        assert!(log.contains("# Base: base:latest\n"));
+        assert!(log.contains("# Layers: rust, typescript\n"));
+        assert!(log.contains("# Result: failed\n"));
+        assert!(log.ends_with("STEP 1/4: FROM base\nCOMMIT\n"));
+    }
+
+    #[tokio::test]
+    async fn read_build_log_rejects_path_traversal() {
+        assert!(read_build_log("../../etc/passwd").await.is_err());
+    }
+
     #[test]
     fn dockerfile_quote_simple() {
         assert_eq!(dockerfile_quote("/cache/cargo"), "/cache/cargo");
@@ -645,7 +1031,7 @@ runtime = "nvm"
         };
         let layers = vec![rust_layer(), user_only];
         let env = merge_layer_env(&layers, true);
-        let dockerfile = generate_dockerfile("base:latest", &layers, &env);
+        let dockerfile = generate_dockerfile("base:latest", &layers, None, &env);
 
         // rust layer should be in Dockerfile
         assert!(dockerfile.contains("# Layer: rust"));
@@ -675,10 +1061,77 @@ runtime = "uv"
             source: LayerSource::BuiltIn,
         }];
         let env = merge_layer_env(&layers, true);
-        let dockerfile = generate_dockerfile("base:latest", &layers, &env);
+        let dockerfile = generate_dockerfile("base:latest", &layers, None, &env);
 
         assert!(dockerfile
             .contains("dnf install -y --setopt=install_weak_deps=False python3 python3-devel"));
         assert!(dockerfile.contains("dnf clean all"));
     }
+
+    fn node_layer() -> ResolvedLayer {
+        make_layer(
+            r#"
+[layer]
+name = "node"
+description = "Node"
+version = "1"
+
+[args]
+node_version = "22"
+"#,
+            "#!/bin/bash\necho \"$node_version\"",
+        )
+    }
+
+    #[tokio::test]
+    async fn layer_args_become_build_args_and_change_tag() {
+        let default = vec![node_layer()];
+        let dockerfile = generate_dockerfile("base:latest", &default, None, &HashMap::new());
+        assert!(dockerfile
+            .contains("COPY install-node.sh /tmp/install-node.sh\nARG node_version=22\nRUN"));
+
+        let mut overridden = vec![node_layer()];
+        let overrides = [(
+            "node".to_string(),
+            [("node_version".to_string(), "20".to_string())].into(),
+        )]
+        .into();
+        crate::layer::apply_layer_args(&mut overridden, &overrides).unwrap();
+        let dockerfile = generate_dockerfile("base:latest", &overridden, None, &HashMap::new());
+        assert!(dockerfile.contains("ARG node_version=20"));
+
+        let tag_default = compute_image_tag("base:latest", &default, None)
+            .await
+            .unwrap();
+        let tag_overridden = compute_image_tag("base:latest", &overridden, None)
+            .await
+            .unwrap();
+        assert_ne!(tag_default, tag_overridden);
+    }
+
+    #[test]
+    fn layer_args_reject_unknown_names() {
+        let mut layers = vec![node_layer()];
+        let unknown_arg = [(
+            "node".to_string(),
+            [("nod_version".to_string(), "20".to_string())].into(),
+        )]
+        .into();
+        let err = crate::layer::apply_layer_args(&mut layers, &unknown_arg).unwrap_err();
+        assert!(
+            err.to_string().contains("declared: node_version"),
+            "{}",
+            err
+        );
+
+        let unknown_layer = [("rust".to_string(), Default::default())].into();
+        assert!(crate::layer::apply_layer_args(&mut layers, &unknown_layer).is_err());
+
+        let multiline = [(
+            "node".to_string(),
+            [("node_version".to_string(), "20\nRUN x".to_string())].into(),
+        )]
+        .into();
+        assert!(crate::layer::apply_layer_args(&mut layers, &multiline).is_err());
+    }
 }
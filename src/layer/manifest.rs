@@ -4,8 +4,11 @@
 //! environment variables, cache paths, and optional user-level install
 //! instructions for bootstrap-based tool installation.
 
+use crate::cache::{CacheVolume, Ecosystem};
 use crate::error::{MinoError, MinoResult};
+use crate::portable::normalize_newlines;
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
 use std::path::Path;
 
 /// Parsed layer manifest from layer.toml
@@ -29,6 +32,19 @@ pub struct LayerManifest {
     /// User-level tool installs (run via bootstrap, not compose)
     #[serde(default)]
     pub user_install: UserInstall,
+
+    /// Build arguments and their defaults, visible to `install.sh` as
+    /// Dockerfile ARGs (override with `--layer-arg <layer>.<arg>=<value>`)
+    #[serde(default)]
+    pub args: BTreeMap<String, String>,
+
+    /// Checks run by `mino layer test`
+    #[serde(default)]
+    pub verify: LayerVerify,
+
+    /// Commands run inside each session of the composed layer
+    #[serde(default)]
+    pub hooks: LayerHooks,
 }
 
 /// Layer metadata section
@@ -42,6 +58,10 @@ pub struct LayerMeta {
 
     /// Schema version for forward compatibility
     pub version: String,
+
+    /// Layers composed before this one (names or remote references)
+    #[serde(default)]
+    pub depends_on: Vec<String>,
 }
 
 /// Environment variables section
@@ -74,6 +94,44 @@ pub struct LayerCache {
     /// Paths that should be persisted in cache volumes
     #[serde(default)]
     pub paths: Vec<String>,
+
+    /// Extra cache key per ecosystem (e.g. `cargo = "rust-1.82"`), folded
+    /// into the lockfile hash so a toolchain change gets new caches
+    #[serde(default)]
+    pub key_extra: HashMap<String, String>,
+}
+
+impl LayerCache {
+    /// Validate that `key_extra` only names known ecosystems.
+    pub fn validate(&self) -> MinoResult<()> {
+        for name in self.key_extra.keys() {
+            if CacheVolume::parse_ecosystem(name).is_none() {
+                return Err(MinoError::ConfigInvalid {
+                    path: "layer.toml".into(),
+                    reason: format!("cache.key_extra names unknown ecosystem '{}'", name),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Verification section
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct LayerVerify {
+    /// Shell commands that must exit 0 in a container of the composed
+    /// layer (e.g., `node --version`)
+    #[serde(default)]
+    pub commands: Vec<String>,
+}
+
+/// Hooks section
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct LayerHooks {
+    /// Shell commands run in the container after the bootstrap, before the
+    /// session command or shell starts
+    #[serde(default)]
+    pub post_create: Vec<String>,
 }
 
 /// System packages requiring root installation (via dnf)
@@ -193,7 +251,7 @@ impl LayerManifest {
         let content = tokio::fs::read_to_string(path)
             .await
             .map_err(|e| MinoError::io(format!("reading layer manifest {}", path.display()), e))?;
-        Self::parse(&content)
+        Self::parse(&normalize_newlines(&content))
     }
 
     /// Parse a manifest from a TOML string (for embedded built-in layers)
@@ -234,6 +292,102 @@ impl LayerManifest {
     pub fn has_root_install(&self) -> bool {
         !self.root_install.packages.is_empty()
     }
+
+    /// Validate that `[args]` names are valid ARG identifiers and values
+    /// are single-line.
+    pub fn validate_args(&self) -> MinoResult<()> {
+        for (name, value) in &self.args {
+            validate_arg(name, value).map_err(|reason| MinoError::ConfigInvalid {
+                path: "layer.toml".into(),
+                reason,
+            })?;
+        }
+        Ok(())
+    }
+}
+
+/// Check one build argument: `[A-Za-z_][A-Za-z0-9_]*` name, no newlines
+fn validate_arg(name: &str, value: &str) -> Result<(), String> {
+    let mut chars = name.chars();
+    let valid_name = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if !valid_name {
+        return Err(format!(
+            "invalid argument name '{}': use letters, digits and underscores",
+            name
+        ));
+    }
+    if value.contains(['\n', '\r', '\0']) {
+        return Err(format!("argument '{}' must be a single line", name));
+    }
+    Ok(())
+}
+
+/// Override layer `[args]` defaults, keyed by layer name then argument.
+///
+/// Every layer named must be among `layers` and every argument declared by
+/// it, so a typo fails instead of silently building with the default.
+pub fn apply_layer_args(
+    layers: &mut [crate::layer::resolve::ResolvedLayer],
+    overrides: &BTreeMap<String, BTreeMap<String, String>>,
+) -> MinoResult<()> {
+    for (layer_name, args) in overrides {
+        let Some(layer) = layers
+            .iter_mut()
+            .find(|l| &l.manifest.layer.name == layer_name)
+        else {
+            return Err(MinoError::User(format!(
+                "Layer argument for '{}', which is not one of the session's layers",
+                layer_name
+            )));
+        };
+        for (name, value) in args {
+            let Some(slot) = layer.manifest.args.get_mut(name) else {
+                let declared: Vec<&str> = layer.manifest.args.keys().map(String::as_str).collect();
+                return Err(MinoError::User(format!(
+                    "Layer '{}' has no argument '{}' (declared: {})",
+                    layer_name,
+                    name,
+                    if declared.is_empty() {
+                        "none".to_string()
+                    } else {
+                        declared.join(", ")
+                    }
+                )));
+            };
+            validate_arg(name, value).map_err(MinoError::User)?;
+            *slot = value.clone();
+        }
+    }
+    Ok(())
+}
+
+/// Merge the `[cache.key_extra]` entries of all layers, in layer order.
+///
+/// Several layers keying the same ecosystem are joined with `+`.
+pub fn merge_cache_key_extra(
+    layers: &[crate::layer::resolve::ResolvedLayer],
+) -> HashMap<Ecosystem, String> {
+    let mut merged: HashMap<Ecosystem, String> = HashMap::new();
+    for layer in layers {
+        let mut entries: Vec<_> = layer.manifest.cache.key_extra.iter().collect();
+        entries.sort();
+        for (name, extra) in entries {
+            let Some(ecosystem) = CacheVolume::parse_ecosystem(name) else {
+                continue;
+            };
+            merged
+                .entry(ecosystem)
+                .and_modify(|key| {
+                    key.push('+');
+                    key.push_str(extra);
+                })
+                .or_insert_with(|| extra.clone());
+        }
+    }
+    merged
 }
 
 /// Build a JSON manifest string from layers that have user_install sections.
@@ -384,6 +538,25 @@ version = "1"
         assert!(manifest.cache.paths.is_empty());
         assert!(!manifest.has_user_install());
         assert!(!manifest.has_root_install());
+        assert!(manifest.hooks.post_create.is_empty());
+    }
+
+    #[test]
+    fn parse_post_create_hooks() {
+        let toml = r#"
+[layer]
+name = "node-deps"
+description = "Installs project dependencies"
+version = "1"
+
+[hooks]
+post_create = ["npm ci", "npm run prepare"]
+"#;
+        let manifest = LayerManifest::parse(toml).unwrap();
+        assert_eq!(
+            manifest.hooks.post_create,
+            vec!["npm ci", "npm run prepare"]
+        );
     }
 
     #[test]
@@ -578,6 +751,51 @@ uv_tools = ["ruff", "pytest"]
         }
     }
 
+    #[test]
+    fn cache_key_extra_validated_and_merged() {
+        let rust = make_resolved_layer(
+            r#"
+[layer]
+name = "rust"
+description = "Rust"
+version = "1"
+
+[cache.key_extra]
+cargo = "rust-1.82"
+"#,
+        );
+        let musl = make_resolved_layer(
+            r#"
+[layer]
+name = "musl"
+description = "musl target"
+version = "1"
+
+[cache.key_extra]
+cargo = "musl"
+"#,
+        );
+        assert!(rust.manifest.cache.validate().is_ok());
+
+        let merged = merge_cache_key_extra(&[rust, musl]);
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged[&Ecosystem::Cargo], "rust-1.82+musl");
+
+        let bad = make_resolved_layer(
+            r#"
+[layer]
+name = "bad"
+description = "Unknown ecosystem"
+version = "1"
+
+[cache.key_extra]
+rustc = "1.82"
+"#,
+        );
+        let err = bad.manifest.cache.validate().unwrap_err();
+        assert!(err.to_string().contains("unknown ecosystem 'rustc'"));
+    }
+
     #[test]
     fn build_layer_manifest_filters_non_user_install() {
         let layer_without = make_resolved_layer(
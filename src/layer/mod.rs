@@ -6,12 +6,22 @@
 
 pub mod compose;
 pub mod manifest;
+pub mod provision;
+pub mod remote;
 pub mod resolve;
+pub mod scaffold;
 
-pub use compose::{compose_image, ComposedImageResult};
-pub(crate) use compose::{compute_path_prepend, merge_layer_env, needs_compose_build};
-pub(crate) use manifest::build_layer_manifest;
+pub use compose::{
+    build_log_path, compose_image, normalize_composed_tag, read_build_log, ComposedImageResult,
+};
+pub(crate) use compose::{
+    check_layer_conflicts, compute_path_prepend, merge_layer_env, needs_compose_build,
+};
 pub use manifest::LayerManifest;
+pub(crate) use manifest::{apply_layer_args, build_layer_manifest, merge_cache_key_extra};
+pub use provision::provision_script;
 pub use resolve::{
-    list_available_layers, resolve_layers, AvailableLayer, LayerScript, LayerSource, ResolvedLayer,
+    find_layer, list_available_layers, packages_layer, resolve_layers, AvailableLayer, LayerFiles,
+    LayerScript, LayerSource, ResolvedLayer,
 };
+pub use scaffold::scaffold_layer;
@@ -0,0 +1,280 @@
+//! Host provisioning
+//!
+//! Renders resolved layers as a standalone bash script that installs them
+//! directly on a machine instead of into an image, for cloud VMs that should
+//! carry the same toolchain as a composed session. It runs the same pieces
+//! [`compose_image`](super::compose_image) and the base image's bootstrap
+//! use: layer install scripts and `root_install.packages` as root, the
+//! merged layer env (written to `~/.config/mino/env.sh` and sourced from
+//! `~/.profile`), then user installs through `mino-bootstrap`.
+
+use crate::error::{MinoError, MinoResult};
+use crate::layer::compose::merge_layer_env;
+use crate::layer::manifest::build_layer_manifest;
+use crate::layer::resolve::ResolvedLayer;
+use crate::network::shell_escape;
+
+/// The base image's bootstrap script, which performs user-level installs
+const BOOTSTRAP: &str = include_str!("../../images/base/mino-bootstrap");
+
+/// Home directory of the container user that layer env values refer to
+const CONTAINER_HOME: &str = "/home/developer";
+
+/// Cache volume mount point that layer env values refer to
+const CONTAINER_CACHE: &str = "/cache";
+
+/// Env file written on the provisioned host, relative to `$HOME`
+pub const HOST_ENV_FILE: &str = ".config/mino/env.sh";
+
+/// Installs with whatever package manager the host has
+const INSTALL_PACKAGES_FN: &str = r#"install_packages() {
+    if command -v dnf >/dev/null 2>&1; then $SUDO dnf install -y "$@"
+    elif command -v apt-get >/dev/null 2>&1; then $SUDO apt-get install -y "$@"
+    elif command -v pacman >/dev/null 2>&1; then $SUDO pacman -S --noconfirm "$@"
+    elif command -v zypper >/dev/null 2>&1; then $SUDO zypper install -y "$@"
+    else
+        echo "No supported package manager to install: $*" >&2
+        return 1
+    fi
+}"#;
+
+/// Render a bash script that installs `layers` on the machine running it.
+pub async fn provision_script(layers: &[ResolvedLayer]) -> MinoResult<String> {
+    let names: Vec<&str> = layers
+        .iter()
+        .map(|l| l.manifest.layer.name.as_str())
+        .collect();
+
+    let mut lines = vec![
+        "#!/usr/bin/env bash".to_string(),
+        format!("# Provision mino layers: {}", names.join(", ")),
+        "# Generated by `mino setup-env`".to_string(),
+        "set -euo pipefail".to_string(),
+        String::new(),
+        r#"if [ "$(id -u)" -eq 0 ]; then SUDO=""; else SUDO="sudo"; fi"#.to_string(),
+        String::new(),
+        INSTALL_PACKAGES_FN.to_string(),
+        String::new(),
+    ];
+
+    // Same order as the composed Dockerfile: scripts, then packages
+    for layer in layers {
+        if !layer.install_script.has_content() {
+            continue;
+        }
+        let name = &layer.manifest.layer.name;
+        let content = layer.install_script.content().await?;
+        lines.push(format!("# Layer: {}", name));
+        lines.push(format!("echo \"Installing layer: {}...\" >&2", name));
+        // Build arguments reach the script as env vars, like Dockerfile ARGs
+        let mut command = "$SUDO".to_string();
+        if !layer.manifest.args.is_empty() {
+            command.push_str(" env");
+            for (arg, value) in &layer.manifest.args {
+                command.push_str(&format!(" {}='{}'", arg, shell_escape(value)));
+            }
+        }
+        command.push_str(" bash");
+        lines.extend(heredoc(&command, &content)?);
+        lines.push(String::new());
+    }
+
+    let root_packages: Vec<String> = layers
+        .iter()
+        .filter(|l| l.manifest.has_root_install())
+        .flat_map(|l| l.manifest.root_install.packages.clone())
+        .collect();
+    if !root_packages.is_empty() {
+        lines.push("# Root-level packages from layer manifests".to_string());
+        lines.push(format!("install_packages {}", root_packages.join(" ")));
+        lines.push(String::new());
+    }
+
+    let env_lines = host_env_lines(layers);
+    if !env_lines.is_empty() {
+        let source_line = format!(". \"$HOME/{}\"", HOST_ENV_FILE);
+        lines.push("# Layer environment".to_string());
+        lines.push("mkdir -p \"$HOME/.config/mino\"".to_string());
+        lines.extend(heredoc(
+            &format!("cat > \"$HOME/{}\"", HOST_ENV_FILE),
+            &env_lines.join("\n"),
+        )?);
+        lines.push(format!(
+            "grep -qxF '{0}' \"$HOME/.profile\" 2>/dev/null || echo '{0}' >> \"$HOME/.profile\"",
+            shell_escape(&source_line)
+        ));
+        // Installers below then land where the env points
+        lines.push(source_line);
+        lines.push(String::new());
+    }
+
+    if let Some(manifest_json) = build_layer_manifest(layers)? {
+        lines.push("# User-level installs, as in a session's first start".to_string());
+        lines.push(
+            "for tool in curl jq; do command -v \"$tool\" >/dev/null 2>&1 || install_packages \"$tool\"; done"
+                .to_string(),
+        );
+        lines.push(format!(
+            "export MINO_LAYER_MANIFEST='{}'",
+            shell_escape(&manifest_json)
+        ));
+        lines.extend(heredoc("bash", BOOTSTRAP)?);
+        lines.push(String::new());
+    }
+
+    lines.push(format!(
+        "echo \"Provisioned layers: {}\" >&2",
+        names.join(", ")
+    ));
+    lines.push(String::new());
+    Ok(lines.join("\n"))
+}
+
+/// `export` lines for the merged layer env, with container paths moved
+/// under the host user's home
+fn host_env_lines(layers: &[ResolvedLayer]) -> Vec<String> {
+    let env = merge_layer_env(layers, false);
+    let mut keys: Vec<&String> = env.keys().collect();
+    keys.sort();
+
+    keys.into_iter()
+        .map(|key| {
+            let value = host_env_value(&env[key]);
+            match key.as_str() {
+                "MINO_PATH_PREPEND" => format!("export PATH=\"{}:$PATH\"", value),
+                _ => format!("export {}=\"{}\"", key, value),
+            }
+        })
+        .collect()
+}
+
+/// Rewrite container paths in an env value for the host, escaped for a
+/// double-quoted shell string (`$HOME` stays live)
+fn host_env_value(value: &str) -> String {
+    let escaped = value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('`', "\\`");
+    escaped
+        .split(':')
+        .map(|part| {
+            if let Some(rest) = strip_dir(part, CONTAINER_HOME) {
+                format!("$HOME{}", rest)
+            } else if let Some(rest) = strip_dir(part, CONTAINER_CACHE) {
+                format!("$HOME/.cache/mino{}", rest)
+            } else {
+                part.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(":")
+}
+
+/// `path` without the leading directory `dir`, if it is inside it
+fn strip_dir<'a>(path: &'a str, dir: &str) -> Option<&'a str> {
+    let rest = path.strip_prefix(dir)?;
+    (rest.is_empty() || rest.starts_with('/')).then_some(rest)
+}
+
+/// `command <<'EOF'` feeding `content` verbatim
+fn heredoc(command: &str, content: &str) -> MinoResult<Vec<String>> {
+    const DELIMITER: &str = "MINO_PROVISION_EOF";
+    if content.lines().any(|line| line.trim() == DELIMITER) {
+        return Err(MinoError::User(format!(
+            "Layer content contains the line {}, which setup-env uses as a delimiter",
+            DELIMITER
+        )));
+    }
+    let mut lines = vec![format!("{} <<'{}'", command, DELIMITER)];
+    lines.extend(content.lines().map(str::to_string));
+    lines.push(DELIMITER.to_string());
+    Ok(lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layer::manifest::LayerManifest;
+    use crate::layer::resolve::{LayerScript, LayerSource};
+
+    fn layer(manifest: &str, script: LayerScript) -> ResolvedLayer {
+        ResolvedLayer {
+            manifest: LayerManifest::parse(manifest).unwrap(),
+            install_script: script,
+            source: LayerSource::BuiltIn,
+        }
+    }
+
+    const RUST: &str = r#"
+[layer]
+name = "rust"
+description = "Rust"
+version = "2"
+
+[user_install]
+runtime = "rustup"
+
+[env]
+CARGO_HOME = "/home/developer/.cargo"
+SCCACHE_DIR = "/cache/sccache"
+
+[env.path_prepend]
+dirs = ["/home/developer/.cargo/bin"]
+"#;
+
+    const TOOLS: &str = r#"
+[layer]
+name = "tools"
+description = "Root tools"
+version = "1"
+
+[root_install]
+packages = ["ripgrep", "fd-find"]
+"#;
+
+    #[tokio::test]
+    async fn script_runs_root_installs_then_env_then_user_installs() {
+        let layers = vec![
+            layer(TOOLS, LayerScript::Embedded("#!/bin/bash\necho tools\n")),
+            layer(RUST, LayerScript::None),
+        ];
+        let script = provision_script(&layers).await.unwrap();
+
+        let root = script.find("$SUDO bash <<'MINO_PROVISION_EOF'").unwrap();
+        let packages = script.find("install_packages ripgrep fd-find").unwrap();
+        let env = script.find(". \"$HOME/.config/mino/env.sh\"\n").unwrap();
+        let bootstrap = script.find("export MINO_LAYER_MANIFEST=").unwrap();
+        assert!(root < packages && packages < env && env < bootstrap);
+        assert!(script.contains("echo tools"));
+        assert!(script.contains("\"runtime\":\"rustup\""));
+        assert!(script.contains("Bootstrap complete."));
+    }
+
+    #[tokio::test]
+    async fn script_writes_host_env() {
+        let script = provision_script(&[layer(RUST, LayerScript::None)])
+            .await
+            .unwrap();
+        assert!(script.contains("export CARGO_HOME=\"$HOME/.cargo\""));
+        assert!(script.contains("export SCCACHE_DIR=\"$HOME/.cache/mino/sccache\""));
+        assert!(script.contains("export PATH=\"$HOME/.cargo/bin:$PATH\""));
+        assert!(script.contains(">> \"$HOME/.profile\""));
+    }
+
+    #[test]
+    fn host_env_value_maps_container_paths() {
+        assert_eq!(host_env_value("/home/developer"), "$HOME");
+        assert_eq!(host_env_value("/home/developerx"), "/home/developerx");
+        assert_eq!(
+            host_env_value("/cache/a:/usr/bin"),
+            "$HOME/.cache/mino/a:/usr/bin"
+        );
+        assert_eq!(host_env_value("say \"hi\""), "say \\\"hi\\\"");
+    }
+
+    #[test]
+    fn heredoc_rejects_its_delimiter() {
+        assert!(heredoc("bash", "echo ok").is_ok());
+        assert!(heredoc("bash", "x\nMINO_PROVISION_EOF\ny").is_err());
+    }
+}
@@ -0,0 +1,611 @@
+//! Remote layers fetched from git repositories and OCI registries
+//!
+//! A layer reference may name a remote source instead of a local layer:
+//!
+//! - `github:org/repo//path@ref`: the `layer.toml` and `install.sh` in
+//!   `path` of the repository at `ref` (branch, tag or commit; default
+//!   `HEAD`). `path` may be omitted for a layer at the repository root.
+//! - `oci://registry/repo:tag` (or `@sha256:...`): an OCI artifact holding
+//!   `layer.toml` and `install.sh`, pulled with `oras`.
+//!
+//! Fetched layers are stored under `<state_dir>/mino/layers/<checksum>/`.
+//! The first fetch records the resolved commit or manifest digest and a
+//! SHA-256 of the layer files in the project's `.mino/layers.lock`; later
+//! resolutions fetch that exact revision and fail if its content differs.
+//! A cached copy whose checksum matches the lock is used without network
+//! access.
+
+use crate::config::ConfigManager;
+use crate::error::{MinoError, MinoResult};
+use crate::portable::normalize_newlines;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+use std::process::{Output, Stdio};
+use tokio::process::Command;
+use tracing::debug;
+
+/// Lockfile pinning remote layers, relative to the project directory
+const LOCK_FILE: &str = ".mino/layers.lock";
+
+const LOCK_HEADER: &str = "# Generated by mino. Pins remote layers to the content first fetched.\n# Remove an entry to accept new content for that layer.\n\n";
+
+/// A remote layer reference
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RemoteLayerRef {
+    /// `github:org/repo//path@ref`
+    Github {
+        owner: String,
+        repo: String,
+        path: Option<String>,
+        git_ref: String,
+    },
+    /// `oci://registry/repo:tag`
+    Oci { reference: String },
+}
+
+impl RemoteLayerRef {
+    /// Parse `name` as a remote reference; `Ok(None)` for local layer names.
+    pub fn parse(name: &str) -> MinoResult<Option<Self>> {
+        if let Some(rest) = name.strip_prefix("github:") {
+            return parse_github(name, rest).map(Some);
+        }
+        if let Some(reference) = name.strip_prefix("oci://") {
+            if reference.is_empty()
+                || reference.starts_with('-')
+                || !reference.contains('/')
+                || reference.contains("..")
+                || reference.chars().any(|c| c.is_whitespace() || c == '\0')
+            {
+                return Err(invalid(name, "expected oci://registry/repo:tag"));
+            }
+            return Ok(Some(Self::Oci {
+                reference: reference.to_string(),
+            }));
+        }
+        Ok(None)
+    }
+}
+
+impl std::fmt::Display for RemoteLayerRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Github {
+                owner,
+                repo,
+                path,
+                git_ref,
+            } => {
+                write!(f, "github:{}/{}", owner, repo)?;
+                if let Some(path) = path {
+                    write!(f, "//{}", path)?;
+                }
+                write!(f, "@{}", git_ref)
+            }
+            Self::Oci { reference } => write!(f, "oci://{}", reference),
+        }
+    }
+}
+
+fn invalid(name: &str, reason: &str) -> MinoError {
+    MinoError::User(format!("Invalid remote layer '{}': {}", name, reason))
+}
+
+fn parse_github(name: &str, rest: &str) -> MinoResult<RemoteLayerRef> {
+    let (location, git_ref) = match rest.rsplit_once('@') {
+        Some((location, git_ref)) => (location, git_ref),
+        None => (rest, "HEAD"),
+    };
+    let (repo_part, path) = match location.split_once("//") {
+        Some((repo_part, path)) => (repo_part, Some(path)),
+        None => (location, None),
+    };
+
+    let is_segment = |s: &str| {
+        !s.is_empty()
+            && s != "."
+            && s != ".."
+            && s.chars()
+                .all(|c| c.is_ascii_alphanumeric() || "-_.".contains(c))
+    };
+    let Some((owner, repo)) = repo_part.split_once('/') else {
+        return Err(invalid(name, "expected github:org/repo//path@ref"));
+    };
+    if !is_segment(owner) || !is_segment(repo) {
+        return Err(invalid(name, "expected github:org/repo//path@ref"));
+    }
+    if let Some(path) = path {
+        if !path.split('/').all(is_segment) {
+            return Err(invalid(name, "path must be a relative directory"));
+        }
+    }
+    if git_ref.is_empty()
+        || git_ref.starts_with('-')
+        || git_ref.contains("..")
+        || !git_ref
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_./".contains(c))
+    {
+        return Err(invalid(name, "invalid git ref"));
+    }
+
+    Ok(RemoteLayerRef::Github {
+        owner: owner.to_string(),
+        repo: repo.to_string(),
+        path: path.map(str::to_string),
+        git_ref: git_ref.to_string(),
+    })
+}
+
+/// Pinned revision of one remote layer in `layers.lock`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockedLayer {
+    /// Layer reference from `--layers` or config, with the default ref spelled out
+    pub source: String,
+    /// Commit SHA (git) or manifest digest (OCI) first fetched
+    pub resolved: String,
+    /// `sha256:<hex>` of the layer files
+    pub checksum: String,
+}
+
+/// Contents of `.mino/layers.lock`
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LayersLock {
+    #[serde(default, rename = "layer")]
+    pub layers: Vec<LockedLayer>,
+}
+
+impl LayersLock {
+    /// Lockfile path for `project_dir`
+    pub fn path(project_dir: &Path) -> PathBuf {
+        project_dir.join(LOCK_FILE)
+    }
+
+    /// Load the lockfile; empty when it does not exist
+    pub async fn load(path: &Path) -> MinoResult<Self> {
+        match tokio::fs::read_to_string(path).await {
+            Ok(content) => toml::from_str(&content).map_err(|e| MinoError::ConfigInvalid {
+                path: path.to_path_buf(),
+                reason: e.to_string(),
+            }),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(MinoError::io(format!("reading {}", path.display()), e)),
+        }
+    }
+
+    /// Write the lockfile atomically
+    pub async fn save(&self, path: &Path) -> MinoResult<()> {
+        let body = toml::to_string(self)
+            .map_err(|e| MinoError::Internal(format!("serializing layers.lock: {}", e)))?;
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(|e| MinoError::io(format!("creating {}", parent.display()), e))?;
+        }
+        let tmp = path.with_extension(format!("lock.tmp-{}", std::process::id()));
+        tokio::fs::write(&tmp, format!("{}{}", LOCK_HEADER, body))
+            .await
+            .map_err(|e| MinoError::io(format!("writing {}", tmp.display()), e))?;
+        tokio::fs::rename(&tmp, path)
+            .await
+            .map_err(|e| MinoError::io(format!("writing {}", path.display()), e))
+    }
+
+    pub fn get(&self, source: &str) -> Option<&LockedLayer> {
+        self.layers.iter().find(|l| l.source == source)
+    }
+
+    /// Insert or replace the entry for `entry.source`, keeping entries sorted
+    pub fn insert(&mut self, entry: LockedLayer) {
+        self.layers.retain(|l| l.source != entry.source);
+        self.layers.push(entry);
+        self.layers.sort_by(|a, b| a.source.cmp(&b.source));
+    }
+}
+
+/// `sha256:<hex>` over a layer's `layer.toml` and optional `install.sh`.
+/// Line endings are normalized first, matching how the files are read.
+pub async fn layer_checksum(dir: &Path) -> MinoResult<String> {
+    let mut hasher = Sha256::new();
+    for (file, required) in [("layer.toml", true), ("install.sh", false)] {
+        let path = dir.join(file);
+        match tokio::fs::read_to_string(&path).await {
+            Ok(content) => {
+                hasher.update(file.as_bytes());
+                hasher.update([0]);
+                hasher.update(normalize_newlines(&content).as_bytes());
+                hasher.update([0]);
+            }
+            Err(e) if !required && e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(MinoError::io(format!("reading {}", path.display()), e)),
+        }
+    }
+    Ok(format!("sha256:{}", hex::encode(hasher.finalize())))
+}
+
+/// Fetch (or reuse) a remote layer, returning its cached directory.
+///
+/// Pins the layer in the project's `layers.lock` on first use.
+pub async fn fetch_remote_layer(
+    reference: &RemoteLayerRef,
+    project_dir: &Path,
+) -> MinoResult<PathBuf> {
+    let cache_root = ConfigManager::state_dir().join("layers");
+    fetch_into(reference, project_dir, &cache_root).await
+}
+
+async fn fetch_into(
+    reference: &RemoteLayerRef,
+    project_dir: &Path,
+    cache_root: &Path,
+) -> MinoResult<PathBuf> {
+    let source = reference.to_string();
+    let lock_path = LayersLock::path(project_dir);
+    let mut lock = LayersLock::load(&lock_path).await?;
+    let pinned = lock.get(&source).cloned();
+
+    if let Some(ref entry) = pinned {
+        let dir = cached_dir(cache_root, &entry.checksum)?;
+        if dir.join("layer.toml").exists() && layer_checksum(&dir).await? == entry.checksum {
+            debug!("Using cached remote layer {} ({})", source, entry.checksum);
+            return Ok(dir);
+        }
+    }
+
+    tokio::fs::create_dir_all(cache_root)
+        .await
+        .map_err(|e| MinoError::io(format!("creating {}", cache_root.display()), e))?;
+    let staging = cache_root.join(format!(
+        ".staging-{}-{}",
+        std::process::id(),
+        chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+    ));
+    let result = async {
+        let pin = pinned.as_ref().map(|e| e.resolved.as_str());
+        let (layer_dir, resolved) = match reference {
+            RemoteLayerRef::Github {
+                owner,
+                repo,
+                path,
+                git_ref,
+            } => {
+                let url = format!("https://github.com/{}/{}.git", owner, repo);
+                let commit = fetch_git(&url, pin.unwrap_or(git_ref), &staging).await?;
+                let dir = match path {
+                    Some(path) => staging.join(path),
+                    None => staging.clone(),
+                };
+                (dir, commit)
+            }
+            RemoteLayerRef::Oci { reference } => {
+                let digest = fetch_oci(reference, pin, &staging).await?;
+                (staging.clone(), digest)
+            }
+        };
+        if !layer_dir.join("layer.toml").exists() {
+            return Err(MinoError::User(format!(
+                "Remote layer {} has no layer.toml",
+                source
+            )));
+        }
+        let checksum = layer_checksum(&layer_dir).await?;
+        if let Some(ref entry) = pinned {
+            if entry.checksum != checksum {
+                return Err(MinoError::User(format!(
+                    "Remote layer {} does not match {}: expected {}, fetched {}. Remove its entry to accept the new content",
+                    source,
+                    lock_path.display(),
+                    entry.checksum,
+                    checksum
+                )));
+            }
+        }
+        let dir = store(&layer_dir, cache_root, &checksum).await?;
+        Ok((dir, resolved, checksum))
+    }
+    .await;
+    tokio::fs::remove_dir_all(&staging).await.ok();
+    let (dir, resolved, checksum) = result?;
+
+    if pinned.is_none() {
+        lock.insert(LockedLayer {
+            source,
+            resolved,
+            checksum,
+        });
+        lock.save(&lock_path).await?;
+    }
+    Ok(dir)
+}
+
+/// Cache directory for a layer checksum
+fn cached_dir(cache_root: &Path, checksum: &str) -> MinoResult<PathBuf> {
+    match checksum.strip_prefix("sha256:") {
+        Some(hex) if hex.len() == 64 && hex.chars().all(|c| c.is_ascii_hexdigit()) => {
+            Ok(cache_root.join(hex))
+        }
+        _ => Err(MinoError::User(format!(
+            "Invalid checksum '{}' in layers.lock",
+            checksum
+        ))),
+    }
+}
+
+/// Copy the layer files from `layer_dir` into the cache
+async fn store(layer_dir: &Path, cache_root: &Path, checksum: &str) -> MinoResult<PathBuf> {
+    let dir = cached_dir(cache_root, checksum)?;
+    if dir.exists() {
+        tokio::fs::remove_dir_all(&dir)
+            .await
+            .map_err(|e| MinoError::io(format!("removing {}", dir.display()), e))?;
+    }
+    let tmp = dir.with_extension(format!("tmp-{}", std::process::id()));
+    tokio::fs::create_dir_all(&tmp)
+        .await
+        .map_err(|e| MinoError::io(format!("creating {}", tmp.display()), e))?;
+    for file in ["layer.toml", "install.sh"] {
+        let from = layer_dir.join(file);
+        if from.exists() {
+            tokio::fs::copy(&from, tmp.join(file))
+                .await
+                .map_err(|e| MinoError::io(format!("copying {}", from.display()), e))?;
+        }
+    }
+    tokio::fs::rename(&tmp, &dir)
+        .await
+        .map_err(|e| MinoError::io(format!("storing layer in {}", dir.display()), e))?;
+    Ok(dir)
+}
+
+/// Shallow-fetch `git_ref` of `url` into `dest` and check it out,
+/// returning the commit SHA
+async fn fetch_git(url: &str, git_ref: &str, dest: &Path) -> MinoResult<String> {
+    let mut init = Command::new("git");
+    init.args(["init", "-q"]).arg(dest);
+    check(run(init, "git init").await?, "git init")?;
+
+    let mut fetch = Command::new("git");
+    fetch
+        .arg("-C")
+        .arg(dest)
+        .args(["fetch", "-q", "--depth", "1", url, git_ref]);
+    check(run(fetch, "git fetch").await?, "git fetch")?;
+
+    let mut checkout = Command::new("git");
+    checkout
+        .arg("-C")
+        .arg(dest)
+        .args(["checkout", "-q", "FETCH_HEAD"]);
+    check(run(checkout, "git checkout").await?, "git checkout")?;
+
+    let mut rev = Command::new("git");
+    rev.arg("-C").arg(dest).args(["rev-parse", "HEAD"]);
+    let output = check(run(rev, "git rev-parse").await?, "git rev-parse")?;
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Repository part of an OCI reference (without tag or digest)
+fn oci_repository(reference: &str) -> &str {
+    if let Some((repo, _)) = reference.split_once('@') {
+        return repo;
+    }
+    match reference.rfind(':') {
+        Some(i) if !reference[i..].contains('/') => &reference[..i],
+        _ => reference,
+    }
+}
+
+/// Pull the artifact at `reference` (or its pinned `digest`) into `dest`,
+/// returning the manifest digest
+async fn fetch_oci(reference: &str, digest: Option<&str>, dest: &Path) -> MinoResult<String> {
+    let digest = match digest {
+        Some(digest) => digest.to_string(),
+        None => {
+            let mut fetch = Command::new("oras");
+            fetch.args(["manifest", "fetch", "--descriptor", reference]);
+            let output = check(
+                run(fetch, "oras manifest fetch").await?,
+                "oras manifest fetch",
+            )?;
+            let descriptor: serde_json::Value = serde_json::from_slice(&output.stdout)
+                .map_err(|e| MinoError::Internal(format!("parsing oras descriptor: {}", e)))?;
+            descriptor["digest"]
+                .as_str()
+                .ok_or_else(|| MinoError::Internal("oras descriptor has no digest".to_string()))?
+                .to_string()
+        }
+    };
+
+    tokio::fs::create_dir_all(dest)
+        .await
+        .map_err(|e| MinoError::io(format!("creating {}", dest.display()), e))?;
+    let mut pull = Command::new("oras");
+    pull.args([
+        "pull",
+        &format!("{}@{}", oci_repository(reference), digest),
+        "--output",
+    ]);
+    pull.arg(dest);
+    check(run(pull, "oras pull").await?, "oras pull")?;
+    Ok(digest)
+}
+
+async fn run(mut cmd: Command, name: &str) -> MinoResult<Output> {
+    cmd.stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    cmd.output()
+        .await
+        .map_err(|e| MinoError::command_failed(name, e))
+}
+
+fn check(output: Output, name: &str) -> MinoResult<Output> {
+    if output.status.success() {
+        Ok(output)
+    } else {
+        Err(MinoError::command_exec(
+            name,
+            String::from_utf8_lossy(&output.stderr),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn parses_github_references() {
+        assert_eq!(
+            RemoteLayerRef::parse("github:acme/layers//tools/protoc@v1.2")
+                .unwrap()
+                .unwrap(),
+            RemoteLayerRef::Github {
+                owner: "acme".to_string(),
+                repo: "layers".to_string(),
+                path: Some("tools/protoc".to_string()),
+                git_ref: "v1.2".to_string(),
+            }
+        );
+        let root = RemoteLayerRef::parse("github:acme/protoc-layer")
+            .unwrap()
+            .unwrap();
+        assert_eq!(root.to_string(), "github:acme/protoc-layer@HEAD");
+        assert!(RemoteLayerRef::parse("rust").unwrap().is_none());
+    }
+
+    #[test]
+    fn rejects_unsafe_references() {
+        for name in [
+            "github:acme",
+            "github:acme/layers//../etc@main",
+            "github:acme/layers//tools@--upload-pack=x",
+            "github:acme/layers@a..b",
+            "oci://",
+            "oci://-x/y:1",
+            "oci://reg/repo:tag with space",
+        ] {
+            assert!(RemoteLayerRef::parse(name).is_err(), "{}", name);
+        }
+    }
+
+    #[test]
+    fn oci_repository_strips_tag_and_digest() {
+        assert_eq!(
+            oci_repository("ghcr.io/acme/protoc:1"),
+            "ghcr.io/acme/protoc"
+        );
+        assert_eq!(
+            oci_repository("localhost:5000/protoc@sha256:abc"),
+            "localhost:5000/protoc"
+        );
+        assert_eq!(
+            oci_repository("localhost:5000/protoc"),
+            "localhost:5000/protoc"
+        );
+    }
+
+    #[tokio::test]
+    async fn lock_round_trips_sorted() {
+        let temp = TempDir::new().unwrap();
+        let path = LayersLock::path(temp.path());
+        let mut lock = LayersLock::default();
+        for source in ["oci://reg/b:1", "github:acme/a@main"] {
+            lock.insert(LockedLayer {
+                source: source.to_string(),
+                resolved: "r".to_string(),
+                checksum: "sha256:00".to_string(),
+            });
+        }
+        lock.save(&path).await.unwrap();
+
+        let loaded = LayersLock::load(&path).await.unwrap();
+        assert_eq!(loaded, lock);
+        assert_eq!(loaded.layers[0].source, "github:acme/a@main");
+        let text = std::fs::read_to_string(&path).unwrap();
+        assert!(text.starts_with("# Generated by mino"));
+        assert!(text.contains("[[layer]]"));
+    }
+
+    #[tokio::test]
+    async fn checksum_ignores_other_files_and_line_endings() {
+        let a = TempDir::new().unwrap();
+        let b = TempDir::new().unwrap();
+        std::fs::write(a.path().join("layer.toml"), "[layer]\nname = \"x\"\n").unwrap();
+        std::fs::write(b.path().join("layer.toml"), "[layer]\r\nname = \"x\"\r\n").unwrap();
+        std::fs::write(b.path().join("README.md"), "docs").unwrap();
+        assert_eq!(
+            layer_checksum(a.path()).await.unwrap(),
+            layer_checksum(b.path()).await.unwrap()
+        );
+
+        std::fs::write(b.path().join("install.sh"), "echo hi\n").unwrap();
+        assert_ne!(
+            layer_checksum(a.path()).await.unwrap(),
+            layer_checksum(b.path()).await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn pinned_cache_is_used_offline() {
+        let project = TempDir::new().unwrap();
+        let cache = TempDir::new().unwrap();
+        let layer = TempDir::new().unwrap();
+        std::fs::write(layer.path().join("layer.toml"), "[layer]\nname = \"x\"\n").unwrap();
+        let checksum = layer_checksum(layer.path()).await.unwrap();
+        store(layer.path(), cache.path(), &checksum).await.unwrap();
+
+        // An unreachable repository: resolution must not touch the network
+        let reference = RemoteLayerRef::parse("github:invalid-owner/invalid-repo@main")
+            .unwrap()
+            .unwrap();
+        let mut lock = LayersLock::default();
+        lock.insert(LockedLayer {
+            source: reference.to_string(),
+            resolved: "0".repeat(40),
+            checksum: checksum.clone(),
+        });
+        lock.save(&LayersLock::path(project.path())).await.unwrap();
+
+        let dir = fetch_into(&reference, project.path(), cache.path())
+            .await
+            .unwrap();
+        assert_eq!(layer_checksum(&dir).await.unwrap(), checksum);
+    }
+
+    #[tokio::test]
+    async fn fetch_git_checks_out_ref() {
+        let repo = TempDir::new().unwrap();
+        let git = |args: &[&str]| {
+            let status = std::process::Command::new("git")
+                .arg("-C")
+                .arg(repo.path())
+                .args(["-c", "user.name=t", "-c", "user.email=t@t"])
+                .args(args)
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .status();
+            matches!(status, Ok(s) if s.success())
+        };
+        if !git(&["init", "-q", "-b", "main"]) {
+            return; // git unavailable
+        }
+        std::fs::create_dir(repo.path().join("protoc")).unwrap();
+        std::fs::write(
+            repo.path().join("protoc/layer.toml"),
+            "[layer]\nname = \"protoc\"\n",
+        )
+        .unwrap();
+        assert!(git(&["add", "."]));
+        assert!(git(&["commit", "-q", "-m", "layer"]));
+
+        let dest = TempDir::new().unwrap();
+        let checkout = dest.path().join("checkout");
+        let commit = fetch_git(repo.path().to_str().unwrap(), "main", &checkout)
+            .await
+            .unwrap();
+        assert_eq!(commit.len(), 40);
+        assert!(checkout.join("protoc/layer.toml").exists());
+    }
+}
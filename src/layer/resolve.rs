@@ -4,9 +4,15 @@
 //! 1. Project-local: `{project_dir}/.mino/layers/{name}/`
 //! 2. User-global: `~/.config/mino/layers/{name}/`
 //! 3. Built-in: compiled into the binary via `include_str!`
+//!
+//! Names with a `github:` or `oci://` scheme are fetched instead; see
+//! [`crate::layer::remote`].
 
 use crate::error::{MinoError, MinoResult};
-use crate::layer::manifest::LayerManifest;
+use crate::layer::manifest::{LayerManifest, LayerMeta, RootInstall};
+use crate::layer::remote::{fetch_remote_layer, RemoteLayerRef};
+use crate::portable::normalize_newlines;
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 
 // Built-in layers embedded at compile time
@@ -16,6 +22,8 @@ const BUILTIN_TS_MANIFEST: &str = include_str!("../../images/typescript/layer.to
 const BUILTIN_TS_INSTALL: &str = include_str!("../../images/typescript/install.sh");
 const BUILTIN_PYTHON_MANIFEST: &str = include_str!("../../images/python/layer.toml");
 const BUILTIN_PYTHON_INSTALL: &str = include_str!("../../images/python/install.sh");
+const BUILTIN_JVM_MANIFEST: &str = include_str!("../../images/jvm/layer.toml");
+const BUILTIN_JVM_INSTALL: &str = include_str!("../../images/jvm/install.sh");
 
 /// A fully resolved layer ready for composition
 #[derive(Debug)]
@@ -45,11 +53,17 @@ pub enum LayerScript {
 
 impl LayerScript {
     /// Read the script content (from disk or embedded)
+    ///
+    /// Line endings are normalized to LF: a CRLF script from a Windows
+    /// checkout would fail under bash and hash differently from its LF twin.
     pub async fn content(&self) -> MinoResult<String> {
         match self {
-            Self::Path(path) => tokio::fs::read_to_string(path).await.map_err(|e| {
-                MinoError::io(format!("reading install script {}", path.display()), e)
-            }),
+            Self::Path(path) => tokio::fs::read_to_string(path)
+                .await
+                .map(|content| normalize_newlines(&content).into_owned())
+                .map_err(|e| {
+                    MinoError::io(format!("reading install script {}", path.display()), e)
+                }),
             Self::Embedded(content) => Ok((*content).to_string()),
             Self::None => Ok(String::new()),
         }
@@ -80,6 +94,38 @@ pub enum LayerSource {
 
     /// Compiled into the binary
     BuiltIn,
+
+    /// Fetched from a `github:` or `oci://` reference
+    Remote,
+
+    /// Generated from `[container.packages]`
+    Config,
+}
+
+impl LayerSource {
+    /// Short label for listings
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::ProjectLocal => "project",
+            Self::UserGlobal => "global",
+            Self::BuiltIn => "built-in",
+            Self::Remote => "remote",
+            Self::Config => "config",
+        }
+    }
+}
+
+/// A resolved layer with its raw manifest, for display
+#[derive(Debug)]
+pub struct LayerFiles {
+    pub layer: ResolvedLayer,
+
+    /// `layer.toml` as written
+    pub manifest_text: String,
+
+    /// Layer directory (the fetched copy for remote layers); `None` for
+    /// built-in layers
+    pub dir: Option<PathBuf>,
 }
 
 /// Resolve a list of layer names to their manifests and scripts.
@@ -88,22 +134,123 @@ pub enum LayerSource {
 /// 1. `{project_dir}/.mino/layers/{name}/`
 /// 2. `~/.config/mino/layers/{name}/`
 /// 3. Built-in embedded layers
+///
+/// Remote references are fetched and pinned in `.mino/layers.lock`.
+///
+/// Layers listed in a manifest's `depends_on` are resolved too and ordered
+/// before their dependents. Each layer appears once, however many layers
+/// depend on it or whichever alias names it; a dependency cycle is an error.
 pub async fn resolve_layers(
     names: &[String],
     project_dir: &Path,
 ) -> MinoResult<Vec<ResolvedLayer>> {
-    let mut resolved = Vec::with_capacity(names.len());
+    let mut by_name = HashMap::new();
+    let mut queue: Vec<String> = names.to_vec();
+    while let Some(name) = queue.pop() {
+        if by_name.contains_key(&name) {
+            continue;
+        }
+        let layer = resolve_single(&name, project_dir).await?;
+        queue.extend(layer.manifest.layer.depends_on.iter().cloned());
+        by_name.insert(name, layer);
+    }
 
+    let mut order = Vec::with_capacity(by_name.len());
+    let mut done = HashSet::new();
+    let mut stack = Vec::new();
     for name in names {
-        let layer = resolve_single(name, project_dir).await?;
-        resolved.push(layer);
+        visit(name, &by_name, &mut done, &mut stack, &mut order)?;
     }
 
-    Ok(resolved)
+    Ok(order
+        .into_iter()
+        .filter_map(|name| by_name.remove(&name))
+        .collect())
+}
+
+/// Depth-first visit appending `name` to `order` after its dependencies.
+///
+/// `done` and `stack` hold manifest names, so aliases of one layer
+/// (`py`, `python`) count as the same layer.
+fn visit(
+    name: &str,
+    by_name: &HashMap<String, ResolvedLayer>,
+    done: &mut HashSet<String>,
+    stack: &mut Vec<String>,
+    order: &mut Vec<String>,
+) -> MinoResult<()> {
+    let layer = &by_name[name];
+    let id = &layer.manifest.layer.name;
+    if done.contains(id) {
+        return Ok(());
+    }
+    if let Some(start) = stack.iter().position(|s| s == id) {
+        let mut cycle = stack[start..].to_vec();
+        cycle.push(id.clone());
+        return Err(MinoError::User(format!(
+            "Layer dependency cycle: {}",
+            cycle.join(" -> ")
+        )));
+    }
+
+    stack.push(id.clone());
+    for dep in &layer.manifest.layer.depends_on {
+        visit(dep, by_name, done, stack, order)?;
+    }
+    stack.pop();
+    done.insert(id.clone());
+    order.push(name.to_string());
+    Ok(())
+}
+
+/// Find a layer like [`resolve_layers`], keeping its raw manifest.
+pub async fn find_layer(name: &str, project_dir: &Path) -> MinoResult<LayerFiles> {
+    let (layer, dir) = match RemoteLayerRef::parse(name)? {
+        Some(reference) => {
+            let dir = fetch_remote_layer(&reference, project_dir).await?;
+            (resolve_remote_dir(name, &dir).await?, Some(dir))
+        }
+        None => {
+            let layer = resolve_single(name, project_dir).await?;
+            let dir = match layer.source {
+                LayerSource::ProjectLocal => Some(project_layer_dir(project_dir, name)),
+                LayerSource::UserGlobal => global_layer_dir(name),
+                LayerSource::BuiltIn | LayerSource::Remote | LayerSource::Config => None,
+            };
+            (layer, dir)
+        }
+    };
+    let manifest_text = match dir {
+        Some(ref dir) => {
+            let path = dir.join("layer.toml");
+            let content = tokio::fs::read_to_string(&path).await.map_err(|e| {
+                MinoError::io(format!("reading layer manifest {}", path.display()), e)
+            })?;
+            normalize_newlines(&content).into_owned()
+        }
+        None => builtin_sources(name)
+            .map(|(manifest, _)| manifest.to_string())
+            .unwrap_or_default(),
+    };
+    Ok(LayerFiles {
+        layer,
+        manifest_text,
+        dir,
+    })
+}
+
+/// Project-local layer directory: `{project_dir}/.mino/layers/{name}/`
+pub fn project_layer_dir(project_dir: &Path, name: &str) -> PathBuf {
+    project_dir.join(".mino").join("layers").join(name)
+}
+
+/// User-global layer directory: `~/.config/mino/layers/{name}/`
+fn global_layer_dir(name: &str) -> Option<PathBuf> {
+    dirs::config_dir().map(|d| d.join("mino").join("layers").join(name))
 }
 
 /// Validate that a layer name is safe (no path traversal, no special characters).
-fn validate_layer_name(name: &str) -> MinoResult<()> {
+pub(crate) fn validate_layer_name(name: &str) -> MinoResult<()> {
     if name.is_empty() {
         return Err(MinoError::User("Layer name cannot be empty".to_string()));
     }
@@ -127,10 +274,14 @@ fn validate_layer_name(name: &str) -> MinoResult<()> {
 }
 
 async fn resolve_single(name: &str, project_dir: &Path) -> MinoResult<ResolvedLayer> {
+    if let Some(reference) = RemoteLayerRef::parse(name)? {
+        let dir = fetch_remote_layer(&reference, project_dir).await?;
+        return resolve_remote_dir(name, &dir).await;
+    }
     validate_layer_name(name)?;
 
-    let project_layer_dir = project_dir.join(".mino").join("layers").join(name);
-    let global_layer_dir = dirs::config_dir().map(|d| d.join("mino").join("layers").join(name));
+    let project_layer_dir = project_layer_dir(project_dir, name);
+    let global_layer_dir = global_layer_dir(name);
 
     // 1. Project-local
     if let Some(layer) = try_resolve_from_dir(&project_layer_dir, LayerSource::ProjectLocal).await?
@@ -163,6 +314,56 @@ async fn resolve_single(name: &str, project_dir: &Path) -> MinoResult<ResolvedLa
     })
 }
 
+/// Name of the inline layer built from `[container.packages]`
+pub const PACKAGES_LAYER_NAME: &str = "container-packages";
+
+/// Inline layer installing `[container.packages] dnf` packages.
+///
+/// The packages are sorted and deduplicated, so the composed image hash
+/// does not depend on the order they are listed in. Returns `None` when
+/// there are no packages.
+pub fn packages_layer(dnf: &[String]) -> MinoResult<Option<ResolvedLayer>> {
+    let mut packages = dnf.to_vec();
+    packages.sort();
+    packages.dedup();
+    if packages.is_empty() {
+        return Ok(None);
+    }
+
+    let manifest = LayerManifest {
+        layer: LayerMeta {
+            name: PACKAGES_LAYER_NAME.to_string(),
+            description: "System packages from container.packages".to_string(),
+            version: "1".to_string(),
+            depends_on: vec![],
+        },
+        env: Default::default(),
+        cache: Default::default(),
+        root_install: RootInstall { packages },
+        user_install: Default::default(),
+        args: Default::default(),
+        verify: Default::default(),
+        hooks: Default::default(),
+    };
+    manifest.root_install.validate()?;
+
+    Ok(Some(ResolvedLayer {
+        manifest,
+        install_script: LayerScript::None,
+        source: LayerSource::Config,
+    }))
+}
+
+/// Resolve a fetched remote layer from its cached directory
+async fn resolve_remote_dir(name: &str, dir: &Path) -> MinoResult<ResolvedLayer> {
+    try_resolve_from_dir(dir, LayerSource::Remote)
+        .await?
+        .ok_or_else(|| MinoError::LayerNotFound {
+            name: name.to_string(),
+            searched: dir.display().to_string(),
+        })
+}
+
 /// Try to resolve a layer from a directory on disk.
 /// Returns None if the directory doesn't exist.
 /// Returns Err if the directory exists but is invalid (missing files).
@@ -180,6 +381,8 @@ async fn try_resolve_from_dir(
     let manifest = LayerManifest::from_file(&manifest_path).await?;
     manifest.user_install.validate()?;
     manifest.root_install.validate()?;
+    manifest.cache.validate()?;
+    manifest.validate_args()?;
 
     // install.sh is optional if the layer has [user_install]
     let install_script = if script_path.exists() {
@@ -199,18 +402,28 @@ async fn try_resolve_from_dir(
     }))
 }
 
+/// Embedded manifest and install script of a built-in layer
+fn builtin_sources(name: &str) -> Option<(&'static str, &'static str)> {
+    match name {
+        "rust" | "cargo" => Some((BUILTIN_RUST_MANIFEST, BUILTIN_RUST_INSTALL)),
+        "typescript" | "ts" | "node" => Some((BUILTIN_TS_MANIFEST, BUILTIN_TS_INSTALL)),
+        "python" | "py" => Some((BUILTIN_PYTHON_MANIFEST, BUILTIN_PYTHON_INSTALL)),
+        "jvm" | "java" | "kotlin" => Some((BUILTIN_JVM_MANIFEST, BUILTIN_JVM_INSTALL)),
+        _ => None,
+    }
+}
+
 /// Resolve a built-in layer by name
 fn resolve_builtin(name: &str) -> MinoResult<Option<ResolvedLayer>> {
-    let (manifest_str, install_str) = match name {
-        "rust" | "cargo" => (BUILTIN_RUST_MANIFEST, BUILTIN_RUST_INSTALL),
-        "typescript" | "ts" | "node" => (BUILTIN_TS_MANIFEST, BUILTIN_TS_INSTALL),
-        "python" | "py" => (BUILTIN_PYTHON_MANIFEST, BUILTIN_PYTHON_INSTALL),
-        _ => return Ok(None),
+    let Some((manifest_str, install_str)) = builtin_sources(name) else {
+        return Ok(None);
     };
 
     let manifest = LayerManifest::parse(manifest_str)?;
     manifest.user_install.validate()?;
     manifest.root_install.validate()?;
+    manifest.cache.validate()?;
+    manifest.validate_args()?;
 
     // Use LayerScript::None for layers where install.sh is a placeholder
     let install_script = if install_str.trim().is_empty()
@@ -258,6 +471,7 @@ pub async fn list_available_layers(project_dir: &Path) -> MinoResult<Vec<Availab
         ("typescript", BUILTIN_TS_MANIFEST),
         ("rust", BUILTIN_RUST_MANIFEST),
         ("python", BUILTIN_PYTHON_MANIFEST),
+        ("jvm", BUILTIN_JVM_MANIFEST),
     ] {
         if seen.contains(*name) {
             continue;
@@ -338,11 +552,12 @@ mod tests {
         assert!(resolve_builtin("cargo").unwrap().is_some());
         assert!(resolve_builtin("ts").unwrap().is_some());
         assert!(resolve_builtin("node").unwrap().is_some());
+        assert!(resolve_builtin("kotlin").unwrap().is_some());
     }
 
     #[test]
     fn resolve_builtin_unknown() {
-        assert!(resolve_builtin("java").unwrap().is_none());
+        assert!(resolve_builtin("haskell").unwrap().is_none());
     }
 
     #[tokio::test]
@@ -373,6 +588,16 @@ MY_VAR = "/custom/path"
         assert!(matches!(layers[0].install_script, LayerScript::Path(_)));
     }
 
+    #[tokio::test]
+    async fn script_content_normalizes_crlf() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("install.sh");
+        std::fs::write(&path, "#!/bin/bash\r\necho hi\r\n").unwrap();
+
+        let content = LayerScript::Path(path).content().await.unwrap();
+        assert_eq!(content, "#!/bin/bash\necho hi\n");
+    }
+
     #[tokio::test]
     async fn resolve_missing_layer_errors() {
         let temp = TempDir::new().unwrap();
@@ -414,6 +639,23 @@ version = "99"
         assert!(content.contains("dnf"));
     }
 
+    #[tokio::test]
+    async fn resolve_builtin_jvm() {
+        let layer = resolve_builtin("java").unwrap().unwrap();
+        assert_eq!(layer.manifest.layer.name, "jvm");
+        assert!(layer
+            .manifest
+            .root_install
+            .packages
+            .contains(&"maven".to_string()));
+        assert_eq!(
+            layer.manifest.env_vars()["GRADLE_USER_HOME"],
+            "/cache/gradle"
+        );
+        let content = layer.install_script.content().await.unwrap();
+        assert!(content.contains("gradle"));
+    }
+
     #[test]
     fn resolve_builtin_rust_user_install() {
         let layer = resolve_builtin("rust").unwrap().unwrap();
@@ -468,6 +710,26 @@ version = "99"
         assert!(validate_layer_name("my_layer_v2").is_ok());
     }
 
+    #[tokio::test]
+    async fn find_layer_keeps_raw_manifest() {
+        let temp = TempDir::new().unwrap();
+        let builtin = find_layer("python", temp.path()).await.unwrap();
+        assert!(builtin.dir.is_none());
+        assert!(builtin.manifest_text.contains("name = \"python\""));
+
+        let dir = project_layer_dir(temp.path(), "python");
+        std::fs::create_dir_all(&dir).unwrap();
+        let manifest =
+            "# custom\n[layer]\nname = \"python\"\ndescription = \"Mine\"\nversion = \"1\"\n";
+        std::fs::write(dir.join("layer.toml"), manifest).unwrap();
+        std::fs::write(dir.join("install.sh"), "#!/bin/bash\n").unwrap();
+
+        let local = find_layer("python", temp.path()).await.unwrap();
+        assert_eq!(local.layer.source, LayerSource::ProjectLocal);
+        assert_eq!(local.dir, Some(dir));
+        assert_eq!(local.manifest_text, manifest);
+    }
+
     #[tokio::test]
     async fn resolve_rejects_traversal_name() {
         let temp = TempDir::new().unwrap();
@@ -617,4 +879,72 @@ version = "1"
         assert_eq!(rust_layers[0].source, LayerSource::ProjectLocal);
         assert_eq!(rust_layers[0].description, "Custom Rust");
     }
+
+    fn write_layer(project: &Path, name: &str, depends_on: &[&str]) {
+        let layer_dir = project.join(".mino").join("layers").join(name);
+        std::fs::create_dir_all(&layer_dir).unwrap();
+        std::fs::write(
+            layer_dir.join("layer.toml"),
+            format!(
+                "[layer]\nname = \"{}\"\ndescription = \"d\"\nversion = \"1\"\ndepends_on = {:?}\n",
+                name, depends_on
+            ),
+        )
+        .unwrap();
+        std::fs::write(layer_dir.join("install.sh"), "#!/bin/bash\necho ok").unwrap();
+    }
+
+    #[tokio::test]
+    async fn dependencies_come_first_once() {
+        let temp = TempDir::new().unwrap();
+        write_layer(temp.path(), "python-ml", &["python", "cuda"]);
+        write_layer(temp.path(), "cuda", &["py"]);
+        write_layer(temp.path(), "notebooks", &["python-ml"]);
+
+        let names = ["notebooks", "python", "rust"].map(String::from);
+        let layers = resolve_layers(&names, temp.path()).await.unwrap();
+        let order: Vec<_> = layers
+            .iter()
+            .map(|l| l.manifest.layer.name.as_str())
+            .collect();
+        assert_eq!(order, ["python", "cuda", "python-ml", "notebooks", "rust"]);
+    }
+
+    #[tokio::test]
+    async fn dependency_cycle_errors() {
+        let temp = TempDir::new().unwrap();
+        write_layer(temp.path(), "a", &["b"]);
+        write_layer(temp.path(), "b", &["c"]);
+        write_layer(temp.path(), "c", &["a"]);
+
+        let err = resolve_layers(&["a".to_string()], temp.path())
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("a -> b -> c -> a"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn missing_dependency_errors() {
+        let temp = TempDir::new().unwrap();
+        write_layer(temp.path(), "a", &["nonexistent"]);
+        let result = resolve_layers(&["a".to_string()], temp.path()).await;
+        assert!(matches!(result, Err(MinoError::LayerNotFound { .. })));
+    }
+
+    #[test]
+    fn packages_layer_is_sorted_and_validated() {
+        assert!(packages_layer(&[]).unwrap().is_none());
+
+        let dnf = ["jq", "postgresql", "jq"].map(String::from);
+        let layer = packages_layer(&dnf).unwrap().unwrap();
+        assert_eq!(layer.manifest.layer.name, PACKAGES_LAYER_NAME);
+        assert_eq!(layer.manifest.root_install.packages, ["jq", "postgresql"]);
+        assert_eq!(layer.source, LayerSource::Config);
+        assert!(!layer.install_script.has_content());
+        assert!(crate::layer::needs_compose_build(std::slice::from_ref(
+            &layer
+        )));
+
+        assert!(packages_layer(&["jq; rm -rf /".to_string()]).is_err());
+    }
 }
@@ -0,0 +1,137 @@
+//! Layer scaffolding
+//!
+//! `mino layer new <name>` writes a commented `layer.toml` and `install.sh`
+//! template to `.mino/layers/<name>/`, where resolution picks it up ahead of
+//! user-global and built-in layers.
+
+use crate::error::{MinoError, MinoResult};
+use crate::layer::resolve::{project_layer_dir, validate_layer_name};
+use std::path::{Path, PathBuf};
+
+/// Template `layer.toml`; `{name}` is replaced with the layer name
+const MANIFEST_TEMPLATE: &str = r#"# Layer manifest. Use it with: mino run --layers {name}
+[layer]
+name = "{name}"
+description = "Describe what this layer installs"
+version = "1"
+# Layers composed before this one
+# depends_on = ["python"]
+
+# System packages installed with dnf when the image is composed (as root)
+# [root_install]
+# packages = ["make"]
+
+# User-level tools installed by the bootstrap script at container start
+# [user_install]
+# runtime = "uv"              # nvm, rustup, or uv
+# runtime_version = "3.12"
+# uv_tools = ["ruff"]
+
+# Environment variables set in the container
+# [env]
+# MY_TOOL_HOME = "/cache/my-tool"
+
+# Directories prepended to PATH
+# [env.path_prepend]
+# dirs = ["/home/developer/.my-tool/bin"]
+
+# Paths persisted in cache volumes
+# [cache]
+# paths = ["/cache/my-tool"]
+
+# Build arguments, visible to install.sh as variables
+# Override with: mino run --layer-arg {name}.my_tool_version=2.0
+# [args]
+# my_tool_version = "1.0"
+
+# Commands that must succeed in the composed image. Run with: mino layer test {name}
+# [verify]
+# commands = ["my-tool --version"]
+
+# Commands run in every session, after the bootstrap and before the shell
+# or command starts. They run again on restart, so keep them idempotent.
+# [hooks]
+# post_create = ["my-tool init"]
+
+# Extra cache key per ecosystem, e.g. a pinned toolchain version
+# [cache.key_extra]
+# cargo = "rust-1.82"
+"#;
+
+/// Template `install.sh`; `{name}` is replaced with the layer name
+const INSTALL_TEMPLATE: &str = r#"#!/usr/bin/env bash
+# Root-level install script for the {name} layer
+# Runs as root when mino composes the image. Keep it idempotent.
+set -euo pipefail
+
+# dnf install -y --setopt=install_weak_deps=False <packages> \
+#     && dnf clean all
+"#;
+
+/// Create `.mino/layers/<name>/` in `project_dir` with template files.
+///
+/// Returns the layer directory. Fails if the directory already exists.
+pub async fn scaffold_layer(project_dir: &Path, name: &str) -> MinoResult<PathBuf> {
+    validate_layer_name(name)?;
+    let dir = project_layer_dir(project_dir, name);
+    if dir.exists() {
+        return Err(MinoError::User(format!(
+            "Layer directory {} already exists",
+            dir.display()
+        )));
+    }
+
+    tokio::fs::create_dir_all(&dir)
+        .await
+        .map_err(|e| MinoError::io(format!("creating directory {}", dir.display()), e))?;
+
+    let manifest_path = dir.join("layer.toml");
+    tokio::fs::write(&manifest_path, MANIFEST_TEMPLATE.replace("{name}", name))
+        .await
+        .map_err(|e| MinoError::io(format!("writing {}", manifest_path.display()), e))?;
+
+    let script_path = dir.join("install.sh");
+    tokio::fs::write(&script_path, INSTALL_TEMPLATE.replace("{name}", name))
+        .await
+        .map_err(|e| MinoError::io(format!("writing {}", script_path.display()), e))?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let perms = std::fs::Permissions::from_mode(0o755);
+        std::fs::set_permissions(&script_path, perms)
+            .map_err(|e| MinoError::io("making install.sh executable", e))?;
+    }
+
+    Ok(dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layer::resolve::{resolve_layers, LayerSource};
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn scaffolded_layer_resolves() {
+        let temp = TempDir::new().unwrap();
+        let dir = scaffold_layer(temp.path(), "protoc").await.unwrap();
+        assert_eq!(dir, temp.path().join(".mino/layers/protoc"));
+
+        let layers = resolve_layers(&["protoc".to_string()], temp.path())
+            .await
+            .unwrap();
+        assert_eq!(layers[0].manifest.layer.name, "protoc");
+        assert_eq!(layers[0].source, LayerSource::ProjectLocal);
+    }
+
+    #[tokio::test]
+    async fn scaffold_refuses_existing_or_invalid() {
+        let temp = TempDir::new().unwrap();
+        scaffold_layer(temp.path(), "protoc").await.unwrap();
+        let err = scaffold_layer(temp.path(), "protoc").await.unwrap_err();
+        assert!(err.to_string().contains("already exists"));
+
+        assert!(scaffold_layer(temp.path(), "../evil").await.is_err());
+    }
+}
@@ -9,15 +9,21 @@ pub mod cli;
 pub mod config;
 #[path = "creds/mod.rs"]
 pub mod credentials;
+pub mod env_passthrough;
 pub mod error;
 pub mod home;
+pub mod labels;
 pub mod layer;
 pub mod network;
 pub mod orchestration;
+pub mod portable;
+pub mod redact;
 pub mod sandbox;
+pub mod scratch;
 pub mod session;
 pub(crate) mod terminal;
 pub mod ui;
 pub mod version;
+pub mod worktree;
 
 pub use error::{MinoError, MinoResult};
@@ -4,11 +4,11 @@
 
 use clap::Parser;
 use console::style;
-use mino::cli::{Cli, Commands};
+use mino::cli::{Cli, Commands, ProgressMode};
 use mino::config::ConfigManager;
 use mino::error::MinoResult;
 use std::process::ExitCode;
-use tracing::debug;
+use tracing::{debug, warn};
 use tracing_subscriber::EnvFilter;
 
 #[tokio::main]
@@ -41,6 +41,10 @@ async fn run() -> MinoResult<ExitCode> {
         .without_time()
         .init();
 
+    if cli.progress == ProgressMode::Json {
+        mino::ui::init_progress(mino::ui::ProgressSink::JsonLines);
+    }
+
     // Commands that don't need config loading
     if let Commands::Init(args) = cli.command {
         mino::cli::commands::init(args).await?;
@@ -58,6 +62,17 @@ async fn run() -> MinoResult<ExitCode> {
         ConfigManager::new()
     };
 
+    // First run: guide the user through setup before anything else
+    let interactive = mino::ui::UiContext::detect().is_interactive();
+    if mino::cli::commands::onboard::should_onboard(
+        &cli.command,
+        config_manager.path().exists(),
+        cli.config.is_some(),
+        interactive,
+    ) {
+        mino::cli::commands::onboard(&config_manager).await?;
+    }
+
     // Find local config unless --no-local is set
     let local_config_path = if cli.no_local {
         debug!("Local config discovery disabled (--no-local)");
@@ -85,6 +100,18 @@ async fn run() -> MinoResult<ExitCode> {
         .load_merged(local_config_path.as_deref())
         .await?;
 
+    // Custom credential env vars are secrets whatever they are called
+    let mut redact_patterns = config.general.redact_patterns.clone();
+    redact_patterns.extend(config.credentials.custom.values().map(|c| c.env.clone()));
+    mino::redact::init(&redact_patterns);
+    // A bad style must not lock the user out of `mino config set` to fix it
+    let style = config.ui.style.parse().unwrap_or_else(|e| {
+        warn!("{}; using auto", e);
+        mino::ui::StyleMode::Auto
+    });
+    mino::ui::init_style(style);
+    mino::ui::init_pager(!cli.no_pager);
+
     // Ensure state directories exist
     ConfigManager::ensure_state_dirs().await?;
 
@@ -92,14 +119,34 @@ async fn run() -> MinoResult<ExitCode> {
     match cli.command {
         Commands::Init(_) | Commands::Completions(_) => unreachable!("handled above"),
         Commands::Exec(args) => mino::cli::commands::exec(args, &config).await?,
+        Commands::Cp(args) => mino::cli::commands::cp(args, &config).await?,
+        Commands::Clip(args) => mino::cli::commands::clip(args, &config).await?,
         Commands::Run(args) => mino::cli::commands::run(args, &config).await?,
+        Commands::Shell(args) => mino::cli::commands::shell(args, &config).await?,
         Commands::List(args) => mino::cli::commands::list(args, &config).await?,
         Commands::Stop(args) => mino::cli::commands::stop(args, &config).await?,
+        Commands::Kill(args) => mino::cli::commands::kill(args, &config).await?,
+        Commands::Pause(args) => mino::cli::commands::pause(args, &config).await?,
+        Commands::Resume(args) => mino::cli::commands::resume(args, &config).await?,
+        Commands::Checkpoint(args) => mino::cli::commands::checkpoint(args, &config).await?,
+        Commands::Restore(args) => mino::cli::commands::restore(args, &config).await?,
         Commands::Logs(args) => mino::cli::commands::logs(args, &config).await?,
-        Commands::Status => mino::cli::commands::status(&config).await?,
+        Commands::Login(args) => mino::cli::commands::login(args, &config).await?,
+        Commands::Status(args) => {
+            let json = args.json;
+            if !mino::cli::commands::status(args, &config).await? && json {
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+        Commands::Bench(args) => mino::cli::commands::bench(args, &config).await?,
         Commands::Setup(args) => mino::cli::commands::setup(args, &config).await?,
+        Commands::SetupEnv(args) => mino::cli::commands::setup_env(args, &config).await?,
         Commands::Config(args) => mino::cli::commands::config(args, &config).await?,
         Commands::Cache(args) => mino::cli::commands::cache(args, &config).await?,
+        Commands::Creds(args) => mino::cli::commands::creds(args, &config).await?,
+        Commands::Images(args) => mino::cli::commands::images(args, &config).await?,
+        Commands::Layer(args) => mino::cli::commands::layer(args, &config).await?,
+        Commands::Session(args) => mino::cli::commands::session(args, &config).await?,
     };
 
     Ok(ExitCode::SUCCESS)
@@ -5,6 +5,14 @@
 
 use crate::error::{MinoError, MinoResult};
 
+/// Shared network that gives sessions stable DNS names
+pub const SESSION_NETWORK: &str = "mino";
+
+/// DNS name other sessions on [`SESSION_NETWORK`] use to reach `session_name`
+pub fn session_hostname(session_name: &str) -> String {
+    format!("{}.{}", session_name, SESSION_NETWORK)
+}
+
 /// A single network allowlist rule: host:port
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct NetworkRule {
@@ -260,6 +268,14 @@ impl NetworkMode {
         }
     }
 
+    /// Whether sessions in this mode can join [`SESSION_NETWORK`].
+    ///
+    /// Only bridged modes use a container network; `host` and `none` have
+    /// no network to attach aliases to.
+    pub fn supports_session_dns(&self) -> bool {
+        matches!(self, NetworkMode::Bridge | NetworkMode::Allow(_))
+    }
+
     /// Whether the container needs `CAP_NET_ADMIN` for iptables.
     pub fn requires_cap_net_admin(&self) -> bool {
         matches!(self, NetworkMode::Allow(_))
@@ -786,6 +802,15 @@ mod tests {
         assert!(NetworkMode::Allow(vec![]).requires_cap_net_admin());
     }
 
+    #[test]
+    fn session_dns_only_for_bridged_modes() {
+        assert!(!NetworkMode::Host.supports_session_dns());
+        assert!(!NetworkMode::None.supports_session_dns());
+        assert!(NetworkMode::Bridge.supports_session_dns());
+        assert!(NetworkMode::Allow(vec![]).supports_session_dns());
+        assert_eq!(session_hostname("api"), "api.mino");
+    }
+
     // ---- shell_escape tests ----
 
     #[test]
@@ -0,0 +1,1154 @@
+//! Docker container runtime
+//!
+//! Implements the ContainerRuntime trait on top of the `docker` CLI, for
+//! machines that already run Docker Desktop or dockerd. Works on both macOS
+//! and Linux; no VM management or rootless setup is performed by mino.
+
+use crate::error::{MinoError, MinoResult};
+use crate::orchestration::executor::{CommandExecutor, SystemExecutor};
+use crate::orchestration::podman::{ContainerConfig, PodSpec, PullPolicy};
+use crate::orchestration::runtime::{ContainerRuntime, ContainerUsage, VolumeInfo};
+use crate::redact::redact_args;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::path::Path;
+use std::process::Stdio;
+use std::sync::Arc;
+use tokio::process::Command;
+use tracing::{debug, warn};
+
+/// Container runtime using the Docker CLI
+pub struct DockerRuntime {
+    /// Runs the `docker` commands
+    executor: Arc<dyn CommandExecutor>,
+}
+
+impl DockerRuntime {
+    /// Create a new Docker runtime
+    pub fn new() -> Self {
+        Self::with_executor(Arc::new(SystemExecutor))
+    }
+
+    /// Create a runtime whose commands go through `executor`
+    pub fn with_executor(executor: Arc<dyn CommandExecutor>) -> Self {
+        Self { executor }
+    }
+
+    /// Check if the Docker CLI is installed
+    async fn docker_installed(&self) -> bool {
+        self.executor
+            .output("docker", &["--version"])
+            .await
+            .map(|o| o.status.success())
+            .unwrap_or(false)
+    }
+
+    /// Check if the Docker daemon answers
+    async fn daemon_running(&self) -> bool {
+        self.executor
+            .output("docker", &["info", "--format", "{{.ServerVersion}}"])
+            .await
+            .map(|o| o.status.success())
+            .unwrap_or(false)
+    }
+
+    /// Execute a Docker command and return the output
+    async fn exec(&self, args: &[&str]) -> MinoResult<std::process::Output> {
+        debug!("Executing: docker {:?}", redact_args(args));
+
+        self.executor
+            .output("docker", args)
+            .await
+            .map_err(|e| MinoError::command_failed(format!("docker {:?}", redact_args(args)), e))
+    }
+
+    /// Execute a Docker command interactively
+    async fn exec_interactive(&self, args: &[&str]) -> MinoResult<i32> {
+        debug!("Executing interactively: docker {:?}", redact_args(args));
+
+        self.executor
+            .interactive("docker", args)
+            .await
+            .map_err(|e| MinoError::command_failed(format!("docker {:?}", redact_args(args)), e))
+    }
+
+    /// Pull an image, retrying interrupted downloads.
+    ///
+    /// Docker has no native pull retry, so every attempt is driven from here.
+    async fn pull(&self, image: &str) -> MinoResult<()> {
+        debug!("Pulling image: {}", image);
+
+        super::pull_with_retry(image, super::PULL_ATTEMPTS, || async move {
+            let output = self.exec(&["pull", image]).await?;
+
+            if output.status.success() {
+                Ok(())
+            } else {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                Err(MinoError::ImagePull {
+                    image: image.to_string(),
+                    reason: stderr.to_string(),
+                })
+            }
+        })
+        .await
+    }
+
+    /// Create (or run, with `-d`) a container and return its ID.
+    async fn create_container(
+        &self,
+        base: &[&str],
+        config: &ContainerConfig,
+        command: &[String],
+    ) -> MinoResult<String> {
+        check_userns(config)?;
+        if !self.image_exists(&config.image).await? {
+            self.pull(&config.image).await?;
+        }
+
+        let mut args: Vec<String> = base.iter().map(|a| a.to_string()).collect();
+        if config.interactive {
+            args.push("-i".to_string());
+        }
+        if config.tty {
+            args.push("-t".to_string());
+        }
+        if config.gpus {
+            args.push("--gpus".to_string());
+            args.push("all".to_string());
+        }
+        docker_config(config).push_args(&mut args, command);
+
+        debug!("Creating container: docker {:?}", redact_args(&args));
+
+        let args_refs: Vec<&str> = args.iter().map(String::as_str).collect();
+        let output = self.exec(&args_refs).await?;
+
+        if output.status.success() {
+            let container_id = String::from_utf8_lossy(&output.stdout).trim().to_string();
+            debug!(
+                "Container created: {}",
+                &container_id[..12.min(container_id.len())]
+            );
+            Ok(container_id)
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::ContainerStart(stderr.to_string()))
+        }
+    }
+}
+
+impl Default for DockerRuntime {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// `docker build` arguments tagging the image built from `context` as `tag`.
+///
+/// Docker only knows "always pull" (`--pull`); without it a missing base
+/// image is pulled, so `never` is enforced before building. `plain_progress`
+/// gives one line per build step instead of BuildKit's TTY redraws.
+fn docker_build_args(
+    tag: &str,
+    context: &str,
+    pull: PullPolicy,
+    plain_progress: bool,
+) -> Vec<String> {
+    let mut args = vec!["build".to_string()];
+    if plain_progress {
+        args.extend(["--progress".to_string(), "plain".to_string()]);
+    }
+    if pull == PullPolicy::Always {
+        args.push("--pull".to_string());
+    }
+    args.extend(["-t".to_string(), tag.to_string(), context.to_string()]);
+    args
+}
+
+/// Adapt a container config to Docker.
+///
+/// Docker has no equivalent of Podman's `U` volume option (chown the volume
+/// to the container user), so it is dropped from volume specs. GPUs are
+/// requested with `--gpus` rather than a CDI device.
+fn docker_config(config: &ContainerConfig) -> ContainerConfig {
+    let mut config = config.clone();
+    config.gpus = false;
+    config.volumes = config
+        .volumes
+        .iter()
+        .map(|v| strip_chown_option(v))
+        .collect();
+    config
+}
+
+/// Reject user namespace modes Docker lacks: only `host` is supported, and
+/// `keep-id` style uid mapping is Podman-only.
+fn check_userns(config: &ContainerConfig) -> MinoResult<()> {
+    match config.userns.as_deref() {
+        None | Some("host") => Ok(()),
+        Some(mode) => Err(MinoError::BackendUnsupported {
+            runtime: "Docker".to_string(),
+            feature: format!("User namespace mode '{}'", mode),
+        }),
+    }
+}
+
+/// Remove the `U` option from a `source:target[:opts]` volume spec.
+fn strip_chown_option(spec: &str) -> String {
+    let parts: Vec<&str> = spec.splitn(3, ':').collect();
+    let [source, target, opts] = parts[..] else {
+        return spec.to_string();
+    };
+
+    let kept: Vec<&str> = opts.split(',').filter(|o| *o != "U").collect();
+    if kept.is_empty() {
+        format!("{source}:{target}")
+    } else {
+        format!("{source}:{target}:{}", kept.join(","))
+    }
+}
+
+/// Whether Docker's stderr reports a missing object ("No such container",
+/// "no such volume", "No such image").
+fn is_not_found(stderr: &str) -> bool {
+    stderr.to_lowercase().contains("no such")
+}
+
+/// Parse `docker system df -v --format json` into volume name -> bytes,
+/// keeping only the volumes named in `names`.
+fn parse_system_df_volumes(stdout: &str, names: &[String]) -> MinoResult<HashMap<String, u64>> {
+    if stdout.trim().is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let df: serde_json::Value = serde_json::from_str(stdout)?;
+    let volumes = df["Volumes"].as_array().cloned().unwrap_or_default();
+
+    Ok(volumes
+        .iter()
+        .filter_map(|vol| {
+            let name = vol["Name"].as_str()?;
+            if !names.iter().any(|n| n == name) {
+                return None;
+            }
+            let size = match &vol["Size"] {
+                serde_json::Value::Number(n) => n.as_u64(),
+                serde_json::Value::String(s) => parse_human_size(s),
+                _ => None,
+            }?;
+            Some((name.to_string(), size))
+        })
+        .collect())
+}
+
+/// Parse Docker's human-readable sizes ("0B", "512kB", "1.2GB").
+///
+/// Docker formats sizes with decimal (SI) units.
+fn parse_human_size(s: &str) -> Option<u64> {
+    let s = s.trim();
+    let split = s
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(s.len());
+    let (number, unit) = s.split_at(split);
+    let number: f64 = number.parse().ok()?;
+
+    let multiplier: f64 = match unit.trim().to_uppercase().as_str() {
+        "" | "B" => 1.0,
+        "KB" => 1e3,
+        "MB" => 1e6,
+        "GB" => 1e9,
+        "TB" => 1e12,
+        _ => return None,
+    };
+
+    Some((number * multiplier).round() as u64)
+}
+
+#[async_trait]
+impl ContainerRuntime for DockerRuntime {
+    async fn is_available(&self) -> MinoResult<bool> {
+        Ok(self.docker_installed().await && self.daemon_running().await)
+    }
+
+    async fn ensure_ready(&self) -> MinoResult<()> {
+        if !self.docker_installed().await {
+            return Err(MinoError::DockerNotFound);
+        }
+        if !self.daemon_running().await {
+            return Err(MinoError::DockerNotRunning);
+        }
+        Ok(())
+    }
+
+    async fn check_device(&self, path: &str) -> MinoResult<()> {
+        super::device_check(Path::new(path).exists(), path, "this host")
+    }
+
+    async fn check_gpu_support(&self) -> MinoResult<()> {
+        let output = self.exec(&["info", "--format", "{{json .}}"]).await?;
+        let info = String::from_utf8_lossy(&output.stdout);
+
+        if output.status.success() && info.contains("nvidia") {
+            Ok(())
+        } else {
+            Err(MinoError::GpuUnsupported {
+                runtime: self.runtime_name().to_string(),
+                reason: "the daemon has no NVIDIA runtime or CDI devices".to_string(),
+            })
+        }
+    }
+
+    async fn run(&self, config: &ContainerConfig, command: &[String]) -> MinoResult<String> {
+        self.create_container(&["run", "-d"], config, command).await
+    }
+
+    async fn create(&self, config: &ContainerConfig, command: &[String]) -> MinoResult<String> {
+        self.create_container(&["create"], config, command).await
+    }
+
+    async fn start_attached(&self, container_id: &str) -> MinoResult<i32> {
+        debug!("Starting container attached: {}", container_id);
+        // Unlike Podman, Docker only forwards stdin with --interactive
+        self.exec_interactive(&["start", "--attach", "--interactive", container_id])
+            .await
+    }
+
+    async fn stop(&self, container_id: &str) -> MinoResult<()> {
+        debug!("Stopping container: {}", container_id);
+
+        let output = self.exec(&["stop", container_id]).await?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec("docker stop", stderr))
+        }
+    }
+
+    async fn kill(&self, container_id: &str) -> MinoResult<()> {
+        debug!("Killing container: {}", container_id);
+
+        let output = self.exec(&["kill", container_id]).await?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec("docker kill", stderr))
+        }
+    }
+
+    async fn signal(&self, container_id: &str, signal: &str) -> MinoResult<()> {
+        debug!("Sending {} to container: {}", signal, container_id);
+
+        let output = self
+            .exec(&["kill", "--signal", signal, container_id])
+            .await?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec("docker kill", stderr))
+        }
+    }
+
+    async fn pause(&self, container_id: &str) -> MinoResult<()> {
+        debug!("Pausing container: {}", container_id);
+
+        let output = self.exec(&["pause", container_id]).await?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec("docker pause", stderr))
+        }
+    }
+
+    async fn unpause(&self, container_id: &str) -> MinoResult<()> {
+        debug!("Unpausing container: {}", container_id);
+
+        let output = self.exec(&["unpause", container_id]).await?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec("docker unpause", stderr))
+        }
+    }
+
+    async fn checkpoint(
+        &self,
+        _container_id: &str,
+        _archive: &Path,
+        _leave_running: bool,
+    ) -> MinoResult<()> {
+        Err(MinoError::CheckpointUnsupported {
+            runtime: self.runtime_name().to_string(),
+            reason: "Docker cannot export checkpoints to an archive".to_string(),
+        })
+    }
+
+    async fn restore(&self, _archive: &Path) -> MinoResult<String> {
+        Err(MinoError::CheckpointUnsupported {
+            runtime: self.runtime_name().to_string(),
+            reason: "Docker cannot import checkpoint archives".to_string(),
+        })
+    }
+
+    async fn remove(&self, container_id: &str) -> MinoResult<()> {
+        debug!("Removing container: {}", container_id);
+
+        let output = self.exec(&["rm", "-f", container_id]).await?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            if is_not_found(&stderr) {
+                Ok(())
+            } else {
+                Err(MinoError::command_exec("docker rm", stderr))
+            }
+        }
+    }
+
+    async fn container_prune(&self) -> MinoResult<()> {
+        let output = self.exec(&["container", "prune", "-f"]).await?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(MinoError::command_exec("docker container prune", stderr));
+        }
+        Ok(())
+    }
+
+    async fn container_list_labeled(&self, label: &str) -> MinoResult<Vec<String>> {
+        let filter = format!("label={}", label);
+        let output = self
+            .exec(&["ps", "-a", "--filter", &filter, "--format", "{{.ID}}"])
+            .await?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(MinoError::command_exec("docker ps", stderr));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        Ok(stdout
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(String::from)
+            .collect())
+    }
+
+    async fn logs(&self, container_id: &str, lines: u32) -> MinoResult<String> {
+        let tail_arg = if lines == 0 {
+            "all".to_string()
+        } else {
+            lines.to_string()
+        };
+
+        let output = self
+            .exec(&["logs", "--tail", &tail_arg, container_id])
+            .await?;
+
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    }
+
+    async fn logs_follow(&self, container_id: &str) -> MinoResult<()> {
+        self.exec_interactive(&["logs", "-f", container_id]).await?;
+        Ok(())
+    }
+
+    async fn logs_follow_lines(
+        &self,
+        container_id: &str,
+        lines: u32,
+        on_line: &(dyn Fn(String) + Send + Sync),
+    ) -> MinoResult<()> {
+        let tail_arg = if lines == 0 {
+            "all".to_string()
+        } else {
+            lines.to_string()
+        };
+
+        let mut child = Command::new("docker")
+            .args(["logs", "-f", "--tail", &tail_arg, container_id])
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| MinoError::command_failed("docker logs -f", e))?;
+
+        super::forward_child_lines(&mut child, on_line).await;
+        Ok(())
+    }
+
+    async fn image_exists(&self, image: &str) -> MinoResult<bool> {
+        // Docker has no `image exists`; inspect fails for unknown images
+        let output = self
+            .exec(&["image", "inspect", "--format", "{{.Id}}", image])
+            .await?;
+        Ok(output.status.success())
+    }
+
+    async fn image_pull(&self, image: &str) -> MinoResult<()> {
+        self.pull(image).await
+    }
+
+    async fn image_pull_with_progress(
+        &self,
+        image: &str,
+        platform: Option<&str>,
+        on_output: &(dyn Fn(String) + Send + Sync),
+    ) -> MinoResult<()> {
+        let mut args = vec!["pull"];
+        if let Some(platform) = platform {
+            args.extend(["--platform", platform]);
+        }
+        args.push(image);
+        let args = &args;
+
+        // Docker has no native pull retry, so every attempt is driven from here
+        super::pull_with_retry(image, super::PULL_ATTEMPTS, || async move {
+            let mut child = Command::new("docker")
+                .args(args)
+                .stdout(Stdio::piped())
+                .stderr(Stdio::piped())
+                .spawn()
+                .map_err(|e| MinoError::command_failed("docker pull", e))?;
+            super::stream_pull(&mut child, image, on_output).await
+        })
+        .await
+    }
+
+    async fn image_platform(&self, image: &str) -> MinoResult<Option<String>> {
+        let output = self
+            .exec(&[
+                "image",
+                "inspect",
+                "--format",
+                super::IMAGE_PLATFORM_FORMAT,
+                image,
+            ])
+            .await?;
+        if !output.status.success() {
+            return Ok(None);
+        }
+        Ok(super::parse_image_platform(&String::from_utf8_lossy(
+            &output.stdout,
+        )))
+    }
+
+    async fn image_user(&self, image: &str) -> MinoResult<Option<String>> {
+        if !self.image_exists(image).await? {
+            self.pull(image).await?;
+        }
+
+        let output = self
+            .exec(&["image", "inspect", "--format", "{{.Config.User}}", image])
+            .await?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(MinoError::command_exec("docker image inspect", stderr));
+        }
+
+        let user = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        Ok(if user.is_empty() { None } else { Some(user) })
+    }
+
+    async fn build_image(&self, context_dir: &Path, tag: &str, pull: PullPolicy) -> MinoResult<()> {
+        let args = docker_build_args(tag, &context_dir.display().to_string(), pull, false);
+        let args: Vec<&str> = args.iter().map(String::as_str).collect();
+        let output = self.exec(&args).await?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            let combined = super::build_error_output(&stdout, &stderr);
+            return Err(MinoError::ImageBuild {
+                tag: tag.to_string(),
+                reason: combined,
+            });
+        }
+
+        Ok(())
+    }
+
+    async fn build_image_with_progress(
+        &self,
+        context_dir: &Path,
+        tag: &str,
+        pull: PullPolicy,
+        on_output: &(dyn Fn(String) + Send + Sync),
+    ) -> MinoResult<()> {
+        let mut child = Command::new("docker")
+            .args(docker_build_args(
+                tag,
+                &context_dir.display().to_string(),
+                pull,
+                true,
+            ))
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| MinoError::command_failed("docker build", e))?;
+
+        let all_output = super::stream_child_output(&mut child, on_output).await;
+
+        let status = child
+            .wait()
+            .await
+            .map_err(|e| MinoError::command_failed("docker build", e))?;
+
+        if !status.success() {
+            let combined = all_output.join("\n");
+            let tail = super::build_error_output(&combined, "");
+            return Err(MinoError::ImageBuild {
+                tag: tag.to_string(),
+                reason: tail,
+            });
+        }
+
+        Ok(())
+    }
+
+    async fn image_remove(&self, image: &str) -> MinoResult<()> {
+        let output = self.exec(&["rmi", image]).await?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            if is_not_found(&stderr) {
+                Ok(())
+            } else {
+                Err(MinoError::command_exec("docker rmi", stderr))
+            }
+        }
+    }
+
+    async fn image_list_prefixed(&self, prefix: &str) -> MinoResult<Vec<String>> {
+        let filter = format!("reference={}*", prefix);
+        let output = self
+            .exec(&[
+                "images",
+                "--filter",
+                &filter,
+                "--format",
+                "{{.Repository}}:{{.Tag}}",
+            ])
+            .await?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(MinoError::command_exec("docker images", stderr));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let images: Vec<String> = stdout
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(String::from)
+            .collect();
+
+        Ok(images)
+    }
+
+    async fn image_list_labeled(&self, label: &str) -> MinoResult<Vec<String>> {
+        let filter = format!("label={}", label);
+        let output = self
+            .exec(&[
+                "images",
+                "--filter",
+                &filter,
+                "--format",
+                "{{.Repository}}:{{.Tag}}",
+            ])
+            .await?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(MinoError::command_exec("docker images", stderr));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let images: Vec<String> = stdout
+            .lines()
+            // Untagged images carrying the label show up as <none>:<none>
+            .filter(|line| !line.is_empty() && !line.contains("<none>"))
+            .map(String::from)
+            .collect();
+
+        Ok(images)
+    }
+
+    fn runtime_name(&self) -> &'static str {
+        "Docker"
+    }
+
+    async fn volume_create(&self, name: &str, labels: &HashMap<String, String>) -> MinoResult<()> {
+        debug!("Creating volume: {}", name);
+
+        // `docker volume create` is already a no-op for an existing volume
+        let mut args = vec!["volume", "create"];
+
+        let label_strings: Vec<String> =
+            labels.iter().map(|(k, v)| format!("{}={}", k, v)).collect();
+
+        for label in &label_strings {
+            args.push("--label");
+            args.push(label);
+        }
+
+        args.push(name);
+
+        let output = self.exec(&args).await?;
+
+        if output.status.success() {
+            debug!("Volume created: {}", name);
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec("docker volume create", stderr))
+        }
+    }
+
+    async fn network_create(&self, name: &str) -> MinoResult<()> {
+        debug!("Creating network: {}", name);
+
+        let output = self.exec(&["network", "create", name]).await?;
+
+        // Unlike volumes, `docker network create` fails for an existing network
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if output.status.success() || stderr.contains("already exists") {
+            Ok(())
+        } else {
+            Err(MinoError::command_exec("docker network create", stderr))
+        }
+    }
+
+    async fn volume_remove(&self, name: &str) -> MinoResult<()> {
+        debug!("Removing volume: {}", name);
+
+        let output = self.exec(&["volume", "rm", "-f", name]).await?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            if is_not_found(&stderr) {
+                Ok(())
+            } else {
+                Err(MinoError::command_exec("docker volume rm", stderr))
+            }
+        }
+    }
+
+    async fn volume_list(&self, label: &str) -> MinoResult<Vec<VolumeInfo>> {
+        // `docker volume ls --format json` emits labels as a flat string, so
+        // list names and inspect the matches to get the labels map.
+        let filter = format!("label={}", label);
+        let output = self
+            .exec(&["volume", "ls", "--quiet", "--filter", &filter])
+            .await?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(MinoError::command_exec("docker volume ls", stderr));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let names: Vec<&str> = stdout
+            .lines()
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .collect();
+        if names.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut args = vec!["volume", "inspect"];
+        args.extend(&names);
+        let output = self.exec(&args).await?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(MinoError::command_exec("docker volume inspect", stderr));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        super::parse_volume_list_json(&stdout, label)
+    }
+
+    async fn volume_inspect(&self, name: &str) -> MinoResult<Option<VolumeInfo>> {
+        let output = self.exec(&["volume", "inspect", name]).await?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            if is_not_found(&stderr) {
+                return Ok(None);
+            }
+            return Err(MinoError::command_exec("docker volume inspect", stderr));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        super::parse_volume_inspect_json(&stdout, name)
+    }
+
+    async fn volume_export(&self, _name: &str, _archive: &Path) -> MinoResult<()> {
+        Err(MinoError::BackendUnsupported {
+            runtime: self.runtime_name().to_string(),
+            feature: "Volume archives (cache.remote)".to_string(),
+        })
+    }
+
+    fn supports_volume_archives(&self) -> bool {
+        false
+    }
+
+    async fn volume_import(&self, _name: &str, _archive: &Path) -> MinoResult<()> {
+        Err(MinoError::BackendUnsupported {
+            runtime: self.runtime_name().to_string(),
+            feature: "Volume archives (cache.remote)".to_string(),
+        })
+    }
+
+    async fn volume_disk_usage(&self, label: &str) -> MinoResult<HashMap<String, u64>> {
+        // `system df` does not filter by label, so resolve the names first
+        let names: Vec<String> = self
+            .volume_list(label)
+            .await?
+            .into_iter()
+            .map(|vol| vol.name)
+            .collect();
+        if names.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        // Volume mountpoints may live inside Docker Desktop's VM, so ask the
+        // daemon for sizes instead of running `du` on the host.
+        let output = self
+            .exec(&["system", "df", "--verbose", "--format", "json"])
+            .await?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(MinoError::command_exec("docker system df", stderr));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        parse_system_df_volumes(&stdout, &names)
+    }
+
+    async fn exec_in_container(
+        &self,
+        container_id: &str,
+        command: &[String],
+        tty: bool,
+    ) -> MinoResult<i32> {
+        debug!("Exec into container: {}", container_id);
+        let mut args = vec!["exec", "-i"];
+        if tty {
+            args.push("-t");
+        }
+        args.push(container_id);
+        args.extend(command.iter().map(String::as_str));
+        self.exec_interactive(&args).await
+    }
+
+    async fn copy_to_container(
+        &self,
+        container_id: &str,
+        host_path: &Path,
+        container_path: &str,
+    ) -> MinoResult<()> {
+        debug!("Copying {} into {}", host_path.display(), container_id);
+        let source = host_path.display().to_string();
+        let target = format!("{}:{}", container_id, container_path);
+        let output = self.exec(&["cp", &source, &target]).await?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec("docker cp", stderr))
+        }
+    }
+
+    async fn copy_from_container(
+        &self,
+        container_id: &str,
+        container_path: &str,
+        host_path: &Path,
+    ) -> MinoResult<()> {
+        debug!("Copying {} out of {}", container_path, container_id);
+        let source = format!("{}:{}", container_id, container_path);
+        let target = host_path.display().to_string();
+        let output = self.exec(&["cp", &source, &target]).await?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec("docker cp", stderr))
+        }
+    }
+
+    async fn get_container_exit_code(&self, container_id: &str) -> MinoResult<Option<i32>> {
+        debug!("Waiting for container exit: {}", container_id);
+
+        let output = self.exec(&["wait", container_id]).await?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            if is_not_found(&stderr) {
+                return Ok(None);
+            }
+            return Err(MinoError::command_exec("docker wait", stderr));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        match stdout.trim().parse::<i32>() {
+            Ok(code) => Ok(Some(code)),
+            Err(_) => {
+                warn!(
+                    "Could not parse exit code from docker wait: {:?}",
+                    stdout.trim()
+                );
+                Ok(None)
+            }
+        }
+    }
+
+    async fn start_detached(&self, container_id: &str) -> MinoResult<()> {
+        debug!("Starting container detached: {}", container_id);
+        let output = self.exec(&["start", container_id]).await?;
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::ContainerStart(stderr.to_string()))
+        }
+    }
+
+    async fn container_restart_count(&self, container_id: &str) -> MinoResult<Option<u32>> {
+        let output = self
+            .exec(&["inspect", "--format", "{{.RestartCount}}", container_id])
+            .await?;
+        super::parse_restart_count(&output, "docker inspect")
+    }
+
+    async fn container_is_running(&self, container_id: &str) -> MinoResult<bool> {
+        let output = self
+            .exec(&["inspect", "--format", "{{.State.Running}}", container_id])
+            .await?;
+        super::parse_running(&output, "docker inspect")
+    }
+
+    async fn attach(&self, container_id: &str) -> MinoResult<i32> {
+        debug!("Attaching to container: {}", container_id);
+        self.exec_interactive(&["attach", container_id]).await
+    }
+
+    async fn registry_login(
+        &self,
+        registry: &str,
+        username: Option<&str>,
+        password_stdin: bool,
+    ) -> MinoResult<()> {
+        debug!("Logging in to registry: {}", registry);
+        let args = super::registry_login_args(registry, username, password_stdin);
+        let args_refs: Vec<&str> = args.iter().map(String::as_str).collect();
+        let code = self.exec_interactive(&args_refs).await?;
+        super::registry_command_result("docker login", registry, code)
+    }
+
+    async fn registry_login_password(
+        &self,
+        registry: &str,
+        username: &str,
+        password: &str,
+    ) -> MinoResult<()> {
+        debug!("Logging in to registry: {}", registry);
+        let args = super::registry_login_args(registry, Some(username), true);
+        let args_refs: Vec<&str> = args.iter().map(String::as_str).collect();
+        let output = self
+            .executor
+            .output_with_input("docker", &args_refs, password.as_bytes())
+            .await
+            .map_err(|e| MinoError::command_failed("docker login", e))?;
+        super::registry_output_result("docker login", &output)
+    }
+
+    async fn registry_logout(&self, registry: &str) -> MinoResult<()> {
+        debug!("Logging out of registry: {}", registry);
+        let code = self.exec_interactive(&["logout", registry]).await?;
+        super::registry_command_result("docker logout", registry, code)
+    }
+
+    async fn pod_create(&self, _spec: &PodSpec) -> MinoResult<()> {
+        Err(MinoError::BackendUnsupported {
+            runtime: self.runtime_name().to_string(),
+            feature: "Service containers (Podman pods)".to_string(),
+        })
+    }
+
+    async fn pod_remove(&self, _name: &str) -> MinoResult<()> {
+        Err(MinoError::BackendUnsupported {
+            runtime: self.runtime_name().to_string(),
+            feature: "Service containers (Podman pods)".to_string(),
+        })
+    }
+
+    async fn container_usage(&self, _container_id: &str) -> MinoResult<ContainerUsage> {
+        // `docker stats` only prints rounded, human-readable figures and
+        // no CPU time
+        Err(MinoError::BackendUnsupported {
+            runtime: self.runtime_name().to_string(),
+            feature: "Usage sampling".to_string(),
+        })
+    }
+
+    async fn logs_follow_until(
+        &self,
+        container_id: &str,
+        marker: &str,
+        timeout: std::time::Duration,
+        on_line: &(dyn Fn(String) + Send + Sync),
+    ) -> MinoResult<bool> {
+        debug!("Following logs for {} until '{}'", container_id, marker);
+
+        let mut child = Command::new("docker")
+            .args(["logs", "-f", container_id])
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| MinoError::command_failed("docker logs -f", e))?;
+
+        Ok(super::follow_until_marker(&mut child, marker, timeout, on_line).await)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn docker_runtime_name() {
+        assert_eq!(DockerRuntime::new().runtime_name(), "Docker");
+    }
+
+    #[test]
+    fn docker_build_args_pull_only_for_always() {
+        assert_eq!(
+            docker_build_args("t", "/c", PullPolicy::Always, true),
+            vec!["build", "--progress", "plain", "--pull", "-t", "t", "/c"]
+        );
+        for pull in [PullPolicy::Missing, PullPolicy::Never] {
+            assert_eq!(
+                docker_build_args("t", "/c", pull, false),
+                vec!["build", "-t", "t", "/c"]
+            );
+        }
+    }
+
+    #[test]
+    fn strip_chown_option_variants() {
+        assert_eq!(strip_chown_option("vol:/cache:U"), "vol:/cache");
+        assert_eq!(strip_chown_option("vol:/cache:ro,U"), "vol:/cache:ro");
+        assert_eq!(strip_chown_option("/src:/workspace"), "/src:/workspace");
+        assert_eq!(strip_chown_option("/etc/a:/etc/a:ro"), "/etc/a:/etc/a:ro");
+    }
+
+    #[test]
+    fn docker_config_keeps_everything_but_chown() {
+        let config = ContainerConfig {
+            image: "fedora:43".to_string(),
+            workdir: "/workspace".to_string(),
+            volumes: vec!["/src:/workspace".to_string(), "c:/cache:U".to_string()],
+            env: HashMap::new(),
+            network: "bridge".to_string(),
+            network_aliases: vec![],
+            interactive: true,
+            tty: true,
+            cap_add: vec![],
+            cap_drop: vec!["ALL".to_string()],
+            security_opt: vec![],
+            pids_limit: 0,
+            ulimits: vec![],
+            cpus: 0.0,
+            memory_mb: 0,
+            gpus: false,
+            devices: vec![],
+            auto_remove: false,
+            read_only: false,
+            tmpfs: vec![],
+            user: Some("developer".to_string()),
+            userns: None,
+            restart: None,
+            labels: HashMap::new(),
+            pod: None,
+            platform: None,
+            hostname: None,
+            extra_hosts: vec![],
+        };
+
+        let adapted = docker_config(&config);
+        assert_eq!(adapted.volumes, vec!["/src:/workspace", "c:/cache"]);
+        assert_eq!(adapted.user.as_deref(), Some("developer"));
+        assert_eq!(adapted.cap_drop, config.cap_drop);
+
+        let mut config = config;
+        assert!(check_userns(&config).is_ok());
+        config.userns = Some("host".to_string());
+        assert!(check_userns(&config).is_ok());
+        config.userns = Some("keep-id".to_string());
+        assert!(matches!(
+            check_userns(&config),
+            Err(MinoError::BackendUnsupported { .. })
+        ));
+    }
+
+    #[test]
+    fn is_not_found_matches_docker_messages() {
+        assert!(is_not_found(
+            "Error response from daemon: No such container: abc"
+        ));
+        assert!(is_not_found("Error: get vol: no such volume"));
+        assert!(!is_not_found("permission denied"));
+    }
+
+    #[test]
+    fn parse_human_size_units() {
+        assert_eq!(parse_human_size("0B"), Some(0));
+        assert_eq!(parse_human_size("512kB"), Some(512_000));
+        assert_eq!(parse_human_size("1.5GB"), Some(1_500_000_000));
+        assert_eq!(parse_human_size("N/A"), None);
+    }
+
+    #[test]
+    fn parse_system_df_keeps_named_volumes() {
+        let json = r#"{
+            "Images": [],
+            "Volumes": [
+                {"Name": "mino-cache-npm-abc", "Size": "12MB"},
+                {"Name": "other", "Size": "1GB"},
+                {"Name": "mino-cache-cargo-def", "Size": "N/A"}
+            ]
+        }"#;
+
+        let names = vec![
+            "mino-cache-npm-abc".to_string(),
+            "mino-cache-cargo-def".to_string(),
+        ];
+        let sizes = parse_system_df_volumes(json, &names).unwrap();
+        assert_eq!(sizes.len(), 1);
+        assert_eq!(sizes["mino-cache-npm-abc"], 12_000_000);
+        assert!(parse_system_df_volumes("", &names).unwrap().is_empty());
+    }
+}
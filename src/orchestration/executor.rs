@@ -0,0 +1,101 @@
+//! Command execution seam for CLI-driven runtimes
+//!
+//! Runtimes that shell out to `podman` or `docker` run their commands through
+//! a [`CommandExecutor`] instead of `tokio::process::Command`, so tests can
+//! swap in a recording executor and drive the real runtime code without a
+//! container engine. Streaming commands (`logs -f`, builds with progress)
+//! still spawn processes directly, since they need a live child to read from.
+
+use async_trait::async_trait;
+use std::process::{Output, Stdio};
+use tokio::process::Command;
+
+/// Runs external programs on behalf of a runtime
+#[async_trait]
+pub trait CommandExecutor: Send + Sync {
+    /// Run `program` with `args`, capturing stdout and stderr
+    async fn output(&self, program: &str, args: &[&str]) -> std::io::Result<Output>;
+
+    /// Run `program` with `args` on the inherited terminal and return its
+    /// exit code (-1 when killed by a signal)
+    async fn interactive(&self, program: &str, args: &[&str]) -> std::io::Result<i32>;
+
+    /// Run `program` with `args`, writing `input` to its stdin and
+    /// capturing stdout and stderr
+    async fn output_with_input(
+        &self,
+        program: &str,
+        args: &[&str],
+        input: &[u8],
+    ) -> std::io::Result<Output>;
+}
+
+/// Executor that spawns real processes
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemExecutor;
+
+#[async_trait]
+impl CommandExecutor for SystemExecutor {
+    async fn output(&self, program: &str, args: &[&str]) -> std::io::Result<Output> {
+        Command::new(program)
+            .args(args)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .output()
+            .await
+    }
+
+    async fn interactive(&self, program: &str, args: &[&str]) -> std::io::Result<i32> {
+        let status = Command::new(program)
+            .args(args)
+            .stdin(Stdio::inherit())
+            .stdout(Stdio::inherit())
+            .stderr(Stdio::inherit())
+            .status()
+            .await?;
+        Ok(status.code().unwrap_or(-1))
+    }
+
+    async fn output_with_input(
+        &self,
+        program: &str,
+        args: &[&str],
+        input: &[u8],
+    ) -> std::io::Result<Output> {
+        super::output_with_input(Command::new(program).args(args), input).await
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn system_executor_captures_output() {
+        let output = SystemExecutor
+            .output("sh", &["-c", "echo out; echo err >&2; exit 3"])
+            .await
+            .unwrap();
+        assert_eq!(String::from_utf8_lossy(&output.stdout), "out\n");
+        assert_eq!(String::from_utf8_lossy(&output.stderr), "err\n");
+        assert_eq!(output.status.code(), Some(3));
+    }
+
+    #[tokio::test]
+    async fn system_executor_writes_input() {
+        let output = SystemExecutor
+            .output_with_input("cat", &[], b"secret")
+            .await
+            .unwrap();
+        assert_eq!(String::from_utf8_lossy(&output.stdout), "secret");
+    }
+
+    #[tokio::test]
+    async fn system_executor_missing_program_is_io_error() {
+        let err = SystemExecutor
+            .output("mino-no-such-program", &[])
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::NotFound);
+    }
+}
@@ -5,14 +5,19 @@
 use crate::config::schema::VmConfig;
 use crate::config::Config;
 use crate::error::{MinoError, MinoResult};
-use crate::orchestration::native_podman::NativePodmanRuntime;
+use crate::orchestration::docker::DockerRuntime;
+use crate::orchestration::kubernetes::KubernetesRuntime;
+use crate::orchestration::lima_runtime::LimaRuntime;
 use crate::orchestration::orbstack_runtime::OrbStackRuntime;
+use crate::orchestration::podman_api_runtime::PodmanApiRuntime;
+use crate::orchestration::remote_ssh::RemoteSshRuntime;
 use crate::orchestration::runtime::ContainerRuntime;
+use serde::{Deserialize, Serialize};
 
 /// Detected platform
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Platform {
-    /// macOS - uses OrbStack + Podman
+    /// macOS - uses OrbStack (or Lima) + Podman
     MacOS,
     /// Linux - uses native rootless Podman
     Linux,
@@ -40,6 +45,85 @@ impl Platform {
     }
 }
 
+/// Container engine backing the runtime
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Backend {
+    /// Podman (OrbStack VM on macOS, native rootless on Linux)
+    #[default]
+    Podman,
+    /// An existing Docker daemon (Docker Desktop or dockerd)
+    Docker,
+    /// Podman on a remote Linux host over SSH (`[orchestration.remote]`)
+    Remote,
+    /// Pods on a Kubernetes cluster via kubectl (`[orchestration.kubernetes]`)
+    #[serde(rename = "k8s", alias = "kubernetes")]
+    Kubernetes,
+}
+
+impl std::str::FromStr for Backend {
+    type Err = MinoError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "podman" => Ok(Self::Podman),
+            "docker" => Ok(Self::Docker),
+            "remote" => Ok(Self::Remote),
+            "k8s" | "kubernetes" => Ok(Self::Kubernetes),
+            other => Err(MinoError::User(format!(
+                "Invalid container backend '{}'. Valid backends: podman, docker, remote, k8s",
+                other
+            ))),
+        }
+    }
+}
+
+impl std::fmt::Display for Backend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Podman => write!(f, "podman"),
+            Self::Docker => write!(f, "docker"),
+            Self::Remote => write!(f, "remote"),
+            Self::Kubernetes => write!(f, "k8s"),
+        }
+    }
+}
+
+/// VM manager hosting Podman on macOS
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VmProvider {
+    /// OrbStack (`orb`)
+    #[default]
+    OrbStack,
+    /// Lima (`limactl`), free for commercial use
+    Lima,
+}
+
+impl std::str::FromStr for VmProvider {
+    type Err = MinoError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "orbstack" => Ok(Self::OrbStack),
+            "lima" => Ok(Self::Lima),
+            other => Err(MinoError::User(format!(
+                "Invalid VM provider '{}'. Valid providers: orbstack, lima",
+                other
+            ))),
+        }
+    }
+}
+
+impl std::fmt::Display for VmProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::OrbStack => write!(f, "orbstack"),
+            Self::Lima => write!(f, "lima"),
+        }
+    }
+}
+
 /// Create a container runtime appropriate for the current platform
 ///
 /// # Arguments
@@ -47,14 +131,44 @@ impl Platform {
 ///
 /// # Returns
 /// * `Ok(Box<dyn ContainerRuntime>)` - A boxed runtime implementation
-/// * `Err` - If the platform is unsupported
+/// * `Err` - If the platform is unsupported or the configured backend is invalid
 pub fn create_runtime(config: &Config) -> MinoResult<Box<dyn ContainerRuntime>> {
-    match Platform::detect() {
-        Platform::MacOS => Ok(Box::new(OrbStackRuntime::new(config.vm.clone()))),
-        Platform::Linux => Ok(Box::new(NativePodmanRuntime::new())),
-        Platform::Unsupported => Err(MinoError::UnsupportedPlatform(
+    create_runtime_with_backend(config, None)
+}
+
+/// Create a container runtime for an explicit backend.
+///
+/// `None` falls back to `orchestration.backend` from config. Sessions pass
+/// the backend they were started with so later commands reach the same engine.
+pub fn create_runtime_with_backend(
+    config: &Config,
+    backend: Option<Backend>,
+) -> MinoResult<Box<dyn ContainerRuntime>> {
+    let backend = match backend {
+        Some(backend) => backend,
+        None => config.orchestration.backend.parse()?,
+    };
+
+    match (backend, Platform::detect()) {
+        (_, Platform::Unsupported) => Err(MinoError::UnsupportedPlatform(
             std::env::consts::OS.to_string(),
         )),
+        (Backend::Docker, _) => Ok(Box::new(DockerRuntime::new())),
+        (Backend::Remote, _) => {
+            let remote = &config.orchestration.remote;
+            if remote.host.is_empty() {
+                return Err(MinoError::User(
+                    "The remote backend needs a host: mino config set orchestration.remote.host user@host"
+                        .to_string(),
+                ));
+            }
+            Ok(Box::new(RemoteSshRuntime::new(remote.clone())))
+        }
+        (Backend::Kubernetes, _) => Ok(Box::new(KubernetesRuntime::new(
+            config.orchestration.kubernetes.clone(),
+        ))),
+        (Backend::Podman, Platform::MacOS) => create_vm_runtime(config.vm.clone()),
+        (Backend::Podman, Platform::Linux) => Ok(Box::new(PodmanApiRuntime::new())),
     }
 }
 
@@ -64,14 +178,22 @@ pub fn create_runtime(config: &Config) -> MinoResult<Box<dyn ContainerRuntime>>
 /// VM configuration that may differ from the main config.
 pub fn create_runtime_with_vm(vm_config: VmConfig) -> MinoResult<Box<dyn ContainerRuntime>> {
     match Platform::detect() {
-        Platform::MacOS => Ok(Box::new(OrbStackRuntime::new(vm_config))),
-        Platform::Linux => Ok(Box::new(NativePodmanRuntime::new())),
+        Platform::MacOS => create_vm_runtime(vm_config),
+        Platform::Linux => Ok(Box::new(PodmanApiRuntime::new())),
         Platform::Unsupported => Err(MinoError::UnsupportedPlatform(
             std::env::consts::OS.to_string(),
         )),
     }
 }
 
+/// Create the macOS VM runtime for `vm.provider`
+fn create_vm_runtime(vm_config: VmConfig) -> MinoResult<Box<dyn ContainerRuntime>> {
+    match vm_config.provider.parse()? {
+        VmProvider::OrbStack => Ok(Box::new(OrbStackRuntime::new(vm_config))),
+        VmProvider::Lima => Ok(Box::new(LimaRuntime::new(vm_config))),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -93,6 +215,86 @@ mod tests {
         assert_eq!(Platform::Unsupported.name(), "Unsupported");
     }
 
+    #[test]
+    fn backend_parse_and_display() {
+        assert_eq!("docker".parse::<Backend>().unwrap(), Backend::Docker);
+        assert_eq!("Podman".parse::<Backend>().unwrap(), Backend::Podman);
+        assert_eq!(Backend::Docker.to_string(), "docker");
+        assert_eq!(Backend::default(), Backend::Podman);
+        assert_eq!(
+            "kubernetes".parse::<Backend>().unwrap(),
+            Backend::Kubernetes
+        );
+        assert_eq!(Backend::Kubernetes.to_string(), "k8s");
+        assert_eq!(
+            serde_json::to_string(&Backend::Kubernetes).unwrap(),
+            r#""k8s""#
+        );
+
+        let err = "containerd".parse::<Backend>().unwrap_err();
+        assert!(err.to_string().contains("Invalid container backend"));
+    }
+
+    #[test]
+    fn create_runtime_rejects_invalid_backend() {
+        let mut config = Config::default();
+        config.orchestration.backend = "lxc".to_string();
+        assert!(create_runtime(&config).is_err());
+    }
+
+    #[test]
+    fn create_runtime_with_docker_backend() {
+        let config = Config::default();
+        if Platform::detect() != Platform::Unsupported {
+            let runtime = create_runtime_with_backend(&config, Some(Backend::Docker)).unwrap();
+            assert_eq!(runtime.runtime_name(), "Docker");
+        }
+    }
+
+    #[test]
+    fn vm_provider_parse_and_display() {
+        assert_eq!("lima".parse::<VmProvider>().unwrap(), VmProvider::Lima);
+        assert_eq!(
+            "OrbStack".parse::<VmProvider>().unwrap(),
+            VmProvider::OrbStack
+        );
+        assert_eq!(VmProvider::Lima.to_string(), "lima");
+        assert!("colima".parse::<VmProvider>().is_err());
+    }
+
+    #[test]
+    fn create_vm_runtime_dispatches_on_provider() {
+        let mut vm = VmConfig::default();
+        assert_eq!(
+            create_vm_runtime(vm.clone()).unwrap().runtime_name(),
+            "OrbStack + Podman"
+        );
+
+        vm.provider = "lima".to_string();
+        assert_eq!(
+            create_vm_runtime(vm.clone()).unwrap().runtime_name(),
+            "Lima + Podman"
+        );
+
+        vm.provider = "parallels".to_string();
+        assert!(create_vm_runtime(vm).is_err());
+    }
+
+    #[test]
+    fn create_runtime_remote_requires_host() {
+        let mut config = Config::default();
+        let err = create_runtime_with_backend(&config, Some(Backend::Remote))
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("orchestration.remote.host"));
+
+        config.orchestration.remote.host = "dev@build-box".to_string();
+        if Platform::detect() != Platform::Unsupported {
+            let runtime = create_runtime_with_backend(&config, Some(Backend::Remote)).unwrap();
+            assert_eq!(runtime.runtime_name(), "Remote Podman (SSH)");
+        }
+    }
+
     #[test]
     fn create_runtime_succeeds_on_supported_platform() {
         let config = Config::default();
@@ -0,0 +1,231 @@
+//! kubectl transport for the Kubernetes runtime
+//!
+//! Runs `kubectl` pinned to one context and namespace, so that commands
+//! for a session reach the cluster it was started on.
+
+use crate::error::{MinoError, MinoResult};
+use crate::redact::redact_args;
+use std::path::Path;
+use std::process::Stdio;
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+use tracing::debug;
+
+/// kubectl bound to a context and namespace
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Kubectl {
+    /// kubeconfig context (empty = the current context)
+    context: String,
+    /// Namespace (empty = the context's namespace)
+    namespace: String,
+}
+
+impl Kubectl {
+    /// Create a kubectl transport
+    pub fn new(context: impl Into<String>, namespace: impl Into<String>) -> Self {
+        Self {
+            context: context.into(),
+            namespace: namespace.into(),
+        }
+    }
+
+    /// Context name, or `current` when unpinned (for messages)
+    pub fn context(&self) -> &str {
+        if self.context.is_empty() {
+            "current"
+        } else {
+            &self.context
+        }
+    }
+
+    /// Namespace (empty when unpinned)
+    pub fn namespace(&self) -> &str {
+        &self.namespace
+    }
+
+    /// Check if the kubectl CLI is installed
+    pub async fn is_installed() -> bool {
+        Command::new("kubectl")
+            .args(["version", "--client"])
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .await
+            .map(|s| s.success())
+            .unwrap_or(false)
+    }
+
+    /// `--context`/`--namespace` flags preceding every command
+    fn scope_args(&self) -> Vec<String> {
+        let mut args = Vec::new();
+        if !self.context.is_empty() {
+            args.push(format!("--context={}", self.context));
+        }
+        if !self.namespace.is_empty() {
+            args.push(format!("--namespace={}", self.namespace));
+        }
+        args
+    }
+
+    fn command(&self, args: &[&str]) -> Command {
+        let mut cmd = Command::new("kubectl");
+        cmd.args(self.scope_args()).args(args);
+        cmd
+    }
+
+    /// Execute a kubectl command and return the output
+    pub async fn exec(&self, args: &[&str]) -> MinoResult<std::process::Output> {
+        debug!("Executing: kubectl {:?}", redact_args(args));
+
+        self.command(args)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .output()
+            .await
+            .map_err(|e| MinoError::command_failed(format!("kubectl {:?}", redact_args(args)), e))
+    }
+
+    /// Execute a kubectl command with `input` on stdin (e.g. `apply -f -`)
+    pub async fn exec_with_input(
+        &self,
+        args: &[&str],
+        input: &[u8],
+    ) -> MinoResult<std::process::Output> {
+        debug!("Executing with input: kubectl {:?}", redact_args(args));
+
+        let mut child = self
+            .command(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| {
+                MinoError::command_failed(format!("kubectl {:?}", redact_args(args)), e)
+            })?;
+
+        let mut stdin = child.stdin.take().expect("stdin piped");
+        stdin
+            .write_all(input)
+            .await
+            .map_err(|e| MinoError::io("writing kubectl input", e))?;
+        drop(stdin);
+
+        child
+            .wait_with_output()
+            .await
+            .map_err(|e| MinoError::command_failed(format!("kubectl {:?}", redact_args(args)), e))
+    }
+
+    /// Execute a kubectl command attached to the local terminal
+    pub async fn exec_interactive(&self, args: &[&str]) -> MinoResult<i32> {
+        debug!("Executing interactively: kubectl {:?}", redact_args(args));
+
+        let status = self
+            .command(args)
+            .stdin(Stdio::inherit())
+            .stdout(Stdio::inherit())
+            .stderr(Stdio::inherit())
+            .status()
+            .await
+            .map_err(|e| {
+                MinoError::command_failed(format!("kubectl {:?}", redact_args(args)), e)
+            })?;
+
+        Ok(status.code().unwrap_or(-1))
+    }
+
+    /// Spawn a kubectl command with piped stdout/stderr
+    pub fn spawn_piped(&self, args: &[&str]) -> MinoResult<tokio::process::Child> {
+        debug!("Spawning piped: kubectl {:?}", redact_args(args));
+
+        self.command(args)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| MinoError::command_failed(format!("kubectl {:?}", redact_args(args)), e))
+    }
+
+    /// Stream a local file or directory into `dir` of a pod container.
+    ///
+    /// Uses `tar | kubectl exec tar` rather than `kubectl cp`, whose
+    /// directory semantics vary between kubectl versions. The entry keeps
+    /// its basename: `/src/app` lands at `<dir>/app`.
+    pub async fn push_tar(
+        &self,
+        local: &Path,
+        pod: &str,
+        container: &str,
+        dir: &str,
+    ) -> MinoResult<()> {
+        let parent = local.parent().unwrap_or(Path::new("/"));
+        let name = local.file_name().ok_or_else(|| {
+            MinoError::User(format!("Cannot sync {}: no file name", local.display()))
+        })?;
+        debug!("Streaming {} into {}:{}", local.display(), pod, dir);
+
+        let mut tar = Command::new("tar")
+            .arg("-C")
+            .arg(parent)
+            .arg("-cf")
+            .arg("-")
+            .arg(name)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| MinoError::command_failed("tar", e))?;
+        let tar_out: Stdio = tar
+            .stdout
+            .take()
+            .expect("stdout piped")
+            .try_into()
+            .map_err(|e| MinoError::io("piping tar output", e))?;
+
+        let extract = self
+            .command(&[
+                "exec", "-i", pod, "-c", container, "--", "tar", "-C", dir, "-xf", "-",
+            ])
+            .stdin(tar_out)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .output()
+            .await
+            .map_err(|e| MinoError::command_failed("kubectl exec tar", e))?;
+        let packed = tar
+            .wait_with_output()
+            .await
+            .map_err(|e| MinoError::command_failed("tar", e))?;
+
+        if !packed.status.success() {
+            let stderr = String::from_utf8_lossy(&packed.stderr);
+            return Err(MinoError::command_exec(
+                format!("tar {}", local.display()),
+                stderr,
+            ));
+        }
+        if !extract.status.success() {
+            let stderr = String::from_utf8_lossy(&extract.stderr);
+            return Err(MinoError::command_exec("kubectl exec tar", stderr));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scope_args_pin_context_and_namespace() {
+        assert!(Kubectl::new("", "").scope_args().is_empty());
+        assert_eq!(
+            Kubectl::new("staging", "sandboxes").scope_args(),
+            vec!["--context=staging", "--namespace=sandboxes"]
+        );
+    }
+
+    #[test]
+    fn context_names_current_when_unpinned() {
+        assert_eq!(Kubectl::new("", "").context(), "current");
+        assert_eq!(Kubectl::new("prod", "").context(), "prod");
+    }
+}
@@ -0,0 +1,1695 @@
+//! Kubernetes container runtime
+//!
+//! Implements the ContainerRuntime trait by running each sandbox as a pod
+//! through `kubectl`. Local bind mounts are streamed into emptyDir volumes
+//! while an init container holds the pod, which doubles as the gap between
+//! `create` and `start`. Named volumes become PersistentVolumeClaims, env
+//! vars live in a Secret, and a NetworkPolicy isolates the pod.
+//!
+//! Container IDs have the form `<context>/<namespace>/<pod>` so that later
+//! commands for a session reach the cluster it was started on.
+
+use crate::config::schema::KubernetesConfig;
+use crate::error::{MinoError, MinoResult};
+use crate::orchestration::kubectl::Kubectl;
+use crate::orchestration::podman::{ContainerConfig, PodSpec, PullPolicy};
+use crate::orchestration::runtime::{ContainerRuntime, ContainerUsage, VolumeInfo};
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, HashMap};
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::sync::OnceCell;
+use tracing::{debug, warn};
+
+/// Name of the container running the session command
+const MAIN_CONTAINER: &str = "sandbox";
+
+/// Init container that receives synced mounts and gates the start
+const SYNC_CONTAINER: &str = "mino-sync";
+
+/// Mount point of the sync volumes inside the init container
+const SYNC_ROOT: &str = "/mino-sync";
+
+/// Created by `start` to let the init container finish
+const READY_MARKER: &str = "/mino-sync/.ready";
+
+/// Pod label selecting one sandbox (NetworkPolicy target)
+const POD_LABEL: &str = "io.mino.pod";
+
+/// Label marking every object mino creates
+const MANAGED_BY: &str = "app.kubernetes.io/managed-by";
+
+/// Delay between pod status polls
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Exit code reported when the pod was deleted before its command finished
+const KILLED_EXIT_CODE: i32 = 137;
+
+/// Waiting reasons that will not resolve by themselves
+const FATAL_WAIT_REASONS: &[&str] = &[
+    "ImagePullBackOff",
+    "InvalidImageName",
+    "CreateContainerConfigError",
+    "CreateContainerError",
+    "RunContainerError",
+];
+
+/// Container runtime running sandboxes as Kubernetes pods
+pub struct KubernetesRuntime {
+    config: KubernetesConfig,
+    /// kubectl pinned to the resolved context and namespace for new pods
+    scope: OnceCell<Kubectl>,
+}
+
+/// A sandbox pod, parsed from a container ID
+#[derive(Debug, PartialEq, Eq)]
+struct PodRef {
+    context: String,
+    namespace: String,
+    name: String,
+}
+
+impl PodRef {
+    fn parse(id: &str) -> MinoResult<Self> {
+        let mut parts = id.rsplitn(3, '/');
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some(name), Some(namespace), Some(context)) if !name.is_empty() => Ok(Self {
+                context: context.to_string(),
+                namespace: namespace.to_string(),
+                name: name.to_string(),
+            }),
+            _ => Err(MinoError::ContainerNotFound(id.to_string())),
+        }
+    }
+
+    fn id(&self) -> String {
+        format!("{}/{}/{}", self.context, self.namespace, self.name)
+    }
+
+    fn kubectl(&self) -> Kubectl {
+        Kubectl::new(&self.context, &self.namespace)
+    }
+}
+
+/// How a container volume spec is provided to the pod
+#[derive(Debug, PartialEq, Eq)]
+enum Mount {
+    /// Named volume backed by a PersistentVolumeClaim
+    Claim {
+        claim: String,
+        target: String,
+        read_only: bool,
+    },
+    /// Local file or directory streamed into an emptyDir before start
+    Synced {
+        local: PathBuf,
+        target: String,
+        read_only: bool,
+    },
+    /// Container-only path (anonymous volume)
+    Scratch { target: String },
+}
+
+/// State of one container in a pod
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ContainerState {
+    Waiting { reason: String, message: String },
+    Running,
+    Terminated(i32),
+}
+
+/// The parts of a pod's status the runtime acts on
+#[derive(Debug, PartialEq, Eq)]
+struct PodState {
+    uid: String,
+    sync: ContainerState,
+    main: ContainerState,
+    stdin: bool,
+    tty: bool,
+}
+
+impl PodState {
+    /// Reason of a container stuck in a state it will not leave
+    fn fatal_reason(&self) -> Option<String> {
+        [&self.sync, &self.main]
+            .into_iter()
+            .find_map(|state| match state {
+                ContainerState::Waiting { reason, message }
+                    if FATAL_WAIT_REASONS.contains(&reason.as_str()) =>
+                {
+                    Some(format!("{}: {}", reason, message))
+                }
+                _ => None,
+            })
+    }
+}
+
+impl KubernetesRuntime {
+    /// Create a new Kubernetes runtime
+    pub fn new(config: KubernetesConfig) -> Self {
+        Self {
+            config,
+            scope: OnceCell::new(),
+        }
+    }
+
+    /// kubectl for new pods, resolving an unset context or namespace once
+    /// so the IDs handed out stay valid if the current context changes.
+    async fn scope(&self) -> MinoResult<&Kubectl> {
+        self.scope
+            .get_or_try_init(|| async {
+                let unpinned = Kubectl::new(&self.config.context, "");
+                let context = if self.config.context.is_empty() {
+                    let output = unpinned.exec(&["config", "current-context"]).await?;
+                    if !output.status.success() {
+                        return Err(MinoError::KubernetesUnreachable {
+                            context: unpinned.context().to_string(),
+                            reason: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+                        });
+                    }
+                    String::from_utf8_lossy(&output.stdout).trim().to_string()
+                } else {
+                    self.config.context.clone()
+                };
+
+                let namespace = if self.config.namespace.is_empty() {
+                    let output = Kubectl::new(&context, "")
+                        .exec(&["config", "view", "--minify", "-o", "jsonpath={..namespace}"])
+                        .await?;
+                    let namespace = String::from_utf8_lossy(&output.stdout).trim().to_string();
+                    if namespace.is_empty() {
+                        "default".to_string()
+                    } else {
+                        namespace
+                    }
+                } else {
+                    self.config.namespace.clone()
+                };
+
+                debug!("Kubernetes scope: {}/{}", context, namespace);
+                Ok(Kubectl::new(context, namespace))
+            })
+            .await
+    }
+
+    /// Fetch a pod's state; `None` once the pod is gone
+    async fn pod_state(&self, pod: &PodRef) -> MinoResult<Option<PodState>> {
+        let output = pod
+            .kubectl()
+            .exec(&["get", "pod", &pod.name, "-o", "json"])
+            .await?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            if is_not_found(&stderr) {
+                return Ok(None);
+            }
+            return Err(MinoError::command_exec("kubectl get pod", stderr));
+        }
+
+        let json: Value = serde_json::from_slice(&output.stdout)
+            .map_err(|e| MinoError::Internal(format!("Failed to parse pod JSON: {}", e)))?;
+        Ok(Some(parse_pod_state(&json)))
+    }
+
+    /// Poll until `done` holds, failing on unrecoverable states or after
+    /// `start_timeout_secs`.
+    async fn wait_for(
+        &self,
+        pod: &PodRef,
+        what: &str,
+        done: impl Fn(&PodState) -> bool,
+    ) -> MinoResult<PodState> {
+        let timeout = Duration::from_secs(self.config.start_timeout_secs);
+        let started = tokio::time::Instant::now();
+        loop {
+            let state = self
+                .pod_state(pod)
+                .await?
+                .ok_or_else(|| MinoError::ContainerNotFound(pod.id()))?;
+            if let Some(reason) = state.fatal_reason() {
+                return Err(MinoError::ContainerStart(format!(
+                    "pod {} failed: {}",
+                    pod.name, reason
+                )));
+            }
+            if done(&state) {
+                return Ok(state);
+            }
+            if started.elapsed() >= timeout {
+                return Err(MinoError::ContainerStart(format!(
+                    "pod {} did not {} within {}s. Inspect it with: kubectl describe pod {} -n {}",
+                    pod.name,
+                    what,
+                    timeout.as_secs(),
+                    pod.name,
+                    pod.namespace
+                )));
+            }
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+    }
+
+    /// Create the pod with its Secret and NetworkPolicy, then stream synced
+    /// mounts into it. The command does not start until [`Self::release`].
+    async fn create_pod(&self, config: &ContainerConfig, command: &[String]) -> MinoResult<String> {
+        if config.userns.is_some() {
+            return Err(MinoError::BackendUnsupported {
+                runtime: self.runtime_name().to_string(),
+                feature: "User namespace modes (--userns)".to_string(),
+            });
+        }
+        if !config.devices.is_empty() {
+            return Err(MinoError::BackendUnsupported {
+                runtime: self.runtime_name().to_string(),
+                feature: "Device passthrough (--device)".to_string(),
+            });
+        }
+        if !config.ulimits.is_empty() {
+            return Err(MinoError::BackendUnsupported {
+                runtime: self.runtime_name().to_string(),
+                feature: "Resource limits (--ulimit)".to_string(),
+            });
+        }
+        if config.restart.is_some() {
+            return Err(MinoError::BackendUnsupported {
+                runtime: self.runtime_name().to_string(),
+                feature: "Restart policies (--restart)".to_string(),
+            });
+        }
+        let scope = self.scope().await?;
+        let mounts: Vec<Mount> = plan_mounts(&config.volumes)
+            .into_iter()
+            .filter(|mount| match mount {
+                Mount::Synced { local, .. } => match std::fs::metadata(local) {
+                    Ok(meta) if meta.is_dir() || meta.is_file() => true,
+                    _ => {
+                        warn!(
+                            "Skipping mount {}: only files and directories can be synced into a pod",
+                            local.display()
+                        );
+                        false
+                    }
+                },
+                _ => true,
+            })
+            .collect();
+
+        let name = pod_name(&config.labels);
+        let pod = PodRef {
+            context: scope.context().to_string(),
+            namespace: scope.namespace().to_string(),
+            name: name.clone(),
+        };
+        let manifest = json!({
+            "apiVersion": "v1",
+            "kind": "List",
+            "items": [
+                secret_manifest(&name, &config.env),
+                network_policy_manifest(&name, config.network == "none"),
+                pod_manifest(&name, config, command, &mounts, &self.config.sync_image),
+            ],
+        });
+
+        debug!("Creating pod {} in {}", name, pod.namespace);
+        let output = scope
+            .exec_with_input(&["create", "-f", "-"], manifest.to_string().as_bytes())
+            .await?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(MinoError::ContainerStart(stderr.to_string()));
+        }
+
+        if let Err(e) = self.prepare_pod(&pod, &mounts).await {
+            self.delete(&pod, true).await;
+            return Err(e);
+        }
+        Ok(pod.id())
+    }
+
+    /// Wait for the init container, then sync mounts and attach the
+    /// Secret and NetworkPolicy to the pod for garbage collection.
+    async fn prepare_pod(&self, pod: &PodRef, mounts: &[Mount]) -> MinoResult<()> {
+        let state = self
+            .wait_for(pod, "start", |s| s.sync == ContainerState::Running)
+            .await?;
+
+        let kubectl = pod.kubectl();
+        let owner = json!({
+            "metadata": {
+                "ownerReferences": [{
+                    "apiVersion": "v1",
+                    "kind": "Pod",
+                    "name": &pod.name,
+                    "uid": &state.uid,
+                }],
+            },
+        })
+        .to_string();
+        for kind in ["secret", "networkpolicy"] {
+            let patched = kubectl
+                .exec(&["patch", kind, &pod.name, "--type=merge", "-p", &owner])
+                .await;
+            if !matches!(patched, Ok(ref o) if o.status.success()) {
+                warn!("Could not tie {} {} to its pod", kind, pod.name);
+            }
+        }
+
+        for (index, mount) in mounts.iter().enumerate() {
+            if let Mount::Synced { local, .. } = mount {
+                debug!("Syncing {} into pod {}", local.display(), pod.name);
+                kubectl
+                    .push_tar(
+                        local,
+                        &pod.name,
+                        SYNC_CONTAINER,
+                        &format!("{}/{}", SYNC_ROOT, volume_name(index)),
+                    )
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Let the init container exit so the session command starts
+    async fn release(&self, pod: &PodRef) -> MinoResult<()> {
+        let output = pod
+            .kubectl()
+            .exec(&[
+                "exec",
+                &pod.name,
+                "-c",
+                SYNC_CONTAINER,
+                "--",
+                "touch",
+                READY_MARKER,
+            ])
+            .await?;
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::ContainerStart(stderr.to_string()))
+        }
+    }
+
+    /// Release the pod and wait until its command is running (or done)
+    async fn start_pod(&self, pod: &PodRef) -> MinoResult<PodState> {
+        self.release(pod).await?;
+        self.wait_for(pod, "start", |s| {
+            !matches!(s.main, ContainerState::Waiting { .. })
+        })
+        .await
+    }
+
+    /// Delete the pod with its Secret and NetworkPolicy
+    async fn delete(&self, pod: &PodRef, now: bool) -> Option<String> {
+        let mut args = vec![
+            "delete",
+            "pod,secret,networkpolicy",
+            &pod.name,
+            "--ignore-not-found",
+        ];
+        if now {
+            args.extend(["--grace-period=0", "--force", "--wait=false"]);
+        }
+        match pod.kubectl().exec(&args).await {
+            Ok(output) if output.status.success() => None,
+            Ok(output) => Some(String::from_utf8_lossy(&output.stderr).to_string()),
+            Err(e) => Some(e.to_string()),
+        }
+    }
+}
+
+/// Decide how to provide each `source:target[:opts]` volume spec
+fn plan_mounts(volumes: &[String]) -> Vec<Mount> {
+    volumes
+        .iter()
+        .map(|spec| {
+            let parts: Vec<&str> = spec.splitn(3, ':').collect();
+            let read_only = parts
+                .get(2)
+                .is_some_and(|opts| opts.split(',').any(|o| o == "ro"));
+            match parts[..] {
+                [target] => Mount::Scratch {
+                    target: target.to_string(),
+                },
+                [source, target, ..] if source.starts_with('/') => Mount::Synced {
+                    local: PathBuf::from(source),
+                    target: target.to_string(),
+                    read_only,
+                },
+                [claim, target, ..] => Mount::Claim {
+                    claim: claim.to_string(),
+                    target: target.to_string(),
+                    read_only,
+                },
+                [] => unreachable!("splitn yields at least one part"),
+            }
+        })
+        .collect()
+}
+
+/// Pod volume name for the mount at `index`
+fn volume_name(index: usize) -> String {
+    format!("m{}", index)
+}
+
+/// Pod name derived from the session label, unique per run
+fn pod_name(labels: &HashMap<String, String>) -> String {
+    let suffix = &uuid::Uuid::new_v4().simple().to_string()[..6];
+    let session: String = labels
+        .get(crate::labels::SESSION)
+        .map(String::as_str)
+        .unwrap_or_default()
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .take(40)
+        .collect();
+    let session = session.trim_matches('-');
+    if session.is_empty() {
+        format!("mino-{}", suffix)
+    } else {
+        format!("mino-{}-{}", session, suffix)
+    }
+}
+
+/// Kubernetes label value for a mino label: kept when valid, otherwise
+/// empty (the full value is carried in an annotation).
+fn label_value(value: &str) -> &str {
+    let valid = value.len() <= 63
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        && value.starts_with(|c: char| c.is_ascii_alphanumeric())
+        && value.ends_with(|c: char| c.is_ascii_alphanumeric());
+    if valid {
+        value
+    } else {
+        ""
+    }
+}
+
+/// Labels and annotations for mino's `key=value` labels
+fn object_metadata(name: &str, labels: &HashMap<String, String>) -> Value {
+    let mut k8s_labels: BTreeMap<&str, &str> = labels
+        .iter()
+        .map(|(k, v)| (k.as_str(), label_value(v)))
+        .collect();
+    k8s_labels.insert(MANAGED_BY, "mino");
+    let annotations: BTreeMap<&String, &String> = labels.iter().collect();
+    json!({
+        "name": name,
+        "labels": k8s_labels,
+        "annotations": annotations,
+    })
+}
+
+/// Secret holding the session env, so values stay out of the pod spec
+fn secret_manifest(name: &str, env: &HashMap<String, String>) -> Value {
+    let env: BTreeMap<&String, &String> = env.iter().collect();
+    json!({
+        "apiVersion": "v1",
+        "kind": "Secret",
+        "metadata": { "name": name, "labels": { MANAGED_BY: "mino" } },
+        "type": "Opaque",
+        "stringData": env,
+    })
+}
+
+/// NetworkPolicy denying all ingress to the pod, and egress too for
+/// `--network none`
+fn network_policy_manifest(name: &str, isolate: bool) -> Value {
+    let policy_types: &[&str] = if isolate {
+        &["Ingress", "Egress"]
+    } else {
+        &["Ingress"]
+    };
+    json!({
+        "apiVersion": "networking.k8s.io/v1",
+        "kind": "NetworkPolicy",
+        "metadata": { "name": name, "labels": { MANAGED_BY: "mino" } },
+        "spec": {
+            "podSelector": { "matchLabels": { POD_LABEL: name } },
+            "policyTypes": policy_types,
+        },
+    })
+}
+
+/// Security context mirroring Podman's capability and privilege options
+fn security_context(config: &ContainerConfig) -> Value {
+    let mut context = json!({
+        "capabilities": { "drop": &config.cap_drop, "add": &config.cap_add },
+        "readOnlyRootFilesystem": config.read_only,
+    });
+    if config
+        .security_opt
+        .iter()
+        .any(|o| o.starts_with("no-new-privileges"))
+    {
+        context["allowPrivilegeEscalation"] = json!(false);
+    }
+
+    // Kubernetes only accepts numeric IDs; named users keep the image default
+    if let Some(user) = &config.user {
+        let (uid, gid) = match user.split_once(':') {
+            Some((uid, gid)) => (uid, Some(gid)),
+            None => (user.as_str(), None),
+        };
+        let uid = if uid == "root" {
+            Some(0)
+        } else {
+            uid.parse::<u64>().ok()
+        };
+        match uid {
+            Some(uid) => {
+                context["runAsUser"] = json!(uid);
+                if let Some(gid) = gid.and_then(|g| g.parse::<u64>().ok()) {
+                    context["runAsGroup"] = json!(gid);
+                }
+            }
+            None => debug!("Keeping the image user: {} is not a numeric ID", user),
+        }
+    }
+    context
+}
+
+/// Pod spec for a sandbox: the gated sync init container and the session
+/// container.
+fn pod_manifest(
+    name: &str,
+    config: &ContainerConfig,
+    command: &[String],
+    mounts: &[Mount],
+    sync_image: &str,
+) -> Value {
+    let mut volumes = vec![json!({ "name": SYNC_CONTAINER, "emptyDir": {} })];
+    let mut sync_mounts = vec![json!({ "name": SYNC_CONTAINER, "mountPath": SYNC_ROOT })];
+    let mut main_mounts = Vec::new();
+
+    for (index, mount) in mounts.iter().enumerate() {
+        let volume = volume_name(index);
+        match mount {
+            Mount::Claim {
+                claim,
+                target,
+                read_only,
+            } => {
+                volumes.push(json!({
+                    "name": volume,
+                    "persistentVolumeClaim": { "claimName": claim },
+                }));
+                main_mounts.push(json!({
+                    "name": volume,
+                    "mountPath": target,
+                    "readOnly": read_only,
+                }));
+            }
+            Mount::Synced {
+                local,
+                target,
+                read_only,
+            } => {
+                let entry = local
+                    .file_name()
+                    .map(|n| n.to_string_lossy().to_string())
+                    .unwrap_or_default();
+                volumes.push(json!({ "name": volume, "emptyDir": {} }));
+                sync_mounts.push(json!({
+                    "name": volume,
+                    "mountPath": format!("{}/{}", SYNC_ROOT, volume),
+                }));
+                main_mounts.push(json!({
+                    "name": volume,
+                    "mountPath": target,
+                    "subPath": entry,
+                    "readOnly": read_only,
+                }));
+            }
+            Mount::Scratch { target } => {
+                volumes.push(json!({ "name": volume, "emptyDir": {} }));
+                main_mounts.push(json!({ "name": volume, "mountPath": target }));
+            }
+        }
+    }
+
+    for (index, spec) in config.tmpfs.iter().enumerate() {
+        let volume = format!("t{}", index);
+        let target = spec.split(':').next().unwrap_or(spec);
+        volumes.push(json!({ "name": volume, "emptyDir": { "medium": "Memory" } }));
+        main_mounts.push(json!({ "name": volume, "mountPath": target }));
+    }
+
+    let mut limits = serde_json::Map::new();
+    if config.cpus > 0.0 {
+        limits.insert("cpu".to_string(), json!(config.cpus.to_string()));
+    }
+    if config.memory_mb > 0 {
+        limits.insert(
+            "memory".to_string(),
+            json!(format!("{}Mi", config.memory_mb)),
+        );
+    }
+    if config.gpus {
+        limits.insert("nvidia.com/gpu".to_string(), json!("1"));
+    }
+
+    let mut main = json!({
+        "name": MAIN_CONTAINER,
+        "image": &config.image,
+        "workingDir": &config.workdir,
+        "stdin": config.interactive,
+        "stdinOnce": config.interactive,
+        "tty": config.tty,
+        "envFrom": [{ "secretRef": { "name": name } }],
+        "volumeMounts": main_mounts,
+        "securityContext": security_context(config),
+        "resources": { "limits": limits },
+    });
+    if !command.is_empty() {
+        main["args"] = json!(command);
+    }
+
+    let mut metadata = object_metadata(name, &config.labels);
+    metadata["labels"][POD_LABEL] = json!(name);
+
+    let mut pod = json!({
+        "apiVersion": "v1",
+        "kind": "Pod",
+        "metadata": metadata,
+        "spec": {
+            "restartPolicy": "Never",
+            // Sandboxes get no cluster credentials or service discovery env
+            "automountServiceAccountToken": false,
+            "enableServiceLinks": false,
+            "initContainers": [{
+                "name": SYNC_CONTAINER,
+                "image": sync_image,
+                "command": [
+                    "sh",
+                    "-c",
+                    format!("until [ -f {} ]; do sleep 1; done", READY_MARKER),
+                ],
+                "volumeMounts": sync_mounts,
+            }],
+            "containers": [main],
+            "volumes": volumes,
+        },
+    });
+    // Nodes pull images, so the platform picks the node architecture
+    if let Some(arch) = config.platform.as_deref().and_then(|p| p.split('/').nth(1)) {
+        pod["spec"]["nodeSelector"] = json!({ "kubernetes.io/arch": arch });
+    }
+    // Pod hostnames are a single DNS label; a dotted name keeps its first
+    if let Some(hostname) = config.hostname.as_deref() {
+        pod["spec"]["hostname"] = json!(hostname.split('.').next().unwrap_or(hostname));
+    }
+    let aliases: Vec<Value> = config
+        .extra_hosts
+        .iter()
+        .filter_map(|entry| entry.split_once(':'))
+        .map(|(name, ip)| json!({ "ip": ip, "hostnames": [name] }))
+        .collect();
+    if !aliases.is_empty() {
+        pod["spec"]["hostAliases"] = json!(aliases);
+    }
+    pod
+}
+
+/// State of container `name` from a pod's `containerStatuses` array
+fn container_state(statuses: &Value, name: &str) -> ContainerState {
+    let state = statuses
+        .as_array()
+        .and_then(|all| all.iter().find(|s| s["name"] == name))
+        .map(|s| &s["state"]);
+
+    match state {
+        Some(state) if state["running"].is_object() => ContainerState::Running,
+        Some(state) if state["terminated"].is_object() => {
+            ContainerState::Terminated(state["terminated"]["exitCode"].as_i64().unwrap_or(-1) as i32)
+        }
+        Some(state) => ContainerState::Waiting {
+            reason: state["waiting"]["reason"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string(),
+            message: state["waiting"]["message"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string(),
+        },
+        None => ContainerState::Waiting {
+            reason: String::new(),
+            message: String::new(),
+        },
+    }
+}
+
+/// Parse `kubectl get pod -o json`
+fn parse_pod_state(pod: &Value) -> PodState {
+    let main_spec = pod["spec"]["containers"]
+        .as_array()
+        .and_then(|all| all.iter().find(|c| c["name"] == MAIN_CONTAINER));
+    let flag = |key: &str| main_spec.and_then(|c| c[key].as_bool()).unwrap_or(false);
+
+    PodState {
+        uid: pod["metadata"]["uid"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string(),
+        sync: container_state(&pod["status"]["initContainerStatuses"], SYNC_CONTAINER),
+        main: container_state(&pod["status"]["containerStatuses"], MAIN_CONTAINER),
+        stdin: flag("stdin"),
+        tty: flag("tty"),
+    }
+}
+
+/// Volume info for a PersistentVolumeClaim; mino labels live in annotations
+fn claim_info(claim: &Value) -> VolumeInfo {
+    let labels = claim["metadata"]["annotations"]
+        .as_object()
+        .map(|annotations| {
+            annotations
+                .iter()
+                .filter_map(|(k, v)| Some((k.clone(), v.as_str()?.to_string())))
+                .collect()
+        })
+        .unwrap_or_default();
+    VolumeInfo {
+        name: claim["metadata"]["name"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string(),
+        labels,
+        mountpoint: None,
+        created_at: claim["metadata"]["creationTimestamp"]
+            .as_str()
+            .map(String::from),
+        size_bytes: None,
+    }
+}
+
+/// Whether kubectl's stderr reports a missing object
+fn is_not_found(stderr: &str) -> bool {
+    stderr.contains("NotFound") || stderr.contains("not found")
+}
+
+#[async_trait]
+impl ContainerRuntime for KubernetesRuntime {
+    async fn is_available(&self) -> MinoResult<bool> {
+        Ok(Kubectl::is_installed().await)
+    }
+
+    async fn ensure_ready(&self) -> MinoResult<()> {
+        if !Kubectl::is_installed().await {
+            return Err(MinoError::KubectlNotFound);
+        }
+
+        let scope = self.scope().await?;
+        let output = scope.exec(&["auth", "can-i", "create", "pods"]).await?;
+        if String::from_utf8_lossy(&output.stdout).trim() != "yes" {
+            let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+            return Err(MinoError::KubernetesUnreachable {
+                context: scope.context().to_string(),
+                reason: if stderr.is_empty() {
+                    format!("not allowed to create pods in {}", scope.namespace())
+                } else {
+                    stderr
+                },
+            });
+        }
+        Ok(())
+    }
+
+    async fn check_device(&self, _path: &str) -> MinoResult<()> {
+        Err(MinoError::BackendUnsupported {
+            runtime: self.runtime_name().to_string(),
+            feature: "Device passthrough (--device)".to_string(),
+        })
+    }
+
+    async fn check_gpu_support(&self) -> MinoResult<()> {
+        let output = self
+            .scope()
+            .await?
+            .exec(&[
+                "get",
+                "nodes",
+                "-o",
+                r"jsonpath={.items[*].status.allocatable.nvidia\.com/gpu}",
+            ])
+            .await?;
+
+        // Namespace-scoped users may not list nodes; leave it to the scheduler
+        if !output.status.success() {
+            debug!("Cannot list nodes to check for GPUs; skipping the check");
+            return Ok(());
+        }
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        if stdout.split_whitespace().any(|count| count != "0") {
+            Ok(())
+        } else {
+            Err(MinoError::GpuUnsupported {
+                runtime: self.runtime_name().to_string(),
+                reason:
+                    "no node advertises nvidia.com/gpu (is the NVIDIA device plugin installed?)"
+                        .to_string(),
+            })
+        }
+    }
+
+    async fn run(&self, config: &ContainerConfig, command: &[String]) -> MinoResult<String> {
+        let id = self.create_pod(config, command).await?;
+        self.start_detached(&id).await?;
+        Ok(id)
+    }
+
+    async fn create(&self, config: &ContainerConfig, command: &[String]) -> MinoResult<String> {
+        self.create_pod(config, command).await
+    }
+
+    async fn start_attached(&self, container_id: &str) -> MinoResult<i32> {
+        debug!("Starting pod attached: {}", container_id);
+        let pod = PodRef::parse(container_id)?;
+        let kubectl = pod.kubectl();
+        let state = self.start_pod(&pod).await?;
+
+        if state.main == ContainerState::Running {
+            let mut args = vec!["attach", &pod.name, "-c", MAIN_CONTAINER];
+            if state.stdin {
+                args.push("-i");
+            }
+            if state.tty && std::io::stdin().is_terminal() {
+                args.push("-t");
+            }
+            kubectl.exec_interactive(&args).await?;
+        } else {
+            // Finished before we could attach; show what it printed
+            kubectl
+                .exec_interactive(&["logs", &pod.name, "-c", MAIN_CONTAINER])
+                .await?;
+        }
+
+        Ok(self
+            .get_container_exit_code(container_id)
+            .await?
+            .unwrap_or(KILLED_EXIT_CODE))
+    }
+
+    async fn stop(&self, container_id: &str) -> MinoResult<()> {
+        debug!("Stopping pod: {}", container_id);
+        let pod = PodRef::parse(container_id)?;
+        match self.delete(&pod, false).await {
+            None => Ok(()),
+            Some(stderr) => Err(MinoError::command_exec("kubectl delete", stderr)),
+        }
+    }
+
+    async fn kill(&self, container_id: &str) -> MinoResult<()> {
+        debug!("Killing pod: {}", container_id);
+        let pod = PodRef::parse(container_id)?;
+        match self.delete(&pod, true).await {
+            None => Ok(()),
+            Some(stderr) => Err(MinoError::command_exec("kubectl delete", stderr)),
+        }
+    }
+
+    async fn signal(&self, container_id: &str, signal: &str) -> MinoResult<()> {
+        debug!("Sending {} to pod: {}", signal, container_id);
+        let pod = PodRef::parse(container_id)?;
+        let signal = format!("-{}", signal.trim_start_matches("SIG"));
+
+        // No kubectl equivalent: signal PID 1 from inside the container
+        let output = pod
+            .kubectl()
+            .exec(&[
+                "exec",
+                &pod.name,
+                "-c",
+                MAIN_CONTAINER,
+                "--",
+                "kill",
+                &signal,
+                "1",
+            ])
+            .await?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec("kubectl exec kill", stderr))
+        }
+    }
+
+    async fn pause(&self, _container_id: &str) -> MinoResult<()> {
+        Err(MinoError::BackendUnsupported {
+            runtime: self.runtime_name().to_string(),
+            feature: "Pausing sessions".to_string(),
+        })
+    }
+
+    async fn unpause(&self, _container_id: &str) -> MinoResult<()> {
+        Err(MinoError::BackendUnsupported {
+            runtime: self.runtime_name().to_string(),
+            feature: "Resuming paused sessions".to_string(),
+        })
+    }
+
+    async fn checkpoint(
+        &self,
+        _container_id: &str,
+        _archive: &Path,
+        _leave_running: bool,
+    ) -> MinoResult<()> {
+        Err(MinoError::CheckpointUnsupported {
+            runtime: self.runtime_name().to_string(),
+            reason: "kubectl cannot export pod checkpoints".to_string(),
+        })
+    }
+
+    async fn restore(&self, _archive: &Path) -> MinoResult<String> {
+        Err(MinoError::CheckpointUnsupported {
+            runtime: self.runtime_name().to_string(),
+            reason: "kubectl cannot import checkpoint archives".to_string(),
+        })
+    }
+
+    async fn remove(&self, container_id: &str) -> MinoResult<()> {
+        debug!("Removing pod: {}", container_id);
+        let pod = PodRef::parse(container_id)?;
+        match self.delete(&pod, true).await {
+            None => Ok(()),
+            Some(stderr) => Err(MinoError::command_exec("kubectl delete", stderr)),
+        }
+    }
+
+    async fn container_prune(&self) -> MinoResult<()> {
+        // Secrets and policies are owned by their pod and collected with it
+        let selector = format!("{}=mino", MANAGED_BY);
+        let output = self
+            .scope()
+            .await?
+            .exec(&[
+                "delete",
+                "pods",
+                "-l",
+                &selector,
+                "--field-selector=status.phase!=Running,status.phase!=Pending",
+            ])
+            .await?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(MinoError::command_exec("kubectl delete pods", stderr));
+        }
+        Ok(())
+    }
+
+    async fn container_list_labeled(&self, label: &str) -> MinoResult<Vec<String>> {
+        let scope = self.scope().await?;
+        let output = scope
+            .exec(&["get", "pods", "-l", label, "-o", "name"])
+            .await?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(MinoError::command_exec("kubectl get pods", stderr));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        Ok(stdout
+            .lines()
+            .filter_map(|line| line.trim().strip_prefix("pod/"))
+            .map(|name| {
+                PodRef {
+                    context: scope.context().to_string(),
+                    namespace: scope.namespace().to_string(),
+                    name: name.to_string(),
+                }
+                .id()
+            })
+            .collect())
+    }
+
+    async fn logs(&self, container_id: &str, lines: u32) -> MinoResult<String> {
+        let pod = PodRef::parse(container_id)?;
+        let tail = if lines == 0 {
+            "--tail=-1".to_string()
+        } else {
+            format!("--tail={}", lines)
+        };
+
+        let output = pod
+            .kubectl()
+            .exec(&["logs", &tail, &pod.name, "-c", MAIN_CONTAINER])
+            .await?;
+
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    }
+
+    async fn logs_follow(&self, container_id: &str) -> MinoResult<()> {
+        let pod = PodRef::parse(container_id)?;
+        pod.kubectl()
+            .exec_interactive(&["logs", "-f", &pod.name, "-c", MAIN_CONTAINER])
+            .await?;
+        Ok(())
+    }
+
+    async fn logs_follow_lines(
+        &self,
+        container_id: &str,
+        lines: u32,
+        on_line: &(dyn Fn(String) + Send + Sync),
+    ) -> MinoResult<()> {
+        let pod = PodRef::parse(container_id)?;
+        let tail = if lines == 0 {
+            "--tail=-1".to_string()
+        } else {
+            format!("--tail={}", lines)
+        };
+
+        let mut child =
+            pod.kubectl()
+                .spawn_piped(&["logs", "-f", &tail, &pod.name, "-c", MAIN_CONTAINER])?;
+
+        super::forward_child_lines(&mut child, on_line).await;
+        Ok(())
+    }
+
+    async fn image_exists(&self, _image: &str) -> MinoResult<bool> {
+        // Nodes pull images themselves; there is no local store to check
+        Ok(true)
+    }
+
+    async fn image_pull(&self, image: &str) -> MinoResult<()> {
+        debug!("Leaving the pull of {} to the cluster nodes", image);
+        Ok(())
+    }
+
+    async fn image_pull_with_progress(
+        &self,
+        image: &str,
+        _platform: Option<&str>,
+        _on_output: &(dyn Fn(String) + Send + Sync),
+    ) -> MinoResult<()> {
+        self.image_pull(image).await
+    }
+
+    async fn image_platform(&self, _image: &str) -> MinoResult<Option<String>> {
+        // Nodes pull images; the pod's node selector picks the architecture
+        Ok(None)
+    }
+
+    async fn image_user(&self, _image: &str) -> MinoResult<Option<String>> {
+        // Images are never pulled locally, so their config cannot be read
+        Ok(None)
+    }
+
+    async fn build_image(
+        &self,
+        _context_dir: &Path,
+        _tag: &str,
+        _pull: PullPolicy,
+    ) -> MinoResult<()> {
+        Err(MinoError::BackendUnsupported {
+            runtime: self.runtime_name().to_string(),
+            feature: "Building images (layers, custom images)".to_string(),
+        })
+    }
+
+    async fn build_image_with_progress(
+        &self,
+        context_dir: &Path,
+        tag: &str,
+        pull: PullPolicy,
+        _on_output: &(dyn Fn(String) + Send + Sync),
+    ) -> MinoResult<()> {
+        self.build_image(context_dir, tag, pull).await
+    }
+
+    async fn image_remove(&self, _image: &str) -> MinoResult<()> {
+        Ok(())
+    }
+
+    async fn image_list_prefixed(&self, _prefix: &str) -> MinoResult<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    async fn image_list_labeled(&self, _label: &str) -> MinoResult<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    fn runtime_name(&self) -> &'static str {
+        "Kubernetes"
+    }
+
+    async fn volume_create(&self, name: &str, labels: &HashMap<String, String>) -> MinoResult<()> {
+        debug!("Creating volume claim: {}", name);
+
+        let mut claim = json!({
+            "apiVersion": "v1",
+            "kind": "PersistentVolumeClaim",
+            "metadata": object_metadata(name, labels),
+            "spec": {
+                "accessModes": [&self.config.volume_access_mode],
+                "resources": { "requests": { "storage": &self.config.volume_size } },
+            },
+        });
+        if !self.config.storage_class.is_empty() {
+            claim["spec"]["storageClassName"] = json!(&self.config.storage_class);
+        }
+
+        let output = self
+            .scope()
+            .await?
+            .exec_with_input(&["create", "-f", "-"], claim.to_string().as_bytes())
+            .await?;
+
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if output.status.success() || stderr.contains("AlreadyExists") {
+            Ok(())
+        } else {
+            Err(MinoError::command_exec("kubectl create pvc", stderr))
+        }
+    }
+
+    async fn network_create(&self, _name: &str) -> MinoResult<()> {
+        // Pods reach each other through Services, not container networks
+        Err(MinoError::BackendUnsupported {
+            runtime: self.runtime_name().to_string(),
+            feature: "Session networks".to_string(),
+        })
+    }
+
+    async fn volume_remove(&self, name: &str) -> MinoResult<()> {
+        debug!("Removing volume claim: {}", name);
+
+        let output = self
+            .scope()
+            .await?
+            .exec(&["delete", "pvc", name, "--ignore-not-found", "--wait=false"])
+            .await?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec("kubectl delete pvc", stderr))
+        }
+    }
+
+    async fn volume_list(&self, label: &str) -> MinoResult<Vec<VolumeInfo>> {
+        let output = self
+            .scope()
+            .await?
+            .exec(&["get", "pvc", "-l", label, "-o", "json"])
+            .await?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(MinoError::command_exec("kubectl get pvc", stderr));
+        }
+
+        let json: Value = serde_json::from_slice(&output.stdout)
+            .map_err(|e| MinoError::Internal(format!("Failed to parse PVC list: {}", e)))?;
+        Ok(json["items"]
+            .as_array()
+            .map(|items| items.iter().map(claim_info).collect())
+            .unwrap_or_default())
+    }
+
+    async fn volume_inspect(&self, name: &str) -> MinoResult<Option<VolumeInfo>> {
+        let output = self
+            .scope()
+            .await?
+            .exec(&["get", "pvc", name, "-o", "json"])
+            .await?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            if is_not_found(&stderr) {
+                return Ok(None);
+            }
+            return Err(MinoError::command_exec("kubectl get pvc", stderr));
+        }
+
+        let json: Value = serde_json::from_slice(&output.stdout)
+            .map_err(|e| MinoError::Internal(format!("Failed to parse PVC JSON: {}", e)))?;
+        Ok(Some(claim_info(&json)))
+    }
+
+    async fn volume_export(&self, _name: &str, _archive: &Path) -> MinoResult<()> {
+        Err(MinoError::BackendUnsupported {
+            runtime: self.runtime_name().to_string(),
+            feature: "Volume archives (cache.remote)".to_string(),
+        })
+    }
+
+    fn supports_volume_archives(&self) -> bool {
+        false
+    }
+
+    async fn volume_import(&self, _name: &str, _archive: &Path) -> MinoResult<()> {
+        Err(MinoError::BackendUnsupported {
+            runtime: self.runtime_name().to_string(),
+            feature: "Volume archives (cache.remote)".to_string(),
+        })
+    }
+
+    async fn volume_disk_usage(&self, _label: &str) -> MinoResult<HashMap<String, u64>> {
+        // Claim usage is not exposed through the API server
+        Ok(HashMap::new())
+    }
+
+    async fn exec_in_container(
+        &self,
+        container_id: &str,
+        command: &[String],
+        tty: bool,
+    ) -> MinoResult<i32> {
+        debug!("Exec into pod: {}", container_id);
+        let pod = PodRef::parse(container_id)?;
+        let mut args = vec!["exec", "-i"];
+        if tty {
+            args.push("-t");
+        }
+        args.extend([pod.name.as_str(), "-c", MAIN_CONTAINER, "--"]);
+        args.extend(command.iter().map(String::as_str));
+        pod.kubectl().exec_interactive(&args).await
+    }
+
+    async fn copy_to_container(
+        &self,
+        container_id: &str,
+        host_path: &Path,
+        container_path: &str,
+    ) -> MinoResult<()> {
+        debug!("Copying {} into {}", host_path.display(), container_id);
+        let pod = PodRef::parse(container_id)?;
+        let host = host_path.display().to_string();
+        let target = format!("{}:{}", pod.name, container_path);
+        kubectl_cp(&pod, &host, &target).await
+    }
+
+    async fn copy_from_container(
+        &self,
+        container_id: &str,
+        container_path: &str,
+        host_path: &Path,
+    ) -> MinoResult<()> {
+        debug!("Copying {} out of {}", container_path, container_id);
+        let pod = PodRef::parse(container_id)?;
+        let source = format!("{}:{}", pod.name, container_path);
+        let host = host_path.display().to_string();
+        kubectl_cp(&pod, &source, &host).await
+    }
+
+    async fn get_container_exit_code(&self, container_id: &str) -> MinoResult<Option<i32>> {
+        debug!("Waiting for pod exit: {}", container_id);
+        let pod = PodRef::parse(container_id)?;
+        loop {
+            match self.pod_state(&pod).await? {
+                None => return Ok(None),
+                Some(PodState {
+                    main: ContainerState::Terminated(code),
+                    ..
+                }) => return Ok(Some(code)),
+                Some(_) => tokio::time::sleep(POLL_INTERVAL).await,
+            }
+        }
+    }
+
+    async fn start_detached(&self, container_id: &str) -> MinoResult<()> {
+        debug!("Starting pod detached: {}", container_id);
+        let pod = PodRef::parse(container_id)?;
+        self.start_pod(&pod).await?;
+        Ok(())
+    }
+
+    async fn container_restart_count(&self, _container_id: &str) -> MinoResult<Option<u32>> {
+        // Pods run with restartPolicy Never; restart policies are rejected at create
+        Ok(Some(0))
+    }
+
+    async fn container_is_running(&self, container_id: &str) -> MinoResult<bool> {
+        let pod = PodRef::parse(container_id)?;
+        Ok(matches!(
+            self.pod_state(&pod).await?,
+            Some(PodState {
+                main: ContainerState::Running,
+                ..
+            })
+        ))
+    }
+
+    async fn attach(&self, _container_id: &str) -> MinoResult<i32> {
+        // start_attached already waits for the pod to terminate
+        Err(MinoError::BackendUnsupported {
+            runtime: self.runtime_name().to_string(),
+            feature: "Re-attaching to sessions".to_string(),
+        })
+    }
+
+    async fn registry_login(
+        &self,
+        _registry: &str,
+        _username: Option<&str>,
+        _password_stdin: bool,
+    ) -> MinoResult<()> {
+        // Nodes pull images; credentials belong in an imagePullSecret
+        Err(MinoError::BackendUnsupported {
+            runtime: self.runtime_name().to_string(),
+            feature: "Registry login (use an imagePullSecret)".to_string(),
+        })
+    }
+
+    async fn registry_login_password(
+        &self,
+        _registry: &str,
+        _username: &str,
+        _password: &str,
+    ) -> MinoResult<()> {
+        Err(MinoError::BackendUnsupported {
+            runtime: self.runtime_name().to_string(),
+            feature: "Registry login (use an imagePullSecret)".to_string(),
+        })
+    }
+
+    async fn registry_logout(&self, _registry: &str) -> MinoResult<()> {
+        Err(MinoError::BackendUnsupported {
+            runtime: self.runtime_name().to_string(),
+            feature: "Registry login (use an imagePullSecret)".to_string(),
+        })
+    }
+
+    async fn pod_create(&self, _spec: &PodSpec) -> MinoResult<()> {
+        Err(MinoError::BackendUnsupported {
+            runtime: self.runtime_name().to_string(),
+            feature: "Service containers".to_string(),
+        })
+    }
+
+    async fn pod_remove(&self, _name: &str) -> MinoResult<()> {
+        Err(MinoError::BackendUnsupported {
+            runtime: self.runtime_name().to_string(),
+            feature: "Service containers".to_string(),
+        })
+    }
+
+    async fn container_usage(&self, _container_id: &str) -> MinoResult<ContainerUsage> {
+        Err(MinoError::BackendUnsupported {
+            runtime: self.runtime_name().to_string(),
+            feature: "Container usage stats".to_string(),
+        })
+    }
+
+    async fn logs_follow_until(
+        &self,
+        container_id: &str,
+        marker: &str,
+        timeout: std::time::Duration,
+        on_line: &(dyn Fn(String) + Send + Sync),
+    ) -> MinoResult<bool> {
+        debug!("Following logs for {} until '{}'", container_id, marker);
+        let pod = PodRef::parse(container_id)?;
+
+        let mut child =
+            pod.kubectl()
+                .spawn_piped(&["logs", "-f", &pod.name, "-c", MAIN_CONTAINER])?;
+
+        Ok(super::follow_until_marker(&mut child, marker, timeout, on_line).await)
+    }
+}
+
+/// Run `kubectl cp` against the session container
+async fn kubectl_cp(pod: &PodRef, source: &str, target: &str) -> MinoResult<()> {
+    let output = pod
+        .kubectl()
+        .exec(&["cp", "-c", MAIN_CONTAINER, source, target])
+        .await?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Err(MinoError::command_exec("kubectl cp", stderr))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_config() -> ContainerConfig {
+        ContainerConfig {
+            image: "fedora:43".to_string(),
+            workdir: "/workspace".to_string(),
+            volumes: vec![],
+            env: HashMap::from([("TERM".to_string(), "xterm".to_string())]),
+            network: "bridge".to_string(),
+            network_aliases: vec![],
+            interactive: true,
+            tty: true,
+            cap_add: vec!["NET_ADMIN".to_string()],
+            cap_drop: vec!["ALL".to_string()],
+            security_opt: vec!["no-new-privileges".to_string()],
+            pids_limit: 4096,
+            ulimits: vec![],
+            cpus: 2.0,
+            memory_mb: 4096,
+            gpus: false,
+            devices: vec![],
+            auto_remove: false,
+            read_only: false,
+            tmpfs: vec!["/tmp:rw,size=64m".to_string()],
+            user: Some("1000:1000".to_string()),
+            userns: None,
+            restart: None,
+            labels: HashMap::from([(crate::labels::SESSION.to_string(), "swift-fox".to_string())]),
+            pod: None,
+            platform: None,
+            hostname: None,
+            extra_hosts: vec![],
+        }
+    }
+
+    #[test]
+    fn pod_ref_roundtrips_through_container_id() {
+        let pod = PodRef::parse("arn:aws:eks:eu-west-1:1:cluster/dev/sandboxes/mino-a").unwrap();
+        assert_eq!(pod.context, "arn:aws:eks:eu-west-1:1:cluster/dev");
+        assert_eq!(pod.namespace, "sandboxes");
+        assert_eq!(pod.name, "mino-a");
+        assert_eq!(
+            pod.id(),
+            "arn:aws:eks:eu-west-1:1:cluster/dev/sandboxes/mino-a"
+        );
+
+        assert!(PodRef::parse("abc123").is_err());
+    }
+
+    #[test]
+    fn plan_mounts_classifies_specs() {
+        let mounts = plan_mounts(&[
+            "/Users/me/app:/workspace".to_string(),
+            "mino-cache-npm-ab12:/cache/npm:U".to_string(),
+            "/etc/localtime:/etc/localtime:ro".to_string(),
+            "/anonymous".to_string(),
+        ]);
+        assert_eq!(
+            mounts,
+            vec![
+                Mount::Synced {
+                    local: PathBuf::from("/Users/me/app"),
+                    target: "/workspace".to_string(),
+                    read_only: false,
+                },
+                Mount::Claim {
+                    claim: "mino-cache-npm-ab12".to_string(),
+                    target: "/cache/npm".to_string(),
+                    read_only: false,
+                },
+                Mount::Synced {
+                    local: PathBuf::from("/etc/localtime"),
+                    target: "/etc/localtime".to_string(),
+                    read_only: true,
+                },
+                Mount::Scratch {
+                    target: "/anonymous".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn pod_name_is_dns_safe() {
+        let labels =
+            HashMap::from([(crate::labels::SESSION.to_string(), "My_Session".to_string())]);
+        let name = pod_name(&labels);
+        assert!(name.starts_with("mino-my-session-"), "{name}");
+        assert_eq!(name.len(), "mino-my-session-".len() + 6);
+        assert!(pod_name(&HashMap::new()).starts_with("mino-"));
+    }
+
+    #[test]
+    fn label_value_blanks_invalid_values() {
+        assert_eq!(label_value("npm"), "npm");
+        assert_eq!(label_value("2024-01-01T00:00:00Z"), "");
+        assert_eq!(label_value("/Users/me/app"), "");
+    }
+
+    #[test]
+    fn pod_manifest_selects_node_arch_for_platform() {
+        let mut config = test_config();
+        config.platform = Some("linux/amd64".to_string());
+        let pod = pod_manifest("mino-x", &config, &[], &[], "busybox:1.36");
+        assert_eq!(
+            pod["spec"]["nodeSelector"],
+            json!({ "kubernetes.io/arch": "amd64" })
+        );
+    }
+
+    #[test]
+    fn pod_manifest_sets_hostname_and_host_aliases() {
+        let mut config = test_config();
+        config.hostname = Some("devbox.corp".to_string());
+        config.extra_hosts = vec!["internal.corp:10.0.0.5".to_string()];
+        let pod = pod_manifest("mino-x", &config, &[], &[], "busybox:1.36");
+        assert_eq!(pod["spec"]["hostname"], "devbox");
+        assert_eq!(
+            pod["spec"]["hostAliases"],
+            json!([{ "ip": "10.0.0.5", "hostnames": ["internal.corp"] }])
+        );
+    }
+
+    #[test]
+    fn pod_manifest_maps_container_config() {
+        let config = test_config();
+        let mounts = plan_mounts(&[
+            "/Users/me/app:/workspace".to_string(),
+            "mino-cache-npm-ab12:/cache/npm".to_string(),
+        ]);
+        let pod = pod_manifest(
+            "mino-swift-fox-abc123",
+            &config,
+            &["bash".to_string()],
+            &mounts,
+            "busybox:1.36",
+        );
+
+        let main = &pod["spec"]["containers"][0];
+        assert_eq!(main["args"], json!(["bash"]));
+        assert_eq!(
+            main["envFrom"][0]["secretRef"]["name"],
+            "mino-swift-fox-abc123"
+        );
+        assert!(main.get("env").is_none(), "env must stay in the Secret");
+        assert_eq!(main["resources"]["limits"]["cpu"], "2");
+        assert_eq!(main["resources"]["limits"]["memory"], "4096Mi");
+
+        let security = &main["securityContext"];
+        assert_eq!(security["capabilities"]["drop"], json!(["ALL"]));
+        assert_eq!(security["capabilities"]["add"], json!(["NET_ADMIN"]));
+        assert_eq!(security["allowPrivilegeEscalation"], false);
+        assert_eq!(security["runAsUser"], 1000);
+        assert_eq!(security["runAsGroup"], 1000);
+
+        assert_eq!(main["volumeMounts"][0]["mountPath"], "/workspace");
+        assert_eq!(main["volumeMounts"][0]["subPath"], "app");
+        assert_eq!(main["volumeMounts"][1]["mountPath"], "/cache/npm");
+        assert_eq!(main["volumeMounts"][2]["mountPath"], "/tmp");
+
+        let volumes = &pod["spec"]["volumes"];
+        assert_eq!(
+            volumes[2]["persistentVolumeClaim"]["claimName"],
+            "mino-cache-npm-ab12"
+        );
+        assert_eq!(volumes[3]["emptyDir"]["medium"], "Memory");
+
+        assert!(pod["spec"].get("nodeSelector").is_none());
+        assert!(pod["spec"].get("hostAliases").is_none());
+
+        let init = &pod["spec"]["initContainers"][0];
+        assert_eq!(init["image"], "busybox:1.36");
+        assert_eq!(init["volumeMounts"][1]["mountPath"], "/mino-sync/m0");
+
+        assert_eq!(pod["spec"]["automountServiceAccountToken"], false);
+        assert_eq!(
+            pod["metadata"]["labels"][POD_LABEL],
+            "mino-swift-fox-abc123"
+        );
+        assert_eq!(
+            pod["metadata"]["annotations"][crate::labels::SESSION],
+            "swift-fox"
+        );
+    }
+
+    #[test]
+    fn network_policy_isolates_egress_only_for_network_none() {
+        let open = network_policy_manifest("p", false);
+        assert_eq!(open["spec"]["policyTypes"], json!(["Ingress"]));
+        assert_eq!(open["spec"]["podSelector"]["matchLabels"][POD_LABEL], "p");
+
+        let none = network_policy_manifest("p", true);
+        assert_eq!(none["spec"]["policyTypes"], json!(["Ingress", "Egress"]));
+    }
+
+    #[test]
+    fn parse_pod_state_reads_container_states() {
+        let pod = json!({
+            "metadata": { "uid": "u-1" },
+            "spec": { "containers": [{ "name": "sandbox", "stdin": true, "tty": false }] },
+            "status": {
+                "initContainerStatuses": [
+                    { "name": "mino-sync", "state": { "terminated": { "exitCode": 0 } } }
+                ],
+                "containerStatuses": [
+                    { "name": "sandbox", "state": { "terminated": { "exitCode": 3 } } }
+                ],
+            },
+        });
+        let state = parse_pod_state(&pod);
+        assert_eq!(state.uid, "u-1");
+        assert_eq!(state.sync, ContainerState::Terminated(0));
+        assert_eq!(state.main, ContainerState::Terminated(3));
+        assert!(state.stdin);
+        assert!(!state.tty);
+        assert!(state.fatal_reason().is_none());
+    }
+
+    #[test]
+    fn fatal_reason_reports_image_pull_backoff() {
+        let pod = json!({
+            "status": {
+                "containerStatuses": [{
+                    "name": "sandbox",
+                    "state": { "waiting": { "reason": "ImagePullBackOff", "message": "no such image" } },
+                }],
+            },
+        });
+        let state = parse_pod_state(&pod);
+        assert_eq!(
+            state.fatal_reason().as_deref(),
+            Some("ImagePullBackOff: no such image")
+        );
+
+        let pending = parse_pod_state(&json!({}));
+        assert!(pending.fatal_reason().is_none());
+        assert!(matches!(pending.main, ContainerState::Waiting { .. }));
+    }
+
+    #[test]
+    fn claim_info_reads_annotations() {
+        let claim = json!({
+            "metadata": {
+                "name": "mino-cache-npm-ab12",
+                "creationTimestamp": "2026-01-01T00:00:00Z",
+                "annotations": { "io.mino.cache": "true" },
+            },
+        });
+        let info = claim_info(&claim);
+        assert_eq!(info.name, "mino-cache-npm-ab12");
+        assert_eq!(info.labels["io.mino.cache"], "true");
+        assert_eq!(info.created_at.as_deref(), Some("2026-01-01T00:00:00Z"));
+    }
+}
@@ -0,0 +1,322 @@
+//! Lima VM management
+
+use crate::config::schema::VmConfig;
+use crate::error::{MinoError, MinoResult};
+use crate::redact::redact_args;
+use std::process::Stdio;
+use tokio::process::Command;
+use tracing::debug;
+
+/// Lima manager
+#[derive(Clone)]
+pub struct Lima {
+    config: VmConfig,
+}
+
+impl Lima {
+    /// Create a new Lima manager
+    pub fn new(config: VmConfig) -> Self {
+        Self { config }
+    }
+
+    /// Check if Lima is installed
+    pub async fn is_installed() -> bool {
+        Command::new("limactl")
+            .arg("--version")
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .await
+            .map(|s| s.success())
+            .unwrap_or(false)
+    }
+
+    /// Get Lima version
+    pub async fn version() -> MinoResult<String> {
+        let output = Command::new("limactl")
+            .arg("--version")
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .output()
+            .await
+            .map_err(|e| MinoError::command_failed("limactl --version", e))?;
+
+        if output.status.success() {
+            // Parse "limactl version 1.0.3" to just "1.0.3"
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            let version = stdout.split_whitespace().last().unwrap_or("unknown");
+            Ok(version.to_string())
+        } else {
+            Err(MinoError::LimaNotFound)
+        }
+    }
+
+    /// Check if the VM exists
+    pub async fn vm_exists(&self) -> MinoResult<bool> {
+        Ok(self.vm_status().await? != "unknown")
+    }
+
+    /// Get VM status ("Running", "Stopped", ... or "unknown" if missing)
+    pub async fn vm_status(&self) -> MinoResult<String> {
+        let output = Command::new("limactl")
+            .args(["list", "--format", "{{.Name}}\t{{.Status}}"])
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .output()
+            .await
+            .map_err(|e| MinoError::command_failed("limactl list", e))?;
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        Ok(parse_vm_status(&stdout, &self.config.name).unwrap_or_else(|| "unknown".to_string()))
+    }
+
+    /// Create and start the VM from the Lima template named after the distro.
+    ///
+    /// Mounts are made writable so project directories under `$HOME` can be
+    /// bind-mounted into containers read-write.
+    pub async fn create_vm(&self) -> MinoResult<()> {
+        debug!("Creating Lima VM: {}", self.config.name);
+
+        let output = Command::new("limactl")
+            .args(create_args(&self.config))
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .output()
+            .await
+            .map_err(|e| MinoError::command_failed("limactl start", e))?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::VmStart(format!(
+                "Failed to create VM: {}",
+                stderr
+            )))
+        }
+    }
+
+    /// Start the VM
+    pub async fn start_vm(&self) -> MinoResult<()> {
+        debug!("Starting VM: {}", self.config.name);
+
+        let status = Command::new("limactl")
+            .args(["start", "--tty=false", &self.config.name])
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .status()
+            .await
+            .map_err(|e| MinoError::command_failed("limactl start", e))?;
+
+        if status.success() {
+            Ok(())
+        } else {
+            Err(MinoError::VmStart(format!(
+                "Failed to start VM: {}",
+                self.config.name
+            )))
+        }
+    }
+
+    /// Ensure VM is running
+    pub async fn ensure_vm_running(&self) -> MinoResult<()> {
+        if !Self::is_installed().await {
+            return Err(MinoError::LimaNotFound);
+        }
+
+        match self.vm_status().await?.as_str() {
+            "Running" => Ok(()),
+            "unknown" => self.create_vm().await,
+            _ => self.start_vm().await,
+        }
+    }
+
+    /// `limactl shell` invocation running `command` in the VM
+    fn shell_command(&self, command: &[&str]) -> Command {
+        let mut cmd = Command::new("limactl");
+        // Without --workdir, limactl tries to cd into the host cwd first
+        cmd.args(["shell", "--workdir", "/", &self.config.name]);
+        cmd.args(command);
+        cmd
+    }
+
+    /// Execute a command in the VM
+    pub async fn exec(&self, command: &[&str]) -> MinoResult<std::process::Output> {
+        debug!(
+            "Executing in VM {}: {:?}",
+            self.config.name,
+            redact_args(command)
+        );
+
+        let mut cmd = self.shell_command(command);
+        cmd.stdout(Stdio::piped()).stderr(Stdio::piped());
+
+        cmd.output().await.map_err(|e| {
+            MinoError::command_failed(
+                format!(
+                    "limactl shell {} {:?}",
+                    self.config.name,
+                    redact_args(command)
+                ),
+                e,
+            )
+        })
+    }
+
+    /// Execute a command in the VM with `input` on stdin
+    pub async fn exec_with_input(
+        &self,
+        command: &[&str],
+        input: &[u8],
+    ) -> MinoResult<std::process::Output> {
+        debug!(
+            "Executing with input in VM {}: {:?}",
+            self.config.name,
+            redact_args(command)
+        );
+
+        let mut cmd = self.shell_command(command);
+        super::output_with_input(&mut cmd, input)
+            .await
+            .map_err(|e| {
+                MinoError::command_failed(
+                    format!(
+                        "limactl shell {} {:?}",
+                        self.config.name,
+                        redact_args(command)
+                    ),
+                    e,
+                )
+            })
+    }
+
+    /// Spawn a command in the VM with piped stdout/stderr.
+    ///
+    /// Returns the child process for streaming output. Caller is responsible
+    /// for reading stdout/stderr and waiting for exit.
+    pub fn spawn_piped(&self, command: &[&str]) -> MinoResult<tokio::process::Child> {
+        debug!(
+            "Spawning piped in VM {}: {:?}",
+            self.config.name,
+            redact_args(command)
+        );
+
+        let mut cmd = self.shell_command(command);
+        cmd.stdout(Stdio::piped()).stderr(Stdio::piped());
+
+        cmd.spawn().map_err(|e| {
+            MinoError::command_failed(
+                format!(
+                    "limactl shell {} {:?}",
+                    self.config.name,
+                    redact_args(command)
+                ),
+                e,
+            )
+        })
+    }
+
+    /// Execute a command in the VM interactively
+    pub async fn exec_interactive(&self, command: &[&str]) -> MinoResult<i32> {
+        debug!(
+            "Executing interactively in VM {}: {:?}",
+            self.config.name,
+            redact_args(command)
+        );
+
+        let mut cmd = self.shell_command(command);
+        cmd.stdin(Stdio::inherit())
+            .stdout(Stdio::inherit())
+            .stderr(Stdio::inherit());
+
+        let status = cmd.status().await.map_err(|e| {
+            MinoError::command_failed(
+                format!(
+                    "limactl shell {} {:?}",
+                    self.config.name,
+                    redact_args(command)
+                ),
+                e,
+            )
+        })?;
+
+        Ok(status.code().unwrap_or(-1))
+    }
+
+    /// Get VM name
+    pub fn vm_name(&self) -> &str {
+        &self.config.name
+    }
+}
+
+/// Arguments to `limactl` that create and start a new VM
+pub(crate) fn create_args(config: &VmConfig) -> Vec<String> {
+    let mut args = vec![
+        "start".to_string(),
+        format!("--name={}", config.name),
+        "--tty=false".to_string(),
+        "--mount-writable".to_string(),
+    ];
+    if config.cpus > 0 {
+        args.push(format!("--cpus={}", config.cpus));
+    }
+    if config.memory_gb > 0 {
+        args.push(format!("--memory={}", config.memory_gb));
+    }
+    args.push(format!("template://{}", config.distro));
+    args
+}
+
+/// Find the status of `name` in `limactl list` Name\tStatus output
+fn parse_vm_status(stdout: &str, name: &str) -> Option<String> {
+    stdout.lines().find_map(|line| {
+        let (vm, status) = line.split_once('\t')?;
+        (vm.trim() == name).then(|| status.trim().to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lima_new() {
+        let lima = Lima::new(VmConfig::default());
+        assert_eq!(lima.vm_name(), "mino");
+    }
+
+    #[test]
+    fn parse_vm_status_finds_named_vm() {
+        let stdout = "default\tStopped\nmino\tRunning\n";
+        assert_eq!(parse_vm_status(stdout, "mino").as_deref(), Some("Running"));
+        assert_eq!(parse_vm_status(stdout, "other"), None);
+    }
+
+    #[test]
+    fn create_args_use_distro_template() {
+        let args = create_args(&VmConfig::default());
+        assert_eq!(
+            args,
+            vec![
+                "start",
+                "--name=mino",
+                "--tty=false",
+                "--mount-writable",
+                "template://fedora"
+            ]
+        );
+    }
+
+    #[test]
+    fn create_args_size_the_vm() {
+        let config = VmConfig {
+            cpus: 6,
+            memory_gb: 12,
+            ..VmConfig::default()
+        };
+        let args = create_args(&config);
+        assert!(args.contains(&"--cpus=6".to_string()));
+        assert!(args.contains(&"--memory=12".to_string()));
+        assert_eq!(args.last().unwrap(), "template://fedora");
+    }
+}
@@ -0,0 +1,1105 @@
+//! Lima container runtime for macOS
+//!
+//! Implements the ContainerRuntime trait using a Lima VM + Podman, as an
+//! alternative to OrbStack (`vm.provider = "lima"`).
+
+use crate::config::schema::VmConfig;
+use crate::error::{MinoError, MinoResult};
+use crate::orchestration::lima::Lima;
+use crate::orchestration::podman::{build_args, ContainerConfig, PodSpec, PullPolicy};
+use crate::orchestration::runtime::{ContainerRuntime, ContainerUsage, VolumeInfo};
+use crate::redact::redact_args;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::path::Path;
+use tokio::sync::OnceCell;
+use tracing::{debug, warn};
+
+/// Container runtime using a Lima VM + Podman (for macOS)
+pub struct LimaRuntime {
+    lima: Lima,
+    /// Whether `podman pull` accepts `--retry` (probed once)
+    pull_retry: OnceCell<bool>,
+}
+
+impl LimaRuntime {
+    /// Create a new Lima runtime
+    pub fn new(vm_config: VmConfig) -> Self {
+        Self {
+            lima: Lima::new(vm_config),
+            pull_retry: OnceCell::new(),
+        }
+    }
+
+    /// Check if Podman is available in the VM
+    async fn podman_available(&self) -> MinoResult<bool> {
+        let output = self.lima.exec(&["which", "podman"]).await?;
+        Ok(output.status.success())
+    }
+
+    /// Install Podman in the VM if not present
+    async fn ensure_podman(&self) -> MinoResult<()> {
+        if self.podman_available().await? {
+            return Ok(());
+        }
+
+        debug!("Installing Podman in VM...");
+
+        // Try to install based on distro
+        let install_result = self
+            .lima
+            .exec(&["sudo", "dnf", "install", "-y", "podman"])
+            .await?;
+
+        if !install_result.status.success() {
+            // Try apt as fallback
+            let apt_result = self
+                .lima
+                .exec(&["sudo", "apt-get", "install", "-y", "podman"])
+                .await?;
+
+            if !apt_result.status.success() {
+                return Err(MinoError::PodmanNotFound);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Ensure rootless Podman is configured (subuid/subgid mappings exist)
+    async fn ensure_rootless(&self) -> MinoResult<()> {
+        let whoami_output = self.lima.exec(&["whoami"]).await?;
+        if !whoami_output.status.success() {
+            return Err(MinoError::PodmanRootlessSetup {
+                reason: "could not determine VM username".to_string(),
+            });
+        }
+        let username = String::from_utf8_lossy(&whoami_output.stdout)
+            .trim()
+            .to_string();
+
+        // Validate username to prevent shell injection via interpolated commands
+        if username.is_empty()
+            || !username
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-' || c == '.')
+        {
+            return Err(MinoError::PodmanRootlessSetup {
+                reason: format!("invalid VM username: '{}'", username),
+            });
+        }
+
+        let grep_pattern = format!("^{}:", username);
+        let mapping_files = ["/etc/subuid", "/etc/subgid"];
+
+        let mut needs_configure = false;
+        for file in &mapping_files {
+            let check = self.lima.exec(&["grep", "-q", &grep_pattern, file]).await?;
+
+            if check.status.success() {
+                continue;
+            }
+
+            needs_configure = true;
+            debug!(
+                "Adding subordinate ID mapping for '{}' in {}",
+                username, file
+            );
+
+            let cmd = format!("echo '{}:100000:65536' | sudo tee -a {}", username, file);
+            let result = self.lima.exec(&["sh", "-c", &cmd]).await?;
+            if !result.status.success() {
+                return Err(MinoError::PodmanRootlessSetup {
+                    reason: format!("failed to configure {}", file),
+                });
+            }
+        }
+
+        if !needs_configure {
+            return Ok(());
+        }
+
+        let migrate = self.lima.exec(&["podman", "system", "migrate"]).await?;
+        if !migrate.status.success() {
+            return Err(MinoError::PodmanRootlessSetup {
+                reason: "podman system migrate failed".to_string(),
+            });
+        }
+
+        debug!("Rootless Podman configured for '{}'", username);
+        Ok(())
+    }
+
+    /// Whether the installed podman supports `pull --retry` (cached)
+    async fn supports_pull_retry(&self) -> bool {
+        *self
+            .pull_retry
+            .get_or_init(|| async {
+                match self.lima.exec(&["podman", "pull", "--help"]).await {
+                    Ok(output) => {
+                        super::pull_help_has_retry(&String::from_utf8_lossy(&output.stdout))
+                    }
+                    Err(_) => false,
+                }
+            })
+            .await
+    }
+
+    /// Pull an image, retrying interrupted downloads
+    async fn pull(&self, image: &str) -> MinoResult<()> {
+        debug!("Pulling image: {}", image);
+
+        let plan = super::podman_pull_plan(image, self.supports_pull_retry().await, None);
+        let args: Vec<&str> = std::iter::once("podman")
+            .chain(plan.args.iter().map(String::as_str))
+            .collect();
+        let args = &args;
+
+        super::pull_with_retry(image, plan.attempts, || async move {
+            let output = self.lima.exec(args).await?;
+
+            if output.status.success() {
+                Ok(())
+            } else {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                Err(MinoError::ImagePull {
+                    image: image.to_string(),
+                    reason: stderr.to_string(),
+                })
+            }
+        })
+        .await
+    }
+}
+
+#[async_trait]
+impl ContainerRuntime for LimaRuntime {
+    async fn is_available(&self) -> MinoResult<bool> {
+        if !Lima::is_installed().await {
+            return Ok(false);
+        }
+        if self.lima.vm_status().await? != "Running" {
+            return Ok(false);
+        }
+        self.podman_available().await
+    }
+
+    async fn ensure_ready(&self) -> MinoResult<()> {
+        self.lima.ensure_vm_running().await?;
+        self.ensure_podman().await?;
+        self.ensure_rootless().await
+    }
+
+    async fn check_device(&self, path: &str) -> MinoResult<()> {
+        let output = self.lima.exec(&["test", "-e", path]).await?;
+        super::device_check(output.status.success(), path, "the Lima VM")
+    }
+
+    async fn check_gpu_support(&self) -> MinoResult<()> {
+        Err(MinoError::GpuUnsupported {
+            runtime: self.runtime_name().to_string(),
+            reason: "macOS VMs cannot pass NVIDIA GPUs through to containers".to_string(),
+        })
+    }
+
+    async fn run(&self, config: &ContainerConfig, command: &[String]) -> MinoResult<String> {
+        // Ensure image is available
+        if !self.image_exists(&config.image).await? {
+            self.pull(&config.image).await?;
+        }
+
+        let mut args = vec!["podman".to_string(), "run".to_string(), "-d".to_string()];
+
+        if config.interactive {
+            args.push("-i".to_string());
+        }
+        if config.tty {
+            args.push("-t".to_string());
+        }
+
+        config.push_args(&mut args, command);
+
+        debug!("Running container (detached): {:?}", redact_args(&args));
+
+        let args_refs: Vec<&str> = args.iter().map(String::as_str).collect();
+        let output = self.lima.exec(&args_refs).await?;
+
+        if output.status.success() {
+            let container_id = String::from_utf8_lossy(&output.stdout).trim().to_string();
+            debug!(
+                "Container started: {}",
+                &container_id[..12.min(container_id.len())]
+            );
+            Ok(container_id)
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::ContainerStart(stderr.to_string()))
+        }
+    }
+
+    async fn create(&self, config: &ContainerConfig, command: &[String]) -> MinoResult<String> {
+        // Ensure image is available
+        if !self.image_exists(&config.image).await? {
+            self.pull(&config.image).await?;
+        }
+
+        let mut args = vec!["podman".to_string(), "create".to_string()];
+
+        if config.interactive {
+            args.push("-i".to_string());
+        }
+        if config.tty {
+            args.push("-t".to_string());
+        }
+
+        config.push_args(&mut args, command);
+
+        debug!("Creating container: {:?}", redact_args(&args));
+
+        let args_refs: Vec<&str> = args.iter().map(String::as_str).collect();
+        let output = self.lima.exec(&args_refs).await?;
+
+        if output.status.success() {
+            let container_id = String::from_utf8_lossy(&output.stdout).trim().to_string();
+            debug!(
+                "Container created: {}",
+                &container_id[..12.min(container_id.len())]
+            );
+            Ok(container_id)
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::ContainerStart(stderr.to_string()))
+        }
+    }
+
+    async fn start_attached(&self, container_id: &str) -> MinoResult<i32> {
+        debug!("Starting container attached: {}", container_id);
+
+        let exit_code = self
+            .lima
+            .exec_interactive(&["podman", "start", "--attach", container_id])
+            .await?;
+
+        Ok(exit_code)
+    }
+
+    async fn stop(&self, container_id: &str) -> MinoResult<()> {
+        debug!("Stopping container: {}", container_id);
+
+        let output = self.lima.exec(&["podman", "stop", container_id]).await?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec("podman stop", stderr))
+        }
+    }
+
+    async fn kill(&self, container_id: &str) -> MinoResult<()> {
+        debug!("Killing container: {}", container_id);
+
+        let output = self.lima.exec(&["podman", "kill", container_id]).await?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec("podman kill", stderr))
+        }
+    }
+
+    async fn signal(&self, container_id: &str, signal: &str) -> MinoResult<()> {
+        debug!("Sending {} to container: {}", signal, container_id);
+
+        let output = self
+            .lima
+            .exec(&["podman", "kill", "--signal", signal, container_id])
+            .await?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec("podman kill", stderr))
+        }
+    }
+
+    async fn pause(&self, container_id: &str) -> MinoResult<()> {
+        debug!("Pausing container: {}", container_id);
+
+        let output = self.lima.exec(&["podman", "pause", container_id]).await?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec("podman pause", stderr))
+        }
+    }
+
+    async fn unpause(&self, container_id: &str) -> MinoResult<()> {
+        debug!("Unpausing container: {}", container_id);
+
+        let output = self.lima.exec(&["podman", "unpause", container_id]).await?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec("podman unpause", stderr))
+        }
+    }
+
+    async fn checkpoint(
+        &self,
+        container_id: &str,
+        archive: &Path,
+        leave_running: bool,
+    ) -> MinoResult<()> {
+        debug!(
+            "Checkpointing container {} to {}",
+            container_id,
+            archive.display()
+        );
+        let archive = archive.display().to_string();
+        let mut args = vec!["podman"];
+        args.extend(super::checkpoint_args(
+            container_id,
+            &archive,
+            leave_running,
+        ));
+        let output = self.lima.exec(&args).await?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(super::checkpoint_error(
+                self.runtime_name(),
+                "podman container checkpoint",
+                &stderr,
+            ))
+        }
+    }
+
+    async fn restore(&self, archive: &Path) -> MinoResult<String> {
+        debug!("Restoring container from {}", archive.display());
+        let archive = archive.display().to_string();
+        let output = self
+            .lima
+            .exec(&["podman", "container", "restore", "--import", &archive])
+            .await?;
+
+        if output.status.success() {
+            super::parse_restored_id(&String::from_utf8_lossy(&output.stdout))
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(super::checkpoint_error(
+                self.runtime_name(),
+                "podman container restore",
+                &stderr,
+            ))
+        }
+    }
+
+    async fn remove(&self, container_id: &str) -> MinoResult<()> {
+        debug!("Removing container: {}", container_id);
+
+        let output = self
+            .lima
+            .exec(&["podman", "rm", "-f", container_id])
+            .await?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            // Ignore error if container doesn't exist
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            if stderr.contains("no such container") {
+                Ok(())
+            } else {
+                Err(MinoError::command_exec("podman rm", stderr))
+            }
+        }
+    }
+
+    async fn container_prune(&self) -> MinoResult<()> {
+        let output = self
+            .lima
+            .exec(&["podman", "container", "prune", "-f"])
+            .await?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(MinoError::command_exec("podman container prune", stderr));
+        }
+        Ok(())
+    }
+
+    async fn container_list_labeled(&self, label: &str) -> MinoResult<Vec<String>> {
+        let filter = format!("label={}", label);
+        let output = self
+            .lima
+            .exec(&[
+                "podman", "ps", "-a", "--filter", &filter, "--format", "{{.ID}}",
+            ])
+            .await?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(MinoError::command_exec("podman ps", stderr));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        Ok(stdout
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(String::from)
+            .collect())
+    }
+
+    async fn logs(&self, container_id: &str, lines: u32) -> MinoResult<String> {
+        let tail_arg = if lines == 0 {
+            "all".to_string()
+        } else {
+            lines.to_string()
+        };
+
+        let output = self
+            .lima
+            .exec(&["podman", "logs", "--tail", &tail_arg, container_id])
+            .await?;
+
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    }
+
+    async fn logs_follow(&self, container_id: &str) -> MinoResult<()> {
+        self.lima
+            .exec_interactive(&["podman", "logs", "-f", container_id])
+            .await?;
+        Ok(())
+    }
+
+    async fn logs_follow_lines(
+        &self,
+        container_id: &str,
+        lines: u32,
+        on_line: &(dyn Fn(String) + Send + Sync),
+    ) -> MinoResult<()> {
+        let tail_arg = if lines == 0 {
+            "all".to_string()
+        } else {
+            lines.to_string()
+        };
+
+        let mut child =
+            self.lima
+                .spawn_piped(&["podman", "logs", "-f", "--tail", &tail_arg, container_id])?;
+
+        super::forward_child_lines(&mut child, on_line).await;
+        Ok(())
+    }
+
+    async fn image_exists(&self, image: &str) -> MinoResult<bool> {
+        let output = self
+            .lima
+            .exec(&["podman", "image", "exists", image])
+            .await?;
+        Ok(output.status.success())
+    }
+
+    async fn image_pull(&self, image: &str) -> MinoResult<()> {
+        self.pull(image).await
+    }
+
+    async fn image_pull_with_progress(
+        &self,
+        image: &str,
+        platform: Option<&str>,
+        on_output: &(dyn Fn(String) + Send + Sync),
+    ) -> MinoResult<()> {
+        let plan = super::podman_pull_plan(image, self.supports_pull_retry().await, platform);
+        let args: Vec<&str> = std::iter::once("podman")
+            .chain(plan.args.iter().map(String::as_str))
+            .collect();
+        let args = &args;
+
+        super::pull_with_retry(image, plan.attempts, || async move {
+            let mut child = self.lima.spawn_piped(args)?;
+            super::stream_pull(&mut child, image, on_output).await
+        })
+        .await
+    }
+
+    async fn image_platform(&self, image: &str) -> MinoResult<Option<String>> {
+        let output = self
+            .lima
+            .exec(&[
+                "podman",
+                "image",
+                "inspect",
+                "--format",
+                super::IMAGE_PLATFORM_FORMAT,
+                image,
+            ])
+            .await?;
+        if !output.status.success() {
+            return Ok(None);
+        }
+        Ok(super::parse_image_platform(&String::from_utf8_lossy(
+            &output.stdout,
+        )))
+    }
+
+    async fn image_user(&self, image: &str) -> MinoResult<Option<String>> {
+        if !self.image_exists(image).await? {
+            self.pull(image).await?;
+        }
+
+        let output = self
+            .lima
+            .exec(&[
+                "podman",
+                "image",
+                "inspect",
+                "--format",
+                "{{.Config.User}}",
+                image,
+            ])
+            .await?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(MinoError::command_exec("podman image inspect", stderr));
+        }
+
+        let user = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        Ok(if user.is_empty() { None } else { Some(user) })
+    }
+
+    async fn build_image(&self, context_dir: &Path, tag: &str, pull: PullPolicy) -> MinoResult<()> {
+        let build = build_args(tag, &context_dir.display().to_string(), pull);
+        let args: Vec<&str> = std::iter::once("podman")
+            .chain(build.iter().map(String::as_str))
+            .collect();
+        let output = self.lima.exec(&args).await?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            let combined = super::build_error_output(&stdout, &stderr);
+            return Err(MinoError::ImageBuild {
+                tag: tag.to_string(),
+                reason: combined,
+            });
+        }
+
+        Ok(())
+    }
+
+    async fn build_image_with_progress(
+        &self,
+        context_dir: &Path,
+        tag: &str,
+        pull: PullPolicy,
+        on_output: &(dyn Fn(String) + Send + Sync),
+    ) -> MinoResult<()> {
+        let build = build_args(tag, &context_dir.display().to_string(), pull);
+        let args: Vec<&str> = std::iter::once("podman")
+            .chain(build.iter().map(String::as_str))
+            .collect();
+        let mut child = self.lima.spawn_piped(&args)?;
+
+        let all_output = super::stream_child_output(&mut child, on_output).await;
+
+        let status = child
+            .wait()
+            .await
+            .map_err(|e| MinoError::command_failed("podman build", e))?;
+
+        if !status.success() {
+            let combined = all_output.join("\n");
+            let tail = super::build_error_output(&combined, "");
+            return Err(MinoError::ImageBuild {
+                tag: tag.to_string(),
+                reason: tail,
+            });
+        }
+
+        Ok(())
+    }
+
+    async fn image_remove(&self, image: &str) -> MinoResult<()> {
+        let output = self.lima.exec(&["podman", "rmi", image]).await?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            if stderr.contains("image not known") {
+                Ok(())
+            } else {
+                Err(MinoError::command_exec("podman rmi", stderr))
+            }
+        }
+    }
+
+    async fn image_list_prefixed(&self, prefix: &str) -> MinoResult<Vec<String>> {
+        let filter = format!("reference={}*", prefix);
+        let output = self
+            .lima
+            .exec(&[
+                "podman",
+                "images",
+                "--filter",
+                &filter,
+                "--format",
+                "{{.Repository}}:{{.Tag}}",
+            ])
+            .await?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(MinoError::command_exec("podman images", stderr));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let images: Vec<String> = stdout
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(String::from)
+            .collect();
+
+        Ok(images)
+    }
+
+    async fn image_list_labeled(&self, label: &str) -> MinoResult<Vec<String>> {
+        let filter = format!("label={}", label);
+        let output = self
+            .lima
+            .exec(&[
+                "podman",
+                "images",
+                "--filter",
+                &filter,
+                "--format",
+                "{{.Repository}}:{{.Tag}}",
+            ])
+            .await?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(MinoError::command_exec("podman images", stderr));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let images: Vec<String> = stdout
+            .lines()
+            // Untagged images carrying the label show up as <none>:<none>
+            .filter(|line| !line.is_empty() && !line.contains("<none>"))
+            .map(String::from)
+            .collect();
+
+        Ok(images)
+    }
+
+    fn runtime_name(&self) -> &'static str {
+        "Lima + Podman"
+    }
+
+    async fn volume_create(&self, name: &str, labels: &HashMap<String, String>) -> MinoResult<()> {
+        debug!("Creating volume: {}", name);
+
+        let mut args = vec!["podman", "volume", "create", "--ignore"];
+
+        // Build label arguments
+        let label_strings: Vec<String> =
+            labels.iter().map(|(k, v)| format!("{}={}", k, v)).collect();
+
+        for label in &label_strings {
+            args.push("--label");
+            args.push(label);
+        }
+
+        args.push(name);
+
+        let output = self.lima.exec(&args).await?;
+
+        if output.status.success() {
+            debug!("Volume created: {}", name);
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec("podman volume create", stderr))
+        }
+    }
+
+    async fn network_create(&self, name: &str) -> MinoResult<()> {
+        debug!("Creating network: {}", name);
+
+        let output = self
+            .lima
+            .exec(&["podman", "network", "create", "--ignore", name])
+            .await?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec("podman network create", stderr))
+        }
+    }
+
+    async fn volume_remove(&self, name: &str) -> MinoResult<()> {
+        debug!("Removing volume: {}", name);
+
+        let output = self
+            .lima
+            .exec(&["podman", "volume", "rm", "-f", name])
+            .await?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            // Ignore "no such volume" errors
+            if stderr.contains("no such volume") {
+                Ok(())
+            } else {
+                Err(MinoError::command_exec("podman volume rm", stderr))
+            }
+        }
+    }
+
+    async fn volume_list(&self, label: &str) -> MinoResult<Vec<VolumeInfo>> {
+        let output = self
+            .lima
+            .exec(&["podman", "volume", "ls", "--format", "json"])
+            .await?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(MinoError::command_exec("podman volume ls", stderr));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        super::parse_volume_list_json(&stdout, label)
+    }
+
+    async fn volume_inspect(&self, name: &str) -> MinoResult<Option<VolumeInfo>> {
+        let output = self
+            .lima
+            .exec(&["podman", "volume", "inspect", name, "--format", "json"])
+            .await?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            if stderr.contains("no such volume") {
+                return Ok(None);
+            }
+            return Err(MinoError::command_exec("podman volume inspect", stderr));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        super::parse_volume_inspect_json(&stdout, name)
+    }
+
+    async fn volume_export(&self, name: &str, archive: &Path) -> MinoResult<()> {
+        debug!("Exporting volume {} to {}", name, archive.display());
+        let archive = archive.display().to_string();
+        let mut args = vec!["podman"];
+        args.extend(super::volume_export_args(name, &archive));
+        let output = self.lima.exec(&args).await?;
+        super::registry_output_result("podman volume export", &output)
+    }
+
+    fn supports_volume_archives(&self) -> bool {
+        true
+    }
+
+    async fn volume_import(&self, name: &str, archive: &Path) -> MinoResult<()> {
+        debug!("Importing {} into volume {}", archive.display(), name);
+        let archive = archive.display().to_string();
+        let mut args = vec!["podman"];
+        args.extend(super::volume_import_args(name, &archive));
+        let output = self.lima.exec(&args).await?;
+        super::registry_output_result("podman volume import", &output)
+    }
+
+    async fn volume_disk_usage(&self, label: &str) -> MinoResult<HashMap<String, u64>> {
+        // Get volume sizes by inspecting each volume individually.
+        // Note: `podman system df -v --format json` is not supported (flags conflict).
+        let volumes = self.volume_list(label).await?;
+
+        let futures = volumes.into_iter().map(|vol| async move {
+            let output = self
+                .lima
+                .exec(&[
+                    "podman",
+                    "volume",
+                    "inspect",
+                    &vol.name,
+                    "--format",
+                    "{{.Mountpoint}}",
+                ])
+                .await?;
+
+            if !output.status.success() {
+                return Ok(None);
+            }
+
+            let mountpoint = String::from_utf8_lossy(&output.stdout).trim().to_string();
+            if mountpoint.is_empty() {
+                return Ok(None);
+            }
+
+            let du_output = self.lima.exec(&["du", "-sb", &mountpoint]).await?;
+
+            let size = du_output
+                .status
+                .success()
+                .then(|| super::parse_du_bytes(&du_output.stdout))
+                .flatten();
+
+            Ok(size.map(|s| (vol.name.clone(), s)))
+        });
+
+        super::measure_volumes(futures).await
+    }
+
+    async fn exec_in_container(
+        &self,
+        container_id: &str,
+        command: &[String],
+        tty: bool,
+    ) -> MinoResult<i32> {
+        debug!("Exec into container: {}", container_id);
+        let mut args = vec!["podman", "exec", "-i"];
+        if tty {
+            args.push("-t");
+        }
+        args.push(container_id);
+        args.extend(command.iter().map(String::as_str));
+        self.lima.exec_interactive(&args).await
+    }
+
+    async fn copy_to_container(
+        &self,
+        container_id: &str,
+        host_path: &Path,
+        container_path: &str,
+    ) -> MinoResult<()> {
+        debug!("Copying {} into {}", host_path.display(), container_id);
+        let source = host_path.display().to_string();
+        let target = format!("{}:{}", container_id, container_path);
+        let output = self.lima.exec(&["podman", "cp", &source, &target]).await?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec("podman cp", stderr))
+        }
+    }
+
+    async fn copy_from_container(
+        &self,
+        container_id: &str,
+        container_path: &str,
+        host_path: &Path,
+    ) -> MinoResult<()> {
+        debug!("Copying {} out of {}", container_path, container_id);
+        let source = format!("{}:{}", container_id, container_path);
+        let target = host_path.display().to_string();
+        let output = self.lima.exec(&["podman", "cp", &source, &target]).await?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec("podman cp", stderr))
+        }
+    }
+
+    async fn get_container_exit_code(&self, container_id: &str) -> MinoResult<Option<i32>> {
+        debug!("Waiting for container exit: {}", container_id);
+
+        let output = self.lima.exec(&["podman", "wait", container_id]).await?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            if stderr.contains("no such container") {
+                return Ok(None);
+            }
+            return Err(MinoError::command_exec("podman wait", stderr));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        match stdout.trim().parse::<i32>() {
+            Ok(code) => Ok(Some(code)),
+            Err(_) => {
+                warn!(
+                    "Could not parse exit code from podman wait: {:?}",
+                    stdout.trim()
+                );
+                Ok(None)
+            }
+        }
+    }
+
+    async fn start_detached(&self, container_id: &str) -> MinoResult<()> {
+        debug!("Starting container detached: {}", container_id);
+        let output = self.lima.exec(&["podman", "start", container_id]).await?;
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::ContainerStart(stderr.to_string()))
+        }
+    }
+
+    async fn container_restart_count(&self, container_id: &str) -> MinoResult<Option<u32>> {
+        let output = self
+            .lima
+            .exec(&[
+                "podman",
+                "inspect",
+                "--format",
+                "{{.RestartCount}}",
+                container_id,
+            ])
+            .await?;
+        super::parse_restart_count(&output, "podman inspect")
+    }
+
+    async fn container_is_running(&self, container_id: &str) -> MinoResult<bool> {
+        let output = self
+            .lima
+            .exec(&[
+                "podman",
+                "inspect",
+                "--format",
+                "{{.State.Running}}",
+                container_id,
+            ])
+            .await?;
+        super::parse_running(&output, "podman inspect")
+    }
+
+    async fn attach(&self, container_id: &str) -> MinoResult<i32> {
+        debug!("Attaching to container: {}", container_id);
+        self.lima
+            .exec_interactive(&["podman", "attach", container_id])
+            .await
+    }
+
+    async fn registry_login(
+        &self,
+        registry: &str,
+        username: Option<&str>,
+        password_stdin: bool,
+    ) -> MinoResult<()> {
+        debug!("Logging in to registry: {}", registry);
+        let mut args = vec!["podman".to_string()];
+        args.extend(super::registry_login_args(
+            registry,
+            username,
+            password_stdin,
+        ));
+        let args_refs: Vec<&str> = args.iter().map(String::as_str).collect();
+        let code = self.lima.exec_interactive(&args_refs).await?;
+        super::registry_command_result("podman login", registry, code)
+    }
+
+    async fn registry_login_password(
+        &self,
+        registry: &str,
+        username: &str,
+        password: &str,
+    ) -> MinoResult<()> {
+        debug!("Logging in to registry: {}", registry);
+        let mut args = vec!["podman".to_string()];
+        args.extend(super::registry_login_args(registry, Some(username), true));
+        let args_refs: Vec<&str> = args.iter().map(String::as_str).collect();
+        let output = self
+            .lima
+            .exec_with_input(&args_refs, password.as_bytes())
+            .await?;
+        super::registry_output_result("podman login", &output)
+    }
+
+    async fn registry_logout(&self, registry: &str) -> MinoResult<()> {
+        debug!("Logging out of registry: {}", registry);
+        let code = self
+            .lima
+            .exec_interactive(&["podman", "logout", registry])
+            .await?;
+        super::registry_command_result("podman logout", registry, code)
+    }
+
+    async fn pod_create(&self, spec: &PodSpec) -> MinoResult<()> {
+        debug!("Creating pod: {}", spec.name);
+        let args = spec.create_args();
+        let args_refs: Vec<&str> = args.iter().map(String::as_str).collect();
+        let output = {
+            let mut podman_args = vec!["podman"];
+            podman_args.extend(args_refs);
+            self.lima.exec(&podman_args).await?
+        };
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec("podman pod create", stderr))
+        }
+    }
+
+    async fn pod_remove(&self, name: &str) -> MinoResult<()> {
+        debug!("Removing pod: {}", name);
+        let output = self
+            .lima
+            .exec(&["podman", "pod", "rm", "--force", "--ignore", name])
+            .await?;
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec("podman pod rm", stderr))
+        }
+    }
+
+    async fn container_usage(&self, container_id: &str) -> MinoResult<ContainerUsage> {
+        let mut args = vec!["podman"];
+        args.extend_from_slice(&super::usage_stats_args(container_id));
+        let output = self.lima.exec(&args).await?;
+        super::parse_usage(&output, "podman stats")
+    }
+
+    async fn logs_follow_until(
+        &self,
+        container_id: &str,
+        marker: &str,
+        timeout: std::time::Duration,
+        on_line: &(dyn Fn(String) + Send + Sync),
+    ) -> MinoResult<bool> {
+        debug!("Following logs for {} until '{}'", container_id, marker);
+
+        let mut child = self
+            .lima
+            .spawn_piped(&["podman", "logs", "-f", container_id])?;
+
+        Ok(super::follow_until_marker(&mut child, marker, timeout, on_line).await)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lima_runtime_new() {
+        let config = VmConfig::default();
+        let runtime = LimaRuntime::new(config);
+        assert_eq!(runtime.runtime_name(), "Lima + Podman");
+    }
+}
@@ -1,15 +1,18 @@
 //! Mock container runtime for unit testing
 //!
 //! Provides a configurable test double for `ContainerRuntime` that records
-//! calls and returns queued or default responses.
+//! calls and returns queued or default responses, and a recording
+//! `CommandExecutor` for exercising the CLI runtimes end to end.
 
 use crate::error::{MinoError, MinoResult};
-use crate::orchestration::podman::ContainerConfig;
-use crate::orchestration::runtime::{ContainerRuntime, VolumeInfo};
+use crate::orchestration::executor::CommandExecutor;
+use crate::orchestration::podman::{ContainerConfig, PodSpec, PullPolicy};
+use crate::orchestration::runtime::{ContainerRuntime, ContainerUsage, VolumeInfo};
 use crate::session::{Session, SessionStatus};
 use async_trait::async_trait;
 use std::collections::{HashMap, VecDeque};
 use std::path::{Path, PathBuf};
+use std::process::{ExitStatus, Output};
 use std::sync::Mutex;
 
 /// A queued response for a mock method call.
@@ -235,6 +238,16 @@ impl ContainerRuntime for MockRuntime {
         self.take_unit("ensure_ready")
     }
 
+    async fn check_device(&self, path: &str) -> MinoResult<()> {
+        self.record("check_device", vec![path.to_string()]);
+        self.take_unit("check_device")
+    }
+
+    async fn check_gpu_support(&self) -> MinoResult<()> {
+        self.record("check_gpu_support", vec![]);
+        self.take_unit("check_gpu_support")
+    }
+
     async fn run(&self, config: &ContainerConfig, command: &[String]) -> MinoResult<String> {
         let args = std::iter::once(&config.image)
             .chain(command)
@@ -268,6 +281,43 @@ impl ContainerRuntime for MockRuntime {
         self.take_unit("kill")
     }
 
+    async fn signal(&self, container_id: &str, signal: &str) -> MinoResult<()> {
+        self.record("signal", vec![container_id.to_string(), signal.to_string()]);
+        self.take_unit("signal")
+    }
+
+    async fn pause(&self, container_id: &str) -> MinoResult<()> {
+        self.record("pause", vec![container_id.to_string()]);
+        self.take_unit("pause")
+    }
+
+    async fn unpause(&self, container_id: &str) -> MinoResult<()> {
+        self.record("unpause", vec![container_id.to_string()]);
+        self.take_unit("unpause")
+    }
+
+    async fn checkpoint(
+        &self,
+        container_id: &str,
+        archive: &Path,
+        leave_running: bool,
+    ) -> MinoResult<()> {
+        self.record(
+            "checkpoint",
+            vec![
+                container_id.to_string(),
+                archive.display().to_string(),
+                leave_running.to_string(),
+            ],
+        );
+        self.take_unit("checkpoint")
+    }
+
+    async fn restore(&self, archive: &Path) -> MinoResult<String> {
+        self.record("restore", vec![archive.display().to_string()]);
+        self.take_string("restore", "mock-restored-id")
+    }
+
     async fn remove(&self, container_id: &str) -> MinoResult<()> {
         self.record("remove", vec![container_id.to_string()]);
         self.take_unit("remove")
@@ -278,6 +328,11 @@ impl ContainerRuntime for MockRuntime {
         self.take_unit("container_prune")
     }
 
+    async fn container_list_labeled(&self, label: &str) -> MinoResult<Vec<String>> {
+        self.record("container_list_labeled", vec![label.to_string()]);
+        self.take_string_vec("container_list_labeled")
+    }
+
     async fn logs(&self, container_id: &str, lines: u32) -> MinoResult<String> {
         self.record("logs", vec![container_id.to_string(), lines.to_string()]);
         self.take_string("logs", "")
@@ -288,12 +343,65 @@ impl ContainerRuntime for MockRuntime {
         self.take_unit("logs_follow")
     }
 
+    async fn logs_follow_lines(
+        &self,
+        container_id: &str,
+        lines: u32,
+        on_line: &(dyn Fn(String) + Send + Sync),
+    ) -> MinoResult<()> {
+        self.record(
+            "logs_follow_lines",
+            vec![container_id.to_string(), lines.to_string()],
+        );
+        let output = self.take_string("logs_follow_lines", "")?;
+        output.lines().for_each(|line| on_line(line.to_string()));
+        Ok(())
+    }
+
     async fn image_exists(&self, image: &str) -> MinoResult<bool> {
         self.record("image_exists", vec![image.to_string()]);
         self.take_bool("image_exists", false)
     }
 
-    async fn build_image(&self, _context_dir: &Path, tag: &str) -> MinoResult<()> {
+    async fn image_pull(&self, image: &str) -> MinoResult<()> {
+        self.record("image_pull", vec![image.to_string()]);
+        self.take_unit("image_pull")
+    }
+
+    async fn image_pull_with_progress(
+        &self,
+        image: &str,
+        platform: Option<&str>,
+        _on_output: &(dyn Fn(String) + Send + Sync),
+    ) -> MinoResult<()> {
+        let mut args = vec![image.to_string()];
+        args.extend(platform.map(String::from));
+        self.record("image_pull_with_progress", args);
+        self.take_unit("image_pull_with_progress")
+    }
+
+    async fn image_platform(&self, image: &str) -> MinoResult<Option<String>> {
+        self.record("image_platform", vec![image.to_string()]);
+        let platform = self.take_string("image_platform", "")?;
+        Ok(if platform.is_empty() {
+            None
+        } else {
+            Some(platform)
+        })
+    }
+
+    async fn image_user(&self, image: &str) -> MinoResult<Option<String>> {
+        self.record("image_user", vec![image.to_string()]);
+        let user = self.take_string("image_user", "")?;
+        Ok(if user.is_empty() { None } else { Some(user) })
+    }
+
+    async fn build_image(
+        &self,
+        _context_dir: &Path,
+        tag: &str,
+        _pull: PullPolicy,
+    ) -> MinoResult<()> {
         self.record("build_image", vec![tag.to_string()]);
         self.take_unit("build_image")
     }
@@ -302,9 +410,13 @@ impl ContainerRuntime for MockRuntime {
         &self,
         _context_dir: &Path,
         tag: &str,
+        pull: PullPolicy,
         on_output: &(dyn Fn(String) + Send + Sync),
     ) -> MinoResult<()> {
-        self.record("build_image_with_progress", vec![tag.to_string()]);
+        self.record(
+            "build_image_with_progress",
+            vec![tag.to_string(), pull.to_string()],
+        );
         on_output("STEP 1: mock build".to_string());
         self.take_unit("build_image_with_progress")
     }
@@ -319,6 +431,11 @@ impl ContainerRuntime for MockRuntime {
         self.take_string_vec("image_list_prefixed")
     }
 
+    async fn image_list_labeled(&self, label: &str) -> MinoResult<Vec<String>> {
+        self.record("image_list_labeled", vec![label.to_string()]);
+        self.take_string_vec("image_list_labeled")
+    }
+
     fn runtime_name(&self) -> &'static str {
         "mock"
     }
@@ -335,13 +452,18 @@ impl ContainerRuntime for MockRuntime {
         self.take_unit("volume_create")
     }
 
+    async fn network_create(&self, name: &str) -> MinoResult<()> {
+        self.record("network_create", vec![name.to_string()]);
+        self.take_unit("network_create")
+    }
+
     async fn volume_remove(&self, name: &str) -> MinoResult<()> {
         self.record("volume_remove", vec![name.to_string()]);
         self.take_unit("volume_remove")
     }
 
-    async fn volume_list(&self, prefix: &str) -> MinoResult<Vec<VolumeInfo>> {
-        self.record("volume_list", vec![prefix.to_string()]);
+    async fn volume_list(&self, label: &str) -> MinoResult<Vec<VolumeInfo>> {
+        self.record("volume_list", vec![label.to_string()]);
         self.take_volume_info_vec("volume_list")
     }
 
@@ -350,8 +472,28 @@ impl ContainerRuntime for MockRuntime {
         self.take_optional_volume_info("volume_inspect")
     }
 
-    async fn volume_disk_usage(&self, prefix: &str) -> MinoResult<HashMap<String, u64>> {
-        self.record("volume_disk_usage", vec![prefix.to_string()]);
+    async fn volume_export(&self, name: &str, archive: &Path) -> MinoResult<()> {
+        self.record(
+            "volume_export",
+            vec![name.to_string(), archive.display().to_string()],
+        );
+        self.take_unit("volume_export")
+    }
+
+    fn supports_volume_archives(&self) -> bool {
+        true
+    }
+
+    async fn volume_import(&self, name: &str, archive: &Path) -> MinoResult<()> {
+        self.record(
+            "volume_import",
+            vec![name.to_string(), archive.display().to_string()],
+        );
+        self.take_unit("volume_import")
+    }
+
+    async fn volume_disk_usage(&self, label: &str) -> MinoResult<HashMap<String, u64>> {
+        self.record("volume_disk_usage", vec![label.to_string()]);
         self.take_disk_usage_map("volume_disk_usage")
     }
 
@@ -367,6 +509,40 @@ impl ContainerRuntime for MockRuntime {
         self.take_int("exec_in_container", 0)
     }
 
+    async fn copy_to_container(
+        &self,
+        container_id: &str,
+        host_path: &Path,
+        container_path: &str,
+    ) -> MinoResult<()> {
+        self.record(
+            "copy_to_container",
+            vec![
+                container_id.to_string(),
+                host_path.display().to_string(),
+                container_path.to_string(),
+            ],
+        );
+        self.take_unit("copy_to_container")
+    }
+
+    async fn copy_from_container(
+        &self,
+        container_id: &str,
+        container_path: &str,
+        host_path: &Path,
+    ) -> MinoResult<()> {
+        self.record(
+            "copy_from_container",
+            vec![
+                container_id.to_string(),
+                container_path.to_string(),
+                host_path.display().to_string(),
+            ],
+        );
+        self.take_unit("copy_from_container")
+    }
+
     async fn get_container_exit_code(&self, container_id: &str) -> MinoResult<Option<i32>> {
         self.record("get_container_exit_code", vec![container_id.to_string()]);
         self.take_optional_int("get_container_exit_code", Some(0))
@@ -377,6 +553,73 @@ impl ContainerRuntime for MockRuntime {
         self.take_unit("start_detached")
     }
 
+    async fn container_restart_count(&self, container_id: &str) -> MinoResult<Option<u32>> {
+        self.record("container_restart_count", vec![container_id.to_string()]);
+        self.take_optional_int("container_restart_count", Some(0))
+            .map(|count| count.map(|c| c.max(0) as u32))
+    }
+
+    async fn container_is_running(&self, container_id: &str) -> MinoResult<bool> {
+        self.record("container_is_running", vec![container_id.to_string()]);
+        self.take_bool("container_is_running", false)
+    }
+
+    async fn attach(&self, container_id: &str) -> MinoResult<i32> {
+        self.record("attach", vec![container_id.to_string()]);
+        self.take_int("attach", 0)
+    }
+
+    async fn registry_login(
+        &self,
+        registry: &str,
+        username: Option<&str>,
+        password_stdin: bool,
+    ) -> MinoResult<()> {
+        self.record(
+            "registry_login",
+            vec![
+                registry.to_string(),
+                username.unwrap_or_default().to_string(),
+                password_stdin.to_string(),
+            ],
+        );
+        self.take_unit("registry_login")
+    }
+
+    async fn registry_login_password(
+        &self,
+        registry: &str,
+        username: &str,
+        _password: &str,
+    ) -> MinoResult<()> {
+        self.record(
+            "registry_login_password",
+            vec![registry.to_string(), username.to_string()],
+        );
+        self.take_unit("registry_login_password")
+    }
+
+    async fn registry_logout(&self, registry: &str) -> MinoResult<()> {
+        self.record("registry_logout", vec![registry.to_string()]);
+        self.take_unit("registry_logout")
+    }
+
+    async fn pod_create(&self, spec: &PodSpec) -> MinoResult<()> {
+        self.record("pod_create", spec.create_args());
+        self.take_unit("pod_create")
+    }
+
+    async fn pod_remove(&self, name: &str) -> MinoResult<()> {
+        self.record("pod_remove", vec![name.to_string()]);
+        self.take_unit("pod_remove")
+    }
+
+    async fn container_usage(&self, container_id: &str) -> MinoResult<ContainerUsage> {
+        self.record("container_usage", vec![container_id.to_string()]);
+        self.take_unit("container_usage")
+            .map(|()| ContainerUsage::default())
+    }
+
     async fn logs_follow_until(
         &self,
         container_id: &str,
@@ -413,18 +656,163 @@ pub fn test_container_config() -> ContainerConfig {
         volumes: vec![],
         env: HashMap::new(),
         network: "bridge".to_string(),
+        network_aliases: vec![],
         interactive: true,
         tty: true,
         cap_add: vec![],
         cap_drop: vec![],
         security_opt: vec![],
         pids_limit: 0,
+        ulimits: vec![],
+        cpus: 0.0,
+        memory_mb: 0,
+        gpus: false,
+        devices: vec![],
         auto_remove: false,
         read_only: false,
         tmpfs: vec![],
+        user: None,
+        userns: None,
+        restart: None,
+        labels: HashMap::new(),
+        pod: None,
+        platform: None,
+        hostname: None,
+        extra_hosts: vec![],
+    }
+}
+
+/// Canned result of a command run through [`RecordingExecutor`].
+struct ExecResponse {
+    code: i32,
+    stdout: String,
+    stderr: String,
+}
+
+/// Recording `CommandExecutor` for driving CLI runtimes without a container
+/// engine.
+///
+/// Commands are recorded as `program arg...` strings. Queued responses match
+/// by command prefix and are consumed FIFO; unmatched commands succeed with
+/// empty output.
+pub struct RecordingExecutor {
+    rules: Mutex<Vec<(String, VecDeque<ExecResponse>)>>,
+    pub commands: Mutex<Vec<String>>,
+}
+
+impl RecordingExecutor {
+    pub fn new() -> Self {
+        Self {
+            rules: Mutex::new(Vec::new()),
+            commands: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Queue a successful response with `stdout` for commands starting with `prefix`.
+    pub fn on_stdout(self, prefix: &str, stdout: &str) -> Self {
+        self.push(prefix, 0, stdout, "")
+    }
+
+    /// Queue a failing response with `stderr` for commands starting with `prefix`.
+    pub fn on_fail(self, prefix: &str, code: i32, stderr: &str) -> Self {
+        self.push(prefix, code, "", stderr)
+    }
+
+    fn push(self, prefix: &str, code: i32, stdout: &str, stderr: &str) -> Self {
+        let response = ExecResponse {
+            code,
+            stdout: stdout.to_string(),
+            stderr: stderr.to_string(),
+        };
+        {
+            let mut rules = self.rules.lock().unwrap();
+            match rules.iter_mut().find(|(p, _)| p == prefix) {
+                Some((_, queue)) => queue.push_back(response),
+                None => rules.push((prefix.to_string(), VecDeque::from([response]))),
+            }
+        }
+        self
+    }
+
+    /// Assert `command` (`program arg...`) ran at least once.
+    pub fn assert_ran(&self, command: &str) {
+        let commands = self.commands.lock().unwrap();
+        assert!(
+            commands.iter().any(|c| c == command),
+            "expected command '{}', ran: {:?}",
+            command,
+            *commands
+        );
+    }
+
+    /// Assert no command starting with `prefix` ran.
+    pub fn assert_not_ran(&self, prefix: &str) {
+        let commands = self.commands.lock().unwrap();
+        assert!(
+            !commands.iter().any(|c| c.starts_with(prefix)),
+            "expected no command starting with '{}', ran: {:?}",
+            prefix,
+            *commands
+        );
+    }
+
+    fn respond(&self, program: &str, args: &[&str]) -> ExecResponse {
+        let command = std::iter::once(program)
+            .chain(args.iter().copied())
+            .collect::<Vec<_>>()
+            .join(" ");
+        self.commands.lock().unwrap().push(command.clone());
+
+        let mut rules = self.rules.lock().unwrap();
+        rules
+            .iter_mut()
+            .filter(|(prefix, _)| command.starts_with(prefix.as_str()))
+            .find_map(|(_, queue)| queue.pop_front())
+            .unwrap_or(ExecResponse {
+                code: 0,
+                stdout: String::new(),
+                stderr: String::new(),
+            })
+    }
+}
+
+#[async_trait]
+impl CommandExecutor for RecordingExecutor {
+    async fn output(&self, program: &str, args: &[&str]) -> std::io::Result<Output> {
+        let response = self.respond(program, args);
+        Ok(Output {
+            status: exit_status(response.code),
+            stdout: response.stdout.into_bytes(),
+            stderr: response.stderr.into_bytes(),
+        })
+    }
+
+    async fn interactive(&self, program: &str, args: &[&str]) -> std::io::Result<i32> {
+        Ok(self.respond(program, args).code)
+    }
+
+    async fn output_with_input(
+        &self,
+        program: &str,
+        args: &[&str],
+        _input: &[u8],
+    ) -> std::io::Result<Output> {
+        self.output(program, args).await
     }
 }
 
+#[cfg(unix)]
+fn exit_status(code: i32) -> ExitStatus {
+    use std::os::unix::process::ExitStatusExt;
+    ExitStatus::from_raw(code << 8)
+}
+
+#[cfg(windows)]
+fn exit_status(code: i32) -> ExitStatus {
+    use std::os::windows::process::ExitStatusExt;
+    ExitStatus::from_raw(code as u32)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -526,4 +914,34 @@ mod tests {
         // Never call logs -- the queued response should remain unconsumed
         mock.verify_all_consumed();
     }
+
+    #[tokio::test]
+    async fn recording_executor_matches_prefixes_fifo() {
+        let exec = RecordingExecutor::new()
+            .on_stdout("podman image exists", "")
+            .on_fail("podman image exists", 1, "")
+            .on_stdout("podman", "catch-all");
+
+        let first = exec
+            .output("podman", &["image", "exists", "a"])
+            .await
+            .unwrap();
+        let second = exec
+            .output("podman", &["image", "exists", "b"])
+            .await
+            .unwrap();
+        let third = exec
+            .output("podman", &["image", "exists", "c"])
+            .await
+            .unwrap();
+        let other = exec.interactive("docker", &["ps"]).await.unwrap();
+
+        assert!(first.status.success());
+        assert_eq!(second.status.code(), Some(1));
+        assert_eq!(String::from_utf8_lossy(&third.stdout), "catch-all");
+        assert_eq!(other, 0);
+        exec.assert_ran("podman image exists b");
+        exec.assert_ran("docker ps");
+        exec.assert_not_ran("podman rm");
+    }
 }
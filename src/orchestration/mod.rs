@@ -1,27 +1,56 @@
 //! Orchestration module for container runtimes
 //!
 //! Provides platform-agnostic container management:
-//! - macOS: OrbStack VM + Podman
-//! - Linux: Native rootless Podman
-
+//! - macOS: OrbStack VM + Podman, or a Lima VM + Podman (`vm.provider = "lima"`)
+//! - Linux: Native rootless Podman, through its REST API socket when available
+//! - Either: an existing Docker daemon (`orchestration.backend = "docker"`)
+//! - Either: Podman on a remote Linux host over SSH (`orchestration.backend = "remote"`)
+//! - Either: pods on a Kubernetes cluster via kubectl (`orchestration.backend = "k8s"`)
+
+mod docker;
+mod executor;
 mod factory;
+mod kubectl;
+mod kubernetes;
+pub mod lima;
+mod lima_runtime;
 #[cfg(test)]
 pub(crate) mod mock;
 mod native_podman;
 pub mod orbstack;
 mod orbstack_runtime;
 pub mod podman;
+mod podman_api;
+mod podman_api_runtime;
+mod remote_ssh;
 mod runtime;
-
-pub use factory::{create_runtime, create_runtime_with_vm, Platform};
+mod ssh;
+
+pub use docker::DockerRuntime;
+pub use executor::{CommandExecutor, SystemExecutor};
+pub use factory::{
+    create_runtime, create_runtime_with_backend, create_runtime_with_vm, Backend, Platform,
+    VmProvider,
+};
+pub use kubectl::Kubectl;
+pub use lima::Lima;
+pub use native_podman::NativePodmanRuntime;
 pub use orbstack::OrbStack;
-pub use podman::ContainerConfig;
-pub use runtime::{ContainerRuntime, VolumeInfo};
+pub use podman::{
+    device_host_path, host_platform, parse_extra_host, parse_hostname, parse_platform,
+    parse_restart_policy, parse_userns, tmpfs_target, ulimit_name, ContainerConfig, PodSpec,
+    PullPolicy, PLATFORMS,
+};
+pub use runtime::{ContainerRuntime, ContainerUsage, VolumeInfo};
 
 use std::collections::HashMap;
-use tokio::io::{AsyncBufReadExt, BufReader};
+use std::future::Future;
+use std::process::Output;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tracing::warn;
 
-use crate::error::MinoResult;
+use crate::error::{MinoError, MinoResult};
 
 /// Max number of output lines to include in build error messages.
 const BUILD_ERROR_TAIL_LINES: usize = 50;
@@ -86,6 +115,44 @@ pub(crate) async fn stream_child_output(
     all_output
 }
 
+/// Forward stdout+stderr lines from a child process to `on_line` until both
+/// streams close.
+///
+/// Unlike `stream_child_output`, nothing is retained, so it is safe for
+/// long-running follows.
+pub(crate) async fn forward_child_lines(
+    child: &mut tokio::process::Child,
+    on_line: &(dyn Fn(String) + Send + Sync),
+) {
+    let stderr = child.stderr.take().expect("stderr piped");
+    let stdout = child.stdout.take().expect("stdout piped");
+
+    let mut stderr_reader = BufReader::new(stderr).lines();
+    let mut stdout_reader = BufReader::new(stdout).lines();
+
+    let mut stderr_done = false;
+    let mut stdout_done = false;
+
+    while !stderr_done || !stdout_done {
+        tokio::select! {
+            line = stderr_reader.next_line(), if !stderr_done => {
+                match line {
+                    Ok(Some(line)) => on_line(line),
+                    _ => stderr_done = true,
+                }
+            }
+            line = stdout_reader.next_line(), if !stdout_done => {
+                match line {
+                    Ok(Some(line)) => on_line(line),
+                    _ => stdout_done = true,
+                }
+            }
+        }
+    }
+
+    let _ = child.wait().await; // reap to avoid zombie
+}
+
 /// Follow a child process's stderr/stdout until a marker line is found or timeout expires.
 ///
 /// Returns `true` if the marker was found, `false` on timeout. Calls `on_line`
@@ -165,6 +232,27 @@ pub(crate) fn parse_du_bytes(output: &[u8]) -> Option<u64> {
         .and_then(|s| s.parse::<u64>().ok())
 }
 
+/// Volumes sized at once: each `du` walks a whole volume, so unbounded
+/// fan-out thrashes the disk on hosts with many caches
+pub(crate) const DISK_USAGE_CONCURRENCY: usize = 4;
+
+/// Run per-volume size futures, at most [`DISK_USAGE_CONCURRENCY`] at a
+/// time, and collect their results with [`collect_disk_usage`].
+pub(crate) async fn measure_volumes<F>(
+    futures: impl IntoIterator<Item = F>,
+) -> MinoResult<HashMap<String, u64>>
+where
+    F: Future<Output = MinoResult<Option<(String, u64)>>>,
+{
+    use futures_util::StreamExt;
+
+    let results: Vec<_> = futures_util::stream::iter(futures)
+        .buffer_unordered(DISK_USAGE_CONCURRENCY)
+        .collect()
+        .await;
+    collect_disk_usage(results)
+}
+
 /// Collect volume disk usage results from a batch of parallel futures.
 ///
 /// Each future should resolve to `Ok(Some((name, size)))` on success or
@@ -193,9 +281,9 @@ pub(crate) fn parse_volume_labels(vol: &serde_json::Value) -> HashMap<String, St
 
 /// Parse `podman volume ls --format json` output into a filtered list of `VolumeInfo`.
 ///
-/// Volumes whose names do not start with `prefix` are excluded. Empty or
-/// whitespace-only stdout is treated as an empty list (not a parse error).
-pub(crate) fn parse_volume_list_json(stdout: &str, prefix: &str) -> MinoResult<Vec<VolumeInfo>> {
+/// Volumes that do not carry `label` are excluded, whatever their name. Empty
+/// or whitespace-only stdout is treated as an empty list (not a parse error).
+pub(crate) fn parse_volume_list_json(stdout: &str, label: &str) -> MinoResult<Vec<VolumeInfo>> {
     if stdout.trim().is_empty() {
         return Ok(Vec::new());
     }
@@ -206,10 +294,8 @@ pub(crate) fn parse_volume_list_json(stdout: &str, prefix: &str) -> MinoResult<V
         .iter()
         .filter_map(|vol| {
             let name = vol["Name"].as_str()?;
-            if !name.starts_with(prefix) {
-                return None;
-            }
-            Some(volume_info_from_json(vol, name))
+            let info = volume_info_from_json(vol, name);
+            info.labels.contains_key(label).then_some(info)
         })
         .collect();
 
@@ -246,12 +332,470 @@ pub(crate) fn parse_volume_inspect_json(
     Ok(volumes.first().map(|vol| volume_info_from_json(vol, name)))
 }
 
+/// Attempts for an image pull before giving up.
+pub(crate) const PULL_ATTEMPTS: u32 = 4;
+
+/// Delay before the first pull retry; doubles on each further attempt.
+const PULL_RETRY_BASE_DELAY: Duration = Duration::from_secs(2);
+
+/// Registry errors that retrying cannot fix.
+const PERMANENT_PULL_ERRORS: &[&str] = &[
+    "manifest unknown",
+    "name unknown",
+    "not found",
+    "unauthorized",
+    "denied",
+    "invalid reference format",
+    "short-name",
+];
+
+/// How to invoke `podman pull` for an image.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct PullPlan {
+    /// Arguments after `podman`
+    pub args: Vec<String>,
+    /// Attempts for `pull_with_retry`
+    pub attempts: u32,
+}
+
+/// Plan a podman pull.
+///
+/// Podman 5+ retries interrupted pulls itself (`--retry`), keeping layers
+/// already downloaded, so that is preferred when `native_retry` is set.
+/// Older podman gets a single attempt per invocation and retries here.
+/// `platform` (e.g. `linux/amd64`) pulls that variant of a multi-arch image.
+pub(crate) fn podman_pull_plan(
+    image: &str,
+    native_retry: bool,
+    platform: Option<&str>,
+) -> PullPlan {
+    let mut args = vec!["pull".to_string()];
+    if let Some(platform) = platform {
+        args.extend(["--platform".to_string(), platform.to_string()]);
+    }
+    let attempts = if native_retry {
+        let retries = (PULL_ATTEMPTS - 1).to_string();
+        let delay = format!("{}s", PULL_RETRY_BASE_DELAY.as_secs());
+        args.extend([
+            "--retry".to_string(),
+            retries,
+            "--retry-delay".to_string(),
+            delay,
+        ]);
+        1
+    } else {
+        PULL_ATTEMPTS
+    };
+    args.push(image.to_string());
+    PullPlan { args, attempts }
+}
+
+/// `image inspect` format printing an image's `os/arch`
+pub(crate) const IMAGE_PLATFORM_FORMAT: &str = "{{.Os}}/{{.Architecture}}";
+
+/// Parse `image inspect --format IMAGE_PLATFORM_FORMAT` output
+pub(crate) fn parse_image_platform(stdout: &str) -> Option<String> {
+    let platform = stdout.trim();
+    match platform.split_once('/') {
+        Some((os, arch)) if !os.is_empty() && !arch.is_empty() => Some(platform.to_string()),
+        _ => None,
+    }
+}
+
+/// Directories podman reads CDI device specs from
+pub(crate) const CDI_SPEC_DIRS: &[&str] = &["/etc/cdi", "/var/run/cdi"];
+
+/// CDI device exposing every NVIDIA GPU to a container
+pub(crate) const NVIDIA_CDI_DEVICE: &str = "nvidia.com/gpu=all";
+
+/// Whether a listing of CDI spec files (one name per line, as printed by
+/// `ls`) contains an NVIDIA spec.
+pub(crate) fn cdi_listing_has_nvidia(listing: &str) -> bool {
+    listing
+        .lines()
+        .any(|line| line.trim().starts_with("nvidia"))
+}
+
+/// `Ok` if the device exists, otherwise `MinoError::DeviceNotFound` naming where it was looked for
+pub(crate) fn device_check(exists: bool, path: &str, location: &str) -> MinoResult<()> {
+    if exists {
+        Ok(())
+    } else {
+        Err(MinoError::DeviceNotFound {
+            device: path.to_string(),
+            location: location.to_string(),
+        })
+    }
+}
+
+/// Parse `inspect --format {{.RestartCount}}` output: `None` when the
+/// container is gone, an error for any other failure.
+pub(crate) fn parse_restart_count(output: &Output, command: &str) -> MinoResult<Option<u32>> {
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if stderr.to_lowercase().contains("no such") {
+            return Ok(None);
+        }
+        return Err(MinoError::command_exec(command, stderr));
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    stdout.trim().parse().map(Some).map_err(|_| {
+        MinoError::command_exec(
+            command,
+            format!("unexpected restart count {:?}", stdout.trim()),
+        )
+    })
+}
+
+/// Parse `inspect --format {{.State.Running}}` output: `false` when the
+/// container is gone, an error for any other failure.
+pub(crate) fn parse_running(output: &Output, command: &str) -> MinoResult<bool> {
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if stderr.to_lowercase().contains("no such") {
+            return Ok(false);
+        }
+        return Err(MinoError::command_exec(command, stderr));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim() == "true")
+}
+
+/// `podman stats` arguments for one usage sample of a container. Stats are
+/// read by the host's container engine, so nothing inside the sandbox can
+/// forge them and the image needs no tools.
+pub(crate) fn usage_stats_args(container_id: &str) -> [&str; 5] {
+    [
+        "stats",
+        "--no-stream",
+        "--format",
+        "{{json .}}",
+        container_id,
+    ]
+}
+
+/// Parse [`usage_stats_args`] output: one JSON object per container
+pub(crate) fn parse_usage(output: &Output, command: &str) -> MinoResult<ContainerUsage> {
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stats = stdout
+        .lines()
+        .find_map(|line| serde_json::from_str::<serde_json::Value>(line).ok());
+    match stats.as_ref().and_then(parse_stats) {
+        Some(usage) if output.status.success() => Ok(usage),
+        _ => {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec(command, stderr))
+        }
+    }
+}
+
+/// Usage from one libpod `ContainerStats` object, as printed by
+/// `podman stats --format '{{json .}}'` and returned by the API's
+/// `/containers/stats`. Memory is the current use; callers sampling
+/// repeatedly keep the highest value seen.
+pub(crate) fn parse_stats(stats: &serde_json::Value) -> Option<ContainerUsage> {
+    let counter = |key: &str| stats.get(key).and_then(serde_json::Value::as_u64);
+    Some(ContainerUsage {
+        cpu_secs: counter("CPUNano")? as f64 / 1_000_000_000.0,
+        peak_memory_bytes: counter("MemUsage"),
+        net_rx_bytes: counter("NetInput").unwrap_or(0),
+        net_tx_bytes: counter("NetOutput").unwrap_or(0),
+    })
+}
+
+/// `login` arguments for podman or docker
+pub(crate) fn registry_login_args(
+    registry: &str,
+    username: Option<&str>,
+    password_stdin: bool,
+) -> Vec<String> {
+    let mut args = vec!["login".to_string()];
+    if let Some(username) = username {
+        args.push("--username".to_string());
+        args.push(username.to_string());
+    }
+    if password_stdin {
+        args.push("--password-stdin".to_string());
+    }
+    args.push(registry.to_string());
+    args
+}
+
+/// Map an interactive `login`/`logout` exit code to a result. The CLI has
+/// already printed its own error to the terminal.
+pub(crate) fn registry_command_result(command: &str, registry: &str, code: i32) -> MinoResult<()> {
+    if code == 0 {
+        Ok(())
+    } else {
+        Err(MinoError::command_exec(
+            command,
+            format!("{} exited with code {}", registry, code),
+        ))
+    }
+}
+
+/// Run `cmd` with `input` written to its stdin, capturing stdout and stderr
+pub(crate) async fn output_with_input(
+    cmd: &mut tokio::process::Command,
+    input: &[u8],
+) -> std::io::Result<Output> {
+    let mut child = cmd
+        .stdin(std::process::Stdio::piped())
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .spawn()?;
+    let mut stdin = child.stdin.take().expect("stdin piped");
+    stdin.write_all(input).await?;
+    drop(stdin);
+    child.wait_with_output().await
+}
+
+/// Map a captured `login --password-stdin` output to a result
+pub(crate) fn registry_output_result(command: &str, output: &Output) -> MinoResult<()> {
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(MinoError::command_exec(
+            command,
+            String::from_utf8_lossy(&output.stderr),
+        ))
+    }
+}
+
+/// `podman volume export` arguments writing volume `name` to `archive`
+pub(crate) fn volume_export_args<'a>(name: &'a str, archive: &'a str) -> [&'a str; 5] {
+    ["volume", "export", name, "--output", archive]
+}
+
+/// `podman volume import` arguments filling volume `name` from `archive`
+pub(crate) fn volume_import_args<'a>(name: &'a str, archive: &'a str) -> [&'a str; 4] {
+    ["volume", "import", name, archive]
+}
+
+/// Error for a podman host without an NVIDIA CDI spec
+pub(crate) fn missing_cdi_spec(runtime: &str) -> MinoError {
+    MinoError::GpuUnsupported {
+        runtime: runtime.to_string(),
+        reason: format!("no NVIDIA CDI spec in {}", CDI_SPEC_DIRS.join(" or ")),
+    }
+}
+
+/// `podman container checkpoint` arguments exporting `container_id` to `archive`
+pub(crate) fn checkpoint_args<'a>(
+    container_id: &'a str,
+    archive: &'a str,
+    leave_running: bool,
+) -> Vec<&'a str> {
+    let mut args = vec!["container", "checkpoint", "--export", archive];
+    if leave_running {
+        args.push("--leave-running");
+    }
+    args.push(container_id);
+    args
+}
+
+/// Map a failed checkpoint or restore to an error, flagging missing CRIU
+/// support separately from other failures.
+pub(crate) fn checkpoint_error(runtime: &str, command: &str, stderr: &str) -> MinoError {
+    let lower = stderr.to_lowercase();
+    if lower.contains("criu") || lower.contains("rootless") || lower.contains("requires root") {
+        MinoError::CheckpointUnsupported {
+            runtime: runtime.to_string(),
+            reason: stderr.trim().to_string(),
+        }
+    } else {
+        MinoError::command_exec(command, stderr)
+    }
+}
+
+/// Container ID printed by `podman container restore` (its last output line)
+pub(crate) fn parse_restored_id(stdout: &str) -> MinoResult<String> {
+    stdout
+        .lines()
+        .map(str::trim)
+        .rfind(|line| !line.is_empty())
+        .map(String::from)
+        .ok_or_else(|| MinoError::Internal("podman restore printed no container ID".to_string()))
+}
+
+/// Whether `podman pull --help` output advertises `--retry`.
+pub(crate) fn pull_help_has_retry(help: &str) -> bool {
+    help.contains("--retry")
+}
+
+/// Whether a failed pull is worth retrying (network trouble rather than a
+/// missing image or bad credentials).
+fn is_transient_pull_error(err: &MinoError) -> bool {
+    match err {
+        MinoError::ImagePull { reason, .. } => {
+            let reason = reason.to_lowercase();
+            !PERMANENT_PULL_ERRORS.iter().any(|p| reason.contains(p))
+        }
+        _ => false,
+    }
+}
+
+/// Run `pull_once` up to `attempts` times with exponential backoff,
+/// reporting each failed attempt.
+pub(crate) async fn pull_with_retry<F, Fut>(
+    image: &str,
+    attempts: u32,
+    pull_once: F,
+) -> MinoResult<()>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = MinoResult<()>>,
+{
+    retry_pull(image, attempts, PULL_RETRY_BASE_DELAY, pull_once).await
+}
+
+/// Finish one streamed pull attempt: forward the child's output lines to
+/// `on_output` and map a failed exit to `MinoError::ImagePull`.
+pub(crate) async fn stream_pull(
+    child: &mut tokio::process::Child,
+    image: &str,
+    on_output: &(dyn Fn(String) + Send + Sync),
+) -> MinoResult<()> {
+    let all_output = stream_child_output(child, on_output).await;
+
+    let status = child
+        .wait()
+        .await
+        .map_err(|e| MinoError::command_failed("pull", e))?;
+
+    if status.success() {
+        Ok(())
+    } else {
+        Err(MinoError::ImagePull {
+            image: image.to_string(),
+            reason: build_error_output(&all_output.join("\n"), ""),
+        })
+    }
+}
+
+async fn retry_pull<F, Fut>(
+    image: &str,
+    attempts: u32,
+    base_delay: Duration,
+    mut pull_once: F,
+) -> MinoResult<()>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = MinoResult<()>>,
+{
+    let mut attempt = 1;
+    loop {
+        match pull_once().await {
+            Err(e) if attempt < attempts && is_transient_pull_error(&e) => {
+                let delay = base_delay * 2u32.pow(attempt - 1);
+                warn!(
+                    "Pulling {} failed (attempt {}/{}), retrying in {}s: {}",
+                    image,
+                    attempt,
+                    attempts,
+                    delay.as_secs(),
+                    e.to_string().lines().last().unwrap_or_default()
+                );
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::error::MinoError;
     use std::sync::{Arc, Mutex};
 
+    // -- cdi_listing_has_nvidia --
+
+    #[test]
+    fn cdi_listing_detects_nvidia_spec() {
+        assert!(cdi_listing_has_nvidia("/etc/cdi:\nnvidia.yaml\n"));
+        assert!(!cdi_listing_has_nvidia("/etc/cdi:\namd.json\n"));
+        assert!(!cdi_listing_has_nvidia(""));
+    }
+
+    // -- usage probe --
+
+    #[test]
+    fn stats_json_parses_raw_counters() {
+        let stats = serde_json::json!({
+            "ContainerID": "abc",
+            "CPU": 12.5,
+            "CPUNano": 2_500_000_000u64,
+            "MemUsage": 104_857_600u64,
+            "NetInput": 5000,
+            "NetOutput": 3000,
+        });
+        let usage = parse_stats(&stats).unwrap();
+        assert_eq!(usage.cpu_secs, 2.5);
+        assert_eq!(usage.peak_memory_bytes, Some(104_857_600));
+        assert_eq!(usage.net_rx_bytes, 5000);
+        assert_eq!(usage.net_tx_bytes, 3000);
+    }
+
+    #[test]
+    fn stats_json_without_cpu_counter_is_rejected() {
+        assert!(parse_stats(&serde_json::json!({"MemUsage": 1})).is_none());
+        let usage = parse_stats(&serde_json::json!({"CPUNano": 0})).unwrap();
+        assert_eq!(usage.peak_memory_bytes, None);
+        assert_eq!(usage.net_rx_bytes, 0);
+    }
+
+    // -- registry login --
+
+    #[test]
+    fn registry_login_args_order() {
+        assert_eq!(
+            registry_login_args("ghcr.io", Some("me"), true),
+            vec!["login", "--username", "me", "--password-stdin", "ghcr.io"]
+        );
+        assert_eq!(
+            registry_login_args("quay.io", None, false),
+            vec!["login", "quay.io"]
+        );
+        assert!(registry_command_result("podman login", "quay.io", 0).is_ok());
+        assert!(registry_command_result("podman login", "quay.io", 125).is_err());
+    }
+
+    // -- checkpoint helpers --
+
+    #[test]
+    fn checkpoint_args_leave_running_before_container() {
+        assert_eq!(
+            checkpoint_args("abc", "/tmp/s.tar.gz", true),
+            [
+                "container",
+                "checkpoint",
+                "--export",
+                "/tmp/s.tar.gz",
+                "--leave-running",
+                "abc"
+            ]
+        );
+        assert!(!checkpoint_args("abc", "/tmp/s.tar.gz", false).contains(&"--leave-running"));
+    }
+
+    #[test]
+    fn checkpoint_error_detects_missing_criu() {
+        let err = checkpoint_error(
+            "Podman",
+            "podman checkpoint",
+            "Error: checkpoint requires root",
+        );
+        assert!(matches!(err, MinoError::CheckpointUnsupported { .. }));
+        let err = checkpoint_error("Podman", "podman checkpoint", "no such container");
+        assert!(!matches!(err, MinoError::CheckpointUnsupported { .. }));
+    }
+
+    #[test]
+    fn parse_restored_id_takes_last_line() {
+        assert_eq!(parse_restored_id("warning: x\nabc123\n").unwrap(), "abc123");
+        assert!(parse_restored_id("\n").is_err());
+    }
+
     // -- parse_du_bytes --
 
     #[test]
@@ -325,6 +869,29 @@ mod tests {
         assert!(err.to_string().contains("test error"));
     }
 
+    #[tokio::test]
+    async fn measure_volumes_bounds_concurrency() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let in_flight = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+        let futures = (0..10).map(|i| {
+            let (in_flight, peak) = (&in_flight, &peak);
+            async move {
+                let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                tokio::task::yield_now().await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+                Ok((i % 2 == 0).then(|| (format!("vol-{i}"), i)))
+            }
+        });
+
+        let sizes = measure_volumes(futures).await.unwrap();
+        assert_eq!(sizes.len(), 5);
+        assert_eq!(sizes["vol-4"], 4);
+        assert_eq!(peak.load(Ordering::SeqCst), DISK_USAGE_CONCURRENCY);
+    }
+
     // -- parse_volume_labels --
 
     #[test]
@@ -386,7 +953,7 @@ mod tests {
             "Mountpoint": "/var/lib/volumes/test/_data",
             "CreatedAt": "2026-03-10T12:00:00Z"
         }]"#;
-        let result = parse_volume_list_json(json, "mino-cache-").unwrap();
+        let result = parse_volume_list_json(json, "io.mino.cache").unwrap();
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].name, "mino-cache-npm-abc123");
         assert_eq!(result[0].labels["io.mino.cache"], "true");
@@ -402,64 +969,64 @@ mod tests {
     }
 
     #[test]
-    fn parse_volume_list_json_multiple_with_prefix_filter() {
+    fn parse_volume_list_json_multiple_with_label_filter() {
         let json = r#"[
-            {"Name": "mino-cache-npm-abc", "Labels": {}},
+            {"Name": "mino-cache-npm-abc", "Labels": {"io.mino.cache": "true"}},
             {"Name": "other-volume", "Labels": {}},
-            {"Name": "mino-cache-cargo-def", "Labels": {}}
+            {"Name": "mino-cache-cargo-def", "Labels": {"io.mino.home": "true"}},
+            {"Name": "renamed-by-user", "Labels": {"io.mino.cache": "true"}}
         ]"#;
-        let result = parse_volume_list_json(json, "mino-cache-").unwrap();
+        let result = parse_volume_list_json(json, "io.mino.cache").unwrap();
         assert_eq!(result.len(), 2);
         assert_eq!(result[0].name, "mino-cache-npm-abc");
-        assert_eq!(result[1].name, "mino-cache-cargo-def");
+        assert_eq!(result[1].name, "renamed-by-user");
     }
 
     #[test]
     fn parse_volume_list_json_empty_string() {
-        let result = parse_volume_list_json("", "mino-cache-").unwrap();
+        let result = parse_volume_list_json("", "io.mino.cache").unwrap();
         assert!(result.is_empty());
     }
 
     #[test]
     fn parse_volume_list_json_whitespace_only() {
-        let result = parse_volume_list_json("   \n  \t  ", "mino-cache-").unwrap();
+        let result = parse_volume_list_json("   \n  \t  ", "io.mino.cache").unwrap();
         assert!(result.is_empty());
     }
 
     #[test]
     fn parse_volume_list_json_empty_array() {
-        let result = parse_volume_list_json("[]", "mino-cache-").unwrap();
+        let result = parse_volume_list_json("[]", "io.mino.cache").unwrap();
         assert!(result.is_empty());
     }
 
     #[test]
-    fn parse_volume_list_json_no_prefix_match() {
-        let json = r#"[{"Name": "other-volume", "Labels": {}}]"#;
-        let result = parse_volume_list_json(json, "mino-cache-").unwrap();
+    fn parse_volume_list_json_no_label_match() {
+        let json = r#"[{"Name": "mino-cache-npm-abc", "Labels": {"other": "x"}}]"#;
+        let result = parse_volume_list_json(json, "io.mino.cache").unwrap();
         assert!(result.is_empty());
     }
 
     #[test]
     fn parse_volume_list_json_null_labels() {
         let json = r#"[{"Name": "mino-cache-npm-abc", "Labels": null}]"#;
-        let result = parse_volume_list_json(json, "mino-cache-").unwrap();
-        assert_eq!(result.len(), 1);
-        assert!(result[0].labels.is_empty());
+        let result = parse_volume_list_json(json, "io.mino.cache").unwrap();
+        assert!(result.is_empty());
     }
 
     #[test]
     fn parse_volume_list_json_missing_optional_fields() {
-        let json = r#"[{"Name": "mino-cache-npm-abc"}]"#;
-        let result = parse_volume_list_json(json, "mino-cache-").unwrap();
+        let json = r#"[{"Name": "mino-cache-npm-abc", "Labels": {"io.mino.cache": "true"}}]"#;
+        let result = parse_volume_list_json(json, "io.mino.cache").unwrap();
         assert_eq!(result.len(), 1);
-        assert!(result[0].labels.is_empty());
+        assert_eq!(result[0].labels.len(), 1);
         assert!(result[0].mountpoint.is_none());
         assert!(result[0].created_at.is_none());
     }
 
     #[test]
     fn parse_volume_list_json_invalid_json() {
-        let err = parse_volume_list_json("not json", "mino-cache-").unwrap_err();
+        let err = parse_volume_list_json("not json", "io.mino.cache").unwrap_err();
         assert!(matches!(err, MinoError::Json(_)));
     }
 
@@ -568,6 +1135,18 @@ mod tests {
         (on_line, lines)
     }
 
+    #[tokio::test]
+    async fn forward_child_lines_passes_both_streams() {
+        let mut child = spawn_marker_test("echo 'out'; echo 'err' >&2");
+        let (on_line, lines) = collecting_callback();
+
+        forward_child_lines(&mut child, &on_line).await;
+
+        let mut captured = lines.lock().unwrap().clone();
+        captured.sort();
+        assert_eq!(captured, vec!["err".to_string(), "out".to_string()]);
+    }
+
     #[tokio::test]
     async fn follow_until_marker_found_on_stdout() {
         let mut child =
@@ -646,4 +1225,118 @@ mod tests {
         let captured = lines.lock().unwrap();
         assert_eq!(captured.len(), 2, "should have captured both output lines");
     }
+
+    // -- pull retry --
+
+    fn pull_error(reason: &str) -> MinoError {
+        MinoError::ImagePull {
+            image: "fedora:43".to_string(),
+            reason: reason.to_string(),
+        }
+    }
+
+    #[test]
+    fn podman_pull_plan_prefers_native_retry() {
+        let plan = podman_pull_plan("fedora:43", true, None);
+        assert_eq!(
+            plan.args,
+            vec!["pull", "--retry", "3", "--retry-delay", "2s", "fedora:43"]
+        );
+        assert_eq!(plan.attempts, 1);
+
+        let plan = podman_pull_plan("fedora:43", false, None);
+        assert_eq!(plan.args, vec!["pull", "fedora:43"]);
+        assert_eq!(plan.attempts, PULL_ATTEMPTS);
+
+        let plan = podman_pull_plan("fedora:43", false, Some("linux/amd64"));
+        assert_eq!(
+            plan.args,
+            vec!["pull", "--platform", "linux/amd64", "fedora:43"]
+        );
+    }
+
+    #[test]
+    fn parse_image_platform_reads_os_arch() {
+        assert_eq!(
+            parse_image_platform("linux/arm64\n").as_deref(),
+            Some("linux/arm64")
+        );
+        assert_eq!(parse_image_platform(""), None);
+        assert_eq!(parse_image_platform("linux/"), None);
+    }
+
+    #[test]
+    fn pull_help_has_retry_detects_flag() {
+        assert!(pull_help_has_retry(
+            "      --retry uint          number of times to retry in case of failure"
+        ));
+        assert!(!pull_help_has_retry("      --quiet   Suppress output"));
+    }
+
+    #[test]
+    fn transient_pull_errors() {
+        assert!(is_transient_pull_error(&pull_error(
+            "read tcp 10.0.0.2:51234->1.2.3.4:443: read: connection reset by peer"
+        )));
+        assert!(!is_transient_pull_error(&pull_error(
+            "reading manifest latest: manifest unknown"
+        )));
+        assert!(!is_transient_pull_error(&pull_error(
+            "unauthorized: authentication required"
+        )));
+        assert!(!is_transient_pull_error(&MinoError::PodmanNotFound));
+    }
+
+    #[tokio::test]
+    async fn retry_pull_retries_transient_failures() {
+        let calls = Arc::new(Mutex::new(0u32));
+        let result = retry_pull("fedora:43", 3, Duration::ZERO, || {
+            let calls = Arc::clone(&calls);
+            async move {
+                let mut n = calls.lock().unwrap();
+                *n += 1;
+                if *n < 3 {
+                    Err(pull_error("connection reset by peer"))
+                } else {
+                    Ok(())
+                }
+            }
+        })
+        .await;
+
+        assert!(result.is_ok());
+        assert_eq!(*calls.lock().unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn retry_pull_gives_up_after_attempts() {
+        let calls = Arc::new(Mutex::new(0u32));
+        let result = retry_pull("fedora:43", 2, Duration::ZERO, || {
+            let calls = Arc::clone(&calls);
+            async move {
+                *calls.lock().unwrap() += 1;
+                Err(pull_error("i/o timeout"))
+            }
+        })
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(*calls.lock().unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn retry_pull_stops_on_permanent_error() {
+        let calls = Arc::new(Mutex::new(0u32));
+        let result = retry_pull("fedora:43", 4, Duration::ZERO, || {
+            let calls = Arc::clone(&calls);
+            async move {
+                *calls.lock().unwrap() += 1;
+                Err(pull_error("manifest unknown"))
+            }
+        })
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(*calls.lock().unwrap(), 1);
+    }
 }
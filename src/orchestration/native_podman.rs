@@ -4,44 +4,59 @@
 //! without a VM layer. Requires rootless Podman to be properly configured.
 
 use crate::error::{MinoError, MinoResult};
-use crate::orchestration::podman::{redact_args, ContainerConfig};
-use crate::orchestration::runtime::{ContainerRuntime, VolumeInfo};
+use crate::orchestration::executor::{CommandExecutor, SystemExecutor};
+use crate::orchestration::podman::{build_args, ContainerConfig, PodSpec, PullPolicy};
+use crate::orchestration::runtime::{ContainerRuntime, ContainerUsage, VolumeInfo};
+use crate::redact::redact_args;
 use async_trait::async_trait;
 use std::collections::HashMap;
 use std::path::Path;
 use std::process::Stdio;
+use std::sync::Arc;
 use tokio::process::Command;
+use tokio::sync::OnceCell;
 use tracing::{debug, warn};
 
 /// Container runtime using native rootless Podman (for Linux)
-pub struct NativePodmanRuntime;
+pub struct NativePodmanRuntime {
+    /// Runs the `podman` commands
+    executor: Arc<dyn CommandExecutor>,
+    /// Whether `podman pull` accepts `--retry` (probed once)
+    pull_retry: OnceCell<bool>,
+}
 
 impl NativePodmanRuntime {
     /// Create a new native Podman runtime
     pub fn new() -> Self {
-        Self
+        Self::with_executor(Arc::new(SystemExecutor))
+    }
+
+    /// Create a runtime whose commands go through `executor`
+    pub fn with_executor(executor: Arc<dyn CommandExecutor>) -> Self {
+        Self {
+            executor,
+            pull_retry: OnceCell::new(),
+        }
     }
 
     /// Check if Podman is installed
-    async fn podman_installed() -> bool {
-        Command::new("podman")
-            .arg("--version")
-            .stdout(Stdio::null())
-            .stderr(Stdio::null())
-            .status()
+    async fn podman_installed(&self) -> bool {
+        self.executor
+            .output("podman", &["--version"])
             .await
-            .map(|s| s.success())
+            .map(|o| o.status.success())
             .unwrap_or(false)
     }
 
     /// Check if rootless Podman is properly configured
-    async fn rootless_configured() -> MinoResult<bool> {
+    async fn rootless_configured(&self) -> MinoResult<bool> {
         // Check if user namespaces are available
-        let output = Command::new("podman")
-            .args(["info", "--format", "{{.Host.Security.Rootless}}"])
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .output()
+        let output = self
+            .executor
+            .output(
+                "podman",
+                &["info", "--format", "{{.Host.Security.Rootless}}"],
+            )
             .await
             .map_err(|e| MinoError::command_failed("podman info", e))?;
 
@@ -53,11 +68,8 @@ impl NativePodmanRuntime {
     async fn exec(&self, args: &[&str]) -> MinoResult<std::process::Output> {
         debug!("Executing: podman {:?}", redact_args(args));
 
-        Command::new("podman")
-            .args(args)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .output()
+        self.executor
+            .output("podman", args)
             .await
             .map_err(|e| MinoError::command_failed(format!("podman {:?}", redact_args(args)), e))
     }
@@ -66,33 +78,49 @@ impl NativePodmanRuntime {
     async fn exec_interactive(&self, args: &[&str]) -> MinoResult<i32> {
         debug!("Executing interactively: podman {:?}", redact_args(args));
 
-        let status = Command::new("podman")
-            .args(args)
-            .stdin(Stdio::inherit())
-            .stdout(Stdio::inherit())
-            .stderr(Stdio::inherit())
-            .status()
+        self.executor
+            .interactive("podman", args)
             .await
-            .map_err(|e| MinoError::command_failed(format!("podman {:?}", redact_args(args)), e))?;
+            .map_err(|e| MinoError::command_failed(format!("podman {:?}", redact_args(args)), e))
+    }
 
-        Ok(status.code().unwrap_or(-1))
+    /// Whether the installed podman supports `pull --retry` (cached)
+    async fn supports_pull_retry(&self) -> bool {
+        *self
+            .pull_retry
+            .get_or_init(|| async {
+                match self.exec(&["pull", "--help"]).await {
+                    Ok(output) => {
+                        super::pull_help_has_retry(&String::from_utf8_lossy(&output.stdout))
+                    }
+                    Err(_) => false,
+                }
+            })
+            .await
     }
 
-    /// Pull an image
+    /// Pull an image, retrying interrupted downloads
     async fn pull(&self, image: &str) -> MinoResult<()> {
         debug!("Pulling image: {}", image);
 
-        let output = self.exec(&["pull", image]).await?;
+        let plan = super::podman_pull_plan(image, self.supports_pull_retry().await, None);
+        let args: Vec<&str> = plan.args.iter().map(String::as_str).collect();
+        let args = &args;
 
-        if output.status.success() {
-            Ok(())
-        } else {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            Err(MinoError::ImagePull {
-                image: image.to_string(),
-                reason: stderr.to_string(),
-            })
-        }
+        super::pull_with_retry(image, plan.attempts, || async move {
+            let output = self.exec(args).await?;
+
+            if output.status.success() {
+                Ok(())
+            } else {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                Err(MinoError::ImagePull {
+                    image: image.to_string(),
+                    reason: stderr.to_string(),
+                })
+            }
+        })
+        .await
     }
 }
 
@@ -105,18 +133,18 @@ impl Default for NativePodmanRuntime {
 #[async_trait]
 impl ContainerRuntime for NativePodmanRuntime {
     async fn is_available(&self) -> MinoResult<bool> {
-        if !Self::podman_installed().await {
+        if !self.podman_installed().await {
             return Ok(false);
         }
-        Self::rootless_configured().await
+        self.rootless_configured().await
     }
 
     async fn ensure_ready(&self) -> MinoResult<()> {
-        if !Self::podman_installed().await {
+        if !self.podman_installed().await {
             return Err(MinoError::PodmanNotFound);
         }
 
-        if !Self::rootless_configured().await? {
+        if !self.rootless_configured().await? {
             return Err(MinoError::PodmanRootlessSetup {
                 reason: "Rootless Podman not configured. Run: podman system migrate".to_string(),
             });
@@ -125,6 +153,28 @@ impl ContainerRuntime for NativePodmanRuntime {
         Ok(())
     }
 
+    async fn check_device(&self, path: &str) -> MinoResult<()> {
+        super::device_check(std::path::Path::new(path).exists(), path, "this host")
+    }
+
+    async fn check_gpu_support(&self) -> MinoResult<()> {
+        let mut listing = String::new();
+        for dir in super::CDI_SPEC_DIRS {
+            if let Ok(entries) = std::fs::read_dir(dir) {
+                for entry in entries.flatten() {
+                    listing.push_str(&entry.file_name().to_string_lossy());
+                    listing.push('\n');
+                }
+            }
+        }
+
+        if super::cdi_listing_has_nvidia(&listing) {
+            Ok(())
+        } else {
+            Err(super::missing_cdi_spec(self.runtime_name()))
+        }
+    }
+
     async fn run(&self, config: &ContainerConfig, command: &[String]) -> MinoResult<String> {
         // Ensure image is available
         if !self.image_exists(&config.image).await? {
@@ -230,6 +280,93 @@ impl ContainerRuntime for NativePodmanRuntime {
         }
     }
 
+    async fn signal(&self, container_id: &str, signal: &str) -> MinoResult<()> {
+        debug!("Sending {} to container: {}", signal, container_id);
+
+        let output = self
+            .exec(&["kill", "--signal", signal, container_id])
+            .await?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec("podman kill", stderr))
+        }
+    }
+
+    async fn pause(&self, container_id: &str) -> MinoResult<()> {
+        debug!("Pausing container: {}", container_id);
+
+        let output = self.exec(&["pause", container_id]).await?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec("podman pause", stderr))
+        }
+    }
+
+    async fn unpause(&self, container_id: &str) -> MinoResult<()> {
+        debug!("Unpausing container: {}", container_id);
+
+        let output = self.exec(&["unpause", container_id]).await?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec("podman unpause", stderr))
+        }
+    }
+
+    async fn checkpoint(
+        &self,
+        container_id: &str,
+        archive: &Path,
+        leave_running: bool,
+    ) -> MinoResult<()> {
+        debug!(
+            "Checkpointing container {} to {}",
+            container_id,
+            archive.display()
+        );
+        let archive = archive.display().to_string();
+        let args = super::checkpoint_args(container_id, &archive, leave_running);
+        let output = self.exec(&args).await?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(super::checkpoint_error(
+                self.runtime_name(),
+                "podman container checkpoint",
+                &stderr,
+            ))
+        }
+    }
+
+    async fn restore(&self, archive: &Path) -> MinoResult<String> {
+        debug!("Restoring container from {}", archive.display());
+        let archive = archive.display().to_string();
+        let output = self
+            .exec(&["container", "restore", "--import", &archive])
+            .await?;
+
+        if output.status.success() {
+            super::parse_restored_id(&String::from_utf8_lossy(&output.stdout))
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(super::checkpoint_error(
+                self.runtime_name(),
+                "podman container restore",
+                &stderr,
+            ))
+        }
+    }
+
     async fn remove(&self, container_id: &str) -> MinoResult<()> {
         debug!("Removing container: {}", container_id);
 
@@ -257,6 +394,25 @@ impl ContainerRuntime for NativePodmanRuntime {
         Ok(())
     }
 
+    async fn container_list_labeled(&self, label: &str) -> MinoResult<Vec<String>> {
+        let filter = format!("label={}", label);
+        let output = self
+            .exec(&["ps", "-a", "--filter", &filter, "--format", "{{.ID}}"])
+            .await?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(MinoError::command_exec("podman ps", stderr));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        Ok(stdout
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(String::from)
+            .collect())
+    }
+
     async fn logs(&self, container_id: &str, lines: u32) -> MinoResult<String> {
         let tail_arg = if lines == 0 {
             "all".to_string()
@@ -276,14 +432,99 @@ impl ContainerRuntime for NativePodmanRuntime {
         Ok(())
     }
 
+    async fn logs_follow_lines(
+        &self,
+        container_id: &str,
+        lines: u32,
+        on_line: &(dyn Fn(String) + Send + Sync),
+    ) -> MinoResult<()> {
+        let tail_arg = if lines == 0 {
+            "all".to_string()
+        } else {
+            lines.to_string()
+        };
+
+        let mut child = Command::new("podman")
+            .args(["logs", "-f", "--tail", &tail_arg, container_id])
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| MinoError::command_failed("podman logs -f", e))?;
+
+        super::forward_child_lines(&mut child, on_line).await;
+        Ok(())
+    }
+
     async fn image_exists(&self, image: &str) -> MinoResult<bool> {
         let output = self.exec(&["image", "exists", image]).await?;
         Ok(output.status.success())
     }
 
-    async fn build_image(&self, context_dir: &Path, tag: &str) -> MinoResult<()> {
-        let context_str = context_dir.display().to_string();
-        let output = self.exec(&["build", "-t", tag, &context_str]).await?;
+    async fn image_pull(&self, image: &str) -> MinoResult<()> {
+        self.pull(image).await
+    }
+
+    async fn image_pull_with_progress(
+        &self,
+        image: &str,
+        platform: Option<&str>,
+        on_output: &(dyn Fn(String) + Send + Sync),
+    ) -> MinoResult<()> {
+        let plan = super::podman_pull_plan(image, self.supports_pull_retry().await, platform);
+        let args = &plan.args;
+
+        super::pull_with_retry(image, plan.attempts, || async move {
+            let mut child = Command::new("podman")
+                .args(args)
+                .stdout(Stdio::piped())
+                .stderr(Stdio::piped())
+                .spawn()
+                .map_err(|e| MinoError::command_failed("podman pull", e))?;
+            super::stream_pull(&mut child, image, on_output).await
+        })
+        .await
+    }
+
+    async fn image_platform(&self, image: &str) -> MinoResult<Option<String>> {
+        let output = self
+            .exec(&[
+                "image",
+                "inspect",
+                "--format",
+                super::IMAGE_PLATFORM_FORMAT,
+                image,
+            ])
+            .await?;
+        if !output.status.success() {
+            return Ok(None);
+        }
+        Ok(super::parse_image_platform(&String::from_utf8_lossy(
+            &output.stdout,
+        )))
+    }
+
+    async fn image_user(&self, image: &str) -> MinoResult<Option<String>> {
+        if !self.image_exists(image).await? {
+            self.pull(image).await?;
+        }
+
+        let output = self
+            .exec(&["image", "inspect", "--format", "{{.Config.User}}", image])
+            .await?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(MinoError::command_exec("podman image inspect", stderr));
+        }
+
+        let user = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        Ok(if user.is_empty() { None } else { Some(user) })
+    }
+
+    async fn build_image(&self, context_dir: &Path, tag: &str, pull: PullPolicy) -> MinoResult<()> {
+        let args = build_args(tag, &context_dir.display().to_string(), pull);
+        let args: Vec<&str> = args.iter().map(String::as_str).collect();
+        let output = self.exec(&args).await?;
 
         if !output.status.success() {
             let stderr = String::from_utf8_lossy(&output.stderr);
@@ -302,12 +543,11 @@ impl ContainerRuntime for NativePodmanRuntime {
         &self,
         context_dir: &Path,
         tag: &str,
+        pull: PullPolicy,
         on_output: &(dyn Fn(String) + Send + Sync),
     ) -> MinoResult<()> {
-        let context_str = context_dir.display().to_string();
-
         let mut child = Command::new("podman")
-            .args(["build", "-t", tag, &context_str])
+            .args(build_args(tag, &context_dir.display().to_string(), pull))
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
             .spawn()
@@ -374,6 +614,34 @@ impl ContainerRuntime for NativePodmanRuntime {
         Ok(images)
     }
 
+    async fn image_list_labeled(&self, label: &str) -> MinoResult<Vec<String>> {
+        let filter = format!("label={}", label);
+        let output = self
+            .exec(&[
+                "images",
+                "--filter",
+                &filter,
+                "--format",
+                "{{.Repository}}:{{.Tag}}",
+            ])
+            .await?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(MinoError::command_exec("podman images", stderr));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let images: Vec<String> = stdout
+            .lines()
+            // Untagged images carrying the label show up as <none>:<none>
+            .filter(|line| !line.is_empty() && !line.contains("<none>"))
+            .map(String::from)
+            .collect();
+
+        Ok(images)
+    }
+
     fn runtime_name(&self) -> &'static str {
         "Native Podman"
     }
@@ -405,6 +673,19 @@ impl ContainerRuntime for NativePodmanRuntime {
         }
     }
 
+    async fn network_create(&self, name: &str) -> MinoResult<()> {
+        debug!("Creating network: {}", name);
+
+        let output = self.exec(&["network", "create", "--ignore", name]).await?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec("podman network create", stderr))
+        }
+    }
+
     async fn volume_remove(&self, name: &str) -> MinoResult<()> {
         debug!("Removing volume: {}", name);
 
@@ -423,7 +704,7 @@ impl ContainerRuntime for NativePodmanRuntime {
         }
     }
 
-    async fn volume_list(&self, prefix: &str) -> MinoResult<Vec<VolumeInfo>> {
+    async fn volume_list(&self, label: &str) -> MinoResult<Vec<VolumeInfo>> {
         let output = self.exec(&["volume", "ls", "--format", "json"]).await?;
 
         if !output.status.success() {
@@ -432,7 +713,7 @@ impl ContainerRuntime for NativePodmanRuntime {
         }
 
         let stdout = String::from_utf8_lossy(&output.stdout);
-        super::parse_volume_list_json(&stdout, prefix)
+        super::parse_volume_list_json(&stdout, label)
     }
 
     async fn volume_inspect(&self, name: &str) -> MinoResult<Option<VolumeInfo>> {
@@ -452,10 +733,32 @@ impl ContainerRuntime for NativePodmanRuntime {
         super::parse_volume_inspect_json(&stdout, name)
     }
 
-    async fn volume_disk_usage(&self, prefix: &str) -> MinoResult<HashMap<String, u64>> {
+    async fn volume_export(&self, name: &str, archive: &Path) -> MinoResult<()> {
+        debug!("Exporting volume {} to {}", name, archive.display());
+        let archive = archive.display().to_string();
+        let output = self
+            .exec(&super::volume_export_args(name, &archive))
+            .await?;
+        super::registry_output_result("podman volume export", &output)
+    }
+
+    fn supports_volume_archives(&self) -> bool {
+        true
+    }
+
+    async fn volume_import(&self, name: &str, archive: &Path) -> MinoResult<()> {
+        debug!("Importing {} into volume {}", archive.display(), name);
+        let archive = archive.display().to_string();
+        let output = self
+            .exec(&super::volume_import_args(name, &archive))
+            .await?;
+        super::registry_output_result("podman volume import", &output)
+    }
+
+    async fn volume_disk_usage(&self, label: &str) -> MinoResult<HashMap<String, u64>> {
         // Get volume sizes by inspecting each volume individually.
         // Note: `podman system df -v --format json` is not supported (flags conflict).
-        let volumes = self.volume_list(prefix).await?;
+        let volumes = self.volume_list(label).await?;
 
         let futures = volumes.into_iter().map(|vol| async move {
             let output = self
@@ -477,9 +780,9 @@ impl ContainerRuntime for NativePodmanRuntime {
                 return Ok(None);
             }
 
-            let du_output = tokio::process::Command::new("du")
-                .args(["-sb", &mountpoint])
-                .output()
+            let du_output = self
+                .executor
+                .output("du", &["-sb", &mountpoint])
                 .await
                 .map_err(|e| MinoError::io("du", e))?;
 
@@ -492,10 +795,7 @@ impl ContainerRuntime for NativePodmanRuntime {
             Ok(size.map(|s| (vol.name.clone(), s)))
         });
 
-        let results: Vec<MinoResult<Option<(String, u64)>>> =
-            futures_util::future::join_all(futures).await;
-
-        super::collect_disk_usage(results)
+        super::measure_volumes(futures).await
     }
 
     async fn exec_in_container(
@@ -514,6 +814,44 @@ impl ContainerRuntime for NativePodmanRuntime {
         self.exec_interactive(&args).await
     }
 
+    async fn copy_to_container(
+        &self,
+        container_id: &str,
+        host_path: &Path,
+        container_path: &str,
+    ) -> MinoResult<()> {
+        debug!("Copying {} into {}", host_path.display(), container_id);
+        let source = host_path.display().to_string();
+        let target = format!("{}:{}", container_id, container_path);
+        let output = self.exec(&["cp", &source, &target]).await?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec("podman cp", stderr))
+        }
+    }
+
+    async fn copy_from_container(
+        &self,
+        container_id: &str,
+        container_path: &str,
+        host_path: &Path,
+    ) -> MinoResult<()> {
+        debug!("Copying {} out of {}", container_path, container_id);
+        let source = format!("{}:{}", container_id, container_path);
+        let target = host_path.display().to_string();
+        let output = self.exec(&["cp", &source, &target]).await?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec("podman cp", stderr))
+        }
+    }
+
     async fn get_container_exit_code(&self, container_id: &str) -> MinoResult<Option<i32>> {
         debug!("Waiting for container exit: {}", container_id);
 
@@ -551,6 +889,92 @@ impl ContainerRuntime for NativePodmanRuntime {
         }
     }
 
+    async fn container_restart_count(&self, container_id: &str) -> MinoResult<Option<u32>> {
+        let output = self
+            .exec(&["inspect", "--format", "{{.RestartCount}}", container_id])
+            .await?;
+        super::parse_restart_count(&output, "podman inspect")
+    }
+
+    async fn container_is_running(&self, container_id: &str) -> MinoResult<bool> {
+        let output = self
+            .exec(&["inspect", "--format", "{{.State.Running}}", container_id])
+            .await?;
+        super::parse_running(&output, "podman inspect")
+    }
+
+    async fn attach(&self, container_id: &str) -> MinoResult<i32> {
+        debug!("Attaching to container: {}", container_id);
+        self.exec_interactive(&["attach", container_id]).await
+    }
+
+    async fn registry_login(
+        &self,
+        registry: &str,
+        username: Option<&str>,
+        password_stdin: bool,
+    ) -> MinoResult<()> {
+        debug!("Logging in to registry: {}", registry);
+        let args = super::registry_login_args(registry, username, password_stdin);
+        let args_refs: Vec<&str> = args.iter().map(String::as_str).collect();
+        let code = self.exec_interactive(&args_refs).await?;
+        super::registry_command_result("podman login", registry, code)
+    }
+
+    async fn registry_login_password(
+        &self,
+        registry: &str,
+        username: &str,
+        password: &str,
+    ) -> MinoResult<()> {
+        debug!("Logging in to registry: {}", registry);
+        let args = super::registry_login_args(registry, Some(username), true);
+        let args_refs: Vec<&str> = args.iter().map(String::as_str).collect();
+        let output = self
+            .executor
+            .output_with_input("podman", &args_refs, password.as_bytes())
+            .await
+            .map_err(|e| MinoError::command_failed("podman login", e))?;
+        super::registry_output_result("podman login", &output)
+    }
+
+    async fn registry_logout(&self, registry: &str) -> MinoResult<()> {
+        debug!("Logging out of registry: {}", registry);
+        let code = self.exec_interactive(&["logout", registry]).await?;
+        super::registry_command_result("podman logout", registry, code)
+    }
+
+    async fn pod_create(&self, spec: &PodSpec) -> MinoResult<()> {
+        debug!("Creating pod: {}", spec.name);
+        let args = spec.create_args();
+        let args_refs: Vec<&str> = args.iter().map(String::as_str).collect();
+        let output = self.exec(&args_refs).await?;
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec("podman pod create", stderr))
+        }
+    }
+
+    async fn pod_remove(&self, name: &str) -> MinoResult<()> {
+        debug!("Removing pod: {}", name);
+        let output = self
+            .exec(&["pod", "rm", "--force", "--ignore", name])
+            .await?;
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec("podman pod rm", stderr))
+        }
+    }
+
+    async fn container_usage(&self, container_id: &str) -> MinoResult<ContainerUsage> {
+        let output = self.exec(&super::usage_stats_args(container_id)).await?;
+        super::parse_usage(&output, "podman stats")
+    }
+
     async fn logs_follow_until(
         &self,
         container_id: &str,
@@ -574,6 +998,7 @@ impl ContainerRuntime for NativePodmanRuntime {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::orchestration::mock::RecordingExecutor;
 
     #[test]
     fn native_podman_runtime_new() {
@@ -583,7 +1008,23 @@ mod tests {
 
     #[test]
     fn native_podman_runtime_default() {
-        let runtime = NativePodmanRuntime;
+        let runtime = NativePodmanRuntime::new();
         assert_eq!(runtime.runtime_name(), "Native Podman");
     }
+
+    #[tokio::test]
+    async fn registry_login_password_uses_password_stdin() {
+        let exec = Arc::new(RecordingExecutor::new().on_fail("podman login", 125, "unauthorized"));
+        let runtime = NativePodmanRuntime::with_executor(exec.clone());
+
+        let err = runtime
+            .registry_login_password("europe-west1-docker.pkg.dev", "oauth2accesstoken", "ya29")
+            .await
+            .unwrap_err();
+
+        exec.assert_ran(
+            "podman login --username oauth2accesstoken --password-stdin europe-west1-docker.pkg.dev",
+        );
+        assert!(err.to_string().contains("unauthorized"));
+    }
 }
@@ -2,10 +2,10 @@
 
 use crate::config::schema::VmConfig;
 use crate::error::{MinoError, MinoResult};
-use crate::orchestration::podman::redact_args;
+use crate::redact::redact_args;
 use std::process::Stdio;
 use tokio::process::Command;
-use tracing::debug;
+use tracing::{debug, warn};
 
 /// OrbStack manager
 #[derive(Clone)]
@@ -89,6 +89,77 @@ impl OrbStack {
         }
     }
 
+    /// Stop OrbStack and all of its machines
+    pub async fn stop() -> MinoResult<()> {
+        debug!("Stopping OrbStack...");
+
+        let status = Command::new("orb")
+            .arg("stop")
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .status()
+            .await
+            .map_err(|e| MinoError::command_failed("orb stop", e))?;
+
+        if status.success() {
+            Ok(())
+        } else {
+            Err(MinoError::VmStart("Failed to stop OrbStack".to_string()))
+        }
+    }
+
+    /// `orb config` settings that differ from `vm.cpus` / `vm.memory_gb`.
+    /// Fails when `orb config show` does, rather than reporting every
+    /// setting as changed.
+    pub async fn pending_resources(&self) -> MinoResult<Vec<(&'static str, String)>> {
+        let wanted = resource_settings(&self.config);
+        if wanted.is_empty() {
+            return Ok(wanted);
+        }
+
+        let output = Command::new("orb")
+            .args(["config", "show"])
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .output()
+            .await
+            .map_err(|e| MinoError::command_failed("orb config show", e))?;
+        if !output.status.success() {
+            return Err(MinoError::VmCommand("orb config show failed".to_string()));
+        }
+
+        let shown = String::from_utf8_lossy(&output.stdout);
+        Ok(pending_settings(wanted, &shown))
+    }
+
+    /// Apply `vm.cpus` / `vm.memory_gb` with `orb config set`.
+    ///
+    /// Returns whether anything changed; OrbStack must be restarted for
+    /// changes to take effect.
+    pub async fn apply_resources(&self) -> MinoResult<bool> {
+        let pending = self.pending_resources().await?;
+        for (key, value) in &pending {
+            debug!("Setting OrbStack {} = {}", key, value);
+            let output = Command::new("orb")
+                .args(["config", "set", key, value])
+                .stdout(Stdio::null())
+                .stderr(Stdio::piped())
+                .output()
+                .await
+                .map_err(|e| MinoError::command_failed("orb config set", e))?;
+
+            if !output.status.success() {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                return Err(MinoError::VmStart(format!(
+                    "Failed to set OrbStack {}: {}",
+                    key,
+                    stderr.trim()
+                )));
+            }
+        }
+        Ok(!pending.is_empty())
+    }
+
     /// Check if the VM exists
     pub async fn vm_exists(&self) -> MinoResult<bool> {
         let output = Command::new("orb")
@@ -130,8 +201,13 @@ impl OrbStack {
 
     /// Ensure VM is running
     pub async fn ensure_vm_running(&self) -> MinoResult<()> {
-        // First ensure OrbStack itself is running
-        if !Self::is_running().await? {
+        // First ensure OrbStack itself is running. Sizing is applied only
+        // while it is stopped: restarting a running OrbStack would stop its
+        // other machines, so that is left to `mino setup`, which asks first.
+        if Self::is_running().await? {
+            self.warn_pending_resources().await;
+        } else {
+            self.apply_resources().await?;
             Self::start().await?;
         }
 
@@ -149,6 +225,18 @@ impl OrbStack {
         Ok(())
     }
 
+    /// Warn when the running OrbStack's sizing differs from config.
+    /// Silent when the settings can't be read.
+    async fn warn_pending_resources(&self) {
+        match self.pending_resources().await {
+            Ok(pending) if !pending.is_empty() => warn!(
+                "OrbStack resources differ from vm.cpus / vm.memory_gb; run 'mino setup' to apply them (restarts OrbStack)"
+            ),
+            Ok(_) => {}
+            Err(e) => debug!("Skipping OrbStack resource check: {}", e),
+        }
+    }
+
     /// Get VM status
     pub async fn vm_status(&self) -> MinoResult<String> {
         let output = Command::new("orb")
@@ -215,6 +303,32 @@ impl OrbStack {
         Ok(output)
     }
 
+    /// Execute a command in the VM with `input` on stdin
+    pub async fn exec_with_input(
+        &self,
+        command: &[&str],
+        input: &[u8],
+    ) -> MinoResult<std::process::Output> {
+        debug!(
+            "Executing with input in VM {}: {:?}",
+            self.config.name,
+            redact_args(command)
+        );
+
+        let mut cmd = Command::new("orb");
+        cmd.arg("-m").arg(&self.config.name);
+        cmd.args(command);
+
+        super::output_with_input(&mut cmd, input)
+            .await
+            .map_err(|e| {
+                MinoError::command_failed(
+                    format!("orb -m {} {:?}", self.config.name, redact_args(command)),
+                    e,
+                )
+            })
+    }
+
     /// Execute a command in the VM and return stdout
     pub async fn exec_output(&self, command: &[&str]) -> MinoResult<String> {
         let output = self.exec(command).await?;
@@ -286,6 +400,38 @@ impl OrbStack {
     }
 }
 
+/// `orb config` keys and values for the configured VM sizing
+fn resource_settings(config: &VmConfig) -> Vec<(&'static str, String)> {
+    let mut settings = Vec::new();
+    if config.cpus > 0 {
+        settings.push(("cpu", config.cpus.to_string()));
+    }
+    if config.memory_gb > 0 {
+        settings.push((
+            "memory_mib",
+            (u64::from(config.memory_gb) * 1024).to_string(),
+        ));
+    }
+    settings
+}
+
+/// Settings whose value differs from `orb config show` ("key: value" lines)
+fn pending_settings(
+    wanted: Vec<(&'static str, String)>,
+    shown: &str,
+) -> Vec<(&'static str, String)> {
+    wanted
+        .into_iter()
+        .filter(|(key, value)| {
+            let current = shown.lines().find_map(|line| {
+                let (k, v) = line.split_once(':')?;
+                (k.trim() == *key).then(|| v.trim())
+            });
+            current != Some(value.as_str())
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -296,4 +442,32 @@ mod tests {
         let orb = OrbStack::new(config);
         assert_eq!(orb.vm_name(), "mino");
     }
+
+    #[test]
+    fn resource_settings_skip_defaults() {
+        assert!(resource_settings(&VmConfig::default()).is_empty());
+
+        let config = VmConfig {
+            cpus: 4,
+            memory_gb: 8,
+            ..VmConfig::default()
+        };
+        assert_eq!(
+            resource_settings(&config),
+            vec![("cpu", "4".to_string()), ("memory_mib", "8192".to_string())]
+        );
+    }
+
+    #[test]
+    fn pending_settings_compares_orb_config() {
+        let shown = "app.start_at_login: false\ncpu: 4\nmemory_mib: 4096\n";
+        let wanted = vec![("cpu", "4".to_string()), ("memory_mib", "8192".to_string())];
+        assert_eq!(
+            pending_settings(wanted, shown),
+            vec![("memory_mib", "8192".to_string())]
+        );
+
+        let wanted = vec![("cpu", "8".to_string())];
+        assert_eq!(pending_settings(wanted, ""), vec![("cpu", "8".to_string())]);
+    }
 }
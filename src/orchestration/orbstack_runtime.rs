@@ -5,16 +5,20 @@
 use crate::config::schema::VmConfig;
 use crate::error::{MinoError, MinoResult};
 use crate::orchestration::orbstack::OrbStack;
-use crate::orchestration::podman::{redact_args, ContainerConfig};
-use crate::orchestration::runtime::{ContainerRuntime, VolumeInfo};
+use crate::orchestration::podman::{build_args, ContainerConfig, PodSpec, PullPolicy};
+use crate::orchestration::runtime::{ContainerRuntime, ContainerUsage, VolumeInfo};
+use crate::redact::redact_args;
 use async_trait::async_trait;
 use std::collections::HashMap;
 use std::path::Path;
+use tokio::sync::OnceCell;
 use tracing::{debug, warn};
 
 /// Container runtime using OrbStack VM + Podman (for macOS)
 pub struct OrbStackRuntime {
     orbstack: OrbStack,
+    /// Whether `podman pull` accepts `--retry` (probed once)
+    pull_retry: OnceCell<bool>,
 }
 
 impl OrbStackRuntime {
@@ -22,6 +26,7 @@ impl OrbStackRuntime {
     pub fn new(vm_config: VmConfig) -> Self {
         Self {
             orbstack: OrbStack::new(vm_config),
+            pull_retry: OnceCell::new(),
         }
     }
 
@@ -127,21 +132,45 @@ impl OrbStackRuntime {
         Ok(())
     }
 
-    /// Pull an image
+    /// Whether the installed podman supports `pull --retry` (cached)
+    async fn supports_pull_retry(&self) -> bool {
+        *self
+            .pull_retry
+            .get_or_init(|| async {
+                match self.orbstack.exec(&["podman", "pull", "--help"]).await {
+                    Ok(output) => {
+                        super::pull_help_has_retry(&String::from_utf8_lossy(&output.stdout))
+                    }
+                    Err(_) => false,
+                }
+            })
+            .await
+    }
+
+    /// Pull an image, retrying interrupted downloads
     async fn pull(&self, image: &str) -> MinoResult<()> {
         debug!("Pulling image: {}", image);
 
-        let output = self.orbstack.exec(&["podman", "pull", image]).await?;
+        let plan = super::podman_pull_plan(image, self.supports_pull_retry().await, None);
+        let args: Vec<&str> = std::iter::once("podman")
+            .chain(plan.args.iter().map(String::as_str))
+            .collect();
+        let args = &args;
 
-        if output.status.success() {
-            Ok(())
-        } else {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            Err(MinoError::ImagePull {
-                image: image.to_string(),
-                reason: stderr.to_string(),
-            })
-        }
+        super::pull_with_retry(image, plan.attempts, || async move {
+            let output = self.orbstack.exec(args).await?;
+
+            if output.status.success() {
+                Ok(())
+            } else {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                Err(MinoError::ImagePull {
+                    image: image.to_string(),
+                    reason: stderr.to_string(),
+                })
+            }
+        })
+        .await
     }
 }
 
@@ -163,6 +192,18 @@ impl ContainerRuntime for OrbStackRuntime {
         self.ensure_rootless().await
     }
 
+    async fn check_device(&self, path: &str) -> MinoResult<()> {
+        let output = self.orbstack.exec(&["test", "-e", path]).await?;
+        super::device_check(output.status.success(), path, "the OrbStack VM")
+    }
+
+    async fn check_gpu_support(&self) -> MinoResult<()> {
+        Err(MinoError::GpuUnsupported {
+            runtime: self.runtime_name().to_string(),
+            reason: "macOS VMs cannot pass NVIDIA GPUs through to containers".to_string(),
+        })
+    }
+
     async fn run(&self, config: &ContainerConfig, command: &[String]) -> MinoResult<String> {
         // Ensure image is available
         if !self.image_exists(&config.image).await? {
@@ -276,6 +317,106 @@ impl ContainerRuntime for OrbStackRuntime {
         }
     }
 
+    async fn signal(&self, container_id: &str, signal: &str) -> MinoResult<()> {
+        debug!("Sending {} to container: {}", signal, container_id);
+
+        let output = self
+            .orbstack
+            .exec(&["podman", "kill", "--signal", signal, container_id])
+            .await?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec("podman kill", stderr))
+        }
+    }
+
+    async fn pause(&self, container_id: &str) -> MinoResult<()> {
+        debug!("Pausing container: {}", container_id);
+
+        let output = self
+            .orbstack
+            .exec(&["podman", "pause", container_id])
+            .await?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec("podman pause", stderr))
+        }
+    }
+
+    async fn unpause(&self, container_id: &str) -> MinoResult<()> {
+        debug!("Unpausing container: {}", container_id);
+
+        let output = self
+            .orbstack
+            .exec(&["podman", "unpause", container_id])
+            .await?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec("podman unpause", stderr))
+        }
+    }
+
+    async fn checkpoint(
+        &self,
+        container_id: &str,
+        archive: &Path,
+        leave_running: bool,
+    ) -> MinoResult<()> {
+        debug!(
+            "Checkpointing container {} to {}",
+            container_id,
+            archive.display()
+        );
+        let archive = archive.display().to_string();
+        let mut args = vec!["podman"];
+        args.extend(super::checkpoint_args(
+            container_id,
+            &archive,
+            leave_running,
+        ));
+        let output = self.orbstack.exec(&args).await?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(super::checkpoint_error(
+                self.runtime_name(),
+                "podman container checkpoint",
+                &stderr,
+            ))
+        }
+    }
+
+    async fn restore(&self, archive: &Path) -> MinoResult<String> {
+        debug!("Restoring container from {}", archive.display());
+        let archive = archive.display().to_string();
+        let output = self
+            .orbstack
+            .exec(&["podman", "container", "restore", "--import", &archive])
+            .await?;
+
+        if output.status.success() {
+            super::parse_restored_id(&String::from_utf8_lossy(&output.stdout))
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(super::checkpoint_error(
+                self.runtime_name(),
+                "podman container restore",
+                &stderr,
+            ))
+        }
+    }
+
     async fn remove(&self, container_id: &str) -> MinoResult<()> {
         debug!("Removing container: {}", container_id);
 
@@ -309,6 +450,28 @@ impl ContainerRuntime for OrbStackRuntime {
         Ok(())
     }
 
+    async fn container_list_labeled(&self, label: &str) -> MinoResult<Vec<String>> {
+        let filter = format!("label={}", label);
+        let output = self
+            .orbstack
+            .exec(&[
+                "podman", "ps", "-a", "--filter", &filter, "--format", "{{.ID}}",
+            ])
+            .await?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(MinoError::command_exec("podman ps", stderr));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        Ok(stdout
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(String::from)
+            .collect())
+    }
+
     async fn logs(&self, container_id: &str, lines: u32) -> MinoResult<String> {
         let tail_arg = if lines == 0 {
             "all".to_string()
@@ -331,6 +494,31 @@ impl ContainerRuntime for OrbStackRuntime {
         Ok(())
     }
 
+    async fn logs_follow_lines(
+        &self,
+        container_id: &str,
+        lines: u32,
+        on_line: &(dyn Fn(String) + Send + Sync),
+    ) -> MinoResult<()> {
+        let tail_arg = if lines == 0 {
+            "all".to_string()
+        } else {
+            lines.to_string()
+        };
+
+        let mut child = self.orbstack.spawn_piped(&[
+            "podman",
+            "logs",
+            "-f",
+            "--tail",
+            &tail_arg,
+            container_id,
+        ])?;
+
+        super::forward_child_lines(&mut child, on_line).await;
+        Ok(())
+    }
+
     async fn image_exists(&self, image: &str) -> MinoResult<bool> {
         let output = self
             .orbstack
@@ -339,12 +527,81 @@ impl ContainerRuntime for OrbStackRuntime {
         Ok(output.status.success())
     }
 
-    async fn build_image(&self, context_dir: &Path, tag: &str) -> MinoResult<()> {
-        let context_str = context_dir.display().to_string();
+    async fn image_pull(&self, image: &str) -> MinoResult<()> {
+        self.pull(image).await
+    }
+
+    async fn image_pull_with_progress(
+        &self,
+        image: &str,
+        platform: Option<&str>,
+        on_output: &(dyn Fn(String) + Send + Sync),
+    ) -> MinoResult<()> {
+        let plan = super::podman_pull_plan(image, self.supports_pull_retry().await, platform);
+        let args: Vec<&str> = std::iter::once("podman")
+            .chain(plan.args.iter().map(String::as_str))
+            .collect();
+        let args = &args;
+
+        super::pull_with_retry(image, plan.attempts, || async move {
+            let mut child = self.orbstack.spawn_piped(args)?;
+            super::stream_pull(&mut child, image, on_output).await
+        })
+        .await
+    }
+
+    async fn image_platform(&self, image: &str) -> MinoResult<Option<String>> {
         let output = self
             .orbstack
-            .exec(&["podman", "build", "-t", tag, &context_str])
+            .exec(&[
+                "podman",
+                "image",
+                "inspect",
+                "--format",
+                super::IMAGE_PLATFORM_FORMAT,
+                image,
+            ])
             .await?;
+        if !output.status.success() {
+            return Ok(None);
+        }
+        Ok(super::parse_image_platform(&String::from_utf8_lossy(
+            &output.stdout,
+        )))
+    }
+
+    async fn image_user(&self, image: &str) -> MinoResult<Option<String>> {
+        if !self.image_exists(image).await? {
+            self.pull(image).await?;
+        }
+
+        let output = self
+            .orbstack
+            .exec(&[
+                "podman",
+                "image",
+                "inspect",
+                "--format",
+                "{{.Config.User}}",
+                image,
+            ])
+            .await?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(MinoError::command_exec("podman image inspect", stderr));
+        }
+
+        let user = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        Ok(if user.is_empty() { None } else { Some(user) })
+    }
+
+    async fn build_image(&self, context_dir: &Path, tag: &str, pull: PullPolicy) -> MinoResult<()> {
+        let build = build_args(tag, &context_dir.display().to_string(), pull);
+        let args: Vec<&str> = std::iter::once("podman")
+            .chain(build.iter().map(String::as_str))
+            .collect();
+        let output = self.orbstack.exec(&args).await?;
 
         if !output.status.success() {
             let stderr = String::from_utf8_lossy(&output.stderr);
@@ -363,12 +620,14 @@ impl ContainerRuntime for OrbStackRuntime {
         &self,
         context_dir: &Path,
         tag: &str,
+        pull: PullPolicy,
         on_output: &(dyn Fn(String) + Send + Sync),
     ) -> MinoResult<()> {
-        let context_str = context_dir.display().to_string();
-        let mut child = self
-            .orbstack
-            .spawn_piped(&["podman", "build", "-t", tag, &context_str])?;
+        let build = build_args(tag, &context_dir.display().to_string(), pull);
+        let args: Vec<&str> = std::iter::once("podman")
+            .chain(build.iter().map(String::as_str))
+            .collect();
+        let mut child = self.orbstack.spawn_piped(&args)?;
 
         let all_output = super::stream_child_output(&mut child, on_output).await;
 
@@ -433,6 +692,36 @@ impl ContainerRuntime for OrbStackRuntime {
         Ok(images)
     }
 
+    async fn image_list_labeled(&self, label: &str) -> MinoResult<Vec<String>> {
+        let filter = format!("label={}", label);
+        let output = self
+            .orbstack
+            .exec(&[
+                "podman",
+                "images",
+                "--filter",
+                &filter,
+                "--format",
+                "{{.Repository}}:{{.Tag}}",
+            ])
+            .await?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(MinoError::command_exec("podman images", stderr));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let images: Vec<String> = stdout
+            .lines()
+            // Untagged images carrying the label show up as <none>:<none>
+            .filter(|line| !line.is_empty() && !line.contains("<none>"))
+            .map(String::from)
+            .collect();
+
+        Ok(images)
+    }
+
     fn runtime_name(&self) -> &'static str {
         "OrbStack + Podman"
     }
@@ -464,6 +753,22 @@ impl ContainerRuntime for OrbStackRuntime {
         }
     }
 
+    async fn network_create(&self, name: &str) -> MinoResult<()> {
+        debug!("Creating network: {}", name);
+
+        let output = self
+            .orbstack
+            .exec(&["podman", "network", "create", "--ignore", name])
+            .await?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec("podman network create", stderr))
+        }
+    }
+
     async fn volume_remove(&self, name: &str) -> MinoResult<()> {
         debug!("Removing volume: {}", name);
 
@@ -485,7 +790,7 @@ impl ContainerRuntime for OrbStackRuntime {
         }
     }
 
-    async fn volume_list(&self, prefix: &str) -> MinoResult<Vec<VolumeInfo>> {
+    async fn volume_list(&self, label: &str) -> MinoResult<Vec<VolumeInfo>> {
         let output = self
             .orbstack
             .exec(&["podman", "volume", "ls", "--format", "json"])
@@ -497,7 +802,7 @@ impl ContainerRuntime for OrbStackRuntime {
         }
 
         let stdout = String::from_utf8_lossy(&output.stdout);
-        super::parse_volume_list_json(&stdout, prefix)
+        super::parse_volume_list_json(&stdout, label)
     }
 
     async fn volume_inspect(&self, name: &str) -> MinoResult<Option<VolumeInfo>> {
@@ -518,10 +823,32 @@ impl ContainerRuntime for OrbStackRuntime {
         super::parse_volume_inspect_json(&stdout, name)
     }
 
-    async fn volume_disk_usage(&self, prefix: &str) -> MinoResult<HashMap<String, u64>> {
+    async fn volume_export(&self, name: &str, archive: &Path) -> MinoResult<()> {
+        debug!("Exporting volume {} to {}", name, archive.display());
+        let archive = archive.display().to_string();
+        let mut args = vec!["podman"];
+        args.extend(super::volume_export_args(name, &archive));
+        let output = self.orbstack.exec(&args).await?;
+        super::registry_output_result("podman volume export", &output)
+    }
+
+    fn supports_volume_archives(&self) -> bool {
+        true
+    }
+
+    async fn volume_import(&self, name: &str, archive: &Path) -> MinoResult<()> {
+        debug!("Importing {} into volume {}", archive.display(), name);
+        let archive = archive.display().to_string();
+        let mut args = vec!["podman"];
+        args.extend(super::volume_import_args(name, &archive));
+        let output = self.orbstack.exec(&args).await?;
+        super::registry_output_result("podman volume import", &output)
+    }
+
+    async fn volume_disk_usage(&self, label: &str) -> MinoResult<HashMap<String, u64>> {
         // Get volume sizes by inspecting each volume individually.
         // Note: `podman system df -v --format json` is not supported (flags conflict).
-        let volumes = self.volume_list(prefix).await?;
+        let volumes = self.volume_list(label).await?;
 
         let futures = volumes.into_iter().map(|vol| async move {
             let output = self
@@ -556,10 +883,7 @@ impl ContainerRuntime for OrbStackRuntime {
             Ok(size.map(|s| (vol.name.clone(), s)))
         });
 
-        let results: Vec<MinoResult<Option<(String, u64)>>> =
-            futures_util::future::join_all(futures).await;
-
-        super::collect_disk_usage(results)
+        super::measure_volumes(futures).await
     }
 
     async fn exec_in_container(
@@ -578,6 +902,50 @@ impl ContainerRuntime for OrbStackRuntime {
         self.orbstack.exec_interactive(&args).await
     }
 
+    async fn copy_to_container(
+        &self,
+        container_id: &str,
+        host_path: &Path,
+        container_path: &str,
+    ) -> MinoResult<()> {
+        debug!("Copying {} into {}", host_path.display(), container_id);
+        let source = host_path.display().to_string();
+        let target = format!("{}:{}", container_id, container_path);
+        let output = self
+            .orbstack
+            .exec(&["podman", "cp", &source, &target])
+            .await?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec("podman cp", stderr))
+        }
+    }
+
+    async fn copy_from_container(
+        &self,
+        container_id: &str,
+        container_path: &str,
+        host_path: &Path,
+    ) -> MinoResult<()> {
+        debug!("Copying {} out of {}", container_path, container_id);
+        let source = format!("{}:{}", container_id, container_path);
+        let target = host_path.display().to_string();
+        let output = self
+            .orbstack
+            .exec(&["podman", "cp", &source, &target])
+            .await?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec("podman cp", stderr))
+        }
+    }
+
     async fn get_container_exit_code(&self, container_id: &str) -> MinoResult<Option<i32>> {
         debug!("Waiting for container exit: {}", container_id);
 
@@ -621,6 +989,123 @@ impl ContainerRuntime for OrbStackRuntime {
         }
     }
 
+    async fn container_restart_count(&self, container_id: &str) -> MinoResult<Option<u32>> {
+        let output = self
+            .orbstack
+            .exec(&[
+                "podman",
+                "inspect",
+                "--format",
+                "{{.RestartCount}}",
+                container_id,
+            ])
+            .await?;
+        super::parse_restart_count(&output, "podman inspect")
+    }
+
+    async fn container_is_running(&self, container_id: &str) -> MinoResult<bool> {
+        let output = self
+            .orbstack
+            .exec(&[
+                "podman",
+                "inspect",
+                "--format",
+                "{{.State.Running}}",
+                container_id,
+            ])
+            .await?;
+        super::parse_running(&output, "podman inspect")
+    }
+
+    async fn attach(&self, container_id: &str) -> MinoResult<i32> {
+        debug!("Attaching to container: {}", container_id);
+        self.orbstack
+            .exec_interactive(&["podman", "attach", container_id])
+            .await
+    }
+
+    async fn registry_login(
+        &self,
+        registry: &str,
+        username: Option<&str>,
+        password_stdin: bool,
+    ) -> MinoResult<()> {
+        debug!("Logging in to registry: {}", registry);
+        let mut args = vec!["podman".to_string()];
+        args.extend(super::registry_login_args(
+            registry,
+            username,
+            password_stdin,
+        ));
+        let args_refs: Vec<&str> = args.iter().map(String::as_str).collect();
+        let code = self.orbstack.exec_interactive(&args_refs).await?;
+        super::registry_command_result("podman login", registry, code)
+    }
+
+    async fn registry_login_password(
+        &self,
+        registry: &str,
+        username: &str,
+        password: &str,
+    ) -> MinoResult<()> {
+        debug!("Logging in to registry: {}", registry);
+        let mut args = vec!["podman".to_string()];
+        args.extend(super::registry_login_args(registry, Some(username), true));
+        let args_refs: Vec<&str> = args.iter().map(String::as_str).collect();
+        let output = self
+            .orbstack
+            .exec_with_input(&args_refs, password.as_bytes())
+            .await?;
+        super::registry_output_result("podman login", &output)
+    }
+
+    async fn registry_logout(&self, registry: &str) -> MinoResult<()> {
+        debug!("Logging out of registry: {}", registry);
+        let code = self
+            .orbstack
+            .exec_interactive(&["podman", "logout", registry])
+            .await?;
+        super::registry_command_result("podman logout", registry, code)
+    }
+
+    async fn pod_create(&self, spec: &PodSpec) -> MinoResult<()> {
+        debug!("Creating pod: {}", spec.name);
+        let args = spec.create_args();
+        let args_refs: Vec<&str> = args.iter().map(String::as_str).collect();
+        let output = {
+            let mut podman_args = vec!["podman"];
+            podman_args.extend(args_refs);
+            self.orbstack.exec(&podman_args).await?
+        };
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec("podman pod create", stderr))
+        }
+    }
+
+    async fn pod_remove(&self, name: &str) -> MinoResult<()> {
+        debug!("Removing pod: {}", name);
+        let output = self
+            .orbstack
+            .exec(&["podman", "pod", "rm", "--force", "--ignore", name])
+            .await?;
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec("podman pod rm", stderr))
+        }
+    }
+
+    async fn container_usage(&self, container_id: &str) -> MinoResult<ContainerUsage> {
+        let mut args = vec!["podman"];
+        args.extend_from_slice(&super::usage_stats_args(container_id));
+        let output = self.orbstack.exec(&args).await?;
+        super::parse_usage(&output, "podman stats")
+    }
+
     async fn logs_follow_until(
         &self,
         container_id: &str,
@@ -3,7 +3,244 @@
 //! Contains data structures and shared argument-building logic
 //! used by both `NativePodmanRuntime` and `OrbStackRuntime`.
 
+use crate::error::{MinoError, MinoResult};
 use std::collections::HashMap;
+use std::net::IpAddr;
+
+/// When to pull the session image from its registry
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PullPolicy {
+    /// Always pull, even if the image exists locally
+    Always,
+    /// Pull only when the image is not present locally
+    #[default]
+    Missing,
+    /// Never pull; fail if the image is not present locally
+    Never,
+}
+
+impl std::str::FromStr for PullPolicy {
+    type Err = MinoError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "always" => Ok(Self::Always),
+            "missing" => Ok(Self::Missing),
+            "never" => Ok(Self::Never),
+            other => Err(MinoError::User(format!(
+                "Invalid pull policy '{}'. Valid policies: always, missing, never",
+                other
+            ))),
+        }
+    }
+}
+
+impl std::fmt::Display for PullPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Always => write!(f, "always"),
+            Self::Missing => write!(f, "missing"),
+            Self::Never => write!(f, "never"),
+        }
+    }
+}
+
+/// `podman build` arguments tagging the image built from `context` as
+/// `tag`, pulling its base image according to `pull`
+pub fn build_args(tag: &str, context: &str, pull: PullPolicy) -> Vec<String> {
+    vec![
+        "build".to_string(),
+        format!("--pull={}", pull),
+        "-t".to_string(),
+        tag.to_string(),
+        context.to_string(),
+    ]
+}
+
+/// User namespace modes accepted for `--userns`
+const USERNS_MODES: &[&str] = &["keep-id", "auto", "host", "nomap"];
+
+/// Validate a `--userns` mode: `keep-id`, `auto`, `host`, or `nomap`.
+///
+/// `keep-id` and `auto` may carry Podman's options after a colon
+/// (`keep-id:uid=1000,gid=1000`), which are passed through unchanged.
+pub fn parse_userns(value: &str) -> MinoResult<String> {
+    let (mode, options) = match value.split_once(':') {
+        Some((mode, options)) => (mode, Some(options)),
+        None => (value, None),
+    };
+    let valid = match options {
+        None => USERNS_MODES.contains(&mode),
+        Some(options) => matches!(mode, "keep-id" | "auto") && !options.is_empty(),
+    };
+    if valid {
+        Ok(value.to_string())
+    } else {
+        Err(MinoError::User(format!(
+            "Invalid user namespace mode '{}'. Valid modes: {}",
+            value,
+            USERNS_MODES.join(", ")
+        )))
+    }
+}
+
+/// Image platforms sessions can run, in `os/arch` form
+pub const PLATFORMS: &[&str] = &["linux/amd64", "linux/arm64"];
+
+/// Validate an image platform, accepting bare architectures
+/// (`amd64`, `x86_64`, `arm64`, `aarch64`) and normalizing to `os/arch`.
+pub fn parse_platform(value: &str) -> MinoResult<String> {
+    let arch = value.strip_prefix("linux/").unwrap_or(value);
+    match arch.to_lowercase().as_str() {
+        "amd64" | "x86_64" => Ok("linux/amd64".to_string()),
+        "arm64" | "aarch64" => Ok("linux/arm64".to_string()),
+        _ => Err(MinoError::User(format!(
+            "Invalid platform '{}'. Valid platforms: {}",
+            value,
+            PLATFORMS.join(", ")
+        ))),
+    }
+}
+
+/// Platform of images the container engine runs natively: the host's
+/// architecture (macOS VMs match the Mac's)
+pub fn host_platform() -> &'static str {
+    match std::env::consts::ARCH {
+        "aarch64" => "linux/arm64",
+        _ => "linux/amd64",
+    }
+}
+
+/// Validate a container hostname: dot-separated labels of letters, digits,
+/// and inner hyphens, at most 63 characters each and 253 in total.
+pub fn parse_hostname(value: &str) -> MinoResult<String> {
+    let valid_label = |label: &str| {
+        (1..=63).contains(&label.len())
+            && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+    };
+    if value.len() <= 253 && value.split('.').all(valid_label) {
+        Ok(value.to_string())
+    } else {
+        Err(MinoError::User(format!(
+            "Invalid hostname '{}'. Use letters, digits, hyphens, and dots (internal.corp)",
+            value
+        )))
+    }
+}
+
+/// Validate an `--add-host` entry (`name:ip`) and return its hostname and
+/// address. Everything after the first colon is the address, so IPv6
+/// entries need no brackets (`db.corp:fd00::5`).
+pub fn parse_extra_host(spec: &str) -> MinoResult<(&str, IpAddr)> {
+    let parsed = spec.split_once(':').and_then(|(name, ip)| {
+        let ip = ip.parse().ok()?;
+        parse_hostname(name).ok().map(|_| (name, ip))
+    });
+    parsed.ok_or_else(|| {
+        MinoError::User(format!(
+            "Invalid host entry '{}'. Use hostname:ip (internal.corp:10.0.0.5)",
+            spec
+        ))
+    })
+}
+
+/// Host path of a `--device` spec (`/dev/ttyUSB0[:/dev/ttyS0][:rwm]`), or
+/// `None` for a CDI device name (`vendor.com/class=name`).
+pub fn device_host_path(spec: &str) -> MinoResult<Option<&str>> {
+    if spec.starts_with('/') {
+        Ok(spec.split(':').next())
+    } else if spec.contains('=') && spec.contains('/') {
+        Ok(None)
+    } else {
+        Err(MinoError::User(format!(
+            "Invalid device '{}'. Use an absolute host path (/dev/ttyUSB0[:/dev/ttyS0][:rwm]) \
+             or a CDI name (vendor.com/class=name)",
+            spec
+        )))
+    }
+}
+
+/// Validate a `--restart` policy: `no`, `on-failure`, or `on-failure:<max>`
+/// with at least one retry. Returns `None` for `no`.
+pub fn parse_restart_policy(value: &str) -> MinoResult<Option<String>> {
+    let valid = match value.split_once(':') {
+        None => matches!(value, "no" | "on-failure"),
+        Some((policy, max)) => {
+            policy == "on-failure" && max.parse::<u32>().is_ok_and(|max| max > 0)
+        }
+    };
+    if !valid {
+        return Err(MinoError::User(format!(
+            "Invalid restart policy '{}'. Use on-failure, on-failure:<max retries>, or no",
+            value
+        )));
+    }
+    Ok((value != "no").then(|| value.to_string()))
+}
+
+/// Resource names accepted for `--ulimit`
+const ULIMIT_NAMES: &[&str] = &[
+    "core",
+    "cpu",
+    "data",
+    "fsize",
+    "locks",
+    "memlock",
+    "msgqueue",
+    "nice",
+    "nofile",
+    "nproc",
+    "rss",
+    "rtprio",
+    "rttime",
+    "sigpending",
+    "stack",
+];
+
+/// Validate a `--ulimit` spec (`nofile=65535` or `nofile=1024:65535`, `-1`
+/// for unlimited) and return its resource name.
+pub fn ulimit_name(spec: &str) -> MinoResult<&str> {
+    let parse_limit = |v: &str| v.parse::<i64>().ok().filter(|n| *n >= -1);
+    let valid = spec.split_once('=').and_then(|(name, limits)| {
+        let (soft, hard) = match limits.split_once(':') {
+            Some((soft, hard)) => (parse_limit(soft)?, parse_limit(hard)?),
+            None => (parse_limit(limits)?, -1),
+        };
+        let within = match (soft, hard) {
+            (-1, -1) | (_, -1) => true,
+            (-1, _) => false,
+            (soft, hard) => soft <= hard,
+        };
+        (ULIMIT_NAMES.contains(&name) && within).then_some(name)
+    });
+    valid.ok_or_else(|| {
+        MinoError::User(format!(
+            "Invalid ulimit '{}'. Use <name>=<soft>[:<hard>] (nofile=65535:65535); \
+             soft must not exceed hard. Names: {}",
+            spec,
+            ULIMIT_NAMES.join(", ")
+        ))
+    })
+}
+
+/// Validate a `--tmpfs` spec (`/tmp` or `/tmp:size=1g,mode=1777`) and
+/// return its container path.
+pub fn tmpfs_target(spec: &str) -> MinoResult<&str> {
+    let (target, options) = match spec.split_once(':') {
+        Some((target, options)) => (target, Some(options)),
+        None => (spec, None),
+    };
+    if !target.starts_with('/') || options.is_some_and(str::is_empty) {
+        return Err(MinoError::User(format!(
+            "Invalid tmpfs mount '{}'. Use an absolute container path, \
+             optionally with options (/tmp:size=1g,mode=1777)",
+            spec
+        )));
+    }
+    Ok(target)
+}
 
 /// Container configuration for running a new container
 #[derive(Debug, Clone)]
@@ -18,6 +255,8 @@ pub struct ContainerConfig {
     pub env: HashMap<String, String>,
     /// Network mode
     pub network: String,
+    /// DNS names other containers on the same network resolve to this one
+    pub network_aliases: Vec<String>,
     /// Enable interactive mode
     pub interactive: bool,
     /// Allocate a TTY
@@ -30,29 +269,83 @@ pub struct ContainerConfig {
     pub security_opt: Vec<String>,
     /// PID limit (0 = no limit)
     pub pids_limit: u32,
+    /// Resource limits (`--ulimit`, e.g. `nofile=65535:65535`)
+    pub ulimits: Vec<String>,
+    /// CPU limit in cores (0 = no limit)
+    pub cpus: f64,
+    /// Memory limit in MB (0 = no limit)
+    pub memory_mb: u64,
+    /// Give the container every NVIDIA GPU (CDI device `nvidia.com/gpu=all`)
+    pub gpus: bool,
+    /// Host devices to pass through (`--device`, e.g. `/dev/ttyUSB0`)
+    pub devices: Vec<String>,
     /// Automatically remove container when it exits (--rm)
     pub auto_remove: bool,
     /// Mount root filesystem as read-only
     pub read_only: bool,
     /// Tmpfs mounts (e.g., "/tmp", "/run")
     pub tmpfs: Vec<String>,
+    /// User to run as (`--user`); `None` keeps the image default
+    pub user: Option<String>,
+    /// User namespace mode (`--userns`, e.g. `keep-id`); `None` keeps the runtime default
+    pub userns: Option<String>,
+    /// Restart policy (`--restart`, e.g. `on-failure:3`); incompatible with `auto_remove`
+    pub restart: Option<String>,
+    /// Container labels (`io.mino.session`, `io.mino.version`, ...)
+    pub labels: HashMap<String, String>,
+    /// Pod to join (`--pod`); the pod owns networking and the user namespace
+    pub pod: Option<String>,
+    /// Image platform (`--platform`, e.g. `linux/amd64`); `None` uses the host's
+    pub platform: Option<String>,
+    /// Container hostname (`--hostname`); `None` keeps the runtime's default
+    pub hostname: Option<String>,
+    /// Extra `/etc/hosts` entries (`--add-host`, `name:ip`)
+    pub extra_hosts: Vec<String>,
 }
 
 impl ContainerConfig {
     /// Append Podman container arguments to a command-line argument vector.
     ///
-    /// Pushes workdir, network, capabilities (drop before add), security options,
-    /// pids-limit, volumes, env vars, image, and the user command.
+    /// Pushes workdir, user, user namespace, network, labels, capabilities (drop before add), security options,
+    /// resource limits, GPU devices, volumes, env vars, image, and the user command.
     ///
     /// Used by both `NativePodmanRuntime` and `OrbStackRuntime`.
     pub fn push_args(&self, args: &mut Vec<String>, command: &[String]) {
         if self.auto_remove {
             args.push("--rm".to_string());
         }
-        args.push("-w".to_string());
-        args.push(self.workdir.clone());
-        args.push("--network".to_string());
-        args.push(self.network.clone());
+        if let Some(ref restart) = self.restart {
+            args.push("--restart".to_string());
+            args.push(restart.clone());
+        }
+        // Empty keeps the image's WORKDIR
+        if !self.workdir.is_empty() {
+            args.push("-w".to_string());
+            args.push(self.workdir.clone());
+        }
+        if let Some(ref user) = self.user {
+            args.push("--user".to_string());
+            args.push(user.clone());
+        }
+        if let Some(ref pod) = self.pod {
+            // Podman rejects --network and --userns for containers in a pod
+            args.push("--pod".to_string());
+            args.push(pod.clone());
+        } else {
+            if let Some(ref userns) = self.userns {
+                args.push("--userns".to_string());
+                args.push(userns.clone());
+            }
+            args.push("--network".to_string());
+            args.push(self.network.clone());
+            for alias in &self.network_aliases {
+                args.push("--network-alias".to_string());
+                args.push(alias.clone());
+            }
+            // A pod owns the UTS namespace and /etc/hosts too
+            push_host_args(args, self.hostname.as_deref(), &self.extra_hosts);
+        }
+        args.extend(crate::labels::label_args(&self.labels));
 
         // cap-drop BEFORE cap-add: Podman processes them in order
         for cap in &self.cap_drop {
@@ -71,6 +364,26 @@ impl ContainerConfig {
             args.push("--pids-limit".to_string());
             args.push(self.pids_limit.to_string());
         }
+        for ulimit in &self.ulimits {
+            args.push("--ulimit".to_string());
+            args.push(ulimit.clone());
+        }
+        if self.cpus > 0.0 {
+            args.push("--cpus".to_string());
+            args.push(self.cpus.to_string());
+        }
+        if self.memory_mb > 0 {
+            args.push("--memory".to_string());
+            args.push(format!("{}m", self.memory_mb));
+        }
+        if self.gpus {
+            args.push("--device".to_string());
+            args.push(super::NVIDIA_CDI_DEVICE.to_string());
+        }
+        for device in &self.devices {
+            args.push("--device".to_string());
+            args.push(device.clone());
+        }
         if self.read_only {
             args.push("--read-only".to_string());
         }
@@ -88,47 +401,76 @@ impl ContainerConfig {
             args.push(format!("{}={}", k, v));
         }
 
+        if let Some(ref platform) = self.platform {
+            args.push("--platform".to_string());
+            args.push(platform.clone());
+        }
+
         args.push(self.image.clone());
         args.extend(command.iter().cloned());
     }
 }
 
-/// Env var keys whose values must never appear in logs.
-const SENSITIVE_ENV_KEYS: &[&str] = &[
-    "AWS_SECRET_ACCESS_KEY",
-    "AWS_SESSION_TOKEN",
-    "AWS_ACCESS_KEY_ID",
-    "GITHUB_TOKEN",
-    "GH_TOKEN",
-    "CLOUDSDK_AUTH_ACCESS_TOKEN",
-    "AZURE_ACCESS_TOKEN",
-];
+/// Pod shared by a session's main container and its services
+#[derive(Debug, Clone)]
+pub struct PodSpec {
+    /// Pod name
+    pub name: String,
+    /// Network mode or network name
+    pub network: String,
+    /// DNS names other containers on the same network resolve the pod to
+    pub network_aliases: Vec<String>,
+    /// Ports published on the host (`--publish`)
+    pub ports: Vec<String>,
+    /// User namespace mode (`--userns`) for every container in the pod
+    pub userns: Option<String>,
+    /// Pod hostname (`--hostname`) shared by every container in the pod
+    pub hostname: Option<String>,
+    /// Extra `/etc/hosts` entries (`--add-host`) for every container in the pod
+    pub extra_hosts: Vec<String>,
+    /// Pod labels
+    pub labels: HashMap<String, String>,
+}
 
-/// Return a copy of `args` with sensitive `-e KEY=VALUE` values replaced by `***`.
-///
-/// Generic over element type so it works with both `&[String]` and `&[&str]`.
-pub(crate) fn redact_args<S: AsRef<str>>(args: &[S]) -> Vec<String> {
-    let mut out = Vec::with_capacity(args.len());
-    let mut redact_next = false;
-    for arg in args {
-        let s = arg.as_ref();
-        if redact_next {
-            if let Some((key, _)) = s.split_once('=') {
-                if SENSITIVE_ENV_KEYS.contains(&key) {
-                    out.push(format!("{key}=***"));
-                } else {
-                    out.push(s.to_owned());
-                }
-            } else {
-                out.push(s.to_owned());
-            }
-            redact_next = false;
-        } else {
-            out.push(s.to_owned());
-            redact_next = s == "-e";
+impl PodSpec {
+    /// `podman pod create` arguments
+    pub fn create_args(&self) -> Vec<String> {
+        let mut args = vec![
+            "pod".to_string(),
+            "create".to_string(),
+            "--name".to_string(),
+            self.name.clone(),
+            "--network".to_string(),
+            self.network.clone(),
+        ];
+        for alias in &self.network_aliases {
+            args.push("--network-alias".to_string());
+            args.push(alias.clone());
         }
+        for port in &self.ports {
+            args.push("--publish".to_string());
+            args.push(port.clone());
+        }
+        if let Some(ref userns) = self.userns {
+            args.push("--userns".to_string());
+            args.push(userns.clone());
+        }
+        push_host_args(&mut args, self.hostname.as_deref(), &self.extra_hosts);
+        args.extend(crate::labels::label_args(&self.labels));
+        args
+    }
+}
+
+/// Append `--hostname` and `--add-host` arguments
+fn push_host_args(args: &mut Vec<String>, hostname: Option<&str>, extra_hosts: &[String]) {
+    if let Some(hostname) = hostname {
+        args.push("--hostname".to_string());
+        args.push(hostname.to_string());
+    }
+    for host in extra_hosts {
+        args.push("--add-host".to_string());
+        args.push(host.clone());
     }
-    out
 }
 
 #[cfg(test)]
@@ -142,18 +484,64 @@ mod tests {
             volumes: vec![],
             env: HashMap::new(),
             network: "bridge".to_string(),
+            network_aliases: vec![],
             interactive: true,
             tty: true,
             cap_add: vec![],
             cap_drop: vec!["ALL".to_string()],
             security_opt: vec!["no-new-privileges".to_string()],
             pids_limit: 4096,
+            ulimits: vec![],
+            cpus: 0.0,
+            memory_mb: 0,
+            gpus: false,
+            devices: vec![],
             auto_remove: false,
             read_only: false,
             tmpfs: vec![],
+            user: None,
+            userns: None,
+            restart: None,
+            labels: HashMap::new(),
+            pod: None,
+            platform: None,
+            hostname: None,
+            extra_hosts: vec![],
         }
     }
 
+    #[test]
+    fn pull_policy_parse_roundtrip() {
+        for policy in [PullPolicy::Always, PullPolicy::Missing, PullPolicy::Never] {
+            assert_eq!(policy.to_string().parse::<PullPolicy>().unwrap(), policy);
+        }
+        assert_eq!("ALWAYS".parse::<PullPolicy>().unwrap(), PullPolicy::Always);
+        assert!("sometimes".parse::<PullPolicy>().is_err());
+    }
+
+    #[test]
+    fn parse_platform_normalizes_arch_names() {
+        assert_eq!(parse_platform("linux/amd64").unwrap(), "linux/amd64");
+        assert_eq!(parse_platform("x86_64").unwrap(), "linux/amd64");
+        assert_eq!(parse_platform("aarch64").unwrap(), "linux/arm64");
+        assert_eq!(parse_platform("ARM64").unwrap(), "linux/arm64");
+        assert!(parse_platform("linux/riscv64").is_err());
+        assert!(PLATFORMS.contains(&host_platform()));
+    }
+
+    #[test]
+    fn push_args_platform_precedes_image() {
+        let mut config = test_config();
+        config.platform = Some("linux/amd64".to_string());
+        let mut args = Vec::new();
+        config.push_args(&mut args, &["true".to_string()]);
+        let n = args.len();
+        assert_eq!(
+            args[n - 4..],
+            ["--platform", "linux/amd64", "fedora:43", "true"]
+        );
+    }
+
     #[test]
     fn container_config_fields() {
         let config = test_config();
@@ -218,6 +606,131 @@ mod tests {
         assert_eq!(args[tmpfs_positions[1] + 1], "/run");
     }
 
+    #[test]
+    fn push_args_pod_replaces_network_and_userns() {
+        let mut config = test_config();
+        config.userns = Some("keep-id".to_string());
+        config.network_aliases = vec!["s.mino".to_string()];
+        config.hostname = Some("box".to_string());
+        config.pod = Some("mino-s".to_string());
+
+        let mut args = Vec::new();
+        config.push_args(&mut args, &[]);
+        let pos = args.iter().position(|a| a == "--pod").unwrap();
+        assert_eq!(args[pos + 1], "mino-s");
+        assert!(!args.contains(&"--network".to_string()));
+        assert!(!args.contains(&"--network-alias".to_string()));
+        assert!(!args.contains(&"--userns".to_string()));
+        assert!(!args.contains(&"--hostname".to_string()));
+
+        config.workdir = String::new();
+        let mut args = Vec::new();
+        config.push_args(&mut args, &[]);
+        assert!(!args.contains(&"-w".to_string()));
+    }
+
+    #[test]
+    fn pod_spec_create_args() {
+        let spec = PodSpec {
+            name: "mino-s".to_string(),
+            network: "mino-sessions".to_string(),
+            network_aliases: vec!["s.mino".to_string()],
+            ports: vec!["5432:5432".to_string()],
+            userns: Some("keep-id".to_string()),
+            hostname: Some("box".to_string()),
+            extra_hosts: vec!["internal.corp:10.0.0.5".to_string()],
+            labels: HashMap::new(),
+        };
+        assert_eq!(
+            spec.create_args(),
+            vec![
+                "pod",
+                "create",
+                "--name",
+                "mino-s",
+                "--network",
+                "mino-sessions",
+                "--network-alias",
+                "s.mino",
+                "--publish",
+                "5432:5432",
+                "--userns",
+                "keep-id",
+                "--hostname",
+                "box",
+                "--add-host",
+                "internal.corp:10.0.0.5",
+            ]
+        );
+    }
+
+    #[test]
+    fn push_args_hostname_and_extra_hosts() {
+        let mut config = test_config();
+        let mut args = Vec::new();
+        config.push_args(&mut args, &[]);
+        assert!(!args.contains(&"--hostname".to_string()));
+        assert!(!args.contains(&"--add-host".to_string()));
+
+        config.hostname = Some("devbox".to_string());
+        config.extra_hosts = vec![
+            "internal.corp:10.0.0.5".to_string(),
+            "db.corp:fd00::5".to_string(),
+        ];
+        let mut args = Vec::new();
+        config.push_args(&mut args, &[]);
+        let pos = args.iter().position(|a| a == "--hostname").unwrap();
+        assert_eq!(args[pos + 1], "devbox");
+        let hosts: Vec<&str> = args
+            .windows(2)
+            .filter(|w| w[0] == "--add-host")
+            .map(|w| w[1].as_str())
+            .collect();
+        assert_eq!(hosts, ["internal.corp:10.0.0.5", "db.corp:fd00::5"]);
+    }
+
+    #[test]
+    fn parse_hostname_variants() {
+        for name in ["devbox", "dev-box.internal.corp", "a1"] {
+            assert_eq!(parse_hostname(name).unwrap(), name);
+        }
+        for name in ["", "-box", "box-", "dev_box", "a..b", &"a".repeat(64)] {
+            assert!(parse_hostname(name).is_err(), "{name} should be rejected");
+        }
+    }
+
+    #[test]
+    fn parse_extra_host_variants() {
+        let (name, ip) = parse_extra_host("internal.corp:10.0.0.5").unwrap();
+        assert_eq!(name, "internal.corp");
+        assert_eq!(ip.to_string(), "10.0.0.5");
+        let (name, ip) = parse_extra_host("db.corp:fd00::5").unwrap();
+        assert_eq!(name, "db.corp");
+        assert!(ip.is_ipv6());
+        for spec in [
+            "internal.corp",
+            "internal.corp:",
+            "internal.corp:10.0.0",
+            ":10.0.0.5",
+        ] {
+            assert!(parse_extra_host(spec).is_err(), "{spec} should be rejected");
+        }
+    }
+
+    #[test]
+    fn push_args_user_only_when_set() {
+        let mut config = test_config();
+        let mut args = Vec::new();
+        config.push_args(&mut args, &[]);
+        assert!(!args.contains(&"--user".to_string()));
+
+        config.user = Some("1000:1000".to_string());
+        let mut args = Vec::new();
+        config.push_args(&mut args, &[]);
+        let pos = args.iter().position(|a| a == "--user").unwrap();
+        assert_eq!(args[pos + 1], "1000:1000");
+    }
+
     #[test]
     fn push_args_no_read_only_by_default() {
         let config = test_config();
@@ -229,91 +742,219 @@ mod tests {
     }
 
     #[test]
-    fn redact_args_masks_sensitive_keys() {
-        let args: Vec<String> = vec![
-            "run",
-            "-d",
-            "-e",
-            "AWS_SECRET_ACCESS_KEY=hunter2",
-            "-e",
-            "GITHUB_TOKEN=ghp_abc123",
-            "-e",
-            "PATH=/usr/bin",
-            "fedora:43",
-        ]
-        .into_iter()
-        .map(String::from)
-        .collect();
-
-        let redacted = redact_args(&args);
-        assert_eq!(redacted[3], "AWS_SECRET_ACCESS_KEY=***");
-        assert_eq!(redacted[5], "GITHUB_TOKEN=***");
-        assert_eq!(redacted[7], "PATH=/usr/bin");
+    fn push_args_no_pids_limit_when_zero() {
+        let mut config = test_config();
+        config.pids_limit = 0;
+        config.cap_drop = vec![];
+        config.security_opt = vec![];
+
+        let mut args = Vec::new();
+        config.push_args(&mut args, &[]);
+        assert!(!args.contains(&"--pids-limit".to_string()));
+    }
+
+    #[test]
+    fn push_args_resource_limits() {
+        let mut config = test_config();
+        config.cpus = 1.5;
+        config.memory_mb = 4096;
+
+        let mut args = Vec::new();
+        config.push_args(&mut args, &[]);
+
+        let cpus = args.iter().position(|a| a == "--cpus").unwrap();
+        assert_eq!(args[cpus + 1], "1.5");
+        let memory = args.iter().position(|a| a == "--memory").unwrap();
+        assert_eq!(args[memory + 1], "4096m");
     }
 
     #[test]
-    fn redact_args_preserves_non_sensitive() {
-        let args: Vec<String> = vec![
-            "run",
-            "-e",
-            "HOME=/home/dev",
-            "-e",
-            "LANG=en_US.UTF-8",
-            "-w",
-            "/workspace",
-        ]
-        .into_iter()
-        .map(String::from)
-        .collect();
-
-        let redacted = redact_args(&args);
-        assert_eq!(redacted, args);
+    fn push_args_omits_unset_limits() {
+        let mut config = test_config();
+        config.pids_limit = 0;
+
+        let mut args = Vec::new();
+        config.push_args(&mut args, &[]);
+
+        assert!(!args.contains(&"--cpus".to_string()));
+        assert!(!args.contains(&"--memory".to_string()));
+        assert!(!args.contains(&"--pids-limit".to_string()));
     }
 
     #[test]
-    fn redact_args_handles_no_env() {
-        let args: Vec<String> = vec!["run", "-d", "-w", "/workspace", "fedora:43"]
-            .into_iter()
-            .map(String::from)
-            .collect();
+    fn push_args_gpus_use_cdi_device() {
+        let mut config = test_config();
+        config.gpus = true;
+
+        let mut args = Vec::new();
+        config.push_args(&mut args, &[]);
+
+        let device = args.iter().position(|a| a == "--device").unwrap();
+        assert_eq!(args[device + 1], "nvidia.com/gpu=all");
+    }
 
-        let redacted = redact_args(&args);
-        assert_eq!(redacted, args);
+    #[test]
+    fn push_args_labels_before_image() {
+        let mut config = test_config();
+        config
+            .labels
+            .insert("io.mino.session".to_string(), "swift-fox".to_string());
+
+        let mut args = Vec::new();
+        config.push_args(&mut args, &[]);
+
+        let label = args.iter().position(|a| a == "--label").unwrap();
+        assert_eq!(args[label + 1], "io.mino.session=swift-fox");
+        let image = args.iter().position(|a| a == "fedora:43").unwrap();
+        assert!(label < image);
+    }
+
+    #[test]
+    fn push_args_userns() {
+        let mut args = Vec::new();
+        test_config().push_args(&mut args, &[]);
+        assert!(!args.contains(&"--userns".to_string()));
+
+        let mut config = test_config();
+        config.userns = Some("keep-id".to_string());
+        let mut args = Vec::new();
+        config.push_args(&mut args, &[]);
+        let userns = args.iter().position(|a| a == "--userns").unwrap();
+        assert_eq!(args[userns + 1], "keep-id");
+    }
+
+    #[test]
+    fn build_args_pass_the_pull_policy() {
+        assert_eq!(
+            build_args("mino-composed-abc", "/tmp/ctx", PullPolicy::Never),
+            vec![
+                "build",
+                "--pull=never",
+                "-t",
+                "mino-composed-abc",
+                "/tmp/ctx"
+            ]
+        );
+        assert!(build_args("t", "/c", PullPolicy::Always).contains(&"--pull=always".to_string()));
+        assert!(build_args("t", "/c", PullPolicy::Missing).contains(&"--pull=missing".to_string()));
+    }
+
+    #[test]
+    fn parse_userns_modes() {
+        for mode in [
+            "keep-id",
+            "auto",
+            "host",
+            "nomap",
+            "keep-id:uid=1000,gid=1000",
+            "auto:size=65536",
+        ] {
+            assert_eq!(parse_userns(mode).unwrap(), mode);
+        }
+        for mode in ["", "keepid", "host:x", "keep-id:", "container:abc"] {
+            assert!(parse_userns(mode).is_err(), "{mode} should be rejected");
+        }
     }
 
     #[test]
-    fn redact_args_works_with_str_slices() {
-        let args: &[&str] = &[
-            "run",
-            "-d",
-            "-e",
-            "AWS_SESSION_TOKEN=secret123",
-            "-e",
-            "HOME=/home/dev",
+    fn push_args_devices() {
+        let mut config = test_config();
+        config.devices = vec![
+            "/dev/ttyUSB0".to_string(),
+            "/dev/sdc:/dev/xvdc:r".to_string(),
         ];
 
-        let redacted = redact_args(args);
-        assert_eq!(redacted[3], "AWS_SESSION_TOKEN=***");
-        assert_eq!(redacted[5], "HOME=/home/dev");
+        let mut args = Vec::new();
+        config.push_args(&mut args, &[]);
+
+        let devices: Vec<&str> = args
+            .windows(2)
+            .filter(|w| w[0] == "--device")
+            .map(|w| w[1].as_str())
+            .collect();
+        assert_eq!(devices, ["/dev/ttyUSB0", "/dev/sdc:/dev/xvdc:r"]);
     }
 
     #[test]
-    fn redact_args_trailing_dash_e_no_panic() {
-        let args: Vec<String> = vec!["run", "-e"].into_iter().map(String::from).collect();
+    fn device_host_path_variants() {
+        assert_eq!(
+            device_host_path("/dev/ttyUSB0").unwrap(),
+            Some("/dev/ttyUSB0")
+        );
+        assert_eq!(
+            device_host_path("/dev/sdc:/dev/xvdc:rwm").unwrap(),
+            Some("/dev/sdc")
+        );
+        assert_eq!(device_host_path("nvidia.com/gpu=0").unwrap(), None);
+        assert!(device_host_path("ttyUSB0").is_err());
+        assert!(device_host_path("").is_err());
+    }
 
-        let redacted = redact_args(&args);
-        assert_eq!(redacted, args);
+    #[test]
+    fn parse_restart_policy_variants() {
+        assert_eq!(
+            parse_restart_policy("on-failure").unwrap().as_deref(),
+            Some("on-failure")
+        );
+        assert_eq!(
+            parse_restart_policy("on-failure:5").unwrap().as_deref(),
+            Some("on-failure:5")
+        );
+        assert_eq!(parse_restart_policy("no").unwrap(), None);
+        assert!(parse_restart_policy("on-failure:0").is_err());
+        assert!(parse_restart_policy("on-failure:x").is_err());
+        assert!(parse_restart_policy("always").is_err());
+        assert!(parse_restart_policy("no:3").is_err());
     }
 
     #[test]
-    fn push_args_no_pids_limit_when_zero() {
+    fn push_args_restart_policy() {
         let mut config = test_config();
-        config.pids_limit = 0;
-        config.cap_drop = vec![];
-        config.security_opt = vec![];
+        config.restart = Some("on-failure:3".to_string());
+        let mut args = Vec::new();
+        config.push_args(&mut args, &[]);
+        let pos = args.iter().position(|a| a == "--restart").unwrap();
+        assert_eq!(args[pos + 1], "on-failure:3");
+    }
+
+    #[test]
+    fn ulimit_name_variants() {
+        assert_eq!(ulimit_name("nofile=65535").unwrap(), "nofile");
+        assert_eq!(ulimit_name("nofile=1024:65535").unwrap(), "nofile");
+        assert_eq!(ulimit_name("memlock=-1:-1").unwrap(), "memlock");
+        for spec in [
+            "nofile",
+            "nofile=",
+            "nofile=x",
+            "nofile=65535:1024",
+            "nofile=-1:1024",
+            "nofile=-2",
+            "files=10",
+        ] {
+            assert!(ulimit_name(spec).is_err(), "{spec} should be rejected");
+        }
+    }
 
+    #[test]
+    fn push_args_ulimits() {
+        let mut config = test_config();
+        config.ulimits = vec!["nofile=65535:65535".to_string(), "nproc=4096".to_string()];
         let mut args = Vec::new();
         config.push_args(&mut args, &[]);
-        assert!(!args.contains(&"--pids-limit".to_string()));
+        let ulimits: Vec<&str> = args
+            .windows(2)
+            .filter(|w| w[0] == "--ulimit")
+            .map(|w| w[1].as_str())
+            .collect();
+        assert_eq!(ulimits, ["nofile=65535:65535", "nproc=4096"]);
+    }
+
+    #[test]
+    fn tmpfs_target_variants() {
+        assert_eq!(tmpfs_target("/run").unwrap(), "/run");
+        assert_eq!(tmpfs_target("/tmp:size=1g,mode=1777").unwrap(), "/tmp");
+        assert!(tmpfs_target("tmp").is_err());
+        assert!(tmpfs_target("/tmp:").is_err());
+        assert!(tmpfs_target("").is_err());
     }
 }
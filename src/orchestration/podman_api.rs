@@ -0,0 +1,384 @@
+//! Minimal client for the Podman REST API over its Unix socket
+//!
+//! Speaks just enough HTTP/1.1 for libpod's JSON endpoints: one request per
+//! connection (`Connection: close`), with fixed-length or chunked bodies.
+//! Avoids spawning a `podman` process for every operation.
+
+use crate::error::{MinoError, MinoResult};
+use std::path::PathBuf;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::UnixStream;
+use tokio::time::timeout;
+use tracing::debug;
+
+/// libpod API prefix. Podman 4 and 5 both serve the v4 routes.
+const API_PREFIX: &str = "/v4.0.0/libpod";
+
+/// Longest wait for the service to accept a connection
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Longest wait for a response before the service counts as wedged.
+/// Generous, since stopping a container first waits out its stop timeout.
+const RESPONSE_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Response from the Podman API
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct ApiResponse {
+    /// HTTP status code
+    pub status: u16,
+    /// Decoded response body
+    pub body: Vec<u8>,
+}
+
+impl ApiResponse {
+    /// Whether the status is 2xx
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    /// Body as (lossy) UTF-8
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+
+    /// Error message from a libpod error body (`{"message": ...}`), or the raw body
+    pub fn error_message(&self) -> String {
+        serde_json::from_slice::<serde_json::Value>(&self.body)
+            .ok()
+            .and_then(|v| v.get("message").and_then(|m| m.as_str()).map(String::from))
+            .unwrap_or_else(|| self.text())
+    }
+}
+
+/// Client for one Podman API socket
+#[derive(Debug, Clone)]
+pub(crate) struct PodmanApiClient {
+    socket: PathBuf,
+    /// Longest wait for a response; `None` for endpoints that block by
+    /// design, like `/wait`
+    response_timeout: Option<Duration>,
+}
+
+impl PodmanApiClient {
+    /// Client for the given socket path
+    pub fn new(socket: impl Into<PathBuf>) -> Self {
+        Self {
+            socket: socket.into(),
+            response_timeout: Some(RESPONSE_TIMEOUT),
+        }
+    }
+
+    /// The same client with another response timeout
+    pub fn with_response_timeout(mut self, response_timeout: Option<Duration>) -> Self {
+        self.response_timeout = response_timeout;
+        self
+    }
+
+    /// Whether the service answers `/_ping`
+    pub async fn ping(&self) -> bool {
+        matches!(self.request("GET", "/_ping", None).await, Ok(r) if r.is_success())
+    }
+
+    /// Send a request to a libpod endpoint (`path` without the API prefix).
+    ///
+    /// Non-2xx statuses are returned, not mapped to errors; only transport
+    /// failures are errors. A service that does not connect or answer in
+    /// time fails with a `TimedOut` I/O error.
+    pub async fn request(
+        &self,
+        method: &str,
+        path: &str,
+        body: Option<&serde_json::Value>,
+    ) -> MinoResult<ApiResponse> {
+        let target = format!("{}{}", API_PREFIX, path);
+        debug!("Podman API: {} {}", method, target);
+
+        let context = format!("podman API {} {}", method, path);
+        let connect_context = format!("connecting to {}", self.socket.display());
+        let mut stream = timeout(CONNECT_TIMEOUT, UnixStream::connect(&self.socket))
+            .await
+            .map_err(|_| timed_out(&connect_context))?
+            .map_err(|e| MinoError::io(connect_context, e))?;
+
+        let body = body.map(|b| b.to_string());
+        stream
+            .write_all(&encode_request(method, &target, body.as_deref()))
+            .await
+            .map_err(|e| MinoError::io(&context, e))?;
+
+        let mut raw = Vec::new();
+        let read = stream.read_to_end(&mut raw);
+        match self.response_timeout {
+            Some(limit) => timeout(limit, read)
+                .await
+                .map_err(|_| timed_out(&context))?,
+            None => read.await,
+        }
+        .map_err(|e| MinoError::io(&context, e))?;
+
+        parse_response(&raw).ok_or_else(|| {
+            MinoError::command_exec(context, "malformed HTTP response from Podman service")
+        })
+    }
+}
+
+/// Error for a service that did not answer in time
+fn timed_out(context: &str) -> MinoError {
+    MinoError::io(
+        context,
+        std::io::Error::new(
+            std::io::ErrorKind::TimedOut,
+            "no answer from the Podman service",
+        ),
+    )
+}
+
+/// Podman API socket for the current user.
+///
+/// Honors a `unix://` `CONTAINER_HOST` like the podman CLI does, then the
+/// rootless default under `$XDG_RUNTIME_DIR` (or `/run/user/<uid>`).
+pub(crate) fn default_socket_path() -> PathBuf {
+    let container_host = std::env::var("CONTAINER_HOST").ok();
+    let runtime_dir = std::env::var("XDG_RUNTIME_DIR").ok();
+    // SAFETY: getuid() has no preconditions and cannot fail.
+    let uid = unsafe { libc::getuid() };
+    socket_path_from(container_host.as_deref(), runtime_dir.as_deref(), uid)
+}
+
+fn socket_path_from(container_host: Option<&str>, runtime_dir: Option<&str>, uid: u32) -> PathBuf {
+    if let Some(path) = container_host.and_then(|h| h.strip_prefix("unix://")) {
+        return PathBuf::from(path);
+    }
+    let runtime_dir = match runtime_dir {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => PathBuf::from(format!("/run/user/{}", uid)),
+    };
+    runtime_dir.join("podman").join("podman.sock")
+}
+
+/// Percent-encode a value for use as a single URL path segment or query value.
+///
+/// Image references contain `/` and `:`, which must not split the path.
+pub(crate) fn encode_segment(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.' | b'~') {
+            out.push(byte as char);
+        } else {
+            out.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    out
+}
+
+/// Serialize an HTTP/1.1 request with an optional JSON body
+fn encode_request(method: &str, target: &str, body: Option<&str>) -> Vec<u8> {
+    let mut req = format!(
+        "{} {} HTTP/1.1\r\nHost: d\r\nConnection: close\r\n",
+        method, target
+    );
+    match body {
+        Some(body) => {
+            req.push_str("Content-Type: application/json\r\n");
+            req.push_str(&format!("Content-Length: {}\r\n\r\n", body.len()));
+            req.push_str(body);
+        }
+        None => req.push_str("Content-Length: 0\r\n\r\n"),
+    }
+    req.into_bytes()
+}
+
+/// Parse a complete HTTP/1.1 response read until the server closed the connection
+fn parse_response(raw: &[u8]) -> Option<ApiResponse> {
+    let header_end = raw.windows(4).position(|w| w == b"\r\n\r\n")?;
+    let head = std::str::from_utf8(&raw[..header_end]).ok()?;
+    let rest = &raw[header_end + 4..];
+
+    let mut lines = head.split("\r\n");
+    let status = lines.next()?.split_whitespace().nth(1)?.parse().ok()?;
+
+    let mut chunked = false;
+    let mut content_length = None;
+    for line in lines {
+        let (name, value) = line.split_once(':')?;
+        let value = value.trim();
+        if name.eq_ignore_ascii_case("transfer-encoding") {
+            chunked = value.eq_ignore_ascii_case("chunked");
+        } else if name.eq_ignore_ascii_case("content-length") {
+            content_length = value.parse::<usize>().ok();
+        }
+    }
+
+    let body = if chunked {
+        decode_chunked(rest)?
+    } else {
+        match content_length {
+            Some(len) => rest.get(..len)?.to_vec(),
+            None => rest.to_vec(),
+        }
+    };
+
+    Some(ApiResponse { status, body })
+}
+
+/// Decode a chunked transfer-encoded body
+fn decode_chunked(mut data: &[u8]) -> Option<Vec<u8>> {
+    let mut body = Vec::new();
+    loop {
+        let line_end = data.windows(2).position(|w| w == b"\r\n")?;
+        let size_line = std::str::from_utf8(&data[..line_end]).ok()?;
+        // Chunk extensions (`;name=value`) are allowed after the size
+        let size_hex = size_line.split(';').next()?.trim();
+        let size = usize::from_str_radix(size_hex, 16).ok()?;
+        data = &data[line_end + 2..];
+        if size == 0 {
+            return Some(body);
+        }
+        body.extend_from_slice(data.get(..size)?);
+        data = data.get(size + 2..)?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::net::UnixListener;
+
+    #[test]
+    fn socket_path_prefers_container_host() {
+        assert_eq!(
+            socket_path_from(Some("unix:///tmp/podman.sock"), Some("/run/user/7"), 7),
+            PathBuf::from("/tmp/podman.sock")
+        );
+        // Remote (ssh://) hosts are not sockets mino can use
+        assert_eq!(
+            socket_path_from(Some("ssh://core@vm/run/podman.sock"), Some("/xdg"), 7),
+            PathBuf::from("/xdg/podman/podman.sock")
+        );
+        assert_eq!(
+            socket_path_from(None, None, 1000),
+            PathBuf::from("/run/user/1000/podman/podman.sock")
+        );
+        assert_eq!(
+            socket_path_from(None, Some(""), 1000),
+            PathBuf::from("/run/user/1000/podman/podman.sock")
+        );
+    }
+
+    #[test]
+    fn encode_segment_escapes_reserved() {
+        assert_eq!(
+            encode_segment("ghcr.io/dean0x/mino-base:latest"),
+            "ghcr.io%2Fdean0x%2Fmino-base%3Alatest"
+        );
+        assert_eq!(encode_segment("mino-cache_npm.1~x"), "mino-cache_npm.1~x");
+    }
+
+    #[test]
+    fn encode_request_with_and_without_body() {
+        let req = String::from_utf8(encode_request("GET", "/v4.0.0/libpod/_ping", None)).unwrap();
+        assert!(req.starts_with("GET /v4.0.0/libpod/_ping HTTP/1.1\r\n"));
+        assert!(req.contains("Connection: close\r\n"));
+        assert!(req.ends_with("Content-Length: 0\r\n\r\n"));
+
+        let req = String::from_utf8(encode_request("POST", "/x", Some(r#"{"a":1}"#))).unwrap();
+        assert!(req.contains("Content-Type: application/json\r\n"));
+        assert!(req.ends_with("Content-Length: 7\r\n\r\n{\"a\":1}"));
+    }
+
+    #[test]
+    fn parse_response_content_length() {
+        let raw =
+            b"HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\nContent-Length: 2\r\n\r\nOKtrailing";
+        let resp = parse_response(raw).unwrap();
+        assert_eq!(resp.status, 200);
+        assert_eq!(resp.text(), "OK");
+        assert!(resp.is_success());
+    }
+
+    #[test]
+    fn parse_response_chunked() {
+        let raw = b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n4\r\n[{\"a\r\n5;ext=1\r\n\":1}]\r\n0\r\n\r\n";
+        let resp = parse_response(raw).unwrap();
+        assert_eq!(resp.text(), r#"[{"a":1}]"#);
+    }
+
+    #[test]
+    fn parse_response_no_body_and_malformed() {
+        let resp = parse_response(b"HTTP/1.1 204 No Content\r\n\r\n").unwrap();
+        assert_eq!(resp.status, 204);
+        assert!(resp.body.is_empty());
+
+        assert!(parse_response(b"HTTP/1.1 200 OK\r\n").is_none());
+        assert!(parse_response(b"garbage\r\n\r\n").is_none());
+        assert!(parse_response(b"HTTP/1.1 200 OK\r\nContent-Length: 10\r\n\r\nshort").is_none());
+    }
+
+    #[test]
+    fn error_message_from_libpod_body() {
+        let resp = ApiResponse {
+            status: 404,
+            body: br#"{"cause":"no such container","message":"no such container: abc","response":404}"#
+                .to_vec(),
+        };
+        assert_eq!(resp.error_message(), "no such container: abc");
+
+        let resp = ApiResponse {
+            status: 500,
+            body: b"plain failure".to_vec(),
+        };
+        assert_eq!(resp.error_message(), "plain failure");
+    }
+
+    #[tokio::test]
+    async fn request_round_trip_over_socket() {
+        let dir = tempfile::tempdir().unwrap();
+        let socket = dir.path().join("podman.sock");
+        let listener = UnixListener::bind(&socket).unwrap();
+
+        let server = tokio::spawn(async move {
+            let (mut conn, _) = listener.accept().await.unwrap();
+            let mut buf = vec![0u8; 1024];
+            let n = conn.read(&mut buf).await.unwrap();
+            conn.write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nOK")
+                .await
+                .unwrap();
+            String::from_utf8_lossy(&buf[..n]).into_owned()
+        });
+
+        let client = PodmanApiClient::new(&socket);
+        assert!(client.ping().await);
+        let request = server.await.unwrap();
+        assert!(request.starts_with("GET /v4.0.0/libpod/_ping HTTP/1.1"));
+    }
+
+    #[tokio::test]
+    async fn ping_fails_without_socket() {
+        let dir = tempfile::tempdir().unwrap();
+        let client = PodmanApiClient::new(dir.path().join("missing.sock"));
+        assert!(!client.ping().await);
+        assert!(client.request("GET", "/_ping", None).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn request_times_out_on_silent_service() {
+        let dir = tempfile::tempdir().unwrap();
+        let socket = dir.path().join("podman.sock");
+        let listener = UnixListener::bind(&socket).unwrap();
+        let server = tokio::spawn(async move {
+            let (conn, _) = listener.accept().await.unwrap();
+            tokio::time::sleep(Duration::from_secs(5)).await;
+            drop(conn);
+        });
+
+        let client =
+            PodmanApiClient::new(&socket).with_response_timeout(Some(Duration::from_millis(50)));
+        let err = client.request("GET", "/_ping", None).await.unwrap_err();
+        assert!(
+            matches!(&err, MinoError::Io { source, .. } if source.kind() == std::io::ErrorKind::TimedOut),
+            "{err}"
+        );
+        server.abort();
+    }
+}
@@ -0,0 +1,933 @@
+//! Podman runtime backed by the Podman REST API socket
+//!
+//! Container lifecycle, volume, network, and image queries go through the
+//! libpod API instead of spawning `podman` for each call. Operations that
+//! need a terminal or a process to stream from (attach, exec, logs, build,
+//! pull) and container creation, whose flags come from
+//! `ContainerConfig::push_args`, stay on the CLI. When the socket is not
+//! reachable everything falls back to [`NativePodmanRuntime`].
+
+use crate::error::{MinoError, MinoResult};
+use crate::orchestration::native_podman::NativePodmanRuntime;
+use crate::orchestration::podman::{ContainerConfig, PodSpec, PullPolicy};
+use crate::orchestration::podman_api::{
+    default_socket_path, encode_segment, ApiResponse, PodmanApiClient,
+};
+use crate::orchestration::runtime::{ContainerRuntime, ContainerUsage, VolumeInfo};
+use async_trait::async_trait;
+use serde_json::json;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use tokio::sync::OnceCell;
+use tracing::{debug, warn};
+
+/// Container runtime talking to the rootless Podman service (for Linux)
+pub struct PodmanApiRuntime {
+    /// Socket to probe for the API service
+    socket: PathBuf,
+    /// API client, or `None` when the service did not answer (probed once)
+    api: OnceCell<Option<PodmanApiClient>>,
+    /// Set once the service stops answering mid-session
+    api_failed: AtomicBool,
+    /// CLI runtime for operations the API does not cover, and the fallback
+    cli: NativePodmanRuntime,
+}
+
+impl PodmanApiRuntime {
+    /// Create a runtime using the current user's Podman socket
+    pub fn new() -> Self {
+        Self::with_socket(default_socket_path())
+    }
+
+    /// Create a runtime using an explicit Podman socket
+    pub fn with_socket(socket: impl Into<PathBuf>) -> Self {
+        Self {
+            socket: socket.into(),
+            api: OnceCell::new(),
+            api_failed: AtomicBool::new(false),
+            cli: NativePodmanRuntime::new(),
+        }
+    }
+
+    /// API client when the service is reachable
+    async fn api(&self) -> Option<&PodmanApiClient> {
+        if self.api_failed.load(Ordering::Relaxed) {
+            return None;
+        }
+        self.api
+            .get_or_init(|| async {
+                let client = PodmanApiClient::new(&self.socket);
+                if client.ping().await {
+                    debug!("Using Podman API at {}", self.socket.display());
+                    Some(client)
+                } else {
+                    debug!(
+                        "Podman API not reachable at {}, using podman CLI",
+                        self.socket.display()
+                    );
+                    None
+                }
+            })
+            .await
+            .as_ref()
+    }
+
+    /// Send a request through `api`. `Ok(None)` when the service did not
+    /// answer (a transport failure or timeout): the API is then abandoned,
+    /// and the caller falls back to the CLI, as do all later calls.
+    async fn send(
+        &self,
+        api: &PodmanApiClient,
+        method: &str,
+        path: &str,
+        body: Option<&serde_json::Value>,
+    ) -> MinoResult<Option<ApiResponse>> {
+        match api.request(method, path, body).await {
+            Ok(resp) => Ok(Some(resp)),
+            Err(e @ MinoError::Io { .. }) => {
+                warn!("Podman API unavailable, using podman CLI: {}", e);
+                self.api_failed.store(true, Ordering::Relaxed);
+                Ok(None)
+            }
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl Default for PodmanApiRuntime {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Error for a failed API call, named after the equivalent CLI command
+fn api_error(command: &str, resp: &ApiResponse) -> MinoError {
+    MinoError::command_exec(command, resp.error_message())
+}
+
+/// Fail unless the response is 2xx or one of the `ok` statuses
+fn expect_status(command: &str, resp: ApiResponse, ok: &[u16]) -> MinoResult<()> {
+    if resp.is_success() || ok.contains(&resp.status) {
+        Ok(())
+    } else {
+        Err(api_error(command, &resp))
+    }
+}
+
+/// Tagged references from a libpod `/images/json` listing
+fn parse_image_list_json(json: &str) -> MinoResult<Vec<String>> {
+    let images: Vec<serde_json::Value> = serde_json::from_str(json)?;
+    Ok(images
+        .iter()
+        .filter_map(|image| image.get("RepoTags")?.as_array())
+        .flatten()
+        .filter_map(|tag| tag.as_str())
+        .filter(|tag| !tag.contains("<none>"))
+        .map(String::from)
+        .collect())
+}
+
+/// Container IDs from a libpod `/containers/json` listing
+fn parse_container_ids(json: &str) -> MinoResult<Vec<String>> {
+    let containers: Vec<serde_json::Value> = serde_json::from_str(json)?;
+    Ok(containers
+        .iter()
+        .filter_map(|c| c.get("Id")?.as_str())
+        .map(String::from)
+        .collect())
+}
+
+/// `Config.User` from a libpod image inspect body
+fn parse_image_user(json: &str) -> MinoResult<Option<String>> {
+    let image: serde_json::Value = serde_json::from_str(json)?;
+    Ok(image
+        .pointer("/Config/User")
+        .and_then(|u| u.as_str())
+        .filter(|u| !u.is_empty())
+        .map(String::from))
+}
+
+#[async_trait]
+impl ContainerRuntime for PodmanApiRuntime {
+    async fn is_available(&self) -> MinoResult<bool> {
+        self.cli.is_available().await
+    }
+
+    async fn ensure_ready(&self) -> MinoResult<()> {
+        self.cli.ensure_ready().await
+    }
+
+    async fn check_device(&self, path: &str) -> MinoResult<()> {
+        self.cli.check_device(path).await
+    }
+
+    async fn check_gpu_support(&self) -> MinoResult<()> {
+        self.cli.check_gpu_support().await
+    }
+
+    async fn run(&self, config: &ContainerConfig, command: &[String]) -> MinoResult<String> {
+        self.cli.run(config, command).await
+    }
+
+    async fn create(&self, config: &ContainerConfig, command: &[String]) -> MinoResult<String> {
+        self.cli.create(config, command).await
+    }
+
+    async fn start_attached(&self, container_id: &str) -> MinoResult<i32> {
+        self.cli.start_attached(container_id).await
+    }
+
+    async fn stop(&self, container_id: &str) -> MinoResult<()> {
+        let Some(api) = self.api().await else {
+            return self.cli.stop(container_id).await;
+        };
+        debug!("Stopping container: {}", container_id);
+
+        let path = format!("/containers/{}/stop", encode_segment(container_id));
+        let Some(resp) = self.send(api, "POST", &path, None).await? else {
+            return self.cli.stop(container_id).await;
+        };
+        // 304: already stopped
+        expect_status("podman stop", resp, &[304])
+    }
+
+    async fn kill(&self, container_id: &str) -> MinoResult<()> {
+        let Some(api) = self.api().await else {
+            return self.cli.kill(container_id).await;
+        };
+        debug!("Killing container: {}", container_id);
+
+        let path = format!("/containers/{}/kill", encode_segment(container_id));
+        let Some(resp) = self.send(api, "POST", &path, None).await? else {
+            return self.cli.kill(container_id).await;
+        };
+        expect_status("podman kill", resp, &[])
+    }
+
+    async fn signal(&self, container_id: &str, signal: &str) -> MinoResult<()> {
+        let Some(api) = self.api().await else {
+            return self.cli.signal(container_id, signal).await;
+        };
+        debug!("Sending {} to container: {}", signal, container_id);
+
+        let path = format!(
+            "/containers/{}/kill?signal={}",
+            encode_segment(container_id),
+            encode_segment(signal)
+        );
+        let Some(resp) = self.send(api, "POST", &path, None).await? else {
+            return self.cli.signal(container_id, signal).await;
+        };
+        expect_status("podman kill", resp, &[])
+    }
+
+    async fn pause(&self, container_id: &str) -> MinoResult<()> {
+        let Some(api) = self.api().await else {
+            return self.cli.pause(container_id).await;
+        };
+        debug!("Pausing container: {}", container_id);
+
+        let path = format!("/containers/{}/pause", encode_segment(container_id));
+        let Some(resp) = self.send(api, "POST", &path, None).await? else {
+            return self.cli.pause(container_id).await;
+        };
+        expect_status("podman pause", resp, &[])
+    }
+
+    async fn unpause(&self, container_id: &str) -> MinoResult<()> {
+        let Some(api) = self.api().await else {
+            return self.cli.unpause(container_id).await;
+        };
+        debug!("Unpausing container: {}", container_id);
+
+        let path = format!("/containers/{}/unpause", encode_segment(container_id));
+        let Some(resp) = self.send(api, "POST", &path, None).await? else {
+            return self.cli.unpause(container_id).await;
+        };
+        expect_status("podman unpause", resp, &[])
+    }
+
+    async fn checkpoint(
+        &self,
+        container_id: &str,
+        archive: &Path,
+        leave_running: bool,
+    ) -> MinoResult<()> {
+        self.cli
+            .checkpoint(container_id, archive, leave_running)
+            .await
+    }
+
+    async fn restore(&self, archive: &Path) -> MinoResult<String> {
+        self.cli.restore(archive).await
+    }
+
+    async fn remove(&self, container_id: &str) -> MinoResult<()> {
+        let Some(api) = self.api().await else {
+            return self.cli.remove(container_id).await;
+        };
+        debug!("Removing container: {}", container_id);
+
+        let path = format!("/containers/{}?force=true", encode_segment(container_id));
+        let Some(resp) = self.send(api, "DELETE", &path, None).await? else {
+            return self.cli.remove(container_id).await;
+        };
+        // 404: container doesn't exist
+        expect_status("podman rm", resp, &[404])
+    }
+
+    async fn container_prune(&self) -> MinoResult<()> {
+        let Some(api) = self.api().await else {
+            return self.cli.container_prune().await;
+        };
+
+        let Some(resp) = self.send(api, "POST", "/containers/prune", None).await? else {
+            return self.cli.container_prune().await;
+        };
+        expect_status("podman container prune", resp, &[])
+    }
+
+    async fn container_list_labeled(&self, label: &str) -> MinoResult<Vec<String>> {
+        let Some(api) = self.api().await else {
+            return self.cli.container_list_labeled(label).await;
+        };
+
+        let filters = json!({ "label": [label] }).to_string();
+        let path = format!(
+            "/containers/json?all=true&filters={}",
+            encode_segment(&filters)
+        );
+        let Some(resp) = self.send(api, "GET", &path, None).await? else {
+            return self.cli.container_list_labeled(label).await;
+        };
+        if !resp.is_success() {
+            return Err(api_error("podman ps", &resp));
+        }
+        parse_container_ids(&resp.text())
+    }
+
+    async fn logs(&self, container_id: &str, lines: u32) -> MinoResult<String> {
+        self.cli.logs(container_id, lines).await
+    }
+
+    async fn logs_follow(&self, container_id: &str) -> MinoResult<()> {
+        self.cli.logs_follow(container_id).await
+    }
+
+    async fn logs_follow_lines(
+        &self,
+        container_id: &str,
+        lines: u32,
+        on_line: &(dyn Fn(String) + Send + Sync),
+    ) -> MinoResult<()> {
+        self.cli
+            .logs_follow_lines(container_id, lines, on_line)
+            .await
+    }
+
+    async fn image_exists(&self, image: &str) -> MinoResult<bool> {
+        let Some(api) = self.api().await else {
+            return self.cli.image_exists(image).await;
+        };
+
+        let path = format!("/images/{}/exists", encode_segment(image));
+        let Some(resp) = self.send(api, "GET", &path, None).await? else {
+            return self.cli.image_exists(image).await;
+        };
+        match resp.status {
+            404 => Ok(false),
+            _ if resp.is_success() => Ok(true),
+            _ => Err(api_error("podman image exists", &resp)),
+        }
+    }
+
+    async fn image_pull(&self, image: &str) -> MinoResult<()> {
+        self.cli.image_pull(image).await
+    }
+
+    async fn image_pull_with_progress(
+        &self,
+        image: &str,
+        platform: Option<&str>,
+        on_output: &(dyn Fn(String) + Send + Sync),
+    ) -> MinoResult<()> {
+        self.cli
+            .image_pull_with_progress(image, platform, on_output)
+            .await
+    }
+
+    async fn image_platform(&self, image: &str) -> MinoResult<Option<String>> {
+        self.cli.image_platform(image).await
+    }
+
+    async fn image_user(&self, image: &str) -> MinoResult<Option<String>> {
+        let Some(api) = self.api().await else {
+            return self.cli.image_user(image).await;
+        };
+
+        if !self.image_exists(image).await? {
+            self.cli.image_pull(image).await?;
+        }
+
+        let path = format!("/images/{}/json", encode_segment(image));
+        let Some(resp) = self.send(api, "GET", &path, None).await? else {
+            return self.cli.image_user(image).await;
+        };
+        if !resp.is_success() {
+            return Err(api_error("podman image inspect", &resp));
+        }
+        parse_image_user(&resp.text())
+    }
+
+    async fn build_image(&self, context_dir: &Path, tag: &str, pull: PullPolicy) -> MinoResult<()> {
+        self.cli.build_image(context_dir, tag, pull).await
+    }
+
+    async fn build_image_with_progress(
+        &self,
+        context_dir: &Path,
+        tag: &str,
+        pull: PullPolicy,
+        on_output: &(dyn Fn(String) + Send + Sync),
+    ) -> MinoResult<()> {
+        self.cli
+            .build_image_with_progress(context_dir, tag, pull, on_output)
+            .await
+    }
+
+    async fn image_remove(&self, image: &str) -> MinoResult<()> {
+        let Some(api) = self.api().await else {
+            return self.cli.image_remove(image).await;
+        };
+
+        let path = format!("/images/{}", encode_segment(image));
+        let Some(resp) = self.send(api, "DELETE", &path, None).await? else {
+            return self.cli.image_remove(image).await;
+        };
+        // 404: image not known
+        expect_status("podman rmi", resp, &[404])
+    }
+
+    async fn image_list_prefixed(&self, prefix: &str) -> MinoResult<Vec<String>> {
+        let Some(api) = self.api().await else {
+            return self.cli.image_list_prefixed(prefix).await;
+        };
+
+        let filters = json!({ "reference": [format!("{}*", prefix)] }).to_string();
+        let path = format!("/images/json?filters={}", encode_segment(&filters));
+        let Some(resp) = self.send(api, "GET", &path, None).await? else {
+            return self.cli.image_list_prefixed(prefix).await;
+        };
+        if !resp.is_success() {
+            return Err(api_error("podman images", &resp));
+        }
+        parse_image_list_json(&resp.text())
+    }
+
+    async fn image_list_labeled(&self, label: &str) -> MinoResult<Vec<String>> {
+        let Some(api) = self.api().await else {
+            return self.cli.image_list_labeled(label).await;
+        };
+
+        let filters = json!({ "label": [label] }).to_string();
+        let path = format!("/images/json?filters={}", encode_segment(&filters));
+        let Some(resp) = self.send(api, "GET", &path, None).await? else {
+            return self.cli.image_list_labeled(label).await;
+        };
+        if !resp.is_success() {
+            return Err(api_error("podman images", &resp));
+        }
+        parse_image_list_json(&resp.text())
+    }
+
+    fn runtime_name(&self) -> &'static str {
+        self.cli.runtime_name()
+    }
+
+    async fn volume_create(&self, name: &str, labels: &HashMap<String, String>) -> MinoResult<()> {
+        let Some(api) = self.api().await else {
+            return self.cli.volume_create(name, labels).await;
+        };
+        debug!("Creating volume: {}", name);
+
+        let body = json!({ "Name": name, "Label": labels });
+        let Some(resp) = self
+            .send(api, "POST", "/volumes/create", Some(&body))
+            .await?
+        else {
+            return self.cli.volume_create(name, labels).await;
+        };
+        // 409: already exists, matching the CLI's `--ignore`
+        expect_status("podman volume create", resp, &[409])
+    }
+
+    async fn network_create(&self, name: &str) -> MinoResult<()> {
+        let Some(api) = self.api().await else {
+            return self.cli.network_create(name).await;
+        };
+        debug!("Creating network: {}", name);
+
+        let body = json!({ "name": name });
+        let Some(resp) = self
+            .send(api, "POST", "/networks/create", Some(&body))
+            .await?
+        else {
+            return self.cli.network_create(name).await;
+        };
+        // 409: already exists, matching the CLI's `--ignore`
+        expect_status("podman network create", resp, &[409])
+    }
+
+    async fn volume_remove(&self, name: &str) -> MinoResult<()> {
+        let Some(api) = self.api().await else {
+            return self.cli.volume_remove(name).await;
+        };
+        debug!("Removing volume: {}", name);
+
+        let path = format!("/volumes/{}?force=true", encode_segment(name));
+        let Some(resp) = self.send(api, "DELETE", &path, None).await? else {
+            return self.cli.volume_remove(name).await;
+        };
+        // 404: no such volume
+        expect_status("podman volume rm", resp, &[404])
+    }
+
+    async fn volume_list(&self, label: &str) -> MinoResult<Vec<VolumeInfo>> {
+        let Some(api) = self.api().await else {
+            return self.cli.volume_list(label).await;
+        };
+
+        let Some(resp) = self.send(api, "GET", "/volumes/json", None).await? else {
+            return self.cli.volume_list(label).await;
+        };
+        if !resp.is_success() {
+            return Err(api_error("podman volume ls", &resp));
+        }
+        super::parse_volume_list_json(&resp.text(), label)
+    }
+
+    async fn volume_inspect(&self, name: &str) -> MinoResult<Option<VolumeInfo>> {
+        let Some(api) = self.api().await else {
+            return self.cli.volume_inspect(name).await;
+        };
+
+        let path = format!("/volumes/{}/json", encode_segment(name));
+        let Some(resp) = self.send(api, "GET", &path, None).await? else {
+            return self.cli.volume_inspect(name).await;
+        };
+        match resp.status {
+            404 => Ok(None),
+            // The API returns one object where the CLI prints an array
+            _ if resp.is_success() => {
+                super::parse_volume_inspect_json(&format!("[{}]", resp.text()), name)
+            }
+            _ => Err(api_error("podman volume inspect", &resp)),
+        }
+    }
+
+    async fn volume_export(&self, name: &str, archive: &Path) -> MinoResult<()> {
+        self.cli.volume_export(name, archive).await
+    }
+
+    fn supports_volume_archives(&self) -> bool {
+        true
+    }
+
+    async fn volume_import(&self, name: &str, archive: &Path) -> MinoResult<()> {
+        self.cli.volume_import(name, archive).await
+    }
+
+    async fn volume_disk_usage(&self, label: &str) -> MinoResult<HashMap<String, u64>> {
+        if self.api().await.is_none() {
+            return self.cli.volume_disk_usage(label).await;
+        }
+
+        // The listing already carries mountpoints, so no per-volume inspect
+        let volumes = self.volume_list(label).await?;
+
+        let futures = volumes.into_iter().map(|vol| async move {
+            let Some(mountpoint) = vol.mountpoint.filter(|m| !m.is_empty()) else {
+                return Ok(None);
+            };
+
+            let du_output = tokio::process::Command::new("du")
+                .args(["-sb", &mountpoint])
+                .output()
+                .await
+                .map_err(|e| MinoError::io("du", e))?;
+
+            let size = du_output
+                .status
+                .success()
+                .then(|| super::parse_du_bytes(&du_output.stdout))
+                .flatten();
+
+            Ok(size.map(|s| (vol.name, s)))
+        });
+
+        super::measure_volumes(futures).await
+    }
+
+    async fn exec_in_container(
+        &self,
+        container_id: &str,
+        command: &[String],
+        tty: bool,
+    ) -> MinoResult<i32> {
+        self.cli.exec_in_container(container_id, command, tty).await
+    }
+
+    async fn copy_to_container(
+        &self,
+        container_id: &str,
+        host_path: &Path,
+        container_path: &str,
+    ) -> MinoResult<()> {
+        self.cli
+            .copy_to_container(container_id, host_path, container_path)
+            .await
+    }
+
+    async fn copy_from_container(
+        &self,
+        container_id: &str,
+        container_path: &str,
+        host_path: &Path,
+    ) -> MinoResult<()> {
+        self.cli
+            .copy_from_container(container_id, container_path, host_path)
+            .await
+    }
+
+    async fn get_container_exit_code(&self, container_id: &str) -> MinoResult<Option<i32>> {
+        let Some(api) = self.api().await else {
+            return self.cli.get_container_exit_code(container_id).await;
+        };
+        debug!("Waiting for container exit: {}", container_id);
+
+        let path = format!("/containers/{}/wait", encode_segment(container_id));
+        // Blocks until the container exits, so no response timeout
+        let api = api.clone().with_response_timeout(None);
+        let Some(resp) = self.send(&api, "POST", &path, None).await? else {
+            return self.cli.get_container_exit_code(container_id).await;
+        };
+        if resp.status == 404 {
+            return Ok(None);
+        }
+        if !resp.is_success() {
+            return Err(api_error("podman wait", &resp));
+        }
+
+        let body = resp.text();
+        match body.trim().parse::<i32>() {
+            Ok(code) => Ok(Some(code)),
+            Err(_) => {
+                warn!(
+                    "Could not parse exit code from Podman API: {:?}",
+                    body.trim()
+                );
+                Ok(None)
+            }
+        }
+    }
+
+    async fn start_detached(&self, container_id: &str) -> MinoResult<()> {
+        let Some(api) = self.api().await else {
+            return self.cli.start_detached(container_id).await;
+        };
+        debug!("Starting container detached: {}", container_id);
+
+        let path = format!("/containers/{}/start", encode_segment(container_id));
+        let Some(resp) = self.send(api, "POST", &path, None).await? else {
+            return self.cli.start_detached(container_id).await;
+        };
+        // 304: already running
+        if resp.is_success() || resp.status == 304 {
+            Ok(())
+        } else {
+            Err(MinoError::ContainerStart(resp.error_message()))
+        }
+    }
+
+    async fn container_restart_count(&self, container_id: &str) -> MinoResult<Option<u32>> {
+        self.cli.container_restart_count(container_id).await
+    }
+
+    async fn container_is_running(&self, container_id: &str) -> MinoResult<bool> {
+        self.cli.container_is_running(container_id).await
+    }
+
+    async fn attach(&self, container_id: &str) -> MinoResult<i32> {
+        self.cli.attach(container_id).await
+    }
+
+    async fn registry_login(
+        &self,
+        registry: &str,
+        username: Option<&str>,
+        password_stdin: bool,
+    ) -> MinoResult<()> {
+        self.cli
+            .registry_login(registry, username, password_stdin)
+            .await
+    }
+
+    async fn registry_login_password(
+        &self,
+        registry: &str,
+        username: &str,
+        password: &str,
+    ) -> MinoResult<()> {
+        self.cli
+            .registry_login_password(registry, username, password)
+            .await
+    }
+
+    async fn registry_logout(&self, registry: &str) -> MinoResult<()> {
+        self.cli.registry_logout(registry).await
+    }
+
+    async fn pod_create(&self, spec: &PodSpec) -> MinoResult<()> {
+        self.cli.pod_create(spec).await
+    }
+
+    async fn pod_remove(&self, name: &str) -> MinoResult<()> {
+        self.cli.pod_remove(name).await
+    }
+
+    async fn container_usage(&self, container_id: &str) -> MinoResult<ContainerUsage> {
+        let Some(api) = self.api().await else {
+            return self.cli.container_usage(container_id).await;
+        };
+
+        let path = format!(
+            "/containers/stats?stream=false&containers={}",
+            encode_segment(container_id)
+        );
+        let Some(resp) = self.send(api, "GET", &path, None).await? else {
+            return self.cli.container_usage(container_id).await;
+        };
+        if !resp.is_success() {
+            return Err(api_error("podman stats", &resp));
+        }
+        serde_json::from_slice::<serde_json::Value>(&resp.body)
+            .ok()
+            .and_then(|body| body.get("Stats")?.get(0).and_then(super::parse_stats))
+            .ok_or_else(|| MinoError::command_exec("podman stats", resp.text()))
+    }
+
+    async fn logs_follow_until(
+        &self,
+        container_id: &str,
+        marker: &str,
+        timeout: std::time::Duration,
+        on_line: &(dyn Fn(String) + Send + Sync),
+    ) -> MinoResult<bool> {
+        self.cli
+            .logs_follow_until(container_id, marker, timeout, on_line)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::UnixListener;
+
+    /// Serve canned responses in order, recording each request line
+    fn fake_service(socket: &Path, responses: Vec<&'static str>) -> Arc<Mutex<Vec<String>>> {
+        let listener = UnixListener::bind(socket).unwrap();
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let seen = Arc::clone(&requests);
+        tokio::spawn(async move {
+            for response in responses {
+                let (mut conn, _) = listener.accept().await.unwrap();
+                let mut buf = vec![0u8; 4096];
+                let n = conn.read(&mut buf).await.unwrap();
+                let request = String::from_utf8_lossy(&buf[..n]).into_owned();
+                let line = request.lines().next().unwrap_or_default().to_string();
+                seen.lock().unwrap().push(line);
+                conn.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+        requests
+    }
+
+    const PONG: &str = "HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nOK";
+
+    #[test]
+    fn runtime_name_matches_cli_runtime() {
+        let runtime = PodmanApiRuntime::with_socket("/nonexistent/podman.sock");
+        assert_eq!(runtime.runtime_name(), "Native Podman");
+    }
+
+    #[test]
+    fn parse_image_list_skips_untagged() {
+        let json = r#"[
+            {"Id": "a", "RepoTags": ["localhost/mino-composed-abc:latest"]},
+            {"Id": "b", "RepoTags": null},
+            {"Id": "c", "RepoTags": ["<none>:<none>"]},
+            {"Id": "d"}
+        ]"#;
+        assert_eq!(
+            parse_image_list_json(json).unwrap(),
+            vec!["localhost/mino-composed-abc:latest"]
+        );
+        assert!(parse_image_list_json("not json").is_err());
+    }
+
+    #[test]
+    fn parse_container_ids_reads_ids() {
+        let json = r#"[{"Id": "abc123", "Names": ["x"]}, {"Names": ["no-id"]}]"#;
+        assert_eq!(parse_container_ids(json).unwrap(), vec!["abc123"]);
+        assert!(parse_container_ids("[]").unwrap().is_empty());
+    }
+
+    #[test]
+    fn parse_image_user_reads_config_user() {
+        assert_eq!(
+            parse_image_user(r#"{"Config": {"User": "developer"}}"#).unwrap(),
+            Some("developer".to_string())
+        );
+        assert_eq!(
+            parse_image_user(r#"{"Config": {"User": ""}}"#).unwrap(),
+            None
+        );
+        assert_eq!(parse_image_user(r#"{"Config": {}}"#).unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn container_ops_use_api_when_reachable() {
+        let dir = tempfile::tempdir().unwrap();
+        let socket = dir.path().join("podman.sock");
+        let requests = fake_service(
+            &socket,
+            vec![
+                PONG,
+                "HTTP/1.1 304 Not Modified\r\n\r\n",
+                "HTTP/1.1 404 Not Found\r\nContent-Length: 2\r\n\r\n{}",
+                "HTTP/1.1 200 OK\r\nContent-Length: 3\r\n\r\n137",
+            ],
+        );
+
+        let runtime = PodmanApiRuntime::with_socket(&socket);
+        runtime.stop("abc").await.unwrap();
+        runtime.remove("abc").await.unwrap();
+        assert_eq!(
+            runtime.get_container_exit_code("abc").await.unwrap(),
+            Some(137)
+        );
+
+        assert_eq!(
+            *requests.lock().unwrap(),
+            vec![
+                "GET /v4.0.0/libpod/_ping HTTP/1.1",
+                "POST /v4.0.0/libpod/containers/abc/stop HTTP/1.1",
+                "DELETE /v4.0.0/libpod/containers/abc?force=true HTTP/1.1",
+                "POST /v4.0.0/libpod/containers/abc/wait HTTP/1.1",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn wedged_service_falls_back_to_cli() {
+        let dir = tempfile::tempdir().unwrap();
+        let socket = dir.path().join("podman.sock");
+        let listener = UnixListener::bind(&socket).unwrap();
+        // Accept connections but never answer
+        tokio::spawn(async move {
+            let mut held = Vec::new();
+            while let Ok((conn, _)) = listener.accept().await {
+                held.push(conn);
+            }
+        });
+
+        let runtime = PodmanApiRuntime::with_socket(&socket);
+        let client =
+            PodmanApiClient::new(&socket).with_response_timeout(Some(Duration::from_millis(50)));
+        let resp = runtime.send(&client, "GET", "/_ping", None).await.unwrap();
+
+        assert!(resp.is_none());
+        assert!(runtime.api().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn api_errors_carry_libpod_message() {
+        let dir = tempfile::tempdir().unwrap();
+        let socket = dir.path().join("podman.sock");
+        fake_service(
+            &socket,
+            vec![
+                PONG,
+                "HTTP/1.1 404 Not Found\r\n\r\n{\"message\":\"no such container: ghost\",\"x\":1}",
+                "HTTP/1.1 404 Not Found\r\n\r\n",
+            ],
+        );
+
+        let runtime = PodmanApiRuntime::with_socket(&socket);
+        let err = runtime.kill("ghost").await.unwrap_err();
+        assert!(err.to_string().contains("no such container: ghost"));
+        assert!(!runtime.image_exists("ghcr.io/x/y:1").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn volume_inspect_wraps_single_object() {
+        let dir = tempfile::tempdir().unwrap();
+        let socket = dir.path().join("podman.sock");
+        fake_service(
+            &socket,
+            vec![
+                PONG,
+                "HTTP/1.1 200 OK\r\n\r\n{\"Name\":\"v\",\"Mountpoint\":\"/data\",\"Labels\":{\"io.mino\":\"1\"}}",
+            ],
+        );
+
+        let runtime = PodmanApiRuntime::with_socket(&socket);
+        let info = runtime.volume_inspect("v").await.unwrap().unwrap();
+        assert_eq!(info.mountpoint.as_deref(), Some("/data"));
+        assert_eq!(info.labels["io.mino"], "1");
+    }
+
+    #[tokio::test]
+    async fn probe_failure_selects_cli() {
+        let dir = tempfile::tempdir().unwrap();
+        let runtime = PodmanApiRuntime::with_socket(dir.path().join("missing.sock"));
+        assert!(runtime.api().await.is_none());
+    }
+
+    /// Compare per-call latency of the CLI and the API on a real host.
+    ///
+    /// Needs rootless Podman with `podman.socket` running:
+    /// `cargo test podman_api_latency -- --ignored --nocapture`
+    #[tokio::test]
+    #[ignore = "needs a running Podman service"]
+    async fn podman_api_latency() {
+        const ROUNDS: u32 = 20;
+        let image = "docker.io/library/alpine:latest";
+        let api = PodmanApiRuntime::new();
+        assert!(api.api().await.is_some(), "Podman API socket not reachable");
+        let cli = NativePodmanRuntime::new();
+
+        let started = std::time::Instant::now();
+        for _ in 0..ROUNDS {
+            cli.image_exists(image).await.unwrap();
+        }
+        let cli_time = started.elapsed() / ROUNDS;
+
+        let started = std::time::Instant::now();
+        for _ in 0..ROUNDS {
+            api.image_exists(image).await.unwrap();
+        }
+        let api_time = started.elapsed() / ROUNDS;
+
+        println!(
+            "image_exists: CLI {:?}/call, API {:?}/call",
+            cli_time, api_time
+        );
+        assert!(api_time < cli_time);
+    }
+}
@@ -0,0 +1,1328 @@
+//! Remote Podman container runtime over SSH
+//!
+//! Implements the ContainerRuntime trait by running `podman` on a remote
+//! Linux host through `ssh`. Bind-mounted local paths are mirrored to the
+//! host with rsync before the container is created, and writable ones are
+//! copied back when the container stops.
+
+use crate::config::schema::RemoteConfig;
+use crate::error::{MinoError, MinoResult};
+use crate::orchestration::podman::{build_args, ContainerConfig, PodSpec, PullPolicy};
+use crate::orchestration::runtime::{ContainerRuntime, ContainerUsage, VolumeInfo};
+use crate::orchestration::ssh::SshHost;
+use crate::redact::redact_args;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+use tokio::sync::OnceCell;
+use tracing::{debug, warn};
+
+/// Container runtime using Podman on a remote host over SSH
+pub struct RemoteSshRuntime {
+    ssh: SshHost,
+    /// Configured sync directory (may be relative to the remote home)
+    sync_dir: String,
+    /// Absolute sync directory, resolved on first use
+    sync_root: OnceCell<String>,
+    /// Whether `podman pull` accepts `--retry` (probed once)
+    pull_retry: OnceCell<bool>,
+}
+
+/// How a container volume spec is handled on the remote host
+#[derive(Debug, PartialEq, Eq)]
+enum MountPlan {
+    /// Named volume or container-only path; passed through unchanged
+    PassThrough,
+    /// Local bind mount mirrored into the sync directory
+    Sync {
+        local: PathBuf,
+        remote: String,
+        spec: String,
+    },
+}
+
+impl RemoteSshRuntime {
+    /// Create a new remote runtime
+    pub fn new(config: RemoteConfig) -> Self {
+        Self {
+            sync_dir: config.sync_dir.clone(),
+            ssh: SshHost::new(config),
+            sync_root: OnceCell::new(),
+            pull_retry: OnceCell::new(),
+        }
+    }
+
+    /// Run a podman command on the remote host
+    async fn podman(&self, args: &[&str]) -> MinoResult<std::process::Output> {
+        let mut command = vec!["podman"];
+        command.extend_from_slice(args);
+        self.ssh.exec(&command).await
+    }
+
+    /// Absolute path of the sync directory on the remote host
+    async fn sync_root(&self) -> MinoResult<&str> {
+        self.sync_root
+            .get_or_try_init(|| async {
+                let dir = self.sync_dir.trim_end_matches('/');
+                if dir.starts_with('/') {
+                    return Ok(dir.to_string());
+                }
+
+                let output = self.ssh.exec(&["sh", "-c", "printf %s \"$HOME\""]).await?;
+                let home = String::from_utf8_lossy(&output.stdout).trim().to_string();
+                if !output.status.success() || !home.starts_with('/') {
+                    return Err(MinoError::RemoteUnreachable {
+                        host: self.ssh.host().to_string(),
+                    });
+                }
+                Ok(format!("{}/{}", home.trim_end_matches('/'), dir))
+            })
+            .await
+            .map(String::as_str)
+    }
+
+    /// Whether the installed podman supports `pull --retry` (cached)
+    async fn supports_pull_retry(&self) -> bool {
+        *self
+            .pull_retry
+            .get_or_init(|| async {
+                match self.podman(&["pull", "--help"]).await {
+                    Ok(output) => {
+                        super::pull_help_has_retry(&String::from_utf8_lossy(&output.stdout))
+                    }
+                    Err(_) => false,
+                }
+            })
+            .await
+    }
+
+    /// Pull an image, retrying interrupted downloads
+    async fn pull(&self, image: &str) -> MinoResult<()> {
+        debug!("Pulling image: {}", image);
+
+        let plan = super::podman_pull_plan(image, self.supports_pull_retry().await, None);
+        let args: Vec<&str> = plan.args.iter().map(String::as_str).collect();
+        let args = &args;
+
+        super::pull_with_retry(image, plan.attempts, || async move {
+            let output = self.podman(args).await?;
+
+            if output.status.success() {
+                Ok(())
+            } else {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                Err(MinoError::ImagePull {
+                    image: image.to_string(),
+                    reason: stderr.to_string(),
+                })
+            }
+        })
+        .await
+    }
+
+    /// Mirror local bind mounts to the remote host and rewrite their sources.
+    ///
+    /// Only regular files and directories can be synced; sockets (e.g. the
+    /// SSH agent) and missing paths are dropped with a warning.
+    async fn remote_config(&self, config: &ContainerConfig) -> MinoResult<ContainerConfig> {
+        let root = self.sync_root().await?;
+        let mut remote = config.clone();
+        remote.volumes.clear();
+
+        for spec in &config.volumes {
+            match plan_mount(spec, root) {
+                MountPlan::PassThrough => remote.volumes.push(spec.clone()),
+                MountPlan::Sync {
+                    local,
+                    remote: target,
+                    spec,
+                } => match std::fs::metadata(&local) {
+                    Ok(meta) if meta.is_dir() || meta.is_file() => {
+                        debug!("Syncing {} to {}", local.display(), target);
+                        self.ssh.push(&local, &target).await?;
+                        remote.volumes.push(spec);
+                    }
+                    _ => warn!(
+                        "Skipping mount {}: only files and directories can be synced to {}",
+                        local.display(),
+                        self.ssh.host()
+                    ),
+                },
+            }
+        }
+
+        Ok(remote)
+    }
+
+    /// Copy writable synced mounts of a container back to the local paths.
+    ///
+    /// Best-effort: failures are logged so that stopping a session never
+    /// fails because of sync.
+    async fn sync_back(&self, container_id: &str) {
+        let root = match self.sync_root().await {
+            Ok(root) => root,
+            Err(e) => {
+                warn!("Cannot sync back container {}: {}", container_id, e);
+                return;
+            }
+        };
+
+        let output = match self
+            .podman(&[
+                "inspect",
+                "--format",
+                "{{range .Mounts}}{{.Source}}\t{{.RW}}\n{{end}}",
+                container_id,
+            ])
+            .await
+        {
+            Ok(output) if output.status.success() => output,
+            _ => return, // Container already gone
+        };
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        for (remote, local) in synced_writable_mounts(&stdout, root) {
+            if !local.is_dir() {
+                continue;
+            }
+            debug!("Syncing {} back to {}", remote, local.display());
+            if let Err(e) = self.ssh.pull(&remote, &local).await {
+                warn!("Failed to sync {} back: {}", local.display(), e);
+            }
+        }
+    }
+
+    /// Create an empty staging directory on the host for `mino cp`.
+    async fn copy_stage(&self) -> MinoResult<String> {
+        let stage = format!("{}/cp-{}", self.sync_root().await?, std::process::id());
+        let output = self.ssh.exec(&["mkdir", "-p", &stage]).await?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(MinoError::command_exec("ssh mkdir", stderr));
+        }
+        Ok(stage)
+    }
+
+    /// Remove a `mino cp` staging directory (best-effort)
+    async fn remove_copy_stage(&self, stage: &str) {
+        if let Err(e) = self.ssh.exec(&["rm", "-rf", stage]).await {
+            warn!("Failed to remove {} on {}: {}", stage, self.ssh.host(), e);
+        }
+    }
+
+    /// Run `podman cp`, mapping failure to an error
+    async fn podman_cp(&self, source: &str, target: &str) -> MinoResult<()> {
+        let output = self.podman(&["cp", source, target]).await?;
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec("podman cp", stderr))
+        }
+    }
+
+    /// Stage `host_path` on the remote host, then copy it into the container.
+    async fn stage_into_container(
+        &self,
+        stage: &str,
+        container_id: &str,
+        host_path: &Path,
+        container_path: &str,
+    ) -> MinoResult<()> {
+        let name = host_path
+            .file_name()
+            .ok_or_else(|| {
+                MinoError::User(format!("Cannot copy {}: no file name", host_path.display()))
+            })?
+            .to_string_lossy();
+        // Same basename as the local path so `podman cp` names the copy alike
+        let staged = format!("{}/{}", stage, name);
+        self.ssh.push(host_path, &staged).await?;
+        self.podman_cp(&staged, &format!("{}:{}", container_id, container_path))
+            .await
+    }
+
+    /// Copy out of the container into the staging directory, then fetch it.
+    async fn stage_from_container(
+        &self,
+        stage: &str,
+        container_id: &str,
+        container_path: &str,
+        host_path: &Path,
+    ) -> MinoResult<()> {
+        let name = container_basename(container_path);
+        let staged = format!("{}/{}", stage, name);
+        self.podman_cp(&format!("{}:{}", container_id, container_path), &staged)
+            .await?;
+
+        let is_dir = self
+            .ssh
+            .exec(&["test", "-d", &staged])
+            .await?
+            .status
+            .success();
+        let dest = copy_destination(host_path, name);
+        if is_dir {
+            std::fs::create_dir_all(&dest)
+                .map_err(|e| MinoError::io(format!("creating {}", dest.display()), e))?;
+        }
+        self.ssh.fetch(&staged, &dest, is_dir).await
+    }
+
+    /// Create (or run, with `-d`) a container and return its ID.
+    async fn create_container(
+        &self,
+        base: &[&str],
+        config: &ContainerConfig,
+        command: &[String],
+    ) -> MinoResult<String> {
+        if !self.image_exists(&config.image).await? {
+            self.pull(&config.image).await?;
+        }
+
+        let mut args: Vec<String> = vec!["podman".to_string()];
+        args.extend(base.iter().map(|a| a.to_string()));
+        if config.interactive {
+            args.push("-i".to_string());
+        }
+        if config.tty {
+            args.push("-t".to_string());
+        }
+        self.remote_config(config)
+            .await?
+            .push_args(&mut args, command);
+
+        debug!(
+            "Creating container on {}: {:?}",
+            self.ssh.host(),
+            redact_args(&args)
+        );
+
+        let args_refs: Vec<&str> = args.iter().map(String::as_str).collect();
+        let output = self.ssh.exec(&args_refs).await?;
+
+        if output.status.success() {
+            let container_id = String::from_utf8_lossy(&output.stdout).trim().to_string();
+            debug!(
+                "Container created: {}",
+                &container_id[..12.min(container_id.len())]
+            );
+            Ok(container_id)
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::ContainerStart(stderr.to_string()))
+        }
+    }
+}
+
+/// Decide how to handle one `source:target[:opts]` volume spec.
+fn plan_mount(spec: &str, sync_root: &str) -> MountPlan {
+    match spec.split_once(':') {
+        Some((source, rest)) if source.starts_with('/') => {
+            let remote = format!("{sync_root}{source}");
+            MountPlan::Sync {
+                local: PathBuf::from(source),
+                spec: format!("{remote}:{rest}"),
+                remote,
+            }
+        }
+        _ => MountPlan::PassThrough,
+    }
+}
+
+/// Parse `Source\tRW` mount lines, keeping writable mounts under the sync
+/// directory and mapping each back to its local path.
+fn synced_writable_mounts(inspect: &str, sync_root: &str) -> Vec<(String, PathBuf)> {
+    inspect
+        .lines()
+        .filter_map(|line| {
+            let (source, rw) = line.split_once('\t')?;
+            if rw.trim() != "true" {
+                return None;
+            }
+            let local = source.strip_prefix(sync_root)?;
+            local
+                .starts_with('/')
+                .then(|| (source.to_string(), PathBuf::from(local)))
+        })
+        .collect()
+}
+
+/// Last path component of a container path (`/` copies come out as `root`)
+fn container_basename(path: &str) -> &str {
+    match path.trim_end_matches('/').rsplit('/').next() {
+        Some(name) if !name.is_empty() && name != "." => name,
+        _ => "root",
+    }
+}
+
+/// Local path a copy named `name` lands at, following `podman cp`: an
+/// existing directory receives the copy inside it, anything else is replaced.
+fn copy_destination(host_path: &Path, name: &str) -> PathBuf {
+    if host_path.is_dir() {
+        host_path.join(name)
+    } else {
+        host_path.to_path_buf()
+    }
+}
+
+/// Directory name for an image build context on the remote host
+fn build_context_name(tag: &str) -> String {
+    tag.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect()
+}
+
+#[async_trait]
+impl ContainerRuntime for RemoteSshRuntime {
+    async fn is_available(&self) -> MinoResult<bool> {
+        if !self.ssh.is_reachable().await {
+            return Ok(false);
+        }
+        let output = self.podman(&["--version"]).await?;
+        Ok(output.status.success())
+    }
+
+    async fn ensure_ready(&self) -> MinoResult<()> {
+        if !self.ssh.is_reachable().await {
+            return Err(MinoError::RemoteUnreachable {
+                host: self.ssh.host().to_string(),
+            });
+        }
+
+        let output = self
+            .podman(&["info", "--format", "{{.Host.Security.Rootless}}"])
+            .await?;
+        if !output.status.success() {
+            return Err(MinoError::PodmanNotFound);
+        }
+        if String::from_utf8_lossy(&output.stdout).trim() != "true" {
+            return Err(MinoError::PodmanRootlessSetup {
+                reason: format!(
+                    "Podman on {} is not running rootless. Run there: podman system migrate",
+                    self.ssh.host()
+                ),
+            });
+        }
+
+        self.sync_root().await?;
+        Ok(())
+    }
+
+    async fn check_device(&self, path: &str) -> MinoResult<()> {
+        let output = self.ssh.exec(&["test", "-e", path]).await?;
+        super::device_check(output.status.success(), path, "the remote host")
+    }
+
+    async fn check_gpu_support(&self) -> MinoResult<()> {
+        // ls exits non-zero when one of the dirs is missing; the listing still counts
+        let mut command = vec!["ls", "-1"];
+        command.extend(super::CDI_SPEC_DIRS);
+        let output = self.ssh.exec(&command).await?;
+
+        if super::cdi_listing_has_nvidia(&String::from_utf8_lossy(&output.stdout)) {
+            Ok(())
+        } else {
+            Err(super::missing_cdi_spec(self.runtime_name()))
+        }
+    }
+
+    async fn run(&self, config: &ContainerConfig, command: &[String]) -> MinoResult<String> {
+        self.create_container(&["run", "-d"], config, command).await
+    }
+
+    async fn create(&self, config: &ContainerConfig, command: &[String]) -> MinoResult<String> {
+        self.create_container(&["create"], config, command).await
+    }
+
+    async fn start_attached(&self, container_id: &str) -> MinoResult<i32> {
+        debug!("Starting container attached: {}", container_id);
+
+        let tty = std::io::stdin().is_terminal();
+        let exit_code = self
+            .ssh
+            .exec_interactive(&["podman", "start", "--attach", container_id], tty)
+            .await?;
+
+        self.sync_back(container_id).await;
+        Ok(exit_code)
+    }
+
+    async fn stop(&self, container_id: &str) -> MinoResult<()> {
+        debug!("Stopping container: {}", container_id);
+
+        let output = self.podman(&["stop", container_id]).await?;
+
+        if output.status.success() {
+            self.sync_back(container_id).await;
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec("podman stop", stderr))
+        }
+    }
+
+    async fn kill(&self, container_id: &str) -> MinoResult<()> {
+        debug!("Killing container: {}", container_id);
+
+        let output = self.podman(&["kill", container_id]).await?;
+
+        if output.status.success() {
+            self.sync_back(container_id).await;
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec("podman kill", stderr))
+        }
+    }
+
+    async fn signal(&self, container_id: &str, signal: &str) -> MinoResult<()> {
+        debug!("Sending {} to container: {}", signal, container_id);
+
+        let output = self
+            .podman(&["kill", "--signal", signal, container_id])
+            .await?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec("podman kill", stderr))
+        }
+    }
+
+    async fn pause(&self, container_id: &str) -> MinoResult<()> {
+        debug!("Pausing container: {}", container_id);
+
+        let output = self.podman(&["pause", container_id]).await?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec("podman pause", stderr))
+        }
+    }
+
+    async fn unpause(&self, container_id: &str) -> MinoResult<()> {
+        debug!("Unpausing container: {}", container_id);
+
+        let output = self.podman(&["unpause", container_id]).await?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec("podman unpause", stderr))
+        }
+    }
+
+    async fn checkpoint(
+        &self,
+        container_id: &str,
+        archive: &Path,
+        leave_running: bool,
+    ) -> MinoResult<()> {
+        debug!(
+            "Checkpointing container {} to {}",
+            container_id,
+            archive.display()
+        );
+        let stage = self.copy_stage().await?;
+        let staged = format!("{}/checkpoint.tar.gz", stage);
+        let result = async {
+            let output = self
+                .podman(&super::checkpoint_args(
+                    container_id,
+                    &staged,
+                    leave_running,
+                ))
+                .await?;
+            if !output.status.success() {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                return Err(super::checkpoint_error(
+                    self.runtime_name(),
+                    "podman container checkpoint",
+                    &stderr,
+                ));
+            }
+            self.ssh.fetch(&staged, archive, false).await
+        }
+        .await;
+        self.remove_copy_stage(&stage).await;
+        result
+    }
+
+    async fn restore(&self, archive: &Path) -> MinoResult<String> {
+        debug!("Restoring container from {}", archive.display());
+        let stage = self.copy_stage().await?;
+        let staged = format!("{}/checkpoint.tar.gz", stage);
+        let result = async {
+            self.ssh.push(archive, &staged).await?;
+            let output = self
+                .podman(&["container", "restore", "--import", &staged])
+                .await?;
+            if output.status.success() {
+                super::parse_restored_id(&String::from_utf8_lossy(&output.stdout))
+            } else {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                Err(super::checkpoint_error(
+                    self.runtime_name(),
+                    "podman container restore",
+                    &stderr,
+                ))
+            }
+        }
+        .await;
+        self.remove_copy_stage(&stage).await;
+        result
+    }
+
+    async fn remove(&self, container_id: &str) -> MinoResult<()> {
+        debug!("Removing container: {}", container_id);
+
+        let output = self.podman(&["rm", "-f", container_id]).await?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            // Ignore error if container doesn't exist
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            if stderr.contains("no such container") {
+                Ok(())
+            } else {
+                Err(MinoError::command_exec("podman rm", stderr))
+            }
+        }
+    }
+
+    async fn container_prune(&self) -> MinoResult<()> {
+        let output = self.podman(&["container", "prune", "-f"]).await?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(MinoError::command_exec("podman container prune", stderr));
+        }
+        Ok(())
+    }
+
+    async fn container_list_labeled(&self, label: &str) -> MinoResult<Vec<String>> {
+        let filter = format!("label={}", label);
+        let output = self
+            .podman(&["ps", "-a", "--filter", &filter, "--format", "{{.ID}}"])
+            .await?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(MinoError::command_exec("podman ps", stderr));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        Ok(stdout
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(String::from)
+            .collect())
+    }
+
+    async fn logs(&self, container_id: &str, lines: u32) -> MinoResult<String> {
+        let tail_arg = if lines == 0 {
+            "all".to_string()
+        } else {
+            lines.to_string()
+        };
+
+        let output = self
+            .podman(&["logs", "--tail", &tail_arg, container_id])
+            .await?;
+
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    }
+
+    async fn logs_follow(&self, container_id: &str) -> MinoResult<()> {
+        self.ssh
+            .exec_interactive(&["podman", "logs", "-f", container_id], false)
+            .await?;
+        Ok(())
+    }
+
+    async fn logs_follow_lines(
+        &self,
+        container_id: &str,
+        lines: u32,
+        on_line: &(dyn Fn(String) + Send + Sync),
+    ) -> MinoResult<()> {
+        let tail_arg = if lines == 0 {
+            "all".to_string()
+        } else {
+            lines.to_string()
+        };
+
+        let mut child =
+            self.ssh
+                .spawn_piped(&["podman", "logs", "-f", "--tail", &tail_arg, container_id])?;
+
+        super::forward_child_lines(&mut child, on_line).await;
+        Ok(())
+    }
+
+    async fn image_exists(&self, image: &str) -> MinoResult<bool> {
+        let output = self.podman(&["image", "exists", image]).await?;
+        Ok(output.status.success())
+    }
+
+    async fn image_pull(&self, image: &str) -> MinoResult<()> {
+        self.pull(image).await
+    }
+
+    async fn image_pull_with_progress(
+        &self,
+        image: &str,
+        platform: Option<&str>,
+        on_output: &(dyn Fn(String) + Send + Sync),
+    ) -> MinoResult<()> {
+        let plan = super::podman_pull_plan(image, self.supports_pull_retry().await, platform);
+        let args: Vec<&str> = std::iter::once("podman")
+            .chain(plan.args.iter().map(String::as_str))
+            .collect();
+        let args = &args;
+
+        super::pull_with_retry(image, plan.attempts, || async move {
+            let mut child = self.ssh.spawn_piped(args)?;
+            super::stream_pull(&mut child, image, on_output).await
+        })
+        .await
+    }
+
+    async fn image_platform(&self, image: &str) -> MinoResult<Option<String>> {
+        let output = self
+            .podman(&[
+                "image",
+                "inspect",
+                "--format",
+                super::IMAGE_PLATFORM_FORMAT,
+                image,
+            ])
+            .await?;
+        if !output.status.success() {
+            return Ok(None);
+        }
+        Ok(super::parse_image_platform(&String::from_utf8_lossy(
+            &output.stdout,
+        )))
+    }
+
+    async fn image_user(&self, image: &str) -> MinoResult<Option<String>> {
+        if !self.image_exists(image).await? {
+            self.pull(image).await?;
+        }
+
+        let output = self
+            .podman(&["image", "inspect", "--format", "{{.Config.User}}", image])
+            .await?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(MinoError::command_exec("podman image inspect", stderr));
+        }
+
+        let user = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        Ok(if user.is_empty() { None } else { Some(user) })
+    }
+
+    async fn build_image(&self, context_dir: &Path, tag: &str, pull: PullPolicy) -> MinoResult<()> {
+        let remote_ctx = format!(
+            "{}/build/{}",
+            self.sync_root().await?,
+            build_context_name(tag)
+        );
+        self.ssh.push(context_dir, &remote_ctx).await?;
+
+        let args = build_args(tag, &remote_ctx, pull);
+        let args: Vec<&str> = args.iter().map(String::as_str).collect();
+        let output = self.podman(&args).await?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            let combined = super::build_error_output(&stdout, &stderr);
+            return Err(MinoError::ImageBuild {
+                tag: tag.to_string(),
+                reason: combined,
+            });
+        }
+
+        Ok(())
+    }
+
+    async fn build_image_with_progress(
+        &self,
+        context_dir: &Path,
+        tag: &str,
+        pull: PullPolicy,
+        on_output: &(dyn Fn(String) + Send + Sync),
+    ) -> MinoResult<()> {
+        let remote_ctx = format!(
+            "{}/build/{}",
+            self.sync_root().await?,
+            build_context_name(tag)
+        );
+        self.ssh.push(context_dir, &remote_ctx).await?;
+
+        let build = build_args(tag, &remote_ctx, pull);
+        let args: Vec<&str> = std::iter::once("podman")
+            .chain(build.iter().map(String::as_str))
+            .collect();
+        let mut child = self.ssh.spawn_piped(&args)?;
+
+        let all_output = super::stream_child_output(&mut child, on_output).await;
+
+        let status = child
+            .wait()
+            .await
+            .map_err(|e| MinoError::command_failed("podman build", e))?;
+
+        if !status.success() {
+            let combined = all_output.join("\n");
+            let tail = super::build_error_output(&combined, "");
+            return Err(MinoError::ImageBuild {
+                tag: tag.to_string(),
+                reason: tail,
+            });
+        }
+
+        Ok(())
+    }
+
+    async fn image_remove(&self, image: &str) -> MinoResult<()> {
+        let output = self.podman(&["rmi", image]).await?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            if stderr.contains("image not known") {
+                Ok(())
+            } else {
+                Err(MinoError::command_exec("podman rmi", stderr))
+            }
+        }
+    }
+
+    async fn image_list_prefixed(&self, prefix: &str) -> MinoResult<Vec<String>> {
+        let filter = format!("reference={}*", prefix);
+        let output = self
+            .podman(&[
+                "images",
+                "--filter",
+                &filter,
+                "--format",
+                "{{.Repository}}:{{.Tag}}",
+            ])
+            .await?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(MinoError::command_exec("podman images", stderr));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let images: Vec<String> = stdout
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(String::from)
+            .collect();
+
+        Ok(images)
+    }
+
+    async fn image_list_labeled(&self, label: &str) -> MinoResult<Vec<String>> {
+        let filter = format!("label={}", label);
+        let output = self
+            .podman(&[
+                "images",
+                "--filter",
+                &filter,
+                "--format",
+                "{{.Repository}}:{{.Tag}}",
+            ])
+            .await?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(MinoError::command_exec("podman images", stderr));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let images: Vec<String> = stdout
+            .lines()
+            // Untagged images carrying the label show up as <none>:<none>
+            .filter(|line| !line.is_empty() && !line.contains("<none>"))
+            .map(String::from)
+            .collect();
+
+        Ok(images)
+    }
+
+    fn runtime_name(&self) -> &'static str {
+        "Remote Podman (SSH)"
+    }
+
+    async fn volume_create(&self, name: &str, labels: &HashMap<String, String>) -> MinoResult<()> {
+        debug!("Creating volume: {}", name);
+
+        let mut args = vec!["volume", "create", "--ignore"];
+
+        let label_strings: Vec<String> =
+            labels.iter().map(|(k, v)| format!("{}={}", k, v)).collect();
+
+        for label in &label_strings {
+            args.push("--label");
+            args.push(label);
+        }
+
+        args.push(name);
+
+        let output = self.podman(&args).await?;
+
+        if output.status.success() {
+            debug!("Volume created: {}", name);
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec("podman volume create", stderr))
+        }
+    }
+
+    async fn network_create(&self, name: &str) -> MinoResult<()> {
+        debug!("Creating network: {}", name);
+
+        let output = self
+            .podman(&["network", "create", "--ignore", name])
+            .await?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec("podman network create", stderr))
+        }
+    }
+
+    async fn volume_remove(&self, name: &str) -> MinoResult<()> {
+        debug!("Removing volume: {}", name);
+
+        let output = self.podman(&["volume", "rm", "-f", name]).await?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            if stderr.contains("no such volume") {
+                Ok(())
+            } else {
+                Err(MinoError::command_exec("podman volume rm", stderr))
+            }
+        }
+    }
+
+    async fn volume_list(&self, label: &str) -> MinoResult<Vec<VolumeInfo>> {
+        let output = self.podman(&["volume", "ls", "--format", "json"]).await?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(MinoError::command_exec("podman volume ls", stderr));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        super::parse_volume_list_json(&stdout, label)
+    }
+
+    async fn volume_inspect(&self, name: &str) -> MinoResult<Option<VolumeInfo>> {
+        let output = self
+            .podman(&["volume", "inspect", name, "--format", "json"])
+            .await?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            if stderr.contains("no such volume") {
+                return Ok(None);
+            }
+            return Err(MinoError::command_exec("podman volume inspect", stderr));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        super::parse_volume_inspect_json(&stdout, name)
+    }
+
+    async fn volume_export(&self, name: &str, archive: &Path) -> MinoResult<()> {
+        debug!("Exporting volume {} to {}", name, archive.display());
+        let stage = self.copy_stage().await?;
+        let staged = format!("{}/volume.tar", stage);
+        let result = async {
+            let output = self
+                .podman(&super::volume_export_args(name, &staged))
+                .await?;
+            super::registry_output_result("podman volume export", &output)?;
+            self.ssh.fetch(&staged, archive, false).await
+        }
+        .await;
+        self.remove_copy_stage(&stage).await;
+        result
+    }
+
+    fn supports_volume_archives(&self) -> bool {
+        true
+    }
+
+    async fn volume_import(&self, name: &str, archive: &Path) -> MinoResult<()> {
+        debug!("Importing {} into volume {}", archive.display(), name);
+        let stage = self.copy_stage().await?;
+        let staged = format!("{}/volume.tar", stage);
+        let result = async {
+            self.ssh.push(archive, &staged).await?;
+            let output = self
+                .podman(&super::volume_import_args(name, &staged))
+                .await?;
+            super::registry_output_result("podman volume import", &output)
+        }
+        .await;
+        self.remove_copy_stage(&stage).await;
+        result
+    }
+
+    async fn volume_disk_usage(&self, label: &str) -> MinoResult<HashMap<String, u64>> {
+        let volumes = self.volume_list(label).await?;
+
+        let futures = volumes.into_iter().map(|vol| async move {
+            let output = self
+                .podman(&[
+                    "volume",
+                    "inspect",
+                    &vol.name,
+                    "--format",
+                    "{{.Mountpoint}}",
+                ])
+                .await?;
+
+            if !output.status.success() {
+                return Ok(None);
+            }
+
+            let mountpoint = String::from_utf8_lossy(&output.stdout).trim().to_string();
+            if mountpoint.is_empty() {
+                return Ok(None);
+            }
+
+            let du_output = self.ssh.exec(&["du", "-sb", &mountpoint]).await?;
+
+            let size = du_output
+                .status
+                .success()
+                .then(|| super::parse_du_bytes(&du_output.stdout))
+                .flatten();
+
+            Ok(size.map(|s| (vol.name.clone(), s)))
+        });
+
+        super::measure_volumes(futures).await
+    }
+
+    async fn exec_in_container(
+        &self,
+        container_id: &str,
+        command: &[String],
+        tty: bool,
+    ) -> MinoResult<i32> {
+        debug!("Exec into container: {}", container_id);
+        let mut args = vec!["podman", "exec", "-i"];
+        if tty {
+            args.push("-t");
+        }
+        args.push(container_id);
+        args.extend(command.iter().map(String::as_str));
+        self.ssh.exec_interactive(&args, tty).await
+    }
+
+    async fn copy_to_container(
+        &self,
+        container_id: &str,
+        host_path: &Path,
+        container_path: &str,
+    ) -> MinoResult<()> {
+        debug!("Copying {} into {}", host_path.display(), container_id);
+        let stage = self.copy_stage().await?;
+        let result = self
+            .stage_into_container(&stage, container_id, host_path, container_path)
+            .await;
+        self.remove_copy_stage(&stage).await;
+        result
+    }
+
+    async fn copy_from_container(
+        &self,
+        container_id: &str,
+        container_path: &str,
+        host_path: &Path,
+    ) -> MinoResult<()> {
+        debug!("Copying {} out of {}", container_path, container_id);
+        let stage = self.copy_stage().await?;
+        let result = self
+            .stage_from_container(&stage, container_id, container_path, host_path)
+            .await;
+        self.remove_copy_stage(&stage).await;
+        result
+    }
+
+    async fn get_container_exit_code(&self, container_id: &str) -> MinoResult<Option<i32>> {
+        debug!("Waiting for container exit: {}", container_id);
+
+        let output = self.podman(&["wait", container_id]).await?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            if stderr.contains("no such container") {
+                return Ok(None);
+            }
+            return Err(MinoError::command_exec("podman wait", stderr));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        match stdout.trim().parse::<i32>() {
+            Ok(code) => Ok(Some(code)),
+            Err(_) => {
+                warn!(
+                    "Could not parse exit code from podman wait: {:?}",
+                    stdout.trim()
+                );
+                Ok(None)
+            }
+        }
+    }
+
+    async fn start_detached(&self, container_id: &str) -> MinoResult<()> {
+        debug!("Starting container detached: {}", container_id);
+        let output = self.podman(&["start", container_id]).await?;
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::ContainerStart(stderr.to_string()))
+        }
+    }
+
+    async fn container_restart_count(&self, container_id: &str) -> MinoResult<Option<u32>> {
+        let output = self
+            .podman(&["inspect", "--format", "{{.RestartCount}}", container_id])
+            .await?;
+        super::parse_restart_count(&output, "podman inspect")
+    }
+
+    async fn container_is_running(&self, container_id: &str) -> MinoResult<bool> {
+        let output = self
+            .podman(&["inspect", "--format", "{{.State.Running}}", container_id])
+            .await?;
+        super::parse_running(&output, "podman inspect")
+    }
+
+    async fn attach(&self, container_id: &str) -> MinoResult<i32> {
+        debug!("Attaching to container: {}", container_id);
+
+        let tty = std::io::stdin().is_terminal();
+        let exit_code = self
+            .ssh
+            .exec_interactive(&["podman", "attach", container_id], tty)
+            .await?;
+
+        self.sync_back(container_id).await;
+        Ok(exit_code)
+    }
+
+    async fn registry_login(
+        &self,
+        registry: &str,
+        username: Option<&str>,
+        password_stdin: bool,
+    ) -> MinoResult<()> {
+        debug!(
+            "Logging in to registry on {}: {}",
+            self.ssh.host(),
+            registry
+        );
+        let mut args = vec!["podman".to_string()];
+        args.extend(super::registry_login_args(
+            registry,
+            username,
+            password_stdin,
+        ));
+        let args_refs: Vec<&str> = args.iter().map(String::as_str).collect();
+        // A piped password must not go through a remote TTY
+        let tty = !password_stdin && std::io::stdin().is_terminal();
+        let code = self.ssh.exec_interactive(&args_refs, tty).await?;
+        super::registry_command_result("podman login", registry, code)
+    }
+
+    async fn registry_login_password(
+        &self,
+        registry: &str,
+        username: &str,
+        password: &str,
+    ) -> MinoResult<()> {
+        debug!(
+            "Logging in to registry on {}: {}",
+            self.ssh.host(),
+            registry
+        );
+        let mut args = vec!["podman".to_string()];
+        args.extend(super::registry_login_args(registry, Some(username), true));
+        let args_refs: Vec<&str> = args.iter().map(String::as_str).collect();
+        let output = self
+            .ssh
+            .exec_with_input(&args_refs, password.as_bytes())
+            .await?;
+        super::registry_output_result("podman login", &output)
+    }
+
+    async fn registry_logout(&self, registry: &str) -> MinoResult<()> {
+        debug!("Logging out of registry: {}", registry);
+        let output = self.podman(&["logout", registry]).await?;
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec("podman logout", stderr))
+        }
+    }
+
+    async fn pod_create(&self, spec: &PodSpec) -> MinoResult<()> {
+        debug!("Creating pod: {}", spec.name);
+        let args = spec.create_args();
+        let args_refs: Vec<&str> = args.iter().map(String::as_str).collect();
+        let output = self.podman(&args_refs).await?;
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec("podman pod create", stderr))
+        }
+    }
+
+    async fn pod_remove(&self, name: &str) -> MinoResult<()> {
+        debug!("Removing pod: {}", name);
+        let output = self
+            .podman(&["pod", "rm", "--force", "--ignore", name])
+            .await?;
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec("podman pod rm", stderr))
+        }
+    }
+
+    async fn container_usage(&self, container_id: &str) -> MinoResult<ContainerUsage> {
+        let output = self.podman(&super::usage_stats_args(container_id)).await?;
+        super::parse_usage(&output, "podman stats")
+    }
+
+    async fn logs_follow_until(
+        &self,
+        container_id: &str,
+        marker: &str,
+        timeout: std::time::Duration,
+        on_line: &(dyn Fn(String) + Send + Sync),
+    ) -> MinoResult<bool> {
+        debug!("Following logs for {} until '{}'", container_id, marker);
+
+        let mut child = self
+            .ssh
+            .spawn_piped(&["podman", "logs", "-f", container_id])?;
+
+        Ok(super::follow_until_marker(&mut child, marker, timeout, on_line).await)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROOT: &str = "/home/dev/.cache/mino/sync";
+
+    #[test]
+    fn remote_runtime_name() {
+        let runtime = RemoteSshRuntime::new(RemoteConfig::default());
+        assert_eq!(runtime.runtime_name(), "Remote Podman (SSH)");
+    }
+
+    #[test]
+    fn plan_mount_rewrites_bind_mounts() {
+        assert_eq!(
+            plan_mount("/Users/me/app:/workspace", ROOT),
+            MountPlan::Sync {
+                local: PathBuf::from("/Users/me/app"),
+                remote: format!("{ROOT}/Users/me/app"),
+                spec: format!("{ROOT}/Users/me/app:/workspace"),
+            }
+        );
+
+        match plan_mount("/etc/localtime:/etc/localtime:ro", ROOT) {
+            MountPlan::Sync { spec, .. } => {
+                assert_eq!(spec, format!("{ROOT}/etc/localtime:/etc/localtime:ro"))
+            }
+            other => panic!("expected sync, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn plan_mount_passes_named_volumes_through() {
+        assert_eq!(
+            plan_mount("mino-cache-npm-abc:/cache/npm:U", ROOT),
+            MountPlan::PassThrough
+        );
+        assert_eq!(plan_mount("/anonymous", ROOT), MountPlan::PassThrough);
+    }
+
+    #[test]
+    fn synced_writable_mounts_maps_back_to_local() {
+        let inspect = format!(
+            "{ROOT}/Users/me/app\ttrue\n\
+             {ROOT}/etc/localtime\tfalse\n\
+             /var/lib/containers/storage/volumes/cache/_data\ttrue\n"
+        );
+
+        let mounts = synced_writable_mounts(&inspect, ROOT);
+        assert_eq!(
+            mounts,
+            vec![(
+                format!("{ROOT}/Users/me/app"),
+                PathBuf::from("/Users/me/app")
+            )]
+        );
+    }
+
+    #[test]
+    fn build_context_name_is_path_safe() {
+        assert_eq!(
+            build_context_name("mino-composed-ab12:latest"),
+            "mino-composed-ab12-latest"
+        );
+    }
+
+    #[test]
+    fn container_basename_takes_last_component() {
+        assert_eq!(container_basename("/workspace/dist/"), "dist");
+        assert_eq!(container_basename("out.log"), "out.log");
+        assert_eq!(container_basename("/"), "root");
+    }
+
+    #[test]
+    fn copy_destination_follows_podman_cp() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(
+            copy_destination(dir.path(), "dist"),
+            dir.path().join("dist")
+        );
+        let missing = dir.path().join("new-name");
+        assert_eq!(copy_destination(&missing, "dist"), missing);
+    }
+}
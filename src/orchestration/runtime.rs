@@ -4,7 +4,7 @@
 //! by different backends (OrbStack+Podman on macOS, native Podman on Linux).
 
 use crate::error::MinoResult;
-use crate::orchestration::podman::ContainerConfig;
+use crate::orchestration::podman::{ContainerConfig, PodSpec, PullPolicy};
 use async_trait::async_trait;
 use std::collections::HashMap;
 use std::path::Path;
@@ -24,11 +24,37 @@ pub struct VolumeInfo {
     pub size_bytes: Option<u64>,
 }
 
+/// Resource usage of a running container, as reported by the container
+/// engine on the host
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ContainerUsage {
+    /// CPU time consumed, in seconds
+    pub cpu_secs: f64,
+    /// Highest memory use seen across samples
+    pub peak_memory_bytes: Option<u64>,
+    /// Bytes received over the container network
+    pub net_rx_bytes: u64,
+    /// Bytes sent over the container network
+    pub net_tx_bytes: u64,
+}
+
+impl ContainerUsage {
+    /// Combine with a later sample: the cumulative counters come from the
+    /// later sample, peak memory is the highest of the two
+    pub fn followed_by(self, later: ContainerUsage) -> ContainerUsage {
+        ContainerUsage {
+            peak_memory_bytes: self.peak_memory_bytes.max(later.peak_memory_bytes),
+            ..later
+        }
+    }
+}
+
 /// Abstract container runtime interface
 ///
 /// This trait allows mino to work with different container runtimes:
 /// - macOS: OrbStack VM + Podman
 /// - Linux: Native rootless Podman
+/// - Docker Desktop / dockerd on either platform
 #[async_trait]
 pub trait ContainerRuntime: Send + Sync {
     /// Check if the runtime is available on this system
@@ -37,6 +63,17 @@ pub trait ContainerRuntime: Send + Sync {
     /// Ensure the runtime is ready (start VM, check rootless setup, etc.)
     async fn ensure_ready(&self) -> MinoResult<()>;
 
+    /// Check that containers can be given the host's NVIDIA GPUs.
+    ///
+    /// Returns `MinoError::GpuUnsupported` describing what is missing.
+    async fn check_gpu_support(&self) -> MinoResult<()>;
+
+    /// Check that device `path` exists where containers run: the host, or
+    /// the VM on macOS.
+    ///
+    /// Returns `MinoError::DeviceNotFound` when it does not.
+    async fn check_device(&self, path: &str) -> MinoResult<()>;
+
     /// Run a container in detached mode and return the container ID
     async fn run(&self, config: &ContainerConfig, command: &[String]) -> MinoResult<String>;
 
@@ -52,23 +89,81 @@ pub trait ContainerRuntime: Send + Sync {
     /// Kill a container immediately
     async fn kill(&self, container_id: &str) -> MinoResult<()>;
 
+    /// Send `signal` (e.g. `SIGUSR1` or `10`) to a container's main process
+    async fn signal(&self, container_id: &str, signal: &str) -> MinoResult<()>;
+
+    /// Freeze all processes in a running container
+    async fn pause(&self, container_id: &str) -> MinoResult<()>;
+
+    /// Thaw a paused container
+    async fn unpause(&self, container_id: &str) -> MinoResult<()>;
+
+    /// Checkpoint a container's processes (via CRIU) into `archive`.
+    /// The container stops afterwards unless `leave_running` is set.
+    async fn checkpoint(
+        &self,
+        container_id: &str,
+        archive: &Path,
+        leave_running: bool,
+    ) -> MinoResult<()>;
+
+    /// Restore a container from a checkpoint archive, returning the new container ID
+    async fn restore(&self, archive: &Path) -> MinoResult<String>;
+
     /// Remove a container
     async fn remove(&self, container_id: &str) -> MinoResult<()>;
 
     /// Remove all stopped containers
     async fn container_prune(&self) -> MinoResult<()>;
 
+    /// List IDs of containers in any state carrying `label` (`key` or `key=value`)
+    async fn container_list_labeled(&self, label: &str) -> MinoResult<Vec<String>>;
+
     /// Get container logs
     async fn logs(&self, container_id: &str, lines: u32) -> MinoResult<String>;
 
     /// Follow container logs interactively
     async fn logs_follow(&self, container_id: &str) -> MinoResult<()>;
 
+    /// Follow container logs, passing each line to `on_line` until the
+    /// container stops. Starts from the last `lines` lines (0 = all).
+    async fn logs_follow_lines(
+        &self,
+        container_id: &str,
+        lines: u32,
+        on_line: &(dyn Fn(String) + Send + Sync),
+    ) -> MinoResult<()>;
+
     /// Check if a container image exists locally
     async fn image_exists(&self, image: &str) -> MinoResult<bool>;
 
+    /// Pull an image from its registry, replacing any local copy
+    async fn image_pull(&self, image: &str) -> MinoResult<()>;
+
+    /// Pull an image with streaming output.
+    ///
+    /// `platform` (e.g. `linux/amd64`) selects a variant of a multi-arch
+    /// image. Each line of pull output (stdout + stderr) is passed to
+    /// `on_output` as an owned String, so callers can show layer progress.
+    async fn image_pull_with_progress(
+        &self,
+        image: &str,
+        platform: Option<&str>,
+        on_output: &(dyn Fn(String) + Send + Sync),
+    ) -> MinoResult<()>;
+
+    /// Platform (`os/arch`) of a local image, or `None` when the image is
+    /// not present or its platform cannot be read
+    async fn image_platform(&self, image: &str) -> MinoResult<Option<String>>;
+
+    /// Get the image's configured default user (`Config.User`).
+    ///
+    /// Pulls the image first if it is not present locally. Returns `None`
+    /// when the image does not declare a user (i.e. it runs as root).
+    async fn image_user(&self, image: &str) -> MinoResult<Option<String>>;
+
     /// Build an image from a context directory
-    async fn build_image(&self, context_dir: &Path, tag: &str) -> MinoResult<()>;
+    async fn build_image(&self, context_dir: &Path, tag: &str, pull: PullPolicy) -> MinoResult<()>;
 
     /// Build an image with line-by-line progress reporting.
     ///
@@ -78,6 +173,7 @@ pub trait ContainerRuntime: Send + Sync {
         &self,
         context_dir: &Path,
         tag: &str,
+        pull: PullPolicy,
         on_output: &(dyn Fn(String) + Send + Sync),
     ) -> MinoResult<()>;
 
@@ -87,6 +183,9 @@ pub trait ContainerRuntime: Send + Sync {
     /// List images matching a name prefix
     async fn image_list_prefixed(&self, prefix: &str) -> MinoResult<Vec<String>>;
 
+    /// List tagged images carrying `label` (any value)
+    async fn image_list_labeled(&self, label: &str) -> MinoResult<Vec<String>>;
+
     /// Get the human-readable runtime name for display
     fn runtime_name(&self) -> &'static str;
 
@@ -95,18 +194,33 @@ pub trait ContainerRuntime: Send + Sync {
     /// Create a new volume with the given name and labels
     async fn volume_create(&self, name: &str, labels: &HashMap<String, String>) -> MinoResult<()>;
 
+    /// Create a user-defined network (no-op if it already exists).
+    ///
+    /// Unlike the default `bridge`, such networks resolve container
+    /// network aliases over DNS.
+    async fn network_create(&self, name: &str) -> MinoResult<()>;
+
     /// Remove a volume
     async fn volume_remove(&self, name: &str) -> MinoResult<()>;
 
-    /// List volumes matching a name prefix
-    async fn volume_list(&self, prefix: &str) -> MinoResult<Vec<VolumeInfo>>;
+    /// List volumes carrying `label` (any value), whatever their name
+    async fn volume_list(&self, label: &str) -> MinoResult<Vec<VolumeInfo>>;
 
     /// Get detailed info about a specific volume
     async fn volume_inspect(&self, name: &str) -> MinoResult<Option<VolumeInfo>>;
 
-    /// Get disk usage for volumes matching a prefix
+    /// Get disk usage for volumes carrying `label`
     /// Returns a map of volume name -> size in bytes
-    async fn volume_disk_usage(&self, prefix: &str) -> MinoResult<HashMap<String, u64>>;
+    async fn volume_disk_usage(&self, label: &str) -> MinoResult<HashMap<String, u64>>;
+
+    /// Write the contents of volume `name` to the tar archive `archive`
+    async fn volume_export(&self, name: &str, archive: &Path) -> MinoResult<()>;
+
+    /// Fill the existing volume `name` from the tar archive `archive`
+    async fn volume_import(&self, name: &str, archive: &Path) -> MinoResult<()>;
+
+    /// Whether `volume_export` and `volume_import` are available
+    fn supports_volume_archives(&self) -> bool;
 
     /// Execute a command inside a running container.
     ///
@@ -119,6 +233,28 @@ pub trait ContainerRuntime: Send + Sync {
         tty: bool,
     ) -> MinoResult<i32>;
 
+    /// Copy a host file or directory into a container (`podman cp`).
+    ///
+    /// `host_path` must be absolute: VM and remote runtimes resolve it
+    /// outside the caller's working directory.
+    async fn copy_to_container(
+        &self,
+        container_id: &str,
+        host_path: &Path,
+        container_path: &str,
+    ) -> MinoResult<()>;
+
+    /// Copy a file or directory out of a container to an absolute host path.
+    ///
+    /// Follows `podman cp`: an existing host directory receives the copy
+    /// inside it; otherwise the copy is created at `host_path`.
+    async fn copy_from_container(
+        &self,
+        container_id: &str,
+        container_path: &str,
+        host_path: &Path,
+    ) -> MinoResult<()>;
+
     /// Wait for a container to exit and return its exit code.
     ///
     /// Uses `podman wait` which blocks until the container stops, then returns
@@ -129,6 +265,49 @@ pub trait ContainerRuntime: Send + Sync {
     /// Start a created container in detached mode.
     async fn start_detached(&self, container_id: &str) -> MinoResult<()>;
 
+    /// Number of times the restart policy has restarted a container.
+    ///
+    /// Returns `None` if the container no longer exists.
+    async fn container_restart_count(&self, container_id: &str) -> MinoResult<Option<u32>>;
+
+    /// Whether a container is running. Returns `false` if it no longer exists.
+    async fn container_is_running(&self, container_id: &str) -> MinoResult<bool>;
+
+    /// Re-attach the terminal to a running container. Returns its exit code
+    /// once it stops.
+    async fn attach(&self, container_id: &str) -> MinoResult<i32>;
+
+    /// Log in to a container registry where images are pulled (the VM on
+    /// macOS), prompting on the terminal for anything not given.
+    /// With `password_stdin`, the password is read from mino's stdin.
+    async fn registry_login(
+        &self,
+        registry: &str,
+        username: Option<&str>,
+        password_stdin: bool,
+    ) -> MinoResult<()>;
+
+    /// Log in to a container registry where images are pulled with a
+    /// password mino already holds, piped to `login --password-stdin`
+    async fn registry_login_password(
+        &self,
+        registry: &str,
+        username: &str,
+        password: &str,
+    ) -> MinoResult<()>;
+
+    /// Remove stored credentials for a container registry
+    async fn registry_logout(&self, registry: &str) -> MinoResult<()>;
+
+    /// Create a pod for a session's main container and its services
+    async fn pod_create(&self, spec: &PodSpec) -> MinoResult<()>;
+
+    /// Remove a pod and every container in it (no error if it is gone)
+    async fn pod_remove(&self, name: &str) -> MinoResult<()>;
+
+    /// Resource usage of a running container so far, sampled from the host
+    async fn container_usage(&self, container_id: &str) -> MinoResult<ContainerUsage>;
+
     /// Follow container logs until a marker string is found or timeout expires.
     ///
     /// Calls `on_line` for each log line received. Returns `true` if the marker
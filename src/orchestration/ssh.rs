@@ -0,0 +1,290 @@
+//! SSH transport for the remote runtime
+//!
+//! Runs commands on a remote host over `ssh` and mirrors files with `rsync`.
+
+use crate::config::schema::RemoteConfig;
+use crate::error::{MinoError, MinoResult};
+use crate::network::shell_escape;
+use crate::redact::redact_args;
+use std::path::Path;
+use std::process::Stdio;
+use tokio::process::Command;
+use tracing::debug;
+
+/// SSH connection to the remote container host
+pub struct SshHost {
+    config: RemoteConfig,
+}
+
+impl SshHost {
+    /// Create a new SSH transport
+    pub fn new(config: RemoteConfig) -> Self {
+        Self { config }
+    }
+
+    /// SSH destination (`user@host` or an ssh_config alias)
+    pub fn host(&self) -> &str {
+        &self.config.host
+    }
+
+    /// Connection options shared by `ssh` and rsync's `-e` transport.
+    fn ssh_options(&self) -> Vec<String> {
+        let mut opts = Vec::new();
+        if let Some(port) = self.config.port {
+            opts.push("-p".to_string());
+            opts.push(port.to_string());
+        }
+        if let Some(ref identity) = self.config.identity_file {
+            opts.push("-i".to_string());
+            opts.push(identity.clone());
+        }
+        opts
+    }
+
+    /// Build an `ssh` invocation running `command` on the remote host.
+    ///
+    /// `tty` requests a remote pseudo-terminal (`-t`) for interactive use.
+    fn ssh_command(&self, command: &[&str], tty: bool) -> Command {
+        let mut cmd = Command::new("ssh");
+        cmd.args(self.ssh_options());
+        cmd.arg(if tty { "-t" } else { "-T" });
+        cmd.arg(&self.config.host);
+        cmd.arg("--");
+        cmd.arg(remote_command_line(command));
+        cmd
+    }
+
+    /// Check that the host accepts a non-interactive SSH login
+    pub async fn is_reachable(&self) -> bool {
+        Command::new("ssh")
+            .args(self.ssh_options())
+            .args(["-o", "BatchMode=yes", "-o", "ConnectTimeout=5", "-T"])
+            .arg(&self.config.host)
+            .arg("true")
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .await
+            .map(|s| s.success())
+            .unwrap_or(false)
+    }
+
+    /// Execute a command on the remote host
+    pub async fn exec(&self, command: &[&str]) -> MinoResult<std::process::Output> {
+        debug!(
+            "Executing on {}: {:?}",
+            self.config.host,
+            redact_args(command)
+        );
+
+        let mut cmd = self.ssh_command(command, false);
+        cmd.stdout(Stdio::piped()).stderr(Stdio::piped());
+
+        cmd.output().await.map_err(|e| {
+            MinoError::command_failed(
+                format!("ssh {} {:?}", self.config.host, redact_args(command)),
+                e,
+            )
+        })
+    }
+
+    /// Execute a command on the remote host with `input` on stdin
+    pub async fn exec_with_input(
+        &self,
+        command: &[&str],
+        input: &[u8],
+    ) -> MinoResult<std::process::Output> {
+        debug!(
+            "Executing with input on {}: {:?}",
+            self.config.host,
+            redact_args(command)
+        );
+
+        let mut cmd = self.ssh_command(command, false);
+        super::output_with_input(&mut cmd, input)
+            .await
+            .map_err(|e| {
+                MinoError::command_failed(
+                    format!("ssh {} {:?}", self.config.host, redact_args(command)),
+                    e,
+                )
+            })
+    }
+
+    /// Spawn a command on the remote host with piped stdout/stderr.
+    pub fn spawn_piped(&self, command: &[&str]) -> MinoResult<tokio::process::Child> {
+        debug!(
+            "Spawning piped on {}: {:?}",
+            self.config.host,
+            redact_args(command)
+        );
+
+        let mut cmd = self.ssh_command(command, false);
+        cmd.stdout(Stdio::piped()).stderr(Stdio::piped());
+
+        cmd.spawn().map_err(|e| {
+            MinoError::command_failed(
+                format!("ssh {} {:?}", self.config.host, redact_args(command)),
+                e,
+            )
+        })
+    }
+
+    /// Execute a command on the remote host attached to the local terminal
+    pub async fn exec_interactive(&self, command: &[&str], tty: bool) -> MinoResult<i32> {
+        debug!(
+            "Executing interactively on {}: {:?}",
+            self.config.host,
+            redact_args(command)
+        );
+
+        let mut cmd = self.ssh_command(command, tty);
+        cmd.stdin(Stdio::inherit())
+            .stdout(Stdio::inherit())
+            .stderr(Stdio::inherit());
+
+        let status = cmd.status().await.map_err(|e| {
+            MinoError::command_failed(
+                format!("ssh {} {:?}", self.config.host, redact_args(command)),
+                e,
+            )
+        })?;
+
+        Ok(status.code().unwrap_or(-1))
+    }
+
+    /// Mirror a local file or directory to `remote` with rsync.
+    ///
+    /// Directories are synced with `--delete` so the remote copy matches
+    /// the local tree exactly.
+    pub async fn push(&self, local: &Path, remote: &str) -> MinoResult<()> {
+        let parent = Path::new(remote)
+            .parent()
+            .map(|p| p.display().to_string())
+            .unwrap_or_else(|| "/".to_string());
+        let mkdir = self.exec(&["mkdir", "-p", &parent]).await?;
+        if !mkdir.status.success() {
+            let stderr = String::from_utf8_lossy(&mkdir.stderr);
+            return Err(MinoError::command_exec("ssh mkdir", stderr));
+        }
+
+        let is_dir = local.is_dir();
+        let (source, dest) = if is_dir {
+            (format!("{}/", local.display()), format!("{}/", remote))
+        } else {
+            (local.display().to_string(), remote.to_string())
+        };
+        let extra: &[&str] = if is_dir { &["--delete"] } else { &[] };
+        self.rsync(extra, &source, &self.remote_spec(&dest)).await
+    }
+
+    /// Copy a remote directory back over its local counterpart.
+    ///
+    /// Never deletes local files: removals inside the container are not
+    /// propagated, so a stale remote copy cannot wipe local work.
+    pub async fn pull(&self, remote: &str, local: &Path) -> MinoResult<()> {
+        let source = self.remote_spec(&format!("{}/", remote));
+        let dest = format!("{}/", local.display());
+        self.rsync(&[], &source, &dest).await
+    }
+
+    /// Copy a remote file or directory to `local`.
+    ///
+    /// Directories are merged into `local` (which must exist); a file
+    /// replaces `local`.
+    pub async fn fetch(&self, remote: &str, local: &Path, is_dir: bool) -> MinoResult<()> {
+        let (source, dest) = if is_dir {
+            (
+                self.remote_spec(&format!("{}/", remote)),
+                format!("{}/", local.display()),
+            )
+        } else {
+            (self.remote_spec(remote), local.display().to_string())
+        };
+        self.rsync(&[], &source, &dest).await
+    }
+
+    /// `host:path` argument for rsync
+    fn remote_spec(&self, path: &str) -> String {
+        format!("{}:{}", self.config.host, path)
+    }
+
+    async fn rsync(&self, extra: &[&str], source: &str, dest: &str) -> MinoResult<()> {
+        let mut transport = vec!["ssh".to_string()];
+        transport.extend(self.ssh_options());
+
+        debug!("rsync {} -> {}", source, dest);
+
+        let output = Command::new("rsync")
+            .args(["-az", "-e", &transport.join(" ")])
+            .args(extra)
+            .arg(source)
+            .arg(dest)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .output()
+            .await
+            .map_err(|e| MinoError::command_failed("rsync", e))?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(MinoError::command_exec(
+                format!("rsync {source} {dest}"),
+                stderr,
+            ))
+        }
+    }
+}
+
+/// Join arguments into one remote shell command line.
+///
+/// sshd hands the command to the remote user's shell as a single string,
+/// so every argument is single-quoted to survive word splitting.
+fn remote_command_line(command: &[&str]) -> String {
+    command
+        .iter()
+        .map(|arg| format!("'{}'", shell_escape(arg)))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn host(port: Option<u16>, identity: Option<&str>) -> SshHost {
+        SshHost::new(RemoteConfig {
+            host: "dev@build-box".to_string(),
+            port,
+            identity_file: identity.map(String::from),
+            ..RemoteConfig::default()
+        })
+    }
+
+    #[test]
+    fn remote_command_line_quotes_every_arg() {
+        assert_eq!(
+            remote_command_line(&["podman", "run", "-e", "MSG=it's here", "img"]),
+            r#"'podman' 'run' '-e' 'MSG=it'\''s here' 'img'"#
+        );
+    }
+
+    #[test]
+    fn ssh_options_include_port_and_identity() {
+        assert!(host(None, None).ssh_options().is_empty());
+        assert_eq!(
+            host(Some(2222), Some("~/.ssh/box")).ssh_options(),
+            vec!["-p", "2222", "-i", "~/.ssh/box"]
+        );
+    }
+
+    #[test]
+    fn remote_spec_prefixes_host() {
+        assert_eq!(
+            host(None, None).remote_spec("/srv/sync/"),
+            "dev@build-box:/srv/sync/"
+        );
+    }
+}
@@ -0,0 +1,88 @@
+//! Platform-neutral handling of project text files and host paths
+//!
+//! Project files may come from a Windows checkout (CRLF line endings,
+//! `C:\` paths) while everything mino generates runs in a Linux container.
+//! These helpers keep hashes, install scripts, and mount specs the same
+//! whichever way a file was checked out.
+
+use std::borrow::Cow;
+
+/// Convert CRLF and lone CR line endings to LF.
+///
+/// Borrows when the text has no carriage returns, which is the common case.
+pub fn normalize_newlines(text: &str) -> Cow<'_, str> {
+    if !text.contains('\r') {
+        return Cow::Borrowed(text);
+    }
+    Cow::Owned(text.replace("\r\n", "\n").replace('\r', "\n"))
+}
+
+/// [`normalize_newlines`] for file contents that may be binary.
+///
+/// Only valid UTF-8 is treated as text; binary files (e.g. `bun.lockb`)
+/// are returned unchanged so unrelated bytes are never rewritten.
+pub fn normalize_text_bytes(bytes: &[u8]) -> Cow<'_, [u8]> {
+    match std::str::from_utf8(bytes) {
+        Ok(text) => match normalize_newlines(text) {
+            Cow::Borrowed(_) => Cow::Borrowed(bytes),
+            Cow::Owned(text) => Cow::Owned(text.into_bytes()),
+        },
+        Err(_) => Cow::Borrowed(bytes),
+    }
+}
+
+/// Windows drive prefix (`C:`) of an absolute path like `C:\src` or `C:/src`.
+///
+/// Mount specs use `:` as their separator, so the drive colon must not be
+/// mistaken for it.
+pub fn windows_drive_prefix(path: &str) -> Option<&str> {
+    let bytes = path.as_bytes();
+    let is_drive = bytes.len() >= 3
+        && bytes[0].is_ascii_alphabetic()
+        && bytes[1] == b':'
+        && matches!(bytes[2], b'\\' | b'/');
+    is_drive.then(|| &path[..2])
+}
+
+/// Whether `c` separates path components on any supported host
+pub fn is_path_separator(c: char) -> bool {
+    c == '/' || c == '\\'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_newlines_variants() {
+        assert_eq!(normalize_newlines("a\r\nb\r\n"), "a\nb\n");
+        assert_eq!(normalize_newlines("a\rb"), "a\nb");
+        assert_eq!(normalize_newlines("a\r\n\rb"), "a\n\nb");
+        assert!(matches!(normalize_newlines("a\nb\n"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn normalize_text_bytes_leaves_binary_alone() {
+        assert_eq!(&*normalize_text_bytes(b"x = 1\r\n"), b"x = 1\n");
+        assert!(matches!(normalize_text_bytes(b"x = 1\n"), Cow::Borrowed(_)));
+
+        let binary = [0xff, 0xfe, b'\r', b'\n', 0x00];
+        assert_eq!(&*normalize_text_bytes(&binary), &binary);
+    }
+
+    #[test]
+    fn windows_drive_prefix_detection() {
+        assert_eq!(windows_drive_prefix(r"C:\src\app"), Some("C:"));
+        assert_eq!(windows_drive_prefix("d:/data"), Some("d:"));
+        assert_eq!(windows_drive_prefix("C:"), None);
+        assert_eq!(windows_drive_prefix("cache:/cache"), None);
+        assert_eq!(windows_drive_prefix("/abs:/x"), None);
+    }
+
+    #[test]
+    fn path_separators() {
+        assert!(is_path_separator('/'));
+        assert!(is_path_separator('\\'));
+        assert!(!is_path_separator(':'));
+    }
+}
@@ -0,0 +1,308 @@
+//! Redaction of sensitive environment values
+//!
+//! Central place for deciding which env keys carry secrets and for masking
+//! their values before they reach tracing output, error messages, or audit
+//! entries. Built-in keys and patterns can be extended with
+//! `general.redact_patterns` in config.
+
+use std::sync::OnceLock;
+
+/// Replacement for redacted values.
+const MASK: &str = "***";
+
+/// Env var keys whose values must never appear in logs.
+const SENSITIVE_ENV_KEYS: &[&str] = &[
+    "AWS_SECRET_ACCESS_KEY",
+    "AWS_SESSION_TOKEN",
+    "AWS_ACCESS_KEY_ID",
+    "GITHUB_TOKEN",
+    "GH_TOKEN",
+    "CLOUDSDK_AUTH_ACCESS_TOKEN",
+    "AZURE_ACCESS_TOKEN",
+    "GITLAB_TOKEN",
+    "BITBUCKET_TOKEN",
+];
+
+/// Glob patterns (`*` wildcard) that catch common secret-bearing key names.
+const DEFAULT_PATTERNS: &[&str] = &[
+    "*_TOKEN",
+    "*_SECRET",
+    "*_SECRET_*",
+    "*PASSWORD*",
+    "*_API_KEY",
+    "*_PRIVATE_KEY",
+];
+
+/// User-configured patterns, installed once at startup.
+static EXTRA_PATTERNS: OnceLock<Vec<String>> = OnceLock::new();
+
+/// Install additional redaction patterns from config.
+///
+/// Only the first call takes effect; later calls are ignored.
+pub fn init(patterns: &[String]) {
+    let _ = EXTRA_PATTERNS.set(patterns.iter().map(|p| p.to_ascii_uppercase()).collect());
+}
+
+/// Whether values for `key` must be masked.
+pub fn is_sensitive_key(key: &str) -> bool {
+    let key = key.to_ascii_uppercase();
+    if SENSITIVE_ENV_KEYS.contains(&key.as_str()) {
+        return true;
+    }
+    if DEFAULT_PATTERNS.iter().any(|p| glob_match(p, &key)) {
+        return true;
+    }
+    EXTRA_PATTERNS
+        .get()
+        .is_some_and(|patterns| patterns.iter().any(|p| glob_match(p, &key)))
+}
+
+/// Return a copy of `args` with sensitive `KEY=VALUE` values replaced by `***`.
+///
+/// Generic over element type so it works with both `&[String]` and `&[&str]`.
+pub fn redact_args<S: AsRef<str>>(args: &[S]) -> Vec<String> {
+    args.iter()
+        .map(|arg| redact_assignment(arg.as_ref()))
+        .collect()
+}
+
+/// Mask sensitive `KEY=VALUE` occurrences inside free-form text.
+///
+/// Handles both plain command lines and `{:?}`-formatted argument lists.
+pub fn redact_text(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(eq) = rest.find('=') {
+        let (before, after) = rest.split_at(eq);
+        // Step past the delimiter by its UTF-8 width: it may be `‘` or `“`
+        let key_start = before
+            .char_indices()
+            .rev()
+            .find(|&(_, c)| !(c.is_ascii_alphanumeric() || c == '_'))
+            .map_or(0, |(i, c)| i + c.len_utf8());
+        let key = &before[key_start..];
+        let value_len = after[1..]
+            .find(|c: char| c.is_whitespace() || matches!(c, '"' | '\'' | ',' | ']' | '’' | '”'))
+            .unwrap_or(after.len() - 1);
+
+        out.push_str(before);
+        out.push('=');
+        if !key.is_empty() && value_len > 0 && is_sensitive_key(key) {
+            out.push_str(MASK);
+            rest = &after[1 + value_len..];
+        } else {
+            // Keep scanning the value: it may hold a nested assignment
+            rest = &after[1..];
+        }
+    }
+
+    out.push_str(rest);
+    out
+}
+
+/// Recursively mask sensitive values in a JSON payload.
+///
+/// Object members with sensitive keys are replaced wholesale; string
+/// values are scanned for embedded `KEY=VALUE` assignments.
+pub fn redact_json(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, v) in map.iter_mut() {
+                if is_sensitive_key(key) {
+                    *v = serde_json::Value::String(MASK.to_string());
+                } else {
+                    redact_json(v);
+                }
+            }
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(redact_json),
+        serde_json::Value::String(s) if s.contains('=') => *s = redact_text(s),
+        _ => {}
+    }
+}
+
+fn redact_assignment(arg: &str) -> String {
+    match arg.split_once('=') {
+        Some((key, _)) if is_sensitive_key(key.trim_start_matches('-')) => {
+            format!("{key}={MASK}")
+        }
+        // `--env=KEY=VALUE` style: the value is itself an assignment
+        Some((flag, value)) if value.contains('=') => {
+            format!("{flag}={}", redact_assignment(value))
+        }
+        _ => arg.to_owned(),
+    }
+}
+
+/// Match `text` against a pattern where `*` matches any run of characters.
+pub(crate) fn glob_match(pattern: &str, text: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = text.strip_prefix(first) else {
+        return false;
+    };
+    let parts: Vec<&str> = parts.collect();
+    let Some((last, middle)) = parts.split_last() else {
+        return rest.is_empty();
+    };
+    for part in middle {
+        match rest.find(part) {
+            Some(i) => rest = &rest[i + part.len()..],
+            None => return false,
+        }
+    }
+    rest.len() >= last.len() && rest.ends_with(last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redact_args_masks_sensitive_keys() {
+        let args: Vec<String> = vec![
+            "run",
+            "-d",
+            "-e",
+            "AWS_SECRET_ACCESS_KEY=hunter2",
+            "-e",
+            "GITHUB_TOKEN=ghp_abc123",
+            "-e",
+            "PATH=/usr/bin",
+            "fedora:43",
+        ]
+        .into_iter()
+        .map(String::from)
+        .collect();
+
+        let redacted = redact_args(&args);
+        assert_eq!(redacted[3], "AWS_SECRET_ACCESS_KEY=***");
+        assert_eq!(redacted[5], "GITHUB_TOKEN=***");
+        assert_eq!(redacted[7], "PATH=/usr/bin");
+    }
+
+    #[test]
+    fn redact_args_preserves_non_sensitive() {
+        let args: Vec<String> = vec![
+            "run",
+            "-e",
+            "HOME=/home/dev",
+            "-e",
+            "LANG=en_US.UTF-8",
+            "-w",
+            "/workspace",
+        ]
+        .into_iter()
+        .map(String::from)
+        .collect();
+
+        let redacted = redact_args(&args);
+        assert_eq!(redacted, args);
+    }
+
+    #[test]
+    fn redact_args_handles_no_env() {
+        let args: Vec<String> = vec!["run", "-d", "-w", "/workspace", "fedora:43"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+
+        let redacted = redact_args(&args);
+        assert_eq!(redacted, args);
+    }
+
+    #[test]
+    fn redact_args_works_with_str_slices() {
+        let args: &[&str] = &[
+            "run",
+            "-d",
+            "-e",
+            "AWS_SESSION_TOKEN=secret123",
+            "-e",
+            "HOME=/home/dev",
+        ];
+
+        let redacted = redact_args(args);
+        assert_eq!(redacted[3], "AWS_SESSION_TOKEN=***");
+        assert_eq!(redacted[5], "HOME=/home/dev");
+    }
+
+    #[test]
+    fn redact_args_trailing_dash_e_no_panic() {
+        let args: Vec<String> = vec!["run", "-e"].into_iter().map(String::from).collect();
+
+        let redacted = redact_args(&args);
+        assert_eq!(redacted, args);
+    }
+
+    #[test]
+    fn redact_args_masks_env_prefix_and_long_flag() {
+        let args = ["env", "NPM_TOKEN=abc", "--env=OPENAI_API_KEY=sk-1", "npm"];
+        let redacted = redact_args(&args);
+        assert_eq!(redacted[1], "NPM_TOKEN=***");
+        assert_eq!(redacted[2], "--env=OPENAI_API_KEY=***");
+    }
+
+    #[test]
+    fn default_patterns_match_common_secrets() {
+        assert!(is_sensitive_key("NPM_TOKEN"));
+        assert!(is_sensitive_key("openai_api_key"));
+        assert!(is_sensitive_key("DB_PASSWORD"));
+        assert!(is_sensitive_key("STRIPE_SECRET_KEY"));
+        assert!(!is_sensitive_key("PATH"));
+        assert!(!is_sensitive_key("SSH_AUTH_SOCK"));
+        assert!(!is_sensitive_key("TOKENIZER_PARALLELISM"));
+    }
+
+    #[test]
+    fn glob_match_wildcards() {
+        assert!(glob_match("*_TOKEN", "NPM_TOKEN"));
+        assert!(!glob_match("*_TOKEN", "NPM_TOKENS"));
+        assert!(glob_match("MY_*", "MY_VAR"));
+        assert!(glob_match("A*B*C", "AXXBYYC"));
+        assert!(glob_match("EXACT", "EXACT"));
+        assert!(!glob_match("EXACT", "EXACTLY"));
+        assert!(!glob_match("AB*BA", "ABA"));
+    }
+
+    #[test]
+    fn redact_text_masks_debug_formatted_args() {
+        let text = format!("{:?}", ["-e", "GH_TOKEN=ghp_1", "-e", "TERM=xterm"]);
+        assert_eq!(
+            redact_text(&text),
+            r#"["-e", "GH_TOKEN=***", "-e", "TERM=xterm"]"#
+        );
+    }
+
+    #[test]
+    fn redact_text_masks_plain_command_line() {
+        assert_eq!(
+            redact_text("env AWS_SESSION_TOKEN=abc FOO=bar cmd --env=DB_PASSWORD=x"),
+            "env AWS_SESSION_TOKEN=*** FOO=bar cmd --env=DB_PASSWORD=***"
+        );
+    }
+
+    #[test]
+    fn redact_text_handles_non_ascii_quotes() {
+        assert_eq!(redact_text("env: ‘GH_TOKEN=abc’"), "env: ‘GH_TOKEN=***’");
+        assert_eq!(
+            redact_text("“NPM_TOKEN=x” and é=1"),
+            "“NPM_TOKEN=***” and é=1"
+        );
+    }
+
+    #[test]
+    fn redact_json_masks_keys_and_embedded_assignments() {
+        let mut value = serde_json::json!({
+            "name": "s1",
+            "env": { "GITHUB_TOKEN": "ghp_1", "HOME": "/home/dev" },
+            "command": ["env", "NPM_TOKEN=abc", "npm", "publish"],
+        });
+        redact_json(&mut value);
+        assert_eq!(value["env"]["GITHUB_TOKEN"], "***");
+        assert_eq!(value["env"]["HOME"], "/home/dev");
+        assert_eq!(value["command"][1], "NPM_TOKEN=***");
+        assert_eq!(value["name"], "s1");
+    }
+}
@@ -24,6 +24,7 @@ pub(crate) const SENSITIVE_PATHS: &[&str] = &[
     ".aws",
     ".azure",
     ".config/gh",
+    ".config/glab-cli",
     ".gnupg",
     ".config/gcloud",
     ".kube",
@@ -129,7 +130,8 @@ pub struct SandboxConfig {
     ///
     /// Set to an explicit list to override (use `[]` to disable all passthrough).
     /// Add other AI provider keys here (e.g., `"OPENAI_API_KEY"`, `"GROQ_API_KEY"`)
-    /// without requiring a code change.
+    /// without requiring a code change. Entries may use `*` wildcards; see
+    /// [`crate::env_passthrough`] for how secret-looking names are handled.
     pub env_passthrough: Option<Vec<String>>,
 }
 
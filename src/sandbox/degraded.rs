@@ -0,0 +1,443 @@
+//! Degraded native sandbox for hosts without the full native setup
+//!
+//! Used with `--allow-degraded` when the primary platform's prerequisites
+//! are missing (user namespaces disabled on Linux, `mino setup --native`
+//! not run on macOS). Runs the command with whatever isolation the host still offers:
+//! `bwrap` (bubblewrap) on Linux, `sandbox-exec` on macOS. Credentials,
+//! env injection, and audit logging go through the same run pipeline; only
+//! the isolation is weaker (on macOS the process runs as your user).
+
+use async_trait::async_trait;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use tokio::process::Command;
+
+use crate::error::{MinoError, MinoResult};
+use crate::network::NetworkMode;
+use crate::sandbox::config::SENSITIVE_PATHS;
+use crate::sandbox::native::{SandboxPlatform, SandboxSpawnConfig};
+use crate::sandbox::process::SandboxProcess;
+use crate::sandbox::resource_limits::ResourceLimits;
+
+/// Bubblewrap binary (Linux)
+const BWRAP: &str = "bwrap";
+
+/// sandbox-exec binary (macOS)
+const SANDBOX_EXEC: &str = "/usr/bin/sandbox-exec";
+
+/// Home directory inside a bubblewrap sandbox, as in the namespace sandbox
+const BWRAP_HOME: &str = "/home/agent";
+
+/// System paths bound read-only into a bubblewrap sandbox
+const BWRAP_SYSTEM_PATHS: &[&str] = &["/usr", "/lib", "/lib64", "/bin", "/sbin"];
+
+/// /etc entries bound read-only into a bubblewrap sandbox
+const BWRAP_ETC_ENTRIES: &[&str] = &["resolv.conf", "ssl", "hosts", "localtime"];
+
+/// Native sandbox with best-effort isolation
+pub struct DegradedSandbox;
+
+impl DegradedSandbox {
+    /// Isolation mechanism this host would use
+    pub fn mechanism() -> &'static str {
+        if cfg!(target_os = "macos") {
+            "sandbox-exec"
+        } else {
+            "bubblewrap"
+        }
+    }
+
+    /// Reject network modes this host's mechanism can't enforce.
+    ///
+    /// bubblewrap can only cut the network entirely; an allowlist would
+    /// depend on the process honouring the proxy env vars.
+    pub fn check_network(network_mode: &NetworkMode) -> MinoResult<()> {
+        if cfg!(not(target_os = "macos")) && matches!(network_mode, NetworkMode::Allow(_)) {
+            return Err(MinoError::NativeUnsupported {
+                feature: format!(
+                    "Network allowlist under {} (use --network none or bridge, or run mino setup --native)",
+                    Self::mechanism()
+                ),
+            });
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl SandboxPlatform for DegradedSandbox {
+    fn isolation(&self) -> &'static str {
+        Self::mechanism()
+    }
+
+    async fn validate_setup(&self) -> MinoResult<()> {
+        if cfg!(target_os = "macos") {
+            if Path::new(SANDBOX_EXEC).exists() {
+                return Ok(());
+            }
+            return Err(MinoError::CliNotFound {
+                name: "sandbox-exec".to_string(),
+                hint: "sandbox-exec ships with macOS at /usr/bin/sandbox-exec".to_string(),
+            });
+        }
+        let found = Command::new("which")
+            .arg(BWRAP)
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .await
+            .map(|s| s.success())
+            .unwrap_or(false);
+        if found {
+            Ok(())
+        } else {
+            Err(MinoError::CliNotFound {
+                name: BWRAP.to_string(),
+                hint: "Install bubblewrap: sudo apt install bubblewrap (Debian/Ubuntu) \
+                       or sudo dnf install bubblewrap (Fedora)"
+                    .to_string(),
+            })
+        }
+    }
+
+    async fn spawn(&self, config: SandboxSpawnConfig) -> MinoResult<SandboxProcess> {
+        let limits = ResourceLimits::from_config(&config.sandbox_config);
+        let mut cmd = if cfg!(target_os = "macos") {
+            let home = dirs::home_dir()
+                .ok_or_else(|| MinoError::User("Cannot determine home directory".to_string()))?;
+            let mut cmd = Command::new(SANDBOX_EXEC);
+            cmd.arg("-p")
+                .arg(sandbox_exec_profile(&config, &home)?)
+                .args(limited_command(&config.command, &limits));
+            cmd.current_dir(&config.project_dir);
+            cmd
+        } else {
+            let entries = dotfile_entries(config.dotfile_dir.as_deref()).await;
+            let mut cmd = Command::new(BWRAP);
+            cmd.args(bwrap_args(&config, &entries)?)
+                .arg("--")
+                .args(limited_command(&config.command, &limits));
+            cmd
+        };
+
+        cmd.env_clear();
+        cmd.envs(&config.env);
+        if cfg!(not(target_os = "macos")) {
+            cmd.env("HOME", BWRAP_HOME);
+        }
+        if config.interactive {
+            cmd.stdin(Stdio::inherit());
+            cmd.stdout(Stdio::inherit());
+            cmd.stderr(Stdio::inherit());
+        }
+
+        let child = cmd.spawn().map_err(|e| {
+            MinoError::command_failed(
+                format!("{} (degraded native sandbox)", Self::mechanism()),
+                e,
+            )
+        })?;
+        Ok(SandboxProcess::new(child, config.session_id))
+    }
+
+    async fn exec(
+        &self,
+        _pid: u32,
+        _session_name: &str,
+        _sandbox_user: &str,
+        _command: &[String],
+    ) -> MinoResult<i32> {
+        Err(MinoError::NativeUnsupported {
+            feature: format!("mino exec into a degraded ({}) sandbox", Self::mechanism()),
+        })
+    }
+
+    async fn cleanup(
+        &self,
+        _session_id: &str,
+        _project_dir: &Path,
+        _sandbox_user: &str,
+    ) -> MinoResult<()> {
+        // Nothing outlives the process: no ACLs, firewall rules, or users
+        Ok(())
+    }
+}
+
+/// Dotfile names prepared for the sandbox HOME
+async fn dotfile_entries(dir: Option<&Path>) -> Vec<PathBuf> {
+    let Some(dir) = dir else {
+        return Vec::new();
+    };
+    let mut entries = Vec::new();
+    if let Ok(mut read) = tokio::fs::read_dir(dir).await {
+        while let Ok(Some(entry)) = read.next_entry().await {
+            entries.push(entry.path());
+        }
+    }
+    entries.sort();
+    entries
+}
+
+/// `sh -c` wrapper applying resource limits before exec'ing the command.
+/// Limits the host's shell rejects are skipped rather than failing the run.
+fn limited_command(command: &[String], limits: &ResourceLimits) -> Vec<String> {
+    let mut script = String::new();
+    let ulimits = [
+        ("-v", limits.max_memory_bytes / 1024),
+        ("-u", u64::from(limits.max_processes)),
+        ("-t", limits.max_cpu_seconds),
+        ("-f", limits.max_file_size_bytes / 512),
+    ];
+    for (flag, value) in ulimits {
+        if value > 0 {
+            script.push_str(&format!("ulimit {} {} 2>/dev/null || :; ", flag, value));
+        }
+    }
+    script.push_str("exec \"$@\"");
+
+    let mut args = vec![
+        "/bin/sh".to_string(),
+        "-c".to_string(),
+        script,
+        "sh".to_string(),
+    ];
+    args.extend(command.iter().cloned());
+    args
+}
+
+/// bubblewrap arguments mirroring the namespace sandbox's layout: system
+/// paths read-only, the project at /workspace, a fresh HOME with the
+/// prepared dotfiles, private /tmp, /proc, and /dev. `--new-session`
+/// detaches the controlling terminal so the sandbox can't push input into
+/// it with TIOCSTI (CVE-2017-5226).
+fn bwrap_args(config: &SandboxSpawnConfig, dotfiles: &[PathBuf]) -> MinoResult<Vec<String>> {
+    DegradedSandbox::check_network(&config.network_mode)?;
+    let mut args: Vec<String> = [
+        "--die-with-parent",
+        "--new-session",
+        "--unshare-pid",
+        "--unshare-ipc",
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect();
+    if matches!(config.network_mode, NetworkMode::None) {
+        args.push("--unshare-net".to_string());
+    }
+
+    let mut bind = |flag: &str, source: &str, target: &str| {
+        args.extend([flag.to_string(), source.to_string(), target.to_string()]);
+    };
+    for path in BWRAP_SYSTEM_PATHS {
+        bind("--ro-bind-try", path, path);
+    }
+    for entry in BWRAP_ETC_ENTRIES {
+        let path = format!("/etc/{}", entry);
+        bind("--ro-bind-try", &path, &path);
+    }
+    bind("--bind", path_str(&config.project_dir)?, "/workspace");
+    for path in &config.sandbox_config.passthrough_paths {
+        bind("--ro-bind-try", path, path);
+    }
+    for path in &config.sandbox_config.writable_paths {
+        bind("--bind-try", path, path);
+    }
+
+    args.extend(
+        [
+            "--dev", "/dev", "--proc", "/proc", "--tmpfs", "/tmp", "--dir", BWRAP_HOME,
+        ]
+        .iter()
+        .map(|s| s.to_string()),
+    );
+    for dotfile in dotfiles {
+        let Some(name) = dotfile.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+        args.extend([
+            "--ro-bind".to_string(),
+            path_str(dotfile)?.to_string(),
+            format!("{}/{}", BWRAP_HOME, name),
+        ]);
+    }
+    args.extend(["--chdir".to_string(), "/workspace".to_string()]);
+    Ok(args)
+}
+
+/// Seatbelt profile for `sandbox-exec`: writes only to the project, temp
+/// dirs, and configured writable paths; credential stores unreadable; the
+/// network cut or limited to the local filtering proxy per the network mode.
+fn sandbox_exec_profile(config: &SandboxSpawnConfig, home: &Path) -> MinoResult<String> {
+    let mut profile = String::from("(version 1)\n(allow default)\n");
+
+    profile.push_str("(deny file-write*)\n(allow file-write*\n");
+    profile.push_str(&format!(
+        "    (subpath {})\n",
+        sbpl_string(path_str(&config.project_dir)?)
+    ));
+    for path in ["/private/tmp", "/private/var/folders", "/dev"] {
+        profile.push_str(&format!("    (subpath {})\n", sbpl_string(path)));
+    }
+    for path in &config.sandbox_config.writable_paths {
+        profile.push_str(&format!("    (subpath {})\n", sbpl_string(path)));
+    }
+    profile.push_str(")\n");
+
+    profile.push_str("(deny file-read* file-write*\n");
+    for sensitive in SENSITIVE_PATHS {
+        let allowed = config
+            .sandbox_config
+            .allow_sensitive_paths
+            .iter()
+            .any(|p| p == sensitive);
+        if allowed && config.sandbox_config.allow_sensitive {
+            continue;
+        }
+        let path = home.join(sensitive);
+        profile.push_str(&format!(
+            "    (subpath {})\n",
+            sbpl_string(path_str(&path)?)
+        ));
+    }
+    profile.push_str(")\n");
+
+    match &config.network_mode {
+        NetworkMode::None => profile.push_str("(deny network*)\n"),
+        // The filtering proxy listens on localhost; everything else is denied
+        NetworkMode::Allow(_) => profile.push_str(
+            "(deny network-outbound)\n(allow network-outbound (remote ip \"localhost:*\"))\n",
+        ),
+        _ => {}
+    }
+    Ok(profile)
+}
+
+/// Quote a string for a Seatbelt (SBPL) profile
+fn sbpl_string(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn path_str(path: &Path) -> MinoResult<&str> {
+    path.to_str().ok_or_else(|| MinoError::PathInvalid {
+        path: path.to_path_buf(),
+        reason: "non-UTF8 path".to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::NetworkRule;
+    use crate::sandbox::config::SandboxConfig;
+    use std::collections::HashMap;
+
+    fn spawn_config(network_mode: NetworkMode) -> SandboxSpawnConfig {
+        SandboxSpawnConfig {
+            session_id: "swift-fox".to_string(),
+            project_dir: PathBuf::from("/home/user/project"),
+            command: vec!["echo".to_string(), "hello world".to_string()],
+            env: HashMap::new(),
+            network_mode,
+            sandbox_config: SandboxConfig::default(),
+            dotfile_dir: None,
+            interactive: true,
+        }
+    }
+
+    fn has_pair(args: &[String], flag: &str, value: &str) -> bool {
+        args.windows(2).any(|w| w[0] == flag && w[1] == value)
+    }
+
+    #[test]
+    fn bwrap_mounts_project_at_workspace() {
+        let args = bwrap_args(&spawn_config(NetworkMode::Bridge), &[]).unwrap();
+        assert!(args
+            .windows(3)
+            .any(|w| w[0] == "--bind" && w[1] == "/home/user/project" && w[2] == "/workspace"));
+        assert!(has_pair(&args, "--chdir", "/workspace"));
+        assert!(has_pair(&args, "--tmpfs", "/tmp"));
+        assert!(!args.contains(&"--unshare-net".to_string()));
+        assert!(args.contains(&"--new-session".to_string()));
+    }
+
+    #[test]
+    fn bwrap_cuts_network_for_none() {
+        let args = bwrap_args(&spawn_config(NetworkMode::None), &[]).unwrap();
+        assert!(args.contains(&"--unshare-net".to_string()));
+    }
+
+    #[test]
+    #[cfg(not(target_os = "macos"))]
+    fn bwrap_refuses_network_allowlist() {
+        let rules = vec![NetworkRule {
+            host: "github.com".to_string(),
+            port: 443,
+        }];
+        let err = bwrap_args(&spawn_config(NetworkMode::Allow(rules)), &[]).unwrap_err();
+        assert!(matches!(err, MinoError::NativeUnsupported { .. }));
+    }
+
+    #[test]
+    fn bwrap_binds_dotfiles_into_home() {
+        let dotfiles = vec![PathBuf::from("/tmp/dots/.gitconfig")];
+        let args = bwrap_args(&spawn_config(NetworkMode::Bridge), &dotfiles).unwrap();
+        assert!(has_pair(
+            &args,
+            "/tmp/dots/.gitconfig",
+            "/home/agent/.gitconfig"
+        ));
+    }
+
+    #[test]
+    fn limited_command_applies_ulimits_then_execs() {
+        let limits = ResourceLimits {
+            max_memory_bytes: 1024 * 1024,
+            max_processes: 0,
+            max_cpu_seconds: 0,
+            max_file_size_bytes: 0,
+        };
+        let args = limited_command(&["echo".to_string(), "a b".to_string()], &limits);
+        assert_eq!(args[..2], ["/bin/sh", "-c"]);
+        assert_eq!(args[2], "ulimit -v 1024 2>/dev/null || :; exec \"$@\"");
+        assert_eq!(args[3..], ["sh", "echo", "a b"]);
+    }
+
+    #[test]
+    fn profile_limits_writes_and_hides_credentials() {
+        let profile =
+            sandbox_exec_profile(&spawn_config(NetworkMode::Bridge), Path::new("/Users/me"))
+                .unwrap();
+        assert!(profile.contains("(deny file-write*)"));
+        assert!(profile.contains("(subpath \"/home/user/project\")"));
+        assert!(profile.contains("(subpath \"/Users/me/.ssh\")"));
+        assert!(!profile.contains("network"));
+    }
+
+    #[test]
+    fn profile_network_follows_mode() {
+        let home = Path::new("/Users/me");
+        let none = sandbox_exec_profile(&spawn_config(NetworkMode::None), home).unwrap();
+        assert!(none.contains("(deny network*)"));
+
+        let rules = vec![NetworkRule {
+            host: "github.com".to_string(),
+            port: 443,
+        }];
+        let allow = sandbox_exec_profile(&spawn_config(NetworkMode::Allow(rules)), home).unwrap();
+        assert!(allow.contains("(allow network-outbound (remote ip \"localhost:*\"))"));
+    }
+
+    #[test]
+    fn sbpl_strings_are_escaped() {
+        assert_eq!(sbpl_string(r#"/a "b"\c"#), r#""/a \"b\"\\c""#);
+    }
+
+    #[tokio::test]
+    async fn exec_is_unsupported() {
+        let err = DegradedSandbox
+            .exec(1, "swift-fox", "agent", &["true".to_string()])
+            .await
+            .unwrap_err();
+        assert!(matches!(err, MinoError::NativeUnsupported { .. }));
+    }
+}
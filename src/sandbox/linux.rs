@@ -22,6 +22,10 @@ pub struct LinuxSandbox;
 
 #[async_trait]
 impl SandboxPlatform for LinuxSandbox {
+    fn isolation(&self) -> &'static str {
+        "namespaces"
+    }
+
     async fn validate_setup(&self) -> MinoResult<()> {
         validate_linux_setup().await
     }
@@ -21,6 +21,10 @@ pub struct MacosSandbox;
 
 #[async_trait]
 impl SandboxPlatform for MacosSandbox {
+    fn isolation(&self) -> &'static str {
+        "sandbox-user"
+    }
+
     async fn validate_setup(&self) -> MinoResult<()> {
         validate_macos_setup().await
     }
@@ -2,8 +2,10 @@
 //!
 //! Provides kernel-enforced process isolation without containers or VMs.
 //! Linux: User namespaces (unshare). macOS: Dedicated user + pf packet filter.
+//! Hosts without those fall back to bubblewrap or sandbox-exec.
 
 pub mod config;
+pub mod degraded;
 pub mod detection;
 pub mod dotfiles;
 pub mod fs_copy;
@@ -4,17 +4,19 @@
 //! the correct platform implementation based on the target OS.
 //! Linux uses user namespaces via `unshare` + `pivot_root`.
 //! macOS uses a dedicated system user + pf packet filter via a privileged helper.
+//! When those prerequisites are missing and the user opted in,
+//! [`select_sandbox_platform`] falls back to the weaker [`DegradedSandbox`].
 
 use async_trait::async_trait;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
-#[cfg(not(any(target_os = "linux", target_os = "macos")))]
-use crate::error::MinoError;
-use crate::error::MinoResult;
+use crate::error::{MinoError, MinoResult};
 use crate::network::NetworkMode;
 use crate::sandbox::config::SandboxConfig;
+use crate::sandbox::degraded::DegradedSandbox;
 use crate::sandbox::process::SandboxProcess;
+use crate::session::Session;
 
 /// Configuration for spawning a native sandbox
 pub struct SandboxSpawnConfig {
@@ -49,6 +51,9 @@ pub struct SandboxSpawnConfig {
 /// and cleanup operations using its native isolation mechanisms.
 #[async_trait]
 pub trait SandboxPlatform: Send + Sync {
+    /// Isolation mechanism, for the audit log and status output
+    fn isolation(&self) -> &'static str;
+
     /// Check if native sandbox prerequisites are met for this platform.
     async fn validate_setup(&self) -> MinoResult<()>;
 
@@ -91,6 +96,41 @@ pub fn create_sandbox_platform() -> MinoResult<Box<dyn SandboxPlatform>> {
     }
 }
 
+/// Pick the platform for a new native session: the full platform when its
+/// prerequisites are met, else the degraded sandbox if `allow_degraded` is
+/// set and the host has it.
+///
+/// Returns the platform and, when degraded, why the full platform was not
+/// usable. Fails with the full platform's error when neither works, and
+/// points at `--allow-degraded` when only the opt-in is missing.
+pub async fn select_sandbox_platform(
+    allow_degraded: bool,
+) -> MinoResult<(Box<dyn SandboxPlatform>, Option<String>)> {
+    let primary = create_sandbox_platform()?;
+    let reason = match primary.validate_setup().await {
+        Ok(()) => return Ok((primary, None)),
+        Err(e) => e,
+    };
+    match DegradedSandbox.validate_setup().await {
+        Ok(()) if allow_degraded => Ok((Box::new(DegradedSandbox), Some(reason.to_string()))),
+        Ok(()) => Err(MinoError::User(format!(
+            "{}. Pass --allow-degraded to run under {} with weaker isolation instead",
+            reason,
+            DegradedSandbox::mechanism()
+        ))),
+        Err(_) => Err(reason),
+    }
+}
+
+/// Platform an existing native session was started on
+pub fn sandbox_platform_for(session: &Session) -> MinoResult<Box<dyn SandboxPlatform>> {
+    if session.degraded_sandbox {
+        Ok(Box::new(DegradedSandbox))
+    } else {
+        create_sandbox_platform()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
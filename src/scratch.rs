@@ -0,0 +1,107 @@
+//! Per-session scratch volume management
+//!
+//! Provides a named volume per session mounted at `/scratch` for build
+//! artifacts and agent temp state. Scratch data lives only in the runtime's
+//! volume store, never on the host project, and persists until pruned.
+
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+
+/// Mount point for scratch volumes inside the container
+pub const SCRATCH_MOUNT: &str = "/scratch";
+
+/// Volume name prefix shared by all scratch volumes
+pub const SCRATCH_PREFIX: &str = "mino-scratch-";
+
+/// Volume label keys for scratch volume metadata
+pub mod labels {
+    /// Marks volume as a mino scratch volume
+    pub const MINO_SCRATCH: &str = "io.mino.scratch";
+    /// Session name the volume belongs to
+    pub const SESSION: &str = "io.mino.scratch.session";
+    /// Creation timestamp (RFC3339)
+    pub const CREATED_AT: &str = "io.mino.scratch.created_at";
+}
+
+/// Information about a scratch volume
+#[derive(Debug, Clone)]
+pub struct ScratchVolume {
+    /// Volume name (mino-scratch-{session})
+    pub name: String,
+    /// Session name this volume belongs to
+    pub session: String,
+    /// When the volume was created
+    pub created_at: DateTime<Utc>,
+}
+
+impl ScratchVolume {
+    /// Try to parse a ScratchVolume from volume labels.
+    pub fn from_labels(name: &str, volume_labels: &HashMap<String, String>) -> Option<Self> {
+        if volume_labels.get(labels::MINO_SCRATCH) != Some(&"true".to_string()) {
+            return None;
+        }
+
+        let session = volume_labels.get(labels::SESSION)?.clone();
+
+        let created_at = volume_labels
+            .get(labels::CREATED_AT)
+            .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+            .map(|dt| dt.with_timezone(&Utc))
+            .unwrap_or_else(Utc::now);
+
+        Some(Self {
+            name: name.to_string(),
+            session,
+            created_at,
+        })
+    }
+
+    /// Generate labels for volume creation.
+    pub fn labels(session_name: &str) -> HashMap<String, String> {
+        let mut map = crate::labels::resource_labels(Some(session_name), None);
+        map.insert(labels::MINO_SCRATCH.to_string(), "true".to_string());
+        map.insert(labels::SESSION.to_string(), session_name.to_string());
+        map.insert(labels::CREATED_AT.to_string(), Utc::now().to_rfc3339());
+        map
+    }
+}
+
+/// Compute the scratch volume name for a session.
+pub fn scratch_volume_name(session_name: &str) -> String {
+    format!("{SCRATCH_PREFIX}{session_name}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scratch_volume_name_format() {
+        assert_eq!(
+            scratch_volume_name("session-ab12"),
+            "mino-scratch-session-ab12"
+        );
+    }
+
+    #[test]
+    fn from_labels_roundtrip() {
+        let labels = ScratchVolume::labels("my-session");
+        let vol = ScratchVolume::from_labels("mino-scratch-my-session", &labels).unwrap();
+        assert_eq!(vol.name, "mino-scratch-my-session");
+        assert_eq!(vol.session, "my-session");
+    }
+
+    #[test]
+    fn from_labels_rejects_non_scratch() {
+        let mut labels = HashMap::new();
+        labels.insert(labels::SESSION.to_string(), "my-session".to_string());
+        assert!(ScratchVolume::from_labels("mino-scratch-my-session", &labels).is_none());
+    }
+
+    #[test]
+    fn from_labels_missing_session() {
+        let mut labels = HashMap::new();
+        labels.insert(labels::MINO_SCRATCH.to_string(), "true".to_string());
+        assert!(ScratchVolume::from_labels("mino-scratch-x", &labels).is_none());
+    }
+}
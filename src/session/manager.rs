@@ -2,8 +2,11 @@
 
 use crate::config::ConfigManager;
 use crate::error::{MinoError, MinoResult};
+use crate::orchestration::ContainerRuntime;
 use crate::session::state::{Session, SessionStatus};
-use chrono::{Duration, Utc};
+use chrono::{DateTime, Duration, Utc};
+use std::collections::BTreeMap;
+use std::path::Path;
 use tracing::{debug, warn};
 
 /// Session manager handles session CRUD and cleanup
@@ -64,6 +67,41 @@ impl SessionManager {
         Ok(())
     }
 
+    /// Record the checkpoint archive for a session
+    pub async fn set_checkpoint(&self, name: &str, archive: &Path) -> MinoResult<()> {
+        let mut session = self
+            .get(name)
+            .await?
+            .ok_or_else(|| MinoError::SessionNotFound(name.to_string()))?;
+
+        session.checkpoint = Some(archive.to_path_buf());
+        session.updated_at = Utc::now();
+        session.save().await?;
+
+        debug!("Set checkpoint for session {}: {}", name, archive.display());
+        Ok(())
+    }
+
+    /// Record when a session's credentials expire, replacing the entries
+    /// of the given providers
+    pub async fn set_credential_expiry(
+        &self,
+        name: &str,
+        expiry: &BTreeMap<String, DateTime<Utc>>,
+    ) -> MinoResult<()> {
+        let mut session = self
+            .get(name)
+            .await?
+            .ok_or_else(|| MinoError::SessionNotFound(name.to_string()))?;
+
+        session.credential_expiry.extend(expiry.clone());
+        session.updated_at = Utc::now();
+        session.save().await?;
+
+        debug!("Set credential expiry for session {}: {:?}", name, expiry);
+        Ok(())
+    }
+
     /// Delete a session
     pub async fn delete(&self, name: &str) -> MinoResult<()> {
         let session = self
@@ -84,16 +122,31 @@ impl SessionManager {
             .find(|s| s.container_id.as_deref() == Some(container_id)))
     }
 
+    /// Stop and remove a session's `[services]` by removing its pod, which
+    /// takes every container in it along. No-op for sessions without a pod.
+    ///
+    /// Best-effort: failures are logged, since the pod may already be gone.
+    pub async fn remove_services(session: &Session, runtime: &dyn ContainerRuntime) {
+        let Some(pod) = &session.pod else {
+            return;
+        };
+        match runtime.pod_remove(pod).await {
+            Ok(()) => debug!("Removed pod {} of session {}", pod, session.name),
+            Err(e) => warn!("Failed to remove pod {}: {}", pod, e),
+        }
+    }
+
     /// Remove stopped/failed sessions older than `max_age_hours`.
-    /// Returns the number of sessions cleaned up.
-    pub async fn cleanup(&self, max_age_hours: u32) -> MinoResult<u32> {
+    /// Returns the removed sessions so callers can release their
+    /// runtime resources (containers, session-scoped volumes).
+    pub async fn cleanup(&self, max_age_hours: u32) -> MinoResult<Vec<Session>> {
         if max_age_hours == 0 {
-            return Ok(0);
+            return Ok(Vec::new());
         }
 
         let cutoff = Utc::now() - Duration::hours(max_age_hours as i64);
         let sessions = self.list().await?;
-        let mut cleaned = 0u32;
+        let mut cleaned = Vec::new();
 
         for session in sessions {
             let dominated = matches!(
@@ -105,7 +158,7 @@ impl SessionManager {
                 match session.delete().await {
                     Ok(()) => {
                         debug!("Cleaned up session: {}", session.name);
-                        cleaned += 1;
+                        cleaned.push(session);
                     }
                     Err(e) => {
                         warn!("Failed to clean up session {}: {}", session.name, e);
@@ -121,6 +174,19 @@ impl SessionManager {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::orchestration::mock::{test_session, MockRuntime};
+
+    #[tokio::test]
+    async fn remove_services_removes_pod_only_when_present() {
+        let mut session = test_session("s", SessionStatus::Running, Some("cid"));
+        let mock = MockRuntime::new();
+        SessionManager::remove_services(&session, &mock).await;
+        mock.assert_no_calls();
+
+        session.pod = Some("mino-pod-s".to_string());
+        SessionManager::remove_services(&session, &mock).await;
+        mock.assert_called_with("pod_remove", &["mino-pod-s"]);
+    }
 
     #[test]
     fn session_status_transitions() {
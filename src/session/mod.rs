@@ -2,6 +2,8 @@
 
 pub mod manager;
 pub mod state;
+pub mod usage;
 
 pub use manager::SessionManager;
 pub use state::{validate_session_name, Session, SessionStatus};
+pub use usage::{UsageLog, UsageRecord};
@@ -1,10 +1,13 @@
 //! Session state persistence
 
 use crate::config::ConfigManager;
+use crate::credentials::deploy_key::SessionDeployKey;
 use crate::error::{MinoError, MinoResult};
+use crate::orchestration::Backend;
 use crate::sandbox::RuntimeMode;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 use tokio::fs;
 use uuid::Uuid;
@@ -15,6 +18,8 @@ use uuid::Uuid;
 pub enum SessionStatus {
     Starting,
     Running,
+    /// Container frozen with `mino pause`
+    Paused,
     Stopped,
     Failed,
 }
@@ -24,6 +29,7 @@ impl std::fmt::Display for SessionStatus {
         match self {
             Self::Starting => write!(f, "starting"),
             Self::Running => write!(f, "running"),
+            Self::Paused => write!(f, "paused"),
             Self::Stopped => write!(f, "stopped"),
             Self::Failed => write!(f, "failed"),
         }
@@ -79,6 +85,60 @@ pub struct Session {
     /// Native mode: sandbox user name (for exec dispatch)
     #[serde(default)]
     pub sandbox_user: Option<String>,
+
+    /// Scratch volume name (if mounted)
+    #[serde(default)]
+    pub scratch_volume: Option<String>,
+
+    /// Dependency cache volumes mounted into the session, which LRU
+    /// eviction leaves alone while the session is active
+    #[serde(default)]
+    pub cache_volumes: Vec<String>,
+
+    /// Container backend the session was started on (container mode only)
+    #[serde(default)]
+    pub backend: Option<Backend>,
+
+    /// Latest checkpoint archive written by `mino checkpoint`
+    #[serde(default)]
+    pub checkpoint: Option<PathBuf>,
+
+    /// DNS name other sessions reach this one by (`<session>.mino`)
+    #[serde(default)]
+    pub hostname: Option<String>,
+
+    /// Container restart policy (`on-failure[:max]`) of a detached session
+    #[serde(default)]
+    pub restart_policy: Option<String>,
+
+    /// Pod shared by the main container and its services
+    #[serde(default)]
+    pub pod: Option<String>,
+
+    /// Service name -> container ID of the `[services]` started in the pod
+    #[serde(default)]
+    pub services: BTreeMap<String, String>,
+
+    /// Native session running in the degraded sandbox (bubblewrap / sandbox-exec)
+    #[serde(default)]
+    pub degraded_sandbox: bool,
+
+    /// Git worktree mounted instead of the project's working tree (`--worktree`)
+    #[serde(default)]
+    pub worktree: Option<PathBuf>,
+
+    /// Deploy key and ssh-agent of a `--deploy-key` session
+    #[serde(default)]
+    pub deploy_key: Option<SessionDeployKey>,
+
+    /// Provider -> expiry of the credentials injected into the session,
+    /// updated when they are refreshed
+    #[serde(default)]
+    pub credential_expiry: BTreeMap<String, DateTime<Utc>>,
+
+    /// Restarts so far, queried from the runtime by `mino list` (never read from disk)
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    pub restart_count: Option<u32>,
 }
 
 impl Session {
@@ -105,6 +165,19 @@ impl Session {
             process_id: None,
             log_file: None,
             sandbox_user: None,
+            scratch_volume: None,
+            cache_volumes: Vec::new(),
+            backend: None,
+            checkpoint: None,
+            hostname: None,
+            restart_policy: None,
+            pod: None,
+            services: BTreeMap::new(),
+            degraded_sandbox: false,
+            worktree: None,
+            deploy_key: None,
+            credential_expiry: BTreeMap::new(),
+            restart_count: None,
         }
     }
 
@@ -204,6 +277,10 @@ impl Session {
                 MinoError::io(format!("deleting session file {}", path.display()), e)
             })?;
         }
+        // The checkpoint cannot be restored without its session record
+        if let Some(archive) = &self.checkpoint {
+            let _ = fs::remove_file(archive).await;
+        }
         Ok(())
     }
 
@@ -383,6 +460,42 @@ mod tests {
         assert!(session.log_file.is_none());
         assert!(session.home_volume.is_none());
         assert!(session.sandbox_user.is_none());
+        assert!(session.backend.is_none());
+    }
+
+    #[test]
+    fn session_backend_roundtrip() {
+        let mut session = Session::new(
+            "docker-session".to_string(),
+            PathBuf::from("/project"),
+            vec!["zsh".to_string()],
+            SessionStatus::Running,
+        );
+        session.backend = Some(Backend::Docker);
+
+        let json = serde_json::to_string(&session).unwrap();
+        assert!(json.contains(r#""backend":"docker""#));
+        let parsed: Session = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.backend, Some(Backend::Docker));
+    }
+
+    #[test]
+    fn session_restart_count_is_never_read_from_disk() {
+        let mut session = Session::new(
+            "test".to_string(),
+            PathBuf::from("/tmp"),
+            vec!["bash".to_string()],
+            SessionStatus::Running,
+        );
+        session.restart_policy = Some("on-failure:3".to_string());
+        session.restart_count = Some(2);
+
+        let json = serde_json::to_string(&session).unwrap();
+        assert!(json.contains("\"restart_count\":2"));
+
+        let loaded: Session = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.restart_policy.as_deref(), Some("on-failure:3"));
+        assert_eq!(loaded.restart_count, None);
     }
 
     #[test]
@@ -405,6 +518,7 @@ mod tests {
     fn status_display() {
         assert_eq!(SessionStatus::Starting.to_string(), "starting");
         assert_eq!(SessionStatus::Running.to_string(), "running");
+        assert_eq!(SessionStatus::Paused.to_string(), "paused");
         assert_eq!(SessionStatus::Stopped.to_string(), "stopped");
         assert_eq!(SessionStatus::Failed.to_string(), "failed");
     }
@@ -0,0 +1,349 @@
+//! Per-session resource usage ledger
+//!
+//! Writes one JSON line per stopped session to `<state_dir>/mino/usage.log`
+//! so platform teams can attribute sandbox usage per project or per user with
+//! `mino session stats export`.
+
+use crate::config::{schema::Config, ConfigManager};
+use crate::error::{MinoError, MinoResult};
+use crate::orchestration::{Backend, ContainerUsage};
+use crate::session::state::Session;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use tokio::fs::OpenOptions;
+use tokio::io::AsyncWriteExt;
+use tracing::warn;
+
+/// Resource usage of one finished session
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UsageRecord {
+    /// Session name
+    pub session: String,
+    /// Host user that started the session
+    pub user: Option<String>,
+    /// Project directory mounted in the sandbox
+    pub project_dir: PathBuf,
+    /// Container backend (container mode only)
+    pub backend: Option<Backend>,
+    /// When the session was created
+    pub started_at: DateTime<Utc>,
+    /// When the session stopped
+    pub stopped_at: DateTime<Utc>,
+    /// Wall-clock time between start and stop, in seconds
+    pub wall_secs: f64,
+    /// CPU time consumed, in seconds (unknown if the container had exited)
+    pub cpu_secs: Option<f64>,
+    /// Peak memory use in bytes
+    pub peak_memory_bytes: Option<u64>,
+    /// Bytes received, loopback excluded
+    pub net_rx_bytes: Option<u64>,
+    /// Bytes sent, loopback excluded
+    pub net_tx_bytes: Option<u64>,
+}
+
+impl UsageRecord {
+    /// Build the record of a session stopping now. `usage` is `None` when
+    /// the container could not be sampled (native mode, already exited).
+    pub fn new(session: &Session, usage: Option<&ContainerUsage>) -> Self {
+        let stopped_at = Utc::now();
+        let wall_secs = (stopped_at - session.created_at)
+            .to_std()
+            .map(|d| d.as_secs_f64())
+            .unwrap_or(0.0);
+
+        Self {
+            session: session.name.clone(),
+            user: current_user(),
+            project_dir: session.project_dir.clone(),
+            backend: session.backend,
+            started_at: session.created_at,
+            stopped_at,
+            wall_secs,
+            cpu_secs: usage.map(|u| u.cpu_secs),
+            peak_memory_bytes: usage.and_then(|u| u.peak_memory_bytes),
+            net_rx_bytes: usage.map(|u| u.net_rx_bytes),
+            net_tx_bytes: usage.map(|u| u.net_tx_bytes),
+        }
+    }
+}
+
+/// Host user name from the environment
+fn current_user() -> Option<String> {
+    std::env::var("USER")
+        .or_else(|_| std::env::var("USERNAME"))
+        .ok()
+        .filter(|u| !u.is_empty())
+}
+
+/// Append-only file of [`UsageRecord`]s, one JSON object per line
+pub struct UsageLog {
+    enabled: bool,
+    path: PathBuf,
+}
+
+impl UsageLog {
+    /// Create a usage log from config
+    pub fn new(config: &Config) -> Self {
+        Self {
+            enabled: config.session.usage_log,
+            path: ConfigManager::usage_log_path(),
+        }
+    }
+
+    /// Record a stopped session
+    ///
+    /// Failures are logged and dropped — accounting must never block
+    /// stopping a session.
+    pub async fn record(&self, session: &Session, usage: Option<&ContainerUsage>) {
+        if !self.enabled {
+            return;
+        }
+        let record = UsageRecord::new(session, usage);
+        if let Err(e) = self.append(&record).await {
+            warn!("Failed to write usage log: {}", e);
+        }
+    }
+
+    async fn append(&self, record: &UsageRecord) -> MinoResult<()> {
+        let mut line = serde_json::to_string(record)?;
+        line.push('\n');
+
+        if let Some(parent) = self.path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(|e| MinoError::io("creating state directory", e))?;
+        }
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .await
+            .map_err(|e| MinoError::io(format!("opening {}", self.path.display()), e))?;
+        file.write_all(line.as_bytes())
+            .await
+            .map_err(|e| MinoError::io(format!("writing {}", self.path.display()), e))
+    }
+
+    /// Records of sessions that stopped within `[since, until)`, oldest first.
+    /// Lines that do not parse (e.g. a torn final write) are skipped.
+    pub async fn read_range(
+        &self,
+        since: Option<DateTime<Utc>>,
+        until: Option<DateTime<Utc>>,
+    ) -> MinoResult<Vec<UsageRecord>> {
+        let content = match tokio::fs::read_to_string(&self.path).await {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+            Err(e) => return Err(MinoError::io(format!("reading {}", self.path.display()), e)),
+        };
+
+        let mut records: Vec<UsageRecord> = content
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .filter_map(|line| match serde_json::from_str(line) {
+                Ok(record) => Some(record),
+                Err(e) => {
+                    warn!("Skipping malformed usage log line: {}", e);
+                    None
+                }
+            })
+            .filter(|r: &UsageRecord| since.is_none_or(|s| r.stopped_at >= s))
+            .filter(|r: &UsageRecord| until.is_none_or(|u| r.stopped_at < u))
+            .collect();
+        records.sort_by_key(|r| r.stopped_at);
+        Ok(records)
+    }
+}
+
+/// CSV column order of [`to_csv`]
+const CSV_HEADER: &str = "session,user,project_dir,backend,started_at,stopped_at,wall_secs,\
+                          cpu_secs,peak_memory_bytes,net_rx_bytes,net_tx_bytes";
+
+/// Render records as CSV with a header row; unknown values are empty cells
+pub fn to_csv(records: &[UsageRecord]) -> String {
+    fn opt<T: ToString>(value: Option<T>) -> String {
+        value.map(|v| v.to_string()).unwrap_or_default()
+    }
+
+    let mut out = String::from(CSV_HEADER);
+    out.push('\n');
+    for r in records {
+        let row = [
+            csv_field(&r.session),
+            csv_field(r.user.as_deref().unwrap_or("")),
+            csv_field(&r.project_dir.display().to_string()),
+            opt(r.backend),
+            r.started_at.to_rfc3339(),
+            r.stopped_at.to_rfc3339(),
+            format!("{:.3}", r.wall_secs),
+            opt(r.cpu_secs.map(|s| format!("{:.3}", s))),
+            opt(r.peak_memory_bytes),
+            opt(r.net_rx_bytes),
+            opt(r.net_tx_bytes),
+        ];
+        out.push_str(&row.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+/// Quote a CSV field when it holds a separator, quote, or line break
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::session::SessionStatus;
+    use chrono::Duration;
+    use tempfile::TempDir;
+
+    fn test_log(dir: &TempDir) -> UsageLog {
+        UsageLog {
+            enabled: true,
+            path: dir.path().join("usage.log"),
+        }
+    }
+
+    fn record(name: &str, stopped_at: DateTime<Utc>) -> UsageRecord {
+        UsageRecord {
+            session: name.to_string(),
+            user: Some("dev".to_string()),
+            project_dir: PathBuf::from("/work/app"),
+            backend: Some(Backend::Podman),
+            started_at: stopped_at - Duration::seconds(90),
+            stopped_at,
+            wall_secs: 90.0,
+            cpu_secs: Some(12.5),
+            peak_memory_bytes: None,
+            net_rx_bytes: Some(5000),
+            net_tx_bytes: Some(3000),
+        }
+    }
+
+    #[test]
+    fn record_from_session_without_usage() {
+        let mut session = Session::new(
+            "swift-fox".to_string(),
+            PathBuf::from("/work/app"),
+            vec!["bash".to_string()],
+            SessionStatus::Running,
+        );
+        session.created_at = Utc::now() - Duration::seconds(60);
+
+        let record = UsageRecord::new(&session, None);
+        assert_eq!(record.session, "swift-fox");
+        assert!(record.wall_secs >= 60.0);
+        assert_eq!(record.cpu_secs, None);
+        assert_eq!(record.net_rx_bytes, None);
+    }
+
+    #[tokio::test]
+    async fn record_appends_json_lines() {
+        let dir = TempDir::new().unwrap();
+        let log = test_log(&dir);
+        let session = Session::new(
+            "swift-fox".to_string(),
+            PathBuf::from("/work/app"),
+            vec![],
+            SessionStatus::Running,
+        );
+        let usage = ContainerUsage {
+            cpu_secs: 2.5,
+            peak_memory_bytes: Some(1024),
+            net_rx_bytes: 10,
+            net_tx_bytes: 20,
+        };
+
+        log.record(&session, Some(&usage)).await;
+        log.record(&session, None).await;
+
+        let records = log.read_range(None, None).await.unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].cpu_secs, Some(2.5));
+        assert_eq!(records[0].peak_memory_bytes, Some(1024));
+        assert_eq!(records[1].cpu_secs, None);
+    }
+
+    #[tokio::test]
+    async fn read_range_filters_by_stop_time_and_skips_garbage() {
+        let dir = TempDir::new().unwrap();
+        let log = test_log(&dir);
+        let day = |d: u32| {
+            DateTime::parse_from_rfc3339(&format!("2026-03-{:02}T12:00:00Z", d))
+                .unwrap()
+                .with_timezone(&Utc)
+        };
+        let lines: Vec<String> = [
+            record("b", day(5)),
+            record("a", day(1)),
+            record("c", day(9)),
+        ]
+        .iter()
+        .map(|r| serde_json::to_string(r).unwrap())
+        .chain(["{\"session\": ".to_string()])
+        .collect();
+        tokio::fs::write(&log.path, lines.join("\n")).await.unwrap();
+
+        let all = log.read_range(None, None).await.unwrap();
+        let names: Vec<&str> = all.iter().map(|r| r.session.as_str()).collect();
+        assert_eq!(names, ["a", "b", "c"]);
+
+        let ranged = log.read_range(Some(day(2)), Some(day(9))).await.unwrap();
+        assert_eq!(ranged.len(), 1);
+        assert_eq!(ranged[0].session, "b");
+    }
+
+    #[tokio::test]
+    async fn record_skips_when_disabled() {
+        let dir = TempDir::new().unwrap();
+        let log = UsageLog {
+            enabled: false,
+            path: dir.path().join("usage.log"),
+        };
+        let session = Session::new(
+            "swift-fox".to_string(),
+            PathBuf::from("/work/app"),
+            vec![],
+            SessionStatus::Running,
+        );
+
+        log.record(&session, None).await;
+        assert!(!log.path.exists());
+    }
+
+    #[tokio::test]
+    async fn read_range_missing_log_is_empty() {
+        let dir = TempDir::new().unwrap();
+        assert!(test_log(&dir)
+            .read_range(None, None)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn csv_has_header_and_empty_unknowns() {
+        let stopped = DateTime::parse_from_rfc3339("2026-03-01T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let mut r = record("swift-fox", stopped);
+        r.project_dir = PathBuf::from("/work/a,b");
+
+        let csv = to_csv(&[r]);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], CSV_HEADER);
+        assert_eq!(
+            lines[1],
+            "swift-fox,dev,\"/work/a,b\",podman,2026-03-01T11:58:30+00:00,\
+             2026-03-01T12:00:00+00:00,90.000,12.500,,5000,3000"
+        );
+    }
+}
@@ -1,5 +1,7 @@
 //! UI context for detecting interactive vs CI environments
 
+use super::events::{default_sink, ProgressSink};
+use super::style::term_caps;
 use std::io::IsTerminal;
 
 /// UI context that determines output behavior
@@ -9,6 +11,10 @@ pub struct UiContext {
     interactive: bool,
     /// Whether --yes flag was passed (auto-approve prompts)
     auto_yes: bool,
+    /// Where spinner and build progress is reported
+    progress: ProgressSink,
+    /// Whether the terminal can draw cliclack's box characters
+    unicode: bool,
 }
 
 impl UiContext {
@@ -18,6 +24,8 @@ impl UiContext {
         Self {
             interactive,
             auto_yes: false,
+            progress: default_sink(),
+            unicode: term_caps().unicode,
         }
     }
 
@@ -26,6 +34,8 @@ impl UiContext {
         Self {
             interactive: false,
             auto_yes: false,
+            progress: ProgressSink::Terminal,
+            unicode: false,
         }
     }
 
@@ -35,6 +45,17 @@ impl UiContext {
         self
     }
 
+    /// Report progress to `sink` instead of the default
+    pub fn with_progress(mut self, sink: ProgressSink) -> Self {
+        self.progress = sink;
+        self
+    }
+
+    /// Where progress events go
+    pub fn progress(&self) -> &ProgressSink {
+        &self.progress
+    }
+
     /// Check if we're in an interactive terminal
     pub fn is_interactive(&self) -> bool {
         self.interactive
@@ -46,8 +67,12 @@ impl UiContext {
     }
 
     /// Check if we should use fancy output (spinners, colors)
+    ///
+    /// Off when progress goes to JSON or a channel, so drawing never
+    /// interleaves with structured output, and on terminals without Unicode
+    /// (or `[ui] style = "plain"`), which get the ASCII fallback.
     pub fn use_fancy_output(&self) -> bool {
-        self.interactive
+        self.interactive && self.unicode && self.progress.is_terminal()
     }
 
     /// Detect if running in an interactive environment
@@ -100,6 +125,33 @@ mod tests {
         assert!(!ctx.auto_yes());
     }
 
+    #[test]
+    fn structured_progress_disables_fancy_output() {
+        let ctx = UiContext {
+            interactive: true,
+            auto_yes: false,
+            progress: ProgressSink::Terminal,
+            unicode: true,
+        };
+        assert!(ctx.use_fancy_output());
+
+        let ctx = ctx.with_progress(ProgressSink::JsonLines);
+        assert!(ctx.is_interactive());
+        assert!(!ctx.use_fancy_output());
+    }
+
+    #[test]
+    fn ascii_terminals_disable_fancy_output() {
+        let ctx = UiContext {
+            interactive: true,
+            auto_yes: false,
+            progress: ProgressSink::Terminal,
+            unicode: false,
+        };
+        assert!(ctx.is_interactive());
+        assert!(!ctx.use_fancy_output());
+    }
+
     #[test]
     fn with_auto_yes() {
         let ctx = UiContext::non_interactive().with_auto_yes(true);
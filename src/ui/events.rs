@@ -0,0 +1,181 @@
+//! Structured progress events
+//!
+//! Spinners and build progress report through a [`ProgressSink`]: the
+//! terminal renders them as before, `--progress json` prints one JSON object
+//! per line on stderr, and embedding applications can receive the events on
+//! a channel to render their own progress.
+
+use chrono::Utc;
+use serde::Serialize;
+use std::io::Write;
+use std::sync::OnceLock;
+use tokio::sync::mpsc::UnboundedSender;
+
+/// A progress update from a long-running step
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum ProgressEvent {
+    /// A phase started
+    PhaseStarted { message: String },
+    /// The running phase changed its message
+    PhaseUpdated { message: String },
+    /// The running phase ended
+    PhaseFinished {
+        outcome: PhaseOutcome,
+        message: Option<String>,
+    },
+    /// An image build started
+    BuildStarted { label: String },
+    /// A build reached step `step` of `total`
+    BuildStep {
+        label: String,
+        step: u64,
+        total: u64,
+        instruction: String,
+    },
+    /// Any other build output line
+    BuildLine { label: String, line: String },
+    /// An image build ended
+    BuildFinished { label: String },
+    /// An image pull started
+    PullStarted { image: String },
+    /// A pull finished `done` of the `total` layers announced so far
+    PullLayers {
+        image: String,
+        done: u64,
+        total: u64,
+    },
+    /// An image pull ended
+    PullFinished { image: String },
+}
+
+/// How a phase ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PhaseOutcome {
+    Ok,
+    Warn,
+    Error,
+    /// Ended without a result message
+    Cleared,
+}
+
+/// Where progress events go
+#[derive(Debug, Clone, Default)]
+pub enum ProgressSink {
+    /// Spinners and progress bars, or plain lines outside a TTY
+    #[default]
+    Terminal,
+    /// One JSON object per line on stderr
+    JsonLines,
+    /// Events sent to an embedding application
+    Channel(UnboundedSender<ProgressEvent>),
+}
+
+impl ProgressSink {
+    /// Whether progress is rendered on the terminal by the caller
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Self::Terminal)
+    }
+
+    /// Deliver an event. The terminal sink ignores events, since spinners
+    /// draw themselves.
+    pub fn emit(&self, event: ProgressEvent) {
+        match self {
+            Self::Terminal => {}
+            Self::JsonLines => {
+                if let Some(line) = json_line(&event) {
+                    let _ = writeln!(std::io::stderr().lock(), "{}", line);
+                }
+            }
+            Self::Channel(tx) => {
+                // A dropped receiver means nobody is listening any more
+                let _ = tx.send(event);
+            }
+        }
+    }
+}
+
+/// Event with the time it was emitted, as written by the JSON-lines sink
+#[derive(Serialize)]
+struct TimestampedEvent<'a> {
+    ts: String,
+    #[serde(flatten)]
+    event: &'a ProgressEvent,
+}
+
+fn json_line(event: &ProgressEvent) -> Option<String> {
+    serde_json::to_string(&TimestampedEvent {
+        ts: Utc::now().to_rfc3339(),
+        event,
+    })
+    .ok()
+}
+
+static DEFAULT_SINK: OnceLock<ProgressSink> = OnceLock::new();
+
+/// Set the sink [`UiContext::detect`](super::UiContext::detect) uses. Only
+/// the first call takes effect.
+pub fn init_progress(sink: ProgressSink) {
+    let _ = DEFAULT_SINK.set(sink);
+}
+
+/// Sink chosen by [`init_progress`], or the terminal
+pub(super) fn default_sink() -> ProgressSink {
+    DEFAULT_SINK.get().cloned().unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_line_is_tagged_and_timestamped() {
+        let line = json_line(&ProgressEvent::BuildStep {
+            label: "rust".to_string(),
+            step: 2,
+            total: 5,
+            instruction: "RUN install.sh".to_string(),
+        })
+        .unwrap();
+        let value: serde_json::Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(value["event"], "build_step");
+        assert_eq!(value["step"], 2);
+        assert_eq!(value["total"], 5);
+        assert!(value["ts"].is_string());
+    }
+
+    #[test]
+    fn phase_outcome_serializes_lowercase() {
+        let line = json_line(&ProgressEvent::PhaseFinished {
+            outcome: PhaseOutcome::Warn,
+            message: None,
+        })
+        .unwrap();
+        assert!(line.contains(r#""event":"phase_finished""#));
+        assert!(line.contains(r#""outcome":"warn""#));
+    }
+
+    #[test]
+    fn channel_sink_delivers_events() {
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let sink = ProgressSink::Channel(tx);
+        assert!(!sink.is_terminal());
+
+        sink.emit(ProgressEvent::PhaseStarted {
+            message: "Pulling".to_string(),
+        });
+        assert_eq!(
+            rx.try_recv().unwrap(),
+            ProgressEvent::PhaseStarted {
+                message: "Pulling".to_string()
+            }
+        );
+
+        // Emitting after the receiver is gone is harmless
+        drop(rx);
+        sink.emit(ProgressEvent::PhaseUpdated {
+            message: "still here".to_string(),
+        });
+    }
+}
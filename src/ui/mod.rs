@@ -26,17 +26,25 @@
 //! ```
 
 mod context;
+mod events;
 mod output;
+mod pager;
 mod progress;
 mod prompts;
+mod style;
+mod table;
 mod theme;
 
 pub use context::UiContext;
+pub use events::{init_progress, PhaseOutcome, ProgressEvent, ProgressSink};
 pub use output::{
     intro, key_value, key_value_status, note, outro_error, outro_success, outro_warn, remark,
     section, step_blocked, step_error, step_error_detail, step_info, step_ok, step_ok_detail,
     step_warn, step_warn_hint,
 };
-pub use progress::{BuildProgress, TaskSpinner};
-pub use prompts::{confirm, confirm_inline, multiselect, select};
+pub use pager::{init_pager, page};
+pub use progress::{BuildProgress, PullProgress, TaskSpinner};
+pub use prompts::{confirm, confirm_inline, input, multiselect, multiselect_with_initial, select};
+pub use style::{init_style, term_caps, StyleMode, TermCaps};
+pub use table::{terminal_width, Column, Table};
 pub use theme::{init_theme, MinoTheme};
@@ -0,0 +1,63 @@
+//! Paging for long command output
+
+use super::context::UiContext;
+use std::io::Write;
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Pager used when `$PAGER` is unset. `-F` exits immediately when the
+/// output fits on one screen, `-R` keeps colors, `-X` leaves the output on
+/// screen after quitting.
+const DEFAULT_PAGER: &str = "less -FRX";
+
+static PAGER_ENABLED: AtomicBool = AtomicBool::new(true);
+
+/// Enable or disable paging for the process (`--no-pager`, `MINO_NO_PAGER`)
+pub fn init_pager(enabled: bool) {
+    PAGER_ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Print `text`, through `$PAGER` when it is taller than the terminal.
+///
+/// Falls back to printing directly when paging is disabled, in
+/// non-interactive contexts, when the output fits, or when the pager cannot
+/// be started.
+pub fn page(ctx: &UiContext, text: &str) {
+    if !PAGER_ENABLED.load(Ordering::Relaxed) || !ctx.is_interactive() || !exceeds_terminal(text) {
+        print!("{}", text);
+        return;
+    }
+
+    let pager = std::env::var("PAGER")
+        .ok()
+        .filter(|p| !p.trim().is_empty())
+        .unwrap_or_else(|| DEFAULT_PAGER.to_string());
+
+    if run_pager(&pager, text).is_err() {
+        print!("{}", text);
+    }
+}
+
+/// Whether `text` has more lines than the terminal can show at once
+fn exceeds_terminal(text: &str) -> bool {
+    let (rows, _) = console::Term::stdout().size();
+    text.lines().count() >= usize::from(rows)
+}
+
+fn run_pager(pager: &str, text: &str) -> std::io::Result<()> {
+    // Through the shell so $PAGER may carry its own arguments
+    let mut child = Command::new("sh")
+        .args(["-c", pager])
+        .stdin(Stdio::piped())
+        .spawn()?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        // The user may quit the pager early; a broken pipe is not an error
+        let _ = stdin.write_all(text.as_bytes());
+    }
+    // 127: the shell could not find the pager command
+    if child.wait()?.code() == Some(127) {
+        return Err(std::io::Error::from(std::io::ErrorKind::NotFound));
+    }
+    Ok(())
+}
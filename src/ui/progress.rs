@@ -1,14 +1,18 @@
 //! Progress indicators with CI fallback
 
 use super::context::UiContext;
+use super::events::{PhaseOutcome, ProgressEvent, ProgressSink};
 use console::style;
 use indicatif::{ProgressBar, ProgressStyle};
+use std::collections::HashSet;
+use std::sync::Mutex;
 
 /// A task spinner with CI fallback
 pub struct TaskSpinner {
     spinner: Option<cliclack::ProgressBar>,
     message: String,
     interactive: bool,
+    sink: ProgressSink,
 }
 
 impl TaskSpinner {
@@ -18,13 +22,33 @@ impl TaskSpinner {
             spinner: None,
             message: String::new(),
             interactive: ctx.use_fancy_output(),
+            sink: ctx.progress().clone(),
         }
     }
 
+    /// Send a phase-finished event; false when the terminal renders instead
+    fn emit_finished(&self, outcome: PhaseOutcome, message: Option<&str>) -> bool {
+        if self.sink.is_terminal() {
+            return false;
+        }
+        self.sink.emit(ProgressEvent::PhaseFinished {
+            outcome,
+            message: message.map(str::to_string),
+        });
+        true
+    }
+
     /// Start the spinner with a message
     pub fn start(&mut self, message: &str) {
         self.message = message.to_string();
 
+        if !self.sink.is_terminal() {
+            self.sink.emit(ProgressEvent::PhaseStarted {
+                message: message.to_string(),
+            });
+            return;
+        }
+
         if self.interactive {
             let spinner = cliclack::spinner();
             spinner.start(message);
@@ -38,6 +62,9 @@ impl TaskSpinner {
     /// Update the spinner message
     pub fn message(&mut self, message: &str) {
         self.message = message.to_string();
+        self.sink.emit(ProgressEvent::PhaseUpdated {
+            message: message.to_string(),
+        });
 
         if let Some(ref spinner) = self.spinner {
             spinner.start(message);
@@ -47,6 +74,9 @@ impl TaskSpinner {
 
     /// Stop with success message
     pub fn stop(&mut self, message: &str) {
+        if self.emit_finished(PhaseOutcome::Ok, Some(message)) {
+            return;
+        }
         if let Some(spinner) = self.spinner.take() {
             spinner.stop(message);
         } else if self.interactive {
@@ -59,6 +89,9 @@ impl TaskSpinner {
 
     /// Stop with error message
     pub fn stop_error(&mut self, message: &str) {
+        if self.emit_finished(PhaseOutcome::Error, Some(message)) {
+            return;
+        }
         if let Some(spinner) = self.spinner.take() {
             spinner.error(message);
         } else if self.interactive {
@@ -70,6 +103,9 @@ impl TaskSpinner {
 
     /// Stop with warning message
     pub fn stop_warn(&mut self, message: &str) {
+        if self.emit_finished(PhaseOutcome::Warn, Some(message)) {
+            return;
+        }
         if let Some(spinner) = self.spinner.take() {
             spinner.stop(message);
         } else if self.interactive {
@@ -81,6 +117,9 @@ impl TaskSpinner {
 
     /// Clear the spinner without any message
     pub fn clear(&mut self) {
+        if self.emit_finished(PhaseOutcome::Cleared, None) {
+            return;
+        }
         if let Some(spinner) = self.spinner.take() {
             spinner.clear();
         }
@@ -93,6 +132,8 @@ impl TaskSpinner {
 /// an indicatif progress bar in interactive mode, or plain text in CI.
 pub struct BuildProgress {
     bar: Option<ProgressBar>,
+    label: String,
+    sink: ProgressSink,
 }
 
 impl BuildProgress {
@@ -100,7 +141,13 @@ impl BuildProgress {
     ///
     /// Shows an indicatif bar in interactive mode, plain text in CI.
     pub fn new(ctx: &UiContext, label: &str) -> Self {
-        let bar = if ctx.use_fancy_output() {
+        let sink = ctx.progress().clone();
+        let bar = if !sink.is_terminal() {
+            sink.emit(ProgressEvent::BuildStarted {
+                label: label.to_string(),
+            });
+            None
+        } else if ctx.use_fancy_output() {
             let bar = ProgressBar::new(0);
             bar.set_style(
                 ProgressStyle::default_bar()
@@ -116,11 +163,19 @@ impl BuildProgress {
             println!("Building {}...", label);
             None
         };
-        Self { bar }
+        Self {
+            bar,
+            label: label.to_string(),
+            sink,
+        }
     }
 
     /// Process a build output line. Parses `STEP N/M:` and updates the bar.
     pub fn on_line(&self, line: String) {
+        if !self.sink.is_terminal() {
+            self.emit_line(line);
+            return;
+        }
         if let Some((n, total, instruction)) = parse_step_line(&line) {
             if let Some(ref bar) = self.bar {
                 bar.set_length(total);
@@ -142,8 +197,137 @@ impl BuildProgress {
         }
     }
 
+    /// Report a build line as a step or plain line event
+    fn emit_line(&self, line: String) {
+        let label = self.label.clone();
+        let event = match parse_step_line(&line) {
+            Some((step, total, instruction)) => ProgressEvent::BuildStep {
+                label,
+                step,
+                total,
+                instruction: instruction.to_string(),
+            },
+            None => {
+                let trimmed = line.trim();
+                if trimmed.is_empty() || is_build_noise(trimmed) {
+                    return;
+                }
+                ProgressEvent::BuildLine { label, line }
+            }
+        };
+        self.sink.emit(event);
+    }
+
+    /// Finish and clear the progress bar.
+    pub fn finish(&self) {
+        self.sink.emit(ProgressEvent::BuildFinished {
+            label: self.label.clone(),
+        });
+        if let Some(ref bar) = self.bar {
+            bar.disable_steady_tick();
+            bar.finish_and_clear();
+        }
+    }
+}
+
+/// Progress bar for image pulls.
+///
+/// Counts layers from `podman pull` (`Copying blob`) and `docker pull`
+/// (`<id>: Pull complete`) output and displays an indicatif bar with an
+/// ETA in interactive mode, or plain text in CI.
+pub struct PullProgress {
+    bar: Option<ProgressBar>,
+    image: String,
+    sink: ProgressSink,
+    layers: Mutex<PullLayers>,
+}
+
+/// Layers announced and finished so far in a pull
+#[derive(Default)]
+struct PullLayers {
+    seen: HashSet<String>,
+    done: HashSet<String>,
+}
+
+impl PullProgress {
+    /// Create a new pull progress indicator.
+    ///
+    /// Shows an indicatif bar in interactive mode, plain text in CI.
+    pub fn new(ctx: &UiContext, image: &str) -> Self {
+        let sink = ctx.progress().clone();
+        let bar = if !sink.is_terminal() {
+            sink.emit(ProgressEvent::PullStarted {
+                image: image.to_string(),
+            });
+            None
+        } else if ctx.use_fancy_output() {
+            let bar = ProgressBar::new(0);
+            bar.set_style(
+                ProgressStyle::default_bar()
+                    .template("  {spinner:.cyan} Pulling {prefix}  {bar:20.cyan/dim} {pos}/{len} layers {msg:.dim}  {elapsed:.dim} (eta {eta})")
+                    .unwrap()
+                    .tick_chars("⠋⠙⠹⠸⠼⠴⠦⠧⠇⠏ ")
+                    .progress_chars("━╸─"),
+            );
+            bar.set_prefix(image.to_string());
+            bar.enable_steady_tick(std::time::Duration::from_millis(120));
+            Some(bar)
+        } else {
+            println!("Pulling {}...", image);
+            None
+        };
+        Self {
+            bar,
+            image: image.to_string(),
+            sink,
+            layers: Mutex::new(PullLayers::default()),
+        }
+    }
+
+    /// Process a pull output line. Layer lines update the counts; other
+    /// lines become the bar message.
+    pub fn on_line(&self, line: String) {
+        let Some((layer, finished)) = parse_pull_layer(&line) else {
+            if let Some(ref bar) = self.bar {
+                let trimmed = line.trim();
+                if !trimmed.is_empty() {
+                    bar.set_message(truncate_message(trimmed));
+                }
+            }
+            return;
+        };
+
+        let (done, total, changed) = {
+            let mut layers = self.layers.lock().unwrap_or_else(|e| e.into_inner());
+            let mut changed = layers.seen.insert(layer.to_string());
+            if finished {
+                changed |= layers.done.insert(layer.to_string());
+            }
+            (layers.done.len() as u64, layers.seen.len() as u64, changed)
+        };
+        if !changed {
+            return;
+        }
+
+        if !self.sink.is_terminal() {
+            self.sink.emit(ProgressEvent::PullLayers {
+                image: self.image.clone(),
+                done,
+                total,
+            });
+        } else if let Some(ref bar) = self.bar {
+            bar.set_length(total);
+            bar.set_position(done);
+        } else if finished {
+            println!("  {}/{} layers", done, total);
+        }
+    }
+
     /// Finish and clear the progress bar.
     pub fn finish(&self) {
+        self.sink.emit(ProgressEvent::PullFinished {
+            image: self.image.clone(),
+        });
         if let Some(ref bar) = self.bar {
             bar.disable_steady_tick();
             bar.finish_and_clear();
@@ -151,6 +335,44 @@ impl BuildProgress {
     }
 }
 
+/// Parse a layer line of pull output into `(layer, finished)`.
+///
+/// Podman prints `Copying blob <digest>` when a layer starts and appends
+/// `done` or `skipped: already exists` when it is in place; Docker prints
+/// `<id>: Pulling fs layer` through `<id>: Pull complete` / `Already exists`.
+fn parse_pull_layer(line: &str) -> Option<(&str, bool)> {
+    let line = line.trim();
+    if let Some(rest) = line.strip_prefix("Copying blob ") {
+        let mut parts = rest.split_whitespace();
+        let layer = parts.next()?;
+        let status = parts.collect::<Vec<_>>().join(" ");
+        let finished = status.starts_with("done") || status.starts_with("skipped");
+        return Some((layer, finished));
+    }
+
+    let (layer, status) = line.split_once(": ")?;
+    if layer.is_empty() || !layer.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    match status.trim() {
+        "Pull complete" | "Already exists" => Some((layer, true)),
+        "Pulling fs layer" | "Waiting" | "Verifying Checksum" | "Download complete" => {
+            Some((layer, false))
+        }
+        s if s.starts_with("Downloading") || s.starts_with("Extracting") => Some((layer, false)),
+        _ => None,
+    }
+}
+
+/// Shorten a line to fit next to a progress bar
+fn truncate_message(line: &str) -> String {
+    if line.chars().count() > 60 {
+        format!("{}...", line.chars().take(57).collect::<String>())
+    } else {
+        line.to_string()
+    }
+}
+
 /// Filter out Podman internal build lines that aren't useful to display.
 fn is_build_noise(line: &str) -> bool {
     line.starts_with("--->")
@@ -221,6 +443,134 @@ mod tests {
         // Should not panic
     }
 
+    #[test]
+    fn spinner_reports_phases_to_channel() {
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let ctx = UiContext::non_interactive().with_progress(ProgressSink::Channel(tx));
+        let mut spinner = TaskSpinner::new(&ctx);
+        spinner.start("Pulling image...");
+        spinner.message("Creating container...");
+        spinner.stop_warn("Started without cache");
+
+        assert_eq!(
+            rx.try_recv().unwrap(),
+            ProgressEvent::PhaseStarted {
+                message: "Pulling image...".to_string()
+            }
+        );
+        assert_eq!(
+            rx.try_recv().unwrap(),
+            ProgressEvent::PhaseUpdated {
+                message: "Creating container...".to_string()
+            }
+        );
+        assert_eq!(
+            rx.try_recv().unwrap(),
+            ProgressEvent::PhaseFinished {
+                outcome: PhaseOutcome::Warn,
+                message: Some("Started without cache".to_string())
+            }
+        );
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn build_progress_reports_steps_and_lines() {
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let ctx = UiContext::non_interactive().with_progress(ProgressSink::Channel(tx));
+        let progress = BuildProgress::new(&ctx, "rust");
+        progress.on_line("STEP 1/2: FROM base:latest".to_string());
+        progress.on_line("   ".to_string());
+        progress.on_line("downloading rustup-init".to_string());
+        progress.finish();
+
+        let events: Vec<ProgressEvent> = std::iter::from_fn(|| rx.try_recv().ok()).collect();
+        let label = || "rust".to_string();
+        assert_eq!(
+            events,
+            vec![
+                ProgressEvent::BuildStarted { label: label() },
+                ProgressEvent::BuildStep {
+                    label: label(),
+                    step: 1,
+                    total: 2,
+                    instruction: "FROM base:latest".to_string(),
+                },
+                ProgressEvent::BuildLine {
+                    label: label(),
+                    line: "downloading rustup-init".to_string(),
+                },
+                ProgressEvent::BuildFinished { label: label() },
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_pull_layer_podman_and_docker() {
+        assert_eq!(
+            parse_pull_layer("Copying blob sha256:4abcf2066143"),
+            Some(("sha256:4abcf2066143", false))
+        );
+        assert_eq!(
+            parse_pull_layer("Copying blob 4abcf2066143 done   | "),
+            Some(("4abcf2066143", true))
+        );
+        assert_eq!(
+            parse_pull_layer("Copying blob 4abcf2066143 skipped: already exists"),
+            Some(("4abcf2066143", true))
+        );
+        assert_eq!(
+            parse_pull_layer("a2abf6c4d29d: Pulling fs layer"),
+            Some(("a2abf6c4d29d", false))
+        );
+        assert_eq!(
+            parse_pull_layer("a2abf6c4d29d: Pull complete"),
+            Some(("a2abf6c4d29d", true))
+        );
+        assert!(parse_pull_layer("Copying config sha256:abc done").is_none());
+        assert!(parse_pull_layer("Trying to pull docker.io/library/node:22...").is_none());
+        assert!(parse_pull_layer("Digest: sha256:abc123").is_none());
+        assert!(parse_pull_layer("Status: Downloaded newer image for node:22").is_none());
+    }
+
+    #[test]
+    fn pull_progress_reports_layer_counts() {
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let ctx = UiContext::non_interactive().with_progress(ProgressSink::Channel(tx));
+        let progress = PullProgress::new(&ctx, "node:22");
+        progress.on_line("Trying to pull docker.io/library/node:22...".to_string());
+        progress.on_line("Copying blob aaa".to_string());
+        progress.on_line("Copying blob bbb".to_string());
+        progress.on_line("Copying blob aaa done".to_string());
+        progress.on_line("Copying blob aaa done".to_string());
+        progress.finish();
+
+        let events: Vec<ProgressEvent> = std::iter::from_fn(|| rx.try_recv().ok()).collect();
+        let image = || "node:22".to_string();
+        assert_eq!(
+            events,
+            vec![
+                ProgressEvent::PullStarted { image: image() },
+                ProgressEvent::PullLayers {
+                    image: image(),
+                    done: 0,
+                    total: 1
+                },
+                ProgressEvent::PullLayers {
+                    image: image(),
+                    done: 0,
+                    total: 2
+                },
+                ProgressEvent::PullLayers {
+                    image: image(),
+                    done: 1,
+                    total: 2
+                },
+                ProgressEvent::PullFinished { image: image() },
+            ]
+        );
+    }
+
     #[test]
     fn is_build_noise_filters_podman_internals() {
         assert!(is_build_noise("---> abc123def"));
@@ -78,6 +78,18 @@ pub async fn multiselect<T: Clone + Send + Eq + 'static>(
     message: &str,
     options: &[(T, &str, &str)], // (value, label, hint)
     required: bool,
+) -> MinoResult<Vec<T>> {
+    multiselect_with_initial(ctx, message, options, &[], required).await
+}
+
+/// Like [`multiselect`], with `initial` values pre-selected.
+/// Returns empty vec if non-interactive.
+pub async fn multiselect_with_initial<T: Clone + Send + Eq + 'static>(
+    ctx: &UiContext,
+    message: &str,
+    options: &[(T, &str, &str)], // (value, label, hint)
+    initial: &[T],
+    required: bool,
 ) -> MinoResult<Vec<T>> {
     // Non-interactive mode returns empty vec (caller decides default)
     if !ctx.is_interactive() || ctx.auto_yes() {
@@ -89,6 +101,7 @@ pub async fn multiselect<T: Clone + Send + Eq + 'static>(
         .iter()
         .map(|(v, l, h)| (v.clone(), l.to_string(), h.to_string()))
         .collect();
+    let initial = initial.to_vec();
 
     let result: Result<Result<Vec<T>, std::io::Error>, _> =
         tokio::task::spawn_blocking(move || {
@@ -96,6 +109,9 @@ pub async fn multiselect<T: Clone + Send + Eq + 'static>(
             for (value, label, hint) in items {
                 ms = ms.item(value, label, hint);
             }
+            if !initial.is_empty() {
+                ms = ms.initial_values(initial);
+            }
             ms = ms.required(required);
             ms.interact()
         })
@@ -114,6 +130,34 @@ pub async fn multiselect<T: Clone + Send + Eq + 'static>(
     }
 }
 
+/// Prompt for a line of text.
+/// Returns `default` if non-interactive or auto-yes.
+pub async fn input(ctx: &UiContext, message: &str, default: &str) -> MinoResult<String> {
+    if !ctx.is_interactive() || ctx.auto_yes() {
+        return Ok(default.to_string());
+    }
+
+    let message = message.to_string();
+    let default = default.to_string();
+    let result: Result<Result<String, std::io::Error>, _> =
+        tokio::task::spawn_blocking(move || {
+            cliclack::input(&message).default_input(&default).interact()
+        })
+        .await;
+
+    match result {
+        Ok(Ok(value)) => Ok(value),
+        Ok(Err(e)) => Err(crate::error::MinoError::User(format!(
+            "Input failed: {}",
+            e
+        ))),
+        Err(e) => Err(crate::error::MinoError::User(format!(
+            "Input task failed: {}",
+            e
+        ))),
+    }
+}
+
 /// Simple inline confirmation for non-fancy mode (used by setup)
 pub fn confirm_inline(prompt: &str, auto_yes: bool) -> bool {
     if auto_yes {
@@ -175,4 +219,11 @@ mod tests {
         let result = select(&ctx, "Choose:", &options).await.unwrap();
         assert_eq!(result, "a");
     }
+
+    #[tokio::test]
+    async fn input_non_interactive_default() {
+        let ctx = UiContext::non_interactive();
+        let result = input(&ctx, "Name:", "swift-fox").await.unwrap();
+        assert_eq!(result, "swift-fox");
+    }
 }
@@ -0,0 +1,226 @@
+//! Terminal capability detection for colors and Unicode
+//!
+//! Follows the `NO_COLOR` and `FORCE_COLOR` conventions (plus `CLICOLOR` /
+//! `CLICOLOR_FORCE`), and falls back to ASCII output on terminals that can't
+//! draw box characters (`TERM=dumb`, the Linux console, non-UTF-8 locales).
+//! `[ui] style` overrides detection in either direction.
+
+use crate::error::MinoError;
+use std::io::IsTerminal;
+use std::sync::OnceLock;
+
+/// Output style from `[ui] style`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum StyleMode {
+    /// Detect from the environment and terminal
+    #[default]
+    Auto,
+    /// No colors, ASCII only, no spinners
+    Plain,
+    /// Colors and Unicode even where detection says otherwise
+    Rich,
+}
+
+impl std::str::FromStr for StyleMode {
+    type Err = MinoError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "auto" => Ok(Self::Auto),
+            "plain" => Ok(Self::Plain),
+            "rich" => Ok(Self::Rich),
+            other => Err(MinoError::User(format!(
+                "Invalid ui style '{}'. Valid styles: auto, plain, rich",
+                other
+            ))),
+        }
+    }
+}
+
+impl std::fmt::Display for StyleMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Auto => write!(f, "auto"),
+            Self::Plain => write!(f, "plain"),
+            Self::Rich => write!(f, "rich"),
+        }
+    }
+}
+
+/// What the output terminal can render
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TermCaps {
+    /// ANSI colors
+    pub color: bool,
+    /// Box-drawing characters and spinner glyphs
+    pub unicode: bool,
+}
+
+impl TermCaps {
+    /// Resolve capabilities for `mode` from environment lookups and whether
+    /// stdout is a terminal
+    pub fn detect(mode: StyleMode, env: impl Fn(&str) -> Option<String>, is_tty: bool) -> Self {
+        match mode {
+            StyleMode::Plain => Self {
+                color: false,
+                unicode: false,
+            },
+            StyleMode::Rich => Self {
+                color: true,
+                unicode: true,
+            },
+            StyleMode::Auto => Self {
+                color: detect_color(&env, is_tty),
+                unicode: detect_unicode(&env),
+            },
+        }
+    }
+}
+
+/// Set-and-non-empty, per the NO_COLOR convention
+fn non_empty(env: &impl Fn(&str) -> Option<String>, key: &str) -> Option<String> {
+    env(key).filter(|v| !v.is_empty())
+}
+
+fn detect_color(env: &impl Fn(&str) -> Option<String>, is_tty: bool) -> bool {
+    if non_empty(env, "NO_COLOR").is_some() {
+        return false;
+    }
+    if let Some(force) = env("FORCE_COLOR") {
+        // FORCE_COLOR= (empty) counts as forcing, like most Node tooling
+        return !matches!(force.as_str(), "0" | "false");
+    }
+    if non_empty(env, "CLICOLOR_FORCE").is_some_and(|v| v != "0") {
+        return true;
+    }
+    if env("CLICOLOR").as_deref() == Some("0") || !is_tty {
+        return false;
+    }
+    match env("TERM") {
+        Some(term) => term != "dumb",
+        // Windows terminals don't set TERM
+        None => cfg!(windows),
+    }
+}
+
+fn detect_unicode(env: &impl Fn(&str) -> Option<String>) -> bool {
+    if matches!(env("TERM").as_deref(), Some("dumb" | "linux")) {
+        return false;
+    }
+    // The first set locale variable wins, as in setlocale(3)
+    let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+        .into_iter()
+        .find_map(|key| non_empty(env, key));
+    match locale {
+        Some(locale) => {
+            let locale = locale.to_lowercase();
+            locale.contains("utf-8") || locale.contains("utf8")
+        }
+        None => true,
+    }
+}
+
+static CAPS: OnceLock<TermCaps> = OnceLock::new();
+
+/// Apply `[ui] style`: resolves capabilities once and turns colors on or off
+/// for everything styled through `console`. Only the first call takes effect.
+pub fn init_style(mode: StyleMode) {
+    let caps = TermCaps::detect(
+        mode,
+        |key| std::env::var(key).ok(),
+        std::io::stdout().is_terminal(),
+    );
+    if CAPS.set(caps).is_ok() {
+        console::set_colors_enabled(caps.color);
+        console::set_colors_enabled_stderr(caps.color);
+    }
+}
+
+/// Capabilities set by [`init_style`], or auto-detected before it runs
+pub fn term_caps() -> TermCaps {
+    CAPS.get().copied().unwrap_or_else(|| {
+        TermCaps::detect(
+            StyleMode::Auto,
+            |key| std::env::var(key).ok(),
+            std::io::stdout().is_terminal(),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn env_of<'a>(vars: &'a [(&'a str, &'a str)]) -> impl Fn(&str) -> Option<String> + 'a {
+        move |key| {
+            vars.iter()
+                .find(|(k, _)| *k == key)
+                .map(|(_, v)| v.to_string())
+        }
+    }
+
+    #[test]
+    fn style_mode_parse_and_display() {
+        assert_eq!("Plain".parse::<StyleMode>().unwrap(), StyleMode::Plain);
+        assert_eq!(StyleMode::Rich.to_string(), "rich");
+        assert!("fancy".parse::<StyleMode>().is_err());
+    }
+
+    #[test]
+    fn auto_colors_on_capable_tty() {
+        let env = [("TERM", "xterm-256color"), ("LANG", "en_US.UTF-8")];
+        let caps = TermCaps::detect(StyleMode::Auto, env_of(&env), true);
+        assert!(caps.color);
+        assert!(caps.unicode);
+
+        let caps = TermCaps::detect(StyleMode::Auto, env_of(&env), false);
+        assert!(!caps.color, "pipes get no color");
+    }
+
+    #[test]
+    fn no_color_wins_over_force_color() {
+        let env = [("TERM", "xterm"), ("NO_COLOR", "1"), ("FORCE_COLOR", "1")];
+        assert!(!TermCaps::detect(StyleMode::Auto, env_of(&env), true).color);
+
+        // An empty NO_COLOR is ignored
+        let env = [("TERM", "xterm"), ("NO_COLOR", "")];
+        assert!(TermCaps::detect(StyleMode::Auto, env_of(&env), true).color);
+    }
+
+    #[test]
+    fn force_color_enables_color_in_pipes() {
+        let env = [("FORCE_COLOR", "1")];
+        assert!(TermCaps::detect(StyleMode::Auto, env_of(&env), false).color);
+
+        let env = [("TERM", "xterm"), ("FORCE_COLOR", "0")];
+        assert!(!TermCaps::detect(StyleMode::Auto, env_of(&env), true).color);
+
+        let env = [("CLICOLOR_FORCE", "1")];
+        assert!(TermCaps::detect(StyleMode::Auto, env_of(&env), false).color);
+    }
+
+    #[test]
+    fn minimal_terminals_get_ascii() {
+        let env = [("TERM", "dumb")];
+        let caps = TermCaps::detect(StyleMode::Auto, env_of(&env), true);
+        assert!(!caps.color);
+        assert!(!caps.unicode);
+
+        let env = [("TERM", "linux")];
+        assert!(!TermCaps::detect(StyleMode::Auto, env_of(&env), true).unicode);
+
+        let env = [("TERM", "xterm"), ("LC_ALL", "C"), ("LANG", "en_US.UTF-8")];
+        assert!(!TermCaps::detect(StyleMode::Auto, env_of(&env), true).unicode);
+    }
+
+    #[test]
+    fn explicit_styles_override_detection() {
+        let env = [("TERM", "dumb"), ("NO_COLOR", "1")];
+        let caps = TermCaps::detect(StyleMode::Rich, env_of(&env), false);
+        assert!(caps.color && caps.unicode);
+
+        let env = [("TERM", "xterm"), ("FORCE_COLOR", "1")];
+        let caps = TermCaps::detect(StyleMode::Plain, env_of(&env), true);
+        assert!(!caps.color && !caps.unicode);
+    }
+}
@@ -0,0 +1,219 @@
+//! Column-aligned tables that fit the terminal width
+//!
+//! Columns size to their content up to a per-column cap. When the table is
+//! wider than the terminal, flexible columns shrink (widest first) and their
+//! cells are truncated with an ellipsis; fixed columns keep their width.
+//! Output that isn't going to a terminal is never truncated.
+
+use super::style::term_caps;
+use console::{measure_text_width, pad_str, style, Alignment};
+use std::fmt::Write;
+
+/// Narrowest a flexible column shrinks to
+const MIN_FLEX_WIDTH: usize = 8;
+
+/// A table column
+#[derive(Debug, Clone)]
+pub struct Column {
+    header: &'static str,
+    max: usize,
+    flexible: bool,
+}
+
+impl Column {
+    /// Column that may be truncated to fit, at most `max` characters wide
+    pub fn new(header: &'static str, max: usize) -> Self {
+        Self {
+            header,
+            max,
+            flexible: true,
+        }
+    }
+
+    /// Keep the column's full width even on narrow terminals
+    pub fn fixed(mut self) -> Self {
+        self.flexible = false;
+        self
+    }
+
+    fn min_width(&self, natural: usize) -> usize {
+        if self.flexible {
+            natural.min(MIN_FLEX_WIDTH.max(self.header.len()))
+        } else {
+            natural
+        }
+    }
+}
+
+/// Rows rendered under a bold header and a separator line
+#[derive(Debug, Clone)]
+pub struct Table {
+    columns: Vec<Column>,
+    rows: Vec<(Vec<String>, bool)>,
+}
+
+impl Table {
+    pub fn new(columns: Vec<Column>) -> Self {
+        Self {
+            columns,
+            rows: Vec::new(),
+        }
+    }
+
+    /// Add a row; cells may carry ANSI styles
+    pub fn row(&mut self, cells: Vec<String>) {
+        self.rows.push((cells, false));
+    }
+
+    /// Add a row rendered dim, for entries that matter less (stopped sessions)
+    pub fn dim_row(&mut self, cells: Vec<String>) {
+        self.rows.push((cells, true));
+    }
+
+    /// Render for the current stdout: fitted to its width on a terminal
+    pub fn render(&self) -> String {
+        self.render_width(terminal_width())
+    }
+
+    /// Render fitted to `width` columns, or at natural width for `None`
+    pub fn render_width(&self, width: Option<usize>) -> String {
+        let widths = self.fit(width);
+        let ellipsis = if term_caps().unicode { "…" } else { "..." };
+
+        let mut out = String::new();
+        let header: Vec<String> = self
+            .columns
+            .iter()
+            .zip(&widths)
+            .map(|(column, &w)| fit_cell(column.header, w, ellipsis))
+            .collect();
+        let _ = writeln!(out, "{}", style(header.join(" ").trim_end()).bold());
+        let total = widths.iter().sum::<usize>() + widths.len().saturating_sub(1);
+        let _ = writeln!(out, "{}", "-".repeat(total));
+
+        for (cells, dim) in &self.rows {
+            let line: Vec<String> = widths
+                .iter()
+                .enumerate()
+                .map(|(i, &w)| {
+                    let cell = cells.get(i).map(String::as_str).unwrap_or("");
+                    fit_cell(cell, w, ellipsis)
+                })
+                .collect();
+            let line = line.join(" ").trim_end().to_string();
+            let line = if *dim {
+                style(line).dim().to_string()
+            } else {
+                line
+            };
+            let _ = writeln!(out, "{}", line);
+        }
+        out
+    }
+
+    /// Column widths: content width capped per column, then flexible
+    /// columns shrunk (widest first) until the table fits `width`
+    fn fit(&self, width: Option<usize>) -> Vec<usize> {
+        let mut widths: Vec<usize> = self
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(i, column)| {
+                let content = self
+                    .rows
+                    .iter()
+                    .filter_map(|(cells, _)| cells.get(i))
+                    .map(|cell| measure_text_width(cell))
+                    .max()
+                    .unwrap_or(0);
+                content.max(column.header.len()).min(column.max)
+            })
+            .collect();
+        let Some(width) = width else {
+            return widths;
+        };
+
+        let mins: Vec<usize> = self
+            .columns
+            .iter()
+            .zip(&widths)
+            .map(|(column, &natural)| column.min_width(natural))
+            .collect();
+        let gaps = widths.len().saturating_sub(1);
+        while widths.iter().sum::<usize>() + gaps > width {
+            let widest = (0..widths.len())
+                .filter(|&i| widths[i] > mins[i])
+                .max_by_key(|&i| widths[i]);
+            match widest {
+                Some(i) => widths[i] -= 1,
+                None => break,
+            }
+        }
+        widths
+    }
+}
+
+/// Pad `cell` to `width`, truncating with `ellipsis` only when it is wider
+fn fit_cell(cell: &str, width: usize, ellipsis: &str) -> String {
+    let truncate = (measure_text_width(cell) > width).then_some(ellipsis);
+    pad_str(cell, width, Alignment::Left, truncate).into_owned()
+}
+
+/// Width of stdout when it is a terminal
+pub fn terminal_width() -> Option<usize> {
+    console::Term::stdout()
+        .size_checked()
+        .map(|(_, cols)| usize::from(cols))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sessions() -> Table {
+        let mut table = Table::new(vec![
+            Column::new("NAME", 20),
+            Column::new("STATUS", 10).fixed(),
+            Column::new("PROJECT", 40),
+        ]);
+        table.row(vec![
+            "swift-fox".to_string(),
+            "running".to_string(),
+            "/home/dev/projects/a-rather-long-project-name".to_string(),
+        ]);
+        table
+    }
+
+    fn plain_lines(text: &str) -> Vec<String> {
+        text.lines()
+            .map(|l| console::strip_ansi_codes(l).to_string())
+            .collect()
+    }
+
+    #[test]
+    fn columns_size_to_content_without_a_terminal() {
+        let lines = plain_lines(&sessions().render_width(None));
+        assert_eq!(lines[0], "NAME      STATUS  PROJECT");
+        assert_eq!(lines[1].len(), 9 + 1 + 7 + 1 + 40);
+        assert!(lines[2].starts_with("swift-fox running "));
+        assert_eq!(measure_text_width(&lines[2]), 58);
+    }
+
+    #[test]
+    fn flexible_columns_shrink_to_fit() {
+        let lines = plain_lines(&sessions().render_width(Some(40)));
+        for line in &lines {
+            assert!(measure_text_width(line) <= 40, "{line:?} is too wide");
+        }
+        // The fixed column keeps its width; the long project is truncated
+        assert!(lines[2].contains("running "));
+        assert!(lines[2].ends_with("...") || lines[2].ends_with('…'));
+    }
+
+    #[test]
+    fn fixed_columns_never_shrink() {
+        let widths = sessions().fit(Some(5));
+        assert_eq!(widths[1], 7);
+        assert_eq!(widths[0], 8);
+    }
+}
@@ -9,6 +9,7 @@
 
 use crate::config::{schema::Config, ConfigManager};
 use crate::error::MinoResult;
+use crate::labels;
 use crate::orchestration::ContainerRuntime;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -163,10 +164,22 @@ async fn save_state_to(path: &Path, state: &VersionState) {
     }
 }
 
+/// List composed images: every image carrying the mino version label, plus
+/// unlabeled `mino-composed-*` images built by releases that predate labels.
+pub async fn list_composed_images(runtime: &dyn ContainerRuntime) -> MinoResult<Vec<String>> {
+    let mut images = runtime.image_list_labeled(labels::VERSION).await?;
+    for image in runtime.image_list_prefixed("mino-composed-").await? {
+        if !images.contains(&image) {
+            images.push(image);
+        }
+    }
+    Ok(images)
+}
+
 /// Clear all composed images. Prunes stopped containers first to avoid
 /// "image in use" errors. Returns Ok(count) with number of images removed.
 pub async fn clear_composed_images(runtime: &dyn ContainerRuntime) -> MinoResult<usize> {
-    let images = runtime.image_list_prefixed("mino-composed-").await?;
+    let images = list_composed_images(runtime).await?;
     if images.is_empty() {
         return Ok(0);
     }
@@ -200,7 +213,7 @@ async fn check_stale_images_inner(
 
     // Only query composed images if version actually changed
     let result = if let Some(info) = info {
-        match runtime.image_list_prefixed("mino-composed-").await {
+        match list_composed_images(runtime).await {
             Ok(images) if !images.is_empty() => Some(info),
             Ok(_) => None,
             Err(e) => {
@@ -780,6 +793,36 @@ mod tests {
         mock.assert_called_with("image_remove", &["mino-composed-def456"]);
     }
 
+    #[tokio::test]
+    async fn list_composed_images_merges_labeled_and_legacy() {
+        let mock = MockRuntime::new()
+            .on(
+                "image_list_labeled",
+                Ok(MockResponse::StringVec(vec![
+                    "mino-composed-abc123:latest".to_string(),
+                    "my-renamed-image:latest".to_string(),
+                ])),
+            )
+            .on(
+                "image_list_prefixed",
+                Ok(MockResponse::StringVec(vec![
+                    "mino-composed-abc123:latest".to_string(),
+                    "mino-composed-old999:latest".to_string(),
+                ])),
+            );
+
+        let images = list_composed_images(&mock).await.unwrap();
+        assert_eq!(
+            images,
+            vec![
+                "mino-composed-abc123:latest",
+                "my-renamed-image:latest",
+                "mino-composed-old999:latest"
+            ]
+        );
+        mock.assert_called_with("image_list_labeled", &["io.mino.version"]);
+    }
+
     #[tokio::test]
     async fn clear_composed_images_empty_returns_zero() {
         let mock = MockRuntime::new();
@@ -0,0 +1,187 @@
+//! Per-session git worktrees
+//!
+//! `mino run --worktree` checks out a new branch named after the session in a
+//! dedicated worktree under the state directory and mounts it instead of the
+//! project's working tree, so parallel sessions never trample each other's
+//! uncommitted changes. Stopping the session removes the worktree unless it
+//! still has uncommitted changes; the branch is always kept.
+
+use crate::config::ConfigManager;
+use crate::error::{MinoError, MinoResult};
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use tokio::process::Command;
+use tracing::debug;
+
+/// Prefix of branches created for session worktrees
+pub const BRANCH_PREFIX: &str = "mino/";
+
+/// A worktree created for a session
+#[derive(Debug, Clone)]
+pub struct Worktree {
+    /// Worktree root
+    pub path: PathBuf,
+    /// The project directory's counterpart inside the worktree
+    pub project_dir: PathBuf,
+    /// Branch checked out in the worktree
+    pub branch: String,
+    /// Repository git dir the worktree's `.git` file points into
+    pub git_common_dir: PathBuf,
+}
+
+impl Worktree {
+    /// Bind mount of the repository's git dir at its host path, so the
+    /// worktree's `.git` file resolves inside the container
+    pub fn git_dir_mount(&self) -> String {
+        format!(
+            "{0}:{0}",
+            self.git_common_dir
+                .display()
+                .to_string()
+                .trim_end_matches('/')
+        )
+    }
+}
+
+/// Branch name for a session's worktree
+pub fn branch_name(session_name: &str) -> String {
+    format!("{}{}", BRANCH_PREFIX, session_name)
+}
+
+/// Create a worktree for `session_name` from the HEAD of the repository
+/// containing `project_dir`.
+pub async fn create(project_dir: &Path, session_name: &str) -> MinoResult<Worktree> {
+    let toplevel = git(project_dir, &["rev-parse", "--show-toplevel"])
+        .await
+        .map(PathBuf::from)
+        .map_err(|_| {
+            MinoError::User(format!(
+                "--worktree needs a git repository, but {} is not inside one",
+                project_dir.display()
+            ))
+        })?;
+    let git_common_dir = git(
+        &toplevel,
+        &["rev-parse", "--path-format=absolute", "--git-common-dir"],
+    )
+    .await
+    .map(PathBuf::from)?;
+
+    let path = ConfigManager::worktrees_dir().join(session_name);
+    if path.exists() {
+        return Err(MinoError::User(format!(
+            "Worktree {} already exists. Remove it with: git worktree remove {}",
+            path.display(),
+            path.display()
+        )));
+    }
+    let parent = ConfigManager::worktrees_dir();
+    tokio::fs::create_dir_all(&parent)
+        .await
+        .map_err(|e| MinoError::io(format!("creating {}", parent.display()), e))?;
+
+    let branch = branch_name(session_name);
+    let target = path.display().to_string();
+    git(
+        &toplevel,
+        &["worktree", "add", "-b", &branch, &target, "HEAD"],
+    )
+    .await?;
+    debug!("Created worktree {} on branch {}", target, branch);
+
+    Ok(Worktree {
+        project_dir: project_dir_in(&path, &toplevel, project_dir),
+        path,
+        branch,
+        git_common_dir,
+    })
+}
+
+/// Remove a session's worktree, keeping its branch.
+///
+/// Fails without removing anything when the worktree has uncommitted
+/// changes. A worktree that is already gone is not an error.
+pub async fn remove(path: &Path) -> MinoResult<()> {
+    if !path.exists() {
+        debug!("Worktree {} already removed", path.display());
+        return Ok(());
+    }
+    let target = path.display().to_string();
+    git(path, &["worktree", "remove", &target]).await?;
+    debug!("Removed worktree {}", target);
+    Ok(())
+}
+
+/// Where `project_dir` (inside the repository at `toplevel`) sits in the
+/// worktree at `worktree`
+fn project_dir_in(worktree: &Path, toplevel: &Path, project_dir: &Path) -> PathBuf {
+    match project_dir.strip_prefix(toplevel) {
+        Ok(relative) if !relative.as_os_str().is_empty() => worktree.join(relative),
+        _ => worktree.to_path_buf(),
+    }
+}
+
+/// Run git in `dir`, returning its trimmed stdout
+async fn git(dir: &Path, args: &[&str]) -> MinoResult<String> {
+    let command = format!("git {}", args.join(" "));
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .stdin(Stdio::null())
+        .output()
+        .await
+        .map_err(|e| MinoError::command_failed(&command, e))?;
+    if !output.status.success() {
+        return Err(MinoError::command_exec(
+            command,
+            String::from_utf8_lossy(&output.stderr).trim(),
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn branch_is_named_after_the_session() {
+        assert_eq!(branch_name("swift-fox"), "mino/swift-fox");
+    }
+
+    #[test]
+    fn project_dir_maps_into_the_worktree() {
+        let worktree = Path::new("/state/worktrees/swift-fox");
+        assert_eq!(
+            project_dir_in(worktree, Path::new("/src/app"), Path::new("/src/app")),
+            worktree
+        );
+        assert_eq!(
+            project_dir_in(worktree, Path::new("/src/app"), Path::new("/src/app/web")),
+            worktree.join("web")
+        );
+        // A project outside the reported toplevel falls back to the root
+        assert_eq!(
+            project_dir_in(worktree, Path::new("/src/app"), Path::new("/elsewhere")),
+            worktree
+        );
+    }
+
+    #[test]
+    fn git_dir_mounts_at_its_host_path() {
+        let worktree = Worktree {
+            path: PathBuf::from("/state/worktrees/swift-fox"),
+            project_dir: PathBuf::from("/state/worktrees/swift-fox"),
+            branch: branch_name("swift-fox"),
+            git_common_dir: PathBuf::from("/src/app/.git"),
+        };
+        assert_eq!(worktree.git_dir_mount(), "/src/app/.git:/src/app/.git");
+    }
+
+    #[tokio::test]
+    async fn removing_a_missing_worktree_is_ok() {
+        assert!(remove(Path::new("/nonexistent/mino-worktree"))
+            .await
+            .is_ok());
+    }
+}